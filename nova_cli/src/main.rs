@@ -14,7 +14,7 @@ use helper::{
 use nova_vm::{
     ecmascript::{
         execution::{
-            Agent, JsResult,
+            Agent, AtomicsWaiter, JsResult,
             agent::{GcAgent, HostHooks, Job, Options},
         },
         scripts_and_modules::script::{parse_script, script_evaluation},
@@ -81,6 +81,8 @@ enum Command {
 #[derive(Default)]
 struct CliHostHooks {
     promise_job_queue: RefCell<VecDeque<Job>>,
+    finalization_registry_cleanup_job_queue: RefCell<VecDeque<Job>>,
+    atomics_wait_async_job_queue: RefCell<VecDeque<(AtomicsWaiter, Job)>>,
 }
 
 // RefCell doesn't implement Debug
@@ -93,12 +95,44 @@ impl Debug for CliHostHooks {
 }
 
 impl CliHostHooks {
-    fn has_promise_jobs(&self) -> bool {
+    fn has_jobs(&self) -> bool {
         !self.promise_job_queue.borrow().is_empty()
+            || !self
+                .finalization_registry_cleanup_job_queue
+                .borrow()
+                .is_empty()
+            || !self.atomics_wait_async_job_queue.borrow().is_empty()
     }
 
-    fn pop_promise_job(&self) -> Option<Job> {
-        self.promise_job_queue.borrow_mut().pop_front()
+    /// Pop the next job to run: promise jobs are run first, then the jobs of
+    /// ready Atomics.waitAsync waiters, then FinalizationRegistry cleanup
+    /// jobs. If only waiting Atomics.waitAsync jobs remain, this blocks until
+    /// the first of them times out.
+    fn pop_job(&self) -> Option<Job> {
+        if let Some(job) = self.promise_job_queue.borrow_mut().pop_front() {
+            return Some(job);
+        }
+        let mut waiters = self.atomics_wait_async_job_queue.borrow_mut();
+        if let Some(index) = waiters.iter().position(|(waiter, _)| waiter.is_ready()) {
+            return waiters.remove(index).map(|(_, job)| job);
+        }
+        if let Some(job) = self
+            .finalization_registry_cleanup_job_queue
+            .borrow_mut()
+            .pop_front()
+        {
+            return Some(job);
+        }
+        // No more code will run in this agent to notify the remaining
+        // waiters, so only the waiters with a timeout can still finish.
+        let (index, _) = waiters
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (waiter, _))| Some((index, waiter.deadline()?)))
+            .min_by_key(|(_, deadline)| *deadline)?;
+        let (waiter, job) = waiters.remove(index)?;
+        waiter.wait();
+        Some(job)
     }
 }
 
@@ -106,6 +140,18 @@ impl HostHooks for CliHostHooks {
     fn enqueue_promise_job(&self, job: Job) {
         self.promise_job_queue.borrow_mut().push_back(job);
     }
+
+    fn enqueue_finalization_registry_cleanup_job(&self, job: Job) {
+        self.finalization_registry_cleanup_job_queue
+            .borrow_mut()
+            .push_back(job);
+    }
+
+    fn enqueue_atomics_wait_async_job(&self, waiter: AtomicsWaiter, job: Job) {
+        self.atomics_wait_async_job_queue
+            .borrow_mut()
+            .push_back((waiter, job));
+    }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -145,6 +191,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Options {
                     disable_gc: nogc,
                     print_internals: verbose,
+                    can_block: true,
                 },
                 // SAFETY: Host hooks is a valid pointer.
                 unsafe { host_hooks.as_ref() },
@@ -199,7 +246,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             match result.bind(gc.nogc()) {
                                 Ok(result) => {
                                     let ok_result = result.unbind().scope(agent, gc.nogc());
-                                    while let Some(job) = host_hooks.pop_promise_job() {
+                                    while let Some(job) = host_hooks.pop_job() {
                                         job.run(agent, gc.reborrow()).unbind()?.bind(gc.nogc());
                                     }
                                     Ok(ok_result.get(agent).bind(gc.into_nogc()))
@@ -210,7 +257,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                         // SAFETY: Still valid.
                         let host_hooks = unsafe { host_hooks.as_ref() };
-                        let result = if host_hooks.has_promise_jobs() {
+                        let result = if host_hooks.has_jobs() {
                             run_microtask_queue(agent, host_hooks, result.unbind(), gc.reborrow())
                                 .unbind()
                                 .bind(gc.nogc())
@@ -254,6 +301,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Options {
                     disable_gc,
                     print_internals,
+                    can_block: true,
                 },
                 host_hooks,
             );
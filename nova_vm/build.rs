@@ -86,6 +86,259 @@ fn gen_builtin_strings() -> io::Result<Vec<u8>> {
 
     Ok(output.into_bytes())
 }
+/// Read the data lines of a Unicode Character Database file, with comments
+/// and surrounding whitespace removed, split into their semicolon separated
+/// fields.
+fn read_ucd_file(name: &str) -> io::Result<Vec<Vec<String>>> {
+    let file = File::open(format!("src/unicode_data/{name}"))?;
+    let mut lines = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        let data = line.split('#').next().unwrap().trim();
+        if data.is_empty() {
+            continue;
+        }
+        lines.push(
+            data.split(';')
+                .map(|field| field.trim().to_string())
+                .collect(),
+        );
+    }
+    Ok(lines)
+}
+
+/// Parse a `XXXX` or `XXXX..YYYY` code point range field.
+fn parse_code_point_range(field: &str) -> (u32, u32) {
+    let parse = |hex: &str| u32::from_str_radix(hex, 16).unwrap();
+    match field.split_once("..") {
+        Some((start, end)) => (parse(start), parse(end)),
+        None => (parse(field), parse(field)),
+    }
+}
+
+/// Inclusive code point ranges, sorted by start.
+type Ranges = Vec<(u32, u32)>;
+
+/// A list of property names or aliases along with the ranges they match.
+type NamedRanges = Vec<(Vec<String>, Ranges)>;
+
+/// Collect the code points for which the predicate holds into sorted,
+/// non-overlapping ranges.
+fn collect_ranges(predicate: impl Fn(usize) -> bool) -> Ranges {
+    let mut ranges: Ranges = Vec::new();
+    for cp in 0..=0x10FFFF {
+        if !predicate(cp) {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if last.1 + 1 == cp as u32 => last.1 = cp as u32,
+            _ => ranges.push((cp as u32, cp as u32)),
+        }
+    }
+    ranges
+}
+
+fn write_ranges_table(output: &mut String, name: &str, ranges: &[(u32, u32)]) {
+    output.push_str(&format!("static {name}: &[(u32, u32)] = &["));
+    for (i, (start, end)) in ranges.iter().enumerate() {
+        if i % 8 == 0 {
+            output.push_str("\n    ");
+        } else {
+            output.push(' ');
+        }
+        output.push_str(&format!("({start:#X}, {end:#X}),"));
+    }
+    output.push_str("\n];\n\n");
+}
+
+/// Write a lookup function matching property names or values to tables.
+fn write_lookup_function(
+    output: &mut String,
+    doc: &str,
+    name: &str,
+    arms: &[(Vec<String>, String)],
+) {
+    output.push_str(&format!("/// {doc}\n"));
+    output.push_str(&format!(
+        "pub(super) fn {name}(name: &str) -> Option<&'static [(u32, u32)]> {{\n    match name {{\n"
+    ));
+    for (names, table) in arms {
+        // Note: Some properties and values have an alias that is the same as
+        // their name.
+        let mut names = names.clone();
+        names.dedup();
+        let names = names
+            .iter()
+            .map(|name| format!("\"{name}\""))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        output.push_str(&format!("        {names} => Some({table}),\n"));
+    }
+    output.push_str("        _ => None,\n    }\n}\n\n");
+}
+
+/// Generate the code point tables of the Unicode properties supported in
+/// regular expression property escapes from the Unicode Character Database
+/// files in `src/unicode_data`.
+fn gen_unicode_tables() -> io::Result<Vec<u8>> {
+    let mut output = String::with_capacity(1 << 20);
+
+    // General_Category: Every code point has exactly one of the leaf values;
+    // the grouped values are unions of the leaf values.
+    let mut general_categories: Vec<(Vec<String>, Vec<String>)> = Vec::new();
+    let mut scripts: Vec<Vec<String>> = Vec::new();
+    for fields in read_ucd_file("PropertyValueAliases.txt")? {
+        match fields[0].as_str() {
+            "gc" => general_categories.push((fields[1..].to_vec(), Vec::new())),
+            "sc" => scripts.push(fields[1..].to_vec()),
+            _ => {}
+        }
+    }
+    // The leaf values that a group value consists of are given in the
+    // comments of PropertyValueAliases.txt.
+    let file = File::open("src/unicode_data/PropertyValueAliases.txt")?;
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        let Some((data, members)) = line.split_once('#') else {
+            continue;
+        };
+        let fields: Vec<&str> = data.split(';').map(str::trim).collect();
+        if fields[0] != "gc" {
+            continue;
+        }
+        let entry = general_categories
+            .iter_mut()
+            .find(|(names, _)| names[0] == fields[1])
+            .unwrap();
+        entry.1 = members.split('|').map(|m| m.trim().to_string()).collect();
+    }
+    let leaf_index = |value: &str| {
+        general_categories
+            .iter()
+            .filter(|(_, members)| members.is_empty())
+            .position(|(names, _)| names[0] == value)
+            .unwrap() as u8
+    };
+    let unassigned = leaf_index("Cn");
+    let mut gc = vec![unassigned; 0x110000];
+    for fields in read_ucd_file("DerivedGeneralCategory.txt")? {
+        let (start, end) = parse_code_point_range(&fields[0]);
+        let index = leaf_index(&fields[1]);
+        gc[start as usize..=end as usize].fill(index);
+    }
+    let mut arms = Vec::new();
+    for (names, members) in &general_categories {
+        let indexes: Vec<u8> = if members.is_empty() {
+            vec![leaf_index(&names[0])]
+        } else {
+            members.iter().map(|member| leaf_index(member)).collect()
+        };
+        let table = format!("GC_{}", names[0].to_uppercase());
+        write_ranges_table(
+            &mut output,
+            &table,
+            &collect_ranges(|cp| indexes.contains(&gc[cp])),
+        );
+        arms.push((names.clone(), table));
+    }
+    write_lookup_function(
+        &mut output,
+        "Returns the code points with the given General_Category value.",
+        "general_category",
+        &arms,
+    );
+
+    // Script and Script_Extensions: Code points not listed in
+    // ScriptExtensions.txt have their Script as their only extension.
+    let unknown = scripts.iter().position(|names| names[0] == "Zzzz").unwrap() as u16;
+    let script_index = |value: &str| {
+        scripts
+            .iter()
+            .position(|names| names.iter().any(|name| name == value))
+            .unwrap() as u16
+    };
+    let mut sc = vec![unknown; 0x110000];
+    for fields in read_ucd_file("Scripts.txt")? {
+        let (start, end) = parse_code_point_range(&fields[0]);
+        sc[start as usize..=end as usize].fill(script_index(&fields[1]));
+    }
+    let mut scx: Vec<Option<Vec<u16>>> = vec![None; 0x110000];
+    for fields in read_ucd_file("ScriptExtensions.txt")? {
+        let (start, end) = parse_code_point_range(&fields[0]);
+        let extensions: Vec<u16> = fields[1].split_whitespace().map(script_index).collect();
+        for cp in start..=end {
+            scx[cp as usize] = Some(extensions.clone());
+        }
+    }
+    let mut script_arms = Vec::new();
+    let mut script_extensions_arms = Vec::new();
+    for (index, names) in scripts.iter().enumerate() {
+        let index = index as u16;
+        let table = format!("SC_{}", names[0].to_uppercase());
+        write_ranges_table(&mut output, &table, &collect_ranges(|cp| sc[cp] == index));
+        script_arms.push((names.clone(), table));
+        let table = format!("SCX_{}", names[0].to_uppercase());
+        write_ranges_table(
+            &mut output,
+            &table,
+            &collect_ranges(|cp| match &scx[cp] {
+                Some(extensions) => extensions.contains(&index),
+                None => sc[cp] == index,
+            }),
+        );
+        script_extensions_arms.push((names.clone(), table));
+    }
+    write_lookup_function(
+        &mut output,
+        "Returns the code points with the given Script value.",
+        "script",
+        &script_arms,
+    );
+    write_lookup_function(
+        &mut output,
+        "Returns the code points with the given value in their Script_Extensions.",
+        "script_extensions",
+        &script_extensions_arms,
+    );
+
+    // Binary properties: ASCII, Any and Assigned are not part of the data
+    // files.
+    let mut binary_properties: NamedRanges = vec![
+        (vec!["ASCII".to_string()], vec![(0, 0x7F)]),
+        (vec!["Any".to_string()], vec![(0, 0x10FFFF)]),
+        (
+            vec!["Assigned".to_string()],
+            collect_ranges(|cp| gc[cp] != unassigned),
+        ),
+    ];
+    for fields in read_ucd_file("PropertyAliases.txt")? {
+        if !matches!(fields[0].as_str(), "gc" | "sc" | "scx") {
+            binary_properties.push((fields, Vec::new()));
+        }
+    }
+    for fields in read_ucd_file("DerivedBinaryProperties.txt")? {
+        let range = parse_code_point_range(&fields[0]);
+        let entry = binary_properties
+            .iter_mut()
+            .find(|(names, _)| names.contains(&fields[1]))
+            .unwrap();
+        entry.1.push(range);
+    }
+    let mut arms = Vec::new();
+    for (names, ranges) in &binary_properties {
+        let table = format!("BINARY_{}", names[0].to_uppercase());
+        write_ranges_table(&mut output, &table, ranges);
+        arms.push((names.clone(), table));
+    }
+    write_lookup_function(
+        &mut output,
+        "Returns the code points that have the given binary property.",
+        "binary_property",
+        &arms,
+    );
+
+    Ok(output.into_bytes())
+}
 use std::env;
 use std::fs;
 use std::path::Path;
@@ -98,4 +351,11 @@ fn main() {
     let dest_path = Path::new(&out_dir).join("builtin_strings.rs");
     let builtin_strings_data = gen_builtin_strings().unwrap();
     fs::write(dest_path, builtin_strings_data).unwrap();
+
+    if env::var_os("CARGO_FEATURE_REGEXP").is_some() {
+        println!("cargo:rerun-if-changed=src/unicode_data");
+        let dest_path = Path::new(&out_dir).join("unicode_tables.rs");
+        let unicode_tables_data = gen_unicode_tables().unwrap();
+        fs::write(dest_path, unicode_tables_data).unwrap();
+    }
 }
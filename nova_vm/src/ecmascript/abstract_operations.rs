@@ -3,6 +3,8 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 pub(crate) mod keyed_group;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) mod operations_on_disposable_objects;
 pub(crate) mod operations_on_iterator_objects;
 pub(crate) mod operations_on_objects;
 pub(crate) mod testing_and_comparison;
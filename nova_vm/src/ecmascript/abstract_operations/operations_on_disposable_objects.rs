@@ -0,0 +1,517 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [Operations on Disposable Objects](https://tc39.es/proposal-explicit-resource-management/#sec-operations-on-disposable-objects)
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_objects::{call_function, get_object_method},
+        builtins::{
+            ArgumentsList,
+            disposable_stack::{
+                DisposableStack,
+                data::{AsyncDisposeState, DisposableResource, DisposeHint, DisposeMethod},
+            },
+            fundamental_objects::error_objects::suppressed_error_constructors::create_suppressed_error,
+            promise::Promise,
+            promise_objects::{
+                promise_abstract_operations::{
+                    promise_capability_records::PromiseCapability,
+                    promise_reaction_records::{PromiseReactionHandler, PromiseReactionType},
+                },
+                promise_prototype::inner_promise_then,
+            },
+        },
+        execution::{
+            Agent, JsResult,
+            agent::{ExceptionType, JsError},
+        },
+        types::{IntoValue, Object, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+/// ### [AddDisposableResource ( disposeCapability, V, hint \[ , method \] )](https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource)
+///
+/// The abstract operation AddDisposableResource takes arguments
+/// disposeCapability (a DisposeCapability Record), V (an ECMAScript language
+/// value), and hint (either sync-dispose or async-dispose) and optional
+/// argument method (a function object) and returns either a normal completion
+/// containing UNUSED or a throw completion.
+///
+/// > NOTE: When method is present, V is the value passed to
+/// > `DisposableStack.prototype.adopt` or undefined for
+/// > `DisposableStack.prototype.defer`.
+pub(crate) fn add_disposable_resource<'a>(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    v: Value,
+    hint: DisposeHint,
+    method: Option<DisposeMethod>,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let mut dispose_capability = dispose_capability.bind(gc.nogc());
+    let v = v.bind(gc.nogc());
+    let resource = if let Some(method) = method {
+        // 2. Else,
+        // a. Assert: V is undefined.
+        // b. Let resource be ? CreateDisposableResource(undefined, hint, method).
+        DisposableResource {
+            value: v,
+            hint,
+            method: Some(method.bind(gc.nogc())),
+        }
+    } else {
+        // 1. If method is not present, then
+        // a. If V is either null or undefined and hint is sync-dispose, return UNUSED.
+        if (v.is_null() || v.is_undefined()) && hint == DisposeHint::SyncDispose {
+            return Ok(());
+        }
+        // b. NOTE: When V is either null or undefined and hint is
+        //    async-dispose, we record that a resource was added to ensure
+        //    that an Await occurs.
+        // c. Let resource be ? CreateDisposableResource(V, hint).
+        let scoped_dispose_capability = dispose_capability.scope(agent, gc.nogc());
+        let resource = create_disposable_resource(agent, v.unbind(), hint, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // SAFETY: not shared.
+        dispose_capability = unsafe { scoped_dispose_capability.take(agent) }.bind(gc.nogc());
+        resource
+    };
+    // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+    agent[dispose_capability].resources.push(resource.unbind());
+    // 4. Return UNUSED.
+    Ok(())
+}
+
+/// ### [CreateDisposableResource ( V, hint \[ , method \] )](https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource)
+///
+/// The abstract operation CreateDisposableResource takes arguments V (an
+/// ECMAScript language value) and hint (either sync-dispose or async-dispose)
+/// and optional argument method (a function object) and returns either a
+/// normal completion containing a DisposableResource Record or a throw
+/// completion.
+///
+/// > NOTE: Resources with a method are created directly by
+/// > AddDisposableResource.
+fn create_disposable_resource<'a>(
+    agent: &mut Agent,
+    v: Value,
+    hint: DisposeHint,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, DisposableResource<'a>> {
+    let v = v.bind(gc.nogc());
+    // 1. If method is not present, then
+    // a. If V is either null or undefined, then
+    if v.is_null() || v.is_undefined() {
+        // i. Set V to undefined.
+        // ii. Set method to undefined.
+        return Ok(DisposableResource {
+            value: Value::Undefined,
+            hint,
+            method: None,
+        });
+    }
+    // b. Else,
+    // i. If V is not an Object, throw a TypeError exception.
+    let Ok(o) = Object::try_from(v) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Value is not an object",
+            gc.into_nogc(),
+        ));
+    };
+    let scoped_o = o.scope(agent, gc.nogc());
+    // ii. Set method to ? GetDisposeMethod(V, hint).
+    let method = get_dispose_method(agent, o.unbind(), hint, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // iii. If method is undefined, throw a TypeError exception.
+    let Some(method) = method else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Value is not disposable",
+            gc.into_nogc(),
+        ));
+    };
+    // 3. Return the DisposableResource Record { [[ResourceValue]]: V,
+    //    [[Hint]]: hint, [[DisposeMethod]]: method }.
+    let method = method.unbind();
+    let gc = gc.into_nogc();
+    Ok(DisposableResource {
+        value: scoped_o.get(agent).into_value().bind(gc),
+        hint,
+        method: Some(method.bind(gc)),
+    })
+}
+
+/// ### [GetDisposeMethod ( V, hint )](https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod)
+///
+/// The abstract operation GetDisposeMethod takes arguments V (an Object) and
+/// hint (either sync-dispose or async-dispose) and returns either a normal
+/// completion containing either a function object or undefined, or a throw
+/// completion.
+pub(crate) fn get_dispose_method<'a>(
+    agent: &mut Agent,
+    v: Object,
+    hint: DisposeHint,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<DisposeMethod<'a>>> {
+    let v = v.bind(gc.nogc());
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::AsyncDispose {
+        let scoped_v = v.scope(agent, gc.nogc());
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        let method = get_object_method(
+            agent,
+            v.unbind(),
+            WellKnownSymbolIndexes::AsyncDispose.into(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        if let Some(method) = method {
+            return Ok(Some(DisposeMethod::Method(method.bind(gc.into_nogc()))));
+        }
+        // b. If method is undefined, then
+        // i. Set method to ? GetMethod(V, @@dispose).
+        let method = get_object_method(
+            agent,
+            scoped_v.get(agent),
+            WellKnownSymbolIndexes::Dispose.into(),
+            gc,
+        )?;
+        // ii. If method is not undefined, then
+        // 1. Let closure be a new Abstract Closure with no parameters that
+        //    captures method and performs the following steps when called:
+        //    ...
+        // 2. NOTE: This function is not observable to user code. It is used
+        //    to ensure that a Promise returned from a synchronous @@dispose
+        //    method will not be awaited and that any exception thrown will
+        //    not be thrown synchronously.
+        // 3. Return CreateBuiltinFunction(closure, 0, "", « »).
+        Ok(method.map(DisposeMethod::SyncAsAsync))
+    } else {
+        // 2. Else,
+        // a. Let method be ? GetMethod(V, @@dispose).
+        let method = get_object_method(
+            agent,
+            v.unbind(),
+            WellKnownSymbolIndexes::Dispose.into(),
+            gc,
+        )?;
+        // 3. Return method.
+        Ok(method.map(DisposeMethod::Method))
+    }
+}
+
+/// Calls a \[\[DisposeMethod]] with the \[\[ResourceValue]] of its resource.
+///
+/// This is the Call(method, value) step of DisposeResources. Methods created
+/// by `adopt` are called with undefined as the this value and the resource
+/// value as their only argument, as the closure created by
+/// `DisposableStack.prototype.adopt` would.
+fn call_dispose_method<'a>(
+    agent: &mut Agent,
+    value: Value,
+    method: DisposeMethod,
+    gc: GcScope<'a, '_>,
+) -> JsResult<'a, Value<'a>> {
+    match method {
+        DisposeMethod::Method(f) | DisposeMethod::SyncAsAsync(f) => {
+            call_function(agent, f.unbind(), value.unbind(), None, gc)
+        }
+        DisposeMethod::Adopt(f) => call_function(
+            agent,
+            f.unbind(),
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [value.unbind()])),
+            gc,
+        ),
+    }
+}
+
+/// Combines a throw completion from a dispose method with the completion of
+/// DisposeResources so far.
+///
+/// This is DisposeResources step 3.e.iii.
+fn suppress_error<'a>(
+    agent: &mut Agent,
+    result: JsError,
+    completion: Option<JsError>,
+    gc: NoGcScope<'a, '_>,
+) -> JsError<'a> {
+    // 1. If completion is a throw completion, then
+    if let Some(completion) = completion {
+        // a. Set result to result.[[Value]].
+        // b. Let suppressed be completion.[[Value]].
+        // c. Let error be a newly created SuppressedError object.
+        // d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+        // e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+        let error = create_suppressed_error(agent, result.value(), completion.value(), gc);
+        // f. Set completion to ThrowCompletion(error).
+        JsError::new(error.into_value())
+    } else {
+        // 2. Else,
+        // a. Set completion to result.
+        result.bind(gc)
+    }
+}
+
+/// ### [DisposeResources ( disposeCapability, completion )](https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources)
+///
+/// The abstract operation DisposeResources takes arguments disposeCapability
+/// (a DisposeCapability Record) and completion (a Completion Record) and
+/// returns either a normal completion containing UNUSED or an abrupt
+/// completion.
+///
+/// This variant is used when the resource stack contains only sync-dispose
+/// resources and thus never awaits.
+pub(crate) fn dispose_resources<'a>(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    completion: Option<JsError>,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let dispose_capability = dispose_capability.scope(agent, gc.nogc());
+    let mut completion = completion.map(|error| error.scope(agent, gc.nogc()));
+    // 1. Let needsAwait be false.
+    // 2. Let hasAwaited be false.
+    // 3. For each element resource of
+    //    disposeCapability.[[DisposableResourceStack]], in reverse list
+    //    order, do
+    loop {
+        let dispose_capability = dispose_capability.get(agent);
+        let Some(resource) = agent[dispose_capability].resources.pop() else {
+            break;
+        };
+        // a. Let value be resource.[[ResourceValue]].
+        // b. Let hint be resource.[[Hint]].
+        // c. Let method be resource.[[DisposeMethod]].
+        let DisposableResource {
+            value,
+            hint,
+            method,
+        } = resource.bind(gc.nogc());
+        debug_assert_eq!(hint, DisposeHint::SyncDispose);
+        // d. If hint is sync-dispose and needsAwait is true and hasAwaited
+        //    is false, then
+        // NOTE: needsAwait is only set by async-dispose resources.
+        // e. If method is not undefined, then
+        // f. Else,
+        //   i. Assert: hint is async-dispose.
+        let method = method.unwrap();
+        // i. Let result be Completion(Call(method, value)).
+        let result = call_dispose_method(agent, value.unbind(), method.unbind(), gc.reborrow())
+            .unbind()
+            .bind(gc.nogc());
+        // iii. If result is a throw completion, then
+        if let Err(result) = result {
+            let previous = completion.take().map(|error| error.get(agent));
+            let error = suppress_error(agent, result.unbind(), previous, gc.nogc());
+            completion = Some(error.scope(agent, gc.nogc()));
+        }
+    }
+    // 4. If needsAwait is true and hasAwaited is false, then
+    // 5. NOTE: After disposeCapability has been disposed, it will never be
+    //    used again. The contents of
+    //    disposeCapability.[[DisposableResourceStack]] can be discarded in
+    //    implementations, such as by garbage collection, at this point.
+    // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+    // 7. Return ? completion.
+    match completion {
+        Some(error) => Err(error.get(agent).bind(gc.into_nogc())),
+        None => Ok(()),
+    }
+}
+
+/// ### [DisposeResources ( disposeCapability, completion )](https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources)
+///
+/// This variant is used when the resource stack may contain async-dispose
+/// resources. The returned promise is fulfilled with undefined when all
+/// resources have been disposed or rejected with the resulting throw
+/// completion.
+///
+/// The Await steps cannot suspend the calling execution context, so the loop
+/// is instead continued in promise reaction jobs with the progress stored in
+/// the disposeCapability.
+pub(crate) fn dispose_resources_async<'a>(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    completion: Option<JsError>,
+    mut gc: GcScope<'a, '_>,
+) -> Promise<'a> {
+    let dispose_capability = dispose_capability.bind(gc.nogc());
+    let completion = completion.bind(gc.nogc());
+    let promise_capability = PromiseCapability::new(agent, gc.nogc());
+    let promise = promise_capability.promise();
+    // 1. Let needsAwait be false.
+    // 2. Let hasAwaited be false.
+    let previous = agent[dispose_capability]
+        .async_dispose
+        .replace(AsyncDisposeState {
+            promise: promise.unbind(),
+            error: completion.unbind(),
+            needs_await: false,
+            has_awaited: false,
+        });
+    debug_assert!(previous.is_none());
+    let promise = promise.scope(agent, gc.nogc());
+    continue_dispose_resources(agent, dispose_capability.unbind(), gc.reborrow());
+    promise.get(agent).bind(gc.into_nogc())
+}
+
+/// Resumes an asynchronous DisposeResources call after an Await.
+pub(crate) fn dispose_resources_resume(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    reaction_type: PromiseReactionType,
+    value: Value,
+    gc: GcScope,
+) {
+    let dispose_capability = dispose_capability.bind(gc.nogc());
+    let value = value.bind(gc.nogc());
+    if reaction_type == PromiseReactionType::Reject {
+        // iii. If result is a throw completion, then
+        let state = agent[dispose_capability].async_dispose.unwrap();
+        let error = suppress_error(agent, JsError::new(value), state.error, gc.nogc());
+        agent[dispose_capability]
+            .async_dispose
+            .as_mut()
+            .unwrap()
+            .error = Some(error.unbind());
+    }
+    continue_dispose_resources(agent, dispose_capability.unbind(), gc);
+}
+
+/// Perform the loop of DisposeResources until the next Await or until all
+/// resources have been disposed.
+fn continue_dispose_resources(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    mut gc: GcScope,
+) {
+    let dispose_capability = dispose_capability.scope(agent, gc.nogc());
+    loop {
+        let stack = dispose_capability.get(agent);
+        let data = &mut agent[stack];
+        let state = data.async_dispose.as_mut().unwrap();
+        // 3. For each element resource of
+        //    disposeCapability.[[DisposableResourceStack]], in reverse list
+        //    order, do
+        let Some(resource) = data.resources.pop() else {
+            // 4. If needsAwait is true and hasAwaited is false, then
+            if state.needs_await && !state.has_awaited {
+                state.needs_await = false;
+                // a. Perform ! Await(undefined).
+                await_dispose(agent, dispose_capability.get(agent), Value::Undefined, gc);
+                return;
+            }
+            // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+            let AsyncDisposeState { promise, error, .. } = data.async_dispose.take().unwrap();
+            let promise_capability = PromiseCapability::from_promise(promise, true);
+            // 7. Return ? completion.
+            match error {
+                Some(error) => promise_capability.reject(agent, error.value(), gc.nogc()),
+                None => promise_capability.resolve(agent, Value::Undefined, gc),
+            }
+            return;
+        };
+        // a. Let value be resource.[[ResourceValue]].
+        // b. Let hint be resource.[[Hint]].
+        // c. Let method be resource.[[DisposeMethod]].
+        let DisposableResource {
+            value,
+            hint,
+            method,
+        } = resource;
+        // d. If hint is sync-dispose and needsAwait is true and hasAwaited
+        //    is false, then
+        if hint == DisposeHint::SyncDispose && state.needs_await && !state.has_awaited {
+            // ii. Set needsAwait to false.
+            state.needs_await = false;
+            // i. Perform ! Await(undefined).
+            // NOTE: The resource is disposed once the Await has finished.
+            data.resources.push(resource);
+            await_dispose(agent, dispose_capability.get(agent), Value::Undefined, gc);
+            return;
+        }
+        // e. If method is not undefined, then
+        let Some(method) = method else {
+            // f. Else,
+            // i. Assert: hint is async-dispose.
+            debug_assert_eq!(hint, DisposeHint::AsyncDispose);
+            // ii. Set needsAwait to true.
+            state.needs_await = true;
+            // iii. NOTE: This can only indicate a case where either null or
+            //      undefined was the initial value of an Object in an await
+            //      using declaration.
+            continue;
+        };
+        // i. Let result be Completion(Call(method, value)).
+        let result = call_dispose_method(agent, value, method, gc.reborrow())
+            .unbind()
+            .bind(gc.nogc());
+        let result = match (method, result) {
+            // The closure created by GetDisposeMethod returns a promise that
+            // is resolved with undefined or rejected with the thrown error.
+            (DisposeMethod::SyncAsAsync(_), Ok(_)) => Ok(Value::Undefined),
+            (DisposeMethod::SyncAsAsync(_), Err(error)) => {
+                let promise_capability = PromiseCapability::new(agent, gc.nogc());
+                let promise = promise_capability.promise();
+                promise_capability.reject(agent, error.value(), gc.nogc());
+                Ok(promise.into_value())
+            }
+            (_, result) => result,
+        };
+        match result {
+            // ii. If result is a normal completion and hint is async-dispose, then
+            Ok(result) if hint == DisposeHint::AsyncDispose => {
+                // 2. Set hasAwaited to true.
+                let dispose_capability = dispose_capability.get(agent);
+                agent[dispose_capability]
+                    .async_dispose
+                    .as_mut()
+                    .unwrap()
+                    .has_awaited = true;
+                // 1. Set result to Completion(Await(result.[[Value]])).
+                await_dispose(agent, dispose_capability, result.unbind(), gc);
+                return;
+            }
+            Ok(_) => {}
+            // iii. If result is a throw completion, then
+            Err(result) => {
+                let dispose_capability = dispose_capability.get(agent);
+                let state = agent[dispose_capability].async_dispose.unwrap();
+                let error = suppress_error(agent, result, state.error, gc.nogc());
+                agent[dispose_capability]
+                    .async_dispose
+                    .as_mut()
+                    .unwrap()
+                    .error = Some(error.unbind());
+            }
+        }
+    }
+}
+
+/// Performs the Await steps of an asynchronous DisposeResources call; the
+/// call is resumed by [`dispose_resources_resume`] once the awaited value
+/// settles.
+fn await_dispose(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    value: Value,
+    mut gc: GcScope,
+) {
+    let dispose_capability = dispose_capability.scope(agent, gc.nogc());
+    let promise = Promise::resolve(agent, value, gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    let handler = PromiseReactionHandler::DisposeResources(dispose_capability.get(agent));
+    inner_promise_then(agent, promise, handler, handler, None, gc.nogc());
+}
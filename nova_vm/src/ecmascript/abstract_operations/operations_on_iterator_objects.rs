@@ -11,7 +11,11 @@ use crate::{
             testing_and_comparison::is_callable,
             type_conversion::to_boolean,
         },
-        builtins::{ArgumentsList, ordinary::ordinary_object_create_with_intrinsics},
+        builtins::{
+            ArgumentsList,
+            control_abstraction_objects::iteration::async_from_sync_iterator_objects::create_async_from_sync_iterator,
+            ordinary::ordinary_object_create_with_intrinsics,
+        },
         execution::{
             Agent, JsResult, ProtoIntrinsics,
             agent::{ExceptionType, JsError},
@@ -202,17 +206,23 @@ pub(crate) fn get_iterator<'a>(
             };
 
             // iii. Let syncIteratorRecord be ? GetIteratorFromMethod(obj, syncMethod).
-            let _sync_iterator_record = get_iterator_from_method(
+            let Some(sync_iterator_record) = get_iterator_from_method(
                 agent,
                 scoped_obj.get(agent),
                 sync_method.unbind(),
                 gc.reborrow(),
             )
             .unbind()?
-            .bind(gc.nogc());
+            .bind(gc.nogc()) else {
+                return Ok(None);
+            };
 
             // iv. Return CreateAsyncFromSyncIterator(syncIteratorRecord).
-            todo!("Implement create_async_from_sync_iterator(sync_iterator_record)")
+            return Ok(Some(
+                create_async_from_sync_iterator(agent, sync_iterator_record.unbind(), gc.nogc())
+                    .unbind()
+                    .bind(gc.into_nogc()),
+            ));
         } else {
             method
         }
@@ -287,7 +297,7 @@ pub(crate) fn iterator_next<'a>(
 /// The abstract operation IteratorComplete takes argument iterResult (an
 /// Object) and returns either a normal completion containing a Boolean or a
 /// throw completion.
-fn iterator_complete<'a>(
+pub(crate) fn iterator_complete<'a>(
     agent: &mut Agent,
     iter_result: Object,
     gc: GcScope<'a, '_>,
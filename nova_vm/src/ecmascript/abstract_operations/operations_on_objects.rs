@@ -4,6 +4,8 @@
 
 //! ## [7.3 Operations on Objects](https://tc39.es/ecma262/#sec-operations-on-objects)
 
+use core::ops::ControlFlow;
+
 use crate::{
     SmallInteger,
     ecmascript::{
@@ -19,7 +21,7 @@ use crate::{
             },
         },
         builtins::{
-            ArgumentsList, Array, BuiltinConstructorFunction, array_create,
+            ArgumentsList, Array, array_create,
             keyed_collections::map_objects::map_prototype::canonicalize_keyed_collection_key,
             proxy::abstract_operations::{
                 try_validate_non_revoked_proxy, validate_non_revoked_proxy,
@@ -31,9 +33,9 @@ use crate::{
             new_class_field_initializer_environment,
         },
         types::{
-            BUILTIN_STRING_MEMORY, Function, InternalMethods, IntoFunction, IntoObject, IntoValue,
-            Number, Object, ObjectHeapData, OrdinaryObject, PropertyDescriptor, PropertyKey,
-            PropertyKeySet, String, Value,
+            BUILTIN_STRING_MEMORY, Function, InternalMethods, IntoObject, IntoValue, Number,
+            Object, ObjectHeapData, OrdinaryObject, PrivateElement, PrivateElementKind,
+            PrivateName, PropertyDescriptor, PropertyKey, PropertyKeySet, String, Value,
         },
     },
     engine::{
@@ -2348,6 +2350,281 @@ fn copy_data_properties_into_object_slow<'a>(
     Ok(object.get(agent).bind(gc.into_nogc()))
 }
 
+/// ### [7.3.26 PrivateElementFind ( O, P )](https://tc39.es/ecma262/#sec-privateelementfind)
+///
+/// The abstract operation PrivateElementFind takes arguments O (an Object)
+/// and P (a Private Name) and returns a PrivateElement or empty.
+pub(crate) fn private_element_find<'a>(
+    agent: &mut Agent,
+    o: Object,
+    p: PrivateName,
+    gc: NoGcScope<'a, '_>,
+) -> Option<PrivateElement<'a>> {
+    let holder = o.private_elements_holder(agent, false)?;
+    // 1. If O.[[PrivateElements]] contains a PrivateElement pe such that
+    //    pe.[[Key]] is P, then
+    // a. Return pe.
+    // 2. Return empty.
+    agent
+        .heap
+        .private_elements
+        .get(&holder)?
+        .iter()
+        .find(|pe| pe.key == p)
+        .map(|pe| pe.bind(gc))
+}
+
+/// Append a PrivateElement to O.\[\[PrivateElements]], throwing a TypeError
+/// if O already contains a PrivateElement with the same \[\[Key]].
+fn private_element_add<'a>(
+    agent: &mut Agent,
+    o: Object,
+    element: PrivateElement,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let Some(holder) = o.private_elements_holder(agent, true) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot add private members to this object",
+            gc,
+        ));
+    };
+    let elements = agent.heap.private_elements.entry(holder).or_default();
+    if elements.iter().any(|pe| pe.key == element.key) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot initialize private member twice on the same object",
+            gc,
+        ));
+    }
+    elements.push(element.unbind());
+    Ok(())
+}
+
+/// ### [7.3.27 PrivateFieldAdd ( O, P, value )](https://tc39.es/ecma262/#sec-privatefieldadd)
+///
+/// The abstract operation PrivateFieldAdd takes arguments O (an Object), P (a
+/// Private Name), and value (an ECMAScript language value) and returns either
+/// a normal completion containing unused or a throw completion.
+pub(crate) fn private_field_add<'a>(
+    agent: &mut Agent,
+    o: Object,
+    p: PrivateName,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    // 1. If the host is a web browser, then
+    // a. Perform ? HostEnsureCanAddPrivateElement(O).
+    // 2. Let entry be PrivateElementFind(O, P).
+    // 3. If entry is not empty, throw a TypeError exception.
+    // 4. Append PrivateElement { [[Key]]: P, [[Kind]]: field, [[Value]]: value }
+    //    to O.[[PrivateElements]].
+    // 5. Return unused.
+    private_element_add(
+        agent,
+        o,
+        PrivateElement {
+            key: p,
+            kind: PrivateElementKind::Field(value),
+        },
+        gc,
+    )
+}
+
+/// ### [7.3.28 PrivateMethodOrAccessorAdd ( O, method )](https://tc39.es/ecma262/#sec-privatemethodoraccessoradd)
+///
+/// The abstract operation PrivateMethodOrAccessorAdd takes arguments O (an
+/// Object) and method (a PrivateElement) and returns either a normal
+/// completion containing unused or a throw completion.
+pub(crate) fn private_method_or_accessor_add<'a>(
+    agent: &mut Agent,
+    o: Object,
+    method: PrivateElement,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    // 1. Assert: method.[[Kind]] is either method or accessor.
+    debug_assert!(!matches!(method.kind, PrivateElementKind::Field(_)));
+    // 2. If the host is a web browser, then
+    // a. Perform ? HostEnsureCanAddPrivateElement(O).
+    // 3. Let entry be PrivateElementFind(O, method.[[Key]]).
+    // 4. If entry is not empty, throw a TypeError exception.
+    // 5. Append method to O.[[PrivateElements]].
+    // 6. Return unused.
+    private_element_add(agent, o, method, gc)
+}
+
+fn throw_private_element_not_found<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+    agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "Cannot access private member on an object whose class did not declare it",
+        gc,
+    )
+}
+
+/// ### [7.3.30 PrivateGet ( O, P )](https://tc39.es/ecma262/#sec-privateget)
+///
+/// The abstract operation PrivateGet takes arguments O (an Object) and P (a
+/// Private Name) and returns either a normal completion containing an
+/// ECMAScript language value or a throw completion.
+pub(crate) fn private_get<'a>(
+    agent: &mut Agent,
+    o: Object,
+    p: PrivateName,
+    gc: GcScope<'a, '_>,
+) -> JsResult<'a, Value<'a>> {
+    let o = o.bind(gc.nogc());
+    // 1. Let entry be PrivateElementFind(O, P).
+    let entry = private_element_find(agent, o, p, gc.nogc());
+    let getter = match entry.map(|entry| entry.kind) {
+        // 2. If entry is empty, throw a TypeError exception.
+        None => return Err(throw_private_element_not_found(agent, gc.into_nogc())),
+        // 3. If entry.[[Kind]] is either field or method, then
+        // a. Return entry.[[Value]].
+        Some(PrivateElementKind::Field(value)) => return Ok(value.unbind().bind(gc.into_nogc())),
+        Some(PrivateElementKind::Method(method)) => {
+            return Ok(method.unbind().into_value().bind(gc.into_nogc()));
+        }
+        // 4. Assert: entry.[[Kind]] is accessor.
+        // 5. If entry.[[Get]] is undefined, throw a TypeError exception.
+        Some(PrivateElementKind::Accessor { get: None, .. }) => {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Private accessor was defined without a getter",
+                gc.into_nogc(),
+            ));
+        }
+        // 6. Let getter be entry.[[Get]].
+        Some(PrivateElementKind::Accessor {
+            get: Some(getter), ..
+        }) => getter,
+    };
+    // 7. Return ? Call(getter, O).
+    call_function(agent, getter.unbind(), o.into_value().unbind(), None, gc)
+}
+
+/// ### Try [7.3.30 PrivateGet ( O, P )](https://tc39.es/ecma262/#sec-privateget)
+///
+/// The abstract operation PrivateGet takes arguments O (an Object) and P (a
+/// Private Name) and returns either a normal completion containing an
+/// ECMAScript language value or a throw completion.
+///
+/// Breaks if the getter of a private accessor would need to be called.
+pub(crate) fn try_private_get<'a>(
+    agent: &mut Agent,
+    o: Object,
+    p: PrivateName,
+    gc: NoGcScope<'a, '_>,
+) -> TryResult<JsResult<'a, Value<'a>>> {
+    // 1. Let entry be PrivateElementFind(O, P).
+    let entry = private_element_find(agent, o, p, gc);
+    match entry.map(|entry| entry.kind) {
+        // 2. If entry is empty, throw a TypeError exception.
+        None => TryResult::Continue(Err(throw_private_element_not_found(agent, gc))),
+        // 3. If entry.[[Kind]] is either field or method, then
+        // a. Return entry.[[Value]].
+        Some(PrivateElementKind::Field(value)) => TryResult::Continue(Ok(value)),
+        Some(PrivateElementKind::Method(method)) => TryResult::Continue(Ok(method.into_value())),
+        // 4. Assert: entry.[[Kind]] is accessor.
+        // 5. If entry.[[Get]] is undefined, throw a TypeError exception.
+        Some(PrivateElementKind::Accessor { get: None, .. }) => TryResult::Continue(Err(agent
+            .throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Private accessor was defined without a getter",
+                gc,
+            ))),
+        // 6. Let getter be entry.[[Get]].
+        // 7. Return ? Call(getter, O).
+        Some(PrivateElementKind::Accessor { get: Some(_), .. }) => TryResult::Break(()),
+    }
+}
+
+/// ### [7.3.31 PrivateSet ( O, P, value )](https://tc39.es/ecma262/#sec-privateset)
+///
+/// The abstract operation PrivateSet takes arguments O (an Object), P (a
+/// Private Name), and value (an ECMAScript language value) and returns either
+/// a normal completion containing unused or a throw completion.
+pub(crate) fn private_set<'a>(
+    agent: &mut Agent,
+    o: Object,
+    p: PrivateName,
+    value: Value,
+    gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let o = o.bind(gc.nogc());
+    let value = value.bind(gc.nogc());
+    let setter = match try_private_set(agent, o, p, value, gc.nogc()) {
+        ControlFlow::Continue(result) => return result.unbind().bind(gc.into_nogc()),
+        ControlFlow::Break(setter) => setter,
+    };
+    // 6. Let setter be entry.[[Set]].
+    // 7. Perform ? Call(setter, O, « value »).
+    call_function(
+        agent,
+        setter.unbind(),
+        o.into_value().unbind(),
+        Some(ArgumentsList::from_mut_slice(&mut [value.unbind()])),
+        gc,
+    )?;
+    // 8. Return unused.
+    Ok(())
+}
+
+/// ### Try [7.3.31 PrivateSet ( O, P, value )](https://tc39.es/ecma262/#sec-privateset)
+///
+/// The abstract operation PrivateSet takes arguments O (an Object), P (a
+/// Private Name), and value (an ECMAScript language value) and returns either
+/// a normal completion containing unused or a throw completion.
+///
+/// Breaks with the setter if the setter of a private accessor would need to
+/// be called.
+pub(crate) fn try_private_set<'a>(
+    agent: &mut Agent,
+    o: Object,
+    p: PrivateName,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> ControlFlow<Function<'a>, JsResult<'a, ()>> {
+    // 1. Let entry be PrivateElementFind(O, P).
+    let entry = o
+        .private_elements_holder(agent, false)
+        .and_then(|holder| agent.heap.private_elements.get_mut(&holder))
+        .and_then(|elements| elements.iter_mut().find(|pe| pe.key == p));
+    let Some(entry) = entry else {
+        // 2. If entry is empty, throw a TypeError exception.
+        return ControlFlow::Continue(Err(throw_private_element_not_found(agent, gc)));
+    };
+    match &mut entry.kind {
+        // 3. If entry.[[Kind]] is field, then
+        PrivateElementKind::Field(entry_value) => {
+            // a. Set entry.[[Value]] to value.
+            *entry_value = value.unbind();
+            ControlFlow::Continue(Ok(()))
+        }
+        // 4. Else if entry.[[Kind]] is method, then
+        PrivateElementKind::Method(_) => {
+            // a. Throw a TypeError exception.
+            ControlFlow::Continue(Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Private method is not writable",
+                gc,
+            )))
+        }
+        // 5. Else,
+        // a. Assert: entry.[[Kind]] is accessor.
+        // b. If entry.[[Set]] is undefined, throw a TypeError exception.
+        PrivateElementKind::Accessor { set: None, .. } => ControlFlow::Continue(Err(agent
+            .throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Private accessor was defined without a setter",
+                gc,
+            ))),
+        // c. Let setter be entry.[[Set]].
+        PrivateElementKind::Accessor {
+            set: Some(setter), ..
+        } => ControlFlow::Break(setter.bind(gc)),
+    }
+}
+
 /// [7.3.33 InitializeInstanceElements ( O, constructor )](https://tc39.es/ecma262/#sec-initializeinstanceelements)
 ///
 /// The abstract operation InitializeInstanceElements takes arguments O (an
@@ -2356,7 +2633,7 @@ fn copy_data_properties_into_object_slow<'a>(
 pub(crate) fn initialize_instance_elements<'a>(
     agent: &mut Agent,
     o: Object,
-    constructor: BuiltinConstructorFunction,
+    constructor: Function,
     gc: GcScope<'a, '_>,
 ) -> JsResult<'a, ()> {
     let o = o.bind(gc.nogc());
@@ -2364,18 +2641,43 @@ pub(crate) fn initialize_instance_elements<'a>(
     // 1. Let methods be the value of constructor.[[PrivateMethods]].
     // 2. For each PrivateElement method of methods, do
     // a. Perform ? PrivateMethodOrAccessorAdd(O, method).
-    // TODO: Private properties and methods.
+    // NOTE: The private methods are stored in the class's PrivateEnvironment
+    // Record and are added to O by the initializer bytecode.
     // 3. Let fields be the value of constructor.[[Fields]].
     // 4. For each element fieldRecord of fields, do
     // a. Perform ? DefineField(O, fieldRecord).
     // 5. Return unused.
-    let constructor_data = &agent[constructor];
-    if let Some(bytecode) = constructor_data.compiled_initializer_bytecode {
+    let (compiled_initializer_bytecode, outer_env, outer_priv_env, source_code, realm) =
+        match constructor {
+            Function::BuiltinConstructorFunction(constructor) => {
+                let data = &agent[constructor];
+                (
+                    data.compiled_initializer_bytecode,
+                    data.environment,
+                    data.private_environment,
+                    data.source_code,
+                    data.realm,
+                )
+            }
+            Function::ECMAScriptFunction(constructor) => {
+                let data = &agent[constructor];
+                (
+                    data.compiled_initializer_bytecode,
+                    data.ecmascript_function.environment,
+                    data.ecmascript_function.private_environment,
+                    data.ecmascript_function.source_code,
+                    data.ecmascript_function.realm,
+                )
+            }
+            _ => unreachable!(),
+        };
+    if let Some(bytecode) = compiled_initializer_bytecode {
         // Note: The code here looks quite a bit different from what the spec
         // says. For one, the spec is bugged and doesn't consider default
         // constructors at all. Second, we compile field initializers into
         // the ECMAScript class constructors directly, so our code only needs
-        // to work for builtin constructors.
+        // to work for builtin constructors and derived class constructors,
+        // whose initializers must wait for the super() call to return.
         // Third, the spec defines the initializers as individual functions
         // run one after the other. Instead we compile all of the initializers
         // into a single bytecode executable associated with the constructor.
@@ -2384,10 +2686,7 @@ pub(crate) fn initialize_instance_elements<'a>(
         // To do this, we need a new execution context that points to a new
         // Function environment. The function environment should be lexically a
         // child of the class constructor's creating environment.
-        let f = constructor.into_function();
-        let outer_env = constructor_data.environment;
-        let outer_priv_env = constructor_data.private_environment;
-        let source_code = constructor_data.source_code;
+        let f = constructor;
         let decl_env = new_class_field_initializer_environment(agent, f, o, outer_env, gc.nogc());
         agent.push_execution_context(ExecutionContext {
             ecmascript_code: Some(ECMAScriptCodeEvaluationState {
@@ -2398,7 +2697,7 @@ pub(crate) fn initialize_instance_elements<'a>(
                 source_code,
             }),
             function: Some(f.unbind()),
-            realm: agent[constructor].realm,
+            realm: realm.unbind(),
             script_or_module: None,
         });
         let bytecode = bytecode.scope(agent, gc.nogc());
@@ -8,7 +8,7 @@ use crate::ecmascript::abstract_operations::type_conversion::to_numeric_primitiv
 use crate::ecmascript::builtins::proxy::abstract_operations::{
     NonRevokedProxy, validate_non_revoked_proxy,
 };
-use crate::ecmascript::types::{InternalSlots, Numeric, Primitive, PropertyKey};
+use crate::ecmascript::types::{Numeric, Primitive, PropertyKey};
 use crate::engine::TryResult;
 use crate::engine::context::{Bindable, GcScope, NoGcScope};
 use crate::engine::rootable::Scopable;
@@ -155,30 +155,29 @@ pub(crate) fn is_reg_exp<'a>(
     gc: GcScope<'a, '_>,
 ) -> JsResult<'a, bool> {
     // 1. If argument is not an Object, return false.
-    if !argument.is_object() {
+    let Ok(argument) = Object::try_from(argument) else {
         return Ok(false);
-    }
+    };
+    // Note: The object type cannot change during the Get call, so we can
+    // check for the internal slot already here.
+    let has_reg_exp_matcher = matches!(argument, Object::RegExp(_));
 
     // 2. Let matcher be ? Get(argument, %Symbol.match%).
     let matcher = get(
         agent,
-        Object::internal_prototype(Object::try_from(argument).unwrap(), agent).unwrap(),
+        argument,
         PropertyKey::Symbol(WellKnownSymbolIndexes::Match.into()),
         gc,
     )?;
 
     // 3. If matcher is not undefined, return ToBoolean(matcher).
-    if matcher.is_undefined() {
+    if !matcher.is_undefined() {
         return Ok(to_boolean(agent, matcher));
     }
 
     // 4. If argument has a [[RegExpMatcher]] internal slot, return true.
-    if let Value::RegExp(_) = argument {
-        return Ok(true);
-    }
-
     // 5. Return false.
-    Ok(false)
+    Ok(has_reg_exp_matcher)
 }
 
 /// ### [7.2.5 IsExtensible ( O )](https://tc39.es/ecma262/#sec-isextensible-o)
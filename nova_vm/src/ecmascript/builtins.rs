@@ -20,6 +20,8 @@ pub(crate) mod control_abstraction_objects;
 pub(crate) mod data_view;
 #[cfg(feature = "date")]
 pub mod date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) mod disposable_stack;
 mod ecmascript_function;
 pub(crate) mod embedder_object;
 pub mod error;
@@ -72,3 +74,5 @@ pub use builtin_function::{
 pub(crate) use builtin_function::{BuiltinIntrinsic, BuiltinIntrinsicConstructor};
 pub use control_abstraction_objects::*;
 pub(crate) use ecmascript_function::*;
+#[cfg(feature = "shared-array-buffer")]
+pub use shared_array_buffer::SharedArrayBuffer;
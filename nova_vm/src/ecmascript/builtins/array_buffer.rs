@@ -6,6 +6,11 @@
 
 mod abstract_operations;
 mod data;
+#[cfg(feature = "shared-array-buffer")]
+use crate::ecmascript::{
+    builtins::shared_array_buffer::SharedArrayBuffer,
+    types::{SharedDataBlockCopy, SharedDataBlockCopyMut},
+};
 use crate::{
     ecmascript::{
         execution::{Agent, JsResult, ProtoIntrinsics},
@@ -26,10 +31,12 @@ use crate::{
 
 use abstract_operations::detach_array_buffer;
 pub(crate) use abstract_operations::{
-    DetachKey, Ordering, allocate_array_buffer, array_buffer_byte_length, clone_array_buffer,
-    get_value_from_buffer, is_detached_buffer, is_fixed_length_array_buffer, set_value_in_buffer,
+    DetachKey, Ordering, PreserveResizability, allocate_array_buffer, array_buffer_byte_length,
+    array_buffer_copy_and_detach, clone_array_buffer, get_array_buffer_max_byte_length_option,
+    get_modify_set_value_in_buffer, get_value_from_buffer, is_detached_buffer,
+    is_fixed_length_array_buffer, set_value_in_buffer,
 };
-use core::ops::{Index, IndexMut};
+use core::ops::{Deref, DerefMut, Index, IndexMut};
 pub use data::*;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
@@ -124,12 +131,14 @@ impl ArrayBuffer<'_> {
         &mut *agent[self].get_data_block_mut()
     }
 
-    /// Copy data from `source` ArrayBuffer to this ArrayBuffer.
+    /// Copy `count` bytes of data from `source` ArrayBuffer starting at
+    /// `first` to this ArrayBuffer starting at `target_index`.
     ///
     /// `self` and `source` must be different ArrayBuffers.
     pub(crate) fn copy_array_buffer_data(
         self,
         agent: &mut Agent,
+        target_index: usize,
         source: ArrayBuffer,
         first: usize,
         count: usize,
@@ -151,7 +160,7 @@ impl ArrayBuffer<'_> {
         };
         let source_data = source_data.buffer.get_data_block();
         let target_data = target_data.buffer.get_data_block_mut();
-        target_data.copy_data_block_bytes(0, source_data, first, count);
+        target_data.copy_data_block_bytes(target_index, source_data, first, count);
     }
 
     pub(crate) const fn _def() -> Self {
@@ -319,3 +328,336 @@ impl<'a> CreateHeapData<ArrayBufferHeapData<'a>, ArrayBuffer<'a>> for Heap {
         ArrayBuffer::from(ArrayBufferIndex::last(&self.array_buffers))
     }
 }
+
+/// An ArrayBuffer or a SharedArrayBuffer.
+///
+/// This is the type of the \[\[ViewedArrayBuffer]] internal slot of
+/// TypedArrays.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub enum AnyArrayBuffer<'a> {
+    ArrayBuffer(ArrayBuffer<'a>),
+    #[cfg(feature = "shared-array-buffer")]
+    SharedArrayBuffer(SharedArrayBuffer<'a>),
+}
+
+impl AnyArrayBuffer<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, AnyArrayBuffer<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    /// Returns true if this is a SharedArrayBuffer.
+    #[inline]
+    pub fn is_shared(self) -> bool {
+        match self {
+            Self::ArrayBuffer(_) => false,
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(_) => true,
+        }
+    }
+
+    /// Returns true if this is a detached ArrayBuffer. A SharedArrayBuffer
+    /// cannot be detached.
+    #[inline]
+    pub fn is_detached(self, agent: &Agent) -> bool {
+        match self {
+            Self::ArrayBuffer(ab) => ab.is_detached(agent),
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(_) => false,
+        }
+    }
+
+    /// Returns true if this is a resizable ArrayBuffer or a growable
+    /// SharedArrayBuffer.
+    #[inline]
+    pub fn is_resizable(self, agent: &Agent) -> bool {
+        match self {
+            Self::ArrayBuffer(ab) => ab.is_resizable(agent),
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(sab) => sab.is_growable(agent),
+        }
+    }
+
+    #[inline]
+    pub fn byte_length(self, agent: &Agent) -> usize {
+        match self {
+            Self::ArrayBuffer(ab) => ab.byte_length(agent),
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(sab) => sab.byte_length(agent),
+        }
+    }
+
+    #[inline]
+    pub fn max_byte_length(self, agent: &Agent) -> usize {
+        match self {
+            Self::ArrayBuffer(ab) => ab.max_byte_length(agent),
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(sab) => sab.max_byte_length(agent),
+        }
+    }
+
+    /// Get temporary access to the buffer's data as a slice of bytes. The
+    /// access can only be held while all JavaScript is paused.
+    ///
+    /// The data of a SharedArrayBuffer can be written by other agents at any
+    /// time, so the returned slice is a copy of its current contents.
+    pub fn as_slice(self, agent: &Agent) -> AnyArrayBufferSlice<'_> {
+        match self {
+            Self::ArrayBuffer(ab) => AnyArrayBufferSlice::ArrayBuffer(ab.as_slice(agent)),
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(sab) => {
+                AnyArrayBufferSlice::SharedArrayBuffer(agent[sab].data_block.copy_bytes())
+            }
+        }
+    }
+
+    /// Get temporary exclusive access to the buffer's data as a slice of
+    /// bytes. The access can only be held while all JavaScript is paused.
+    ///
+    /// The data of a SharedArrayBuffer can be written by other agents at any
+    /// time, so the returned slice is a copy of its current contents. The
+    /// bytes changed through the copy are written back into the
+    /// SharedArrayBuffer when it is dropped.
+    pub fn as_mut_slice(self, agent: &mut Agent) -> AnyArrayBufferSliceMut<'_> {
+        match self {
+            Self::ArrayBuffer(ab) => AnyArrayBufferSliceMut::ArrayBuffer(ab.as_mut_slice(agent)),
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(sab) => {
+                AnyArrayBufferSliceMut::SharedArrayBuffer(agent[sab].data_block.copy_bytes_mut())
+            }
+        }
+    }
+
+    /// Copy `count` bytes of data from `source` buffer starting at `first`
+    /// to this buffer starting at `target_index`.
+    ///
+    /// `self` and `source` must be different buffers.
+    pub(crate) fn copy_array_buffer_data(
+        self,
+        agent: &mut Agent,
+        target_index: usize,
+        source: AnyArrayBuffer,
+        first: usize,
+        count: usize,
+    ) {
+        debug_assert_ne!(self, source.unbind());
+        match (self, source) {
+            (Self::ArrayBuffer(target), AnyArrayBuffer::ArrayBuffer(source)) => {
+                target.copy_array_buffer_data(agent, target_index, source, first, count)
+            }
+            #[cfg(feature = "shared-array-buffer")]
+            (Self::ArrayBuffer(target), AnyArrayBuffer::SharedArrayBuffer(source)) => {
+                let Heap {
+                    array_buffers,
+                    shared_array_buffers,
+                    ..
+                } = &mut agent.heap;
+                let target_data = array_buffers[target].get_data_block_mut();
+                let source_data = &shared_array_buffers[source].data_block;
+                source_data.load_bytes(first, &mut target_data[target_index..target_index + count]);
+            }
+            #[cfg(feature = "shared-array-buffer")]
+            (Self::SharedArrayBuffer(target), AnyArrayBuffer::ArrayBuffer(source)) => {
+                let target_data = &agent[target].data_block;
+                let source_data = agent[source].get_data_block();
+                target_data.store_bytes(target_index, &source_data[first..first + count]);
+            }
+            #[cfg(feature = "shared-array-buffer")]
+            (Self::SharedArrayBuffer(target), AnyArrayBuffer::SharedArrayBuffer(source)) => {
+                // Note: Two SharedArrayBuffers may share a Shared Data Block,
+                // so the bytes are copied through a temporary buffer.
+                let mut bytes = vec![0; count];
+                agent[source].data_block.load_bytes(first, &mut bytes);
+                agent[target].data_block.store_bytes(target_index, &bytes);
+            }
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for AnyArrayBuffer<'_> {
+    type Of<'a> = AnyArrayBuffer<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> From<ArrayBuffer<'a>> for AnyArrayBuffer<'a> {
+    fn from(value: ArrayBuffer<'a>) -> Self {
+        Self::ArrayBuffer(value)
+    }
+}
+
+#[cfg(feature = "shared-array-buffer")]
+impl<'a> From<SharedArrayBuffer<'a>> for AnyArrayBuffer<'a> {
+    fn from(value: SharedArrayBuffer<'a>) -> Self {
+        Self::SharedArrayBuffer(value)
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for AnyArrayBuffer<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Value::ArrayBuffer(ab) => Ok(Self::ArrayBuffer(ab)),
+            #[cfg(feature = "shared-array-buffer")]
+            Value::SharedArrayBuffer(sab) => Ok(Self::SharedArrayBuffer(sab)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for AnyArrayBuffer<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::ArrayBuffer(ab) => Ok(Self::ArrayBuffer(ab)),
+            #[cfg(feature = "shared-array-buffer")]
+            Object::SharedArrayBuffer(sab) => Ok(Self::SharedArrayBuffer(sab)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> From<AnyArrayBuffer<'a>> for Object<'a> {
+    fn from(value: AnyArrayBuffer<'a>) -> Self {
+        match value {
+            AnyArrayBuffer::ArrayBuffer(ab) => ab.into(),
+            #[cfg(feature = "shared-array-buffer")]
+            AnyArrayBuffer::SharedArrayBuffer(sab) => sab.into(),
+        }
+    }
+}
+
+impl<'a> From<AnyArrayBuffer<'a>> for Value<'a> {
+    fn from(value: AnyArrayBuffer<'a>) -> Self {
+        match value {
+            AnyArrayBuffer::ArrayBuffer(ab) => ab.into(),
+            #[cfg(feature = "shared-array-buffer")]
+            AnyArrayBuffer::SharedArrayBuffer(sab) => sab.into(),
+        }
+    }
+}
+
+impl<'a> IntoObject<'a> for AnyArrayBuffer<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> IntoValue<'a> for AnyArrayBuffer<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl Rootable for AnyArrayBuffer<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        match value {
+            Self::ArrayBuffer(ab) => Err(HeapRootData::ArrayBuffer(ab.unbind())),
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(sab) => Err(HeapRootData::SharedArrayBuffer(sab.unbind())),
+        }
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::ArrayBuffer(ab) => Some(Self::ArrayBuffer(ab)),
+            #[cfg(feature = "shared-array-buffer")]
+            HeapRootData::SharedArrayBuffer(sab) => Some(Self::SharedArrayBuffer(sab)),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for AnyArrayBuffer<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::ArrayBuffer(ab) => ab.mark_values(queues),
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(sab) => sab.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::ArrayBuffer(ab) => ab.sweep_values(compactions),
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(sab) => sab.sweep_values(compactions),
+        }
+    }
+}
+
+/// Temporary access to the data of an ArrayBuffer or a copy of the data of a
+/// SharedArrayBuffer.
+#[derive(Debug)]
+pub enum AnyArrayBufferSlice<'a> {
+    ArrayBuffer(&'a [u8]),
+    #[cfg(feature = "shared-array-buffer")]
+    SharedArrayBuffer(SharedDataBlockCopy),
+}
+
+impl Deref for AnyArrayBufferSlice<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::ArrayBuffer(slice) => slice,
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(copy) => copy,
+        }
+    }
+}
+
+/// Temporary exclusive access to the data of an ArrayBuffer or a copy of the
+/// data of a SharedArrayBuffer that is written back when dropped.
+#[derive(Debug)]
+pub enum AnyArrayBufferSliceMut<'a> {
+    ArrayBuffer(&'a mut [u8]),
+    #[cfg(feature = "shared-array-buffer")]
+    SharedArrayBuffer(SharedDataBlockCopyMut),
+}
+
+impl Deref for AnyArrayBufferSliceMut<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::ArrayBuffer(slice) => slice,
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(copy) => copy,
+        }
+    }
+}
+
+impl DerefMut for AnyArrayBufferSliceMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::ArrayBuffer(slice) => slice,
+            #[cfg(feature = "shared-array-buffer")]
+            Self::SharedArrayBuffer(copy) => copy,
+        }
+    }
+}
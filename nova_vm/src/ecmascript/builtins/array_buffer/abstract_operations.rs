@@ -2,17 +2,23 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::{ArrayBuffer, ArrayBufferHeapData};
-use crate::ecmascript::abstract_operations::type_conversion::to_index;
-use crate::ecmascript::types::{Numeric, Viewable};
+use super::{
+    AnyArrayBuffer, ArrayBuffer, ArrayBufferHeapData, ViewedArrayBufferByteLength,
+    ViewedArrayBufferByteOffset,
+};
+use crate::ecmascript::abstract_operations::type_conversion::{to_index, try_to_index};
+use crate::ecmascript::builtins::{data_view::DataView, typed_array::data::TypedArrayArrayLength};
+use crate::ecmascript::types::{Numeric, ReadModifyWriteOp, Viewable};
+use crate::engine::TryResult;
 use crate::engine::context::{Bindable, GcScope, NoGcScope};
 use crate::heap::CreateHeapData;
+use crate::heap::indexes::{DataViewIndex, TypedArrayIndex};
 use crate::{
     Heap,
     ecmascript::{
         abstract_operations::operations_on_objects::get,
         execution::{Agent, JsResult, agent::ExceptionType},
-        types::{BUILTIN_STRING_MEMORY, DataBlock, Function, IntoFunction, Number, Object, Value},
+        types::{BUILTIN_STRING_MEMORY, DataBlock, Function, IntoFunction, Object, Value},
     },
 };
 
@@ -77,22 +83,134 @@ pub(crate) fn allocate_array_buffer<'a>(
     Ok(agent.heap.create(obj).bind(gc))
 }
 
+/// Whether ArrayBufferCopyAndDetach preserves the resizability of the
+/// ArrayBuffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PreserveResizability {
+    /// ### PRESERVE-RESIZABILITY
+    PreserveResizability,
+    /// ### FIXED-LENGTH
+    FixedLength,
+}
+
+/// ### [25.1.3.2 ArrayBufferCopyAndDetach ( arrayBuffer, newLength, preserveResizability )](https://tc39.es/ecma262/#sec-arraybuffercopyanddetach)
+///
+/// The abstract operation ArrayBufferCopyAndDetach takes arguments
+/// arrayBuffer (an ECMAScript language value), newLength (an ECMAScript
+/// language value), and preserveResizability (PRESERVE-RESIZABILITY or
+/// FIXED-LENGTH) and returns either a normal completion containing an
+/// ArrayBuffer or a throw completion.
+///
+/// The DataBlock of arrayBuffer is moved into the new ArrayBuffer and
+/// reallocated to the new length, so the contents are never copied by us.
+pub(crate) fn array_buffer_copy_and_detach<'a>(
+    agent: &mut Agent,
+    array_buffer: ArrayBuffer,
+    new_length: Value,
+    preserve_resizability: PreserveResizability,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ArrayBuffer<'a>> {
+    let mut array_buffer = array_buffer.bind(gc.nogc());
+    let new_length = new_length.bind(gc.nogc());
+    // 1. Perform ? RequireInternalSlot(arrayBuffer, [[ArrayBufferData]]).
+    // 2. If IsSharedArrayBuffer(arrayBuffer) is true, throw a TypeError exception.
+    // 3. If newLength is undefined, then
+    let new_byte_length = if new_length.is_undefined() {
+        // a. Let newByteLength be arrayBuffer.[[ArrayBufferByteLength]].
+        array_buffer.byte_length(agent)
+    } else if let TryResult::Continue(res) = try_to_index(agent, new_length, gc.nogc()) {
+        // 4. Else,
+        // a. Let newByteLength be ? ToIndex(newLength).
+        res.unbind()? as usize
+    } else {
+        let scoped_array_buffer = array_buffer.scope(agent, gc.nogc());
+        let res = to_index(agent, new_length.unbind(), gc.reborrow()).unbind()? as usize;
+        array_buffer = scoped_array_buffer.get(agent).bind(gc.nogc());
+        res
+    };
+    let array_buffer = array_buffer.unbind();
+    let gc = gc.into_nogc();
+    let array_buffer = array_buffer.bind(gc);
+    // 5. If IsDetachedBuffer(arrayBuffer) is true, throw a TypeError exception.
+    if is_detached_buffer(agent, array_buffer) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot transfer a detached ArrayBuffer",
+            gc,
+        ));
+    }
+    // 6. If preserveResizability is PRESERVE-RESIZABILITY and
+    //    IsFixedLengthArrayBuffer(arrayBuffer) is false, then
+    let new_max_byte_length = if preserve_resizability == PreserveResizability::PreserveResizability
+        && !is_fixed_length_array_buffer(agent, array_buffer)
+    {
+        // a. Let newMaxByteLength be arrayBuffer.[[ArrayBufferMaxByteLength]].
+        Some(array_buffer.max_byte_length(agent))
+    } else {
+        // 7. Else,
+        // a. Let newMaxByteLength be EMPTY.
+        None
+    };
+    // 8. If arrayBuffer.[[ArrayBufferDetachKey]] is not undefined, throw a
+    //    TypeError exception.
+    if array_buffer.get_detach_key(agent).is_some() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot transfer an ArrayBuffer with a detach key",
+            gc,
+        ));
+    }
+    // 9. Let newBuffer be ? AllocateArrayBuffer(%ArrayBuffer%, newByteLength, newMaxByteLength).
+    // NOTE: The steps of AllocateArrayBuffer that can throw are performed
+    // before arrayBuffer is detached; the DataBlock is then moved instead of
+    // allocating a new one.
+    if new_max_byte_length.is_some_and(|max_byte_length| new_byte_length > max_byte_length) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Byte length is over maximum byte length",
+            gc,
+        ));
+    }
+    // 10. Let copyLength be min(newByteLength, arrayBuffer.[[ArrayBufferByteLength]]).
+    // 11. Let fromBlock be arrayBuffer.[[ArrayBufferData]].
+    // 15. Perform ! DetachArrayBuffer(arrayBuffer).
+    let mut block = agent[array_buffer].buffer.take();
+    detach_array_buffer_views(agent, array_buffer);
+    // 12. Let toBlock be newBuffer.[[ArrayBufferData]].
+    // 13. Perform CopyDataBlockBytes(toBlock, 0, fromBlock, 0, copyLength).
+    // 14. NOTE: Neither creation of the new Data Block nor copying from the
+    //     old Data Block are observable. Implementations may implement this
+    //     method as a zero-copy move or a realloc.
+    block.realloc(new_byte_length);
+    let new_buffer = if let Some(new_max_byte_length) = new_max_byte_length {
+        ArrayBufferHeapData::new_resizable(block, new_max_byte_length)
+    } else {
+        ArrayBufferHeapData::new_fixed_length(block)
+    };
+    // 16. Return newBuffer.
+    Ok(agent.heap.create(new_buffer).bind(gc))
+}
+
 /// ### [25.1.3.2 ArrayBufferByteLength ( arrayBuffer, order )](https://tc39.es/ecma262/#sec-arraybufferbytelength)
 ///
 /// The abstract operation ArrayBufferByteLength takes arguments arrayBuffer
 /// (an ArrayBuffer or SharedArrayBuffer) and order (SEQ-CST or UNORDERED)
 /// and returns a non-negative integer.
-pub(crate) fn array_buffer_byte_length(
+pub(crate) fn array_buffer_byte_length<'a>(
     agent: &Agent,
-    array_buffer: ArrayBuffer,
+    array_buffer: impl Into<AnyArrayBuffer<'a>>,
     _order: Ordering,
 ) -> usize {
-    let array_buffer = &agent[array_buffer];
-    // 1. If IsSharedArrayBuffer(arrayBuffer) is true and arrayBuffer has an [[ArrayBufferByteLengthData]] internal slot, then
-    // a. Let bufferByteLengthBlock be arrayBuffer.[[ArrayBufferByteLengthData]].
-    // b. Let rawLength be GetRawBytesFromSharedBlock(bufferByteLengthBlock, 0, BIGUINT64, true, order).
-    // c. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
-    // d. Return ℝ(RawBytesToNumeric(BIGUINT64, rawLength, isLittleEndian)).
+    let array_buffer = match array_buffer.into() {
+        AnyArrayBuffer::ArrayBuffer(array_buffer) => &agent[array_buffer],
+        // 1. If IsSharedArrayBuffer(arrayBuffer) is true and arrayBuffer has an [[ArrayBufferByteLengthData]] internal slot, then
+        // a. Let bufferByteLengthBlock be arrayBuffer.[[ArrayBufferByteLengthData]].
+        // b. Let rawLength be GetRawBytesFromSharedBlock(bufferByteLengthBlock, 0, BIGUINT64, true, order).
+        // c. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // d. Return ℝ(RawBytesToNumeric(BIGUINT64, rawLength, isLittleEndian)).
+        #[cfg(feature = "shared-array-buffer")]
+        AnyArrayBuffer::SharedArrayBuffer(sab) => return sab.byte_length(agent),
+    };
     // 2. Assert: IsDetachedBuffer(arrayBuffer) is false.
     debug_assert!(!array_buffer.is_detached());
     // 3. Return arrayBuffer.[[ArrayBufferByteLength]].
@@ -104,10 +222,13 @@ pub(crate) fn array_buffer_byte_length(
 /// The abstract operation IsDetachedBuffer takes argument *arrayBuffer* (an
 /// ArrayBuffer or a SharedArrayBuffer) and returns a Boolean.
 #[inline]
-pub(crate) fn is_detached_buffer(agent: &Agent, array_buffer: ArrayBuffer) -> bool {
+pub(crate) fn is_detached_buffer<'a>(
+    agent: &Agent,
+    array_buffer: impl Into<AnyArrayBuffer<'a>>,
+) -> bool {
     // 1. If arrayBuffer.[[ArrayBufferData]] is null, return true.
     // 2. Return false.
-    agent[array_buffer].is_detached()
+    array_buffer.into().is_detached(agent)
 }
 
 /// ### [25.1.3.4 DetachArrayBuffer ( arrayBuffer \[ , key \] )](https://tc39.es/ecma262/#sec-detacharraybuffer)
@@ -137,10 +258,61 @@ pub(crate) fn detach_array_buffer<'a>(
     // 4. Set arrayBuffer.[[ArrayBufferData]] to null.
     // 5. Set arrayBuffer.[[ArrayBufferByteLength]] to 0.
     agent[array_buffer].buffer.detach();
+    detach_array_buffer_views(agent, array_buffer);
     // 6. Return UNUSED.
     Ok(())
 }
 
+/// Clears the cached lengths and offsets of all TypedArrays and DataViews
+/// viewing a now detached ArrayBuffer.
+///
+/// A view of a detached buffer is always out of bounds, so its
+/// \[\[ByteLength]], \[\[ByteOffset]], and \[\[ArrayLength]] are no
+/// longer observable. Resetting them to zero drops any entries in the heap's
+/// overflow maps that would otherwise keep describing the old DataBlock.
+fn detach_array_buffer_views(agent: &mut Agent, array_buffer: ArrayBuffer) {
+    let array_buffer = array_buffer.unbind();
+    let heap = &mut agent.heap;
+    for (index, data) in heap.typed_arrays.iter_mut().enumerate() {
+        let Some(data) = data else {
+            continue;
+        };
+        if data.viewed_array_buffer != AnyArrayBuffer::ArrayBuffer(array_buffer) {
+            continue;
+        }
+        let key = TypedArrayIndex::from_index(index);
+        if data.byte_length.is_overflowing() {
+            heap.typed_array_byte_lengths.remove(&key);
+        }
+        if data.byte_offset.is_overflowing() {
+            heap.typed_array_byte_offsets.remove(&key);
+        }
+        if data.array_length.is_overflowing() {
+            heap.typed_array_array_lengths.remove(&key);
+        }
+        data.byte_length = ViewedArrayBufferByteLength::value(0);
+        data.byte_offset = ViewedArrayBufferByteOffset::value(0);
+        data.array_length = TypedArrayArrayLength::value(0);
+    }
+    for (index, data) in heap.data_views.iter_mut().enumerate() {
+        let Some(data) = data else {
+            continue;
+        };
+        if data.viewed_array_buffer != array_buffer {
+            continue;
+        }
+        let key = DataView(DataViewIndex::from_index(index));
+        if data.byte_length.is_overflowing() {
+            heap.data_view_byte_lengths.remove(&key);
+        }
+        if data.byte_offset.is_overflowing() {
+            heap.data_view_byte_offsets.remove(&key);
+        }
+        data.byte_length = ViewedArrayBufferByteLength::value(0);
+        data.byte_offset = ViewedArrayBufferByteOffset::value(0);
+    }
+}
+
 /// ### [25.1.3.5 CloneArrayBuffer ( srcBuffer, srcByteOffset, srcLength )](https://tc39.es/ecma262/#sec-clonearraybuffer)
 ///
 /// The abstract operation CloneArrayBuffer takes arguments srcBuffer (an
@@ -151,7 +323,7 @@ pub(crate) fn detach_array_buffer<'a>(
 /// range starting at srcByteOffset and continuing for srcLength bytes.
 pub(crate) fn clone_array_buffer<'a>(
     agent: &mut Agent,
-    src_buffer: ArrayBuffer<'a>,
+    src_buffer: AnyArrayBuffer<'a>,
     src_byte_offset: usize,
     src_length: usize,
     gc: NoGcScope<'a, '_>,
@@ -169,6 +341,24 @@ pub(crate) fn clone_array_buffer<'a>(
     )
     .unbind()?
     .bind(gc);
+    let src_buffer = match src_buffer {
+        AnyArrayBuffer::ArrayBuffer(src_buffer) => src_buffer,
+        #[cfg(feature = "shared-array-buffer")]
+        AnyArrayBuffer::SharedArrayBuffer(_) => {
+            // 3. Let srcBlock be srcBuffer.[[ArrayBufferData]].
+            // 4. Let targetBlock be targetBuffer.[[ArrayBufferData]].
+            // 5. Perform CopyDataBlockBytes(targetBlock, 0, srcBlock, srcByteOffset, srcLength).
+            AnyArrayBuffer::from(target_buffer).copy_array_buffer_data(
+                agent,
+                0,
+                src_buffer,
+                src_byte_offset,
+                src_length,
+            );
+            // 6. Return targetBuffer.
+            return Ok(target_buffer);
+        }
+    };
     let Heap { array_buffers, .. } = &mut agent.heap;
     let (target_buffer_data, array_buffers) = array_buffers.split_last_mut().unwrap();
     let target_buffer_data = target_buffer_data.as_mut().unwrap();
@@ -253,10 +443,13 @@ pub(crate) fn host_resize_array_buffer(
 /// The abstract operation IsFixedLengthArrayBuffer takes argument
 /// arrayBuffer (an ArrayBuffer or a SharedArrayBuffer) and returns a
 /// Boolean.
-pub(crate) fn is_fixed_length_array_buffer(agent: &Agent, array_buffer: ArrayBuffer) -> bool {
+pub(crate) fn is_fixed_length_array_buffer<'a>(
+    agent: &Agent,
+    array_buffer: impl Into<AnyArrayBuffer<'a>>,
+) -> bool {
     // 1. If arrayBuffer has an [[ArrayBufferMaxByteLength]] internal slot, return false.
     // 2. Return true.
-    !agent[array_buffer].is_resizable()
+    !array_buffer.into().is_resizable(agent)
 }
 
 /// ### [25.1.3.9 IsUnsignedElementType ( type )](https://tc39.es/ecma262/#sec-isunsignedelementtype)
@@ -378,30 +571,48 @@ pub(crate) fn get_raw_bytes_from_shared_block(
 /// integer), type (a TypedArray element type), isTypedArray (a Boolean),
 /// and order (SEQ-CST or UNORDERED) and optional argument isLittleEndian
 /// (a Boolean) and returns a Number or a BigInt.
-pub(crate) fn get_value_from_buffer<'a, T: Viewable>(
+pub(crate) fn get_value_from_buffer<'a, 'b, T: Viewable>(
     agent: &mut Agent,
-    array_buffer: ArrayBuffer,
+    array_buffer: impl Into<AnyArrayBuffer<'b>>,
     byte_index: usize,
     _is_typed_array: bool,
-    _order: Ordering,
+    order: Ordering,
     is_little_endian: Option<bool>,
     gc: NoGcScope<'a, '_>,
 ) -> Numeric<'a> {
+    let array_buffer = array_buffer.into();
     // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
     debug_assert!(!array_buffer.is_detached(agent));
     // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
     // 4. Let elementSize be the Element Size value specified in Table 71 for Element Type type.
+    let array_buffer = match array_buffer {
+        AnyArrayBuffer::ArrayBuffer(array_buffer) => array_buffer,
+        // 5. If IsSharedArrayBuffer(arrayBuffer) is true, then
+        // a. Assert: block is a Shared Data Block.
+        // b. Let rawValue be GetRawBytesFromSharedBlock(block, byteIndex, type,
+        //    isTypedArray, order).
+        // Note: Shared Data Blocks are always read with a sequentially
+        // consistent atomic load instruction.
+        #[cfg(feature = "shared-array-buffer")]
+        AnyArrayBuffer::SharedArrayBuffer(sab) => {
+            let raw_value = agent[sab].data_block.load::<T>(byte_index).unwrap();
+            let is_little_endian = is_little_endian.unwrap_or(cfg!(target_endian = "little"));
+            return raw_bytes_to_numeric::<T>(agent, raw_value, is_little_endian, gc);
+        }
+    };
     // 3. Let block be arrayBuffer.[[ArrayBufferData]].
     let block = agent[array_buffer].get_data_block();
-    // 5. If IsSharedArrayBuffer(arrayBuffer) is true, then
-    // a. Assert: block is a Shared Data Block.
-    // b. Let rawValue be GetRawBytesFromSharedBlock(block, byteIndex, type,
-    //    isTypedArray, order).
     // 6. Else,
     // a. Let rawValue be a List whose elements are bytes from block at indices
     //    in the interval from byteIndex (inclusive) to byteIndex + elementSize
     //    (exclusive).
-    let raw_value = block.get_offset_by_byte::<T>(byte_index).unwrap();
+    let raw_value = if order == Ordering::SeqCst {
+        // Note: Atomics operations read with a sequentially consistent atomic
+        // load instruction.
+        block.load_atomic::<T>(byte_index).unwrap()
+    } else {
+        block.get_offset_by_byte::<T>(byte_index).unwrap()
+    };
     // 7. Assert: The number of elements in rawValue is elementSize.
     // 8. If isLittleEndian is not present, set isLittleEndian to the value of
     //    the [[LittleEndian]] field of the surrounding agent's Agent Record.
@@ -459,15 +670,16 @@ pub(crate) fn numeric_to_raw_bytes<T: Viewable>(
 /// isTypedArray (a Boolean), and order (SEQ-CST, UNORDERED, or INIT) and
 /// optional argument isLittleEndian (a Boolean) and returns UNUSED.
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn set_value_in_buffer<T: Viewable>(
+pub(crate) fn set_value_in_buffer<'a, T: Viewable>(
     agent: &mut Agent,
-    array_buffer: ArrayBuffer,
+    array_buffer: impl Into<AnyArrayBuffer<'a>>,
     byte_index: usize,
     value: Numeric,
     _is_typed_array: bool,
-    _order: Ordering,
+    order: Ordering,
     is_little_endian: Option<bool>,
 ) {
+    let array_buffer = array_buffer.into();
     // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
     debug_assert!(!array_buffer.is_detached(agent));
     // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
@@ -488,18 +700,34 @@ pub(crate) fn set_value_in_buffer<T: Viewable>(
 
     // 7. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
     let raw_bytes = numeric_to_raw_bytes::<T>(agent, value, is_little_endian);
-    // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
-    // a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
-    // b. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
-    // c. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
-    // d. Append WriteSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes } to eventsRecord.[[EventList]].
+    let array_buffer = match array_buffer {
+        AnyArrayBuffer::ArrayBuffer(array_buffer) => array_buffer,
+        // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
+        // a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+        // b. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+        // c. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
+        // d. Append WriteSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes } to eventsRecord.[[EventList]].
+        // Note: Shared Data Blocks are always written with a sequentially
+        // consistent atomic store instruction.
+        #[cfg(feature = "shared-array-buffer")]
+        AnyArrayBuffer::SharedArrayBuffer(sab) => {
+            agent[sab].data_block.store::<T>(byte_index, raw_bytes);
+            return;
+        }
+    };
     // 9. Else,
 
     // 4. Let block be arrayBuffer.[[ArrayBufferData]].
     let block = agent[array_buffer].get_data_block_mut();
 
     // a. Store the individual bytes of rawBytes into block, starting at block[byteIndex].
-    block.set_offset_by_byte::<T>(byte_index, raw_bytes);
+    if order == Ordering::SeqCst {
+        // Note: Atomics operations write with a sequentially consistent
+        // atomic store instruction.
+        block.store_atomic::<T>(byte_index, raw_bytes);
+    } else {
+        block.set_offset_by_byte::<T>(byte_index, raw_bytes);
+    }
     // 10. Return UNUSED.
 }
 
@@ -510,31 +738,53 @@ pub(crate) fn set_value_in_buffer<T: Viewable>(
 /// non-negative integer), type (a TypedArray element type), value (a Number or
 /// a BigInt), and op (a read-modify-write modification function) and returns a
 /// Number or a BigInt.
-pub(crate) fn get_modify_set_value_in_buffer(
-    _array_buffer: ArrayBuffer,
-    _byte_index: u32,
-    _type: (),
-    _value: Number,
-    _op: (),
-) {
+///
+/// In Nova, the read-modify-write is always performed using an atomic
+/// instruction.
+pub(crate) fn get_modify_set_value_in_buffer<'a, 'b, T: Viewable>(
+    agent: &mut Agent,
+    array_buffer: impl Into<AnyArrayBuffer<'b>>,
+    byte_index: usize,
+    value: Numeric,
+    op: ReadModifyWriteOp,
+    gc: NoGcScope<'a, '_>,
+) -> Numeric<'a> {
+    let array_buffer = array_buffer.into();
     // 1. Assert: IsDetachedBuffer(arrayBuffer) is false.
+    debug_assert!(!array_buffer.is_detached(agent));
     // 2. Assert: There are sufficient bytes in arrayBuffer starting at byteIndex to represent a value of type.
     // 3. Assert: value is a BigInt if IsBigIntElementType(type) is true; otherwise, value is a Number.
-    // 4. Let block be arrayBuffer.[[ArrayBufferData]].
+    debug_assert_eq!(T::IS_BIGINT, value.is_bigint());
     // 5. Let elementSize be the Element Size value specified in Table 71 for Element Type type.
     // 6. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
     // 7. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
-    // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
-    // a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
-    // b. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
-    // c. Let rawBytesRead be a List of length elementSize whose elements are nondeterministically chosen byte values.
-    // d. NOTE: In implementations, rawBytesRead is the result of a load-link, of a load-exclusive, or of an operand of a read-modify-write instruction on the underlying hardware. The nondeterminism is a semantic prescription of the memory model to describe observable behaviour of hardware with weak consistency.
-    // e. Let rmwEvent be ReadModifyWriteSharedMemory { [[Order]]: SEQ-CST, [[NoTear]]: true, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes, [[ModifyOp]]: op }.
-    // f. Append rmwEvent to eventsRecord.[[EventList]].
-    // g. Append Chosen Value Record { [[Event]]: rmwEvent, [[ChosenValue]]: rawBytesRead } to execution.[[ChosenValues]].
+    let raw_bytes = T::from_ne_value(agent, value);
+    let array_buffer = match array_buffer {
+        AnyArrayBuffer::ArrayBuffer(array_buffer) => array_buffer,
+        // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
+        // a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+        // b. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+        // c. Let rawBytesRead be a List of length elementSize whose elements are nondeterministically chosen byte values.
+        // d. NOTE: In implementations, rawBytesRead is the result of a load-link, of a load-exclusive, or of an operand of a read-modify-write instruction on the underlying hardware. The nondeterminism is a semantic prescription of the memory model to describe observable behaviour of hardware with weak consistency.
+        // e. Let rmwEvent be ReadModifyWriteSharedMemory { [[Order]]: SEQ-CST, [[NoTear]]: true, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes, [[ModifyOp]]: op }.
+        // f. Append rmwEvent to eventsRecord.[[EventList]].
+        // g. Append Chosen Value Record { [[Event]]: rmwEvent, [[ChosenValue]]: rawBytesRead } to execution.[[ChosenValues]].
+        #[cfg(feature = "shared-array-buffer")]
+        AnyArrayBuffer::SharedArrayBuffer(sab) => {
+            let raw_bytes_read = agent[sab]
+                .data_block
+                .modify::<T>(byte_index, op, raw_bytes)
+                .unwrap();
+            return raw_bytes_read.into_ne_value(agent, gc);
+        }
+    };
+    // 4. Let block be arrayBuffer.[[ArrayBufferData]].
+    let block = agent[array_buffer].get_data_block_mut();
     // 9. Else,
     // a. Let rawBytesRead be a List of length elementSize whose elements are the sequence of elementSize bytes starting with block[byteIndex].
     // b. Let rawBytesModified be op(rawBytesRead, rawBytes).
     // c. Store the individual bytes of rawBytesModified into block, starting at block[byteIndex].
+    let raw_bytes_read = block.modify_atomic::<T>(byte_index, op, raw_bytes).unwrap();
     // 10. Return RawBytesToNumeric(type, rawBytesRead, isLittleEndian).
+    raw_bytes_read.into_ne_value(agent, gc)
 }
@@ -137,6 +137,12 @@ impl InternalBuffer {
         self.data_block = DataBlock::DETACHED_DATA_BLOCK;
     }
 
+    /// Detaches the buffer and returns the DataBlock it contained.
+    pub(crate) fn take(&mut self) -> DataBlock {
+        self.capacity = 0;
+        core::mem::replace(&mut self.data_block, DataBlock::DETACHED_DATA_BLOCK)
+    }
+
     const fn detached() -> Self {
         Self {
             data_block: DataBlock::DETACHED_DATA_BLOCK,
@@ -2,9 +2,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) mod async_disposable_stack_objects;
 pub(crate) mod async_function_objects;
 pub(crate) mod async_generator_function_objects;
 pub(crate) mod async_generator_objects;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) mod disposable_stack_objects;
 pub(crate) mod generator_function_objects;
 pub(crate) mod generator_objects;
 pub(crate) mod generator_prototype;
@@ -0,0 +1,202 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_disposable_objects::dispose_resources_async,
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsic,
+            control_abstraction_objects::disposable_stack_objects::disposable_stack_prototype::{
+                adopt, defer, get_disposed, r#move, require_disposable_stack_internal_slot, r#use,
+            },
+            disposable_stack::data::{DisposableState, DisposeHint},
+            promise::Promise,
+            promise_objects::promise_abstract_operations::promise_capability_records::PromiseCapability,
+        },
+        execution::{Agent, JsResult, Realm},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, PropertyKey, String, Value},
+    },
+    engine::context::{Bindable, GcScope},
+    heap::{IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
+};
+
+pub(crate) struct AsyncDisposableStackPrototype;
+
+struct AsyncDisposableStackPrototypeAdopt;
+impl Builtin for AsyncDisposableStackPrototypeAdopt {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.adopt;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::adopt);
+}
+struct AsyncDisposableStackPrototypeDefer;
+impl Builtin for AsyncDisposableStackPrototypeDefer {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.defer;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::defer);
+}
+struct AsyncDisposableStackPrototypeDisposeAsync;
+impl Builtin for AsyncDisposableStackPrototypeDisposeAsync {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.disposeAsync;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::dispose_async);
+}
+impl BuiltinIntrinsic for AsyncDisposableStackPrototypeDisposeAsync {
+    const INDEX: IntrinsicFunctionIndexes =
+        IntrinsicFunctionIndexes::AsyncDisposableStackPrototypeDisposeAsync;
+}
+struct AsyncDisposableStackPrototypeGetDisposed;
+impl Builtin for AsyncDisposableStackPrototypeGetDisposed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_disposed;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.disposed.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::get_disposed);
+}
+impl BuiltinGetter for AsyncDisposableStackPrototypeGetDisposed {}
+struct AsyncDisposableStackPrototypeMove;
+impl Builtin for AsyncDisposableStackPrototypeMove {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#move;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::r#move);
+}
+struct AsyncDisposableStackPrototypeUse;
+impl Builtin for AsyncDisposableStackPrototypeUse {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#use;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::r#use);
+}
+
+impl AsyncDisposableStackPrototype {
+    /// ### [AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt)
+    fn adopt<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        adopt(agent, this_value, arguments, DisposeHint::AsyncDispose, gc)
+    }
+
+    /// ### [AsyncDisposableStack.prototype.defer ( onDisposeAsync )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer)
+    fn defer<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        defer(agent, this_value, arguments, DisposeHint::AsyncDispose, gc)
+    }
+
+    /// ### [AsyncDisposableStack.prototype.disposeAsync ( )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync)
+    fn dispose_async<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 3. If asyncDisposableStack does not have an
+        //    [[AsyncDisposableState]] internal slot, then
+        let async_disposable_stack = match require_disposable_stack_internal_slot(
+            agent,
+            this_value,
+            DisposeHint::AsyncDispose,
+            gc.nogc(),
+        ) {
+            Ok(stack) => stack,
+            Err(err) => {
+                // a. Let error be a newly created TypeError object.
+                // b. Perform ! Call(promiseCapability.[[Reject]], undefined, « error »).
+                let promise_capability = PromiseCapability::new(agent, gc.nogc());
+                let promise = promise_capability.promise();
+                promise_capability.reject(agent, err.value(), gc.nogc());
+                // c. Return promiseCapability.[[Promise]].
+                return Ok(promise.into_value().unbind());
+            }
+        };
+        // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+        if agent[async_disposable_stack].state == DisposableState::Disposed {
+            // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(Promise::resolve(agent, Value::Undefined, gc).into_value());
+        }
+        // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        agent[async_disposable_stack].state = DisposableState::Disposed;
+        // 6. Let result be DisposeResources(
+        //    asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(dispose_resources_async(agent, async_disposable_stack.unbind(), None, gc).into_value())
+    }
+
+    /// ### [get AsyncDisposableStack.prototype.disposed](https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed)
+    fn get_disposed<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        get_disposed(agent, this_value, DisposeHint::AsyncDispose, gc.into_nogc())
+    }
+
+    /// ### [AsyncDisposableStack.prototype.move ( )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move)
+    fn r#move<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        r#move(agent, this_value, DisposeHint::AsyncDispose, gc.into_nogc())
+    }
+
+    /// ### [AsyncDisposableStack.prototype.use ( value )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use)
+    fn r#use<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        r#use(agent, this_value, arguments, DisposeHint::AsyncDispose, gc)
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.async_disposable_stack_prototype();
+        let async_disposable_stack_constructor = intrinsics.async_disposable_stack();
+        let async_disposable_stack_prototype_dispose_async =
+            intrinsics.async_disposable_stack_prototype_dispose_async();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(9)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<AsyncDisposableStackPrototypeAdopt>()
+            .with_constructor_property(async_disposable_stack_constructor)
+            .with_builtin_function_property::<AsyncDisposableStackPrototypeDefer>()
+            .with_builtin_intrinsic_function_property::<AsyncDisposableStackPrototypeDisposeAsync>()
+            .with_builtin_function_getter_property::<AsyncDisposableStackPrototypeGetDisposed>()
+            .with_builtin_function_property::<AsyncDisposableStackPrototypeMove>()
+            .with_builtin_function_property::<AsyncDisposableStackPrototypeUse>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::AsyncDispose.into())
+                    .with_value(async_disposable_stack_prototype_dispose_async.into_value())
+                    .with_enumerable(AsyncDisposableStackPrototypeDisposeAsync::ENUMERABLE)
+                    .with_configurable(AsyncDisposableStackPrototypeDisposeAsync::CONFIGURABLE)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.AsyncDisposableStack.into_value())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
@@ -25,6 +25,7 @@ use crate::{
             promise::Promise,
         },
         execution::{Agent, ExecutionContext},
+        scripts_and_modules::module::module_semantics::source_text_module_records::SourceTextModule,
         types::Value,
     },
     engine::{ExecutionResult, SuspendedVm},
@@ -78,18 +79,21 @@ impl AwaitReactionIdentifier<'_> {
         // 3. d. Resume the suspended evaluation of asyncContext using NormalCompletion(v) as the result of the operation that suspended it.
         // 5. d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as the result of the operation that suspended it.
         let vm = agent[self].vm.take().unwrap();
-        let async_function = agent[self].async_function.unwrap();
+        let executable = if let Some(async_function) = agent[self].async_function {
+            async_function.get_executable(agent, gc.nogc())
+        } else {
+            let async_module = agent[self].async_module.unwrap();
+            agent[async_module]
+                .compiled_bytecode
+                .unwrap()
+                .bind(gc.nogc())
+        };
+        let executable = executable.scope(agent, gc.nogc());
         let execution_result = match reaction_type {
             PromiseReactionType::Fulfill => {
-                let executable = async_function
-                    .get_executable(agent, gc.nogc())
-                    .scope(agent, gc.nogc());
                 vm.resume(agent, executable, value.unbind(), gc.reborrow())
             }
             PromiseReactionType::Reject => {
-                let executable = async_function
-                    .get_executable(agent, gc.nogc())
-                    .scope(agent, gc.nogc());
                 vm.resume_throw(agent, executable, value.unbind(), gc.reborrow())
             }
         };
@@ -103,6 +107,13 @@ impl AwaitReactionIdentifier<'_> {
                 //       i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
                 //    f. Else if result is a return completion, then
                 //       i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result.[[Value]] »).
+                // NOTE: Module bodies cannot contain return statements, so
+                // their result is always a normal completion.
+                let result = if agent[self].async_module.is_some() {
+                    Value::Undefined
+                } else {
+                    result
+                };
                 agent[self]
                     .return_promise_capability
                     .clone()
@@ -192,6 +203,9 @@ impl HeapMarkAndSweep for AwaitReactionIdentifier<'static> {
 pub(crate) struct AwaitReaction<'a> {
     pub(crate) vm: Option<SuspendedVm>,
     pub(crate) async_function: Option<ECMAScriptFunction<'a>>,
+    /// The module whose top-level await this reaction resumes. Exactly one
+    /// of `async_function` and `async_module` is set.
+    pub(crate) async_module: Option<SourceTextModule<'a>>,
     pub(crate) execution_context: Option<ExecutionContext>,
     pub(crate) return_promise_capability: PromiseCapability<'a>,
 }
@@ -224,11 +238,13 @@ impl HeapMarkAndSweep for AwaitReaction<'static> {
         let Self {
             vm,
             async_function,
+            async_module,
             execution_context,
             return_promise_capability,
         } = self;
         vm.mark_values(queues);
         async_function.mark_values(queues);
+        async_module.mark_values(queues);
         execution_context.mark_values(queues);
         return_promise_capability.mark_values(queues);
     }
@@ -237,11 +253,13 @@ impl HeapMarkAndSweep for AwaitReaction<'static> {
         let Self {
             vm,
             async_function,
+            async_module,
             execution_context,
             return_promise_capability,
         } = self;
         vm.sweep_values(compactions);
         async_function.sweep_values(compactions);
+        async_module.sweep_values(compactions);
         execution_context.sweep_values(compactions);
         return_promise_capability.sweep_values(compactions);
     }
@@ -26,7 +26,7 @@ use crate::{
         },
     },
     engine::{
-        Executable, ExecutionResult, Scoped, SuspendedVm,
+        Executable, Scoped, SuspendedVm,
         context::{Bindable, GcScope, NoGcScope},
         rootable::{HeapRootData, HeapRootRef, Rootable, Scopable},
     },
@@ -82,6 +82,16 @@ impl AsyncGenerator<'_> {
             .is_executing()
     }
 
+    /// Returns true if the generator is executing, awaiting, or draining its
+    /// queue.
+    pub(crate) fn is_active(self, agent: &Agent) -> bool {
+        agent[self]
+            .async_generator_state
+            .as_ref()
+            .unwrap()
+            .is_active()
+    }
+
     pub(crate) fn is_suspended_start(self, agent: &Agent) -> bool {
         agent[self]
             .async_generator_state
@@ -308,10 +318,10 @@ impl AsyncGenerator<'_> {
                     let executable = agent[self].executable.unwrap().scope(agent, gc.nogc());
                     vm.resume_throw(agent, executable, value.unbind(), gc.reborrow())
                 } else {
-                    // TODO: vm.resume_return(agent, executable, value, gc.reborrow())
                     // 4. Assert: awaited is a normal completion.
                     // 5. Return ReturnCompletion(awaited.[[Value]]).
-                    ExecutionResult::Return(value)
+                    let executable = agent[self].executable.unwrap().scope(agent, gc.nogc());
+                    vm.resume_return(agent, executable, value.unbind(), gc.reborrow())
                 }
             }
         };
@@ -208,22 +208,30 @@ pub(super) fn async_generator_resume(
     // 7. Resume the suspended evaluation of genContext using completion as the
     //    result of the operation that suspended it. Let result be the
     //    Completion Record returned by the resumed computation.
-    let execution_result = match vm_or_args {
+    match vm_or_args {
         VmOrArguments::Arguments(mut args) => {
-            Vm::execute(agent, executable, Some(&mut args), gc.reborrow())
+            // Note: A generator in the suspended-start state is only resumed
+            // with a normal completion; return and throw completions complete
+            // it without running it.
+            let execution_result = Vm::execute(agent, executable, Some(&mut args), gc.reborrow());
+            // 8. Assert: result is never an abrupt completion.
+            // 9. Assert: When we return here, genContext has already been
+            //    removed from the execution context stack and callerContext
+            //    is the currently running execution context.
+            resume_handle_result(agent, execution_result.unbind(), scoped_generator, gc);
         }
         VmOrArguments::Vm(vm) => {
-            let AsyncGeneratorRequestCompletion::Ok(value) = completion else {
-                unreachable!()
-            };
-            vm.resume(agent, executable, value.unbind(), gc.reborrow())
+            // 27.6.3.8 AsyncGeneratorYield ( value )
+            // f. Return ? AsyncGeneratorUnwrapYieldResumption(resumptionValue).
+            async_generator_unwrap_yield_resumption(
+                agent,
+                vm,
+                scoped_generator,
+                completion.unbind(),
+                gc,
+            );
         }
-    };
-    // 8. Assert: result is never an abrupt completion.
-    // 9. Assert: When we return here, genContext has already been removed from
-    //    the execution context stack and callerContext is the currently
-    //    running execution context.
-    resume_handle_result(agent, execution_result.unbind(), scoped_generator, gc);
+    }
     // 10. Return unused.
 }
 
@@ -276,7 +284,22 @@ pub(super) fn resume_handle_result(
             async_generator_drain_queue(agent, scoped_generator, gc.reborrow());
             // l. Return undefined.
         }
-        ExecutionResult::Yield { vm, yielded_value } => {
+        ExecutionResult::Yield {
+            vm,
+            yielded_value,
+            delegate: true,
+        } => {
+            // 15.5.5 YieldExpression : yield * AssignmentExpression
+            // If generatorKind is async, set received to
+            // Completion(AsyncGeneratorYield(? IteratorValue(innerResult))).
+            // Note: Unlike Yield, the value is not awaited.
+            async_generator_yield(agent, yielded_value, scoped_generator, vm, gc);
+        }
+        ExecutionResult::Yield {
+            vm,
+            yielded_value,
+            delegate: false,
+        } => {
             // 27.5.3.7 Yield ( value )
             // If generatorKind is async, return ? AsyncGeneratorYield(? Await(value)).
             async_generator_perform_await(
@@ -381,7 +404,7 @@ pub(super) fn async_generator_yield(
     value: Value,
     generator: Scoped<AsyncGenerator>,
     vm: SuspendedVm,
-    mut gc: GcScope,
+    gc: GcScope,
 ) {
     // 1. Let genContext be the running execution context.
     let gen_context = agent.running_execution_context();
@@ -437,20 +460,8 @@ pub(super) fn async_generator_yield(
         //    which it is resumed.
         // e. Assert: If control reaches here, then genContext is the running execution context again.
         // f. Return ? AsyncGeneratorUnwrapYieldResumption(resumptionValue).
-
-        // Note: Yield performs an implicit await on the value.
-        // [27.7.5.3 Await ( value )](https://tc39.es/ecma262/#await)
-        // 8. Remove asyncContext from the execution context stack and
-        //    restore the execution context that is at the top of the
-        //    execution context stack as the running execution context.
-        let handler = PromiseReactionHandler::AsyncGenerator(generator.unbind());
-        // 2. Let promise be ? PromiseResolve(%Promise%, value).
-        let promise = Promise::resolve(agent, value, gc.reborrow())
-            .unbind()
-            .bind(gc.nogc());
-
-        // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
-        inner_promise_then(agent, promise, handler, handler, None, gc.nogc());
+        // Note: This is performed by AsyncGeneratorResume when the generator
+        // is resumed.
     }
 }
 
@@ -162,7 +162,7 @@ impl AsyncGeneratorPrototype {
         } else {
             // 10. Else,
             // a. Assert: state is either executing or draining-queue.
-            assert!(generator.is_draining_queue(agent) || generator.is_executing(agent));
+            assert!(generator.is_active(agent));
             // 11. Return promiseCapability.[[Promise]].
             Ok(promise.into_value().unbind())
         }
@@ -226,7 +226,7 @@ impl AsyncGeneratorPrototype {
         } else {
             // 11. Else,
             // a. Assert: state is either executing or draining-queue.
-            assert!(generator.is_executing(agent) || generator.is_draining_queue(agent));
+            assert!(generator.is_active(agent));
         }
         // 12. Return promiseCapability.[[Promise]].
         Ok(promise.into_value().unbind())
@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            ordinary::ordinary_create_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::context::{Bindable, GcScope},
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct DisposableStackConstructor;
+impl Builtin for DisposableStackConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.DisposableStack;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for DisposableStackConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::DisposableStack;
+}
+
+impl DisposableStackConstructor {
+    /// ### [DisposableStack ( )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _arguments: ArgumentsList,
+        new_target: Option<Object>,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let new_target = new_target.bind(gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor DisposableStack requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget,
+        //    "%DisposableStack.prototype%", « [[DisposableState]],
+        //    [[DisposeCapability]] »).
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return disposableStack.
+        Ok(ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::DisposableStack,
+            gc,
+        )?
+        .into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let disposable_stack_prototype = intrinsics.disposable_stack_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<DisposableStackConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype_property(disposable_stack_prototype.into_object())
+        .build();
+    }
+}
@@ -0,0 +1,430 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_disposable_objects::{add_disposable_resource, dispose_resources},
+            testing_and_comparison::is_callable,
+        },
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsic,
+            disposable_stack::{
+                DisposableStack,
+                data::{DisposableState, DisposeHint, DisposeMethod},
+            },
+            ordinary::ordinary_object_create_with_intrinsics,
+        },
+        execution::{
+            Agent, JsResult, ProtoIntrinsics, Realm,
+            agent::{ExceptionType, JsError},
+        },
+        types::{BUILTIN_STRING_MEMORY, IntoValue, PropertyKey, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+    heap::{IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
+};
+
+pub(crate) struct DisposableStackPrototype;
+
+struct DisposableStackPrototypeAdopt;
+impl Builtin for DisposableStackPrototypeAdopt {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.adopt;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::adopt);
+}
+struct DisposableStackPrototypeDefer;
+impl Builtin for DisposableStackPrototypeDefer {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.defer;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::defer);
+}
+struct DisposableStackPrototypeDispose;
+impl Builtin for DisposableStackPrototypeDispose {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.dispose;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::dispose);
+}
+impl BuiltinIntrinsic for DisposableStackPrototypeDispose {
+    const INDEX: IntrinsicFunctionIndexes =
+        IntrinsicFunctionIndexes::DisposableStackPrototypeDispose;
+}
+struct DisposableStackPrototypeGetDisposed;
+impl Builtin for DisposableStackPrototypeGetDisposed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_disposed;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.disposed.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::get_disposed);
+}
+impl BuiltinGetter for DisposableStackPrototypeGetDisposed {}
+struct DisposableStackPrototypeMove;
+impl Builtin for DisposableStackPrototypeMove {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#move;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::r#move);
+}
+struct DisposableStackPrototypeUse;
+impl Builtin for DisposableStackPrototypeUse {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#use;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::r#use);
+}
+
+impl DisposableStackPrototype {
+    /// ### [DisposableStack.prototype.adopt ( value, onDispose )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt)
+    fn adopt<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        adopt(agent, this_value, arguments, DisposeHint::SyncDispose, gc)
+    }
+
+    /// ### [DisposableStack.prototype.defer ( onDispose )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer)
+    fn defer<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        defer(agent, this_value, arguments, DisposeHint::SyncDispose, gc)
+    }
+
+    /// ### [DisposableStack.prototype.dispose ( )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose)
+    fn dispose<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let disposable_stack = require_disposable_stack_internal_slot(
+            agent,
+            this_value,
+            DisposeHint::SyncDispose,
+            gc.nogc(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+        if agent[disposable_stack].state == DisposableState::Disposed {
+            return Ok(Value::Undefined);
+        }
+        // 4. Set disposableStack.[[DisposableState]] to disposed.
+        agent[disposable_stack].state = DisposableState::Disposed;
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]],
+        //    NormalCompletion(undefined)).
+        dispose_resources(agent, disposable_stack.unbind(), None, gc)?;
+        Ok(Value::Undefined)
+    }
+
+    /// ### [get DisposableStack.prototype.disposed](https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed)
+    fn get_disposed<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        get_disposed(agent, this_value, DisposeHint::SyncDispose, gc.into_nogc())
+    }
+
+    /// ### [DisposableStack.prototype.move ( )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move)
+    fn r#move<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        r#move(agent, this_value, DisposeHint::SyncDispose, gc.into_nogc())
+    }
+
+    /// ### [DisposableStack.prototype.use ( value )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use)
+    fn r#use<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        r#use(agent, this_value, arguments, DisposeHint::SyncDispose, gc)
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.disposable_stack_prototype();
+        let disposable_stack_constructor = intrinsics.disposable_stack();
+        let disposable_stack_prototype_dispose = intrinsics.disposable_stack_prototype_dispose();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(9)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<DisposableStackPrototypeAdopt>()
+            .with_constructor_property(disposable_stack_constructor)
+            .with_builtin_function_property::<DisposableStackPrototypeDefer>()
+            .with_builtin_intrinsic_function_property::<DisposableStackPrototypeDispose>()
+            .with_builtin_function_getter_property::<DisposableStackPrototypeGetDisposed>()
+            .with_builtin_function_property::<DisposableStackPrototypeMove>()
+            .with_builtin_function_property::<DisposableStackPrototypeUse>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::Dispose.into())
+                    .with_value(disposable_stack_prototype_dispose.into_value())
+                    .with_enumerable(DisposableStackPrototypeDispose::ENUMERABLE)
+                    .with_configurable(DisposableStackPrototypeDispose::CONFIGURABLE)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.DisposableStack.into_value())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// Performs RequireInternalSlot(value, \[\[DisposableState]]) or
+/// RequireInternalSlot(value, \[\[AsyncDisposableState]]) depending on the
+/// hint.
+pub(crate) fn require_disposable_stack_internal_slot<'a>(
+    agent: &mut Agent,
+    value: Value,
+    hint: DisposeHint,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, DisposableStack<'a>> {
+    let is_async = hint == DisposeHint::AsyncDispose;
+    match value {
+        Value::DisposableStack(disposable_stack)
+            if disposable_stack.is_async(agent) == is_async =>
+        {
+            Ok(disposable_stack.bind(gc))
+        }
+        _ if is_async => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not an AsyncDisposableStack",
+            gc,
+        )),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a DisposableStack",
+            gc,
+        )),
+    }
+}
+
+/// Throws a ReferenceError if the DisposableStack or AsyncDisposableStack has
+/// already been disposed.
+fn require_pending<'a>(
+    agent: &mut Agent,
+    disposable_stack: DisposableStack,
+    gc: NoGcScope<'a, '_>,
+) -> Result<(), JsError<'a>> {
+    if agent[disposable_stack].state == DisposableState::Disposed {
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::ReferenceError,
+            "Stack has already been disposed",
+            gc,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Shared steps of `DisposableStack.prototype.adopt` and
+/// `AsyncDisposableStack.prototype.adopt`.
+pub(crate) fn adopt<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    hint: DisposeHint,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let value = arguments.get(0).bind(gc.nogc());
+    let on_dispose = arguments.get(1).bind(gc.nogc());
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack =
+        require_disposable_stack_internal_slot(agent, this_value, hint, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+    // 3. If disposableStack.[[DisposableState]] is disposed, throw a
+    //    ReferenceError exception.
+    require_pending(agent, disposable_stack, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+    let Some(on_dispose) = is_callable(on_dispose, gc.nogc()) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "onDispose is not callable",
+            gc.into_nogc(),
+        ));
+    };
+    // 5. Let closure be a new Abstract Closure with no parameters that
+    //    captures value and onDispose and performs the following steps when
+    //    called:
+    //    a. Return ? Call(onDispose, undefined, « value »).
+    // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+    let f = DisposeMethod::Adopt(on_dispose);
+    let scoped_value = value.scope(agent, gc.nogc());
+    // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]],
+    //    undefined, sync-dispose, F).
+    add_disposable_resource(
+        agent,
+        disposable_stack.unbind(),
+        value.unbind(),
+        hint,
+        Some(f.unbind()),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 8. Return value.
+    Ok(scoped_value.get(agent).bind(gc.into_nogc()))
+}
+
+/// Shared steps of `DisposableStack.prototype.defer` and
+/// `AsyncDisposableStack.prototype.defer`.
+pub(crate) fn defer<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    hint: DisposeHint,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let on_dispose = arguments.get(0).bind(gc.nogc());
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack =
+        require_disposable_stack_internal_slot(agent, this_value, hint, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+    // 3. If disposableStack.[[DisposableState]] is disposed, throw a
+    //    ReferenceError exception.
+    require_pending(agent, disposable_stack, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+    let Some(on_dispose) = is_callable(on_dispose, gc.nogc()) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "onDispose is not callable",
+            gc.into_nogc(),
+        ));
+    };
+    // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]],
+    //    undefined, sync-dispose, onDispose).
+    add_disposable_resource(
+        agent,
+        disposable_stack.unbind(),
+        Value::Undefined,
+        hint,
+        Some(DisposeMethod::Method(on_dispose.unbind())),
+        gc,
+    )?;
+    // 6. Return undefined.
+    Ok(Value::Undefined)
+}
+
+/// Shared steps of `get DisposableStack.prototype.disposed` and
+/// `get AsyncDisposableStack.prototype.disposed`.
+pub(crate) fn get_disposed<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    hint: DisposeHint,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack = require_disposable_stack_internal_slot(agent, this_value, hint, gc)?;
+    // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+    // 4. Otherwise, return false.
+    Ok((agent[disposable_stack].state == DisposableState::Disposed).into())
+}
+
+/// Shared steps of `DisposableStack.prototype.move` and
+/// `AsyncDisposableStack.prototype.move`.
+pub(crate) fn r#move<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    hint: DisposeHint,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack = require_disposable_stack_internal_slot(agent, this_value, hint, gc)?;
+    // 3. If disposableStack.[[DisposableState]] is disposed, throw a
+    //    ReferenceError exception.
+    require_pending(agent, disposable_stack, gc)?;
+    // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(
+    //    %DisposableStack%, "%DisposableStack.prototype%",
+    //    « [[DisposableState]], [[DisposeCapability]] »).
+    let proto_intrinsics = if hint == DisposeHint::AsyncDispose {
+        ProtoIntrinsics::AsyncDisposableStack
+    } else {
+        ProtoIntrinsics::DisposableStack
+    };
+    let new_disposable_stack = DisposableStack::try_from(ordinary_object_create_with_intrinsics(
+        agent,
+        Some(proto_intrinsics),
+        None,
+        gc,
+    ))
+    .unwrap();
+    // 5. Set newDisposableStack.[[DisposableState]] to pending.
+    // 6. Set newDisposableStack.[[DisposeCapability]] to
+    //    disposableStack.[[DisposeCapability]].
+    // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+    let resources = core::mem::take(&mut agent[disposable_stack].resources);
+    agent[new_disposable_stack].resources = resources;
+    // 8. Set disposableStack.[[DisposableState]] to disposed.
+    agent[disposable_stack].state = DisposableState::Disposed;
+    // 9. Return newDisposableStack.
+    Ok(new_disposable_stack.into_value())
+}
+
+/// Shared steps of `DisposableStack.prototype.use` and
+/// `AsyncDisposableStack.prototype.use`.
+pub(crate) fn r#use<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    hint: DisposeHint,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let value = arguments.get(0).bind(gc.nogc());
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack =
+        require_disposable_stack_internal_slot(agent, this_value, hint, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+    // 3. If disposableStack.[[DisposableState]] is disposed, throw a
+    //    ReferenceError exception.
+    require_pending(agent, disposable_stack, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    let scoped_value = value.scope(agent, gc.nogc());
+    // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]],
+    //    value, sync-dispose).
+    add_disposable_resource(
+        agent,
+        disposable_stack.unbind(),
+        value.unbind(),
+        hint,
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 5. Return value.
+    Ok(scoped_value.get(agent).bind(gc.into_nogc()))
+}
@@ -154,7 +154,11 @@ impl Generator<'_> {
                 //    ii. Return ? result.
                 Err(err.unbind())
             }
-            ExecutionResult::Yield { vm, yielded_value } => {
+            ExecutionResult::Yield {
+                vm,
+                yielded_value,
+                delegate,
+            } => {
                 // Yield:
                 // 3. Otherwise, return ? GeneratorYield(CreateIterResultObject(value, false)).
                 // GeneratorYield:
@@ -168,7 +172,7 @@ impl Generator<'_> {
                     }));
                 // 8. Resume callerContext passing NormalCompletion(iterNextObj). ...
                 // NOTE: `callerContext` here is the `GeneratorResume` execution context.
-                Ok(create_iter_result_object(agent, yielded_value, false, gc))
+                Ok(generator_yield_result(agent, yielded_value, delegate, gc))
             }
             ExecutionResult::Await { .. } => unreachable!(),
         }
@@ -266,18 +270,167 @@ impl Generator<'_> {
                 agent[self].generator_state = Some(GeneratorState::Completed);
                 Err(err.unbind())
             }
-            ExecutionResult::Yield { vm, yielded_value } => {
+            ExecutionResult::Yield {
+                vm,
+                yielded_value,
+                delegate,
+            } => {
                 agent[self].generator_state =
                     Some(GeneratorState::Suspended(SuspendedGeneratorState {
                         vm_or_args: VmOrArguments::Vm(vm),
                         executable: executable.get(agent),
                         execution_context,
                     }));
-                Ok(create_iter_result_object(agent, yielded_value, false, gc))
+                Ok(generator_yield_result(agent, yielded_value, delegate, gc))
             }
             ExecutionResult::Await { .. } => unreachable!(),
         }
     }
+    /// [27.5.3.4 GeneratorResumeAbrupt ( generator, abruptCompletion, generatorBrand )](https://tc39.es/ecma262/#sec-generatorresumeabrupt)
+    /// NOTE: This method only accepts return completions.
+    pub(crate) fn resume_return<'a>(
+        self,
+        agent: &mut Agent,
+        value: Value,
+        mut gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, Object<'a>> {
+        let value = value.bind(gc.nogc());
+        // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
+        match agent[self].generator_state.as_ref().unwrap() {
+            GeneratorState::Suspended(SuspendedGeneratorState {
+                vm_or_args: VmOrArguments::Arguments(_),
+                ..
+            }) => {
+                // 2. If state is suspended-start, then
+                // a. Set generator.[[GeneratorState]] to completed.
+                // b. NOTE: Once a generator enters the completed state it never leaves it and its
+                // associated execution context is never resumed. Any execution state associated
+                // with generator can be discarded at this point.
+                agent[self].generator_state = Some(GeneratorState::Completed);
+                // c. Set state to completed.
+
+                // 3. If state is completed, then
+                // a. If abruptCompletion is a return completion, then
+                // i. Return CreateIterResultObject(abruptCompletion.[[Value]], true).
+                return Ok(create_iter_result_object(
+                    agent,
+                    value.unbind(),
+                    true,
+                    gc.into_nogc(),
+                ));
+            }
+            GeneratorState::Suspended { .. } => {
+                // 4. Assert: state is suspended-yield.
+            }
+            GeneratorState::Executing => {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "The generator is currently running",
+                    gc.into_nogc(),
+                ));
+            }
+            GeneratorState::Completed => {
+                // 3. If state is completed, then
+                // a. If abruptCompletion is a return completion, then
+                // i. Return CreateIterResultObject(abruptCompletion.[[Value]], true).
+                return Ok(create_iter_result_object(
+                    agent,
+                    value.unbind(),
+                    true,
+                    gc.into_nogc(),
+                ));
+            }
+        };
+
+        // 8. Set generator.[[GeneratorState]] to executing.
+        let Some(GeneratorState::Suspended(SuspendedGeneratorState {
+            vm_or_args: VmOrArguments::Vm(vm),
+            executable,
+            execution_context,
+        })) = agent[self]
+            .generator_state
+            .replace(GeneratorState::Executing)
+        else {
+            unreachable!()
+        };
+        let executable = executable.scope(agent, gc.nogc());
+
+        // 5. Let genContext be generator.[[GeneratorContext]].
+        // 6. Let methodContext be the running execution context.
+        // 7. Suspend methodContext.
+        // 9. Push genContext onto the execution context stack; genContext is now the running
+        // execution context.
+        agent.push_execution_context(execution_context);
+
+        // 10. Resume the suspended evaluation of genContext using abruptCompletion as the result
+        // of the operation that suspended it. Let result be the Completion Record returned by the
+        // resumed computation.
+        let execution_result = vm
+            .resume_return(agent, executable.clone(), value.unbind(), gc.reborrow())
+            .unbind();
+        let gc = gc.into_nogc();
+        let execution_result = execution_result.bind(gc);
+
+        // GeneratorStart: 4.f. Remove acGenContext from the execution context stack and restore the
+        // execution context that is at the top of the execution context stack as the running
+        // execution context.
+        // GeneratorYield 6 is the same.
+        let execution_context = agent.pop_execution_context().unwrap();
+
+        // 11. Assert: When we return here, genContext has already been removed
+        // from the execution context stack and methodContext is the currently
+        // running execution context.
+        // 12. Return ? result.
+        match execution_result {
+            ExecutionResult::Return(result) => {
+                agent[self].generator_state = Some(GeneratorState::Completed);
+                Ok(create_iter_result_object(agent, result, true, gc))
+            }
+            ExecutionResult::Throw(err) => {
+                agent[self].generator_state = Some(GeneratorState::Completed);
+                Err(err.unbind())
+            }
+            ExecutionResult::Yield {
+                vm,
+                yielded_value,
+                delegate,
+            } => {
+                // Note: A finally block yielded before completing the return.
+                agent[self].generator_state =
+                    Some(GeneratorState::Suspended(SuspendedGeneratorState {
+                        vm_or_args: VmOrArguments::Vm(vm),
+                        executable: executable.get(agent),
+                        execution_context,
+                    }));
+                Ok(generator_yield_result(agent, yielded_value, delegate, gc))
+            }
+            ExecutionResult::Await { .. } => unreachable!(),
+        }
+    }
+}
+
+/// Get the iterNextObj that GeneratorYield passes to the caller of the
+/// generator for a yielded value.
+///
+/// Yield creates a new iterator result object for the value, while `yield*`
+/// yields the inner iterator's result objects as-is.
+fn generator_yield_result<'a>(
+    agent: &mut Agent,
+    yielded_value: Value,
+    delegate: bool,
+    gc: NoGcScope<'a, '_>,
+) -> Object<'a> {
+    if delegate {
+        // GeneratorYield ( innerResult )
+        let Ok(inner_result) = Object::try_from(yielded_value) else {
+            unreachable!()
+        };
+        inner_result.bind(gc)
+    } else {
+        // Yield ( value )
+        // 3. Otherwise, return ? GeneratorYield(CreateIterResultObject(value, false)).
+        create_iter_result_object(agent, yielded_value, false, gc)
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
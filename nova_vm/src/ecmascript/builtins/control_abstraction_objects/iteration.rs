@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+pub(crate) mod async_from_sync_iterator_objects;
 pub(crate) mod async_from_sync_iterator_prototype;
 pub(crate) mod async_iterator_prototype;
 pub(crate) mod iterator_constructor;
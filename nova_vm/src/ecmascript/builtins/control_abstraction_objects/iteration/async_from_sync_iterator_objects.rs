@@ -0,0 +1,277 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [27.1.6 Async-from-Sync Iterator Objects](https://tc39.es/ecma262/#sec-async-from-sync-iterator-objects)
+//!
+//! An Async-from-Sync Iterator object is an async iterator that adapts a
+//! specific synchronous iterator. Async-from-Sync Iterator objects are never
+//! directly accessible to ECMAScript code.
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_iterator_objects::IteratorRecord,
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoFunction, IntoObject, IntoValue, Object,
+            OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        context::{Bindable, NoGcScope},
+        rootable::HeapRootData,
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::AsyncFromSyncIteratorIndex,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct AsyncFromSyncIterator<'a>(AsyncFromSyncIteratorIndex<'a>);
+
+impl<'a> AsyncFromSyncIterator<'a> {
+    /// # Do not use this
+    /// This is only for Value discriminant creation.
+    pub(crate) const fn _def() -> Self {
+        Self(AsyncFromSyncIteratorIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    /// \[\[SyncIteratorRecord]]
+    pub(crate) fn sync_iterator_record(self, agent: &Agent) -> IteratorRecord<'a> {
+        agent[self].sync_iterator_record.unwrap()
+    }
+}
+
+/// ### [27.1.6.1 CreateAsyncFromSyncIterator ( syncIteratorRecord )](https://tc39.es/ecma262/#sec-createasyncfromsynciterator)
+///
+/// The abstract operation CreateAsyncFromSyncIterator takes argument
+/// syncIteratorRecord (an Iterator Record) and returns an Iterator Record. It
+/// is used to create an async Iterator Record from a synchronous Iterator
+/// Record.
+pub(crate) fn create_async_from_sync_iterator<'a>(
+    agent: &mut Agent,
+    sync_iterator_record: IteratorRecord,
+    gc: NoGcScope<'a, '_>,
+) -> IteratorRecord<'a> {
+    // 1. Let asyncIterator be OrdinaryObjectCreate(%AsyncFromSyncIteratorPrototype%, « [[SyncIteratorRecord]] »).
+    // 2. Set asyncIterator.[[SyncIteratorRecord]] to syncIteratorRecord.
+    let async_iterator = agent.heap.create(AsyncFromSyncIteratorHeapData {
+        object_index: None,
+        sync_iterator_record: Some(sync_iterator_record.unbind()),
+    });
+    // 3. Let nextMethod be ! Get(asyncIterator, "next").
+    let next_method = agent
+        .current_realm_record()
+        .intrinsics()
+        .async_from_sync_iterator_prototype_next()
+        .into_function()
+        .bind(gc);
+    // 4. Let iteratorRecord be the Iterator Record { [[Iterator]]: asyncIterator, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 5. Return iteratorRecord.
+    IteratorRecord {
+        iterator: async_iterator.into_object(),
+        next_method,
+    }
+    .bind(gc)
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for AsyncFromSyncIterator<'_> {
+    type Of<'a> = AsyncFromSyncIterator<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for AsyncFromSyncIterator<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> IntoObject<'a> for AsyncFromSyncIterator<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<AsyncFromSyncIterator<'a>> for Object<'a> {
+    fn from(value: AsyncFromSyncIterator<'a>) -> Self {
+        Self::AsyncFromSyncIterator(value)
+    }
+}
+
+impl<'a> From<AsyncFromSyncIterator<'a>> for Value<'a> {
+    fn from(value: AsyncFromSyncIterator<'a>) -> Self {
+        Self::AsyncFromSyncIterator(value)
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for AsyncFromSyncIterator<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Value::AsyncFromSyncIterator(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for AsyncFromSyncIterator<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::AsyncFromSyncIterator(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for AsyncFromSyncIterator<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::AsyncFromSyncIterator;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            agent[self]
+                .object_index
+                .replace(backing_object.unbind())
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for AsyncFromSyncIterator<'a> {}
+
+impl Index<AsyncFromSyncIterator<'_>> for Agent {
+    type Output = AsyncFromSyncIteratorHeapData<'static>;
+
+    fn index(&self, index: AsyncFromSyncIterator) -> &Self::Output {
+        &self.heap.async_from_sync_iterators[index]
+    }
+}
+
+impl IndexMut<AsyncFromSyncIterator<'_>> for Agent {
+    fn index_mut(&mut self, index: AsyncFromSyncIterator) -> &mut Self::Output {
+        &mut self.heap.async_from_sync_iterators[index]
+    }
+}
+
+impl Index<AsyncFromSyncIterator<'_>> for Vec<Option<AsyncFromSyncIteratorHeapData<'static>>> {
+    type Output = AsyncFromSyncIteratorHeapData<'static>;
+
+    fn index(&self, index: AsyncFromSyncIterator) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("AsyncFromSyncIterator out of bounds")
+            .as_ref()
+            .expect("AsyncFromSyncIterator slot empty")
+    }
+}
+
+impl IndexMut<AsyncFromSyncIterator<'_>> for Vec<Option<AsyncFromSyncIteratorHeapData<'static>>> {
+    fn index_mut(&mut self, index: AsyncFromSyncIterator) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("AsyncFromSyncIterator out of bounds")
+            .as_mut()
+            .expect("AsyncFromSyncIterator slot empty")
+    }
+}
+
+impl TryFrom<HeapRootData> for AsyncFromSyncIterator<'_> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: HeapRootData) -> Result<Self, Self::Error> {
+        if let HeapRootData::AsyncFromSyncIterator(value) = value {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<'a> CreateHeapData<AsyncFromSyncIteratorHeapData<'a>, AsyncFromSyncIterator<'a>> for Heap {
+    fn create(&mut self, data: AsyncFromSyncIteratorHeapData<'a>) -> AsyncFromSyncIterator<'a> {
+        self.async_from_sync_iterators.push(Some(data.unbind()));
+        self.alloc_counter +=
+            core::mem::size_of::<Option<AsyncFromSyncIteratorHeapData<'static>>>();
+        AsyncFromSyncIterator(AsyncFromSyncIteratorIndex::last(
+            &self.async_from_sync_iterators,
+        ))
+    }
+}
+
+impl HeapMarkAndSweep for AsyncFromSyncIterator<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.async_from_sync_iterators.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions
+            .async_from_sync_iterators
+            .shift_index(&mut self.0);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsyncFromSyncIteratorHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// \[\[SyncIteratorRecord]]
+    pub(crate) sync_iterator_record: Option<IteratorRecord<'a>>,
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for AsyncFromSyncIteratorHeapData<'_> {
+    type Of<'a> = AsyncFromSyncIteratorHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for AsyncFromSyncIteratorHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            sync_iterator_record,
+        } = self;
+        object_index.mark_values(queues);
+        sync_iterator_record.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            sync_iterator_record,
+        } = self;
+        object_index.sweep_values(compactions);
+        sync_iterator_record.sweep_values(compactions);
+    }
+}
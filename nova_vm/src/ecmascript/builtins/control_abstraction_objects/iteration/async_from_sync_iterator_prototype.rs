@@ -4,14 +4,37 @@
 
 use crate::{
     ecmascript::{
+        abstract_operations::{
+            operations_on_iterator_objects::{
+                create_iter_result_object, iterator_close_with_value, iterator_complete,
+                iterator_next, iterator_value,
+            },
+            operations_on_objects::{call_function, get_method},
+        },
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsic,
+            promise::Promise,
+            promise_objects::{
+                promise_abstract_operations::{
+                    promise_capability_records::{PromiseCapability, if_abrupt_reject_promise},
+                    promise_reaction_records::PromiseReactionHandler,
+                },
+                promise_prototype::inner_promise_then,
+            },
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, Object, String, Value},
     },
-    engine::context::GcScope,
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::IntrinsicFunctionIndexes,
 };
 
+use super::async_from_sync_iterator_objects::AsyncFromSyncIterator;
+
 pub(crate) struct AsyncFromSyncIteratorPrototype;
 
 struct AsyncFromSyncIteratorPrototypeNext;
@@ -20,6 +43,10 @@ impl Builtin for AsyncFromSyncIteratorPrototypeNext {
     const LENGTH: u8 = 0;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncFromSyncIteratorPrototype::next);
 }
+impl BuiltinIntrinsic for AsyncFromSyncIteratorPrototypeNext {
+    const INDEX: IntrinsicFunctionIndexes =
+        IntrinsicFunctionIndexes::AsyncFromSyncIteratorPrototypeNext;
+}
 struct AsyncFromSyncIteratorPrototypeReturn;
 impl Builtin for AsyncFromSyncIteratorPrototypeReturn {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#return;
@@ -34,31 +61,269 @@ impl Builtin for AsyncFromSyncIteratorPrototypeThrow {
 }
 
 impl AsyncFromSyncIteratorPrototype {
+    /// ### [27.1.6.2.1 %AsyncFromSyncIteratorPrototype%.next ( \[ value \] )](https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.next)
     fn next<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("AsyncFromSyncIterator.prototype.next", gc.into_nogc()))
+        let value = arguments.get_if_present(0).map(Value::unbind);
+        // 1. Let O be the this value.
+        // 2. Assert: O is an Object that has a [[SyncIteratorRecord]] internal slot.
+        let Ok(o) = AsyncFromSyncIterator::try_from(this_value) else {
+            unreachable!()
+        };
+        // 3. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise = PromiseCapability::new(agent, gc.nogc())
+            .promise()
+            .scope(agent, gc.nogc());
+        // 4. Let syncIteratorRecord be O.[[SyncIteratorRecord]].
+        let sync_iterator_record = o.sync_iterator_record(agent);
+        let scoped_sync_iterator = sync_iterator_record.iterator.scope(agent, gc.nogc());
+        // 5. If value is present, then
+        //    a. Let result be Completion(IteratorNext(syncIteratorRecord, value)).
+        // 6. Else,
+        //    a. Let result be Completion(IteratorNext(syncIteratorRecord)).
+        let result = iterator_next(agent, sync_iterator_record.unbind(), value, gc.reborrow())
+            .unbind()
+            .bind(gc.nogc());
+        let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        let result = match if_abrupt_reject_promise(agent, result, promise_capability, gc.nogc()) {
+            Ok(result) => result,
+            Err(promise) => return Ok(promise.into_value().unbind()),
+        };
+        // 8. Return AsyncFromSyncIteratorContinuation(result, promiseCapability, syncIteratorRecord, true).
+        Ok(async_from_sync_iterator_continuation(
+            agent,
+            result.unbind(),
+            promise.get(agent),
+            scoped_sync_iterator.get(agent),
+            true,
+            gc,
+        )
+        .into_value())
     }
 
+    /// ### [27.1.6.2.2 %AsyncFromSyncIteratorPrototype%.return ( \[ value \] )](https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.return)
     fn r#return<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("AsyncFromSyncIterator.prototype.return", gc.into_nogc()))
+        let value = arguments
+            .get_if_present(0)
+            .map(|v| v.scope(agent, gc.nogc()));
+        // 1. Let O be the this value.
+        // 2. Assert: O is an Object that has a [[SyncIteratorRecord]] internal slot.
+        let Ok(o) = AsyncFromSyncIterator::try_from(this_value) else {
+            unreachable!()
+        };
+        // 3. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise = PromiseCapability::new(agent, gc.nogc())
+            .promise()
+            .scope(agent, gc.nogc());
+        // 4. Let syncIterator be O.[[SyncIteratorRecord]].[[Iterator]].
+        let sync_iterator = o.sync_iterator_record(agent).iterator;
+        let scoped_sync_iterator = sync_iterator.scope(agent, gc.nogc());
+        // 5. Let return be Completion(GetMethod(syncIterator, "return")).
+        let r#return = get_method(
+            agent,
+            sync_iterator.into_value().unbind(),
+            BUILTIN_STRING_MEMORY.r#return.into(),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        // 6. IfAbruptRejectPromise(return, promiseCapability).
+        let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+        let r#return =
+            match if_abrupt_reject_promise(agent, r#return, promise_capability, gc.nogc()) {
+                Ok(r#return) => r#return,
+                Err(promise) => return Ok(promise.into_value().unbind()),
+            };
+        // 7. If return is undefined, then
+        let Some(r#return) = r#return else {
+            // a. Let iteratorResult be CreateIteratorResultObject(value, true).
+            let value = value.map_or(Value::Undefined, |v| v.get(agent));
+            let iterator_result = create_iter_result_object(agent, value, true, gc.nogc());
+            // b. Perform ! Call(promiseCapability.[[Resolve]], undefined, « iteratorResult »).
+            let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+            promise_capability.resolve(agent, iterator_result.into_value().unbind(), gc.reborrow());
+            // c. Return promiseCapability.[[Promise]].
+            return Ok(promise.get(agent).into_value());
+        };
+        // 8. If value is present, then
+        //    a. Let result be Completion(Call(return, syncIterator, « value »)).
+        // 9. Else,
+        //    a. Let result be Completion(Call(return, syncIterator)).
+        let mut value = value.map(|v| v.get(agent));
+        let result = call_function(
+            agent,
+            r#return.unbind(),
+            scoped_sync_iterator.get(agent).into_value(),
+            value.as_mut().map(ArgumentsList::from_mut_value),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        // 10. IfAbruptRejectPromise(result, promiseCapability).
+        let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+        let result = match if_abrupt_reject_promise(agent, result, promise_capability, gc.nogc()) {
+            Ok(result) => result,
+            Err(promise) => return Ok(promise.into_value().unbind()),
+        };
+        // 11. If result is not an Object, then
+        let Ok(result) = Object::try_from(result) else {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            let error = agent.create_exception_with_static_message(
+                ExceptionType::TypeError,
+                "AsyncFromSyncIterator return result is not an object",
+                gc.nogc(),
+            );
+            let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+            promise_capability.reject(agent, error, gc.nogc());
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise.get(agent).into_value());
+        };
+        // 12. Return AsyncFromSyncIteratorContinuation(result, promiseCapability, syncIteratorRecord, false).
+        Ok(async_from_sync_iterator_continuation(
+            agent,
+            result.unbind(),
+            promise.get(agent),
+            scoped_sync_iterator.get(agent),
+            false,
+            gc,
+        )
+        .into_value())
     }
 
+    /// ### [27.1.6.2.3 %AsyncFromSyncIteratorPrototype%.throw ( \[ value \] )](https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.throw)
+    ///
+    /// > NOTE: In this specification, value is always provided, but is left
+    /// > optional for consistency with
+    /// > %AsyncFromSyncIteratorPrototype%.return ( \[ value \] ).
     fn throw<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("AsyncFromSyncIterator.prototype.throw", gc.into_nogc()))
+        let value = arguments
+            .get_if_present(0)
+            .map(|v| v.scope(agent, gc.nogc()));
+        // 1. Let O be the this value.
+        // 2. Assert: O is an Object that has a [[SyncIteratorRecord]] internal slot.
+        let Ok(o) = AsyncFromSyncIterator::try_from(this_value) else {
+            unreachable!()
+        };
+        // 3. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise = PromiseCapability::new(agent, gc.nogc())
+            .promise()
+            .scope(agent, gc.nogc());
+        // 4. Let syncIteratorRecord be O.[[SyncIteratorRecord]].
+        // 5. Let syncIterator be syncIteratorRecord.[[Iterator]].
+        let sync_iterator = o.sync_iterator_record(agent).iterator;
+        let scoped_sync_iterator = sync_iterator.scope(agent, gc.nogc());
+        // 6. Let throw be Completion(GetMethod(syncIterator, "throw")).
+        let throw = get_method(
+            agent,
+            sync_iterator.into_value().unbind(),
+            BUILTIN_STRING_MEMORY.throw.into(),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        // 7. IfAbruptRejectPromise(throw, promiseCapability).
+        let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+        let throw = match if_abrupt_reject_promise(agent, throw, promise_capability, gc.nogc()) {
+            Ok(throw) => throw,
+            Err(promise) => return Ok(promise.into_value().unbind()),
+        };
+        // 8. If throw is undefined, then
+        let Some(throw) = throw else {
+            // a. NOTE: If syncIterator does not have a throw method, close it
+            //    to give it a chance to clean up before we reject the
+            //    capability.
+            // b. Let closeCompletion be NormalCompletion(empty).
+            // c. Let result be Completion(IteratorClose(syncIteratorRecord, closeCompletion)).
+            let result = iterator_close_with_value(
+                agent,
+                scoped_sync_iterator.get(agent),
+                Value::Undefined,
+                gc.reborrow(),
+            )
+            .unbind()
+            .bind(gc.nogc());
+            // d. IfAbruptRejectPromise(result, promiseCapability).
+            let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+            if let Err(promise) =
+                if_abrupt_reject_promise(agent, result, promise_capability, gc.nogc())
+            {
+                return Ok(promise.into_value().unbind());
+            }
+            // e. NOTE: The next step throws a TypeError to indicate that
+            //    there was a protocol violation: syncIterator does not have a
+            //    throw method.
+            // f. NOTE: If closing syncIterator does not throw then the result
+            //    of that operation is ignored, even if it yields a rejected
+            //    promise.
+            // g. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            let error = agent.create_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Iterator does not have a throw method",
+                gc.nogc(),
+            );
+            let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+            promise_capability.reject(agent, error, gc.nogc());
+            // h. Return promiseCapability.[[Promise]].
+            return Ok(promise.get(agent).into_value());
+        };
+        // 9. If value is present, then
+        //    a. Let result be Completion(Call(throw, syncIterator, « value »)).
+        // 10. Else,
+        //    a. Let result be Completion(Call(throw, syncIterator)).
+        let mut value = value.map(|v| v.get(agent));
+        let result = call_function(
+            agent,
+            throw.unbind(),
+            scoped_sync_iterator.get(agent).into_value(),
+            value.as_mut().map(ArgumentsList::from_mut_value),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        // 11. IfAbruptRejectPromise(result, promiseCapability).
+        let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+        let result = match if_abrupt_reject_promise(agent, result, promise_capability, gc.nogc()) {
+            Ok(result) => result,
+            Err(promise) => return Ok(promise.into_value().unbind()),
+        };
+        // 12. If result is not an Object, then
+        let Ok(result) = Object::try_from(result) else {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            let error = agent.create_exception_with_static_message(
+                ExceptionType::TypeError,
+                "AsyncFromSyncIterator throw result is not an object",
+                gc.nogc(),
+            );
+            let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+            promise_capability.reject(agent, error, gc.nogc());
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise.get(agent).into_value());
+        };
+        // 13. Return AsyncFromSyncIteratorContinuation(result, promiseCapability, syncIteratorRecord, true).
+        Ok(async_from_sync_iterator_continuation(
+            agent,
+            result.unbind(),
+            promise.get(agent),
+            scoped_sync_iterator.get(agent),
+            true,
+            gc,
+        )
+        .into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -69,9 +334,109 @@ impl AsyncFromSyncIteratorPrototype {
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
             .with_property_capacity(3)
             .with_prototype(async_iterator_prototype)
-            .with_builtin_function_property::<AsyncFromSyncIteratorPrototypeNext>()
+            .with_builtin_intrinsic_function_property::<AsyncFromSyncIteratorPrototypeNext>()
             .with_builtin_function_property::<AsyncFromSyncIteratorPrototypeReturn>()
             .with_builtin_function_property::<AsyncFromSyncIteratorPrototypeThrow>()
             .build();
     }
 }
+
+/// ### [27.1.6.4 AsyncFromSyncIteratorContinuation ( result, promiseCapability, syncIteratorRecord, closeOnRejection )](https://tc39.es/ecma262/#sec-asyncfromsynciteratorcontinuation)
+///
+/// The abstract operation AsyncFromSyncIteratorContinuation takes arguments
+/// result (an Object), promiseCapability (a PromiseCapability Record for an
+/// intrinsic %Promise%), syncIteratorRecord (an Iterator Record), and
+/// closeOnRejection (a Boolean) and returns a Promise.
+///
+/// Note: Only the \[\[Iterator]] of syncIteratorRecord is needed here, so it
+/// is passed in directly.
+fn async_from_sync_iterator_continuation<'a>(
+    agent: &mut Agent,
+    result: Object,
+    promise: Promise,
+    sync_iterator: Object,
+    close_on_rejection: bool,
+    mut gc: GcScope<'a, '_>,
+) -> Promise<'a> {
+    let result = result.bind(gc.nogc());
+    let promise = promise.scope(agent, gc.nogc());
+    let sync_iterator = sync_iterator.scope(agent, gc.nogc());
+    let scoped_result = result.scope(agent, gc.nogc());
+    // 1. NOTE: Because promiseCapability is derived from the intrinsic
+    //    %Promise%, the calls to promiseCapability.[[Reject]] entailed by the
+    //    use IfAbruptRejectPromise below are guaranteed not to throw.
+    // 2. Let done be Completion(IteratorComplete(result)).
+    let done = iterator_complete(agent, result.unbind(), gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    // 3. IfAbruptRejectPromise(done, promiseCapability).
+    let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+    let done = match if_abrupt_reject_promise(agent, done, promise_capability, gc.nogc()) {
+        Ok(done) => done,
+        Err(promise) => return promise.unbind().bind(gc.into_nogc()),
+    };
+    // 4. Let value be Completion(IteratorValue(result)).
+    let value = iterator_value(agent, scoped_result.get(agent), gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    // 5. IfAbruptRejectPromise(value, promiseCapability).
+    let promise_capability = PromiseCapability::from_promise(promise.get(agent), true);
+    let value = match if_abrupt_reject_promise(agent, value, promise_capability, gc.nogc()) {
+        Ok(value) => value,
+        Err(promise) => return promise.unbind().bind(gc.into_nogc()),
+    };
+    // 6. Let valueWrapper be Completion(PromiseResolve(%Promise%, value)).
+    // 7. If valueWrapper is an abrupt completion, done is false, and
+    //    closeOnRejection is true, then
+    //    a. Set valueWrapper to Completion(IteratorClose(syncIteratorRecord, valueWrapper)).
+    // 8. IfAbruptRejectPromise(valueWrapper, promiseCapability).
+    // NOTE: PromiseResolve with the intrinsic %Promise% cannot throw, as we
+    // do not look up the "constructor" property of the value.
+    let value_wrapper = Promise::resolve(agent, value.unbind(), gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    // 9. Let unwrap be a new Abstract Closure with parameters (v) that
+    //    captures done and performs the following steps when called:
+    //    a. Return CreateIteratorResultObject(v, done).
+    // 10. Let onFulfilled be CreateBuiltinFunction(unwrap, 1, "", « »).
+    // 11. NOTE: onFulfilled is used when processing the "value" property of
+    //     an IteratorResult object in order to wait for its value if it is a
+    //     promise and re-package the result in a new "unwrapped"
+    //     IteratorResult object.
+    // 12. If done is true, or if closeOnRejection is false, then
+    //     a. Let onRejected be undefined.
+    // 13. Else,
+    //     a. Let closeIterator be a new Abstract Closure with parameters
+    //        (error) that captures syncIteratorRecord and performs the
+    //        following steps when called:
+    //        i. Return ? IteratorClose(syncIteratorRecord, ThrowCompletion(error)).
+    //     b. Let onRejected be CreateBuiltinFunction(closeIterator, 1, "", « »).
+    //     c. NOTE: onRejected is used to close the Iterator when the "value"
+    //        property of an IteratorResult object it yields is a rejected
+    //        promise.
+    let close_iterator = if done || !close_on_rejection {
+        None
+    } else {
+        Some(sync_iterator.get(agent))
+    };
+    let handler = PromiseReactionHandler::AsyncFromSyncIterator {
+        done,
+        close_iterator,
+    };
+    // 14. Perform PerformPromiseThen(valueWrapper, onFulfilled, onRejected, promiseCapability).
+    let value_wrapper = value_wrapper.unbind();
+    let gc = gc.into_nogc();
+    let value_wrapper = value_wrapper.bind(gc);
+    let promise = promise.get(agent).bind(gc);
+    let promise_capability = PromiseCapability::from_promise(promise, true);
+    inner_promise_then(
+        agent,
+        value_wrapper,
+        handler,
+        handler,
+        Some(promise_capability),
+        gc,
+    );
+    // 15. Return promiseCapability.[[Promise]].
+    promise
+}
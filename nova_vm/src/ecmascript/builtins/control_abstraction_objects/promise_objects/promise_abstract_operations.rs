@@ -3,6 +3,192 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 pub mod promise_capability_records;
+pub(crate) mod promise_collector_functions;
+pub(crate) mod promise_group_records;
 pub(crate) mod promise_jobs;
 pub(crate) mod promise_reaction_records;
 pub(crate) mod promise_resolving_functions;
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::{call_function, construct, get},
+            testing_and_comparison::{is_callable, is_constructor, same_value},
+        },
+        builtins::{ArgumentsList, promise::Promise},
+        execution::{Agent, JsResult, agent::ExceptionType},
+        types::{
+            BUILTIN_STRING_MEMORY, Function, IntoFunction, IntoObject, IntoValue, Object, Value,
+        },
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+    heap::CreateHeapData,
+};
+
+use promise_capability_records::PromiseCapability;
+use promise_collector_functions::{PromiseCollectorFunctionHeapData, PromiseCollectorFunctionType};
+use promise_resolving_functions::{PromiseResolvingFunctionHeapData, PromiseResolvingFunctionType};
+
+/// ### [27.2.1.5 NewPromiseCapability ( C )](https://tc39.es/ecma262/#sec-newpromisecapability)
+///
+/// The abstract operation NewPromiseCapability takes argument C (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing a PromiseCapability Record or a throw completion. It attempts
+/// to use C as a constructor in the fashion of the built-in Promise
+/// constructor to create a promise and extract its resolve and reject
+/// functions.
+///
+/// Returns the \[\[Promise]], \[\[Resolve]] and \[\[Reject]] fields of the
+/// PromiseCapability Record. When C is %Promise%, the capability is created
+/// directly without calling the constructor: this is unobservable.
+pub(crate) fn new_promise_capability<'a>(
+    agent: &mut Agent,
+    c: Value,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, (Object<'a>, Function<'a>, Function<'a>)> {
+    let c = c.bind(gc.nogc());
+    // 1. If IsConstructor(C) is false, throw a TypeError exception.
+    let Some(c) = is_constructor(agent, c) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Expected a constructor",
+            gc.into_nogc(),
+        ));
+    };
+    if c == agent
+        .current_realm_record()
+        .intrinsics()
+        .promise()
+        .into_function()
+    {
+        let gc = gc.into_nogc();
+        let promise_capability = PromiseCapability::new(agent, gc);
+        let resolve = agent.heap.create(PromiseResolvingFunctionHeapData {
+            object_index: None,
+            promise_capability: promise_capability.clone(),
+            resolve_type: PromiseResolvingFunctionType::Resolve,
+        });
+        let reject = agent.heap.create(PromiseResolvingFunctionHeapData {
+            object_index: None,
+            promise_capability: promise_capability.clone(),
+            resolve_type: PromiseResolvingFunctionType::Reject,
+        });
+        return Ok((
+            promise_capability.promise().into_object(),
+            resolve.into_function().bind(gc),
+            reject.into_function().bind(gc),
+        ));
+    }
+    // 2. NOTE: C is assumed to be a constructor function that supports the
+    //    parameter conventions of the Promise constructor.
+    // 3. Let resolvingFunctions be the Record { [[Resolve]]: undefined,
+    //    [[Reject]]: undefined }.
+    // 4. Let executorClosure be a new Abstract Closure with parameters
+    //    (resolve, reject) that captures resolvingFunctions.
+    // 5. Let executor be CreateBuiltinFunction(executorClosure, 2, "", « »).
+    let executor = agent.heap.create(PromiseCollectorFunctionHeapData {
+        object_index: None,
+        collector_type: PromiseCollectorFunctionType::GetCapabilitiesExecutor {
+            resolve: Value::Undefined,
+            reject: Value::Undefined,
+        },
+    });
+    let scoped_executor = executor.scope(agent, gc.nogc());
+    // 6. Let promise be ? Construct(C, « executor »).
+    let promise = construct(
+        agent,
+        c.unbind(),
+        Some(ArgumentsList::from_mut_slice(&mut [executor
+            .into_value()
+            .unbind()])),
+        None,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let promise = promise.bind(gc);
+    let PromiseCollectorFunctionType::GetCapabilitiesExecutor { resolve, reject } =
+        agent[scoped_executor.get(agent)].collector_type
+    else {
+        unreachable!()
+    };
+    // 7. If IsCallable(resolvingFunctions.[[Resolve]]) is false, throw a
+    //    TypeError exception.
+    // 8. If IsCallable(resolvingFunctions.[[Reject]]) is false, throw a
+    //    TypeError exception.
+    let (Some(resolve), Some(reject)) = (is_callable(resolve, gc), is_callable(reject, gc)) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Promise resolve or reject function is not callable",
+            gc,
+        ));
+    };
+    // 9. Return the PromiseCapability Record { [[Promise]]: promise,
+    //    [[Resolve]]: resolvingFunctions.[[Resolve]], [[Reject]]:
+    //    resolvingFunctions.[[Reject]] }.
+    Ok((promise, resolve, reject))
+}
+
+/// ### [27.2.4.7.1 PromiseResolve ( C, x )](https://tc39.es/ecma262/#sec-promise-resolve)
+///
+/// The abstract operation PromiseResolve takes arguments C (an Object) and x
+/// (an ECMAScript language value) and returns either a normal completion
+/// containing an Object or a throw completion. It returns a new promise
+/// resolved with x.
+pub(crate) fn promise_resolve<'a>(
+    agent: &mut Agent,
+    c: Object,
+    x: Value,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Object<'a>> {
+    let c = c.bind(gc.nogc());
+    let x = x.bind(gc.nogc());
+    if c == agent
+        .current_realm_record()
+        .intrinsics()
+        .promise()
+        .into_object()
+    {
+        return Ok(Promise::resolve(agent, x.unbind(), gc).into_object());
+    }
+    let scoped_c = c.scope(agent, gc.nogc());
+    let scoped_x = x.scope(agent, gc.nogc());
+    // 1. If IsPromise(x) is true, then
+    if let Value::Promise(x) = x {
+        // a. Let xConstructor be ? Get(x, "constructor").
+        let x_constructor = get(
+            agent,
+            x.unbind(),
+            BUILTIN_STRING_MEMORY.constructor.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // b. If SameValue(xConstructor, C) is true, return x.
+        if same_value(agent, x_constructor, scoped_c.get(agent)) {
+            let gc = gc.into_nogc();
+            return Ok(scoped_x.get(agent).bind(gc).try_into().unwrap());
+        }
+    }
+    // 2. Let promiseCapability be ? NewPromiseCapability(C).
+    let (promise, resolve) =
+        match new_promise_capability(agent, scoped_c.get(agent).into_value(), gc.reborrow()) {
+            Ok((promise, resolve, _)) => (promise.unbind(), resolve.unbind()),
+            Err(err) => return Err(err.unbind().bind(gc.into_nogc())),
+        };
+    let promise = promise.scope(agent, gc.nogc());
+    // 3. Perform ? Call(promiseCapability.[[Resolve]], undefined, « x »).
+    call_function(
+        agent,
+        resolve.unbind(),
+        Value::Undefined,
+        Some(ArgumentsList::from_mut_slice(&mut [scoped_x.get(agent)])),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 4. Return promiseCapability.[[Promise]].
+    Ok(promise.get(agent).bind(gc.into_nogc()))
+}
@@ -0,0 +1,657 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_objects::{call_function, invoke},
+        builtins::ArgumentsList,
+        execution::{
+            Agent, JsResult, ProtoIntrinsics,
+            agent::{ExceptionType, JsError},
+        },
+        types::{
+            BUILTIN_STRING_MEMORY, Function, FunctionInternalProperties, InternalMethods,
+            InternalSlots, IntoFunction, IntoObject, IntoValue, Object, OrdinaryObject,
+            PropertyDescriptor, PropertyKey, String, Value, function_create_backing_object,
+            function_internal_define_own_property, function_internal_delete, function_internal_get,
+            function_internal_get_own_property, function_internal_has_property,
+            function_internal_own_property_keys, function_internal_set, function_try_get,
+            function_try_has_property, function_try_set,
+        },
+    },
+    engine::{
+        Scoped, TryResult,
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable, Scopable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, ObjectEntry, WorkQueues,
+        indexes::BaseIndex,
+    },
+};
+
+use super::{promise_group_records::PromiseGroup, promise_resolve};
+
+/// The anonymous built-in functions created by the Promise combinators and by
+/// `Promise.prototype.finally`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PromiseCollectorFunctionType<'a> {
+    /// ### [27.2.4.1.3 Promise.all Resolve Element Functions](https://tc39.es/ecma262/#sec-promise.all-resolve-element-functions)
+    AllResolveElement {
+        promise_group: PromiseGroup<'a>,
+        index: u32,
+    },
+    /// ### [27.2.4.2.2 Promise.allSettled Resolve Element Functions](https://tc39.es/ecma262/#sec-promise.allsettled-resolve-element-functions)
+    AllSettledResolveElement {
+        promise_group: PromiseGroup<'a>,
+        index: u32,
+    },
+    /// ### [27.2.4.2.3 Promise.allSettled Reject Element Functions](https://tc39.es/ecma262/#sec-promise.allsettled-reject-element-functions)
+    AllSettledRejectElement {
+        promise_group: PromiseGroup<'a>,
+        index: u32,
+    },
+    /// ### [27.2.4.3.2 Promise.any Reject Element Functions](https://tc39.es/ecma262/#sec-promise.any-reject-element-functions)
+    AnyRejectElement {
+        promise_group: PromiseGroup<'a>,
+        index: u32,
+    },
+    /// The thenFinally closure of
+    /// [27.2.5.3 Promise.prototype.finally](https://tc39.es/ecma262/#sec-promise.prototype.finally).
+    ThenFinally {
+        on_finally: Function<'a>,
+        constructor: Function<'a>,
+    },
+    /// The catchFinally closure of
+    /// [27.2.5.3 Promise.prototype.finally](https://tc39.es/ecma262/#sec-promise.prototype.finally).
+    CatchFinally {
+        on_finally: Function<'a>,
+        constructor: Function<'a>,
+    },
+    /// The valueThunk function created by thenFinally: returns the captured
+    /// value.
+    ValueThunk(Value<'a>),
+    /// The thrower function created by catchFinally: throws the captured
+    /// reason.
+    Thrower(Value<'a>),
+    /// ### [27.2.1.5.1 GetCapabilitiesExecutor Functions](https://tc39.es/ecma262/#sec-getcapabilitiesexecutor-functions)
+    ///
+    /// The executor passed to a Promise subclass constructor by
+    /// NewPromiseCapability(C). The resolve and reject arguments it is called
+    /// with are stored here until the constructor returns.
+    GetCapabilitiesExecutor {
+        resolve: Value<'a>,
+        reject: Value<'a>,
+    },
+}
+
+/// A Promise.all, Promise.allSettled or Promise.any element function, one of
+/// the closures of Promise.prototype.finally, or a GetCapabilitiesExecutor
+/// function.
+///
+/// The "length" property of these functions is 1𝔽, except for the valueThunk
+/// and thrower functions of Promise.prototype.finally whose "length" is +0𝔽,
+/// and GetCapabilitiesExecutor functions whose "length" is 2𝔽.
+#[derive(Debug, Clone)]
+pub struct PromiseCollectorFunctionHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    pub(crate) collector_type: PromiseCollectorFunctionType<'a>,
+}
+
+pub(crate) type BuiltinPromiseCollectorFunctionIndex<'a> =
+    BaseIndex<'a, PromiseCollectorFunctionHeapData<'static>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BuiltinPromiseCollectorFunction<'a>(pub(crate) BuiltinPromiseCollectorFunctionIndex<'a>);
+
+impl BuiltinPromiseCollectorFunction<'_> {
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, BuiltinPromiseCollectorFunction<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(BaseIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for BuiltinPromiseCollectorFunction<'_> {
+    type Of<'a> = BuiltinPromiseCollectorFunction<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> From<BuiltinPromiseCollectorFunction<'a>> for Function<'a> {
+    fn from(value: BuiltinPromiseCollectorFunction<'a>) -> Self {
+        Self::BuiltinPromiseCollectorFunction(value)
+    }
+}
+
+impl<'a> IntoFunction<'a> for BuiltinPromiseCollectorFunction<'a> {
+    fn into_function(self) -> Function<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<BuiltinPromiseCollectorFunction<'a>> for Object<'a> {
+    fn from(value: BuiltinPromiseCollectorFunction) -> Self {
+        Self::BuiltinPromiseCollectorFunction(value.unbind())
+    }
+}
+
+impl<'a> IntoObject<'a> for BuiltinPromiseCollectorFunction<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<BuiltinPromiseCollectorFunction<'a>> for Value<'a> {
+    fn from(value: BuiltinPromiseCollectorFunction<'a>) -> Self {
+        Self::BuiltinPromiseCollectorFunction(value)
+    }
+}
+
+impl<'a> IntoValue<'a> for BuiltinPromiseCollectorFunction<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseCollectorFunction<'a> {
+    fn get_name(self, _: &Agent) -> String<'static> {
+        String::EMPTY_STRING
+    }
+
+    fn get_length(self, agent: &Agent) -> u8 {
+        match agent[self].collector_type {
+            PromiseCollectorFunctionType::ValueThunk(_)
+            | PromiseCollectorFunctionType::Thrower(_) => 0,
+            PromiseCollectorFunctionType::GetCapabilitiesExecutor { .. } => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for BuiltinPromiseCollectorFunction<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::Function;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self].object_index.replace(backing_object).is_none());
+    }
+
+    fn create_backing_object(self, agent: &mut Agent) -> OrdinaryObject<'static> {
+        function_create_backing_object(self, agent)
+    }
+}
+
+impl<'a> InternalMethods<'a> for BuiltinPromiseCollectorFunction<'a> {
+    fn try_get_own_property<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: NoGcScope<'gc, '_>,
+    ) -> TryResult<Option<PropertyDescriptor<'gc>>> {
+        TryResult::Continue(function_internal_get_own_property(
+            self,
+            agent,
+            property_key,
+            gc,
+        ))
+    }
+
+    fn try_define_own_property(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        property_descriptor: PropertyDescriptor,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        TryResult::Continue(function_internal_define_own_property(
+            self,
+            agent,
+            property_key,
+            property_descriptor,
+            gc,
+        ))
+    }
+
+    fn try_has_property(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        function_try_has_property(self, agent, property_key, gc)
+    }
+
+    fn internal_has_property<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, bool> {
+        function_internal_has_property(self, agent, property_key, gc)
+    }
+
+    fn try_get<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        receiver: Value,
+        gc: NoGcScope<'gc, '_>,
+    ) -> TryResult<Value<'gc>> {
+        function_try_get(self, agent, property_key, receiver, gc)
+    }
+
+    fn internal_get<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        receiver: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        function_internal_get(self, agent, property_key, receiver, gc)
+    }
+
+    fn try_set(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        value: Value,
+        receiver: Value,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        function_try_set(self, agent, property_key, value, receiver, gc)
+    }
+
+    fn internal_set<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        value: Value,
+        receiver: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, bool> {
+        function_internal_set(self, agent, property_key, value, receiver, gc)
+    }
+
+    fn try_delete(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        TryResult::Continue(function_internal_delete(self, agent, property_key, gc))
+    }
+
+    fn try_own_property_keys<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> TryResult<Vec<PropertyKey<'gc>>> {
+        TryResult::Continue(function_internal_own_property_keys(self, agent, gc))
+    }
+
+    fn internal_call<'gc>(
+        self,
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments_list: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let x = arguments_list.get(0).bind(gc.nogc());
+        match agent[self].collector_type {
+            PromiseCollectorFunctionType::AllResolveElement {
+                promise_group,
+                index,
+            }
+            | PromiseCollectorFunctionType::AnyRejectElement {
+                promise_group,
+                index,
+            } => {
+                promise_group.settle_element(agent, index, x.unbind(), gc)?;
+                // 12. Return undefined.
+                Ok(Value::Undefined)
+            }
+            PromiseCollectorFunctionType::AllSettledResolveElement {
+                promise_group,
+                index,
+            } => {
+                if !promise_group.already_called(agent, index) {
+                    // 9. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+                    // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", "fulfilled").
+                    // 11. Perform ! CreateDataPropertyOrThrow(obj, "value", x).
+                    let obj = create_settled_object(
+                        agent,
+                        BUILTIN_STRING_MEMORY.fulfilled,
+                        BUILTIN_STRING_MEMORY.value.into(),
+                        x,
+                    );
+                    // 12. Set values[index] to obj.
+                    promise_group.settle_element(agent, index, obj.into_value().unbind(), gc)?;
+                }
+                // 15. Return undefined.
+                Ok(Value::Undefined)
+            }
+            PromiseCollectorFunctionType::AllSettledRejectElement {
+                promise_group,
+                index,
+            } => {
+                if !promise_group.already_called(agent, index) {
+                    // 9. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+                    // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", "rejected").
+                    // 11. Perform ! CreateDataPropertyOrThrow(obj, "reason", x).
+                    let obj = create_settled_object(
+                        agent,
+                        BUILTIN_STRING_MEMORY.rejected,
+                        BUILTIN_STRING_MEMORY.reason.into(),
+                        x,
+                    );
+                    // 12. Set values[index] to obj.
+                    promise_group.settle_element(agent, index, obj.into_value().unbind(), gc)?;
+                }
+                // 15. Return undefined.
+                Ok(Value::Undefined)
+            }
+            PromiseCollectorFunctionType::ThenFinally {
+                on_finally,
+                constructor,
+            }
+            | PromiseCollectorFunctionType::CatchFinally {
+                on_finally,
+                constructor,
+            } => {
+                let is_then_finally = matches!(
+                    agent[self].collector_type,
+                    PromiseCollectorFunctionType::ThenFinally { .. }
+                );
+                let x = x.scope(agent, gc.nogc());
+                let constructor = constructor.scope(agent, gc.nogc());
+                // i. Let result be ? Call(onFinally, undefined).
+                let result =
+                    call_function(agent, on_finally, Value::Undefined, None, gc.reborrow())
+                        .unbind()?
+                        .bind(gc.nogc());
+                // ii. Let p be ? PromiseResolve(C, result).
+                let p = promise_resolve(
+                    agent,
+                    constructor.get(agent).into_object(),
+                    result.unbind(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .scope(agent, gc.nogc());
+                // iii. Let returnValue be a new Abstract Closure with no
+                //      parameters that captures value and performs the
+                //      following steps when called:
+                //     1. Return NormalCompletion(value).
+                // iii. Let throwReason be a new Abstract Closure with no
+                //      parameters that captures reason and performs the
+                //      following steps when called:
+                //     1. Return ThrowCompletion(reason).
+                // iv. Let valueThunk be CreateBuiltinFunction(returnValue, 0, "", « »).
+                // iv. Let thrower be CreateBuiltinFunction(throwReason, 0, "", « »).
+                let collector_type = if is_then_finally {
+                    PromiseCollectorFunctionType::ValueThunk(x.get(agent))
+                } else {
+                    PromiseCollectorFunctionType::Thrower(x.get(agent))
+                };
+                let thunk = agent.heap.create(PromiseCollectorFunctionHeapData {
+                    object_index: None,
+                    collector_type,
+                });
+                // v. Return ? Invoke(p, "then", « valueThunk »).
+                // v. Return ? Invoke(p, "then", « thrower »).
+                invoke(
+                    agent,
+                    p.get(agent).into_value(),
+                    BUILTIN_STRING_MEMORY.then.into(),
+                    Some(ArgumentsList::from_mut_slice(&mut [thunk
+                        .into_value()
+                        .unbind()])),
+                    gc,
+                )
+            }
+            // 1. Return NormalCompletion(value).
+            PromiseCollectorFunctionType::ValueThunk(value) => Ok(value),
+            // 1. Return ThrowCompletion(reason).
+            PromiseCollectorFunctionType::Thrower(reason) => Err(JsError::new(reason)),
+            PromiseCollectorFunctionType::GetCapabilitiesExecutor {
+                resolve: previous_resolve,
+                reject: previous_reject,
+            } => {
+                // a. If promiseCapability.[[Resolve]] is not undefined, throw
+                //    a TypeError exception.
+                // b. If promiseCapability.[[Reject]] is not undefined, throw
+                //    a TypeError exception.
+                if !previous_resolve.is_undefined() || !previous_reject.is_undefined() {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "Promise executor has already been invoked",
+                        gc.into_nogc(),
+                    ));
+                }
+                // c. Set promiseCapability.[[Resolve]] to resolve.
+                // d. Set promiseCapability.[[Reject]] to reject.
+                agent[self].collector_type =
+                    PromiseCollectorFunctionType::GetCapabilitiesExecutor {
+                        resolve: x.unbind(),
+                        reject: arguments_list.get(1).unbind(),
+                    };
+                // e. Return undefined.
+                Ok(Value::Undefined)
+            }
+        }
+    }
+}
+
+/// Creates the `{ status, value }` or `{ status, reason }` object that
+/// `Promise.allSettled` collects for each element.
+fn create_settled_object<'a>(
+    agent: &mut Agent,
+    status: String<'static>,
+    key: PropertyKey<'static>,
+    x: Value<'a>,
+) -> OrdinaryObject<'a> {
+    let object_prototype = agent
+        .current_realm_record()
+        .intrinsics()
+        .object_prototype()
+        .into_object();
+    agent.heap.create_object_with_prototype(
+        object_prototype,
+        &[
+            ObjectEntry::new_data_entry(BUILTIN_STRING_MEMORY.status.into(), status.into_value()),
+            ObjectEntry::new_data_entry(key, x.unbind()),
+        ],
+    )
+}
+
+impl Index<BuiltinPromiseCollectorFunction<'_>> for Agent {
+    type Output = PromiseCollectorFunctionHeapData<'static>;
+
+    fn index(&self, index: BuiltinPromiseCollectorFunction) -> &Self::Output {
+        &self.heap.promise_collector_functions[index]
+    }
+}
+
+impl IndexMut<BuiltinPromiseCollectorFunction<'_>> for Agent {
+    fn index_mut(&mut self, index: BuiltinPromiseCollectorFunction) -> &mut Self::Output {
+        &mut self.heap.promise_collector_functions[index]
+    }
+}
+
+impl Index<BuiltinPromiseCollectorFunction<'_>>
+    for Vec<Option<PromiseCollectorFunctionHeapData<'static>>>
+{
+    type Output = PromiseCollectorFunctionHeapData<'static>;
+
+    fn index(&self, index: BuiltinPromiseCollectorFunction) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("BuiltinPromiseCollectorFunction out of bounds")
+            .as_ref()
+            .expect("BuiltinPromiseCollectorFunction slot empty")
+    }
+}
+
+impl IndexMut<BuiltinPromiseCollectorFunction<'_>>
+    for Vec<Option<PromiseCollectorFunctionHeapData<'static>>>
+{
+    fn index_mut(&mut self, index: BuiltinPromiseCollectorFunction) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("BuiltinPromiseCollectorFunction out of bounds")
+            .as_mut()
+            .expect("BuiltinPromiseCollectorFunction slot empty")
+    }
+}
+
+impl Rootable for BuiltinPromiseCollectorFunction<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::BuiltinPromiseCollectorFunction(
+            value.unbind(),
+        ))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::BuiltinPromiseCollectorFunction(d) => Some(d),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> CreateHeapData<PromiseCollectorFunctionHeapData<'a>, BuiltinPromiseCollectorFunction<'a>>
+    for Heap
+{
+    fn create(
+        &mut self,
+        data: PromiseCollectorFunctionHeapData<'a>,
+    ) -> BuiltinPromiseCollectorFunction<'a> {
+        self.promise_collector_functions.push(Some(data.unbind()));
+        self.alloc_counter +=
+            core::mem::size_of::<Option<PromiseCollectorFunctionHeapData<'static>>>();
+
+        BuiltinPromiseCollectorFunction(BaseIndex::last(&self.promise_collector_functions))
+    }
+}
+
+impl HeapMarkAndSweep for BuiltinPromiseCollectorFunction<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.promise_collector_functions.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions
+            .promise_collector_functions
+            .shift_index(&mut self.0);
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PromiseCollectorFunctionHeapData<'_> {
+    type Of<'a> = PromiseCollectorFunctionHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for PromiseCollectorFunctionHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        self.object_index.mark_values(queues);
+        match &self.collector_type {
+            PromiseCollectorFunctionType::AllResolveElement { promise_group, .. }
+            | PromiseCollectorFunctionType::AllSettledResolveElement { promise_group, .. }
+            | PromiseCollectorFunctionType::AllSettledRejectElement { promise_group, .. }
+            | PromiseCollectorFunctionType::AnyRejectElement { promise_group, .. } => {
+                promise_group.mark_values(queues)
+            }
+            PromiseCollectorFunctionType::ThenFinally {
+                on_finally,
+                constructor,
+            }
+            | PromiseCollectorFunctionType::CatchFinally {
+                on_finally,
+                constructor,
+            } => {
+                on_finally.mark_values(queues);
+                constructor.mark_values(queues);
+            }
+            PromiseCollectorFunctionType::ValueThunk(value)
+            | PromiseCollectorFunctionType::Thrower(value) => value.mark_values(queues),
+            PromiseCollectorFunctionType::GetCapabilitiesExecutor { resolve, reject } => {
+                resolve.mark_values(queues);
+                reject.mark_values(queues);
+            }
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        self.object_index.sweep_values(compactions);
+        match &mut self.collector_type {
+            PromiseCollectorFunctionType::AllResolveElement { promise_group, .. }
+            | PromiseCollectorFunctionType::AllSettledResolveElement { promise_group, .. }
+            | PromiseCollectorFunctionType::AllSettledRejectElement { promise_group, .. }
+            | PromiseCollectorFunctionType::AnyRejectElement { promise_group, .. } => {
+                promise_group.sweep_values(compactions)
+            }
+            PromiseCollectorFunctionType::ThenFinally {
+                on_finally,
+                constructor,
+            }
+            | PromiseCollectorFunctionType::CatchFinally {
+                on_finally,
+                constructor,
+            } => {
+                on_finally.sweep_values(compactions);
+                constructor.sweep_values(compactions);
+            }
+            PromiseCollectorFunctionType::ValueThunk(value)
+            | PromiseCollectorFunctionType::Thrower(value) => value.sweep_values(compactions),
+            PromiseCollectorFunctionType::GetCapabilitiesExecutor { resolve, reject } => {
+                resolve.sweep_values(compactions);
+                reject.sweep_values(compactions);
+            }
+        }
+    }
+}
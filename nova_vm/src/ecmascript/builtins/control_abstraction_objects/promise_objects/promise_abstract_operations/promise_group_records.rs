@@ -0,0 +1,320 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Promise group records
+//!
+//! The state shared by the element functions of a single `Promise.all`,
+//! `Promise.allSettled` or `Promise.any` call.
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_objects::{
+            call_function, create_array_from_list, try_define_property_or_throw,
+        },
+        builtins::{ArgumentsList, error::ErrorHeapData},
+        execution::{Agent, JsResult, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoValue, PropertyDescriptor, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+        unwrap_try,
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues, indexes::BaseIndex,
+    },
+};
+
+/// The Promise combinator that a promise group was created by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PromiseGroupType {
+    All,
+    AllSettled,
+    Any,
+}
+
+#[derive(Debug, Clone)]
+pub struct PromiseGroupRecord<'a> {
+    pub(crate) group_type: PromiseGroupType,
+    /// ### values / errors
+    ///
+    /// The List of values (or errors, for `Promise.any`) collected by the
+    /// element functions, in iteration order. An entry is None until its
+    /// element function has been called: this doubles as the
+    /// \[\[AlreadyCalled\]\] state of the element functions at that index.
+    pub(crate) values: Vec<Option<Value<'a>>>,
+    /// ### remainingElementsCount.\[\[Value\]\]
+    pub(crate) remaining_elements_count: u32,
+    /// ### \[\[Capability\]\].\[\[Resolve\]\]
+    pub(crate) resolve: Function<'a>,
+    /// ### \[\[Capability\]\].\[\[Reject\]\]
+    pub(crate) reject: Function<'a>,
+}
+
+impl<'a> PromiseGroupRecord<'a> {
+    pub(crate) fn new(
+        group_type: PromiseGroupType,
+        resolve: Function<'a>,
+        reject: Function<'a>,
+    ) -> Self {
+        Self {
+            group_type,
+            values: vec![],
+            // Let remainingElementsCount be the Record { [[Value]]: 1 }.
+            remaining_elements_count: 1,
+            resolve,
+            reject,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct PromiseGroup<'a>(BaseIndex<'a, PromiseGroupRecord<'static>>);
+
+impl PromiseGroup<'_> {
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    /// Returns true if an element function for the given index has already
+    /// been called.
+    pub(crate) fn already_called(self, agent: &Agent, index: u32) -> bool {
+        agent[self].values[index as usize].is_some()
+    }
+
+    /// The common steps of the Promise.all Resolve Element Functions, the
+    /// Promise.allSettled Resolve and Reject Element Functions and the
+    /// Promise.any Reject Element Functions.
+    pub(crate) fn settle_element<'gc>(
+        self,
+        agent: &mut Agent,
+        index: u32,
+        x: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        let record = &mut agent[self];
+        let slot = &mut record.values[index as usize];
+        // 2. Let alreadyCalled be F.[[AlreadyCalled]].
+        // 3. If alreadyCalled.[[Value]] is true, return undefined.
+        if slot.is_some() {
+            return Ok(());
+        }
+        // 4. Set alreadyCalled.[[Value]] to true.
+        // 9. Set values[index] to x.
+        *slot = Some(x.unbind());
+        // 10. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+        // 11. If remainingElementsCount.[[Value]] = 0, then
+        self.decrement_remaining_elements_count(agent, gc)
+    }
+
+    /// Decrements remainingElementsCount and settles the group's promise once
+    /// it reaches zero: `Promise.all` and `Promise.allSettled` fulfill it with
+    /// the array of values, `Promise.any` rejects it with an AggregateError.
+    pub(crate) fn decrement_remaining_elements_count<'gc>(
+        self,
+        agent: &mut Agent,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        let record = &mut agent[self];
+        record.remaining_elements_count -= 1;
+        if record.remaining_elements_count > 0 {
+            return Ok(());
+        }
+        let group_type = record.group_type;
+        let resolve = record.resolve;
+        let reject = record.reject;
+        let values = record
+            .values
+            .iter()
+            .map(|value| value.unwrap())
+            .collect::<Vec<_>>();
+        // a. Let valuesArray be CreateArrayFromList(values).
+        let values_array = create_array_from_list(agent, &values, gc.nogc());
+        match group_type {
+            PromiseGroupType::All | PromiseGroupType::AllSettled => {
+                // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+                call_function(
+                    agent,
+                    resolve,
+                    Value::Undefined,
+                    Some(ArgumentsList::from_mut_slice(&mut [values_array
+                        .into_value()
+                        .unbind()])),
+                    gc,
+                )?;
+            }
+            PromiseGroupType::Any => {
+                // a. Let error be a newly created AggregateError object.
+                let error = agent.heap.create(ErrorHeapData::new(
+                    ExceptionType::AggregateError,
+                    None,
+                    None,
+                ));
+                // b. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+                unwrap_try(try_define_property_or_throw(
+                    agent,
+                    error,
+                    BUILTIN_STRING_MEMORY.errors.into(),
+                    PropertyDescriptor {
+                        value: Some(values_array.into_value().unbind()),
+                        writable: Some(true),
+                        enumerable: Some(false),
+                        configurable: Some(true),
+                        ..Default::default()
+                    },
+                    gc.nogc(),
+                ))
+                .unwrap();
+                // c. Return ? Call(promiseCapability.[[Reject]], undefined, « error »).
+                call_function(
+                    agent,
+                    reject,
+                    Value::Undefined,
+                    Some(ArgumentsList::from_mut_slice(&mut [error.into_value()])),
+                    gc,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Index<PromiseGroup<'_>> for Agent {
+    type Output = PromiseGroupRecord<'static>;
+
+    fn index(&self, index: PromiseGroup) -> &Self::Output {
+        &self.heap.promise_group_records[index]
+    }
+}
+
+impl IndexMut<PromiseGroup<'_>> for Agent {
+    fn index_mut(&mut self, index: PromiseGroup) -> &mut Self::Output {
+        &mut self.heap.promise_group_records[index]
+    }
+}
+
+impl Index<PromiseGroup<'_>> for Vec<Option<PromiseGroupRecord<'static>>> {
+    type Output = PromiseGroupRecord<'static>;
+
+    fn index(&self, index: PromiseGroup) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("PromiseGroup out of bounds")
+            .as_ref()
+            .expect("PromiseGroup slot empty")
+    }
+}
+
+impl IndexMut<PromiseGroup<'_>> for Vec<Option<PromiseGroupRecord<'static>>> {
+    fn index_mut(&mut self, index: PromiseGroup) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("PromiseGroup out of bounds")
+            .as_mut()
+            .expect("PromiseGroup slot empty")
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PromiseGroup<'_> {
+    type Of<'a> = PromiseGroup<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for PromiseGroup<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.promise_group_records.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.promise_group_records.shift_index(&mut self.0);
+    }
+}
+
+impl Rootable for PromiseGroup<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::PromiseGroup(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        if let HeapRootData::PromiseGroup(data) = heap_data {
+            Some(data)
+        } else {
+            None
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PromiseGroupRecord<'_> {
+    type Of<'a> = PromiseGroupRecord<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for PromiseGroupRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            group_type: _,
+            values,
+            remaining_elements_count: _,
+            resolve,
+            reject,
+        } = self;
+        values.iter().for_each(|value| value.mark_values(queues));
+        resolve.mark_values(queues);
+        reject.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            group_type: _,
+            values,
+            remaining_elements_count: _,
+            resolve,
+            reject,
+        } = self;
+        values
+            .iter_mut()
+            .for_each(|value| value.sweep_values(compactions));
+        resolve.sweep_values(compactions);
+        reject.sweep_values(compactions);
+    }
+}
+
+impl<'a> CreateHeapData<PromiseGroupRecord<'a>, PromiseGroup<'a>> for Heap {
+    fn create(&mut self, data: PromiseGroupRecord<'a>) -> PromiseGroup<'a> {
+        self.promise_group_records.push(Some(data.unbind()));
+        self.alloc_counter += core::mem::size_of::<Option<PromiseGroupRecord<'static>>>();
+        PromiseGroup(BaseIndex::last(&self.promise_group_records))
+    }
+}
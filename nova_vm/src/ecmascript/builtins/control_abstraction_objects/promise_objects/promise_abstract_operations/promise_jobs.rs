@@ -4,17 +4,33 @@
 
 //! ## [27.2.2 Promise Jobs](https://tc39.es/ecma262/#sec-promise-jobs)
 
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::abstract_operations::operations_on_disposable_objects::dispose_resources_resume;
 use crate::engine::Global;
 use crate::engine::context::{Bindable, GcScope, NoGcScope};
 use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
-        abstract_operations::operations_on_objects::{call_function, get_function_realm},
+        abstract_operations::{
+            operations_on_iterator_objects::{
+                create_iter_result_object, iterator_close_with_error,
+            },
+            operations_on_objects::{call_function, get_function_realm},
+        },
         builtins::{ArgumentsList, promise::Promise},
         execution::{
             Agent, JsResult,
             agent::{InnerJob, Job, JsError},
         },
+        scripts_and_modules::module::{
+            import_calls::{
+                dynamic_import_link_and_evaluate, dynamic_import_reject,
+                dynamic_import_resolve_namespace,
+            },
+            module_semantics::cyclic_module_records::{
+                async_module_execution_fulfilled, async_module_execution_rejected,
+            },
+        },
         types::{Function, IntoValue, Object, Value},
     },
     heap::CreateHeapData,
@@ -188,6 +204,114 @@ impl PromiseReactionJob {
                 );
                 return Ok(());
             }
+            PromiseReactionHandler::AsyncModule(module) => {
+                assert!(agent[reaction].capability.is_none());
+                match agent[reaction].reaction_type {
+                    PromiseReactionType::Fulfill => {
+                        // a. Perform AsyncModuleExecutionFulfilled(module).
+                        async_module_execution_fulfilled(agent, module.unbind(), gc.reborrow());
+                    }
+                    PromiseReactionType::Reject => {
+                        // a. Perform AsyncModuleExecutionRejected(module, error).
+                        async_module_execution_rejected(agent, module, argument, gc.nogc());
+                    }
+                }
+                // b. Return undefined.
+                return Ok(());
+            }
+            PromiseReactionHandler::DynamicImport { promise, module } => {
+                assert!(agent[reaction].capability.is_none());
+                match agent[reaction].reaction_type {
+                    PromiseReactionType::Fulfill => {
+                        // Perform the linkAndEvaluateClosure steps.
+                        dynamic_import_link_and_evaluate(
+                            agent,
+                            promise.unbind(),
+                            module.unbind(),
+                            gc.reborrow(),
+                        );
+                    }
+                    PromiseReactionType::Reject => {
+                        // Perform the rejectedClosure steps.
+                        dynamic_import_reject(agent, promise, argument, gc.nogc());
+                    }
+                }
+                return Ok(());
+            }
+            PromiseReactionHandler::DynamicImportEvaluate { promise, module } => {
+                assert!(agent[reaction].capability.is_none());
+                match agent[reaction].reaction_type {
+                    PromiseReactionType::Fulfill => {
+                        // Perform the fulfilledClosure steps.
+                        dynamic_import_resolve_namespace(
+                            agent,
+                            promise.unbind(),
+                            module.unbind(),
+                            gc.reborrow(),
+                        );
+                    }
+                    PromiseReactionType::Reject => {
+                        // Perform the rejectedClosure steps.
+                        dynamic_import_reject(agent, promise, argument, gc.nogc());
+                    }
+                }
+                return Ok(());
+            }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            PromiseReactionHandler::DisposeResources(disposable_stack) => {
+                assert!(agent[reaction].capability.is_none());
+                let reaction_type = agent[reaction].reaction_type;
+                dispose_resources_resume(
+                    agent,
+                    disposable_stack.unbind(),
+                    reaction_type,
+                    argument.unbind(),
+                    gc.reborrow(),
+                );
+                return Ok(());
+            }
+            PromiseReactionHandler::AsyncFromSyncIterator {
+                done,
+                close_iterator,
+            } => match agent[reaction].reaction_type {
+                PromiseReactionType::Fulfill => {
+                    // Perform the unwrap closure steps.
+                    // a. Return CreateIteratorResultObject(v, done).
+                    let result = create_iter_result_object(agent, argument, done, gc.nogc());
+                    (
+                        Ok(result.into_value()),
+                        agent[reaction].capability.clone().unwrap().bind(gc.nogc()),
+                    )
+                }
+                PromiseReactionType::Reject => {
+                    if let Some(sync_iterator) = close_iterator {
+                        // Perform the closeIterator closure steps.
+                        let reaction = reaction.scope(agent, gc.nogc());
+                        // i. Return ? IteratorClose(syncIteratorRecord, ThrowCompletion(error)).
+                        let error = iterator_close_with_error(
+                            agent,
+                            sync_iterator.unbind(),
+                            JsError::new(argument.unbind()),
+                            gc.reborrow(),
+                        )
+                        .unbind()
+                        .bind(gc.nogc());
+                        // SAFETY: reaction is not shared.
+                        let reaction = unsafe { reaction.take(agent) };
+                        (
+                            Err(error),
+                            agent[reaction].capability.clone().unwrap().bind(gc.nogc()),
+                        )
+                    } else {
+                        // onRejected is undefined, so the rejection is passed
+                        // through as with an empty handler.
+                        (
+                            Err(JsError::new(argument.unbind())),
+                            agent[reaction].capability.clone().unwrap().bind(gc.nogc()),
+                        )
+                    }
+                }
+            },
         };
 
         // f. If promiseCapability is undefined, then
@@ -241,8 +365,24 @@ pub(crate) fn new_promise_reaction_job(
                 .unwrap()
                 .realm,
         ),
+        // The closures created in ExecuteAsyncModule are created in the
+        // module's realm.
+        PromiseReactionHandler::AsyncModule(module) => {
+            Some(agent[module].abstract_record.realm.bind(gc))
+        }
+        // The closures created in ContinueDynamicImport are created in the
+        // current realm when the module has finished loading; we use the
+        // imported module's realm instead.
+        PromiseReactionHandler::DynamicImport { module, .. }
+        | PromiseReactionHandler::DynamicImportEvaluate { module, .. } => {
+            Some(module.realm(agent, gc))
+        }
         // 2. Let handlerRealm be null.
-        PromiseReactionHandler::AsyncGenerator(_) | PromiseReactionHandler::Empty => None,
+        PromiseReactionHandler::AsyncGenerator(_)
+        | PromiseReactionHandler::AsyncFromSyncIterator { .. }
+        | PromiseReactionHandler::Empty => None,
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        PromiseReactionHandler::DisposeResources(_) => None,
     };
 
     // 4. Return the Record { [[Job]]: job, [[Realm]]: handlerRealm }.
@@ -4,14 +4,20 @@
 
 use core::ops::{Index, IndexMut};
 
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 use crate::{
     ecmascript::{
         builtins::{
             async_generator_objects::AsyncGenerator,
             control_abstraction_objects::async_function_objects::await_reaction::AwaitReactionIdentifier,
+            promise::Promise,
         },
         execution::Agent,
-        types::Function,
+        scripts_and_modules::module::module_semantics::{
+            abstract_module_records::AbstractModule, source_text_module_records::SourceTextModule,
+        },
+        types::{Function, Object},
     },
     engine::{
         context::{Bindable, NoGcScope},
@@ -49,6 +55,35 @@ pub(crate) enum PromiseReactionHandler<'a> {
     JobCallback(Function<'a>),
     Await(AwaitReactionIdentifier<'a>),
     AsyncGenerator(AsyncGenerator<'a>),
+    /// The onFulfilled and onRejected closures created in
+    /// [ExecuteAsyncModule](https://tc39.es/ecma262/#sec-execute-async-module).
+    AsyncModule(SourceTextModule<'a>),
+    /// The linkAndEvaluate and onRejected closures created in
+    /// [ContinueDynamicImport](https://tc39.es/ecma262/#sec-ContinueDynamicImport).
+    DynamicImport {
+        promise: Promise<'a>,
+        module: AbstractModule<'a>,
+    },
+    /// The onFulfilled and onRejected closures created in the
+    /// linkAndEvaluate closure of
+    /// [ContinueDynamicImport](https://tc39.es/ecma262/#sec-ContinueDynamicImport).
+    DynamicImportEvaluate {
+        promise: Promise<'a>,
+        module: AbstractModule<'a>,
+    },
+    /// The onFulfilled and onRejected closures created in
+    /// [AsyncFromSyncIteratorContinuation](https://tc39.es/ecma262/#sec-asyncfromsynciteratorcontinuation).
+    ///
+    /// If `close_iterator` is None, then onRejected is undefined.
+    AsyncFromSyncIterator {
+        done: bool,
+        close_iterator: Option<Object<'a>>,
+    },
+    /// The Await steps of an asynchronous
+    /// [DisposeResources](https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources)
+    /// call.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposeResources(DisposableStack<'a>),
     Empty,
 }
 
@@ -58,6 +93,18 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
             Self::JobCallback(function) => function.mark_values(queues),
             Self::Await(await_reaction_identifier) => await_reaction_identifier.mark_values(queues),
             Self::AsyncGenerator(async_generator) => async_generator.mark_values(queues),
+            Self::AsyncModule(module) => module.mark_values(queues),
+            Self::DynamicImport { promise, module }
+            | Self::DynamicImportEvaluate { promise, module } => {
+                promise.mark_values(queues);
+                module.mark_values(queues);
+            }
+            Self::AsyncFromSyncIterator {
+                done: _,
+                close_iterator,
+            } => close_iterator.mark_values(queues),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Self::DisposeResources(disposable_stack) => disposable_stack.mark_values(queues),
             Self::Empty => {}
         }
     }
@@ -69,6 +116,18 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
                 await_reaction_identifier.sweep_values(compactions)
             }
             Self::AsyncGenerator(async_generator) => async_generator.sweep_values(compactions),
+            Self::AsyncModule(module) => module.sweep_values(compactions),
+            Self::DynamicImport { promise, module }
+            | Self::DynamicImportEvaluate { promise, module } => {
+                promise.sweep_values(compactions);
+                module.sweep_values(compactions);
+            }
+            Self::AsyncFromSyncIterator {
+                done: _,
+                close_iterator,
+            } => close_iterator.sweep_values(compactions),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Self::DisposeResources(disposable_stack) => disposable_stack.sweep_values(compactions),
             Self::Empty => {}
         }
     }
@@ -2,13 +2,17 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use crate::engine::Scoped;
 use crate::engine::context::{Bindable, GcScope};
 use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
         abstract_operations::{
-            operations_on_objects::{call, call_function},
-            testing_and_comparison::is_constructor,
+            operations_on_iterator_objects::{
+                IteratorRecord, get_iterator, if_abrupt_close_iterator, iterator_step_value,
+            },
+            operations_on_objects::{call, call_function, get, invoke, throw_not_callable},
+            testing_and_comparison::{is_callable, is_constructor},
         },
         builders::builtin_function_builder::BuiltinFunctionBuilder,
         builtins::{
@@ -19,7 +23,10 @@ use crate::{
                 data::{PromiseHeapData, PromiseState},
             },
         },
-        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        execution::{
+            Agent, JsResult, ProtoIntrinsics, Realm,
+            agent::{ExceptionType, JsError},
+        },
         types::{
             BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, PropertyKey, String,
             Value,
@@ -29,7 +36,11 @@ use crate::{
 };
 
 use super::promise_abstract_operations::{
+    new_promise_capability,
     promise_capability_records::PromiseCapability,
+    promise_collector_functions::{PromiseCollectorFunctionHeapData, PromiseCollectorFunctionType},
+    promise_group_records::{PromiseGroupRecord, PromiseGroupType},
+    promise_resolve,
     promise_resolving_functions::{PromiseResolvingFunctionHeapData, PromiseResolvingFunctionType},
 };
 
@@ -122,16 +133,6 @@ impl PromiseConstructor {
         };
         let new_target = new_target.unbind().bind(gc.nogc());
 
-        // We currently don't support Promise subclassing.
-        assert_eq!(
-            new_target,
-            agent
-                .current_realm_record()
-                .intrinsics()
-                .promise()
-                .into_object()
-        );
-
         // 2. If IsCallable(executor) is false, throw a TypeError exception.
         // TODO: Callable proxies
         let Ok(executor) = Function::try_from(executor) else {
@@ -202,71 +203,128 @@ impl PromiseConstructor {
         Ok(scoped_promise.get(agent).into_value())
     }
 
+    /// ### [27.2.4.1 Promise.all ( iterable )](https://tc39.es/ecma262/#sec-promise.all)
+    ///
+    /// This function returns a new promise which is fulfilled with an array
+    /// of fulfillment values for the passed promises, or rejects with the
+    /// reason of the first passed promise that rejects. It resolves all
+    /// elements of the passed iterable to promises as it runs this algorithm.
     fn all<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Promise.all", gc.into_nogc()))
+        promise_combinator(
+            agent,
+            this_value,
+            arguments.get(0),
+            Some(PromiseGroupType::All),
+            gc,
+        )
     }
 
+    /// ### [27.2.4.2 Promise.allSettled ( iterable )](https://tc39.es/ecma262/#sec-promise.allsettled)
+    ///
+    /// This function returns a promise that is fulfilled with an array of
+    /// promise state snapshots, but only after all the original promises have
+    /// settled, i.e. become either fulfilled or rejected. It resolves all
+    /// elements of the passed iterable to promises as it runs this algorithm.
     fn all_settled<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Promise.allSettled", gc.into_nogc()))
+        promise_combinator(
+            agent,
+            this_value,
+            arguments.get(0),
+            Some(PromiseGroupType::AllSettled),
+            gc,
+        )
     }
+
+    /// ### [27.2.4.3 Promise.any ( iterable )](https://tc39.es/ecma262/#sec-promise.any)
+    ///
+    /// This function returns a promise that is fulfilled by the first given
+    /// promise to be fulfilled, or rejected with an AggregateError holding
+    /// the rejection reasons if all of the given promises are rejected. It
+    /// resolves all elements of the passed iterable to promises as it runs
+    /// this algorithm.
     fn any<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Promise.any", gc.into_nogc()))
+        promise_combinator(
+            agent,
+            this_value,
+            arguments.get(0),
+            Some(PromiseGroupType::Any),
+            gc,
+        )
     }
+
+    /// ### [27.2.4.5 Promise.race ( iterable )](https://tc39.es/ecma262/#sec-promise.race)
+    ///
+    /// This function returns a new promise which is settled in the same way
+    /// as the first passed promise to settle. It resolves all elements of the
+    /// passed iterable to promises as it runs this algorithm.
     fn race<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Promise.race", gc.into_nogc()))
+        promise_combinator(agent, this_value, arguments.get(0), None, gc)
     }
 
     fn reject<'gc>(
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
         let r = arguments.get(0).bind(gc.nogc());
-        // We currently don't support Promise subclassing.
-        assert_eq!(
-            this_value,
-            agent
+        // 1. Let C be the this value.
+        if this_value
+            == agent
                 .current_realm_record()
                 .intrinsics()
                 .promise()
                 .into_value()
-        );
-
-        // 1. Let C be the this value.
+        {
+            // NOTE: When C is %Promise%, the below steps are equivalent to
+            // creating an already-rejected promise.
+            let promise = agent.heap.create(PromiseHeapData {
+                object_index: None,
+                promise_state: PromiseState::Rejected {
+                    promise_result: r.unbind(),
+                    is_handled: false,
+                },
+            });
+            return Ok(promise.into_value());
+        }
+        let r = r.scope(agent, gc.nogc());
         // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let (promise, _, reject) = match new_promise_capability(agent, this_value, gc.reborrow()) {
+            Ok((promise, resolve, reject)) => (promise.unbind(), resolve.unbind(), reject.unbind()),
+            Err(err) => return Err(err.unbind().bind(gc.into_nogc())),
+        };
+        let promise = promise.scope(agent, gc.nogc());
         // 3. Perform ? Call(promiseCapability.[[Reject]], undefined, « r »).
+        call_function(
+            agent,
+            reject,
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [r.get(agent)])),
+            gc.reborrow(),
+        )
+        .unbind()?;
         // 4. Return promiseCapability.[[Promise]].
-        // NOTE: Since we don't support promise subclassing, this is equivalent
-        // to creating an already-rejected promise.
-        let promise = agent.heap.create(PromiseHeapData {
-            object_index: None,
-            promise_state: PromiseState::Rejected {
-                promise_result: r.unbind(),
-                is_handled: false,
-            },
-        });
-        Ok(promise.into_value())
+        Ok(promise.get(agent).into_value().bind(gc.into_nogc()))
     }
 
     fn resolve<'gc>(
@@ -275,18 +333,17 @@ impl PromiseConstructor {
         arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        // We currently don't support Promise subclassing.
-        assert_eq!(
-            this_value,
-            agent
-                .current_realm_record()
-                .intrinsics()
-                .promise()
-                .into_value()
-        );
-
+        // 1. Let C be the this value.
+        // 2. If C is not an Object, throw a TypeError exception.
+        let Ok(c) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected the this value to be an object.",
+                gc.into_nogc(),
+            ));
+        };
         // 3. Return ? PromiseResolve(C, x).
-        Ok(Promise::resolve(agent, arguments.get(0), gc).into_value())
+        promise_resolve(agent, c, arguments.get(0), gc).map(|promise| promise.into_value())
     }
 
     /// ### [1 Promise.try ( callbackfn, ...args )](https://tc39.es/proposal-promise-try)
@@ -453,3 +510,319 @@ impl PromiseConstructor {
             .build();
     }
 }
+
+/// ### [27.2.4.1.1 GetPromiseResolve ( promiseConstructor )](https://tc39.es/ecma262/#sec-getpromiseresolve)
+///
+/// The abstract operation GetPromiseResolve takes argument
+/// promiseConstructor (a constructor) and returns either a normal completion
+/// containing a function object or a throw completion.
+fn get_promise_resolve<'gc>(
+    agent: &mut Agent,
+    promise_constructor: Function,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Function<'gc>> {
+    // 1. Let promiseResolve be ? Get(promiseConstructor, "resolve").
+    let promise_resolve = get(
+        agent,
+        promise_constructor,
+        BUILTIN_STRING_MEMORY.resolve.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let promise_resolve = promise_resolve.bind(gc);
+    // 2. If IsCallable(promiseResolve) is false, throw a TypeError exception.
+    let Some(promise_resolve) = is_callable(promise_resolve, gc) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Promise resolve is not callable",
+            gc,
+        ));
+    };
+    // 3. Return promiseResolve.
+    Ok(promise_resolve)
+}
+
+/// The shared steps of Promise.all, Promise.allSettled, Promise.any and
+/// Promise.race. `group_type` is None for Promise.race, which doesn't collect
+/// the values of its elements.
+fn promise_combinator<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    iterable: Value,
+    group_type: Option<PromiseGroupType>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let iterable = iterable.scope(agent, gc.nogc());
+    // 1. Let C be the this value.
+    let Some(c) = is_constructor(agent, this_value) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Expected the this value to be a constructor.",
+            gc.into_nogc(),
+        ));
+    };
+    let c = c.scope(agent, gc.nogc());
+
+    // 2. Let promiseCapability be ? NewPromiseCapability(C).
+    let (promise, resolve, reject) =
+        match new_promise_capability(agent, c.get(agent).into_value(), gc.reborrow()) {
+            Ok((promise, resolve, reject)) => (promise.unbind(), resolve.unbind(), reject.unbind()),
+            Err(err) => return Err(err.unbind().bind(gc.into_nogc())),
+        };
+    let promise = promise.scope(agent, gc.nogc());
+    let resolve = resolve.scope(agent, gc.nogc());
+    let reject = reject.scope(agent, gc.nogc());
+
+    // 3. Let promiseResolve be Completion(GetPromiseResolve(C)).
+    let promise_resolve = get_promise_resolve(agent, c.get(agent), gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    // 4. IfAbruptRejectPromise(promiseResolve, promiseCapability).
+    let promise_resolve = match promise_resolve {
+        Ok(promise_resolve) => promise_resolve.scope(agent, gc.nogc()),
+        Err(err) => return if_abrupt_reject(agent, err.unbind(), &promise, &reject, gc),
+    };
+
+    // 5. Let iteratorRecord be Completion(GetIterator(iterable, SYNC)).
+    let iterator_record = get_iterator(agent, iterable.get(agent), false, gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    // 6. IfAbruptRejectPromise(iteratorRecord, promiseCapability).
+    let IteratorRecord {
+        iterator,
+        next_method,
+    } = match iterator_record {
+        Ok(Some(iterator_record)) => iterator_record,
+        Ok(None) => {
+            let err = throw_not_callable(agent, gc.nogc());
+            return if_abrupt_reject(agent, err.unbind(), &promise, &reject, gc);
+        }
+        Err(err) => return if_abrupt_reject(agent, err.unbind(), &promise, &reject, gc),
+    };
+    let iterator = iterator.scope(agent, gc.nogc());
+    let next_method = next_method.scope(agent, gc.nogc());
+
+    // 7. Let result be Completion(PerformPromiseAll(iteratorRecord, C, promiseCapability, promiseResolve)).
+    let result = perform_promise_combinator(
+        agent,
+        iterator,
+        next_method,
+        c,
+        resolve,
+        reject.clone(),
+        promise_resolve,
+        group_type,
+        gc.reborrow(),
+    )
+    .unbind();
+    // 8. If result is an abrupt completion, then
+    //     a. If iteratorRecord.[[Done]] is false, set result to
+    //        Completion(IteratorClose(iteratorRecord, result)).
+    // NOTE: PerformPromiseAll has already closed the iterator if the abrupt
+    // completion didn't come from the iterator itself.
+    //     b. IfAbruptRejectPromise(result, promiseCapability).
+    if let Err(err) = result {
+        return if_abrupt_reject(agent, err, &promise, &reject, gc);
+    }
+    // 9. Return ? result.
+    Ok(promise.get(agent).into_value())
+}
+
+/// ### [27.2.1.1.1 IfAbruptRejectPromise ( value, capability )](https://tc39.es/ecma262/#sec-ifabruptrejectpromise)
+///
+/// The abrupt completion steps of IfAbruptRejectPromise for a
+/// PromiseCapability Record given by its \[\[Promise]] and \[\[Reject]]
+/// fields.
+fn if_abrupt_reject<'gc>(
+    agent: &mut Agent,
+    err: JsError,
+    promise: &Scoped<Object>,
+    reject: &Scoped<Function>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
+    call_function(
+        agent,
+        reject.get(agent),
+        Value::Undefined,
+        Some(ArgumentsList::from_mut_slice(&mut [err.value().unbind()])),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // b. Return capability.[[Promise]].
+    Ok(promise.get(agent).into_value().bind(gc.into_nogc()))
+}
+
+/// ### [27.2.4.1.2 PerformPromiseAll ( iteratorRecord, constructor, resultCapability, promiseResolve )](https://tc39.es/ecma262/#sec-performpromiseall)
+/// ### [27.2.4.2.1 PerformPromiseAllSettled ( iteratorRecord, constructor, resultCapability, promiseResolve )](https://tc39.es/ecma262/#sec-performpromiseallsettled)
+/// ### [27.2.4.3.1 PerformPromiseAny ( iteratorRecord, constructor, resultCapability, promiseResolve )](https://tc39.es/ecma262/#sec-performpromiseany)
+/// ### [27.2.4.5.1 PerformPromiseRace ( iteratorRecord, constructor, resultCapability, promiseResolve )](https://tc39.es/ecma262/#sec-performpromiserace)
+///
+/// These algorithms only differ in the functions passed to the "then" method
+/// of each element and in how the result promise is settled once every
+/// element has settled.
+#[allow(clippy::too_many_arguments)]
+fn perform_promise_combinator<'gc>(
+    agent: &mut Agent,
+    iterator: Scoped<Object>,
+    next_method: Scoped<Function>,
+    constructor: Scoped<Function>,
+    resolve: Scoped<Function>,
+    reject: Scoped<Function>,
+    promise_resolve: Scoped<Function>,
+    group_type: Option<PromiseGroupType>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    // 1. Let values be a new empty List.
+    // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+    let promise_group = group_type.map(|group_type| {
+        agent
+            .heap
+            .create(PromiseGroupRecord::new(
+                group_type,
+                resolve.get(agent),
+                reject.get(agent),
+            ))
+            .scope(agent, gc.nogc())
+    });
+    // 3. Let index be 0.
+    let mut index: u32 = 0;
+    // 4. Repeat,
+    loop {
+        // a. Let next be ? IteratorStepValue(iteratorRecord).
+        let next = iterator_step_value(
+            agent,
+            IteratorRecord {
+                iterator: iterator.get(agent),
+                next_method: next_method.get(agent),
+            },
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // b. If next is DONE, then
+        let Some(next) = next else {
+            if let Some(promise_group) = promise_group {
+                // i. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                // ii. If remainingElementsCount.[[Value]] = 0, then
+                //     1. Let valuesArray be CreateArrayFromList(values).
+                //     2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                return promise_group
+                    .get(agent)
+                    .decrement_remaining_elements_count(agent, gc);
+            }
+            // iii. Return resultCapability.[[Promise]].
+            return Ok(());
+        };
+        // c. Append undefined to values.
+        // NOTE: The entry is set when an element function for this index is
+        // called.
+        if let Some(promise_group) = &promise_group {
+            let promise_group = promise_group.get(agent);
+            agent[promise_group].values.push(None);
+        }
+        // d. Let nextPromise be ? Call(promiseResolve, constructor, « next »).
+        let next_promise = call_function(
+            agent,
+            promise_resolve.get(agent),
+            constructor.get(agent).into_value(),
+            Some(ArgumentsList::from_mut_slice(&mut [next.unbind()])),
+            gc.reborrow(),
+        );
+        let iterator_record = IteratorRecord {
+            iterator: iterator.get(agent),
+            next_method: next_method.get(agent),
+        };
+        let next_promise = if_abrupt_close_iterator!(agent, next_promise, iterator_record, gc);
+        let (on_fulfilled, on_rejected) = match &promise_group {
+            None => (
+                resolve.get(agent).into_value(),
+                reject.get(agent).into_value(),
+            ),
+            Some(promise_group) => {
+                let promise_group = promise_group.get(agent);
+                // e. Let steps be the algorithm steps defined in Promise.all Resolve Element Functions.
+                // f. Let length be the number of non-optional parameters of the function definition in Promise.all Resolve Element Functions.
+                // g. Let onFulfilled be CreateBuiltinFunction(steps, length, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+                // h. Set onFulfilled.[[AlreadyCalled]] to false.
+                // i. Set onFulfilled.[[Index]] to index.
+                // j. Set onFulfilled.[[Values]] to values.
+                // k. Set onFulfilled.[[Capability]] to resultCapability.
+                // l. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
+                let functions = match group_type.unwrap() {
+                    PromiseGroupType::All => (
+                        create_element_function(
+                            agent,
+                            PromiseCollectorFunctionType::AllResolveElement {
+                                promise_group,
+                                index,
+                            },
+                        ),
+                        reject.get(agent).into_value(),
+                    ),
+                    PromiseGroupType::AllSettled => (
+                        create_element_function(
+                            agent,
+                            PromiseCollectorFunctionType::AllSettledResolveElement {
+                                promise_group,
+                                index,
+                            },
+                        ),
+                        create_element_function(
+                            agent,
+                            PromiseCollectorFunctionType::AllSettledRejectElement {
+                                promise_group,
+                                index,
+                            },
+                        ),
+                    ),
+                    PromiseGroupType::Any => (
+                        resolve.get(agent).into_value(),
+                        create_element_function(
+                            agent,
+                            PromiseCollectorFunctionType::AnyRejectElement {
+                                promise_group,
+                                index,
+                            },
+                        ),
+                    ),
+                };
+                // m. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+                agent[promise_group].remaining_elements_count += 1;
+                functions
+            }
+        };
+        // n. Perform ? Invoke(nextPromise, "then", « onFulfilled, resultCapability.[[Reject]] »).
+        let result = invoke(
+            agent,
+            next_promise.unbind(),
+            BUILTIN_STRING_MEMORY.then.into(),
+            Some(ArgumentsList::from_mut_slice(&mut [
+                on_fulfilled.unbind(),
+                on_rejected.unbind(),
+            ])),
+            gc.reborrow(),
+        );
+        let iterator_record = IteratorRecord {
+            iterator: iterator.get(agent),
+            next_method: next_method.get(agent),
+        };
+        let _ = if_abrupt_close_iterator!(agent, result, iterator_record, gc);
+        // o. Set index to index + 1.
+        index += 1;
+    }
+}
+
+fn create_element_function<'a>(
+    agent: &mut Agent,
+    collector_type: PromiseCollectorFunctionType<'a>,
+) -> Value<'a> {
+    agent
+        .heap
+        .create(PromiseCollectorFunctionHeapData {
+            object_index: None,
+            collector_type,
+        })
+        .into_value()
+}
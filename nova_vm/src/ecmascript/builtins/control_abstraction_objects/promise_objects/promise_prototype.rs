@@ -3,9 +3,13 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::engine::context::{Bindable, GcScope, NoGcScope};
+use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
-        abstract_operations::operations_on_objects::invoke,
+        abstract_operations::{
+            operations_on_objects::{invoke, species_constructor},
+            testing_and_comparison::is_callable,
+        },
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
         builtins::{
             ArgumentsList, Behaviour, Builtin,
@@ -18,13 +22,14 @@ use crate::{
             Agent, JsResult, Realm,
             agent::{ExceptionType, PromiseRejectionTrackerOperation},
         },
-        types::{BUILTIN_STRING_MEMORY, Function, IntoValue, String, Value},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoFunction, IntoValue, Object, String, Value},
     },
     heap::{CreateHeapData, WellKnownSymbolIndexes},
 };
 
 use super::promise_abstract_operations::{
     promise_capability_records::PromiseCapability,
+    promise_collector_functions::{PromiseCollectorFunctionHeapData, PromiseCollectorFunctionType},
     promise_jobs::new_promise_reaction_job,
     promise_reaction_records::{
         PromiseReactionHandler, PromiseReactionRecord, PromiseReactionType,
@@ -76,13 +81,79 @@ impl PromisePrototype {
         )
     }
 
+    /// ### [27.2.5.3 Promise.prototype.finally ( onFinally )](https://tc39.es/ecma262/#sec-promise.prototype.finally)
     fn finally<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Promise.prototype.finally", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let on_finally = args.get(0).scope(agent, gc.nogc());
+        // 1. Let promise be the this value.
+        // 2. If promise is not an Object, throw a TypeError exception.
+        let Ok(promise) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.into_nogc(),
+            ));
+        };
+        let scoped_promise = promise.scope(agent, gc.nogc());
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let promise_constructor = agent
+            .current_realm_record()
+            .intrinsics()
+            .promise()
+            .into_function();
+        let c = species_constructor(agent, promise.unbind(), promise_constructor, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Assert: IsConstructor(C) is true.
+        let on_finally = on_finally.get(agent).bind(gc.nogc());
+        let (then_finally, catch_finally) = match is_callable(on_finally, gc.nogc()) {
+            // 5. If IsCallable(onFinally) is false, then
+            //     a. Let thenFinally be onFinally.
+            //     b. Let catchFinally be onFinally.
+            None => (on_finally, on_finally),
+            // 6. Else,
+            Some(on_finally) => {
+                // a. Let thenFinallyClosure be a new Abstract Closure with
+                //    parameters (value) that captures onFinally and C and
+                //    performs the following steps when called:
+                // b. Let thenFinally be CreateBuiltinFunction(thenFinallyClosure, 1, "", « »).
+                let then_finally = agent.heap.create(PromiseCollectorFunctionHeapData {
+                    object_index: None,
+                    collector_type: PromiseCollectorFunctionType::ThenFinally {
+                        on_finally: on_finally.unbind(),
+                        constructor: c.unbind(),
+                    },
+                });
+                // c. Let catchFinallyClosure be a new Abstract Closure with
+                //    parameters (reason) that captures onFinally and C and
+                //    performs the following steps when called:
+                // d. Let catchFinally be CreateBuiltinFunction(catchFinallyClosure, 1, "", « »).
+                let catch_finally = agent.heap.create(PromiseCollectorFunctionHeapData {
+                    object_index: None,
+                    collector_type: PromiseCollectorFunctionType::CatchFinally {
+                        on_finally: on_finally.unbind(),
+                        constructor: c.unbind(),
+                    },
+                });
+                (then_finally.into_value(), catch_finally.into_value())
+            }
+        };
+        // 7. Return ? Invoke(promise, "then", « thenFinally, catchFinally »).
+        invoke(
+            agent,
+            scoped_promise.get(agent).into_value(),
+            BUILTIN_STRING_MEMORY.then.into(),
+            Some(ArgumentsList::from_mut_slice(&mut [
+                then_finally.unbind(),
+                catch_finally.unbind(),
+            ])),
+            gc,
+        )
     }
 
     /// ### [27.2.5.4 Promise.prototype.then ( onFulfilled, onRejected )](https://tc39.es/ecma262/#sec-promise.prototype.then)
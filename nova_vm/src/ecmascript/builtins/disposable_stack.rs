@@ -0,0 +1,214 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        context::{Bindable, NoGcScope},
+        rootable::HeapRootData,
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::{BaseIndex, DisposableStackIndex},
+    },
+};
+
+use self::data::DisposableStackHeapData;
+
+pub mod data;
+
+/// A DisposableStack or AsyncDisposableStack object.
+///
+/// The same heap data is also used for the DisposeCapability Records of
+/// blocks, functions and modules containing `using` declarations; those are
+/// never exposed to ECMAScript code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct DisposableStack<'a>(pub(crate) DisposableStackIndex<'a>);
+
+impl DisposableStack<'_> {
+    pub(crate) const fn _def() -> Self {
+        Self(BaseIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    /// Returns true if this is an AsyncDisposableStack.
+    pub(crate) fn is_async(self, agent: &Agent) -> bool {
+        agent[self].is_async
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for DisposableStack<'_> {
+    type Of<'a> = DisposableStack<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for DisposableStack<'a> {
+    fn into_value(self) -> Value<'a> {
+        self.into()
+    }
+}
+
+impl<'a> IntoObject<'a> for DisposableStack<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<DisposableStack<'a>> for Value<'a> {
+    fn from(value: DisposableStack<'a>) -> Self {
+        Value::DisposableStack(value)
+    }
+}
+
+impl<'a> From<DisposableStack<'a>> for Object<'a> {
+    fn from(value: DisposableStack<'a>) -> Self {
+        Object::DisposableStack(value)
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for DisposableStack<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Value::DisposableStack(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for DisposableStack<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::DisposableStack(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for DisposableStack<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::DisposableStack;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            agent[self]
+                .object_index
+                .replace(backing_object.unbind())
+                .is_none()
+        );
+    }
+
+    fn internal_prototype(self, agent: &Agent) -> Option<Object<'static>> {
+        if let Some(backing_object) = self.get_backing_object(agent) {
+            backing_object.internal_prototype(agent)
+        } else {
+            let intrinsic = if self.is_async(agent) {
+                ProtoIntrinsics::AsyncDisposableStack
+            } else {
+                ProtoIntrinsics::DisposableStack
+            };
+            Some(
+                agent
+                    .current_realm_record()
+                    .intrinsics()
+                    .get_intrinsic_default_proto(intrinsic),
+            )
+        }
+    }
+}
+
+impl<'a> InternalMethods<'a> for DisposableStack<'a> {}
+
+impl Index<DisposableStack<'_>> for Agent {
+    type Output = DisposableStackHeapData<'static>;
+
+    fn index(&self, index: DisposableStack) -> &Self::Output {
+        &self.heap.disposable_stacks[index]
+    }
+}
+
+impl IndexMut<DisposableStack<'_>> for Agent {
+    fn index_mut(&mut self, index: DisposableStack) -> &mut Self::Output {
+        &mut self.heap.disposable_stacks[index]
+    }
+}
+
+impl Index<DisposableStack<'_>> for Vec<Option<DisposableStackHeapData<'static>>> {
+    type Output = DisposableStackHeapData<'static>;
+
+    fn index(&self, index: DisposableStack) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("DisposableStack out of bounds")
+            .as_ref()
+            .expect("DisposableStack slot empty")
+    }
+}
+
+impl IndexMut<DisposableStack<'_>> for Vec<Option<DisposableStackHeapData<'static>>> {
+    fn index_mut(&mut self, index: DisposableStack) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("DisposableStack out of bounds")
+            .as_mut()
+            .expect("DisposableStack slot empty")
+    }
+}
+
+impl TryFrom<HeapRootData> for DisposableStack<'_> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: HeapRootData) -> Result<Self, Self::Error> {
+        if let HeapRootData::DisposableStack(value) = value {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<'a> CreateHeapData<DisposableStackHeapData<'a>, DisposableStack<'a>> for Heap {
+    fn create(&mut self, data: DisposableStackHeapData<'a>) -> DisposableStack<'a> {
+        self.disposable_stacks.push(Some(data.unbind()));
+        self.alloc_counter += core::mem::size_of::<Option<DisposableStackHeapData<'static>>>();
+        DisposableStack(DisposableStackIndex::last(&self.disposable_stacks))
+    }
+}
+
+impl HeapMarkAndSweep for DisposableStack<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.disposable_stacks.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.disposable_stacks.shift_index(&mut self.0);
+    }
+}
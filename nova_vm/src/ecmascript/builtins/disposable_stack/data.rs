@@ -0,0 +1,237 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builtins::promise::Promise,
+        execution::agent::JsError,
+        types::{Function, OrdinaryObject, Value},
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// \[\[DisposableState]] and \[\[AsyncDisposableState]]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum DisposableState {
+    #[default]
+    Pending,
+    Disposed,
+}
+
+/// \[\[Hint]]
+///
+/// Either sync-dispose or async-dispose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeHint {
+    SyncDispose,
+    AsyncDispose,
+}
+
+/// \[\[DisposeMethod]]
+///
+/// The specification creates new built-in function closures for
+/// `DisposableStack.prototype.adopt` and for `@@dispose` methods used with an
+/// async-dispose hint. Those closures are never observable, so we instead
+/// record how the original function should be called.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum DisposeMethod<'a> {
+    /// Call the function with the resource value as the this value.
+    Method(Function<'a>),
+    /// Call the `onDispose` function of `adopt` with undefined as the this
+    /// value and the resource value as the only argument.
+    Adopt(Function<'a>),
+    /// Call the `@@dispose` method of a resource that was added with an
+    /// async-dispose hint. The result of the call is ignored and any thrown
+    /// error becomes a rejection.
+    ///
+    /// See [GetDisposeMethod](https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod)
+    /// step 1.b.ii.
+    SyncAsAsync(Function<'a>),
+}
+
+/// ### [DisposableResource Records](https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records)
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposableResource<'a> {
+    /// \[\[ResourceValue]]
+    pub(crate) value: Value<'a>,
+    /// \[\[Hint]]
+    pub(crate) hint: DisposeHint,
+    /// \[\[DisposeMethod]]
+    ///
+    /// None if the resource was null or undefined in an `await using`
+    /// declaration.
+    pub(crate) method: Option<DisposeMethod<'a>>,
+}
+
+/// Progress of an asynchronous DisposeResources call.
+///
+/// Asynchronous disposal cannot suspend the calling execution context, so the
+/// loop of DisposeResources is continued in promise reaction jobs and this
+/// record holds the state of the loop between them.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncDisposeState<'a> {
+    /// The promise that settles once all resources have been disposed.
+    pub(crate) promise: Promise<'a>,
+    /// The completion of DisposeResources so far; None if normal.
+    pub(crate) error: Option<JsError<'a>>,
+    /// needsAwait
+    pub(crate) needs_await: bool,
+    /// hasAwaited
+    pub(crate) has_awaited: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DisposableStackHeapData<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// True if this is an AsyncDisposableStack.
+    pub(crate) is_async: bool,
+    /// \[\[DisposableState]] or \[\[AsyncDisposableState]]
+    pub(crate) state: DisposableState,
+    /// \[\[DisposeCapability]].\[\[DisposableResourceStack]]
+    pub(crate) resources: Vec<DisposableResource<'a>>,
+    pub(crate) async_dispose: Option<AsyncDisposeState<'a>>,
+}
+
+impl DisposableStackHeapData<'_> {
+    pub(crate) fn new(is_async: bool) -> Self {
+        Self {
+            is_async,
+            ..Default::default()
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for DisposableStackHeapData<'_> {
+    type Of<'a> = DisposableStackHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for DisposeMethod<'_> {
+    type Of<'a> = DisposeMethod<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for DisposableResource<'_> {
+    type Of<'a> = DisposableResource<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for DisposeMethod<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::Method(f) | Self::Adopt(f) | Self::SyncAsAsync(f) => f.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::Method(f) | Self::Adopt(f) | Self::SyncAsAsync(f) => f.sweep_values(compactions),
+        }
+    }
+}
+
+impl HeapMarkAndSweep for DisposableResource<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            value,
+            hint: _,
+            method,
+        } = self;
+        value.mark_values(queues);
+        method.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            value,
+            hint: _,
+            method,
+        } = self;
+        value.sweep_values(compactions);
+        method.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for AsyncDisposeState<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            promise,
+            error,
+            needs_await: _,
+            has_awaited: _,
+        } = self;
+        promise.mark_values(queues);
+        error.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            promise,
+            error,
+            needs_await: _,
+            has_awaited: _,
+        } = self;
+        promise.sweep_values(compactions);
+        error.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for DisposableStackHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            is_async: _,
+            state: _,
+            resources,
+            async_dispose,
+        } = self;
+        object_index.mark_values(queues);
+        resources.as_slice().mark_values(queues);
+        async_dispose.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            is_async: _,
+            state: _,
+            resources,
+            async_dispose,
+        } = self;
+        object_index.sweep_values(compactions);
+        resources.as_mut_slice().sweep_values(compactions);
+        async_dispose.sweep_values(compactions);
+    }
+}
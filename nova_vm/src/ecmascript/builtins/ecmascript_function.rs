@@ -966,6 +966,7 @@ pub(crate) fn ordinary_function_create<'agent, 'program, 'gc>(
         length: 0,
         ecmascript_function,
         compiled_bytecode: None,
+        compiled_initializer_bytecode: None,
         name: None,
     };
     if let Some(function_prototype) = params.function_prototype {
@@ -1033,7 +1034,7 @@ pub(crate) fn make_constructor<'a>(
         Function::BuiltinGeneratorFunction
         | Function::BuiltinConstructorFunction(_)
         | Function::BuiltinPromiseResolvingFunction(_)
-        | Function::BuiltinPromiseCollectorFunction
+        | Function::BuiltinPromiseCollectorFunction(_)
         | Function::BuiltinProxyRevokerFunction => unreachable!(),
     }
     // 5. If prototype is not present, then
@@ -1153,7 +1154,7 @@ pub(crate) fn set_function_name<'a>(
         Function::BuiltinGeneratorFunction
         | Function::BuiltinConstructorFunction(_)
         | Function::BuiltinPromiseResolvingFunction(_)
-        | Function::BuiltinPromiseCollectorFunction
+        | Function::BuiltinPromiseCollectorFunction(_)
         | Function::BuiltinProxyRevokerFunction => unreachable!(),
     }
 }
@@ -1245,6 +1246,7 @@ impl HeapMarkAndSweep for ECMAScriptFunctionHeapData<'static> {
             length: _,
             ecmascript_function,
             compiled_bytecode,
+            compiled_initializer_bytecode,
             name,
         } = self;
         let ECMAScriptFunctionObjectHeapData {
@@ -1266,6 +1268,7 @@ impl HeapMarkAndSweep for ECMAScriptFunctionHeapData<'static> {
         } = ecmascript_function;
         object_index.mark_values(queues);
         compiled_bytecode.mark_values(queues);
+        compiled_initializer_bytecode.mark_values(queues);
         name.mark_values(queues);
         environment.mark_values(queues);
         private_environment.mark_values(queues);
@@ -1281,6 +1284,7 @@ impl HeapMarkAndSweep for ECMAScriptFunctionHeapData<'static> {
             length: _,
             ecmascript_function,
             compiled_bytecode,
+            compiled_initializer_bytecode,
             name,
         } = self;
         let ECMAScriptFunctionObjectHeapData {
@@ -1302,6 +1306,7 @@ impl HeapMarkAndSweep for ECMAScriptFunctionHeapData<'static> {
         } = ecmascript_function;
         object_index.sweep_values(compactions);
         compiled_bytecode.sweep_values(compactions);
+        compiled_initializer_bytecode.sweep_values(compactions);
         name.sweep_values(compactions);
         environment.sweep_values(compactions);
         private_environment.sweep_values(compactions);
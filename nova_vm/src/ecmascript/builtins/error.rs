@@ -176,6 +176,8 @@ impl<'a> InternalSlots<'a> for Error<'a> {
                 ExceptionType::SyntaxError => ProtoIntrinsics::SyntaxError,
                 ExceptionType::TypeError => ProtoIntrinsics::TypeError,
                 ExceptionType::UriError => ProtoIntrinsics::UriError,
+                #[cfg(feature = "proposal-explicit-resource-management")]
+                ExceptionType::SuppressedError => ProtoIntrinsics::SuppressedError,
             };
             Some(
                 agent
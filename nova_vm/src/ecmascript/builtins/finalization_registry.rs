@@ -6,14 +6,20 @@ use core::ops::{Index, IndexMut};
 
 use crate::{
     ecmascript::{
-        execution::{Agent, ProtoIntrinsics},
+        abstract_operations::operations_on_objects::call_function,
+        builtins::ArgumentsList,
+        execution::{
+            Agent, JsResult, ProtoIntrinsics,
+            agent::{InnerJob, Job},
+        },
         types::{
             InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
         },
     },
     engine::{
-        context::{Bindable, NoGcScope},
-        rootable::{HeapRootData, HeapRootRef, Rootable},
+        Global,
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable, Scopable},
     },
     heap::{
         CreateHeapData, Heap, HeapMarkAndSweep,
@@ -78,6 +84,17 @@ impl<'a> From<FinalizationRegistry<'a>> for Object<'a> {
     }
 }
 
+impl<'a> TryFrom<Object<'a>> for FinalizationRegistry<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::FinalizationRegistry(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
 impl<'a> InternalSlots<'a> for FinalizationRegistry<'a> {
     const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::FinalizationRegistry;
 
@@ -170,3 +187,101 @@ impl HeapMarkAndSweep for FinalizationRegistry<'static> {
         compactions.finalization_registrys.shift_index(&mut self.0);
     }
 }
+
+/// A Job that calls the cleanup callback of a FinalizationRegistry for each
+/// of its cells whose target has been garbage collected.
+pub(crate) struct FinalizationRegistryCleanupJob {
+    finalization_registry: Global<FinalizationRegistry<'static>>,
+}
+
+impl FinalizationRegistryCleanupJob {
+    pub(crate) fn run<'a>(self, agent: &mut Agent, gc: GcScope<'a, '_>) -> JsResult<'a, ()> {
+        let finalization_registry = self.finalization_registry.take(agent);
+        cleanup_finalization_registry(agent, finalization_registry, gc)
+    }
+}
+
+/// ### [9.13 CleanupFinalizationRegistry ( finalizationRegistry )](https://tc39.es/ecma262/#sec-cleanup-finalization-registry)
+///
+/// The abstract operation CleanupFinalizationRegistry takes argument
+/// finalizationRegistry (a FinalizationRegistry) and returns either a normal
+/// completion containing UNUSED or a throw completion.
+pub(crate) fn cleanup_finalization_registry<'a>(
+    agent: &mut Agent,
+    finalization_registry: FinalizationRegistry,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let finalization_registry = finalization_registry.scope(agent, gc.nogc());
+    // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]]
+    //    internal slots.
+    // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
+    // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that
+    //    cell.[[WeakRefTarget]] is EMPTY, an implementation may perform the
+    //    following steps:
+    loop {
+        let registry = finalization_registry.get(agent);
+        let data = &mut agent[registry];
+        // a. Choose any such cell.
+        let Some(index) = data
+            .cells
+            .iter()
+            .position(|cell| cell.weak_ref_target.is_none())
+        else {
+            break;
+        };
+        // b. Remove cell from finalizationRegistry.[[Cells]].
+        let cell = data.cells.remove(index);
+        let callback = data.cleanup_callback.unwrap();
+        // c. Perform ? HostCallJobCallback(callback, undefined,
+        //    « cell.[[HeldValue]] »).
+        call_function(
+            agent,
+            callback,
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [cell.held_value])),
+            gc.reborrow(),
+        )
+        .unbind()?;
+    }
+    // 4. Return UNUSED.
+    Ok(())
+}
+
+/// ### [9.10.4.1 HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )](https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry)
+///
+/// Called after garbage collection: a cleanup Job is created for each
+/// FinalizationRegistry that had cell targets collected, and handed to the
+/// host to be scheduled.
+pub(crate) fn enqueue_finalization_registry_cleanup_jobs(agent: &mut Agent) {
+    for index in 0..agent.heap.finalization_registrys.len() {
+        let Some(data) = agent.heap.finalization_registrys[index].as_mut() else {
+            continue;
+        };
+        if !core::mem::take(&mut data.needs_cleanup) {
+            continue;
+        }
+        let realm = data.realm;
+        let finalization_registry =
+            FinalizationRegistry(FinalizationRegistryIndex::from_index(index));
+        // 1. Let cleanupJob be a new Job Abstract Closure with no parameters
+        //    that captures finalizationRegistry and performs the following
+        //    steps when called:
+        //     a. Let cleanupResult be
+        //        Completion(CleanupFinalizationRegistry(finalizationRegistry)).
+        //     b. If cleanupResult is an abrupt completion, perform any
+        //        host-defined steps for reporting the error.
+        //     c. Return UNUSED.
+        let job = Job {
+            realm,
+            inner: InnerJob::FinalizationRegistryCleanup(FinalizationRegistryCleanupJob {
+                finalization_registry: Global::new(agent, finalization_registry),
+            }),
+        };
+        // 2. An implementation of HostEnqueueFinalizationRegistryCleanupJob
+        //    schedules cleanupJob to be performed at some future time, if
+        //    possible.
+        agent
+            .host_hooks
+            .enqueue_finalization_registry_cleanup_job(job);
+    }
+}
@@ -3,14 +3,65 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::{
-    ecmascript::types::OrdinaryObject,
+    ecmascript::{
+        execution::{Realm, WeakKey},
+        types::{Function, OrdinaryObject, Value},
+    },
     engine::context::{Bindable, NoGcScope},
-    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+    heap::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues},
 };
 
 #[derive(Debug, Clone, Default)]
 pub struct FinalizationRegistryHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// ### \[\[Realm]]
+    pub(crate) realm: Option<Realm<'a>>,
+    /// ### \[\[CleanupCallback]]
+    pub(crate) cleanup_callback: Option<Function<'a>>,
+    /// ### \[\[Cells]]
+    pub(crate) cells: Vec<FinalizationRegistryCell<'a>>,
+    /// Set when the target of a cell has been garbage collected and a cleanup
+    /// job for the FinalizationRegistry has not yet been enqueued.
+    pub(crate) needs_cleanup: bool,
+}
+
+/// A Record in the \[\[Cells]] of a FinalizationRegistry.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FinalizationRegistryCell<'a> {
+    /// ### \[\[WeakRefTarget]]
+    ///
+    /// Held weakly; None (EMPTY) once the target has been garbage collected.
+    pub(crate) weak_ref_target: Option<WeakKey<'a>>,
+    /// ### \[\[HeldValue]]
+    pub(crate) held_value: Value<'a>,
+    /// ### \[\[UnregisterToken]]
+    ///
+    /// Held weakly; None (EMPTY) if no token was given or once the token has
+    /// been garbage collected.
+    pub(crate) unregister_token: Option<WeakKey<'a>>,
+}
+
+impl FinalizationRegistryHeapData<'static> {
+    /// Empty the targets and unregister tokens of cells that were not found
+    /// reachable. If any target was emptied, the FinalizationRegistry is
+    /// marked as needing cleanup.
+    pub(crate) fn sweep_weak_targets(&mut self, bits: &HeapBits) {
+        for cell in self.cells.iter_mut() {
+            if cell
+                .weak_ref_target
+                .is_some_and(|target| !target.is_marked(bits))
+            {
+                cell.weak_ref_target = None;
+                self.needs_cleanup = true;
+            }
+            if cell
+                .unregister_token
+                .is_some_and(|token| !token.is_marked(bits))
+            {
+                cell.unregister_token = None;
+            }
+        }
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -30,12 +81,43 @@ unsafe impl Bindable for FinalizationRegistryHeapData<'_> {
 
 impl HeapMarkAndSweep for FinalizationRegistryHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
-        let Self { object_index } = self;
+        let Self {
+            object_index,
+            realm,
+            cleanup_callback,
+            cells,
+            needs_cleanup: _,
+        } = self;
         object_index.mark_values(queues);
+        realm.mark_values(queues);
+        cleanup_callback.mark_values(queues);
+        // Note: The targets and unregister tokens of cells are held weakly
+        // and are not marked.
+        for cell in cells {
+            cell.held_value.mark_values(queues);
+        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
-        let Self { object_index } = self;
+        let Self {
+            object_index,
+            realm,
+            cleanup_callback,
+            cells,
+            needs_cleanup: _,
+        } = self;
         object_index.sweep_values(compactions);
+        realm.sweep_values(compactions);
+        cleanup_callback.sweep_values(compactions);
+        for FinalizationRegistryCell {
+            weak_ref_target,
+            held_value,
+            unregister_token,
+        } in cells
+        {
+            weak_ref_target.sweep_values(compactions);
+            held_value.sweep_values(compactions);
+            unregister_token.sweep_values(compactions);
+        }
     }
 }
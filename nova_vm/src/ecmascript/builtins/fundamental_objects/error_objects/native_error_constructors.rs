@@ -117,6 +117,8 @@ impl NativeErrorConstructors {
             ExceptionType::SyntaxError => ProtoIntrinsics::SyntaxError,
             ExceptionType::TypeError => ProtoIntrinsics::TypeError,
             ExceptionType::UriError => ProtoIntrinsics::UriError,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ExceptionType::SuppressedError => ProtoIntrinsics::SuppressedError,
         };
 
         let new_target = new_target.unwrap_or_else(|| {
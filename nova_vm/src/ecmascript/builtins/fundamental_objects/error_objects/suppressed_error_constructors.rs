@@ -0,0 +1,155 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::engine::context::{Bindable, GcScope, NoGcScope};
+use crate::engine::rootable::Scopable;
+use crate::engine::unwrap_try;
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::try_define_property_or_throw, type_conversion::to_string,
+        },
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            error::{Error, ErrorHeapData},
+            ordinary::ordinary_create_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        types::{
+            BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, PropertyDescriptor,
+            PropertyKey, String, Value,
+        },
+    },
+    heap::{CreateHeapData, IntrinsicConstructorIndexes},
+};
+
+pub(crate) struct SuppressedErrorConstructor;
+impl Builtin for SuppressedErrorConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.SuppressedError;
+
+    const LENGTH: u8 = 3;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for SuppressedErrorConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::SuppressedError;
+}
+
+impl SuppressedErrorConstructor {
+    /// ### [SuppressedError ( error, suppressed, message )](https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let error = arguments.get(0).scope(agent, gc.nogc());
+        let suppressed = arguments.get(1).scope(agent, gc.nogc());
+        let message = arguments.get(2).scope(agent, gc.nogc());
+        // 1. If NewTarget is undefined, let newTarget be the active function object; else let newTarget be NewTarget.
+        let new_target = new_target.map_or_else(
+            || agent.running_execution_context().function.unwrap(),
+            |new_target| Function::try_from(new_target).unwrap(),
+        );
+        // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%SuppressedError.prototype%", « [[ErrorData]] »).
+        let o = ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::SuppressedError,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let o = Error::try_from(o.unbind()).unwrap().scope(agent, gc.nogc());
+        // 3. If message is not undefined, then
+        let message = message.get(agent).bind(gc.nogc());
+        let message = if !message.is_undefined() {
+            // a. Let messageString be ? ToString(message).
+            Some(
+                to_string(agent, message.unbind(), gc.reborrow())
+                    .unbind()?
+                    .bind(gc.nogc()),
+            )
+        } else {
+            None
+        };
+        let message = message.unbind();
+        let gc = gc.into_nogc();
+        let message = message.bind(gc);
+        // SAFETY: not shared.
+        let o = unsafe { o.take(agent) }.bind(gc);
+        // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", messageString).
+        agent[o].message = message.unbind();
+        // 4. Perform CreateNonEnumerableDataPropertyOrThrow(O, "error", error).
+        // 5. Perform CreateNonEnumerableDataPropertyOrThrow(O, "suppressed", suppressed).
+        install_error_and_suppressed(agent, o, error.get(agent), suppressed.get(agent), gc);
+        // 6. Return O.
+        Ok(o.into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let error_constructor = intrinsics.error();
+        let suppressed_error_prototype = intrinsics.suppressed_error_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<SuppressedErrorConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype(error_constructor.into_object())
+        .with_prototype_property(suppressed_error_prototype.into_object())
+        .build();
+    }
+}
+
+/// Creates a new %SuppressedError% object with the given error and suppressed
+/// values and no message. This is used by DisposeResources to combine errors
+/// thrown during disposal with the completion that triggered it.
+pub(crate) fn create_suppressed_error<'a>(
+    agent: &mut Agent,
+    error: Value,
+    suppressed: Value,
+    gc: NoGcScope<'a, '_>,
+) -> Error<'a> {
+    let o = agent
+        .heap
+        .create(ErrorHeapData::new(
+            ExceptionType::SuppressedError,
+            None,
+            None,
+        ))
+        .bind(gc);
+    install_error_and_suppressed(agent, o, error, suppressed, gc);
+    o
+}
+
+fn install_error_and_suppressed(
+    agent: &mut Agent,
+    o: Error,
+    error: Value,
+    suppressed: Value,
+    gc: NoGcScope,
+) {
+    for (key, value) in [
+        (BUILTIN_STRING_MEMORY.error, error),
+        (BUILTIN_STRING_MEMORY.suppressed, suppressed),
+    ] {
+        unwrap_try(try_define_property_or_throw(
+            agent,
+            o,
+            PropertyKey::from(key),
+            PropertyDescriptor {
+                value: Some(value.unbind()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+            gc,
+        ))
+        .unwrap();
+    }
+}
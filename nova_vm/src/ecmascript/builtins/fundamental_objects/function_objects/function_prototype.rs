@@ -398,16 +398,19 @@ impl FunctionPrototype {
             // 4. If func is an Object and IsCallable(func) is true, return an
             // implementation-defined String source code representation of func.
             // The representation must have the syntax of a NativeFunction.
-            Function::BoundFunction(_) | Function::BuiltinPromiseResolvingFunction(_) => {
-                // Promise resolving functions have no initial name.
+            Function::BoundFunction(_)
+            | Function::BuiltinPromiseResolvingFunction(_)
+            | Function::BuiltinPromiseCollectorFunction(_) => {
+                // Promise resolving and collector functions have no initial
+                // name.
                 Ok(
                     Value::from_static_str(agent, "function () { [ native code ] }", gc.nogc())
                         .unbind(),
                 )
             }
-            Function::BuiltinGeneratorFunction
-            | Function::BuiltinPromiseCollectorFunction
-            | Function::BuiltinProxyRevokerFunction => unreachable!(),
+            Function::BuiltinGeneratorFunction | Function::BuiltinProxyRevokerFunction => {
+                unreachable!()
+            }
         }
 
         // NOTE: NativeFunction means the following string:
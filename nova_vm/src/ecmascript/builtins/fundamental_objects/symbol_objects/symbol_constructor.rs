@@ -110,115 +110,141 @@ impl SymbolConstructor {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let symbol_prototype = intrinsics.symbol_prototype();
 
-        BuiltinFunctionBuilder::new_intrinsic_constructor::<SymbolConstructor>(agent, realm)
-            .with_property_capacity(16)
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.asyncIterator.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::AsyncIterator.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_builtin_function_property::<SymbolFor>()
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.hasInstance.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::HasInstance.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.isConcatSpreadable.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::IsConcatSpreadable.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.iterator.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::Iterator.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_builtin_function_property::<SymbolKeyFor>()
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.r#match.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::Match.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.matchAll.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::MatchAll.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_prototype_property(symbol_prototype.into_object())
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.replace.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::Replace.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.search.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::Search.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.species.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::Species.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.split.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::Split.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
-            .with_property(|builder| {
-                builder
-                    .with_key(BUILTIN_STRING_MEMORY.toPrimitive.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::ToPrimitive.into())
-                    .with_enumerable(false)
-                    .with_configurable(false)
-                    .build()
-            })
+        let mut property_capacity = 16;
+        if cfg!(feature = "proposal-explicit-resource-management") {
+            property_capacity += 2;
+        }
+
+        let builder =
+            BuiltinFunctionBuilder::new_intrinsic_constructor::<SymbolConstructor>(agent, realm)
+                .with_property_capacity(property_capacity)
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.asyncIterator.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::AsyncIterator.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_builtin_function_property::<SymbolFor>()
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.hasInstance.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::HasInstance.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.isConcatSpreadable.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::IsConcatSpreadable.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.iterator.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::Iterator.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_builtin_function_property::<SymbolKeyFor>()
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.r#match.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::Match.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.matchAll.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::MatchAll.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_prototype_property(symbol_prototype.into_object())
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.replace.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::Replace.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.search.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::Search.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.species.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::Species.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.split.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::Split.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.toPrimitive.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::ToPrimitive.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.toStringTag.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::ToStringTag.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.unscopables.into())
+                        .with_value_readonly(WellKnownSymbolIndexes::Unscopables.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                });
+
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let builder = builder
             .with_property(|builder| {
                 builder
-                    .with_key(BUILTIN_STRING_MEMORY.toStringTag.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_key(BUILTIN_STRING_MEMORY.asyncDispose.into())
+                    .with_value_readonly(WellKnownSymbolIndexes::AsyncDispose.into())
                     .with_enumerable(false)
                     .with_configurable(false)
                     .build()
             })
             .with_property(|builder| {
                 builder
-                    .with_key(BUILTIN_STRING_MEMORY.unscopables.into())
-                    .with_value_readonly(WellKnownSymbolIndexes::Unscopables.into())
+                    .with_key(BUILTIN_STRING_MEMORY.dispose.into())
+                    .with_value_readonly(WellKnownSymbolIndexes::Dispose.into())
                     .with_enumerable(false)
                     .with_configurable(false)
                     .build()
-            })
-            .build();
+            });
+
+        builder.build();
     }
 }
@@ -114,9 +114,15 @@ impl SymbolPrototype {
 
         agent.heap.symbols.extend_from_slice(
             &[
+                SymbolHeapData {
+                    descriptor: Some(BUILTIN_STRING_MEMORY.Symbol_asyncDispose),
+                },
                 SymbolHeapData {
                     descriptor: Some(BUILTIN_STRING_MEMORY.Symbol_asyncIterator),
                 },
+                SymbolHeapData {
+                    descriptor: Some(BUILTIN_STRING_MEMORY.Symbol_dispose),
+                },
                 SymbolHeapData {
                     descriptor: Some(BUILTIN_STRING_MEMORY.Symbol_hasInstance),
                 },
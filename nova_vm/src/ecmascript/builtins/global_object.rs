@@ -11,7 +11,7 @@ use crate::ecmascript::abstract_operations::type_conversion::{
     is_trimmable_whitespace, to_int32, to_int32_number, to_number_primitive, to_string,
 };
 use crate::ecmascript::types::Primitive;
-use crate::engine::context::{Bindable, GcScope};
+use crate::engine::context::{Bindable, GcScope, NoGcScope};
 use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
@@ -122,21 +122,27 @@ impl Builtin for GlobalObjectEncodeURIComponent {
 impl BuiltinIntrinsic for GlobalObjectEncodeURIComponent {
     const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::EncodeURIComponent;
 }
+#[cfg(feature = "annex-b-global")]
 struct GlobalObjectEscape;
+#[cfg(feature = "annex-b-global")]
 impl Builtin for GlobalObjectEscape {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.escape;
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(GlobalObject::escape);
 }
+#[cfg(feature = "annex-b-global")]
 impl BuiltinIntrinsic for GlobalObjectEscape {
     const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::Escape;
 }
+#[cfg(feature = "annex-b-global")]
 struct GlobalObjectUnescape;
+#[cfg(feature = "annex-b-global")]
 impl Builtin for GlobalObjectUnescape {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.unescape;
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(GlobalObject::unescape);
 }
+#[cfg(feature = "annex-b-global")]
 impl BuiltinIntrinsic for GlobalObjectUnescape {
     const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::Unescape;
 }
@@ -465,7 +471,7 @@ pub fn eval_declaration_instantiation<'a>(
     }
 
     // 4. Let privateIdentifiers be a new empty List.
-    let mut private_identifiers = vec![];
+    let mut private_identifiers: Vec<std::string::String> = vec![];
 
     // 5. Let pointer be privateEnv.
     let mut pointer = private_env.as_ref().map(|v| v.get(agent).bind(gc.nogc()));
@@ -475,12 +481,12 @@ pub fn eval_declaration_instantiation<'a>(
         let env = &agent[index];
 
         // a. For each Private Name binding of pointer.[[Names]], do
-        for name in env.names.values() {
+        for description in env.names.keys() {
             // i. If privateIdentifiers does not contain
             //    binding.[[Description]], append binding.[[Description]] to
             //    privateIdentifiers.
-            if private_identifiers.contains(&name.description()) {
-                private_identifiers.push(name.description());
+            if !private_identifiers.contains(description) {
+                private_identifiers.push(description.clone());
             }
         }
 
@@ -770,6 +776,239 @@ pub fn eval_declaration_instantiation<'a>(
     Ok(())
 }
 
+/// ### [19.2.6.5 Encode ( string, extraUnescaped )](https://tc39.es/ecma262/#sec-encode)
+///
+/// The abstract operation Encode takes arguments string (a String) and
+/// extraUnescaped (a String) and returns either a normal completion
+/// containing a String or a throw completion. It performs URI encoding and
+/// escaping, interpreting string as a sequence of UTF-16 encoded code points
+/// as described in 6.1.4. If a character is identified as unreserved in RFC
+/// 2396 or appears in extraUnescaped, it is not escaped.
+fn encode<'a>(
+    agent: &mut Agent,
+    string: String,
+    extra_unescaped: &str,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, String<'a>> {
+    use core::fmt::Write;
+
+    let string = string.bind(gc);
+    // 1. Let len be the length of string.
+    // 2. Let R be the empty String.
+    let mut r = std::string::String::with_capacity(string.len(agent));
+    // 3. Let alwaysUnescaped be the string-concatenation of the ASCII word
+    //    characters and "-.!~*'()".
+    // 4. Let unescapedSet be the string-concatenation of alwaysUnescaped and
+    //    extraUnescaped.
+    let is_unescaped = |c: char| {
+        c.is_ascii_alphanumeric() || "_-.!~*'()".contains(c) || extra_unescaped.contains(c)
+    };
+    // 5. Let k be 0.
+    // 6. Repeat, while k < len,
+    let well_formed = 'encode: {
+        for cp in string.as_wtf8(agent).code_points() {
+            // a. Let C be the code unit at index k within string.
+            // b. If unescapedSet contains C, then
+            if let Some(c) = cp.to_char().filter(|c| c.is_ascii() && is_unescaped(*c)) {
+                // i. Set k to k + 1.
+                // ii. Set R to the string-concatenation of R and C.
+                r.push(c);
+                continue;
+            }
+            // c. Else,
+            // i. Let cp be CodePointAt(string, k).
+            // ii. If cp.[[IsUnpairedSurrogate]] is true, throw a URIError
+            //     exception.
+            // NOTE: For now, all strings are well-formed Unicode: unpaired
+            // surrogates, eg. in '\uD800', are replaced with U+FFFD when the
+            // string is created. This error therefore cannot yet be observed.
+            let Some(c) = cp.to_char() else {
+                break 'encode false;
+            };
+            // iii. Set k to k + cp.[[CodeUnitCount]].
+            // iv. Let Octets be the List of octets resulting by applying the
+            //     UTF-8 transformation to cp.[[CodePoint]].
+            let mut octets = [0u8; 4];
+            // v. For each element octet of Octets, do
+            for octet in c.encode_utf8(&mut octets).as_bytes() {
+                // 1. Let hex be the String representation of octet, formatted as
+                //    an uppercase hexadecimal number.
+                // 2. Set R to the string-concatenation of R, "%", and
+                //    StringPad(hex, 2, "0", start).
+                write!(r, "%{octet:02X}").unwrap();
+            }
+        }
+        true
+    };
+    if !well_formed {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::UriError,
+            "URI malformed",
+            gc,
+        ));
+    }
+    // 7. Return R.
+    Ok(String::from_string(agent, r, gc))
+}
+
+/// ### [19.2.6.6 Decode ( string, preserveEscapeSet )](https://tc39.es/ecma262/#sec-decode)
+///
+/// The abstract operation Decode takes arguments string (a String) and
+/// preserveEscapeSet (a String) and returns either a normal completion
+/// containing a String or a throw completion. It performs URI unescaping and
+/// decoding, preserving any escape sequences that correspond to Basic Latin
+/// characters in preserveEscapeSet.
+fn decode<'a>(
+    agent: &mut Agent,
+    string: String,
+    preserve_escape_set: &str,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, String<'a>> {
+    let string = string.bind(gc);
+    let result = 'decode: {
+        // 1. Let len be the length of string.
+        let code_units = string
+            .as_wtf8(agent)
+            .to_ill_formed_utf16()
+            .collect::<Vec<u16>>();
+        let len = code_units.len();
+        // 2. Let R be the empty String.
+        let mut r = Vec::with_capacity(len);
+        // 3. Let k be 0.
+        let mut k = 0;
+        // 4. Repeat, while k < len,
+        while k < len {
+            // a. Let C be the code unit at index k within string.
+            let c = code_units[k];
+            // b. Let S be C.
+            // c. If C is the code unit 0x0025 (PERCENT SIGN), then
+            if c != u16::from(b'%') {
+                r.push(c);
+                k += 1;
+                continue;
+            }
+            // i. If k + 3 > len, throw a URIError exception.
+            if k + 3 > len {
+                break 'decode None;
+            }
+            // ii. Let escape be the substring of string from k to k + 3.
+            let escape = &code_units[k..k + 3];
+            // iii. Let B be ParseHexOctet(string, k + 1).
+            // iv. If B is not an integer, throw a URIError exception.
+            let Some(b) = parse_hex_octet(&code_units, k + 1) else {
+                break 'decode None;
+            };
+            // v. Set k to k + 2.
+            k += 2;
+            // vi. Let n be the number of leading 1 bits in B.
+            let n = b.leading_ones() as usize;
+            // vii. If n = 0, then
+            if n == 0 {
+                // 1. Let asciiChar be the code unit whose numeric value is B.
+                // 2. If preserveEscapeSet contains asciiChar, let S be
+                //    escape. Otherwise, let S be asciiChar.
+                if preserve_escape_set.as_bytes().contains(&b) {
+                    r.extend_from_slice(escape);
+                } else {
+                    r.push(u16::from(b));
+                }
+            } else {
+                // viii. Else,
+                // 1. If n = 1 or n > 4, throw a URIError exception.
+                if n == 1 || n > 4 {
+                    break 'decode None;
+                }
+                // 2. Let Octets be « B ».
+                let mut octets = [b, 0, 0, 0];
+                // 3. Let j be 1.
+                // 4. Repeat, while j < n,
+                for octet in octets.iter_mut().take(n).skip(1) {
+                    // a. Set k to k + 1.
+                    k += 1;
+                    // b. If k + 3 > len, throw a URIError exception.
+                    // c. If the code unit at index k within string is not the
+                    //    code unit 0x0025 (PERCENT SIGN), throw a URIError
+                    //    exception.
+                    if k + 3 > len || code_units[k] != u16::from(b'%') {
+                        break 'decode None;
+                    }
+                    // d. Let continuationByte be ParseHexOctet(string, k + 1).
+                    // e. If continuationByte is not an integer, throw a
+                    //    URIError exception.
+                    let Some(continuation_byte) = parse_hex_octet(&code_units, k + 1) else {
+                        break 'decode None;
+                    };
+                    // f. Append continuationByte to Octets.
+                    *octet = continuation_byte;
+                    // g. Set k to k + 2.
+                    k += 2;
+                    // h. Set j to j + 1.
+                }
+                // 5. Assert: The length of Octets is n.
+                // 6. If Octets does not contain a valid UTF-8 encoding of a
+                //    Unicode code point, throw a URIError exception.
+                // 7. Let V be the code point obtained by applying the UTF-8
+                //    transformation to Octets, that is, from a List of octets
+                //    into a 21-bit value.
+                // 8. Let S be UTF16EncodeCodePoint(V).
+                let Some(v) = core::str::from_utf8(&octets[..n])
+                    .ok()
+                    .and_then(|s| s.chars().next())
+                else {
+                    break 'decode None;
+                };
+                r.extend_from_slice(v.encode_utf16(&mut [0; 2]));
+            }
+            // d. Set R to the string-concatenation of R and S.
+            // e. Set k to k + 1.
+            k += 1;
+        }
+        // 5. Return R.
+        Some(r)
+    };
+    let Some(r) = result else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::UriError,
+            "URI malformed",
+            gc,
+        ));
+    };
+    // TODO: For now, all strings are well-formed Unicode. Unpaired
+    // surrogates copied over from string are replaced with U+FFFD.
+    let r = std::string::String::from_utf16_lossy(&r);
+    Ok(String::from_string(agent, r, gc))
+}
+
+/// ### [19.2.6.7 ParseHexOctet ( string, position )](https://tc39.es/ecma262/#sec-parsehexoctet)
+///
+/// The abstract operation ParseHexOctet takes arguments string (a String) and
+/// position (a non-negative integer) and returns either a non-negative
+/// integer or a non-empty List of SyntaxError objects. It parses a sequence
+/// of two hexadecimal characters at the specified position in string into an
+/// unsigned 8-bit integer.
+fn parse_hex_octet(string: &[u16], position: usize) -> Option<u8> {
+    // 1. Let len be the length of string.
+    // 2. Assert: position + 2 ≤ len.
+    // 3. Let hexDigits be the substring of string from position to position + 2.
+    let hex_digits = &string[position..position + 2];
+    // 4. Let parseResult be ParseText(hexDigits, HexDigits[~Sep]).
+    // 5. If parseResult is not a Parse Node, return parseResult.
+    // 6. Let n be the MV of parseResult.
+    // 7. Assert: n is in the inclusive interval from 0 to 255.
+    // 8. Return n.
+    hex_digits.iter().try_fold(0, |n, &d| {
+        let digit = char::from_u32(u32::from(d))?.to_digit(16)?;
+        Some((n << 4) | digit as u8)
+    })
+}
+
+/// Returns true if the ASCII code unit is in the escape function's
+/// unescapedSet: the ASCII word characters and "@*+-./".
+#[cfg(feature = "annex-b-global")]
+fn is_escape_unescaped(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || b"_@*+-./".contains(&c)
+}
+
 impl GlobalObject {
     /// ### [19.2.1 eval ( x )](https://tc39.es/ecma262/#sec-eval-x)
     ///
@@ -1085,53 +1324,234 @@ impl GlobalObject {
         }
     }
 
+    /// ### [19.2.6.1 decodeURI ( encodedURI )](https://tc39.es/ecma262/#sec-decodeuri-encodeduri)
+    ///
+    /// This function computes a new version of a URI in which each escape
+    /// sequence and UTF-8 encoding of the sort that might be introduced by
+    /// the encodeURI function is replaced with the UTF-16 encoding of the code
+    /// point that it represents. Escape sequences that could not have been
+    /// introduced by encodeURI are not replaced.
     fn decode_uri<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("decodeURI", gc.into_nogc()))
+        let encoded_uri = arguments.get(0).bind(gc.nogc());
+        // 1. Let uriString be ? ToString(encodedURI).
+        let uri_string = to_string(agent, encoded_uri.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let uri_string = uri_string.bind(gc);
+        // 2. Let preserveEscapeSet be ";/?:@&=+$,#".
+        // 3. Return ? Decode(uriString, preserveEscapeSet).
+        decode(agent, uri_string, ";/?:@&=+$,#", gc).map(|s| s.into_value())
     }
+
+    /// ### [19.2.6.2 decodeURIComponent ( encodedURIComponent )](https://tc39.es/ecma262/#sec-decodeuricomponent-encodeduricomponent)
+    ///
+    /// This function computes a new version of a URI in which each escape
+    /// sequence and UTF-8 encoding of the sort that might be introduced by
+    /// the encodeURIComponent function is replaced with the UTF-16 encoding of
+    /// the code point that it represents.
     fn decode_uri_component<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("decodeURIComponent", gc.into_nogc()))
+        let encoded_uri_component = arguments.get(0).bind(gc.nogc());
+        // 1. Let componentString be ? ToString(encodedURIComponent).
+        let component_string =
+            to_string(agent, encoded_uri_component.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let component_string = component_string.bind(gc);
+        // 2. Let preserveEscapeSet be the empty String.
+        // 3. Return ? Decode(componentString, preserveEscapeSet).
+        decode(agent, component_string, "", gc).map(|s| s.into_value())
     }
+
+    /// ### [19.2.6.3 encodeURI ( uri )](https://tc39.es/ecma262/#sec-encodeuri-uri)
+    ///
+    /// This function computes a new version of a UTF-16 encoded (6.1.4) URI
+    /// in which each instance of certain code points is replaced by one, two,
+    /// three, or four escape sequences representing the UTF-8 encoding of the
+    /// code point.
     fn encode_uri<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("encodeURI", gc.into_nogc()))
+        let uri = arguments.get(0).bind(gc.nogc());
+        // 1. Let uriString be ? ToString(uri).
+        let uri_string = to_string(agent, uri.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let uri_string = uri_string.bind(gc);
+        // 2. Let extraUnescaped be ";/?:@&=+$,#".
+        // 3. Return ? Encode(uriString, extraUnescaped).
+        encode(agent, uri_string, ";/?:@&=+$,#", gc).map(|s| s.into_value())
     }
+
+    /// ### [19.2.6.4 encodeURIComponent ( uriComponent )](https://tc39.es/ecma262/#sec-encodeuricomponent-uricomponent)
+    ///
+    /// This function computes a new version of a UTF-16 encoded (6.1.4) URI
+    /// in which each instance of certain code points is replaced by one, two,
+    /// three, or four escape sequences representing the UTF-8 encoding of the
+    /// code point.
     fn encode_uri_component<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("encodeURIComponent", gc.into_nogc()))
+        let uri_component = arguments.get(0).bind(gc.nogc());
+        // 1. Let componentString be ? ToString(uriComponent).
+        let component_string = to_string(agent, uri_component.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let component_string = component_string.bind(gc);
+        // 2. Let extraUnescaped be the empty String.
+        // 3. Return ? Encode(componentString, extraUnescaped).
+        encode(agent, component_string, "", gc).map(|s| s.into_value())
     }
+
+    /// ### [B.2.1.1 escape ( string )](https://tc39.es/ecma262/#sec-escape-string)
+    ///
+    /// This function is a property of the global object. It computes a new
+    /// version of a String value in which certain code units have been
+    /// replaced by a hexadecimal escape sequence.
+    #[cfg(feature = "annex-b-global")]
     fn escape<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("escape", gc.into_nogc()))
+        use core::fmt::Write;
+
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. Set string to ? ToString(string).
+        let string = to_string(agent, string.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let string = string.bind(gc);
+        // 2. Let len be the length of string.
+        // 3. Let R be the empty String.
+        let mut r = std::string::String::with_capacity(string.len(agent));
+        // 4. Let unescapedSet be the string-concatenation of the ASCII word
+        //    characters and "@*+-./".
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        // a. Let C be the code unit at index k within string.
+        for c in string.as_wtf8(agent).to_ill_formed_utf16() {
+            // b. If unescapedSet contains C, then
+            if c < 0x80 && is_escape_unescaped(c as u8) {
+                // i. Let S be C.
+                r.push(c as u8 as char);
+            } else {
+                // c. Else,
+                // i. Let n be the numeric value of C.
+                // ii. If n < 256, then
+                if c < 256 {
+                    // 1. Let hex be the String representation of n, formatted
+                    //    as an uppercase hexadecimal number.
+                    // 2. Let S be the string-concatenation of "%" and
+                    //    StringPad(hex, 2, "0", start).
+                    write!(r, "%{c:02X}").unwrap();
+                } else {
+                    // iii. Else,
+                    // 1. Let hex be the String representation of n, formatted
+                    //    as an uppercase hexadecimal number.
+                    // 2. Let S be the string-concatenation of "%u" and
+                    //    StringPad(hex, 4, "0", start).
+                    write!(r, "%u{c:04X}").unwrap();
+                }
+            }
+            // d. Set R to the string-concatenation of R and S.
+            // e. Set k to k + 1.
+        }
+        // 7. Return R.
+        Ok(String::from_string(agent, r, gc).into_value())
     }
+
+    /// ### [B.2.1.2 unescape ( string )](https://tc39.es/ecma262/#sec-unescape-string)
+    ///
+    /// This function is a property of the global object. It computes a new
+    /// version of a String value in which each escape sequence of the sort
+    /// that might be introduced by the escape function is replaced with the
+    /// code unit that it represents.
+    #[cfg(feature = "annex-b-global")]
     fn unescape<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("unescape", gc.into_nogc()))
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. Set string to ? ToString(string).
+        let string = to_string(agent, string.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let string = string.bind(gc);
+        let code_units = string
+            .as_wtf8(agent)
+            .to_ill_formed_utf16()
+            .collect::<Vec<u16>>();
+        // 2. Let len be the length of string.
+        let len = code_units.len();
+        // 3. Let R be the empty String.
+        let mut r = Vec::<u16>::with_capacity(len);
+        // 4. Let k be 0.
+        let mut k = 0;
+        // 5. Repeat, while k < len,
+        while k < len {
+            // a. Let C be the code unit at index k within string.
+            let mut c = code_units[k];
+            // b. If C is the code unit 0x0025 (PERCENT SIGN), then
+            if c == b'%' as u16 {
+                // i. Let hexDigits be the empty String.
+                // ii. Let optionalAdvance be 0.
+                // iii. If k + 5 < len and the code unit at index k + 1 within
+                //      string is the code unit 0x0075 (LATIN SMALL LETTER U),
+                //      then
+                let (hex_digits, optional_advance) =
+                    if k + 5 < len && code_units[k + 1] == b'u' as u16 {
+                        // 1. Set hexDigits to the substring of string from
+                        //    k + 2 to k + 6.
+                        // 2. Set optionalAdvance to 5.
+                        (&code_units[k + 2..k + 6], 5)
+                    } else if k + 3 <= len {
+                        // iv. Else if k + 3 ≤ len, then
+                        // 1. Set hexDigits to the substring of string from
+                        //    k + 1 to k + 3.
+                        // 2. Set optionalAdvance to 2.
+                        (&code_units[k + 1..k + 3], 2)
+                    } else {
+                        (&code_units[..0], 0)
+                    };
+                // v. Let parseResult be ParseText(hexDigits, HexDigits[~Sep]).
+                // vi. If parseResult is a Parse Node, then
+                if !hex_digits.is_empty()
+                    && hex_digits
+                        .iter()
+                        .all(|&d| d < 0x80 && (d as u8).is_ascii_hexdigit())
+                {
+                    // 1. Let n be the MV of parseResult.
+                    // 2. Set C to the code unit whose numeric value is n.
+                    c = hex_digits.iter().fold(0u16, |n, &d| {
+                        (n << 4) | (d as u8 as char).to_digit(16).unwrap() as u16
+                    });
+                    // 3. Set k to k + optionalAdvance.
+                    k += optional_advance;
+                }
+            }
+            // c. Set R to the string-concatenation of R and C.
+            r.push(c);
+            // d. Set k to k + 1.
+            k += 1;
+        }
+        // 6. Return R.
+        // TODO: For now, all strings are well-formed Unicode. Unpaired
+        // surrogates produced by unescape are replaced with U+FFFD.
+        let r = std::string::String::from_utf16_lossy(&r);
+        Ok(String::from_string(agent, r, gc).into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -1155,7 +1575,9 @@ impl GlobalObject {
             agent, realm,
         )
         .build();
+        #[cfg(feature = "annex-b-global")]
         BuiltinFunctionBuilder::new_intrinsic_function::<GlobalObjectEscape>(agent, realm).build();
+        #[cfg(feature = "annex-b-global")]
         BuiltinFunctionBuilder::new_intrinsic_function::<GlobalObjectUnescape>(agent, realm)
             .build();
     }
@@ -14,9 +14,9 @@ use crate::{
             type_conversion::{to_big_int, to_index, to_number},
         },
         builtins::{
-            ArgumentsList, ArrayBuffer, BuiltinFunction,
+            ArgumentsList, BuiltinFunction,
             array_buffer::{
-                Ordering, ViewedArrayBufferByteLength, allocate_array_buffer,
+                AnyArrayBuffer, Ordering, ViewedArrayBufferByteLength, allocate_array_buffer,
                 array_buffer_byte_length, clone_array_buffer, get_value_from_buffer,
                 is_detached_buffer, is_fixed_length_array_buffer, set_value_in_buffer,
             },
@@ -876,7 +876,7 @@ pub(crate) fn initialize_typed_array_from_typed_array<'a, O: Viewable, Src: View
     let heap_array_length = element_length.into();
 
     // 13. Set O.[[ViewedArrayBuffer]] to data.
-    o_heap_data.viewed_array_buffer = data.unbind();
+    o_heap_data.viewed_array_buffer = data.unbind().into();
     // 14. Set O.[[ByteLength]] to byteLength.
     o_heap_data.byte_length = heap_byte_length;
     // 15. Set O.[[ByteOffset]] to 0.
@@ -907,7 +907,7 @@ pub(crate) fn initialize_typed_array_from_typed_array<'a, O: Viewable, Src: View
 pub(crate) fn initialize_typed_array_from_array_buffer<'a, T: Viewable>(
     agent: &mut Agent,
     scoped_o: Scoped<TypedArray>,
-    scoped_buffer: Scoped<ArrayBuffer>,
+    scoped_buffer: Scoped<AnyArrayBuffer>,
     byte_offset: Option<Scoped<Value>>,
     length: Option<Scoped<Value>>,
     mut gc: GcScope<'a, '_>,
@@ -1184,7 +1184,7 @@ pub(crate) fn allocate_typed_array_buffer<'a, T: Viewable>(
     let heap_array_length = length.into();
 
     // 5. Set O.[[ViewedArrayBuffer]] to data.
-    o_heap_data.viewed_array_buffer = data.unbind();
+    o_heap_data.viewed_array_buffer = data.unbind().into();
     // 6. Set O.[[ByteLength]] to byteLength.
     o_heap_data.byte_length = heap_byte_length;
     // 7. Set O.[[ByteOffset]] to 0.
@@ -1330,7 +1330,7 @@ pub(crate) fn typed_array_create_from_constructor_with_length<'a>(
 pub(crate) fn typed_array_create_from_constructor_with_buffer<'a>(
     agent: &mut Agent,
     constructor: Function,
-    array_buffer: ArrayBuffer,
+    array_buffer: AnyArrayBuffer,
     byte_offset: i64,
     length: Option<i64>,
     mut gc: GcScope<'a, '_>,
@@ -1359,10 +1359,11 @@ pub(crate) fn typed_array_create_from_constructor_with_buffer<'a>(
     )
     .unbind()?
     .bind(gc.nogc());
+    // NOTE: argumentList is never a single Number here, so step 3 is skipped.
     typed_array_create_from_constructor_internal(
         agent,
         new_typed_array.unbind(),
-        length,
+        None,
         gc.into_nogc(),
     )
 }
@@ -1516,7 +1517,7 @@ pub(crate) fn typed_array_species_create_with_length<'a, T: Viewable + 'static>(
 pub(crate) fn typed_array_species_create_with_buffer<'a, T: Viewable + 'static>(
     agent: &mut Agent,
     exemplar: TypedArray,
-    array_buffer: ArrayBuffer,
+    array_buffer: AnyArrayBuffer,
     byte_offset: i64,
     length: Option<i64>,
     mut gc: GcScope<'a, '_>,
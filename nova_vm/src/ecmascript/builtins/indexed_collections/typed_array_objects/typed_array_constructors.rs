@@ -9,7 +9,7 @@ use crate::ecmascript::abstract_operations::operations_on_objects::{
     get_method, throw_not_callable,
 };
 use crate::ecmascript::abstract_operations::type_conversion::{to_index, try_to_index};
-use crate::ecmascript::builtins::ArrayBuffer;
+use crate::ecmascript::builtins::array_buffer::AnyArrayBuffer;
 use crate::ecmascript::builtins::indexed_collections::typed_array_objects::abstract_operations::{
     allocate_typed_array, initialize_typed_array_from_array_buffer,
     initialize_typed_array_from_array_like, initialize_typed_array_from_list,
@@ -863,7 +863,7 @@ fn typed_array_constructor<'gc, T: Viewable>(
                 .unbind()?
                 .bind(gc.nogc()),
             }
-        } else if let Ok(first_argument) = ArrayBuffer::try_from(first_argument) {
+        } else if let Ok(first_argument) = AnyArrayBuffer::try_from(first_argument) {
             // SAFETY: scoped_first_argument is not shared.
             let scoped_first_argument =
                 unsafe { scoped_first_argument.replace_self(agent, first_argument.unbind()) };
@@ -10,8 +10,8 @@ use crate::{
         abstract_operations::{
             operations_on_iterator_objects::{get_iterator_from_method, iterator_to_list},
             operations_on_objects::{
-                call_function, get, get_method, length_of_array_like, set, throw_not_callable,
-                try_get, try_set,
+                call_function, get, get_method, invoke, length_of_array_like, set,
+                throw_not_callable, try_get, try_set,
             },
             testing_and_comparison::{is_array, is_callable, is_constructor, same_value_zero},
             type_conversion::{
@@ -26,7 +26,10 @@ use crate::{
         builtins::{
             ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsic,
             BuiltinIntrinsicConstructor,
-            array_buffer::{Ordering, get_value_from_buffer, is_detached_buffer},
+            array_buffer::{
+                AnyArrayBuffer, Ordering, clone_array_buffer, get_value_from_buffer,
+                is_detached_buffer, set_value_in_buffer,
+            },
             indexed_collections::array_objects::{
                 array_iterator_objects::array_iterator::{ArrayIterator, CollectionIteratorKind},
                 array_prototype::find_via_predicate,
@@ -52,10 +55,11 @@ use crate::{
 };
 
 use super::abstract_operations::{
-    TypedArrayWithBufferWitnessRecords, is_typed_array_out_of_bounds,
+    TypedArrayWithBufferWitnessRecords, is_typed_array_out_of_bounds, is_valid_integer_index,
     make_typed_array_with_buffer_witness_record, typed_array_byte_length,
     typed_array_create_from_constructor_with_length, typed_array_create_same_type,
-    typed_array_length, typed_array_species_create_with_length, validate_typed_array,
+    typed_array_length, typed_array_set_element, typed_array_species_create_with_buffer,
+    typed_array_species_create_with_length, validate_typed_array,
 };
 
 pub struct TypedArrayIntrinsicObject;
@@ -2621,13 +2625,126 @@ impl TypedArrayPrototype {
         Ok(Value::try_from(length).unwrap())
     }
 
+    /// ### [23.2.3.22 %TypedArray%.prototype.map ( callback \[ , thisArg \] )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.map)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.map as defined in 23.1.3.21.
+    ///
+    /// This method is not generic. The this value must be an object with a
+    /// `[[TypedArrayName]]` internal slot.
     fn map<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("TypedArray.prototype.map", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let callback = arguments.get(0).bind(gc.nogc());
+        let this_arg = arguments.get(1).bind(gc.nogc());
+        // 1. Let O be the this value.
+        let o = this_value;
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let o = ta_record.object;
+        // 4. If IsCallable(callback) is false, throw a TypeError exception.
+        let Some(callback) = is_callable(callback, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        // 3. Let len be TypedArrayLength(taRecord).
+        let a = match o {
+            TypedArray::Int8Array(_) => map_typed_array::<i8>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint8Array(_) => map_typed_array::<u8>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint8ClampedArray(_) => map_typed_array::<U8Clamped>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::Int16Array(_) => map_typed_array::<i16>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint16Array(_) => map_typed_array::<u16>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::Int32Array(_) => map_typed_array::<i32>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint32Array(_) => map_typed_array::<u32>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::BigInt64Array(_) => map_typed_array::<i64>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::BigUint64Array(_) => map_typed_array::<u64>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            #[cfg(feature = "proposal-float16array")]
+            TypedArray::Float16Array(_) => map_typed_array::<f16>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::Float32Array(_) => map_typed_array::<f32>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+            TypedArray::Float64Array(_) => map_typed_array::<f64>(
+                agent,
+                callback.unbind(),
+                this_arg.unbind(),
+                ta_record.unbind(),
+                gc,
+            )?,
+        };
+        Ok(a.into_value())
     }
 
     /// ### [23.2.3.23 %TypedArray%.prototype.reduce ( callback [ , initialValue ] )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.reduce)
@@ -2913,173 +3030,503 @@ impl TypedArrayPrototype {
         Ok(o.into_value())
     }
 
+    /// ### [23.2.3.26 %TypedArray%.prototype.set ( source \[ , offset \] )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.set)
+    ///
+    /// This method sets multiple values in this TypedArray, reading the
+    /// values from source. The details differ based upon the type of source.
+    /// The optional offset value indicates the first element index in this
+    /// TypedArray where values are written. If omitted, it is assumed to be
+    /// 0.
     fn set<'gc>(
-        agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
-    ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("TypedArray.prototype.set", gc.into_nogc()))
-    }
-
-    fn slice<'gc>(
-        agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
-    ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("TypedArray.prototype.slice", gc.into_nogc()))
-    }
-
-    /// ### [23.2.3.28 get %TypedArray%.prototype.some](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.some)
-    fn some<'gc>(
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
         mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let nogc = gc.nogc();
-        let this_value = this_value.bind(nogc);
-        let callback = arguments.get(0).bind(nogc);
-        let this_arg = arguments.get(1).bind(nogc);
-        // 1. Let O be the this value.
-        let o = this_value;
-        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
-        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, nogc)
-            .unbind()?
-            .bind(nogc);
-        let mut o = ta_record.object;
-        // 3. Let len be TypedArrayLength(taRecord).
-        let len = match o {
-            TypedArray::Int8Array(_)
-            | TypedArray::Uint8Array(_)
-            | TypedArray::Uint8ClampedArray(_) => typed_array_length::<u8>(agent, &ta_record, nogc),
-            TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => {
-                typed_array_length::<u16>(agent, &ta_record, nogc)
-            }
-            #[cfg(feature = "proposal-float16array")]
-            TypedArray::Float16Array(_) => typed_array_length::<f16>(agent, &ta_record, nogc),
-            TypedArray::Int32Array(_)
-            | TypedArray::Uint32Array(_)
-            | TypedArray::Float32Array(_) => typed_array_length::<u32>(agent, &ta_record, nogc),
-            TypedArray::BigInt64Array(_)
-            | TypedArray::BigUint64Array(_)
-            | TypedArray::Float64Array(_) => typed_array_length::<u64>(agent, &ta_record, nogc),
-        };
-        // 4. If IsCallable(callback) is false, throw a TypeError exception.
-        let Some(callback) = is_callable(callback, nogc) else {
-            return Err(agent.throw_exception_with_static_message(
-                ExceptionType::TypeError,
-                "Callback is not callable",
-                gc.into_nogc(),
-            ));
-        };
-        let callback = callback.scope(agent, nogc);
-        let this_arg = this_arg.scope(agent, nogc);
-        let scoped_o = o.scope(agent, nogc);
-        // 5. Let k be 0.
-        let mut k = 0;
-        // 6. Repeat, while k < len,
-        while k < len {
-            // a. Let Pk be ! ToString(𝔽(k)).
-            let pk = PropertyKey::from(SmallInteger::from(k as u32));
-            // b. Let kValue be ! Get(O, Pk).
-            let k_value = unwrap_try(try_get(agent, o, pk, gc.nogc()));
-            // c. Let testResult be ToBoolean(? Call(callback, thisArg, « kValue, 𝔽(k), O »)).
-            let call = call_function(
-                agent,
-                callback.get(agent),
-                this_arg.get(agent),
-                Some(ArgumentsList::from_mut_slice(&mut [
-                    k_value.unbind(),
-                    Number::try_from(k).unwrap().into_value().unbind(),
-                    o.into_value().unbind(),
-                ])),
-                gc.reborrow(),
-            )
+        let this_value = this_value.bind(gc.nogc());
+        let mut source = arguments.get(0).bind(gc.nogc());
+        let offset = arguments.get(1).bind(gc.nogc());
+        // 1. Let target be the this value.
+        // 2. Perform ? RequireInternalSlot(target, [[TypedArrayName]]).
+        // 3. Assert: target has a [[ViewedArrayBuffer]] internal slot.
+        let mut target = require_internal_slot_typed_array(agent, this_value, gc.nogc())
             .unbind()?
             .bind(gc.nogc());
-            let test_result = to_boolean(agent, call);
-            // d. If testResult is true, return true.
-            if test_result {
-                return Ok(true.into());
-            }
-            // e. Set k to k + 1.
-            o = scoped_o.get(agent).bind(gc.nogc());
-            k += 1;
-        }
-        // 7. Return false.
-        Ok(false.into())
-    }
-
-    /// ### [23.2.3.29 %TypedArray%.prototype.sort ( comparator )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.sort)
-    /// This is a distinct method that, except as described below,
-    /// implements the same requirements as those of Array.prototype.sort as defined in 23.1.3.30.
-    /// The implementation of this method may be optimized with the knowledge that
-    /// the this value is an object that has a fixed length and whose integer-indexed properties are not sparse.
-    /// This method is not generic. The this value must be an object with a [[TypedArrayName]] internal slot.
-    fn sort<'gc>(
-        agent: &mut Agent,
-        this_value: Value,
-        arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
-    ) -> JsResult<'gc, Value<'gc>> {
-        let nogc = gc.nogc();
-        let this_value = this_value.bind(nogc);
-        let comparator = arguments.get(0).bind(nogc);
-        // 1. If comparator is not undefined and IsCallable(comparator) is false, throw a TypeError exception.
-        let comparator = if comparator.is_undefined() {
-            None
-        } else if let Some(comparator) = is_callable(comparator, nogc) {
-            Some(comparator.scope(agent, nogc))
+        // 4. Let targetOffset be ? ToIntegerOrInfinity(offset).
+        let target_offset = if let Value::Integer(offset) = offset {
+            offset.into_i64()
         } else {
+            let scoped_target = target.scope(agent, gc.nogc());
+            let scoped_source = source.scope(agent, gc.nogc());
+            let result = to_integer_or_infinity(agent, offset.unbind(), gc.reborrow())
+                .unbind()?
+                .into_i64();
+            let gc = gc.nogc();
+            target = scoped_target.get(agent).bind(gc);
+            source = scoped_source.get(agent).bind(gc);
+            result
+        };
+        // 5. If targetOffset < 0, throw a RangeError exception.
+        if target_offset < 0 {
             return Err(agent.throw_exception_with_static_message(
-                ExceptionType::TypeError,
-                "The comparison function must be either a function or undefined",
+                ExceptionType::RangeError,
+                "Offset must not be negative",
                 gc.into_nogc(),
             ));
-        };
-        // 2. Let obj be the this value.
-        let obj = this_value;
-        // 3. Let taRecord be ? ValidateTypedArray(obj, seq-cst).
-        let ta_record = validate_typed_array(agent, obj, Ordering::SeqCst, nogc)
-            .unbind()?
-            .bind(gc.nogc());
-        // 4. Let len be TypedArrayLength(taRecord).
-        // 5. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.30.
-        // 6. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparator and performs the following steps when called:
-        //    a. Return ? CompareTypedArrayElements(x, y, comparator).
-        // 7. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, read-through-holes).
-        if let Some(comparator) = comparator {
-            let obj = ta_record.object;
-            let scoped_obj = ta_record.object.scope(agent, nogc);
-            match obj {
-                TypedArray::Int8Array(_) => sort_comparator_typed_array::<i8>(
+        }
+        // 6. If source is an Object that has a [[TypedArrayName]] internal
+        //    slot, then
+        if let Ok(source) = TypedArray::try_from(source) {
+            let target = target.unbind();
+            let source = source.unbind();
+            let gc = gc.into_nogc();
+            let target = target.bind(gc);
+            let source = source.bind(gc);
+            // a. Perform ? SetTypedArrayFromTypedArray(target, targetOffset, source).
+            match target {
+                TypedArray::Int8Array(_) => set_typed_array_from_typed_array::<i8>(
                     agent,
-                    ta_record.unbind(),
-                    scoped_obj.clone(),
-                    comparator,
+                    target,
+                    target_offset,
+                    source,
                     gc,
                 )?,
-                TypedArray::Uint8Array(_) => sort_comparator_typed_array::<u8>(
+                TypedArray::Uint8Array(_) => set_typed_array_from_typed_array::<u8>(
                     agent,
-                    ta_record.unbind(),
-                    scoped_obj.clone(),
-                    comparator,
+                    target,
+                    target_offset,
+                    source,
                     gc,
                 )?,
-                TypedArray::Uint8ClampedArray(_) => sort_comparator_typed_array::<U8Clamped>(
+                TypedArray::Uint8ClampedArray(_) => set_typed_array_from_typed_array::<U8Clamped>(
                     agent,
-                    ta_record.unbind(),
-                    scoped_obj.clone(),
-                    comparator,
+                    target,
+                    target_offset,
+                    source,
                     gc,
                 )?,
-                TypedArray::Int16Array(_) => sort_comparator_typed_array::<i16>(
+                TypedArray::Int16Array(_) => set_typed_array_from_typed_array::<i16>(
                     agent,
-                    ta_record.unbind(),
-                    scoped_obj.clone(),
-                    comparator,
+                    target,
+                    target_offset,
+                    source,
+                    gc,
+                )?,
+                TypedArray::Uint16Array(_) => set_typed_array_from_typed_array::<u16>(
+                    agent,
+                    target,
+                    target_offset,
+                    source,
+                    gc,
+                )?,
+                TypedArray::Int32Array(_) => set_typed_array_from_typed_array::<i32>(
+                    agent,
+                    target,
+                    target_offset,
+                    source,
+                    gc,
+                )?,
+                TypedArray::Uint32Array(_) => set_typed_array_from_typed_array::<u32>(
+                    agent,
+                    target,
+                    target_offset,
+                    source,
+                    gc,
+                )?,
+                TypedArray::BigInt64Array(_) => set_typed_array_from_typed_array::<i64>(
+                    agent,
+                    target,
+                    target_offset,
+                    source,
+                    gc,
+                )?,
+                TypedArray::BigUint64Array(_) => set_typed_array_from_typed_array::<u64>(
+                    agent,
+                    target,
+                    target_offset,
+                    source,
+                    gc,
+                )?,
+                #[cfg(feature = "proposal-float16array")]
+                TypedArray::Float16Array(_) => set_typed_array_from_typed_array::<f16>(
+                    agent,
+                    target,
+                    target_offset,
+                    source,
+                    gc,
+                )?,
+                TypedArray::Float32Array(_) => set_typed_array_from_typed_array::<f32>(
+                    agent,
+                    target,
+                    target_offset,
+                    source,
+                    gc,
+                )?,
+                TypedArray::Float64Array(_) => set_typed_array_from_typed_array::<f64>(
+                    agent,
+                    target,
+                    target_offset,
+                    source,
+                    gc,
+                )?,
+            }
+        } else {
+            // 7. Else,
+            // a. Perform ? SetTypedArrayFromArrayLike(target, targetOffset, source).
+            match target {
+                TypedArray::Int8Array(_) => set_typed_array_from_array_like::<i8>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::Uint8Array(_) => set_typed_array_from_array_like::<u8>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::Uint8ClampedArray(_) => set_typed_array_from_array_like::<U8Clamped>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::Int16Array(_) => set_typed_array_from_array_like::<i16>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::Uint16Array(_) => set_typed_array_from_array_like::<u16>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::Int32Array(_) => set_typed_array_from_array_like::<i32>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::Uint32Array(_) => set_typed_array_from_array_like::<u32>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::BigInt64Array(_) => set_typed_array_from_array_like::<i64>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::BigUint64Array(_) => set_typed_array_from_array_like::<u64>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                #[cfg(feature = "proposal-float16array")]
+                TypedArray::Float16Array(_) => set_typed_array_from_array_like::<f16>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::Float32Array(_) => set_typed_array_from_array_like::<f32>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+                TypedArray::Float64Array(_) => set_typed_array_from_array_like::<f64>(
+                    agent,
+                    target.unbind(),
+                    target_offset,
+                    source.unbind(),
+                    gc,
+                )?,
+            }
+        }
+        // 8. Return undefined.
+        Ok(Value::Undefined)
+    }
+
+    /// ### [23.2.3.27 %TypedArray%.prototype.slice ( start, end )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.slice)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.slice as defined in 23.1.3.28.
+    ///
+    /// This method is not generic. The this value must be an object with a
+    /// `[[TypedArrayName]]` internal slot.
+    fn slice<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let start = arguments.get(0).bind(gc.nogc());
+        let end = arguments.get(1).bind(gc.nogc());
+        // 1. Let O be the this value.
+        let o = this_value;
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let o = ta_record.object;
+        let a = match o {
+            TypedArray::Int8Array(_) => slice_typed_array::<i8>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint8Array(_) => slice_typed_array::<u8>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint8ClampedArray(_) => slice_typed_array::<U8Clamped>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::Int16Array(_) => slice_typed_array::<i16>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint16Array(_) => slice_typed_array::<u16>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::Int32Array(_) => slice_typed_array::<i32>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint32Array(_) => slice_typed_array::<u32>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::BigInt64Array(_) => slice_typed_array::<i64>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::BigUint64Array(_) => slice_typed_array::<u64>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            #[cfg(feature = "proposal-float16array")]
+            TypedArray::Float16Array(_) => slice_typed_array::<f16>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::Float32Array(_) => slice_typed_array::<f32>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::Float64Array(_) => slice_typed_array::<f64>(
+                agent,
+                ta_record.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+        };
+        Ok(a.into_value())
+    }
+
+    /// ### [23.2.3.28 get %TypedArray%.prototype.some](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.some)
+    fn some<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let callback = arguments.get(0).bind(nogc);
+        let this_arg = arguments.get(1).bind(nogc);
+        // 1. Let O be the this value.
+        let o = this_value;
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, nogc)
+            .unbind()?
+            .bind(nogc);
+        let mut o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = match o {
+            TypedArray::Int8Array(_)
+            | TypedArray::Uint8Array(_)
+            | TypedArray::Uint8ClampedArray(_) => typed_array_length::<u8>(agent, &ta_record, nogc),
+            TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => {
+                typed_array_length::<u16>(agent, &ta_record, nogc)
+            }
+            #[cfg(feature = "proposal-float16array")]
+            TypedArray::Float16Array(_) => typed_array_length::<f16>(agent, &ta_record, nogc),
+            TypedArray::Int32Array(_)
+            | TypedArray::Uint32Array(_)
+            | TypedArray::Float32Array(_) => typed_array_length::<u32>(agent, &ta_record, nogc),
+            TypedArray::BigInt64Array(_)
+            | TypedArray::BigUint64Array(_)
+            | TypedArray::Float64Array(_) => typed_array_length::<u64>(agent, &ta_record, nogc),
+        };
+        // 4. If IsCallable(callback) is false, throw a TypeError exception.
+        let Some(callback) = is_callable(callback, nogc) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        let callback = callback.scope(agent, nogc);
+        let this_arg = this_arg.scope(agent, nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 5. Let k be 0.
+        let mut k = 0;
+        // 6. Repeat, while k < len,
+        while k < len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk = PropertyKey::from(SmallInteger::from(k as u32));
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = unwrap_try(try_get(agent, o, pk, gc.nogc()));
+            // c. Let testResult be ToBoolean(? Call(callback, thisArg, « kValue, 𝔽(k), O »)).
+            let call = call_function(
+                agent,
+                callback.get(agent),
+                this_arg.get(agent),
+                Some(ArgumentsList::from_mut_slice(&mut [
+                    k_value.unbind(),
+                    Number::try_from(k).unwrap().into_value().unbind(),
+                    o.into_value().unbind(),
+                ])),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            let test_result = to_boolean(agent, call);
+            // d. If testResult is true, return true.
+            if test_result {
+                return Ok(true.into());
+            }
+            // e. Set k to k + 1.
+            o = scoped_o.get(agent).bind(gc.nogc());
+            k += 1;
+        }
+        // 7. Return false.
+        Ok(false.into())
+    }
+
+    /// ### [23.2.3.29 %TypedArray%.prototype.sort ( comparator )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.sort)
+    /// This is a distinct method that, except as described below,
+    /// implements the same requirements as those of Array.prototype.sort as defined in 23.1.3.30.
+    /// The implementation of this method may be optimized with the knowledge that
+    /// the this value is an object that has a fixed length and whose integer-indexed properties are not sparse.
+    /// This method is not generic. The this value must be an object with a [[TypedArrayName]] internal slot.
+    fn sort<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let comparator = arguments.get(0).bind(nogc);
+        // 1. If comparator is not undefined and IsCallable(comparator) is false, throw a TypeError exception.
+        let comparator = if comparator.is_undefined() {
+            None
+        } else if let Some(comparator) = is_callable(comparator, nogc) {
+            Some(comparator.scope(agent, nogc))
+        } else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "The comparison function must be either a function or undefined",
+                gc.into_nogc(),
+            ));
+        };
+        // 2. Let obj be the this value.
+        let obj = this_value;
+        // 3. Let taRecord be ? ValidateTypedArray(obj, seq-cst).
+        let ta_record = validate_typed_array(agent, obj, Ordering::SeqCst, nogc)
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let len be TypedArrayLength(taRecord).
+        // 5. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.30.
+        // 6. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparator and performs the following steps when called:
+        //    a. Return ? CompareTypedArrayElements(x, y, comparator).
+        // 7. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, read-through-holes).
+        if let Some(comparator) = comparator {
+            let obj = ta_record.object;
+            let scoped_obj = ta_record.object.scope(agent, nogc);
+            match obj {
+                TypedArray::Int8Array(_) => sort_comparator_typed_array::<i8>(
+                    agent,
+                    ta_record.unbind(),
+                    scoped_obj.clone(),
+                    comparator,
+                    gc,
+                )?,
+                TypedArray::Uint8Array(_) => sort_comparator_typed_array::<u8>(
+                    agent,
+                    ta_record.unbind(),
+                    scoped_obj.clone(),
+                    comparator,
+                    gc,
+                )?,
+                TypedArray::Uint8ClampedArray(_) => sort_comparator_typed_array::<U8Clamped>(
+                    agent,
+                    ta_record.unbind(),
+                    scoped_obj.clone(),
+                    comparator,
+                    gc,
+                )?,
+                TypedArray::Int16Array(_) => sort_comparator_typed_array::<i16>(
+                    agent,
+                    ta_record.unbind(),
+                    scoped_obj.clone(),
+                    comparator,
                     gc,
                 )?,
                 TypedArray::Uint16Array(_) => sort_comparator_typed_array::<u16>(
@@ -3190,22 +3637,165 @@ impl TypedArrayPrototype {
         }
     }
 
+    /// ### [23.2.3.30 %TypedArray%.prototype.subarray ( start, end )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.subarray)
+    ///
+    /// This method returns a new TypedArray whose element type is the element
+    /// type of this TypedArray and whose ArrayBuffer is the ArrayBuffer of
+    /// this TypedArray, referencing the elements in the interval from start
+    /// (inclusive) to end (exclusive). If either start or end is negative, it
+    /// refers to an index from the end of the array, as opposed to from the
+    /// beginning.
     fn subarray<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("TypedArray.prototype.subarray", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let start = arguments.get(0).bind(gc.nogc());
+        let end = arguments.get(1).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
+        // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
+        let o = require_internal_slot_typed_array(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let a = match o {
+            TypedArray::Int8Array(_) => {
+                subarray_typed_array::<i8>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            TypedArray::Uint8Array(_) => {
+                subarray_typed_array::<u8>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            TypedArray::Uint8ClampedArray(_) => subarray_typed_array::<U8Clamped>(
+                agent,
+                o.unbind(),
+                start.unbind(),
+                end.unbind(),
+                gc,
+            )?,
+            TypedArray::Int16Array(_) => {
+                subarray_typed_array::<i16>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            TypedArray::Uint16Array(_) => {
+                subarray_typed_array::<u16>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            TypedArray::Int32Array(_) => {
+                subarray_typed_array::<i32>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            TypedArray::Uint32Array(_) => {
+                subarray_typed_array::<u32>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            TypedArray::BigInt64Array(_) => {
+                subarray_typed_array::<i64>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            TypedArray::BigUint64Array(_) => {
+                subarray_typed_array::<u64>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            #[cfg(feature = "proposal-float16array")]
+            TypedArray::Float16Array(_) => {
+                subarray_typed_array::<f16>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            TypedArray::Float32Array(_) => {
+                subarray_typed_array::<f32>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+            TypedArray::Float64Array(_) => {
+                subarray_typed_array::<f64>(agent, o.unbind(), start.unbind(), end.unbind(), gc)?
+            }
+        };
+        Ok(a.into_value())
     }
 
+    /// ### [23.2.3.31 %TypedArray%.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.tolocalestring)
+    ///
+    /// This is a distinct method that implements the same algorithm as
+    /// Array.prototype.toLocaleString as defined in 23.1.3.32 except that
+    /// TypedArrayLength is called in place of performing a \[\[Get\]\] of
+    /// "length".
+    ///
+    /// This method is not generic. ValidateTypedArray is called with the this
+    /// value and seq-cst as arguments prior to evaluating the algorithm. If
+    /// its result is an abrupt completion that exception is thrown instead of
+    /// evaluating the algorithm.
     fn to_locale_string<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("TypedArray.prototype.toLocaleString", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 1. Let array be ? ToObject(this value).
+        let o = ta_record.object;
+        // 2. Let len be ? LengthOfArrayLike(array).
+        let len = match o {
+            TypedArray::Int8Array(_)
+            | TypedArray::Uint8Array(_)
+            | TypedArray::Uint8ClampedArray(_) => {
+                typed_array_length::<u8>(agent, &ta_record, gc.nogc())
+            }
+            TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => {
+                typed_array_length::<u16>(agent, &ta_record, gc.nogc())
+            }
+            #[cfg(feature = "proposal-float16array")]
+            TypedArray::Float16Array(_) => typed_array_length::<f16>(agent, &ta_record, gc.nogc()),
+            TypedArray::Int32Array(_)
+            | TypedArray::Uint32Array(_)
+            | TypedArray::Float32Array(_) => {
+                typed_array_length::<u32>(agent, &ta_record, gc.nogc())
+            }
+            TypedArray::BigInt64Array(_)
+            | TypedArray::BigUint64Array(_)
+            | TypedArray::Float64Array(_) => {
+                typed_array_length::<u64>(agent, &ta_record, gc.nogc())
+            }
+        } as i64;
+        let o = o.scope(agent, gc.nogc());
+        // 3. Let separator be the implementation-defined list-separator
+        //    String appropriate for the host environment's current locale
+        //    (such as ", ").
+        let separator = ",";
+        // 4. Let R be the empty String.
+        let mut r = std::string::String::new();
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. If k > 0, set R to the string-concatenation of R and
+            //    separator.
+            if k > 0 {
+                r.push_str(separator);
+            }
+            // b. Let element be ? Get(array, ! ToString(𝔽(k))).
+            let element = unwrap_try(try_get(
+                agent,
+                o.get(agent),
+                PropertyKey::try_from(k).unwrap(),
+                gc.nogc(),
+            ));
+            // c. If element is neither undefined nor null, then
+            if !element.is_undefined() && !element.is_null() {
+                // i. Let S be ? ToString(? Invoke(element, "toLocaleString")).
+                let s = invoke(
+                    agent,
+                    element.unbind(),
+                    BUILTIN_STRING_MEMORY.toLocaleString.to_property_key(),
+                    None,
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                let s = to_string(agent, s.unbind(), gc.reborrow())
+                    .unbind()?
+                    .bind(gc.nogc());
+                // ii. Set R to the string-concatenation of R and S.
+                r.push_str(s.as_str(agent));
+            }
+            // d. Set k to k + 1.
+        }
+        // 7. Return R.
+        Ok(String::from_string(agent, r, gc.into_nogc()).into_value())
     }
 
     /// ### [23.2.3.32 %TypedArray%.prototype.toReversed ( )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-array.prototype.tospliced)
@@ -3280,13 +3870,104 @@ impl TypedArrayPrototype {
         Ok(scope_a.get(agent).into_value())
     }
 
+    /// ### [23.2.3.33 %TypedArray%.prototype.toSorted ( comparator )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.tosorted)
     fn to_sorted<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("TypedArray.prototype.toSorted", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let comparator = arguments.get(0).bind(gc.nogc());
+        // 1. If comparator is not undefined and IsCallable(comparator) is
+        //    false, throw a TypeError exception.
+        if !comparator.is_undefined() && is_callable(comparator, gc.nogc()).is_none() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "The comparison function must be either a function or undefined",
+                gc.into_nogc(),
+            ));
+        }
+        // 2. Let O be the this value.
+        let o = this_value;
+        // 3. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let o = ta_record.object;
+        // 4. Let len be TypedArrayLength(taRecord).
+        let (len, element_size) = match o {
+            TypedArray::Int8Array(_)
+            | TypedArray::Uint8Array(_)
+            | TypedArray::Uint8ClampedArray(_) => (
+                typed_array_length::<u8>(agent, &ta_record, gc.nogc()),
+                core::mem::size_of::<u8>(),
+            ),
+            TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => (
+                typed_array_length::<u16>(agent, &ta_record, gc.nogc()),
+                core::mem::size_of::<u16>(),
+            ),
+            #[cfg(feature = "proposal-float16array")]
+            TypedArray::Float16Array(_) => (
+                typed_array_length::<f16>(agent, &ta_record, gc.nogc()),
+                core::mem::size_of::<f16>(),
+            ),
+            TypedArray::Int32Array(_)
+            | TypedArray::Uint32Array(_)
+            | TypedArray::Float32Array(_) => (
+                typed_array_length::<u32>(agent, &ta_record, gc.nogc()),
+                core::mem::size_of::<u32>(),
+            ),
+            TypedArray::BigInt64Array(_)
+            | TypedArray::BigUint64Array(_)
+            | TypedArray::Float64Array(_) => (
+                typed_array_length::<u64>(agent, &ta_record, gc.nogc()),
+                core::mem::size_of::<u64>(),
+            ),
+        };
+        let scoped_o = o.scope(agent, gc.nogc());
+        let comparator = comparator.scope(agent, gc.nogc());
+        // 5. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = typed_array_create_same_type(agent, o.unbind(), len as i64, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // NOTE: Creating A cannot call into user code, so O is still valid
+        // and has len elements. We copy its elements into A and then sort A
+        // in place: A is not observable to the comparator, so the result is
+        // the same as sorting a list of O's elements and writing them into A.
+        if len > 0 {
+            let o = scoped_o.get(agent).bind(gc.nogc());
+            let o_buffer = o.get_viewed_array_buffer(agent, gc.nogc());
+            let o_byte_offset = o.byte_offset(agent);
+            let a_buffer = a.get_viewed_array_buffer(agent, gc.nogc());
+            let a_byte_offset = a.byte_offset(agent);
+            a_buffer.copy_array_buffer_data(
+                agent,
+                a_byte_offset,
+                o_buffer,
+                o_byte_offset,
+                len * element_size,
+            );
+        }
+        // 6. NOTE: The following closure performs a numeric comparison rather
+        //    than the string comparison used in 23.1.3.34.
+        // 7. Let SortCompare be a new Abstract Closure with parameters (x, y)
+        //    that captures comparator and performs the following steps when
+        //    called:
+        //    a. Return ? CompareTypedArrayElements(x, y, comparator).
+        // 8. Let sortedList be ? SortIndexedProperties(O, len, SortCompare,
+        //    read-through-holes).
+        // 9. Let j be 0.
+        // 10. Repeat, while j < len,
+        //     a. Perform ! Set(A, ! ToString(𝔽(j)), sortedList[j], true).
+        //     b. Set j to j + 1.
+        // 11. Return A.
+        Self::sort(
+            agent,
+            a.into_value().unbind(),
+            ArgumentsList::from_mut_slice(&mut [comparator.get(agent)]),
+            gc,
+        )
     }
 
     /// ### [23.2.3.35 %TypedArray%.prototype.values ( )](https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-%symbol.tostringtag%)
@@ -3307,13 +3988,111 @@ impl TypedArrayPrototype {
         )
     }
 
+    /// ### [23.2.3.36 %TypedArray%.prototype.with ( index, value )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.with)
     fn with<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("TypedArray.prototype.with", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let index = arguments.get(0).bind(gc.nogc());
+        let value = arguments.get(1).bind(gc.nogc());
+        // 1. Let O be the this value.
+        let o = this_value;
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let o = ta_record.object;
+        let a = match o {
+            TypedArray::Int8Array(_) => with_typed_array::<i8>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint8Array(_) => with_typed_array::<u8>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint8ClampedArray(_) => with_typed_array::<U8Clamped>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::Int16Array(_) => with_typed_array::<i16>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint16Array(_) => with_typed_array::<u16>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::Int32Array(_) => with_typed_array::<i32>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::Uint32Array(_) => with_typed_array::<u32>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::BigInt64Array(_) => with_typed_array::<i64>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::BigUint64Array(_) => with_typed_array::<u64>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            #[cfg(feature = "proposal-float16array")]
+            TypedArray::Float16Array(_) => with_typed_array::<f16>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::Float32Array(_) => with_typed_array::<f32>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+            TypedArray::Float64Array(_) => with_typed_array::<f64>(
+                agent,
+                ta_record.unbind(),
+                index.unbind(),
+                value.unbind(),
+                gc,
+            )?,
+        };
+        Ok(a.into_value())
     }
 
     /// ### [23.2.3.38 get %TypedArray%.prototype \[ %Symbol.toStringTag% \]](https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-%symbol.tostringtag%)
@@ -3511,7 +4290,7 @@ fn reverse_typed_array<'a, T: Viewable + Copy + std::fmt::Debug>(
     let array_buffer = ta.get_viewed_array_buffer(agent, gc);
     let byte_offset = ta.byte_offset(agent);
     let byte_length = ta.byte_length(agent);
-    let byte_slice = array_buffer.as_mut_slice(agent);
+    let mut byte_slice = array_buffer.as_mut_slice(agent);
     if byte_slice.is_empty() {
         return Ok(());
     }
@@ -3561,7 +4340,7 @@ fn copy_within_typed_array<'a, T: Viewable + std::fmt::Debug>(
     let len = typed_array_length::<T>(agent, &ta_record, gc) as usize;
     let byte_offset = ta.byte_offset(agent);
     let byte_length = ta.byte_length(agent);
-    let byte_slice = array_buffer.as_mut_slice(agent);
+    let mut byte_slice = array_buffer.as_mut_slice(agent);
     if byte_slice.is_empty() {
         return Ok(());
     }
@@ -3683,7 +4462,7 @@ fn fill_typed_array<'a, T: Viewable>(
     let array_buffer = ta.get_viewed_array_buffer(agent, gc);
     let byte_offset = ta.byte_offset(agent);
     let byte_length = ta.byte_length(agent);
-    let byte_slice = array_buffer.as_mut_slice(agent);
+    let mut byte_slice = array_buffer.as_mut_slice(agent);
     if byte_slice.is_empty() {
         return Ok(ta);
     }
@@ -3728,7 +4507,7 @@ fn sort_total_cmp_typed_array<'a, T: Viewable + std::fmt::Debug + Ord>(
     let array_buffer = ta.get_viewed_array_buffer(agent, gc);
     let byte_offset = ta.byte_offset(agent);
     let byte_length = ta.byte_length(agent);
-    let byte_slice = array_buffer.as_mut_slice(agent);
+    let mut byte_slice = array_buffer.as_mut_slice(agent);
     if byte_slice.is_empty() {
         return Ok(());
     }
@@ -3841,7 +4620,7 @@ fn sort_ecmascript_cmp_typed_array<'a, T: Viewable + std::fmt::Debug + ECMAScrip
     let array_buffer = ta.get_viewed_array_buffer(agent, gc);
     let byte_offset = ta.byte_offset(agent);
     let byte_length = ta.byte_length(agent);
-    let byte_slice = array_buffer.as_mut_slice(agent);
+    let mut byte_slice = array_buffer.as_mut_slice(agent);
     if byte_slice.is_empty() {
         return Ok(());
     }
@@ -3943,7 +4722,7 @@ fn sort_comparator_typed_array<'a, T: Viewable + Copy + std::fmt::Debug>(
         return Err(error);
     }
     let array_buffer = ta.get(agent).get_viewed_array_buffer(agent, gc.nogc());
-    let byte_slice = array_buffer.as_mut_slice(agent);
+    let mut byte_slice = array_buffer.as_mut_slice(agent);
     if byte_slice.is_empty() {
         return Ok(());
     }
@@ -4057,7 +4836,7 @@ fn filter_typed_array<'a, T: Viewable + 'static + std::fmt::Debug>(
     let array_buffer = a.get_viewed_array_buffer(agent, gc.nogc());
     let byte_offset = a.byte_offset(agent);
     let byte_length = a.byte_length(agent);
-    let byte_slice = array_buffer.as_mut_slice(agent);
+    let mut byte_slice = array_buffer.as_mut_slice(agent);
     if byte_slice.is_empty() {
         return Ok(a.unbind());
     }
@@ -4161,6 +4940,716 @@ fn filter_typed_array<'a, T: Viewable + 'static + std::fmt::Debug>(
     Ok(a.unbind())
 }
 
+fn map_typed_array<'a, T: Viewable + 'static>(
+    agent: &mut Agent,
+    callback: Function,
+    this_arg: Value,
+    ta_record: TypedArrayWithBufferWitnessRecords,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, TypedArray<'a>> {
+    let ta_record = ta_record.bind(gc.nogc());
+    let callback = callback.scope(agent, gc.nogc());
+    let this_arg = this_arg.scope(agent, gc.nogc());
+    let o = ta_record.object;
+    let scoped_o = o.scope(agent, gc.nogc());
+    // 3. Let len be TypedArrayLength(taRecord).
+    let len = typed_array_length::<T>(agent, &ta_record, gc.nogc()) as i64;
+    // 5. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(len) »).
+    let a = typed_array_species_create_with_length::<T>(agent, o.unbind(), len, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    let a = a.scope(agent, gc.nogc());
+    // 6. Let k be 0.
+    // 7. Repeat, while k < len,
+    for k in 0..len {
+        // a. Let Pk be ! ToString(𝔽(k)).
+        let pk = PropertyKey::try_from(k).unwrap();
+        // b. Let kValue be ! Get(O, Pk).
+        let k_value = unwrap_try(try_get(agent, scoped_o.get(agent), pk, gc.nogc()));
+        // c. Let mappedValue be ? Call(callback, thisArg, « kValue, 𝔽(k), O »).
+        let mapped_value = call_function(
+            agent,
+            callback.get(agent),
+            this_arg.get(agent),
+            Some(ArgumentsList::from_mut_slice(&mut [
+                k_value.unbind(),
+                Number::try_from(k).unwrap().into_value(),
+                scoped_o.get(agent).into_value(),
+            ])),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // d. Perform ? Set(A, Pk, mappedValue, true).
+        set(
+            agent,
+            a.get(agent).into_object(),
+            pk,
+            mapped_value.unbind(),
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // e. Set k to k + 1.
+    }
+    // 8. Return A.
+    Ok(a.get(agent).bind(gc.into_nogc()))
+}
+
+/// ### [23.2.3.26.1 SetTypedArrayFromTypedArray ( target, targetOffset, source )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-settypedarrayfromtypedarray)
+///
+/// The abstract operation SetTypedArrayFromTypedArray takes arguments target
+/// (a TypedArray), targetOffset (a non-negative integer or +∞), and source (a
+/// TypedArray) and returns either a normal completion containing unused or a
+/// throw completion. It sets multiple values in target, starting at index
+/// targetOffset, reading the values from source.
+fn set_typed_array_from_typed_array<'a, T: Viewable>(
+    agent: &mut Agent,
+    target: TypedArray,
+    target_offset: i64,
+    source: TypedArray,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    match source {
+        TypedArray::Int8Array(_) => set_typed_array_from_typed_array_internal::<T, i8>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        TypedArray::Uint8Array(_) => set_typed_array_from_typed_array_internal::<T, u8>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        TypedArray::Uint8ClampedArray(_) => {
+            set_typed_array_from_typed_array_internal::<T, U8Clamped>(
+                agent,
+                target,
+                target_offset,
+                source,
+                gc,
+            )
+        }
+        TypedArray::Int16Array(_) => set_typed_array_from_typed_array_internal::<T, i16>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        TypedArray::Uint16Array(_) => set_typed_array_from_typed_array_internal::<T, u16>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        TypedArray::Int32Array(_) => set_typed_array_from_typed_array_internal::<T, i32>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        TypedArray::Uint32Array(_) => set_typed_array_from_typed_array_internal::<T, u32>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        TypedArray::BigInt64Array(_) => set_typed_array_from_typed_array_internal::<T, i64>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        TypedArray::BigUint64Array(_) => set_typed_array_from_typed_array_internal::<T, u64>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        #[cfg(feature = "proposal-float16array")]
+        TypedArray::Float16Array(_) => set_typed_array_from_typed_array_internal::<T, f16>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        TypedArray::Float32Array(_) => set_typed_array_from_typed_array_internal::<T, f32>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+        TypedArray::Float64Array(_) => set_typed_array_from_typed_array_internal::<T, f64>(
+            agent,
+            target,
+            target_offset,
+            source,
+            gc,
+        ),
+    }
+}
+
+fn set_typed_array_from_typed_array_internal<'a, T: Viewable, Src: Viewable>(
+    agent: &mut Agent,
+    target: TypedArray,
+    target_offset: i64,
+    source: TypedArray,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let target = target.bind(gc);
+    let source = source.bind(gc);
+    // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
+    let target_buffer = target.get_viewed_array_buffer(agent, gc);
+    // 2. Let targetRecord be MakeTypedArrayWithBufferWitnessRecord(target, seq-cst).
+    let target_record =
+        make_typed_array_with_buffer_witness_record(agent, target, Ordering::SeqCst, gc);
+    // 3. If IsTypedArrayOutOfBounds(targetRecord) is true, throw a TypeError exception.
+    if is_typed_array_out_of_bounds::<T>(agent, &target_record, gc) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    }
+    // 4. Let targetLength be TypedArrayLength(targetRecord).
+    let target_length = typed_array_length::<T>(agent, &target_record, gc);
+    // 5. Let srcBuffer be source.[[ViewedArrayBuffer]].
+    let mut src_buffer = source.get_viewed_array_buffer(agent, gc);
+    // 6. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(source, seq-cst).
+    let src_record =
+        make_typed_array_with_buffer_witness_record(agent, source, Ordering::SeqCst, gc);
+    // 7. If IsTypedArrayOutOfBounds(srcRecord) is true, throw a TypeError exception.
+    if is_typed_array_out_of_bounds::<Src>(agent, &src_record, gc) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    }
+    // 8. Let srcLength be TypedArrayLength(srcRecord).
+    let src_length = typed_array_length::<Src>(agent, &src_record, gc);
+    // 9. Let targetType be TypedArrayElementType(target).
+    // 10. Let targetElementSize be TypedArrayElementSize(target).
+    let target_element_size = core::mem::size_of::<T>();
+    // 11. Let targetByteOffset be target.[[ByteOffset]].
+    let target_byte_offset = target.byte_offset(agent);
+    // 12. Let srcType be TypedArrayElementType(source).
+    // 13. Let srcElementSize be TypedArrayElementSize(source).
+    let src_element_size = core::mem::size_of::<Src>();
+    // 14. Let srcByteOffset be source.[[ByteOffset]].
+    let src_byte_offset = source.byte_offset(agent);
+    // 15. If targetOffset = +∞, throw a RangeError exception.
+    // 16. If srcLength + targetOffset > targetLength, throw a RangeError exception.
+    if target_offset == i64::MAX
+        || (src_length as i64).saturating_add(target_offset) > target_length as i64
+    {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Source is too large",
+            gc,
+        ));
+    }
+    let target_offset = target_offset as usize;
+    // 17. If target.[[ContentType]] is not source.[[ContentType]], throw a TypeError exception.
+    if T::IS_BIGINT != Src::IS_BIGINT {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray content type mismatch",
+            gc,
+        ));
+    }
+    if src_length == 0 {
+        return Ok(());
+    }
+    // 18. If IsSharedArrayBuffer(srcBuffer) is true,
+    //     IsSharedArrayBuffer(targetBuffer) is true, and
+    //     srcBuffer.[[ArrayBufferData]] is targetBuffer.[[ArrayBufferData]],
+    //     let sameSharedArrayBuffer be true; otherwise, let
+    //     sameSharedArrayBuffer be false.
+    #[cfg(feature = "shared-array-buffer")]
+    let same_shared_array_buffer = if let (
+        AnyArrayBuffer::SharedArrayBuffer(src_buffer),
+        AnyArrayBuffer::SharedArrayBuffer(target_buffer),
+    ) = (src_buffer, target_buffer)
+    {
+        agent[src_buffer]
+            .data_block
+            .ptr_eq(&agent[target_buffer].data_block)
+    } else {
+        false
+    };
+    #[cfg(not(feature = "shared-array-buffer"))]
+    let same_shared_array_buffer = false;
+    let same_buffer = src_buffer == target_buffer || same_shared_array_buffer;
+    // 21. Let targetByteIndex be (targetOffset × targetElementSize) + targetByteOffset.
+    let mut target_byte_index = target_offset * target_element_size + target_byte_offset;
+    // 22. Let limit be targetByteIndex + (targetElementSize × srcLength).
+    let limit = target_byte_index + target_element_size * src_length;
+    // 23. If srcType is targetType, then
+    if T::PROTO == Src::PROTO {
+        // a. NOTE: The transfer must be performed in a manner that preserves
+        //    the bit-level encoding of the source data.
+        // b. Repeat, while targetByteIndex < limit,
+        //    i. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, uint8, true, unordered).
+        //    ii. Perform SetValueInBuffer(targetBuffer, targetByteIndex, uint8, value, true, unordered).
+        //    iii. Set srcByteIndex to srcByteIndex + 1.
+        //    iv. Set targetByteIndex to targetByteIndex + 1.
+        let count = limit - target_byte_index;
+        if same_buffer {
+            // NOTE: Copying within a single buffer has the same result as
+            // copying from a clone of the source data.
+            target_buffer
+                .as_mut_slice(agent)
+                .copy_within(src_byte_offset..src_byte_offset + count, target_byte_index);
+        } else {
+            target_buffer.copy_array_buffer_data(
+                agent,
+                target_byte_index,
+                src_buffer,
+                src_byte_offset,
+                count,
+            );
+        }
+        return Ok(());
+    }
+    // 19. If SameValue(srcBuffer, targetBuffer) is true or
+    //     sameSharedArrayBuffer is true, then
+    let mut src_byte_index = if same_buffer {
+        // a. Let srcByteLength be TypedArrayByteLength(srcRecord).
+        let src_byte_length = typed_array_byte_length::<Src>(agent, &src_record, gc);
+        // b. Set srcBuffer to ? CloneArrayBuffer(srcBuffer, srcByteOffset, srcByteLength).
+        src_buffer =
+            clone_array_buffer(agent, src_buffer, src_byte_offset, src_byte_length, gc)?.into();
+        // c. Let srcByteIndex be 0.
+        0
+    } else {
+        // 20. Else,
+        // a. Let srcByteIndex be srcByteOffset.
+        src_byte_offset
+    };
+    // 24. Else,
+    // a. Repeat, while targetByteIndex < limit,
+    while target_byte_index < limit {
+        // i. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, srcType, true, unordered).
+        let value = get_value_from_buffer::<Src>(
+            agent,
+            src_buffer,
+            src_byte_index,
+            true,
+            Ordering::Unordered,
+            None,
+            gc,
+        );
+        // ii. Perform SetValueInBuffer(targetBuffer, targetByteIndex, targetType, value, true, unordered).
+        set_value_in_buffer::<T>(
+            agent,
+            target_buffer,
+            target_byte_index,
+            value,
+            true,
+            Ordering::Unordered,
+            None,
+        );
+        // iii. Set srcByteIndex to srcByteIndex + srcElementSize.
+        src_byte_index += src_element_size;
+        // iv. Set targetByteIndex to targetByteIndex + targetElementSize.
+        target_byte_index += target_element_size;
+    }
+    // 25. Return unused.
+    Ok(())
+}
+
+/// ### [23.2.3.26.2 SetTypedArrayFromArrayLike ( target, targetOffset, source )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-settypedarrayfromarraylike)
+///
+/// The abstract operation SetTypedArrayFromArrayLike takes arguments target
+/// (a TypedArray), targetOffset (a non-negative integer or +∞), and source (an
+/// ECMAScript language value, but not a TypedArray) and returns either a
+/// normal completion containing unused or a throw completion. It sets
+/// multiple values in target, starting at index targetOffset, reading the
+/// values from source.
+fn set_typed_array_from_array_like<'a, T: Viewable>(
+    agent: &mut Agent,
+    target: TypedArray,
+    target_offset: i64,
+    source: Value,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let target = target.bind(gc.nogc());
+    let source = source.bind(gc.nogc());
+    // 1. Let targetRecord be MakeTypedArrayWithBufferWitnessRecord(target, seq-cst).
+    let target_record =
+        make_typed_array_with_buffer_witness_record(agent, target, Ordering::SeqCst, gc.nogc());
+    // 2. If IsTypedArrayOutOfBounds(targetRecord) is true, throw a TypeError exception.
+    if is_typed_array_out_of_bounds::<T>(agent, &target_record, gc.nogc()) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc.into_nogc(),
+        ));
+    }
+    // 3. Let targetLength be TypedArrayLength(targetRecord).
+    let target_length = typed_array_length::<T>(agent, &target_record, gc.nogc()) as i64;
+    let target = target.scope(agent, gc.nogc());
+    // 4. Let src be ? ToObject(source).
+    let src = to_object(agent, source, gc.nogc())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 5. Let srcLength be ? LengthOfArrayLike(src).
+    let src_length = length_of_array_like(agent, src.get(agent), gc.reborrow()).unbind()?;
+    // 6. If targetOffset = +∞, throw a RangeError exception.
+    // 7. If srcLength + targetOffset > targetLength, throw a RangeError exception.
+    if target_offset == i64::MAX || src_length.saturating_add(target_offset) > target_length {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Source is too large",
+            gc.into_nogc(),
+        ));
+    }
+    // 8. Let k be 0.
+    // 9. Repeat, while k < srcLength,
+    for k in 0..src_length {
+        // a. Let Pk be ! ToString(𝔽(k)).
+        let pk = PropertyKey::try_from(k).unwrap();
+        // b. Let value be ? Get(src, Pk).
+        let value = get(agent, src.get(agent), pk, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // c. Let targetIndex be 𝔽(targetOffset + k).
+        let target_index = target_offset + k;
+        // d. Perform ? TypedArraySetElement(target, targetIndex, value).
+        typed_array_set_element::<T>(
+            agent,
+            target.get(agent),
+            target_index,
+            value.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // e. Set k to k + 1.
+    }
+    // 10. Return unused.
+    Ok(())
+}
+
+fn slice_typed_array<'a, T: Viewable + 'static>(
+    agent: &mut Agent,
+    ta_record: TypedArrayWithBufferWitnessRecords,
+    start: Value,
+    end: Value,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, TypedArray<'a>> {
+    let ta_record = ta_record.bind(gc.nogc());
+    let start = start.bind(gc.nogc());
+    let end = end.scope(agent, gc.nogc());
+    let o = ta_record.object;
+    let scoped_o = o.scope(agent, gc.nogc());
+    // 3. Let srcArrayLength be TypedArrayLength(taRecord).
+    let src_array_length = typed_array_length::<T>(agent, &ta_record, gc.nogc()) as i64;
+    // 4. Let relativeStart be ? ToIntegerOrInfinity(start).
+    let relative_start = to_integer_or_infinity(agent, start.unbind(), gc.reborrow()).unbind()?;
+    // 5. If relativeStart = -∞, let startIndex be 0.
+    let start_index = if relative_start.is_neg_infinity() {
+        0
+    } else if relative_start.is_negative() {
+        // 6. Else if relativeStart < 0, let startIndex be max(srcArrayLength + relativeStart, 0).
+        (src_array_length + relative_start.into_i64()).max(0)
+    } else {
+        // 7. Else, let startIndex be min(relativeStart, srcArrayLength).
+        relative_start.into_i64().min(src_array_length)
+    };
+    // 8. If end is undefined, let relativeEnd be srcArrayLength; else let relativeEnd be ? ToIntegerOrInfinity(end).
+    // SAFETY: end has not been shared.
+    let end = unsafe { end.take(agent) }.bind(gc.nogc());
+    let end_index = if end.is_undefined() {
+        src_array_length
+    } else {
+        let relative_end = to_integer_or_infinity(agent, end.unbind(), gc.reborrow()).unbind()?;
+        // 9. If relativeEnd = -∞, let endIndex be 0.
+        if relative_end.is_neg_infinity() {
+            0
+        } else if relative_end.is_negative() {
+            // 10. Else if relativeEnd < 0, let endIndex be max(srcArrayLength + relativeEnd, 0).
+            (src_array_length + relative_end.into_i64()).max(0)
+        } else {
+            // 11. Else, let endIndex be min(relativeEnd, srcArrayLength).
+            relative_end.into_i64().min(src_array_length)
+        }
+    };
+    // 12. Let countBytes be max(endIndex - startIndex, 0).
+    let count_bytes = (end_index - start_index).max(0);
+    // 13. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(countBytes) »).
+    let a = typed_array_species_create_with_length::<T>(
+        agent,
+        scoped_o.get(agent),
+        count_bytes,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let a = a.bind(gc);
+    let o = scoped_o.get(agent).bind(gc);
+    // 14. If countBytes > 0, then
+    if count_bytes > 0 {
+        // a. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        let ta_record = make_typed_array_with_buffer_witness_record(agent, o, Ordering::SeqCst, gc);
+        // b. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        if is_typed_array_out_of_bounds::<T>(agent, &ta_record, gc) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "TypedArray out of bounds",
+                gc,
+            ));
+        }
+        // c. Set endIndex to min(endIndex, TypedArrayLength(taRecord)).
+        let end_index = end_index.min(typed_array_length::<T>(agent, &ta_record, gc) as i64);
+        // d. Set countBytes to max(endIndex - startIndex, 0).
+        let count_bytes = (end_index - start_index).max(0) as usize;
+        let start_index = start_index as usize;
+        // e. Let srcType be TypedArrayElementType(O).
+        // f. Let targetType be TypedArrayElementType(A).
+        // g. If srcType is targetType, then
+        if core::mem::discriminant(&o) == core::mem::discriminant(&a) {
+            // i. NOTE: The transfer must be performed in a manner that
+            //    preserves the bit-level encoding of the source data.
+            // ii. Let srcBuffer be O.[[ViewedArrayBuffer]].
+            let src_buffer = o.get_viewed_array_buffer(agent, gc);
+            // iii. Let targetBuffer be A.[[ViewedArrayBuffer]].
+            let target_buffer = a.get_viewed_array_buffer(agent, gc);
+            // iv. Let elementSize be TypedArrayElementSize(O).
+            let element_size = core::mem::size_of::<T>();
+            // v. Let srcByteOffset be O.[[ByteOffset]].
+            let src_byte_offset = o.byte_offset(agent);
+            // vi. Let srcByteIndex be (startIndex × elementSize) + srcByteOffset.
+            let src_byte_index = start_index * element_size + src_byte_offset;
+            // vii. Let targetByteIndex be A.[[ByteOffset]].
+            let target_byte_index = a.byte_offset(agent);
+            // viii. Let endByteIndex be targetByteIndex + (countBytes × elementSize).
+            let count = count_bytes * element_size;
+            // ix. Repeat, while targetByteIndex < endByteIndex,
+            //     1. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, uint8, true, unordered).
+            //     2. Perform SetValueInBuffer(targetBuffer, targetByteIndex, uint8, value, true, unordered).
+            //     3. Set srcByteIndex to srcByteIndex + 1.
+            //     4. Set targetByteIndex to targetByteIndex + 1.
+            if src_buffer == target_buffer {
+                target_buffer
+                    .as_mut_slice(agent)
+                    .copy_within(src_byte_index..src_byte_index + count, target_byte_index);
+            } else {
+                target_buffer.copy_array_buffer_data(
+                    agent,
+                    target_byte_index,
+                    src_buffer,
+                    src_byte_index,
+                    count,
+                );
+            }
+        } else {
+            // h. Else,
+            // i. Let n be 0.
+            // ii. Let k be startIndex.
+            // iii. Repeat, while k < endIndex,
+            for (n, k) in (start_index..start_index + count_bytes).enumerate() {
+                // 1. Let Pk be ! ToString(𝔽(k)).
+                let pk = PropertyKey::try_from(k as i64).unwrap();
+                // 2. Let kValue be ! Get(O, Pk).
+                let k_value = unwrap_try(try_get(agent, o, pk, gc));
+                // 3. Perform ! Set(A, ! ToString(𝔽(n)), kValue, true).
+                unwrap_try(try_set(
+                    agent,
+                    a.into_object(),
+                    PropertyKey::try_from(n as i64).unwrap(),
+                    k_value,
+                    true,
+                    gc,
+                ))
+                .unwrap();
+                // 4. Set k to k + 1.
+                // 5. Set n to n + 1.
+            }
+        }
+    }
+    // 15. Return A.
+    Ok(a)
+}
+
+fn subarray_typed_array<'a, T: Viewable + 'static>(
+    agent: &mut Agent,
+    o: TypedArray,
+    start: Value,
+    end: Value,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, TypedArray<'a>> {
+    let o = o.bind(gc.nogc());
+    let start = start.bind(gc.nogc());
+    let end = end.scope(agent, gc.nogc());
+    // 4. Let buffer be O.[[ViewedArrayBuffer]].
+    let buffer = o.get_viewed_array_buffer(agent, gc.nogc());
+    // 5. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+    let src_record =
+        make_typed_array_with_buffer_witness_record(agent, o, Ordering::SeqCst, gc.nogc());
+    // 6. If IsTypedArrayOutOfBounds(srcRecord) is true, then
+    let src_length = if is_typed_array_out_of_bounds::<T>(agent, &src_record, gc.nogc()) {
+        // a. Let srcLength be 0.
+        0
+    } else {
+        // 7. Else,
+        // a. Let srcLength be TypedArrayLength(srcRecord).
+        typed_array_length::<T>(agent, &src_record, gc.nogc()) as i64
+    };
+    let scoped_o = o.scope(agent, gc.nogc());
+    let buffer = buffer.scope(agent, gc.nogc());
+    // 8. Let relativeStart be ? ToIntegerOrInfinity(start).
+    let relative_start = to_integer_or_infinity(agent, start.unbind(), gc.reborrow()).unbind()?;
+    // 9. If relativeStart = -∞, let startIndex be 0.
+    let start_index = if relative_start.is_neg_infinity() {
+        0
+    } else if relative_start.is_negative() {
+        // 10. Else if relativeStart < 0, let startIndex be max(srcLength + relativeStart, 0).
+        (src_length + relative_start.into_i64()).max(0)
+    } else {
+        // 11. Else, let startIndex be min(relativeStart, srcLength).
+        relative_start.into_i64().min(src_length)
+    };
+    let o = scoped_o.get(agent).bind(gc.nogc());
+    // 12. Let elementSize be TypedArrayElementSize(O).
+    let element_size = core::mem::size_of::<T>() as i64;
+    // 13. Let srcByteOffset be O.[[ByteOffset]].
+    let src_byte_offset = o.byte_offset(agent) as i64;
+    // 14. Let beginByteOffset be srcByteOffset + (startIndex × elementSize).
+    let begin_byte_offset = src_byte_offset + start_index * element_size;
+    // SAFETY: end has not been shared.
+    let end = unsafe { end.take(agent) }.bind(gc.nogc());
+    // 15. If O.[[ArrayLength]] is auto and end is undefined, then
+    let new_length = if o.array_length(agent).is_none() && end.is_undefined() {
+        // a. Let argumentsList be « buffer, 𝔽(beginByteOffset) ».
+        None
+    } else {
+        // 16. Else,
+        // a. If end is undefined, let relativeEnd be srcLength; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        let end_index = if end.is_undefined() {
+            src_length
+        } else {
+            let relative_end =
+                to_integer_or_infinity(agent, end.unbind(), gc.reborrow()).unbind()?;
+            // b. If relativeEnd = -∞, let endIndex be 0.
+            if relative_end.is_neg_infinity() {
+                0
+            } else if relative_end.is_negative() {
+                // c. Else if relativeEnd < 0, let endIndex be max(srcLength + relativeEnd, 0).
+                (src_length + relative_end.into_i64()).max(0)
+            } else {
+                // d. Else, let endIndex be min(relativeEnd, srcLength).
+                relative_end.into_i64().min(src_length)
+            }
+        };
+        // e. Let newLength be max(endIndex - startIndex, 0).
+        // f. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
+        Some((end_index - start_index).max(0))
+    };
+    // 17. Return ? TypedArraySpeciesCreate(O, argumentsList).
+    typed_array_species_create_with_buffer::<T>(
+        agent,
+        scoped_o.get(agent),
+        buffer.get(agent),
+        begin_byte_offset,
+        new_length,
+        gc,
+    )
+}
+
+fn with_typed_array<'a, T: Viewable>(
+    agent: &mut Agent,
+    ta_record: TypedArrayWithBufferWitnessRecords,
+    index: Value,
+    value: Value,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, TypedArray<'a>> {
+    let ta_record = ta_record.bind(gc.nogc());
+    let index = index.bind(gc.nogc());
+    let value = value.scope(agent, gc.nogc());
+    let o = ta_record.object;
+    let scoped_o = o.scope(agent, gc.nogc());
+    // 3. Let len be TypedArrayLength(taRecord).
+    let len = typed_array_length::<T>(agent, &ta_record, gc.nogc()) as i64;
+    // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+    let relative_index = if let Value::Integer(index) = index {
+        index.into_i64()
+    } else {
+        to_integer_or_infinity(agent, index.unbind(), gc.reborrow())
+            .unbind()?
+            .into_i64()
+    };
+    // 5. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+    let actual_index = if relative_index >= 0 {
+        relative_index
+    } else {
+        // 6. Else, let actualIndex be len + relativeIndex.
+        len + relative_index
+    };
+    // SAFETY: value has not been shared.
+    let value = unsafe { value.take(agent) }.bind(gc.nogc());
+    let numeric_value = if T::IS_BIGINT {
+        // 7. If O.[[ContentType]] is bigint, let numericValue be ? ToBigInt(value).
+        to_big_int(agent, value.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
+            .into_value()
+    } else {
+        // 8. Else, let numericValue be ? ToNumber(value).
+        to_number(agent, value.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
+            .into_value()
+    };
+    // 9. If IsValidIntegerIndex(O, 𝔽(actualIndex)) is false, throw a RangeError exception.
+    if is_valid_integer_index::<T>(agent, scoped_o.get(agent), actual_index, gc.nogc()).is_none() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Index out of bounds",
+            gc.into_nogc(),
+        ));
+    }
+    let numeric_value = numeric_value.scope(agent, gc.nogc());
+    // 10. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+    let a = typed_array_create_same_type(agent, scoped_o.get(agent), len, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    let a = a.scope(agent, gc.nogc());
+    // 11. Let k be 0.
+    // 12. Repeat, while k < len,
+    for k in 0..len {
+        // a. Let Pk be ! ToString(𝔽(k)).
+        let pk = PropertyKey::try_from(k).unwrap();
+        let from_value = if k == actual_index {
+            // b. If k = actualIndex, let fromValue be numericValue.
+            numeric_value.get(agent)
+        } else {
+            // c. Else, let fromValue be ! Get(O, Pk).
+            unwrap_try(try_get(agent, scoped_o.get(agent), pk, gc.nogc()))
+        };
+        // d. Perform ! Set(A, Pk, fromValue, true).
+        // NOTE: If O was shrunk by the conversion of value, fromValue may be
+        // undefined which can throw when converted to a BigInt.
+        typed_array_set_element::<T>(agent, a.get(agent), k, from_value.unbind(), gc.reborrow())
+            .unbind()?;
+        // e. Set k to k + 1.
+    }
+    // 13. Return A.
+    Ok(a.get(agent).bind(gc.into_nogc()))
+}
+
 fn copy_between_different_type_typed_arrays<Src: Viewable, Dst: Viewable>(
     kept: &[Src],
     byte_slice: &mut [u8],
@@ -412,11 +412,12 @@ pub fn add_entries_from_iterable_map_constructor<'a>(
 
     add_entries_from_iterable(
         agent,
-        target.unbind(),
+        target.into_object().unbind(),
         iterable.unbind(),
         adder.unbind(),
         gc,
     )
+    .map(|target| Map::try_from(target).unwrap())
 }
 
 /// ### [24.1.1.2 AddEntriesFromIterable ( target, iterable, adder )](https://tc39.es/ecma262/#sec-add-entries-from-iterable)
@@ -434,11 +435,11 @@ pub fn add_entries_from_iterable_map_constructor<'a>(
 /// > key.
 pub(crate) fn add_entries_from_iterable<'a>(
     agent: &mut Agent,
-    target: Map,
+    target: Object,
     iterable: Value,
     adder: Function,
     mut gc: GcScope<'a, '_>,
-) -> JsResult<'a, Map<'a>> {
+) -> JsResult<'a, Object<'a>> {
     let nogc = gc.nogc();
     let target = target.scope(agent, nogc);
     let iterable = iterable.bind(nogc);
@@ -2,13 +2,21 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::engine::context::GcScope;
 use crate::{
     ecmascript::{
+        abstract_operations::{operations_on_objects::get, testing_and_comparison::is_callable},
         builders::builtin_function_builder::BuiltinFunctionBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, IntoObject, Object, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            keyed_collections::map_objects::map_constructor::add_entries_from_iterable,
+            ordinary::ordinary_create_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
     },
     heap::IntrinsicConstructorIndexes,
 };
@@ -26,14 +34,74 @@ impl BuiltinIntrinsicConstructor for WeakMapConstructor {
 }
 
 impl WeakMapConstructor {
+    /// ### [24.3.1.1 WeakMap ( \[ iterable \] )](https://tc39.es/ecma262/#sec-weakmap-iterable)
     fn constructor<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
-        _new_target: Option<Object>,
-        gc: GcScope<'gc, '_>,
+        _: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("WeakMap", gc.into_nogc()))
+        let nogc = gc.nogc();
+        let iterable = arguments.get(0).bind(nogc);
+        let new_target = new_target.bind(nogc);
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor WeakMap requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        // 4. If iterable is either undefined or null, return map.
+        if iterable.is_undefined() || iterable.is_null() {
+            // 2. Let map be ? OrdinaryCreateFromConstructor(NewTarget,
+            //    "%WeakMap.prototype%", « [[WeakMapData]] »).
+            // 3. Set map.[[WeakMapData]] to a new empty List.
+            return Ok(ordinary_create_from_constructor(
+                agent,
+                new_target.unbind(),
+                ProtoIntrinsics::WeakMap,
+                gc,
+            )?
+            .into_value());
+        }
+        let iterable = iterable.scope(agent, nogc);
+        let map = ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::WeakMap,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .scope(agent, gc.nogc());
+        // 5. Let adder be ? Get(map, "set").
+        let adder = get(
+            agent,
+            map.get(agent),
+            BUILTIN_STRING_MEMORY.set.to_property_key(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // 6. If IsCallable(adder) is false, throw a TypeError exception.
+        let Some(adder) = is_callable(adder, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "WeakMap.prototype.set is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        // 7. Return ? AddEntriesFromIterable(map, iterable, adder).
+        add_entries_from_iterable(
+            agent,
+            map.get(agent),
+            iterable.get(agent),
+            adder.unbind(),
+            gc,
+        )
+        .map(|map| map.into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
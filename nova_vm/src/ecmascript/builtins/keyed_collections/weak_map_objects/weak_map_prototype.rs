@@ -2,15 +2,14 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::ecmascript::types::IntoValue;
-use crate::engine::context::GcScope;
 use crate::{
     ecmascript::{
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, String, Value},
+        builtins::{ArgumentsList, Behaviour, Builtin, weak_map::WeakMap},
+        execution::{Agent, JsResult, Realm, agent::ExceptionType, can_be_held_weakly},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
     },
+    engine::context::{Bindable, GcScope, NoGcScope},
     heap::WellKnownSymbolIndexes,
 };
 
@@ -42,40 +41,115 @@ impl Builtin for WeakMapPrototypeSet {
 }
 
 impl WeakMapPrototype {
+    /// ### [24.3.3.2 WeakMap.prototype.delete ( key )](https://tc39.es/ecma262/#sec-weakmap.prototype.delete)
     fn delete<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("WeakMap.prototype.delete", gc.into_nogc()))
+        let gc = gc.into_nogc();
+        let key = arguments.get(0).bind(gc);
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let m = require_weak_map_data_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(key) is false, return false.
+        let Some(key) = can_be_held_weakly(agent, key) else {
+            return Ok(false.into());
+        };
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is
+        //        true, then
+        //         i. Set p.[[Key]] to EMPTY.
+        //         ii. Set p.[[Value]] to EMPTY.
+        //         iii. Return true.
+        // 5. Return false.
+        let removed = agent[m].weak_map_data.remove(&key.unbind()).is_some();
+        Ok(removed.into())
     }
 
+    /// ### [24.3.3.3 WeakMap.prototype.get ( key )](https://tc39.es/ecma262/#sec-weakmap.prototype.get)
     fn get<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("WeakMap.prototype.get", gc.into_nogc()))
+        let gc = gc.into_nogc();
+        let key = arguments.get(0).bind(gc);
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let m = require_weak_map_data_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(key) is false, return undefined.
+        let Some(key) = can_be_held_weakly(agent, key) else {
+            return Ok(Value::Undefined);
+        };
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is
+        //        true, return p.[[Value]].
+        // 5. Return undefined.
+        Ok(agent[m]
+            .weak_map_data
+            .get(&key.unbind())
+            .copied()
+            .unwrap_or(Value::Undefined)
+            .bind(gc))
     }
 
+    /// ### [24.3.3.4 WeakMap.prototype.has ( key )](https://tc39.es/ecma262/#sec-weakmap.prototype.has)
     fn has<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("WeakMap.prototype.has", gc.into_nogc()))
+        let gc = gc.into_nogc();
+        let key = arguments.get(0).bind(gc);
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let m = require_weak_map_data_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(key) is false, return false.
+        let Some(key) = can_be_held_weakly(agent, key) else {
+            return Ok(false.into());
+        };
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is
+        //        true, return true.
+        // 5. Return false.
+        Ok(agent[m].weak_map_data.contains_key(&key.unbind()).into())
     }
 
+    /// ### [24.3.3.5 WeakMap.prototype.set ( key, value )](https://tc39.es/ecma262/#sec-weakmap.prototype.set)
     fn set<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("WeakMap.prototype.set", gc.into_nogc()))
+        let gc = gc.into_nogc();
+        let key = arguments.get(0).bind(gc);
+        let value = arguments.get(1).bind(gc);
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let m = require_weak_map_data_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
+        let Some(key) = can_be_held_weakly(agent, key) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Invalid value used as weak map key",
+                gc,
+            ));
+        };
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is
+        //        true, then
+        //         i. Set p.[[Value]] to value.
+        //         ii. Return M.
+        // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 6. Append p to M.[[WeakMapData]].
+        agent[m].weak_map_data.insert(key.unbind(), value.unbind());
+        // 7. Return M.
+        Ok(m.into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -103,3 +177,19 @@ impl WeakMapPrototype {
             .build();
     }
 }
+
+#[inline(always)]
+fn require_weak_map_data_internal_slot<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, WeakMap<'a>> {
+    match value {
+        Value::WeakMap(map) => Ok(map.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a WeakMap",
+            gc,
+        )),
+    }
+}
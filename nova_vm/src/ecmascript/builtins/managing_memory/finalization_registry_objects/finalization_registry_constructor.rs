@@ -2,13 +2,21 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::engine::context::GcScope;
 use crate::{
     ecmascript::{
+        abstract_operations::testing_and_comparison::is_callable,
         builders::builtin_function_builder::BuiltinFunctionBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, IntoObject, Object, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            finalization_registry::FinalizationRegistry,
+            ordinary::ordinary_create_from_constructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
     },
     heap::IntrinsicConstructorIndexes,
 };
@@ -26,14 +34,62 @@ impl BuiltinIntrinsicConstructor for FinalizationRegistryConstructor {
 }
 
 impl FinalizationRegistryConstructor {
+    /// ### [26.2.1.1 FinalizationRegistry ( cleanupCallback )](https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback)
     fn constructor<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
-        _new_target: Option<Object>,
-        gc: GcScope<'gc, '_>,
+        _: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("FinalizationRegistry", gc.into_nogc()))
+        let nogc = gc.nogc();
+        let cleanup_callback = arguments.get(0).bind(nogc);
+        let new_target = new_target.bind(nogc);
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor FinalizationRegistry requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError
+        //    exception.
+        let Some(cleanup_callback) = is_callable(cleanup_callback, nogc) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "FinalizationRegistry: cleanup must be callable",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        let cleanup_callback = cleanup_callback.scope(agent, nogc);
+        // 3. Let finalizationRegistry be ?
+        //    OrdinaryCreateFromConstructor(NewTarget,
+        //    "%FinalizationRegistry.prototype%", « [[Realm]],
+        //    [[CleanupCallback]], [[Cells]] »).
+        let finalization_registry = ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::FinalizationRegistry,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let finalization_registry =
+            FinalizationRegistry::try_from(finalization_registry.bind(gc)).unwrap();
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        let realm = agent.current_realm(gc);
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to
+        //    HostMakeJobCallback(cleanupCallback).
+        let cleanup_callback = cleanup_callback.get(agent).bind(gc);
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let data = &mut agent[finalization_registry];
+        data.realm = Some(realm.unbind());
+        data.cleanup_callback = Some(cleanup_callback.unbind());
+        // 8. Return finalizationRegistry.
+        Ok(finalization_registry.into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -2,15 +2,18 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::ecmascript::types::IntoValue;
-use crate::engine::context::GcScope;
 use crate::{
     ecmascript::{
+        abstract_operations::testing_and_comparison::same_value,
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin,
+            finalization_registry::{FinalizationRegistry, data::FinalizationRegistryCell},
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType, can_be_held_weakly},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
     },
+    engine::context::{Bindable, GcScope, NoGcScope},
     heap::WellKnownSymbolIndexes,
 };
 
@@ -30,22 +33,101 @@ impl Builtin for FinalizationRegistryPrototypeUnregister {
 }
 
 impl FinalizationRegistryPrototype {
+    /// ### [26.2.3.2 FinalizationRegistry.prototype.register ( target, heldValue \[ , unregisterToken \] )](https://tc39.es/ecma262/#sec-finalization-registry.prototype.register)
     fn register<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("FinalizationRegistry.prototype.register", gc.into_nogc()))
+        let gc = gc.into_nogc();
+        let target = arguments.get(0).bind(gc);
+        let held_value = arguments.get(1).bind(gc);
+        let unregister_token = arguments.get(2).bind(gc);
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let finalization_registry =
+            require_finalization_registry_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let Some(target) = can_be_held_weakly(agent, target) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "FinalizationRegistry.prototype.register: invalid target",
+                gc,
+            ));
+        };
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError
+        //    exception.
+        if same_value(agent, target.into_value(), held_value) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "FinalizationRegistry.prototype.register: target and held value must not be the same",
+                gc,
+            ));
+        }
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        let unregister_token = can_be_held_weakly(agent, unregister_token);
+        if unregister_token.is_none() && !arguments.get(2).is_undefined() {
+            // a. If unregisterToken is not undefined, throw a TypeError
+            //    exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "FinalizationRegistry.prototype.register: invalid unregister token",
+                gc,
+            ));
+        }
+        // b. Set unregisterToken to EMPTY.
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target,
+        //    [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken
+        //    }.
+        let cell = FinalizationRegistryCell {
+            weak_ref_target: Some(target.unbind()),
+            held_value: held_value.unbind(),
+            unregister_token: unregister_token.unbind(),
+        };
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        agent[finalization_registry].cells.push(cell);
+        // 8. Return undefined.
+        Ok(Value::Undefined)
     }
 
+    /// ### [26.2.3.3 FinalizationRegistry.prototype.unregister ( unregisterToken )](https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister)
     fn unregister<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("FinalizationRegistry.prototype.unregister", gc.into_nogc()))
+        let gc = gc.into_nogc();
+        let unregister_token = arguments.get(0).bind(gc);
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let finalization_registry =
+            require_finalization_registry_internal_slot(agent, this_value, gc)?;
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError
+        //    exception.
+        let Some(unregister_token) = can_be_held_weakly(agent, unregister_token) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "FinalizationRegistry.prototype.unregister: invalid unregister token",
+                gc,
+            ));
+        };
+        let unregister_token = unregister_token.unbind();
+        let cells = &mut agent[finalization_registry].cells;
+        // 4. Let removed be false.
+        let cell_count = cells.len();
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]],
+        //    [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not EMPTY and
+        //        SameValue(cell.[[UnregisterToken]], unregisterToken) is true,
+        //        then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        cells.retain(|cell| cell.unregister_token != Some(unregister_token));
+        let removed = cells.len() != cell_count;
+        // 6. Return removed.
+        Ok(removed.into())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -71,3 +153,19 @@ impl FinalizationRegistryPrototype {
             .build();
     }
 }
+
+#[inline(always)]
+fn require_finalization_registry_internal_slot<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, FinalizationRegistry<'a>> {
+    match value {
+        Value::FinalizationRegistry(finalization_registry) => Ok(finalization_registry.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a FinalizationRegistry",
+            gc,
+        )),
+    }
+}
@@ -2,13 +2,22 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::engine::context::GcScope;
 use crate::{
     ecmascript::{
         builders::builtin_function_builder::BuiltinFunctionBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, IntoObject, Object, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsicConstructor,
+            ordinary::ordinary_create_from_constructor, weak_ref::WeakRef,
+        },
+        execution::{
+            Agent, JsResult, ProtoIntrinsics, Realm, WeakKey, agent::ExceptionType,
+            can_be_held_weakly,
+        },
+        types::{BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
     },
     heap::IntrinsicConstructorIndexes,
 };
@@ -26,14 +35,53 @@ impl BuiltinIntrinsicConstructor for WeakRefConstructor {
 }
 
 impl WeakRefConstructor {
+    /// ### [26.1.1.1 WeakRef ( target )](https://tc39.es/ecma262/#sec-weak-ref-target)
     fn constructor<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
-        _new_target: Option<Object>,
-        gc: GcScope<'gc, '_>,
+        _: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("WeakRef", gc.into_nogc()))
+        let nogc = gc.nogc();
+        let target = arguments.get(0).bind(nogc);
+        let new_target = new_target.bind(nogc);
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor WeakRef requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        // 2. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let Some(target) = can_be_held_weakly(agent, target) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "WeakRef: invalid target",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        let target = target.into_value().scope(agent, nogc);
+        // 3. Let weakRef be ? OrdinaryCreateFromConstructor(NewTarget,
+        //    "%WeakRef.prototype%", « [[WeakRefTarget]] »).
+        let weak_ref = ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::WeakRef,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let weak_ref = WeakRef::try_from(weak_ref.bind(gc)).unwrap();
+        let target = WeakKey::try_from(target.get(agent).bind(gc)).unwrap();
+        // 4. Perform AddToKeptObjects(target).
+        agent.add_to_kept_objects(target);
+        // 5. Set weakRef.[[WeakRefTarget]] to target.
+        agent[weak_ref].weak_ref_target = Some(target.unbind());
+        // 6. Return weakRef.
+        Ok(weak_ref.into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -2,15 +2,14 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::ecmascript::types::IntoValue;
-use crate::engine::context::GcScope;
 use crate::{
     ecmascript::{
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, String, Value},
+        builtins::{ArgumentsList, Behaviour, Builtin, weak_ref::WeakRef},
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, String, Value},
     },
+    engine::context::{Bindable, GcScope, NoGcScope},
     heap::WellKnownSymbolIndexes,
 };
 
@@ -24,13 +23,25 @@ impl Builtin for WeakRefPrototypeDeref {
 }
 
 impl WeakRefPrototype {
+    /// ### [26.1.3.2 WeakRef.prototype.deref ( )](https://tc39.es/ecma262/#sec-weak-ref.prototype.deref)
+    ///
+    /// > NOTE: If the WeakRef returns a target value that is not undefined,
+    /// > then this target value should not be garbage collected until the
+    /// > current execution of ECMAScript code has completed. The
+    /// > AddToKeptObjects operation makes sure read consistency is
+    /// > maintained.
     fn deref<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("WeakRef.prototype.deref", gc.into_nogc()))
+        let gc = gc.into_nogc();
+        // 1. Let weakRef be the this value.
+        // 2. Perform ? RequireInternalSlot(weakRef, [[WeakRefTarget]]).
+        let weak_ref = require_weak_ref_target_internal_slot(agent, this_value, gc)?;
+        // 3. Return WeakRefDeref(weakRef).
+        Ok(weak_ref_deref(agent, weak_ref, gc))
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -55,3 +66,38 @@ impl WeakRefPrototype {
             .build();
     }
 }
+
+/// ### [26.1.4.1 WeakRefDeref ( weakRef )](https://tc39.es/ecma262/#sec-weakrefderef)
+///
+/// The abstract operation WeakRefDeref takes argument weakRef (a WeakRef) and
+/// returns an ECMAScript language value.
+fn weak_ref_deref<'a>(agent: &mut Agent, weak_ref: WeakRef, gc: NoGcScope<'a, '_>) -> Value<'a> {
+    // 1. Let target be weakRef.[[WeakRefTarget]].
+    let target = agent[weak_ref].weak_ref_target.bind(gc);
+    // 2. If target is not EMPTY, then
+    if let Some(target) = target {
+        // a. Perform AddToKeptObjects(target).
+        agent.add_to_kept_objects(target);
+        // b. Return target.
+        target.into_value()
+    } else {
+        // 3. Return undefined.
+        Value::Undefined
+    }
+}
+
+#[inline(always)]
+fn require_weak_ref_target_internal_slot<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, WeakRef<'a>> {
+    match value {
+        Value::WeakRef(weak_ref) => Ok(weak_ref.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a WeakRef",
+            gc,
+        )),
+    }
+}
@@ -13,12 +13,19 @@ use crate::{
         abstract_operations::testing_and_comparison::same_value,
         builtins::ordinary::ordinary_get_own_property,
         execution::{Agent, JsResult, agent::ExceptionType},
+        scripts_and_modules::module::module_semantics::{
+            abstract_module_records::{AbstractModule, ResolveExportResult, ResolvedBindingName},
+            get_module_namespace,
+        },
         types::{
-            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject,
-            PropertyDescriptor, PropertyKey, String, Value,
+            BUILTIN_STRING_MEMORY, InternalMethods, InternalSlots, IntoObject, IntoValue, Object,
+            OrdinaryObject, PropertyDescriptor, PropertyKey, String, Value,
         },
     },
-    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+    heap::{
+        CompactionLists, HeapMarkAndSweep, ObjectEntry, ObjectEntryPropertyDescriptor,
+        WellKnownSymbolIndexes, WorkQueues,
+    },
 };
 
 use self::data::ModuleHeapData;
@@ -120,6 +127,120 @@ impl Module<'_> {
     }
 }
 
+impl Module<'_> {
+    /// Performs steps 4 through 12 of the \[\[Get]] internal method for an
+    /// export name known to be contained in \[\[Exports]].
+    fn get_export_value<'gc>(
+        self,
+        agent: &mut Agent,
+        export_name: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let export_name = export_name.bind(gc);
+        // 4. Let m be O.[[Module]].
+        let m = agent[self].module.bind(gc);
+        // 5. Let binding be m.ResolveExport(P).
+        let binding = m.resolve_export(agent, export_name, &mut vec![], gc);
+        // 6. Assert: binding is a ResolvedBinding Record.
+        let Some(ResolveExportResult::Resolved(binding)) = binding else {
+            unreachable!();
+        };
+        // 7. Let targetModule be binding.[[Module]].
+        // 8. Assert: targetModule is not undefined.
+        let target_module = binding.module;
+        let binding_name = match binding.binding_name {
+            // 9. If binding.[[BindingName]] is NAMESPACE, then
+            ResolvedBindingName::Namespace => {
+                // a. Return GetModuleNamespace(targetModule).
+                return Ok(get_module_namespace(agent, target_module, gc).into_value());
+            }
+            ResolvedBindingName::String(binding_name) => binding_name,
+        };
+        // 10. Let targetEnv be targetModule.[[Environment]].
+        // 11. If targetEnv is EMPTY, throw a ReferenceError exception.
+        let Some(target_env) = target_module.environment(agent, gc) else {
+            return Err(agent.throw_exception(
+                ExceptionType::ReferenceError,
+                format!(
+                    "Cannot access '{}' before module is linked.",
+                    export_name.as_str(agent)
+                ),
+                gc,
+            ));
+        };
+        // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
+        target_env.get_binding_value(agent, binding_name, true, gc)
+    }
+}
+
+/// Converts a non-Symbol property key into the export name String it
+/// represents.
+fn export_name_from_property_key<'a>(
+    agent: &mut Agent,
+    property_key: PropertyKey,
+    gc: NoGcScope<'a, '_>,
+) -> String<'a> {
+    match property_key {
+        PropertyKey::Integer(data) => String::from_string(agent, data.into_i64().to_string(), gc),
+        PropertyKey::SmallString(data) => String::SmallString(data),
+        PropertyKey::String(data) => String::String(data.bind(gc)),
+        PropertyKey::Symbol(_) => unreachable!(),
+    }
+}
+
+/// ### [10.4.6.12 ModuleNamespaceCreate ( module, exports )](https://tc39.es/ecma262/#sec-modulenamespacecreate)
+///
+/// The abstract operation ModuleNamespaceCreate takes arguments module (a
+/// Module Record) and exports (a List of Strings) and returns a module
+/// namespace exotic object. It is used to specify the creation of new module
+/// namespace exotic objects.
+pub(crate) fn module_namespace_create<'a>(
+    agent: &mut Agent,
+    module: AbstractModule,
+    mut exports: Vec<String>,
+    gc: NoGcScope<'a, '_>,
+) -> Module<'a> {
+    // 1. Assert: module.[[Namespace]] is EMPTY.
+    debug_assert!(module.namespace(agent, gc).is_none());
+    // 2. Let internalSlotsList be the internal slots listed in Table 33.
+    // 3. Let M be MakeBasicObject(internalSlotsList).
+    // 4. Set M's essential internal methods to the definitions specified in
+    //    10.4.6.
+    // 5. Set M.[[Module]] to module.
+    // 6. Let sortedExports be a List whose elements are the elements of
+    //    exports, sorted according to lexicographic code unit order.
+    exports.sort_by(|a, b| a.as_str(agent).cmp(b.as_str(agent)));
+    // 7. Set M.[[Exports]] to sortedExports.
+    let namespace = agent.heap.add_module(
+        ModuleHeapData {
+            object_index: None,
+            module: module.unbind(),
+            exports: exports.into_iter().map(|e| e.unbind()).collect(),
+        },
+        gc,
+    );
+    // 8. Create own properties of M corresponding to the definitions in
+    //    28.3.
+    // 28.3.1 %Symbol.toStringTag%
+    // This property has the attributes { [[Writable]]: false,
+    // [[Enumerable]]: false, [[Configurable]]: false }.
+    let backing_object = agent.heap.create_null_object(&[ObjectEntry {
+        key: WellKnownSymbolIndexes::ToStringTag.into(),
+        value: ObjectEntryPropertyDescriptor::Data {
+            value: BUILTIN_STRING_MEMORY.Module.into_value(),
+            writable: false,
+            enumerable: false,
+            configurable: false,
+        },
+    }]);
+    backing_object.internal_set_extensible(agent, false);
+    namespace.set_backing_object(agent, backing_object);
+    // 9. Set module.[[Namespace]] to M.
+    module.set_namespace(agent, namespace);
+    // 10. Return M.
+    namespace
+}
+
 // SAFETY: Property implemented as a lifetime transmute.
 unsafe impl Bindable for Module<'_> {
     type Of<'a> = Module<'a>;
@@ -213,13 +334,9 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                 )
             }
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
+                let key = export_name_from_property_key(agent, property_key, gc);
                 // 2. Let exports be O.[[Exports]].
                 let exports: &[String] = &agent[self].exports;
-                let key = match property_key {
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::Integer(_) | PropertyKey::Symbol(_) => unreachable!(),
-                };
                 let exports_contains_p = exports.contains(&key);
                 // 3. If exports does not contain P, return undefined.
                 if !exports_contains_p {
@@ -257,14 +374,9 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                 unreachable!();
             }
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
+                let key = export_name_from_property_key(agent, property_key, gc.nogc());
                 // 2. Let exports be O.[[Exports]].
                 let exports: &[String] = &agent[self].exports;
-                let key = match property_key {
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::Integer(_) => todo!(),
-                    PropertyKey::Symbol(_) => unreachable!(),
-                };
                 let exports_contains_p = exports.contains(&key);
                 // 3. If exports does not contain P, return undefined.
                 if !exports_contains_p {
@@ -425,12 +537,7 @@ impl<'a> InternalMethods<'a> for Module<'a> {
     ) -> TryResult<bool> {
         match property_key {
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
-                let p = match property_key {
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::Integer(_data) => todo!(),
-                    _ => unreachable!(),
-                };
+                let p = export_name_from_property_key(agent, property_key, gc);
                 // 2. Let exports be O.[[Exports]].
                 let exports: &[String] = &agent[self].exports;
                 // 3. If exports contains P, return true.
@@ -476,48 +583,21 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                 ))
             }
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
+                let key = export_name_from_property_key(agent, property_key, gc);
                 // 2. Let exports be O.[[Exports]].
                 let exports: &[String] = &agent[self].exports;
-                let key = match property_key {
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::Integer(_) => todo!(),
-                    PropertyKey::Symbol(_) => unreachable!(),
-                };
                 let exports_contains_p = exports.contains(&key);
                 // 3. If exports does not contain P, return undefined.
                 if !exports_contains_p {
                     TryResult::Continue(Value::Undefined)
                 } else {
                     // 4. Let m be O.[[Module]].
-                    let m = &agent[self].module;
-                    // 5. Let binding be m.ResolveExport(P).
-                    let binding = m.resolve_export(property_key);
-                    // 6. Assert: binding is a ResolvedBinding Record.
-                    let Some(data::ResolveExportResult::Resolved(binding)) = binding else {
-                        unreachable!();
-                    };
-                    // 7. Let targetModule be binding.[[Module]].
-                    // 8. Assert: targetModule is not undefined.
-                    let target_module = binding.module.unwrap();
-                    // 9. If binding.[[BindingName]] is NAMESPACE, then
-                    let _binding_name = match binding.binding_name {
-                        data::ResolvedBindingName::Namespace => {
-                            // a. Return GetModuleNamespace(targetModule).
-                            todo!();
-                        }
-                        data::ResolvedBindingName::String(data) => String::String(data),
-                        data::ResolvedBindingName::SmallString(data) => String::SmallString(data),
-                    };
-                    // 10. Let targetEnv be targetModule.[[Environment]].
-                    let target_env = agent[target_module].module.environment;
-                    // 11. If targetEnv is EMPTY, throw a ReferenceError exception.
-                    match target_env {
-                        None => TryResult::Break(()),
-                        Some(_target_env) => {
-                            // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
-                            todo!()
-                        }
+                    // ...
+                    // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
+                    // NOTE: A thrown error is thrown again in the GC path.
+                    match self.get_export_value(agent, key, gc) {
+                        Ok(value) => TryResult::Continue(value),
+                        Err(_) => TryResult::Break(()),
                     }
                 }
             }
@@ -558,53 +638,18 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                 })
             }
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
+                let key = export_name_from_property_key(agent, property_key, gc.nogc());
                 // 2. Let exports be O.[[Exports]].
                 let exports: &[String] = &agent[self].exports;
-                let key = match property_key {
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::Integer(_) => todo!(),
-                    _ => unreachable!(),
-                };
                 let exports_contains_p = exports.contains(&key);
                 // 3. If exports does not contain P, return undefined.
                 if !exports_contains_p {
                     Ok(Value::Undefined)
                 } else {
                     // 4. Let m be O.[[Module]].
-                    let m = &agent[self].module;
-                    // 5. Let binding be m.ResolveExport(P).
-                    let binding = m.resolve_export(property_key);
-                    // 6. Assert: binding is a ResolvedBinding Record.
-                    let Some(data::ResolveExportResult::Resolved(binding)) = binding else {
-                        unreachable!();
-                    };
-                    // 7. Let targetModule be binding.[[Module]].
-                    // 8. Assert: targetModule is not undefined.
-                    let target_module = binding.module.unwrap();
-                    // 9. If binding.[[BindingName]] is NAMESPACE, then
-                    let _binding_name = match binding.binding_name {
-                        data::ResolvedBindingName::Namespace => {
-                            // a. Return GetModuleNamespace(targetModule).
-                            todo!();
-                        }
-                        data::ResolvedBindingName::String(data) => String::String(data),
-                        data::ResolvedBindingName::SmallString(data) => String::SmallString(data),
-                    };
-                    // 10. Let targetEnv be targetModule.[[Environment]].
-                    let target_env = agent[target_module].module.environment;
-                    // 11. If targetEnv is EMPTY, throw a ReferenceError exception.
-                    match target_env {
-                        None => Err(agent.throw_exception(
-                            ExceptionType::ReferenceError,
-                            format!("Could not resolve module '{}'.", key.as_str(agent)),
-                            gc.into_nogc(),
-                        )),
-                        Some(_target_env) => {
-                            // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
-                            todo!()
-                        }
-                    }
+                    // ...
+                    // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
+                    self.get_export_value(agent, key.unbind(), gc.into_nogc())
                 }
             }
         }
@@ -639,12 +684,7 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                 )
             }
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
-                let p = match property_key {
-                    PropertyKey::String(data) => String::String(data),
-                    PropertyKey::SmallString(data) => String::SmallString(data),
-                    PropertyKey::Integer(_) => todo!(),
-                    _ => unreachable!(),
-                };
+                let p = export_name_from_property_key(agent, property_key, gc);
                 // 2. Let exports be O.[[Exports]].
                 let exports = &agent[self].exports;
                 // 3. If exports contains P, return false.
@@ -35,21 +35,32 @@ use crate::{
 
 #[cfg(feature = "date")]
 use super::date::data::DateHeapData;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use super::disposable_stack::data::DisposableStackHeapData;
 #[cfg(feature = "regexp")]
 use super::regexp::RegExpHeapData;
 #[cfg(feature = "shared-array-buffer")]
 use super::shared_array_buffer::data::SharedArrayBufferHeapData;
+#[cfg(feature = "regexp")]
+use super::text_processing::regexp_objects::regexp_string_iterator_objects::RegExpStringIteratorHeapData;
 #[cfg(feature = "array-buffer")]
 use super::{
     ArrayBufferHeapData, data_view::data::DataViewHeapData, typed_array::data::TypedArrayHeapData,
 };
 use super::{
-    ArrayHeapData, async_generator_objects::AsyncGeneratorHeapData,
-    control_abstraction_objects::generator_objects::GeneratorHeapData, error::ErrorHeapData,
+    ArrayHeapData,
+    async_generator_objects::AsyncGeneratorHeapData,
+    control_abstraction_objects::{
+        generator_objects::GeneratorHeapData,
+        iteration::async_from_sync_iterator_objects::AsyncFromSyncIteratorHeapData,
+    },
+    error::ErrorHeapData,
     finalization_registry::data::FinalizationRegistryHeapData,
     indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIteratorHeapData,
     keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIteratorHeapData,
-    map::data::MapHeapData, module::Module, primitive_objects::PrimitiveObjectHeapData,
+    map::data::MapHeapData,
+    module::Module,
+    primitive_objects::PrimitiveObjectHeapData,
     promise::data::PromiseHeapData,
     text_processing::string_objects::string_iterator_objects::StringIteratorHeapData,
 };
@@ -1227,6 +1238,10 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
             .heap
             .create(ArrayIteratorHeapData::default())
             .into_object(),
+        ProtoIntrinsics::AsyncFromSyncIterator => agent
+            .heap
+            .create(AsyncFromSyncIteratorHeapData::default())
+            .into_object(),
         ProtoIntrinsics::BigInt => agent
             .heap
             .create(PrimitiveObjectHeapData::new_big_int_object(0.into()))
@@ -1245,6 +1260,16 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
             .into_object(),
         #[cfg(feature = "date")]
         ProtoIntrinsics::Date => agent.heap.create(DateHeapData::new_invalid()).into_object(),
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ProtoIntrinsics::DisposableStack => agent
+            .heap
+            .create(DisposableStackHeapData::new(false))
+            .into_object(),
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ProtoIntrinsics::AsyncDisposableStack => agent
+            .heap
+            .create(DisposableStackHeapData::new(true))
+            .into_object(),
         ProtoIntrinsics::Function => todo!(),
         ProtoIntrinsics::Number => agent
             .heap
@@ -1309,6 +1334,15 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
                 None,
             ))
             .into_object(),
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ProtoIntrinsics::SuppressedError => agent
+            .heap
+            .create(ErrorHeapData::new(
+                ExceptionType::SuppressedError,
+                None,
+                None,
+            ))
+            .into_object(),
         ProtoIntrinsics::AsyncFunction => todo!(),
         ProtoIntrinsics::AsyncGenerator => agent
             .heap
@@ -1377,6 +1411,11 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
         ProtoIntrinsics::Promise => agent.heap.create(PromiseHeapData::default()).into_object(),
         #[cfg(feature = "regexp")]
         ProtoIntrinsics::RegExp => agent.heap.create(RegExpHeapData::default()).into_object(),
+        #[cfg(feature = "regexp")]
+        ProtoIntrinsics::RegExpStringIterator => agent
+            .heap
+            .create(RegExpStringIteratorHeapData::default())
+            .into_object(),
         #[cfg(feature = "set")]
         ProtoIntrinsics::Set => agent.heap.create(SetHeapData::default()).into_object(),
         #[cfg(feature = "set")]
@@ -1500,6 +1539,11 @@ pub(crate) fn get_prototype_from_constructor<'a>(
             ProtoIntrinsics::AggregateError => Some(intrinsics.aggregate_error().into_function()),
             ProtoIntrinsics::Array => Some(intrinsics.array().into_function()),
             ProtoIntrinsics::ArrayIterator => None,
+            ProtoIntrinsics::AsyncFromSyncIterator => None,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::AsyncDisposableStack => {
+                Some(intrinsics.async_disposable_stack().into_function())
+            }
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::ArrayBuffer => Some(intrinsics.array_buffer().into_function()),
             ProtoIntrinsics::AsyncFunction => Some(intrinsics.async_function().into_function()),
@@ -1517,6 +1561,8 @@ pub(crate) fn get_prototype_from_constructor<'a>(
             ProtoIntrinsics::DataView => Some(intrinsics.data_view().into_function()),
             #[cfg(feature = "date")]
             ProtoIntrinsics::Date => Some(intrinsics.date().into_function()),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::DisposableStack => Some(intrinsics.disposable_stack().into_function()),
             ProtoIntrinsics::Error => Some(intrinsics.error().into_function()),
             ProtoIntrinsics::EvalError => Some(intrinsics.eval_error().into_function()),
             ProtoIntrinsics::FinalizationRegistry => {
@@ -1549,6 +1595,8 @@ pub(crate) fn get_prototype_from_constructor<'a>(
             ProtoIntrinsics::ReferenceError => Some(intrinsics.reference_error().into_function()),
             #[cfg(feature = "regexp")]
             ProtoIntrinsics::RegExp => Some(intrinsics.reg_exp().into_function()),
+            #[cfg(feature = "regexp")]
+            ProtoIntrinsics::RegExpStringIterator => None,
             #[cfg(feature = "set")]
             ProtoIntrinsics::Set => Some(intrinsics.set().into_function()),
             #[cfg(feature = "set")]
@@ -1559,6 +1607,8 @@ pub(crate) fn get_prototype_from_constructor<'a>(
             }
             ProtoIntrinsics::String => Some(intrinsics.string().into_function()),
             ProtoIntrinsics::StringIterator => None,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::SuppressedError => Some(intrinsics.suppressed_error().into_function()),
             ProtoIntrinsics::Symbol => Some(intrinsics.symbol().into_function()),
             ProtoIntrinsics::SyntaxError => Some(intrinsics.syntax_error().into_function()),
             ProtoIntrinsics::TypeError => Some(intrinsics.type_error().into_function()),
@@ -3,7 +3,12 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 pub(crate) mod abstract_operations;
+mod compiler;
 pub(crate) mod data;
+#[cfg(feature = "annex-b-regexp")]
+pub(crate) mod legacy_features;
+mod matcher;
+mod unicode_tables;
 
 use core::ops::{Index, IndexMut};
 
@@ -311,7 +316,7 @@ impl<'a> InternalMethods<'a> for RegExp<'a> {
                 // If we we set a value that is not a valid index or undefined,
                 // we need to create the backing object and set the actual
                 // value there.
-                if !new_last_index.is_valid() && value.is_undefined() {
+                if !new_last_index.is_valid() && !value.is_undefined() {
                     unwrap_try(self.create_backing_object(agent).try_set(
                         agent,
                         property_key,
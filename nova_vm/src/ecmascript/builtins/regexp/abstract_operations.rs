@@ -2,12 +2,22 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::sync::Arc;
+
 use oxc_ast::ast::RegExpFlags;
 
-use crate::ecmascript::abstract_operations::operations_on_objects::{set, try_set};
-use crate::ecmascript::types::IntoObject;
+use crate::ecmascript::abstract_operations::operations_on_objects::{
+    call_function, create_array_from_list, get, set, try_create_data_property_or_throw, try_set,
+};
+use crate::ecmascript::abstract_operations::testing_and_comparison::is_callable;
+use crate::ecmascript::abstract_operations::type_conversion::{to_length, to_string};
+use crate::ecmascript::builtins::ordinary::ordinary_object_create_with_intrinsics;
+use crate::ecmascript::builtins::{ArgumentsList, Array};
+use crate::ecmascript::execution::agent::ExceptionType;
+use crate::ecmascript::types::{IntoObject, IntoValue, Object, PropertyKey, Value};
 use crate::engine::context::{Bindable, GcScope, NoGcScope};
 use crate::engine::rootable::Scopable;
+use crate::engine::unwrap_try;
 use crate::{
     ecmascript::{
         builtins::ordinary::ordinary_create_from_constructor,
@@ -17,37 +27,114 @@ use crate::{
     heap::CreateHeapData,
 };
 
+use super::compiler::compile_pattern;
+#[cfg(feature = "annex-b-regexp")]
+use super::legacy_features::{
+    invalidate_legacy_reg_exp_static_properties, update_legacy_reg_exp_static_properties,
+};
+use super::matcher::{RegExpMatch, RegExpMatcher};
 use super::{RegExp, RegExpHeapData, RegExpLastIndex};
+#[cfg(feature = "annex-b-regexp")]
+use crate::ecmascript::types::IntoFunction;
 
-/// ### [22.2.3.1 RegExpCreate ( P, F )]()
+/// ### [22.2.3.1 RegExpCreate ( P, F )](https://tc39.es/ecma262/#sec-regexpcreate)
 ///
 /// The abstract operation RegExpCreate takes arguments P (an ECMAScript
 /// language value) and F (a String or undefined) and returns either a normal
 /// completion containing an Object or a throw completion.
+pub(crate) fn reg_exp_create<'a>(
+    agent: &mut Agent,
+    p: Value,
+    f: Option<RegExpFlags>,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, RegExp<'a>> {
+    let p = p.bind(gc.nogc());
+    // Note: ToString(P) is performed before allocating the object; the
+    // allocation is not observable.
+    let p = if p.is_undefined() {
+        String::EMPTY_STRING
+    } else if let Ok(p) = String::try_from(p) {
+        p
+    } else {
+        to_string(agent, p.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
+    };
+    // 1. Let obj be ! RegExpAlloc(%RegExp%).
+    let obj = agent
+        .heap
+        .create(RegExpHeapData {
+            #[cfg(feature = "annex-b-regexp")]
+            realm: Some(agent.current_realm_id_internal()),
+            #[cfg(feature = "annex-b-regexp")]
+            legacy_features_enabled: true,
+            ..Default::default()
+        })
+        .bind(gc.nogc());
+    // 2. Return ? RegExpInitialize(obj, P, F).
+    reg_exp_initialize_from_string(agent, obj.unbind(), p.unbind(), f, gc)
+}
+
+/// ### [22.2.3.1 RegExpCreate ( P, F )](https://tc39.es/ecma262/#sec-regexpcreate)
 ///
-/// This is a variant for RegExp literal creation that cannot fail and skips
-/// all of the abstract operation busy-work.
+/// The abstract operation RegExpCreate takes arguments P (an ECMAScript
+/// language value) and F (a String or undefined) and returns either a normal
+/// completion containing an Object or a throw completion.
+///
+/// This is a variant for RegExp literal creation that skips all of the
+/// abstract operation busy-work. The only possible error is an invalid
+/// pattern, in which case the SyntaxError message is returned.
 pub(crate) fn reg_exp_create_literal<'a>(
     agent: &mut Agent,
     p: String,
     f: Option<RegExpFlags>,
     gc: NoGcScope<'a, '_>,
-) -> RegExp<'a> {
+) -> Result<RegExp<'a>, std::string::String> {
     //     1. Let obj be ! RegExpAlloc(%RegExp%).
     //     2. Return ? RegExpInitialize(obj, P, F).
     let f = f.unwrap_or(RegExpFlags::empty());
-    agent
+    let matcher = compile_pattern(p.as_str(agent), f)?;
+    Ok(agent
         .heap
         .create(RegExpHeapData {
             object_index: None,
+            reg_exp_matcher: Some(Arc::new(matcher)),
             original_source: p.unbind(),
             original_flags: f,
             last_index: RegExpLastIndex::ZERO,
+            #[cfg(feature = "annex-b-regexp")]
+            realm: None,
+            #[cfg(feature = "annex-b-regexp")]
+            legacy_features_enabled: true,
         })
-        .bind(gc)
+        .bind(gc))
+}
+
+/// Create a new RegExp object from a RegExp literal template.
+///
+/// The new RegExp shares the source, flags, and compiled matcher of the
+/// template but has its own identity and lastIndex.
+pub(crate) fn reg_exp_instantiate_literal<'a>(
+    agent: &mut Agent,
+    template: RegExp,
+    gc: NoGcScope<'a, '_>,
+) -> RegExp<'a> {
+    let data = &agent[template];
+    let data = RegExpHeapData {
+        object_index: None,
+        reg_exp_matcher: data.reg_exp_matcher.clone(),
+        original_source: data.original_source,
+        original_flags: data.original_flags,
+        last_index: RegExpLastIndex::ZERO,
+        #[cfg(feature = "annex-b-regexp")]
+        realm: Some(agent.current_realm_id_internal()),
+        #[cfg(feature = "annex-b-regexp")]
+        legacy_features_enabled: true,
+    };
+    agent.heap.create(data).bind(gc)
 }
 
-/// ### [22.2.3.2 RegExpAlloc ( newTarget )]()
+/// ### [22.2.3.2 RegExpAlloc ( newTarget )](https://tc39.es/ecma262/#sec-regexpalloc)
 ///
 /// The abstract operation RegExpAlloc takes argument newTarget (a constructor)
 /// and returns either a normal completion containing an Object or a throw
@@ -57,7 +144,16 @@ pub(crate) fn reg_exp_alloc<'a>(
     new_target: Function,
     gc: GcScope<'a, '_>,
 ) -> JsResult<'a, RegExp<'a>> {
-    // 1. Let obj be ? OrdinaryCreateFromConstructor(newTarget, "%RegExp.prototype%", « [[OriginalSource]], [[OriginalFlags]], [[RegExpRecord]], [[RegExpMatcher]] »).
+    #[cfg(feature = "annex-b-regexp")]
+    let this_realm = agent.current_realm_id_internal();
+    #[cfg(feature = "annex-b-regexp")]
+    let legacy_features_enabled = new_target
+        == agent
+            .current_realm_record()
+            .intrinsics()
+            .reg_exp()
+            .into_function();
+    // 1. Let obj be ? OrdinaryCreateFromConstructor(newTarget, "%RegExp.prototype%", « [[OriginalSource]], [[OriginalFlags]], [[RegExpRecord]], [[RegExpMatcher]], [[Realm]], [[LegacyFeaturesEnabled]] »).
     let obj = RegExp::try_from(ordinary_create_from_constructor(
         agent,
         new_target,
@@ -65,18 +161,101 @@ pub(crate) fn reg_exp_alloc<'a>(
         gc,
     )?)
     .unwrap();
+    #[cfg(feature = "annex-b-regexp")]
+    {
+        // 2. Let thisRealm be the current Realm Record.
+        // 3. Set the value of obj’s [[Realm]] internal slot to thisRealm.
+        agent[obj].realm = Some(this_realm);
+        // 4. If SameValue(newTarget, thisRealm.[[Intrinsics]].[[%RegExp%]])
+        //    is true, then
+        //     a. Set the value of obj’s [[LegacyFeaturesEnabled]] internal
+        //        slot to true.
+        // 5. Else,
+        //     a. Set the value of obj’s [[LegacyFeaturesEnabled]] internal
+        //        slot to false.
+        agent[obj].legacy_features_enabled = legacy_features_enabled;
+    }
     // 2. Perform ! DefinePropertyOrThrow(obj, "lastIndex", PropertyDescriptor { [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: false }).
     // TODO: lastIndex should be in RegExpHeapData itself, one way or another.
     // 3. Return obj.
     Ok(obj)
 }
 
-/// ### [22.2.3.3 RegExpInitialize ( obj, pattern, flags )]()
+/// ### [22.2.3.3 RegExpInitialize ( obj, pattern, flags )](https://tc39.es/ecma262/#sec-regexpinitialize)
 ///
 /// The abstract operation RegExpInitialize takes arguments obj (an Object),
 /// pattern (an ECMAScript language value), and flags (an ECMAScript language
 /// value) and returns either a normal completion containing an Object or a
 /// throw completion.
+pub(crate) fn reg_exp_initialize<'a>(
+    agent: &mut Agent,
+    obj: RegExp,
+    pattern: Value,
+    flags: Value,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, RegExp<'a>> {
+    let obj = obj.bind(gc.nogc()).scope(agent, gc.nogc());
+    let pattern = pattern.bind(gc.nogc());
+    let flags = flags.bind(gc.nogc()).scope(agent, gc.nogc());
+    // 1. If pattern is undefined, let P be the empty String.
+    let p = if pattern.is_undefined() {
+        String::EMPTY_STRING
+    } else {
+        // 2. Else, let P be ? ToString(pattern).
+        to_string(agent, pattern.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
+    };
+    let flags = flags.get(agent).bind(gc.nogc());
+    let (p, f) = if flags.is_undefined() {
+        // 3. If flags is undefined, let F be the empty String.
+        (p.unbind(), RegExpFlags::empty())
+    } else {
+        // 4. Else, let F be ? ToString(flags).
+        let p = p.scope(agent, gc.nogc());
+        let f = to_string(agent, flags.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 5. If F contains any code unit other than "d", "g", "i", "m", "s",
+        //    "u", "v", or "y", or if F contains any code unit more than once,
+        //    throw a SyntaxError exception.
+        let Some(f) = parse_flags(f.as_str(agent)) else {
+            let message = format!("Invalid regular expression flags '{}'", f.as_str(agent));
+            return Err(agent.throw_exception(ExceptionType::SyntaxError, message, gc.into_nogc()));
+        };
+        (p.get(agent), f)
+    };
+    reg_exp_initialize_from_string(agent, obj.get(agent), p, Some(f), gc)
+}
+
+/// Parse a RegExp flags string, returning None if it contains unknown or
+/// duplicate flags.
+pub(crate) fn parse_flags(flags: &str) -> Option<RegExpFlags> {
+    let mut result = RegExpFlags::empty();
+    for flag in flags.chars() {
+        let flag = match flag {
+            'd' => RegExpFlags::D,
+            'g' => RegExpFlags::G,
+            'i' => RegExpFlags::I,
+            'm' => RegExpFlags::M,
+            's' => RegExpFlags::S,
+            'u' => RegExpFlags::U,
+            'v' => RegExpFlags::V,
+            'y' => RegExpFlags::Y,
+            _ => return None,
+        };
+        if result.contains(flag) {
+            return None;
+        }
+        result |= flag;
+    }
+    Some(result)
+}
+
+/// ### [22.2.3.3 RegExpInitialize ( obj, pattern, flags )](https://tc39.es/ecma262/#sec-regexpinitialize)
+///
+/// Variant of RegExpInitialize for an already converted pattern String and
+/// parsed flags.
 pub(crate) fn reg_exp_initialize_from_string<'a>(
     agent: &mut Agent,
     obj: RegExp,
@@ -88,8 +267,6 @@ pub(crate) fn reg_exp_initialize_from_string<'a>(
     let p = p.bind(gc.nogc());
     //     3. If flags is undefined, let F be the empty String.
     let f = flags.unwrap_or(RegExpFlags::empty());
-    //     4. Else, let F be ? ToString(flags).
-    //     5. If F contains any code unit other than "d", "g", "i", "m", "s", "u", "v", or "y", or if F contains any code unit more than once, throw a SyntaxError exception.
     //     6. If F contains "i", let i be true; else let i be false.
     //     7. If F contains "m", let m be true; else let m be false.
     //     8. If F contains "s", let s be true; else let s be false.
@@ -100,7 +277,13 @@ pub(crate) fn reg_exp_initialize_from_string<'a>(
     //     12. Else,
     //         a. Let patternText be the result of interpreting each of P's 16-bit elements as a Unicode BMP code point. UTF-16 decoding is not applied to the elements.
     //     13. Let parseResult be ParsePattern(patternText, u, v).
-    //     14. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
+    let matcher = match compile_pattern(p.as_str(agent), f) {
+        Ok(matcher) => matcher,
+        //     14. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
+        Err(message) => {
+            return Err(agent.throw_exception(ExceptionType::SyntaxError, message, gc.into_nogc()));
+        }
+    };
     //     15. Assert: parseResult is a Pattern Parse Node.
     //     16. Set obj.[[OriginalSource]] to P.
     agent[obj].original_source = p.unbind();
@@ -110,6 +293,7 @@ pub(crate) fn reg_exp_initialize_from_string<'a>(
     //     19. Let rer be the RegExp Record { [[IgnoreCase]]: i, [[Multiline]]: m, [[DotAll]]: s, [[Unicode]]: u, [[UnicodeSets]]: v, [[CapturingGroupsCount]]: capturingGroupsCount }.
     //     20. Set obj.[[RegExpRecord]] to rer.
     //     21. Set obj.[[RegExpMatcher]] to CompilePattern of parseResult with argument rer.
+    agent[obj].reg_exp_matcher = Some(Arc::new(matcher));
     //     22. Perform ? Set(obj, "lastIndex", +0𝔽, true).
     //     23. Return obj.
     if try_set(
@@ -138,20 +322,483 @@ pub(crate) fn reg_exp_initialize_from_string<'a>(
     }
 }
 
-/// ### [22.2.3.4 Static Semantics: ParsePattern ( patternText, u, v )]()
+/// ### [22.2.7.1 RegExpExec ( R, S )](https://tc39.es/ecma262/#sec-regexpexec)
 ///
-/// The abstract operation ParsePattern takes arguments patternText (a sequence of Unicode code points), u (a Boolean), and v (a Boolean) and returns a Parse Node or a non-empty List of SyntaxError objects.
+/// The abstract operation RegExpExec takes arguments R (an Object) and S (a
+/// String) and returns either a normal completion containing either an
+/// Object or null, or a throw completion.
 ///
 /// > #### Note
-/// > This section is amended in B.1.2.9.
-pub(crate) fn parse_pattern() {
-    //     1. If v is true and u is true, then
-    //         a. Let parseResult be a List containing one or more SyntaxError objects.
-    //     2. Else if v is true, then
-    //         a. Let parseResult be ParseText(patternText, Pattern[+UnicodeMode, +UnicodeSetsMode, +NamedCaptureGroups]).
-    //     3. Else if u is true, then
-    //         a. Let parseResult be ParseText(patternText, Pattern[+UnicodeMode, ~UnicodeSetsMode, +NamedCaptureGroups]).
-    //     4. Else,
-    //         a. Let parseResult be ParseText(patternText, Pattern[~UnicodeMode, ~UnicodeSetsMode, +NamedCaptureGroups]).
-    //     5. Return parseResult.
+/// > If a callable "exec" property is not found this algorithm falls back to
+/// > attempting to use the built-in RegExp matching algorithm. This provides
+/// > compatible behaviour for code written for prior editions where most
+/// > built-in algorithms that use regular expressions did not perform a
+/// > dynamic property lookup of "exec".
+pub(crate) fn reg_exp_exec<'a>(
+    agent: &mut Agent,
+    r: Object,
+    s: String,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Object<'a>>> {
+    let r = r.bind(gc.nogc());
+    let s = s.bind(gc.nogc());
+    let scoped_r = r.scope(agent, gc.nogc());
+    let scoped_s = s.scope(agent, gc.nogc());
+    // 1. Let exec be ? Get(R, "exec").
+    let exec = get(
+        agent,
+        r.unbind(),
+        BUILTIN_STRING_MEMORY.exec.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 2. If IsCallable(exec) is true, then
+    if let Some(exec) = is_callable(exec, gc.nogc()) {
+        // a. Let result be ? Call(exec, R, « S »).
+        let result = call_function(
+            agent,
+            exec.unbind(),
+            scoped_r.get(agent).into_value(),
+            Some(ArgumentsList::from_mut_slice(&mut [scoped_s
+                .get(agent)
+                .into_value()])),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let result = result.bind(gc);
+        // b. If result is not an Object and result is not null, throw a
+        //    TypeError exception.
+        // c. Return result.
+        return if result.is_null() {
+            Ok(None)
+        } else if let Ok(result) = Object::try_from(result) {
+            Ok(Some(result))
+        } else {
+            Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp exec method returned something other than an Object or null",
+                gc,
+            ))
+        };
+    }
+    // 3. Perform ? RequireInternalSlot(R, [[RegExpMatcher]]).
+    let Ok(r) = RegExp::try_from(scoped_r.get(agent)) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Expected RegExp object",
+            gc.into_nogc(),
+        ));
+    };
+    // 4. Return ? RegExpBuiltinExec(R, S).
+    Ok(reg_exp_builtin_exec(agent, r, scoped_s.get(agent), gc)?.map(|a| a.into_object()))
+}
+
+/// ### [22.2.7.2 RegExpBuiltinExec ( R, S )](https://tc39.es/ecma262/#sec-regexpbuiltinexec)
+///
+/// The abstract operation RegExpBuiltinExec takes arguments R (an
+/// initialized RegExp instance) and S (a String) and returns either a normal
+/// completion containing either an Array exotic object or null, or a throw
+/// completion.
+pub(crate) fn reg_exp_builtin_exec<'a>(
+    agent: &mut Agent,
+    r: RegExp,
+    s: String,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Array<'a>>> {
+    let mut r = r.bind(gc.nogc());
+    let mut s = s.bind(gc.nogc());
+    // 1. Let length be the length of S.
+    // 2. Let lastIndex be ℝ(? ToLength(! Get(R, "lastIndex"))).
+    let last_index = if let Some(last_index) = agent[r].last_index.get_value() {
+        last_index as usize
+    } else {
+        let scoped_r = r.scope(agent, gc.nogc());
+        let scoped_s = s.scope(agent, gc.nogc());
+        let last_index = get(
+            agent,
+            r.unbind(),
+            BUILTIN_STRING_MEMORY.lastIndex.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let last_index = to_length(agent, last_index.unbind(), gc.reborrow()).unbind()?;
+        r = scoped_r.get(agent).bind(gc.nogc());
+        s = scoped_s.get(agent).bind(gc.nogc());
+        usize::try_from(last_index).unwrap_or(usize::MAX)
+    };
+    let (r, s) = (r.unbind(), s.unbind());
+    let gc = gc.into_nogc();
+    let (r, s) = (r.bind(gc), s.bind(gc));
+    // 3. Let flags be R.[[OriginalFlags]].
+    let flags = agent[r].original_flags;
+    // 4. If flags contains "g", let global be true; else let global be false.
+    let global = flags.contains(RegExpFlags::G);
+    // 5. If flags contains "y", let sticky be true; else let sticky be false.
+    let sticky = flags.contains(RegExpFlags::Y);
+    // 6. If flags contains "d", let hasIndices be true; else let hasIndices be false.
+    let has_indices = flags.contains(RegExpFlags::D);
+    // 7. If global is false and sticky is false, set lastIndex to 0.
+    let last_index = if !global && !sticky { 0 } else { last_index };
+    // 8. Let matcher be R.[[RegExpMatcher]].
+    let Some(matcher) = agent[r].reg_exp_matcher.clone() else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "RegExp object is not initialized",
+            gc,
+        ));
+    };
+    // 9. If flags contains "u" or flags contains "v", let fullUnicode be
+    //    true; else let fullUnicode be false.
+    // 10. Let matchSucceeded be false.
+    // 11. If fullUnicode is true, let input be StringToCodePoints(S).
+    //     Otherwise, let input be a List whose elements are the code units
+    //     that are the elements of S.
+    // 12. NOTE: Each element of input is considered to be a character.
+    // 13. Repeat, while matchSucceeded is false,
+    let Some((found, substrings)) = match_string(agent, &matcher, s, last_index, sticky) else {
+        // a. If lastIndex > length, then
+        //     i. If global is true or sticky is true, then
+        //         1. Perform ? Set(R, "lastIndex", +0𝔽, true).
+        //     ii. Return null.
+        // d. If r is failure, then
+        //     i. If sticky is true, then
+        //         1. Perform ? Set(R, "lastIndex", +0𝔽, true).
+        //         2. Return null.
+        if global || sticky {
+            set_last_index(agent, r, 0.into(), gc)?;
+        }
+        return Ok(None);
+    };
+    // 14. Assert: r is a MatchState.
+    let range = found.range();
+    // 15. Let e be r.[[EndIndex]].
+    // 16. If fullUnicode is true, set e to GetStringIndex(S, e).
+    // Note: The matcher works on code unit indexes directly.
+    let e = range.end;
+    // 17. If global is true or sticky is true, then
+    if global || sticky {
+        // a. Perform ? Set(R, "lastIndex", 𝔽(e), true).
+        set_last_index(agent, r, Value::try_from(e as i64).unwrap(), gc)?;
+    }
+    // 18. Let n be the number of elements in r.[[Captures]].
+    // 19. Assert: n = R.[[RegExpRecord]].[[CapturingGroupsCount]].
+    // 20. Assert: n < 2**32 - 1.
+    let n = matcher.capture_count();
+    // 28. Let matchedSubstr be GetMatchString(S, match).
+    // 33. For each integer i such that 1 ≤ i ≤ n, in ascending order, do
+    //     a. Let captureI be ith element of r.[[Captures]].
+    //     b. If captureI is undefined, let capturedValue be undefined.
+    //     c. Else,
+    //         i. If fullUnicode is true, then
+    //             1. Let captureStart be GetStringIndex(S, captureI.[[StartIndex]]).
+    //             2. Let captureEnd be GetStringIndex(S, captureI.[[EndIndex]]).
+    //         ii. Let captureI be the Match Record { [[StartIndex]]: captureStart, [[EndIndex]]: captureEnd }.
+    //         iii. Let capturedValue be GetMatchString(S, captureI).
+    let captured_values = substrings
+        .into_iter()
+        .map(|substring| {
+            substring.map_or(Value::Undefined, |substring| {
+                String::from_string(agent, substring, gc).into_value()
+            })
+        })
+        .collect::<Vec<_>>();
+    // 21. Let A be ! ArrayCreate(n + 1).
+    // 22. Assert: The mathematical value of A's "length" property is n + 1.
+    // 29. Perform ! CreateDataPropertyOrThrow(A, "0", matchedSubstr).
+    // 33. d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), capturedValue).
+    let a = create_array_from_list(agent, &captured_values, gc);
+    // 23. Perform ! CreateDataPropertyOrThrow(A, "index", 𝔽(lastIndex)).
+    let index = Value::try_from(range.start as i64).unwrap();
+    create_data_property(
+        agent,
+        a.into_object(),
+        BUILTIN_STRING_MEMORY.index.into(),
+        index,
+        gc,
+    );
+    // 24. Perform ! CreateDataPropertyOrThrow(A, "input", S).
+    create_data_property(
+        agent,
+        a.into_object(),
+        BUILTIN_STRING_MEMORY.input.into(),
+        s.into_value(),
+        gc,
+    );
+    // 30. If R contains any GroupName, then
+    let groups = if matcher.has_group_names() {
+        // a. Let groups be OrdinaryObjectCreate(null).
+        // b. Let hasGroups be true.
+        Some(ordinary_object_create_with_intrinsics(
+            agent, None, None, gc,
+        ))
+    } else {
+        // 31. Else,
+        // a. Let groups be undefined.
+        // b. Let hasGroups be false.
+        None
+    };
+    // 32. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
+    create_data_property(
+        agent,
+        a.into_object(),
+        BUILTIN_STRING_MEMORY.groups.into(),
+        groups.map_or(Value::Undefined, |groups| groups.into_value()),
+        gc,
+    );
+    // 26. Let groupNames be a new empty List.
+    let mut group_names: Vec<Option<PropertyKey>> = Vec::with_capacity(n);
+    // 33. For each integer i such that 1 ≤ i ≤ n, in ascending order, do
+    if let Some(groups) = groups {
+        let mut matched_group_names: Vec<&str> = Vec::new();
+        for (i, &captured_value) in captured_values.iter().enumerate().skip(1) {
+            // e. If the ith capture of R was defined with a GroupName, then
+            let Some(s) = matcher.group_name(i) else {
+                // f. Else,
+                // i. Append undefined to groupNames.
+                group_names.push(None);
+                continue;
+            };
+            // i. Let s be the CapturingGroupName of that GroupName.
+            // ii. If matchedGroupNames contains s, then
+            if matched_group_names.contains(&s) {
+                // 1. Assert: capturedValue is undefined.
+                debug_assert!(captured_value.is_undefined());
+                // 2. Append undefined to groupNames.
+                group_names.push(None);
+                continue;
+            }
+            // iii. Else,
+            // 1. If capturedValue is not undefined, append s to
+            //    matchedGroupNames.
+            if !captured_value.is_undefined() {
+                matched_group_names.push(s);
+            }
+            // 2. NOTE: If there are multiple groups named s, groups may
+            //    already have an s property at this point. However, because
+            //    groups is an ordinary object whose properties are all
+            //    writable data properties, the call to
+            //    CreateDataPropertyOrThrow is nevertheless guaranteed to
+            //    succeed.
+            // 3. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
+            let key = PropertyKey::from_str(agent, s, gc);
+            create_data_property(agent, groups, key, captured_value, gc);
+            // 4. Append s to groupNames.
+            group_names.push(Some(key));
+        }
+    } else {
+        group_names.resize(n, None);
+    }
+    // 34. If hasIndices is true, then
+    if has_indices {
+        // a. Let indicesArray be MakeMatchIndicesIndexPairArray(S, indices,
+        //    groupNames, hasGroups).
+        let indices_array =
+            make_match_indices_index_pair_array(agent, &found, &group_names, groups.is_some(), gc);
+        // b. Perform ! CreateDataPropertyOrThrow(A, "indices", indicesArray).
+        create_data_property(
+            agent,
+            a.into_object(),
+            BUILTIN_STRING_MEMORY.indices.into(),
+            indices_array.into_value(),
+            gc,
+        );
+    }
+    #[cfg(feature = "annex-b-regexp")]
+    {
+        // Legacy RegExp features:
+        // 1. Let thisRealm be the current Realm Record.
+        // 2. Let rRealm be the value of R’s [[Realm]] internal slot.
+        // 3. If SameValue(thisRealm, rRealm) is true, then
+        if agent[r].realm == Some(agent.current_realm_id_internal()) {
+            // a. If the value of R’s [[LegacyFeaturesEnabled]] internal slot
+            //    is true, then
+            if agent[r].legacy_features_enabled {
+                // i. Perform UpdateLegacyRegExpStaticProperties(%RegExp%, S,
+                //    lastIndex, e, capturedValues).
+                let matched = String::try_from(captured_values[0]).unwrap();
+                update_legacy_reg_exp_static_properties(
+                    agent,
+                    s,
+                    range.start,
+                    e,
+                    matched,
+                    &captured_values[1..],
+                );
+            } else {
+                // b. Else,
+                // i. Perform InvalidateLegacyRegExpStaticProperties(%RegExp%).
+                invalidate_legacy_reg_exp_static_properties(agent);
+            }
+        }
+    }
+    // 35. Return A.
+    Ok(Some(a))
+}
+
+/// ### [22.2.7.3 AdvanceStringIndex ( S, index, unicode )](https://tc39.es/ecma262/#sec-advancestringindex)
+///
+/// The abstract operation AdvanceStringIndex takes arguments S (a String),
+/// index (a non-negative integer), and unicode (a Boolean) and returns an
+/// integer.
+pub(crate) fn advance_string_index(agent: &Agent, s: String, index: usize, unicode: bool) -> usize {
+    // 1. Assert: index ≤ 2**53 - 1.
+    // 2. If unicode is false, return index + 1.
+    if !unicode {
+        return index + 1;
+    }
+    // 3. Let length be the length of S.
+    let length = s.utf16_len(agent);
+    // 4. If index + 1 ≥ length, return index + 1.
+    if index + 1 >= length {
+        return index + 1;
+    }
+    // 5. Let cp be CodePointAt(S, index).
+    // 6. Return index + cp.[[CodeUnitCount]].
+    // Note: An index pointing to the second half of a surrogate pair is a
+    // lone trailing surrogate with a code unit count of 1.
+    let code_unit_count = s.utf8_index(agent, index).map_or(1, |utf8_index| {
+        s.as_str(agent)[utf8_index..]
+            .chars()
+            .next()
+            .map_or(1, |c| c.len_utf16())
+    });
+    index + code_unit_count
+}
+
+/// Run the matcher on the String, returning the match and the matched
+/// substrings of all capture groups.
+fn match_string(
+    agent: &Agent,
+    matcher: &RegExpMatcher,
+    s: String,
+    last_index: usize,
+    sticky: bool,
+) -> Option<(RegExpMatch, Vec<Option<std::string::String>>)> {
+    let input = s.as_str(agent);
+    let group_count = matcher.capture_count() + 1;
+    if input.is_ascii() {
+        // Note: ASCII strings' UTF-8 bytes are their UTF-16 code units.
+        let found = matcher.exec(input.as_bytes(), last_index, sticky)?;
+        let substrings = (0..group_count)
+            .map(|i| found.get(i).map(|range| input[range].to_owned()))
+            .collect();
+        Some((found, substrings))
+    } else {
+        let input = input.encode_utf16().collect::<Vec<u16>>();
+        let found = matcher.exec(input.as_slice(), last_index, sticky)?;
+        let substrings = (0..group_count)
+            .map(|i| {
+                found
+                    .get(i)
+                    .map(|range| std::string::String::from_utf16_lossy(&input[range]))
+            })
+            .collect();
+        Some((found, substrings))
+    }
+}
+
+/// ### [22.2.7.8 MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )](https://tc39.es/ecma262/#sec-makematchindicesindexpairarray)
+///
+/// The abstract operation MakeMatchIndicesIndexPairArray takes arguments S
+/// (a String), indices (a List of either Match Records or undefined),
+/// groupNames (a List of either Strings or undefined), and hasGroups (a
+/// Boolean) and returns an Array.
+fn make_match_indices_index_pair_array<'a>(
+    agent: &mut Agent,
+    indices: &RegExpMatch,
+    group_names: &[Option<PropertyKey>],
+    has_groups: bool,
+    gc: NoGcScope<'a, '_>,
+) -> Array<'a> {
+    // 1. Let n be the number of elements in indices.
+    // 2. Assert: n < 2**32 - 1.
+    // 3. Assert: groupNames has n - 1 elements.
+    let n = group_names.len() + 1;
+    // 7. For each integer i such that 0 ≤ i < n, in ascending order, do
+    let match_index_pairs = (0..n)
+        .map(|i| {
+            // a. Let matchIndices be indices[i].
+            // b. If matchIndices is not undefined, then
+            //     i. Let matchIndexPair be GetMatchIndexPair(S, matchIndices).
+            // c. Else,
+            //     i. Let matchIndexPair be undefined.
+            indices.get(i).map_or(Value::Undefined, |range| {
+                // ### [22.2.7.7 GetMatchIndexPair ( S, match )](https://tc39.es/ecma262/#sec-getmatchindexpair)
+                create_array_from_list(
+                    agent,
+                    &[
+                        Value::try_from(range.start as i64).unwrap(),
+                        Value::try_from(range.end as i64).unwrap(),
+                    ],
+                    gc,
+                )
+                .into_value()
+            })
+        })
+        .collect::<Vec<_>>();
+    // 4. NOTE: The groupNames List contains elements aligned with the indices
+    //    List starting at indices[1].
+    // 5. Let A be ! ArrayCreate(n).
+    // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
+    let a = create_array_from_list(agent, &match_index_pairs, gc);
+    // 6. If hasGroups is true, then
+    let groups = if has_groups {
+        // a. Let groups be OrdinaryObjectCreate(null).
+        Some(ordinary_object_create_with_intrinsics(
+            agent, None, None, gc,
+        ))
+    } else {
+        // 7. Else,
+        // a. Let groups be undefined.
+        None
+    };
+    // 8. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
+    create_data_property(
+        agent,
+        a.into_object(),
+        BUILTIN_STRING_MEMORY.groups.into(),
+        groups.map_or(Value::Undefined, |groups| groups.into_value()),
+        gc,
+    );
+    if let Some(groups) = groups {
+        for (i, name) in group_names.iter().enumerate() {
+            // e. If i > 0 and groupNames[i - 1] is not undefined, then
+            if let Some(name) = name {
+                // i. Assert: groups is not undefined.
+                // ii. Perform ! CreateDataPropertyOrThrow(groups,
+                //     groupNames[i - 1], matchIndexPair).
+                create_data_property(agent, groups, *name, match_index_pairs[i + 1], gc);
+            }
+        }
+    }
+    // 9. Return A.
+    a
+}
+
+/// Perform ! CreateDataPropertyOrThrow(O, P, V) on an object that is known
+/// to be a fresh ordinary or Array object.
+fn create_data_property(agent: &mut Agent, o: Object, p: PropertyKey, v: Value, gc: NoGcScope) {
+    unwrap_try(try_create_data_property_or_throw(agent, o, p, v, gc)).unwrap();
+}
+
+/// Perform ? Set(R, "lastIndex", V, true).
+fn set_last_index<'a>(
+    agent: &mut Agent,
+    r: RegExp,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    // Note: lastIndex is an unconfigurable data property: It cannot become
+    // a getter or setter and will thus never call into JavaScript.
+    unwrap_try(try_set(
+        agent,
+        r.into_object(),
+        BUILTIN_STRING_MEMORY.lastIndex.into(),
+        value,
+        true,
+        gc,
+    ))
 }
@@ -0,0 +1,686 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::RegExpFlags;
+use oxc_regular_expression::{
+    LiteralParser, Options,
+    ast::{
+        Alternative, BoundaryAssertionKind, CharacterClass, CharacterClassContents,
+        CharacterClassContentsKind, CharacterClassEscapeKind, Disjunction, LookAroundAssertionKind,
+        Quantifier, Term, UnicodePropertyEscape,
+    },
+};
+
+use super::{
+    matcher::{CharMatcher, CharSet, Insn, RegExpMatcher, canonicalize},
+    unicode_tables::property_ranges,
+};
+
+/// ### [22.2.3.4 Static Semantics: ParsePattern ( patternText, u, v )](https://tc39.es/ecma262/#sec-parsepattern)
+///
+/// Parses the pattern text and compiles it into a matcher. The error message
+/// is returned if the pattern is not valid, or if it uses a feature that the
+/// matcher does not support.
+///
+/// > #### Note
+/// > This section is amended in B.1.2.9.
+pub(crate) fn compile_pattern(
+    pattern_text: &str,
+    flags: RegExpFlags,
+) -> Result<RegExpMatcher, std::string::String> {
+    let unicode = flags.contains(RegExpFlags::U);
+    let unicode_sets = flags.contains(RegExpFlags::V);
+    // 1. If v is true and u is true, then
+    //     a. Let parseResult be a List containing one or more SyntaxError objects.
+    // 2. Else if v is true, then
+    //     a. Let parseResult be ParseText(patternText, Pattern[+UnicodeMode, +UnicodeSetsMode, +NamedCaptureGroups]).
+    // 3. Else if u is true, then
+    //     a. Let parseResult be ParseText(patternText, Pattern[+UnicodeMode, ~UnicodeSetsMode, +NamedCaptureGroups]).
+    // 4. Else,
+    //     a. Let parseResult be ParseText(patternText, Pattern[~UnicodeMode, ~UnicodeSetsMode, +NamedCaptureGroups]).
+    let flags_text = match (unicode, unicode_sets) {
+        (true, true) => {
+            return Err("Invalid regular expression flags: u and v are exclusive".into());
+        }
+        (true, false) => "u",
+        (false, true) => "v",
+        (false, false) => "",
+    };
+    let allocator = Allocator::default();
+    let pattern = LiteralParser::new(
+        &allocator,
+        pattern_text,
+        Some(flags_text),
+        Options::default(),
+    )
+    .parse()
+    .map_err(|err| err.to_string())?;
+
+    let mut group_starts = Vec::new();
+    let mut group_names = Vec::new();
+    collect_groups_in_disjunction(&pattern.body, &mut group_starts, &mut group_names);
+
+    let mut compiler = Compiler {
+        program: Vec::new(),
+        sets: Vec::new(),
+        back_references: Vec::new(),
+        state_size: (group_starts.len() + 1) * 2,
+        group_starts,
+        group_names,
+        unicode: unicode || unicode_sets,
+        unicode_sets,
+        ignore_case: flags.contains(RegExpFlags::I),
+        multiline: flags.contains(RegExpFlags::M),
+        dot_all: flags.contains(RegExpFlags::S),
+        backward: false,
+    };
+    compiler.emit(Insn::Save { slot: 0 });
+    compiler.compile_disjunction(&pattern.body)?;
+    compiler.emit(Insn::Save { slot: 1 });
+    compiler.emit(Insn::Match);
+
+    Ok(RegExpMatcher {
+        program: compiler.program.into_boxed_slice(),
+        sets: compiler.sets.into_boxed_slice(),
+        back_references: compiler.back_references.into_boxed_slice(),
+        group_names: compiler.group_names.into_boxed_slice(),
+        state_size: compiler.state_size,
+        unicode: compiler.unicode,
+    })
+}
+
+/// Collect the span starts and names of all capturing groups in source order.
+/// The index of a group in the list is then its group number minus one.
+fn collect_groups_in_disjunction(
+    disjunction: &Disjunction,
+    starts: &mut Vec<u32>,
+    names: &mut Vec<Option<Box<str>>>,
+) {
+    for alternative in &disjunction.body {
+        for term in &alternative.body {
+            collect_groups_in_term(term, starts, names);
+        }
+    }
+}
+
+fn collect_groups_in_term(term: &Term, starts: &mut Vec<u32>, names: &mut Vec<Option<Box<str>>>) {
+    match term {
+        Term::LookAroundAssertion(assertion) => {
+            collect_groups_in_disjunction(&assertion.body, starts, names)
+        }
+        Term::Quantifier(quantifier) => collect_groups_in_term(&quantifier.body, starts, names),
+        Term::CapturingGroup(group) => {
+            starts.push(group.span.start);
+            names.push(group.name.as_ref().map(|name| name.as_str().into()));
+            collect_groups_in_disjunction(&group.body, starts, names);
+        }
+        Term::IgnoreGroup(group) => collect_groups_in_disjunction(&group.body, starts, names),
+        _ => {}
+    }
+}
+
+/// A character class compiled with CompileToCharSet: The characters of the
+/// class and, in UnicodeSetsMode, the strings of any length other than one.
+#[derive(Default)]
+struct ClassSet {
+    chars: CharSet,
+    strings: Vec<Box<[u32]>>,
+}
+
+impl ClassSet {
+    fn union(&mut self, other: ClassSet) {
+        self.chars.add_set(&other.chars);
+        for string in other.strings {
+            if !self.strings.contains(&string) {
+                self.strings.push(string);
+            }
+        }
+    }
+
+    fn intersect(&mut self, other: &ClassSet) {
+        self.chars.intersect(&other.chars);
+        self.strings.retain(|string| other.strings.contains(string));
+    }
+
+    fn subtract(&mut self, other: &ClassSet) {
+        self.chars.subtract(&other.chars);
+        self.strings
+            .retain(|string| !other.strings.contains(string));
+    }
+}
+
+struct Compiler {
+    program: Vec<Insn>,
+    sets: Vec<CharSet>,
+    back_references: Vec<Box<[u32]>>,
+    group_starts: Vec<u32>,
+    group_names: Vec<Option<Box<str>>>,
+    state_size: usize,
+    unicode: bool,
+    unicode_sets: bool,
+    ignore_case: bool,
+    multiline: bool,
+    dot_all: bool,
+    /// True when compiling the body of a lookbehind assertion.
+    backward: bool,
+}
+
+impl Compiler {
+    fn emit(&mut self, insn: Insn) -> usize {
+        self.program.push(insn);
+        self.program.len() - 1
+    }
+
+    fn next_pc(&self) -> u32 {
+        self.program.len() as u32
+    }
+
+    fn allocate_register(&mut self) -> u32 {
+        self.state_size += 1;
+        (self.state_size - 1) as u32
+    }
+
+    /// Get the group number of the capturing group starting at the given
+    /// source position.
+    fn group_number(&self, start: u32) -> u32 {
+        self.group_starts.binary_search(&start).unwrap() as u32 + 1
+    }
+
+    /// ### [22.2.2.3 Runtime Semantics: CompileSubpattern](https://tc39.es/ecma262/#sec-compilesubpattern)
+    ///
+    /// Disjunction :: Alternative | Disjunction
+    fn compile_disjunction(&mut self, disjunction: &Disjunction) -> Result<(), String> {
+        let (last, alternatives) = disjunction.body.split_last().unwrap();
+        let mut jumps = Vec::with_capacity(alternatives.len());
+        for alternative in alternatives {
+            let split = self.emit(Insn::Split {
+                first: self.next_pc() + 1,
+                second: 0,
+            });
+            self.compile_alternative(alternative)?;
+            jumps.push(self.emit(Insn::Jump { target: 0 }));
+            let next = self.next_pc();
+            let Insn::Split { second, .. } = &mut self.program[split] else {
+                unreachable!()
+            };
+            *second = next;
+        }
+        self.compile_alternative(last)?;
+        let end = self.next_pc();
+        for jump in jumps {
+            self.program[jump] = Insn::Jump { target: end };
+        }
+        Ok(())
+    }
+
+    /// Alternative :: Alternative Term
+    ///
+    /// Terms are matched in reverse order when the direction is backward.
+    fn compile_alternative(&mut self, alternative: &Alternative) -> Result<(), String> {
+        if self.backward {
+            for term in alternative.body.iter().rev() {
+                self.compile_term(term)?;
+            }
+        } else {
+            for term in &alternative.body {
+                self.compile_term(term)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn compile_term(&mut self, term: &Term) -> Result<(), String> {
+        match term {
+            Term::BoundaryAssertion(assertion) => {
+                let insn = match assertion.kind {
+                    BoundaryAssertionKind::Start => Insn::AssertStart {
+                        multiline: self.multiline,
+                    },
+                    BoundaryAssertionKind::End => Insn::AssertEnd {
+                        multiline: self.multiline,
+                    },
+                    BoundaryAssertionKind::Boundary => Insn::WordBoundary {
+                        negate: false,
+                        unicode_ignore_case: self.unicode && self.ignore_case,
+                    },
+                    BoundaryAssertionKind::NegativeBoundary => Insn::WordBoundary {
+                        negate: true,
+                        unicode_ignore_case: self.unicode && self.ignore_case,
+                    },
+                };
+                self.emit(insn);
+            }
+            Term::LookAroundAssertion(assertion) => {
+                let (negative, backward) = match assertion.kind {
+                    LookAroundAssertionKind::Lookahead => (false, false),
+                    LookAroundAssertionKind::NegativeLookahead => (true, false),
+                    LookAroundAssertionKind::Lookbehind => (false, true),
+                    LookAroundAssertionKind::NegativeLookbehind => (true, true),
+                };
+                let outer_backward = core::mem::replace(&mut self.backward, backward);
+                let lookaround = self.emit(Insn::LookAround { negative, end: 0 });
+                self.compile_disjunction(&assertion.body)?;
+                self.emit(Insn::LookAroundMatch);
+                self.program[lookaround] = Insn::LookAround {
+                    negative,
+                    end: self.next_pc(),
+                };
+                self.backward = outer_backward;
+            }
+            Term::Quantifier(quantifier) => self.compile_quantifier(quantifier)?,
+            Term::CapturingGroup(group) => {
+                let n = self.group_number(group.span.start);
+                let (first, second) = if self.backward {
+                    (n * 2 + 1, n * 2)
+                } else {
+                    (n * 2, n * 2 + 1)
+                };
+                self.emit(Insn::Save { slot: first });
+                self.compile_disjunction(&group.body)?;
+                self.emit(Insn::Save { slot: second });
+            }
+            Term::IgnoreGroup(group) => {
+                let outer = (self.ignore_case, self.multiline, self.dot_all);
+                if let Some(modifiers) = &group.modifiers {
+                    for (modifier, value) in
+                        [(&modifiers.enabling, true), (&modifiers.disabling, false)]
+                    {
+                        let Some(modifier) = modifier else {
+                            continue;
+                        };
+                        if modifier.ignore_case {
+                            self.ignore_case = value;
+                        }
+                        if modifier.multiline {
+                            self.multiline = value;
+                        }
+                        // Note: oxc calls the `s` (dotAll) modifier "sticky".
+                        if modifier.sticky {
+                            self.dot_all = value;
+                        }
+                    }
+                }
+                self.compile_disjunction(&group.body)?;
+                (self.ignore_case, self.multiline, self.dot_all) = outer;
+            }
+            Term::IndexedReference(reference) => {
+                // Note: References to nonexistent groups are syntax errors
+                // in all but the Annex B grammar, where they never match
+                // anything but are treated as empty.
+                let groups: Box<[u32]> = if (reference.index as usize) <= self.group_names.len() {
+                    Box::new([reference.index])
+                } else {
+                    Box::new([])
+                };
+                self.compile_back_reference(groups);
+            }
+            Term::NamedReference(reference) => {
+                // Note: Duplicate named groups may appear in different
+                // alternatives; the reference matches whichever of them
+                // participated.
+                let groups = self
+                    .group_names
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, name)| name.as_deref() == Some(reference.name.as_str()))
+                    .map(|(index, _)| index as u32 + 1)
+                    .collect();
+                self.compile_back_reference(groups);
+            }
+            Term::CharacterClass(class) => {
+                let set = self.compile_character_class(class)?;
+                if set.strings.is_empty() {
+                    let matcher = self.add_set(set.chars, class.negative);
+                    self.emit(Insn::Char {
+                        matcher,
+                        backward: self.backward,
+                    });
+                } else {
+                    self.compile_class_strings(set);
+                }
+            }
+            _ => {
+                let matcher = self.compile_char_matcher(term)?.unwrap();
+                self.emit(Insn::Char {
+                    matcher,
+                    backward: self.backward,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// ### [22.2.2.7 Runtime Semantics: CompileAtom](https://tc39.es/ecma262/#sec-compileatom)
+    ///
+    /// Atom :: CharacterClass, where the class contains strings: The strings
+    /// are tried longest first, followed by the single characters, and
+    /// finally the empty string.
+    fn compile_class_strings(&mut self, set: ClassSet) {
+        let ClassSet { chars, mut strings } = set;
+        strings.sort_by_key(|string| core::cmp::Reverse(string.len()));
+        let matches_empty = strings.last().is_some_and(|string| string.is_empty());
+        if matches_empty {
+            strings.pop();
+        }
+        let mut alternatives: Vec<Vec<CharMatcher>> = strings
+            .iter()
+            .map(|string| {
+                string
+                    .iter()
+                    .map(|&ch| {
+                        if self.ignore_case {
+                            CharMatcher::CharIgnoreCase(ch)
+                        } else {
+                            CharMatcher::Char(ch)
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        if !chars.is_empty() {
+            alternatives.push(vec![self.add_set(chars, false)]);
+        }
+        if matches_empty {
+            alternatives.push(Vec::new());
+        }
+        let (last, alternatives) = alternatives.split_last().unwrap();
+        let mut jumps = Vec::with_capacity(alternatives.len());
+        for alternative in alternatives {
+            let split = self.emit(Insn::Split {
+                first: self.next_pc() + 1,
+                second: 0,
+            });
+            self.compile_char_sequence(alternative);
+            jumps.push(self.emit(Insn::Jump { target: 0 }));
+            let next = self.next_pc();
+            let Insn::Split { second, .. } = &mut self.program[split] else {
+                unreachable!()
+            };
+            *second = next;
+        }
+        self.compile_char_sequence(last);
+        let end = self.next_pc();
+        for jump in jumps {
+            self.program[jump] = Insn::Jump { target: end };
+        }
+    }
+
+    fn compile_char_sequence(&mut self, matchers: &[CharMatcher]) {
+        let backward = self.backward;
+        let mut emit = |matcher: &CharMatcher| {
+            self.emit(Insn::Char {
+                matcher: *matcher,
+                backward,
+            });
+        };
+        if backward {
+            matchers.iter().rev().for_each(&mut emit);
+        } else {
+            matchers.iter().for_each(&mut emit);
+        }
+    }
+
+    fn compile_back_reference(&mut self, groups: Box<[u32]>) {
+        self.back_references.push(groups);
+        self.emit(Insn::BackReference {
+            index: self.back_references.len() as u32 - 1,
+            ignore_case: self.ignore_case,
+            backward: self.backward,
+        });
+    }
+
+    /// ### [22.2.2.3.1 RepeatMatcher ( m, min, max, greedy, x, c, parenIndex, parenCount )](https://tc39.es/ecma262/#sec-runtime-semantics-repeatmatcher-abstract-operation)
+    fn compile_quantifier(&mut self, quantifier: &Quantifier) -> Result<(), String> {
+        let min = quantifier.min;
+        let max = quantifier.max.unwrap_or(u64::MAX);
+        let greedy = quantifier.greedy;
+        if max == 0 {
+            // Note: The atom can never be matched, and thus it has no effect.
+            return Ok(());
+        }
+        if !self.backward {
+            if let Some(matcher) = self.compile_char_matcher(&quantifier.body)? {
+                self.emit(Insn::RepeatChar {
+                    matcher,
+                    min,
+                    max,
+                    greedy,
+                });
+                return Ok(());
+            }
+        }
+        // 4. For each integer k in the inclusive interval from parenIndex + 1
+        //    to parenIndex + parenCount, set cap[k] to undefined.
+        let span = quantifier.span;
+        let first_group = self.group_starts.partition_point(|&s| s < span.start) as u32 + 1;
+        let end_group = self.group_starts.partition_point(|&s| s < span.end) as u32 + 1;
+        let counter = self.allocate_register();
+        let position = self.allocate_register();
+        self.emit(Insn::RepeatInit { counter });
+        let repeat = self.emit(Insn::Repeat {
+            counter,
+            min,
+            max,
+            greedy,
+            exit: 0,
+        });
+        self.emit(Insn::SetPosition { register: position });
+        if first_group < end_group {
+            self.emit(Insn::ClearCaptures {
+                start: first_group,
+                end: end_group,
+            });
+        }
+        self.compile_term(&quantifier.body)?;
+        self.emit(Insn::RepeatStep {
+            counter,
+            position,
+            min,
+            repeat: repeat as u32,
+        });
+        let exit = self.next_pc();
+        let Insn::Repeat { exit: target, .. } = &mut self.program[repeat] else {
+            unreachable!()
+        };
+        *target = exit;
+        Ok(())
+    }
+
+    /// Compile a term matching exactly one character into a character
+    /// matcher. Returns None if the term does not match a single character.
+    ///
+    /// ### [22.2.2.7.1 CharacterSetMatcher ( rer, A, invert, direction )](https://tc39.es/ecma262/#sec-runtime-semantics-charactersetmatcher-abstract-operation)
+    fn compile_char_matcher(&mut self, term: &Term) -> Result<Option<CharMatcher>, String> {
+        let matcher = match term {
+            Term::Character(character) => {
+                if self.ignore_case {
+                    CharMatcher::CharIgnoreCase(canonicalize(character.value, self.unicode))
+                } else {
+                    CharMatcher::Char(character.value)
+                }
+            }
+            Term::Dot(_) => CharMatcher::Dot {
+                dot_all: self.dot_all,
+            },
+            Term::CharacterClassEscape(escape) => {
+                let mut set = CharSet::default();
+                self.add_class_escape(&mut set, escape.kind);
+                self.add_set(set, false)
+            }
+            Term::CharacterClass(class) => {
+                let set = self.compile_character_class(class)?;
+                if !set.strings.is_empty() {
+                    return Ok(None);
+                }
+                self.add_set(set.chars, class.negative)
+            }
+            Term::UnicodePropertyEscape(escape) => {
+                let mut set = CharSet::default();
+                self.add_property_escape(&mut set, escape)?;
+                self.add_set(set, false)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(matcher))
+    }
+
+    fn add_set(&mut self, mut set: CharSet, negate: bool) -> CharMatcher {
+        if self.ignore_case {
+            set.close_over_case(self.unicode);
+        } else {
+            set.normalize();
+        }
+        self.sets.push(set);
+        CharMatcher::Set {
+            index: self.sets.len() as u32 - 1,
+            negate,
+            ignore_case: self.ignore_case,
+        }
+    }
+
+    /// ### [22.2.2.9 Runtime Semantics: CompileToCharSet](https://tc39.es/ecma262/#sec-compiletocharset)
+    ///
+    /// With ignoreCase, each operand is closed over case before the operands
+    /// are combined. In UnicodeSetsMode this corresponds to
+    /// MaybeSimpleCaseFolding; otherwise it is equivalent to closing the
+    /// union of the operands.
+    fn compile_character_class(&mut self, class: &CharacterClass) -> Result<ClassSet, String> {
+        let mut result: Option<ClassSet> = None;
+        for contents in &class.body {
+            let mut operand = ClassSet::default();
+            match contents {
+                CharacterClassContents::CharacterClassRange(range) => {
+                    operand.chars.add_range(range.min.value, range.max.value);
+                }
+                CharacterClassContents::CharacterClassEscape(escape) => {
+                    self.add_class_escape(&mut operand.chars, escape.kind);
+                }
+                CharacterClassContents::Character(character) => {
+                    operand.chars.add(character.value);
+                }
+                CharacterClassContents::UnicodePropertyEscape(escape) => {
+                    self.add_property_escape(&mut operand.chars, escape)?;
+                }
+                CharacterClassContents::NestedCharacterClass(nested) => {
+                    operand = self.compile_character_class(nested)?;
+                    if nested.negative {
+                        // NestedClass :: [^ ClassContents ]
+                        // 2. Return CharacterComplement(rer, A).
+                        operand.chars.invert();
+                    }
+                }
+                CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                    for string in &disjunction.body {
+                        if let [character] = &string.body[..] {
+                            operand.chars.add(character.value);
+                            continue;
+                        }
+                        let string: Box<[u32]> = string
+                            .body
+                            .iter()
+                            .map(|character| {
+                                if self.ignore_case {
+                                    canonicalize(character.value, self.unicode)
+                                } else {
+                                    character.value
+                                }
+                            })
+                            .collect();
+                        if !operand.strings.contains(&string) {
+                            operand.strings.push(string);
+                        }
+                    }
+                }
+            }
+            if self.ignore_case {
+                operand.chars.close_over_case(self.unicode);
+            }
+            match &mut result {
+                None => result = Some(operand),
+                Some(result) => match class.kind {
+                    CharacterClassContentsKind::Union => result.union(operand),
+                    CharacterClassContentsKind::Intersection => result.intersect(&operand),
+                    CharacterClassContentsKind::Subtraction => result.subtract(&operand),
+                },
+            }
+        }
+        Ok(result.unwrap_or_default())
+    }
+
+    /// CharacterClassEscape :: p{ UnicodePropertyValueExpression }
+    ///
+    /// CharacterClassEscape :: P{ UnicodePropertyValueExpression }
+    fn add_property_escape(
+        &self,
+        set: &mut CharSet,
+        escape: &UnicodePropertyEscape,
+    ) -> Result<(), String> {
+        if escape.strings {
+            return Err("Unicode properties of strings are not supported".into());
+        }
+        let Some(ranges) = property_ranges(
+            escape.name.as_str(),
+            escape.value.as_ref().map(|value| value.as_str()),
+        ) else {
+            return Err("Invalid property name".into());
+        };
+        let mut escape_set = CharSet::default();
+        for &(start, end) in ranges {
+            escape_set.add_range(start, end);
+        }
+        // MaybeSimpleCaseFolding(rer, A)
+        if self.unicode_sets && self.ignore_case {
+            escape_set.close_over_case(true);
+        }
+        if escape.negative {
+            // CharacterComplement(rer, S)
+            escape_set.invert();
+        }
+        set.add_set(&escape_set);
+        Ok(())
+    }
+
+    /// ### [22.2.2.9 CharacterClassEscape](https://tc39.es/ecma262/#sec-compiletocharset)
+    fn add_class_escape(&self, set: &mut CharSet, kind: CharacterClassEscapeKind) {
+        let mut escape_set = CharSet::default();
+        match kind {
+            CharacterClassEscapeKind::D | CharacterClassEscapeKind::NegativeD => {
+                escape_set.add_range(0x30, 0x39);
+            }
+            CharacterClassEscapeKind::S | CharacterClassEscapeKind::NegativeS => {
+                // WhiteSpace or LineTerminator code points.
+                for ch in [
+                    0x0009, 0x000A, 0x000B, 0x000C, 0x000D, 0x0020, 0x00A0, 0x1680, 0x2028, 0x2029,
+                    0x202F, 0x205F, 0x3000, 0xFEFF,
+                ] {
+                    escape_set.add(ch);
+                }
+                escape_set.add_range(0x2000, 0x200A);
+            }
+            CharacterClassEscapeKind::W | CharacterClassEscapeKind::NegativeW => {
+                // ### [22.2.2.9.4 WordCharacters ( rer )](https://tc39.es/ecma262/#sec-wordcharacters)
+                escape_set.add_range(0x30, 0x39);
+                escape_set.add_range(0x41, 0x5A);
+                escape_set.add(0x5F);
+                escape_set.add_range(0x61, 0x7A);
+                if self.unicode && self.ignore_case {
+                    // Characters that canonicalize into basic word
+                    // characters.
+                    escape_set.add(0x017F);
+                    escape_set.add(0x212A);
+                }
+            }
+        }
+        if matches!(
+            kind,
+            CharacterClassEscapeKind::NegativeD
+                | CharacterClassEscapeKind::NegativeS
+                | CharacterClassEscapeKind::NegativeW
+        ) {
+            escape_set.invert();
+        }
+        set.add_set(&escape_set);
+    }
+}
@@ -2,14 +2,20 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::sync::Arc;
+
 use oxc_ast::ast::RegExpFlags;
 
+#[cfg(feature = "annex-b-regexp")]
+use crate::ecmascript::execution::Realm;
 use crate::{
     ecmascript::types::{OrdinaryObject, PropertyDescriptor, String, Value},
     engine::context::{Bindable, NoGcScope},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
+use super::matcher::RegExpMatcher;
+
 /// ## Optimistic storage for the RegExp "lastIndex" property
 ///
 /// The property can take any JavaScript Value, but under any reasonable use it
@@ -99,22 +105,40 @@ impl RegExpLastIndex {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct RegExpHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
-    // _regex: RegExp,
+    /// ### \[\[RegExpMatcher]]
+    ///
+    /// The compiled pattern. This is None only for uninitialized RegExp
+    /// objects created by RegExpAlloc.
+    pub(crate) reg_exp_matcher: Option<Arc<RegExpMatcher>>,
     pub(crate) original_source: String<'a>,
     pub(crate) original_flags: RegExpFlags,
     pub(crate) last_index: RegExpLastIndex,
+    /// ### \[\[Realm]]
+    ///
+    /// The realm that the RegExp object was allocated in. This is None only
+    /// for RegExp literal templates.
+    #[cfg(feature = "annex-b-regexp")]
+    pub(crate) realm: Option<Realm<'a>>,
+    /// ### \[\[LegacyFeaturesEnabled]]
+    #[cfg(feature = "annex-b-regexp")]
+    pub(crate) legacy_features_enabled: bool,
 }
 
 impl Default for RegExpHeapData<'_> {
     fn default() -> Self {
         Self {
             object_index: Default::default(),
+            reg_exp_matcher: None,
             original_source: String::EMPTY_STRING,
             original_flags: RegExpFlags::empty(),
             last_index: Default::default(),
+            #[cfg(feature = "annex-b-regexp")]
+            realm: None,
+            #[cfg(feature = "annex-b-regexp")]
+            legacy_features_enabled: false,
         }
     }
 }
@@ -138,22 +162,36 @@ impl HeapMarkAndSweep for RegExpHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
             object_index,
+            reg_exp_matcher: _,
             original_source,
             original_flags: _,
             last_index: _,
+            #[cfg(feature = "annex-b-regexp")]
+            realm,
+            #[cfg(feature = "annex-b-regexp")]
+                legacy_features_enabled: _,
         } = self;
         object_index.mark_values(queues);
         original_source.mark_values(queues);
+        #[cfg(feature = "annex-b-regexp")]
+        realm.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Self {
             object_index,
+            reg_exp_matcher: _,
             original_source,
             original_flags: _,
             last_index: _,
+            #[cfg(feature = "annex-b-regexp")]
+            realm,
+            #[cfg(feature = "annex-b-regexp")]
+                legacy_features_enabled: _,
         } = self;
         object_index.sweep_values(compactions);
         original_source.sweep_values(compactions);
+        #[cfg(feature = "annex-b-regexp")]
+        realm.sweep_values(compactions);
     }
 }
@@ -0,0 +1,294 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [Legacy RegExp features](https://tc39.es/proposal-regexp-legacy-features/)
+//!
+//! The legacy static properties of the %RegExp% constructor are stored in the
+//! Realm Record that owns the constructor.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{testing_and_comparison::same_value, type_conversion::to_string},
+        execution::{Agent, JsResult, agent::ExceptionType},
+        types::{IntoValue, String, Value},
+    },
+    engine::context::{Bindable, GcScope, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// The legacy static property internal slots of a %RegExp% constructor.
+///
+/// All slots are initially empty.
+#[derive(Debug, Default)]
+pub(crate) struct LegacyRegExpStatics<'a> {
+    /// ### \[\[RegExpInput]]
+    input: Option<String<'a>>,
+    /// The last successful match that the \[\[RegExpLastMatch]],
+    /// \[\[RegExpLastParen]], \[\[RegExpLeftContext]],
+    /// \[\[RegExpRightContext]], and \[\[RegExpParen1]] through
+    /// \[\[RegExpParen9]] slots are derived from.
+    ///
+    /// The context slots are only computed when they are read.
+    last_match: Option<LegacyRegExpMatch<'a>>,
+}
+
+#[derive(Debug)]
+struct LegacyRegExpMatch<'a> {
+    /// The String that the match was made against.
+    string: String<'a>,
+    /// UTF-16 index of the start of the match.
+    start_index: usize,
+    /// UTF-16 index of the end of the match.
+    end_index: usize,
+    /// ### \[\[RegExpLastMatch]]
+    matched: String<'a>,
+    /// Captured values of the match, with undefined captures replaced by the
+    /// empty String.
+    captured_values: Box<[String<'a>]>,
+}
+
+/// A legacy static property of the %RegExp% constructor.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum LegacyRegExpStaticProperty {
+    /// ### \[\[RegExpInput]]
+    Input,
+    /// ### \[\[RegExpLastMatch]]
+    LastMatch,
+    /// ### \[\[RegExpLastParen]]
+    LastParen,
+    /// ### \[\[RegExpLeftContext]]
+    LeftContext,
+    /// ### \[\[RegExpRightContext]]
+    RightContext,
+    /// ### \[\[RegExpParen1]] through \[\[RegExpParen9]]
+    Paren(u8),
+}
+
+/// ### [GetLegacyRegExpStaticProperty ( C, thisValue, internalSlotName )](https://tc39.es/proposal-regexp-legacy-features/#sec-getlegacyregexpstaticproperty)
+///
+/// The abstract operation GetLegacyRegExpStaticProperty is used when
+/// retrieving a value from a legacy static property. C is the %RegExp%
+/// constructor of the current realm.
+pub(crate) fn get_legacy_reg_exp_static_property<'a>(
+    agent: &mut Agent,
+    this_value: Value,
+    internal_slot_name: LegacyRegExpStaticProperty,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, Value<'a>> {
+    // 1. Assert C is an object that has an internal slot named
+    //    internalSlotName.
+    let c = agent.current_realm_record().intrinsics().reg_exp();
+    // 2. If SameValue(C, thisValue) is false, throw a TypeError exception.
+    if !same_value(agent, c.into_value(), this_value.unbind()) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Legacy RegExp static property accessed on an object other than RegExp",
+            gc,
+        ));
+    }
+    // 3. Let value be the value of the internal slot of C named
+    //    internalSlotName.
+    let statics = &agent.current_realm_record().legacy_reg_exp_statics;
+    let value = match internal_slot_name {
+        LegacyRegExpStaticProperty::Input => statics.input,
+        LegacyRegExpStaticProperty::LastMatch => statics.last_match.as_ref().map(|m| m.matched),
+        LegacyRegExpStaticProperty::LastParen => statics.last_match.as_ref().map(|m| {
+            m.captured_values
+                .last()
+                .copied()
+                .unwrap_or(String::EMPTY_STRING)
+        }),
+        LegacyRegExpStaticProperty::Paren(n) => statics.last_match.as_ref().map(|m| {
+            m.captured_values
+                .get(n as usize - 1)
+                .copied()
+                .unwrap_or(String::EMPTY_STRING)
+        }),
+        LegacyRegExpStaticProperty::LeftContext => {
+            let last_match = statics.last_match.as_ref();
+            let context = last_match.map(|m| (m.string.unbind(), m.start_index));
+            context.map(|(string, end)| substring(agent, string, 0, end, gc).unbind())
+        }
+        LegacyRegExpStaticProperty::RightContext => {
+            let last_match = statics.last_match.as_ref();
+            let context = last_match.map(|m| (m.string.unbind(), m.end_index));
+            context.map(|(string, start)| {
+                let end = string.utf16_len(agent);
+                substring(agent, string, start, end, gc).unbind()
+            })
+        }
+    };
+    // 4. Assert: value is either a String, or empty.
+    // 5. If value is empty, throw a TypeError exception.
+    let Some(value) = value else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Legacy RegExp static property is not available",
+            gc,
+        ));
+    };
+    // 6. Return value.
+    Ok(value.into_value().bind(gc))
+}
+
+/// ### [SetLegacyRegExpStaticProperty ( C, thisValue, internalSlotName, val )](https://tc39.es/proposal-regexp-legacy-features/#sec-setlegacyregexpstaticproperty)
+///
+/// The abstract operation SetLegacyRegExpStaticProperty is used when updating
+/// the value of a legacy static property. Only \[\[RegExpInput]] is
+/// writable.
+pub(crate) fn set_legacy_reg_exp_static_input<'a>(
+    agent: &mut Agent,
+    this_value: Value,
+    val: Value,
+    gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    // 1. Assert C is an object that has an internal slot named
+    //    internalSlotName.
+    let c = agent.current_realm_record().intrinsics().reg_exp();
+    // 2. If SameValue(C, thisValue) is false, throw a TypeError exception.
+    if !same_value(agent, c.into_value(), this_value.unbind()) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Legacy RegExp static property accessed on an object other than RegExp",
+            gc.into_nogc(),
+        ));
+    }
+    // 3. Let strVal be ? ToString(val).
+    let str_val = to_string(agent, val, gc)?;
+    // 4. Set the value of the internal slot of C named internalSlotName to
+    //    strVal.
+    agent
+        .current_realm_record_mut()
+        .legacy_reg_exp_statics
+        .input = Some(str_val.unbind());
+    Ok(())
+}
+
+/// ### [UpdateLegacyRegExpStaticProperties ( C, S, startIndex, endIndex, capturedValues )](https://tc39.es/proposal-regexp-legacy-features/#sec-updatelegacyregexpstaticproperties)
+///
+/// The abstract operation UpdateLegacyRegExpStaticProperties updates the
+/// values of the legacy static properties of the current realm's %RegExp%
+/// after a successful match. The matched substring is passed in separately as
+/// it has already been created by RegExpBuiltinExec.
+pub(crate) fn update_legacy_reg_exp_static_properties(
+    agent: &mut Agent,
+    s: String,
+    start_index: usize,
+    end_index: usize,
+    matched: String,
+    captured_values: &[Value],
+) {
+    // 1. Assert: C is an Object that has a [[RegExpInput]] internal slot.
+    // 2. Assert: Type(S) is String.
+    // 3. Let len be the number of code units in S.
+    // 4. Assert: startIndex and endIndex are integers such that
+    //    0 ≤ startIndex ≤ endIndex ≤ len.
+    // 5. Assert: capturedValues is a List of Strings.
+    // 6. Let n be the number of elements in capturedValues.
+    let captured_values = captured_values
+        .iter()
+        .map(|value| {
+            String::try_from(*value)
+                .unwrap_or(String::EMPTY_STRING)
+                .unbind()
+        })
+        .collect();
+    let statics = &mut agent.current_realm_record_mut().legacy_reg_exp_statics;
+    // 7. Set the value of C’s [[RegExpInput]] internal slot to S.
+    statics.input = Some(s.unbind());
+    // 8. Set the value of C’s [[RegExpLastMatch]] internal slot to a String
+    //    whose length is endIndex - startIndex and containing the code units
+    //    from S with indices startIndex through endIndex - 1, in ascending
+    //    order.
+    // 9. If n > 0, set the value of C’s [[RegExpLastParen]] internal slot to
+    //    the last element of capturedValues.
+    // 10. Else, set the value of C’s [[RegExpLastParen]] internal slot to the
+    //     empty String.
+    // 11. Set the value of C’s [[RegExpLeftContext]] internal slot to a
+    //     String whose length is startIndex and containing the code units
+    //     from S with indices 0 through startIndex - 1, in ascending order.
+    // 12. Set the value of C’s [[RegExpRightContext]] internal slot to a
+    //     String whose length is len - endIndex and containing the code units
+    //     from S with indices endIndex through len - 1, in ascending order.
+    // 13. For each integer i such that 1 ≤ i ≤ 9
+    //     a. If i ≤ n, set the value of C’s [[RegExpPareni]] internal slot to
+    //        the ith element of capturedValues.
+    //     b. Else, set the value of C’s [[RegExpPareni]] internal slot to the
+    //        empty String.
+    statics.last_match = Some(LegacyRegExpMatch {
+        string: s.unbind(),
+        start_index,
+        end_index,
+        matched: matched.unbind(),
+        captured_values,
+    });
+}
+
+/// ### [InvalidateLegacyRegExpStaticProperties ( C )](https://tc39.es/proposal-regexp-legacy-features/#sec-invalidatelegacyregexpstaticproperties)
+///
+/// The abstract operation InvalidateLegacyRegExpStaticProperties marks the
+/// values of the legacy static properties of the current realm's %RegExp% as
+/// empty.
+pub(crate) fn invalidate_legacy_reg_exp_static_properties(agent: &mut Agent) {
+    // 1. Assert: C is an Object that has a [[RegExpInput]] internal slot.
+    // 2. Set the value of the following internal slots of C to empty:
+    //    [[RegExpInput]], [[RegExpLastMatch]], [[RegExpLastParen]],
+    //    [[RegExpLeftContext]], [[RegExpRightContext]], and [[RegExpParen1]]
+    //    through [[RegExpParen9]].
+    agent.current_realm_record_mut().legacy_reg_exp_statics = Default::default();
+}
+
+/// Create the substring of S from start to end, as UTF-16 indexes.
+fn substring<'a>(
+    agent: &mut Agent,
+    s: String,
+    start: usize,
+    end: usize,
+    gc: NoGcScope<'a, '_>,
+) -> String<'a> {
+    if let (Some(start), Some(end)) = (s.utf8_index(agent, start), s.utf8_index(agent, end)) {
+        let substring = s.as_str(agent)[start..end].to_owned();
+        String::from_string(agent, substring, gc)
+    } else {
+        let utf16 = s.as_str(agent).encode_utf16().collect::<Vec<u16>>();
+        let substring = std::string::String::from_utf16_lossy(&utf16[start..end]);
+        String::from_string(agent, substring, gc)
+    }
+}
+
+impl HeapMarkAndSweep for LegacyRegExpStatics<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self { input, last_match } = self;
+        input.mark_values(queues);
+        if let Some(LegacyRegExpMatch {
+            string,
+            start_index: _,
+            end_index: _,
+            matched,
+            captured_values,
+        }) = last_match
+        {
+            string.mark_values(queues);
+            matched.mark_values(queues);
+            captured_values.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self { input, last_match } = self;
+        input.sweep_values(compactions);
+        if let Some(LegacyRegExpMatch {
+            string,
+            start_index: _,
+            end_index: _,
+            matched,
+            captured_values,
+        }) = last_match
+        {
+            string.sweep_values(compactions);
+            matched.sweep_values(compactions);
+            captured_values.sweep_values(compactions);
+        }
+    }
+}
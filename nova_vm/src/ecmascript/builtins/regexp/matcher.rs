@@ -0,0 +1,972 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::ops::Range;
+use std::sync::OnceLock;
+
+/// Sentinel value for capture slots that have not been set.
+const UNSET: usize = usize::MAX;
+
+/// Largest code point value.
+const MAX_CODE_POINT: u32 = 0x10FFFF;
+
+/// ## Matcher input
+///
+/// The matcher operates on UTF-16 code units. ASCII-only strings can be
+/// matched directly from their UTF-8 bytes, as each byte is then exactly one
+/// code unit.
+pub(crate) trait MatchInput {
+    /// Number of code units in the input.
+    fn code_unit_count(&self) -> usize;
+
+    /// Get the code unit at the given index.
+    fn code_unit(&self, index: usize) -> u16;
+}
+
+impl MatchInput for [u8] {
+    #[inline(always)]
+    fn code_unit_count(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn code_unit(&self, index: usize) -> u16 {
+        self[index] as u16
+    }
+}
+
+impl MatchInput for [u16] {
+    #[inline(always)]
+    fn code_unit_count(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn code_unit(&self, index: usize) -> u16 {
+        self[index]
+    }
+}
+
+#[inline(always)]
+fn is_high_surrogate(code_unit: u16) -> bool {
+    (0xD800..=0xDBFF).contains(&code_unit)
+}
+
+#[inline(always)]
+fn is_low_surrogate(code_unit: u16) -> bool {
+    (0xDC00..=0xDFFF).contains(&code_unit)
+}
+
+#[inline(always)]
+fn is_line_terminator(ch: u32) -> bool {
+    matches!(ch, 0x000A | 0x000D | 0x2028 | 0x2029)
+}
+
+/// ### [22.2.2.9.3 IsWordChar ( rer, Input, e )](https://tc39.es/ecma262/#sec-runtime-semantics-iswordchar-abstract-operation)
+///
+/// Checks if the character is in WordCharacters(rer). When both ignoreCase
+/// and unicode matching are on, the word characters also include the
+/// characters that canonicalize into basic word characters: U+017F LATIN
+/// SMALL LETTER LONG S and U+212A KELVIN SIGN.
+#[inline]
+fn is_word_char(ch: u32, unicode_ignore_case: bool) -> bool {
+    matches!(ch, 0x30..=0x39 | 0x41..=0x5A | 0x5F | 0x61..=0x7A)
+        || (unicode_ignore_case && matches!(ch, 0x017F | 0x212A))
+}
+
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    let first = chars.next()?;
+    if chars.next().is_some() {
+        None
+    } else {
+        Some(first)
+    }
+}
+
+/// ### [22.2.2.7.3 Canonicalize ( rer, ch )](https://tc39.es/ecma262/#sec-runtime-semantics-canonicalize-ch)
+///
+/// In Unicode mode the simple case folding of the character is derived as the
+/// lowercase of its uppercase form; the Turkic dotted and dotless I and the
+/// few characters whose folding is not given by their case mappings are
+/// special-cased.
+pub(super) fn canonicalize(ch: u32, unicode: bool) -> u32 {
+    if ch < 0x80 {
+        let ch = ch as u8;
+        return if unicode {
+            ch.to_ascii_lowercase()
+        } else {
+            ch.to_ascii_uppercase()
+        } as u32;
+    }
+    let Some(c) = char::from_u32(ch) else {
+        // Lone surrogates canonicalize to themselves.
+        return ch;
+    };
+    if unicode {
+        // 1. If HasEitherUnicodeFlag(rer) is true and rer.[[IgnoreCase]] is
+        //    true, then
+        // a. If the file CaseFolding.txt of the Unicode Character Database
+        //    provides a simple or common case folding mapping for ch, return
+        //    the result of applying that mapping to ch.
+        // b. Return ch.
+        match ch {
+            0x0130 | 0x0131 => return ch,
+            // Simple case foldings that don't follow from the case mappings.
+            0x1FD3 => return 0x0390,
+            0x1FE3 => return 0x03B0,
+            0xFB05 => return 0xFB06,
+            _ => {}
+        }
+        let upper = single_char(c.to_uppercase()).unwrap_or(c);
+        single_char(upper.to_lowercase()).unwrap_or(upper) as u32
+    } else {
+        // 3. Let cp be the code point whose numeric value is the numeric
+        //    value of ch.
+        // 4. Let u be toUppercase(« cp »), according to the Unicode Default
+        //    Case Conversion algorithm.
+        // 5. Let uStr be CodePointsToString(u).
+        // 6. If the length of uStr ≠ 1, return ch.
+        let Some(u) = single_char(c.to_uppercase()) else {
+            return ch;
+        };
+        // 7. Let cu be uStr's single code unit element.
+        let cu = u as u32;
+        if cu > 0xFFFF {
+            return ch;
+        }
+        // 8. If the numeric value of ch ≥ 128 and the numeric value of cu <
+        //    128, return ch.
+        if cu < 0x80 {
+            return ch;
+        }
+        // 9. Return cu.
+        cu
+    }
+}
+
+/// Returns all characters that are not their own canonical form, together
+/// with their canonical form.
+///
+/// Characters above U+1E950 have no case mappings and are not included.
+fn case_table(unicode: bool) -> &'static [(u32, u32)] {
+    static UNICODE_TABLE: OnceLock<Box<[(u32, u32)]>> = OnceLock::new();
+    static NON_UNICODE_TABLE: OnceLock<Box<[(u32, u32)]>> = OnceLock::new();
+    let (table, end) = if unicode {
+        (&UNICODE_TABLE, 0x1E950)
+    } else {
+        (&NON_UNICODE_TABLE, 0x10000)
+    };
+    table.get_or_init(|| {
+        (0..end)
+            .filter_map(|ch| {
+                let canonical = canonicalize(ch, unicode);
+                (canonical != ch).then_some((ch, canonical))
+            })
+            .collect()
+    })
+}
+
+/// ## Character set
+///
+/// A set of characters stored as inclusive ranges. After normalization, the
+/// ranges are sorted and non-overlapping.
+#[derive(Debug, Clone, Default)]
+pub(super) struct CharSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharSet {
+    pub(super) fn add(&mut self, ch: u32) {
+        self.ranges.push((ch, ch));
+    }
+
+    pub(super) fn add_range(&mut self, start: u32, end: u32) {
+        debug_assert!(start <= end);
+        self.ranges.push((start, end));
+    }
+
+    pub(super) fn add_set(&mut self, other: &CharSet) {
+        self.ranges.extend_from_slice(&other.ranges);
+    }
+
+    /// Sort and merge the ranges of the set.
+    pub(super) fn normalize(&mut self) {
+        self.ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(self.ranges.len());
+        for &(start, end) in &self.ranges {
+            if let Some(last) = merged.last_mut() {
+                if start <= last.1.saturating_add(1) {
+                    last.1 = last.1.max(end);
+                    continue;
+                }
+            }
+            merged.push((start, end));
+        }
+        self.ranges = merged;
+    }
+
+    /// Check if the set contains no characters.
+    pub(super) fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Remove all characters that are not in the other set from the set.
+    pub(super) fn intersect(&mut self, other: &CharSet) {
+        // A ∩ B = ¬(¬A ∪ ¬B)
+        let mut other = other.clone();
+        other.invert();
+        self.invert();
+        self.add_set(&other);
+        self.invert();
+    }
+
+    /// Remove all characters that are in the other set from the set.
+    pub(super) fn subtract(&mut self, other: &CharSet) {
+        // A - B = ¬(¬A ∪ B)
+        self.invert();
+        self.add_set(other);
+        self.invert();
+    }
+
+    /// Replace the set with its complement over all code points.
+    pub(super) fn invert(&mut self) {
+        self.normalize();
+        let mut inverted = Vec::with_capacity(self.ranges.len() + 1);
+        let mut next = 0;
+        for &(start, end) in &self.ranges {
+            if start > next {
+                inverted.push((next, start - 1));
+            }
+            next = end + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            inverted.push((next, MAX_CODE_POINT));
+        }
+        self.ranges = inverted;
+    }
+
+    /// Add the canonical forms of all characters in the set to the set.
+    ///
+    /// A character `ch` then matches the set with ignoreCase if and only if
+    /// the set contains `Canonicalize(ch)`: The canonical form is always its
+    /// own canonical form, and thus it's either in the set already or it is
+    /// the canonical form of some other character in the set.
+    pub(super) fn close_over_case(&mut self, unicode: bool) {
+        self.normalize();
+        let additions = case_table(unicode)
+            .iter()
+            .filter(|(ch, _)| self.contains(*ch))
+            .map(|&(_, canonical)| (canonical, canonical))
+            .collect::<Vec<_>>();
+        if !additions.is_empty() {
+            self.ranges.extend(additions);
+            self.normalize();
+        }
+    }
+
+    /// Check if the normalized set contains a character.
+    pub(super) fn contains(&self, ch: u32) -> bool {
+        self.ranges
+            .binary_search_by(|&(start, end)| {
+                if end < ch {
+                    core::cmp::Ordering::Less
+                } else if start > ch {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+}
+
+/// Matcher for a single character.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum CharMatcher {
+    /// Match the character exactly.
+    Char(u32),
+    /// Match any character whose canonical form is the given character.
+    CharIgnoreCase(u32),
+    /// Match any character, except line terminators if `dot_all` is false.
+    Dot { dot_all: bool },
+    /// Match any character in (or not in, if `negate` is true) a set.
+    ///
+    /// If `ignore_case` is true, the set is closed over canonicalization and
+    /// the canonical form of the character is looked up.
+    Set {
+        index: u32,
+        negate: bool,
+        ignore_case: bool,
+    },
+}
+
+/// A single instruction of the matcher program.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Insn {
+    /// Match a single character, moving forwards or backwards.
+    Char {
+        matcher: CharMatcher,
+        backward: bool,
+    },
+    /// Match a single character between `min` and `max` times, moving
+    /// forwards. A `max` of `u64::MAX` means there is no upper bound.
+    RepeatChar {
+        matcher: CharMatcher,
+        min: u64,
+        max: u64,
+        greedy: bool,
+    },
+    /// Assert that the position is at the start of the input, or after a
+    /// line terminator if `multiline` is true.
+    AssertStart { multiline: bool },
+    /// Assert that the position is at the end of the input, or before a line
+    /// terminator if `multiline` is true.
+    AssertEnd { multiline: bool },
+    /// Assert that the position is (or is not, if `negate` is true) at a word
+    /// boundary.
+    WordBoundary {
+        negate: bool,
+        unicode_ignore_case: bool,
+    },
+    /// Match the contents of the first participating capture group in the
+    /// back reference group list at `index`.
+    BackReference {
+        index: u32,
+        ignore_case: bool,
+        backward: bool,
+    },
+    /// Store the current position into a capture slot.
+    Save { slot: u32 },
+    /// Reset the capture groups `start..end` to undefined.
+    ClearCaptures { start: u32, end: u32 },
+    /// Continue at `target`.
+    Jump { target: u32 },
+    /// Continue at `first`; on failure backtrack to `second`.
+    Split { first: u32, second: u32 },
+    /// Set the iteration counter of a loop to zero.
+    RepeatInit { counter: u32 },
+    /// Decide whether to run another iteration of a loop: The loop body
+    /// follows this instruction, and `exit` is the instruction after the
+    /// loop.
+    Repeat {
+        counter: u32,
+        min: u64,
+        max: u64,
+        greedy: bool,
+        exit: u32,
+    },
+    /// Store the current position into a register.
+    SetPosition { register: u32 },
+    /// End of a loop body: Fail if the iteration matched the empty string
+    /// and the minimum number of iterations has already been reached.
+    /// Otherwise increment the counter and continue at `repeat`.
+    RepeatStep {
+        counter: u32,
+        position: u32,
+        min: u64,
+        repeat: u32,
+    },
+    /// Run a lookaround assertion whose body follows this instruction and
+    /// ends in [`Insn::LookAroundMatch`]. Matching continues at `end`.
+    LookAround { negative: bool, end: u32 },
+    /// Successful end of a lookaround body.
+    LookAroundMatch,
+    /// Successful end of the pattern.
+    Match,
+}
+
+/// ## [22.2.2.1 Notation: Matcher](https://tc39.es/ecma262/#sec-pattern-notation)
+///
+/// A compiled regular expression pattern. The matcher is a backtracking
+/// virtual machine running a program compiled from the pattern's syntax tree.
+#[derive(Debug)]
+pub(crate) struct RegExpMatcher {
+    pub(super) program: Box<[Insn]>,
+    pub(super) sets: Box<[CharSet]>,
+    /// Lists of capture groups referred to by back references. A named back
+    /// reference may refer to multiple groups with the same name.
+    pub(super) back_references: Box<[Box<[u32]>]>,
+    /// Names of the capture groups, excluding the implicit group 0.
+    pub(super) group_names: Box<[Option<Box<str>>]>,
+    /// Number of slots in the match state: Two capture slots for each group
+    /// including group 0, followed by loop registers.
+    pub(super) state_size: usize,
+    /// True if the pattern matches code points instead of code units.
+    pub(super) unicode: bool,
+}
+
+/// ### [22.2.2.1 MatchState](https://tc39.es/ecma262/#pattern-matchstate)
+///
+/// The successful result of a match: The start and end indexes of all
+/// capture groups, including group 0.
+#[derive(Debug)]
+pub(crate) struct RegExpMatch {
+    captures: Box<[usize]>,
+}
+
+impl RegExpMatch {
+    /// Get the code unit range of a capture group, or None if the group did
+    /// not participate in the match.
+    pub(crate) fn get(&self, group: usize) -> Option<Range<usize>> {
+        let start = self.captures[group * 2];
+        let end = self.captures[group * 2 + 1];
+        if start == UNSET || end == UNSET {
+            None
+        } else {
+            Some(start..end)
+        }
+    }
+
+    /// Code unit range of the whole match.
+    pub(crate) fn range(&self) -> Range<usize> {
+        self.get(0).unwrap()
+    }
+}
+
+impl RegExpMatcher {
+    /// Number of capture groups in the pattern, excluding group 0.
+    pub(crate) fn capture_count(&self) -> usize {
+        self.group_names.len()
+    }
+
+    /// Name of a capture group, if it is named. Group numbers start from 1.
+    pub(crate) fn group_name(&self, group: usize) -> Option<&str> {
+        self.group_names[group - 1].as_deref()
+    }
+
+    /// True if the pattern contains any named capture groups.
+    pub(crate) fn has_group_names(&self) -> bool {
+        self.group_names.iter().any(Option::is_some)
+    }
+
+    /// Find the first match in the input starting at `last_index` or, if
+    /// `sticky` is true, only at `last_index`.
+    ///
+    /// This performs steps 12 and 13 of RegExpBuiltinExec.
+    pub(crate) fn exec<I: MatchInput + ?Sized>(
+        &self,
+        input: &I,
+        last_index: usize,
+        sticky: bool,
+    ) -> Option<RegExpMatch> {
+        let length = input.code_unit_count();
+        let mut execution = Execution {
+            matcher: self,
+            input,
+            state: Vec::with_capacity(self.state_size),
+            undo: Vec::new(),
+            backtrack: Vec::new(),
+        };
+        let mut last_index = last_index;
+        // Note: When matching code points, a lastIndex pointing to the
+        // middle of a surrogate pair starts the match from the character
+        // that the code unit belongs to.
+        if self.unicode
+            && last_index > 0
+            && last_index < length
+            && is_low_surrogate(input.code_unit(last_index))
+            && is_high_surrogate(input.code_unit(last_index - 1))
+        {
+            last_index -= 1;
+        }
+        // 12. Repeat, while matchSucceeded is false,
+        loop {
+            // a. If lastIndex > length, then
+            if last_index > length {
+                // ii. Return null.
+                return None;
+            }
+            // c. Let r be matcher(input, inputIndex).
+            if let Some(result) = execution.attempt(last_index) {
+                return Some(result);
+            }
+            // d. If r is failure, then
+            // i. If sticky is true, then
+            if sticky {
+                // 2. Return null.
+                return None;
+            }
+            // ii. Set lastIndex to AdvanceStringIndex(S, lastIndex, fullUnicode).
+            last_index += if self.unicode
+                && last_index + 1 < length
+                && is_high_surrogate(input.code_unit(last_index))
+                && is_low_surrogate(input.code_unit(last_index + 1))
+            {
+                2
+            } else {
+                1
+            };
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Backtrack {
+    /// Continue matching at the given instruction and position.
+    Branch {
+        pc: usize,
+        pos: usize,
+        undo_len: usize,
+    },
+    /// Give back one character matched by a greedy [`Insn::RepeatChar`] and
+    /// continue at the following instruction.
+    GreedyChar {
+        pc: usize,
+        min_pos: usize,
+        pos: usize,
+        undo_len: usize,
+    },
+    /// Match one more character with the lazy [`Insn::RepeatChar`] at `pc`
+    /// and continue at the following instruction.
+    LazyChar {
+        pc: usize,
+        pos: usize,
+        count: u64,
+        undo_len: usize,
+    },
+}
+
+struct Execution<'m, 'i, I: MatchInput + ?Sized> {
+    matcher: &'m RegExpMatcher,
+    input: &'i I,
+    /// Capture slots followed by loop registers.
+    state: Vec<usize>,
+    /// Log of state changes as (slot, previous value) pairs, used to restore
+    /// the state when backtracking.
+    undo: Vec<(usize, usize)>,
+    backtrack: Vec<Backtrack>,
+}
+
+impl<I: MatchInput + ?Sized> Execution<'_, '_, I> {
+    fn attempt(&mut self, index: usize) -> Option<RegExpMatch> {
+        self.state.clear();
+        self.state.resize(self.matcher.state_size, UNSET);
+        self.undo.clear();
+        self.backtrack.clear();
+        self.run(0, index)?;
+        let capture_slots = (self.matcher.capture_count() + 1) * 2;
+        Some(RegExpMatch {
+            captures: self.state[..capture_slots].into(),
+        })
+    }
+
+    #[inline]
+    fn set(&mut self, slot: usize, value: usize) {
+        let previous = core::mem::replace(&mut self.state[slot], value);
+        self.undo.push((slot, previous));
+    }
+
+    fn rewind(&mut self, undo_len: usize) {
+        while self.undo.len() > undo_len {
+            let (slot, value) = self.undo.pop().unwrap();
+            self.state[slot] = value;
+        }
+    }
+
+    #[inline]
+    fn next_char(&self, pos: usize) -> Option<(u32, usize)> {
+        let length = self.input.code_unit_count();
+        if pos >= length {
+            return None;
+        }
+        let cu = self.input.code_unit(pos);
+        if self.matcher.unicode && is_high_surrogate(cu) && pos + 1 < length {
+            let low = self.input.code_unit(pos + 1);
+            if is_low_surrogate(low) {
+                let ch = 0x10000 + (((cu as u32) - 0xD800) << 10) + ((low as u32) - 0xDC00);
+                return Some((ch, pos + 2));
+            }
+        }
+        Some((cu as u32, pos + 1))
+    }
+
+    #[inline]
+    fn prev_char(&self, pos: usize) -> Option<(u32, usize)> {
+        if pos == 0 {
+            return None;
+        }
+        let cu = self.input.code_unit(pos - 1);
+        if self.matcher.unicode && is_low_surrogate(cu) && pos >= 2 {
+            let high = self.input.code_unit(pos - 2);
+            if is_high_surrogate(high) {
+                let ch = 0x10000 + (((high as u32) - 0xD800) << 10) + ((cu as u32) - 0xDC00);
+                return Some((ch, pos - 2));
+            }
+        }
+        Some((cu as u32, pos - 1))
+    }
+
+    #[inline]
+    fn char_matches(&self, matcher: CharMatcher, ch: u32) -> bool {
+        match matcher {
+            CharMatcher::Char(expected) => ch == expected,
+            CharMatcher::CharIgnoreCase(expected) => {
+                canonicalize(ch, self.matcher.unicode) == expected
+            }
+            CharMatcher::Dot { dot_all } => dot_all || !is_line_terminator(ch),
+            CharMatcher::Set {
+                index,
+                negate,
+                ignore_case,
+            } => {
+                let ch = if ignore_case {
+                    canonicalize(ch, self.matcher.unicode)
+                } else {
+                    ch
+                };
+                self.matcher.sets[index as usize].contains(ch) != negate
+            }
+        }
+    }
+
+    #[inline]
+    fn match_char(&self, matcher: CharMatcher, pos: usize, backward: bool) -> Option<usize> {
+        let (ch, next) = if backward {
+            self.prev_char(pos)?
+        } else {
+            self.next_char(pos)?
+        };
+        self.char_matches(matcher, ch).then_some(next)
+    }
+
+    /// ### [22.2.2.9.7 BackreferenceMatcher ( rer, ns, direction )](https://tc39.es/ecma262/#sec-backreference-matcher)
+    fn match_back_reference(
+        &self,
+        index: u32,
+        ignore_case: bool,
+        backward: bool,
+        pos: usize,
+    ) -> Option<usize> {
+        let groups = &self.matcher.back_references[index as usize];
+        // a. Let r be the first element of ns such that cap[r] is not
+        //    undefined, if any.
+        let Some(captured) = groups.iter().find_map(|&group| {
+            let start = self.state[group as usize * 2];
+            let end = self.state[group as usize * 2 + 1];
+            (start != UNSET && end != UNSET).then_some(start..end)
+        }) else {
+            // b. If cap[r] is undefined, return c(x).
+            return Some(pos);
+        };
+        // e. Let len be the number of elements in s.
+        let len = captured.len();
+        // f. If direction is forward, let f be e + len.
+        // g. Else, let f be e - len.
+        // h. If f < 0 or f > InputLength, return failure.
+        let (start, next) = if backward {
+            let start = pos.checked_sub(len)?;
+            (start, start)
+        } else {
+            let end = pos + len;
+            if end > self.input.code_unit_count() {
+                return None;
+            }
+            (pos, end)
+        };
+        // i. Let g be min(e, f).
+        // j. If there exists an integer i in the interval from 0
+        //    (inclusive) to len (exclusive) such that Canonicalize(rer,
+        //    s[i]) is not Canonicalize(rer, Input[g + i]), return failure.
+        if !ignore_case {
+            if (0..len).any(|i| {
+                self.input.code_unit(captured.start + i) != self.input.code_unit(start + i)
+            }) {
+                return None;
+            }
+        } else if !self.matcher.unicode {
+            if (0..len).any(|i| {
+                canonicalize(self.input.code_unit(captured.start + i) as u32, false)
+                    != canonicalize(self.input.code_unit(start + i) as u32, false)
+            }) {
+                return None;
+            }
+        } else {
+            let mut a = captured.start;
+            let mut b = start;
+            while a < captured.end {
+                let (ca, next_a) = self.next_char(a)?;
+                let (cb, next_b) = self.next_char(b)?;
+                if next_a - a != next_b - b || canonicalize(ca, true) != canonicalize(cb, true) {
+                    return None;
+                }
+                a = next_a;
+                b = next_b;
+            }
+        }
+        Some(next)
+    }
+
+    /// Pop backtrack entries until one provides a new state to continue
+    /// from. Returns None if there are no entries above `base` left.
+    fn backtrack(&mut self, base: usize) -> Option<(usize, usize)> {
+        while self.backtrack.len() > base {
+            match self.backtrack.pop().unwrap() {
+                Backtrack::Branch { pc, pos, undo_len } => {
+                    self.rewind(undo_len);
+                    return Some((pc, pos));
+                }
+                Backtrack::GreedyChar {
+                    pc,
+                    min_pos,
+                    pos,
+                    undo_len,
+                } => {
+                    self.rewind(undo_len);
+                    let back = if self.matcher.unicode
+                        && pos - min_pos >= 2
+                        && is_low_surrogate(self.input.code_unit(pos - 1))
+                        && is_high_surrogate(self.input.code_unit(pos - 2))
+                    {
+                        pos - 2
+                    } else {
+                        pos - 1
+                    };
+                    if back > min_pos {
+                        self.backtrack.push(Backtrack::GreedyChar {
+                            pc,
+                            min_pos,
+                            pos: back,
+                            undo_len,
+                        });
+                    }
+                    return Some((pc, back));
+                }
+                Backtrack::LazyChar {
+                    pc,
+                    pos,
+                    count,
+                    undo_len,
+                } => {
+                    self.rewind(undo_len);
+                    let Insn::RepeatChar { matcher, max, .. } = self.matcher.program[pc] else {
+                        unreachable!()
+                    };
+                    let Some(next) = self.match_char(matcher, pos, false) else {
+                        continue;
+                    };
+                    let count = count + 1;
+                    if count < max {
+                        self.backtrack.push(Backtrack::LazyChar {
+                            pc,
+                            pos: next,
+                            count,
+                            undo_len,
+                        });
+                    }
+                    return Some((pc + 1, next));
+                }
+            }
+        }
+        None
+    }
+
+    /// Run the program from `pc` at position `pos` until a match instruction
+    /// is reached, returning the final position. Returns None if no match is
+    /// found.
+    fn run(&mut self, mut pc: usize, mut pos: usize) -> Option<usize> {
+        let base = self.backtrack.len();
+        let matcher = self.matcher;
+        let length = self.input.code_unit_count();
+        loop {
+            let success = match matcher.program[pc] {
+                Insn::Char { matcher, backward } => {
+                    if let Some(next) = self.match_char(matcher, pos, backward) {
+                        pos = next;
+                        pc += 1;
+                        true
+                    } else {
+                        false
+                    }
+                }
+                Insn::RepeatChar {
+                    matcher,
+                    min,
+                    max,
+                    greedy,
+                } => {
+                    let mut count = 0;
+                    let mut min_pos = pos;
+                    let limit = if greedy { max } else { min };
+                    while count < limit {
+                        let Some(next) = self.match_char(matcher, pos, false) else {
+                            break;
+                        };
+                        pos = next;
+                        count += 1;
+                        if count == min {
+                            min_pos = pos;
+                        }
+                    }
+                    if count < min {
+                        false
+                    } else {
+                        let undo_len = self.undo.len();
+                        if greedy {
+                            if pos > min_pos {
+                                self.backtrack.push(Backtrack::GreedyChar {
+                                    pc: pc + 1,
+                                    min_pos,
+                                    pos,
+                                    undo_len,
+                                });
+                            }
+                        } else if count < max {
+                            self.backtrack.push(Backtrack::LazyChar {
+                                pc,
+                                pos,
+                                count,
+                                undo_len,
+                            });
+                        }
+                        pc += 1;
+                        true
+                    }
+                }
+                Insn::AssertStart { multiline } => {
+                    pc += 1;
+                    pos == 0
+                        || (multiline && is_line_terminator(self.input.code_unit(pos - 1) as u32))
+                }
+                Insn::AssertEnd { multiline } => {
+                    pc += 1;
+                    pos == length
+                        || (multiline && is_line_terminator(self.input.code_unit(pos) as u32))
+                }
+                Insn::WordBoundary {
+                    negate,
+                    unicode_ignore_case,
+                } => {
+                    let a = pos > 0
+                        && is_word_char(self.input.code_unit(pos - 1) as u32, unicode_ignore_case);
+                    let b = pos < length
+                        && is_word_char(self.input.code_unit(pos) as u32, unicode_ignore_case);
+                    pc += 1;
+                    (a != b) != negate
+                }
+                Insn::BackReference {
+                    index,
+                    ignore_case,
+                    backward,
+                } => {
+                    if let Some(next) = self.match_back_reference(index, ignore_case, backward, pos)
+                    {
+                        pos = next;
+                        pc += 1;
+                        true
+                    } else {
+                        false
+                    }
+                }
+                Insn::Save { slot } => {
+                    self.set(slot as usize, pos);
+                    pc += 1;
+                    true
+                }
+                Insn::ClearCaptures { start, end } => {
+                    for slot in (start as usize * 2)..(end as usize * 2) {
+                        if self.state[slot] != UNSET {
+                            self.set(slot, UNSET);
+                        }
+                    }
+                    pc += 1;
+                    true
+                }
+                Insn::Jump { target } => {
+                    pc = target as usize;
+                    true
+                }
+                Insn::Split { first, second } => {
+                    self.backtrack.push(Backtrack::Branch {
+                        pc: second as usize,
+                        pos,
+                        undo_len: self.undo.len(),
+                    });
+                    pc = first as usize;
+                    true
+                }
+                Insn::RepeatInit { counter } => {
+                    self.set(counter as usize, 0);
+                    pc += 1;
+                    true
+                }
+                Insn::Repeat {
+                    counter,
+                    min,
+                    max,
+                    greedy,
+                    exit,
+                } => {
+                    let count = self.state[counter as usize] as u64;
+                    if count < min {
+                        pc += 1;
+                    } else if count >= max {
+                        pc = exit as usize;
+                    } else if greedy {
+                        self.backtrack.push(Backtrack::Branch {
+                            pc: exit as usize,
+                            pos,
+                            undo_len: self.undo.len(),
+                        });
+                        pc += 1;
+                    } else {
+                        self.backtrack.push(Backtrack::Branch {
+                            pc: pc + 1,
+                            pos,
+                            undo_len: self.undo.len(),
+                        });
+                        pc = exit as usize;
+                    }
+                    true
+                }
+                Insn::SetPosition { register } => {
+                    self.set(register as usize, pos);
+                    pc += 1;
+                    true
+                }
+                Insn::RepeatStep {
+                    counter,
+                    position,
+                    min,
+                    repeat,
+                } => {
+                    let count = self.state[counter as usize];
+                    // Note: Once the minimum number of iterations has been
+                    // matched, an iteration matching the empty string fails.
+                    if count as u64 >= min && pos == self.state[position as usize] {
+                        false
+                    } else {
+                        self.set(counter as usize, count + 1);
+                        pc = repeat as usize;
+                        true
+                    }
+                }
+                Insn::LookAround { negative, end } => {
+                    let undo_len = self.undo.len();
+                    let matched = self.run(pc + 1, pos).is_some();
+                    if matched != negative {
+                        if negative {
+                            // Note: Negative lookarounds never leave any
+                            // captures behind.
+                            self.rewind(undo_len);
+                        }
+                        pc = end as usize;
+                        true
+                    } else {
+                        self.rewind(undo_len);
+                        false
+                    }
+                }
+                Insn::LookAroundMatch | Insn::Match => {
+                    // Note: Once a match is found, the remaining
+                    // alternatives are discarded.
+                    self.backtrack.truncate(base);
+                    return Some(pos);
+                }
+            };
+            if !success {
+                (pc, pos) = self.backtrack(base)?;
+            }
+        }
+    }
+}
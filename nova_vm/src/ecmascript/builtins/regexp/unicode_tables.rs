@@ -0,0 +1,48 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Code point tables of the Unicode properties supported in property
+//! escapes. The tables are generated by the build script from the Unicode
+//! Character Database files in `src/unicode_data`.
+
+include!(concat!(env!("OUT_DIR"), "/unicode_tables.rs"));
+
+/// ### [22.2.2.9 UnicodePropertyValueExpression](https://tc39.es/ecma262/#sec-compiletocharset)
+///
+/// Returns the code point ranges matched by the property escape
+/// `\p{name=value}`, or by `\p{name}` if `value` is None. Returns None if the
+/// name or value is not supported.
+///
+/// > #### Note
+/// > Property names and values are matched exactly, without loose matching.
+pub(super) fn property_ranges(name: &str, value: Option<&str>) -> Option<&'static [(u32, u32)]> {
+    match value {
+        // UnicodePropertyValueExpression :: UnicodePropertyName = UnicodePropertyValue
+        // 1. Let ps be the source text matched by UnicodePropertyName.
+        // 2. Let p be UnicodeMatchProperty(rer, ps).
+        // 3. Assert: p is a Unicode property name or property alias listed in
+        //    the “Property name and aliases” column of Table 67.
+        // 4. Let vs be the source text matched by UnicodePropertyValue.
+        // 5. Let v be UnicodeMatchPropertyValue(p, vs).
+        Some(value) => match name {
+            "General_Category" | "gc" => general_category(value),
+            "Script" | "sc" => script(value),
+            "Script_Extensions" | "scx" => script_extensions(value),
+            _ => None,
+        },
+        // UnicodePropertyValueExpression :: LoneUnicodePropertyNameOrValue
+        // 2. If UnicodeMatchPropertyValue(General_Category, s) is a Unicode
+        //    property value or property value alias for the General_Category
+        //    (gc) property listed in PropertyValueAliases.txt, then
+        //     a. Return the CharSet containing all Unicode code points whose
+        //        character database definition includes the property
+        //        “General_Category” with value s.
+        // 3. Let p be UnicodeMatchProperty(rer, s).
+        // 4. Assert: p is a binary Unicode property or binary property alias
+        //    listed in the “Property name and aliases” column of Table 68,
+        //    or a binary Unicode property of strings listed in the “Property
+        //    name” column of Table 69.
+        None => general_category(name).or_else(|| binary_property(name)),
+    }
+}
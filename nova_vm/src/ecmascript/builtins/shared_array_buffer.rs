@@ -2,16 +2,20 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+//! ## [25.2 SharedArrayBuffer Objects](https://tc39.es/ecma262/#sec-sharedarraybuffer-objects)
+
 use core::ops::{Index, IndexMut};
 
 use crate::{
     ecmascript::{
         execution::{Agent, ProtoIntrinsics},
         types::{
-            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject,
+            SharedDataBlock, Value,
         },
     },
     engine::{
+        Scoped,
         context::{Bindable, NoGcScope},
         rootable::HeapRootData,
     },
@@ -23,8 +27,11 @@ use crate::{
 
 use self::data::SharedArrayBufferHeapData;
 
+mod abstract_operations;
 pub mod data;
 
+pub(crate) use abstract_operations::{allocate_shared_array_buffer, host_grow_shared_array_buffer};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct SharedArrayBuffer<'a>(pub(crate) SharedArrayBufferIndex<'a>);
@@ -37,6 +44,54 @@ impl SharedArrayBuffer<'_> {
     pub(crate) const fn get_index(self) -> usize {
         self.0.into_index()
     }
+
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, SharedArrayBuffer<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    /// Create a new SharedArrayBuffer object in this agent that wraps an
+    /// existing Shared Data Block, which may be shared with other agents.
+    ///
+    /// The SharedArrayBuffer uses the %SharedArrayBuffer.prototype% of the
+    /// current Realm.
+    pub fn from_shared_data_block<'a>(
+        agent: &mut Agent,
+        data_block: SharedDataBlock,
+        gc: NoGcScope<'a, '_>,
+    ) -> SharedArrayBuffer<'a> {
+        agent
+            .heap
+            .create(SharedArrayBufferHeapData::new(data_block))
+            .bind(gc)
+    }
+
+    /// Get a new reference to the Shared Data Block of this
+    /// SharedArrayBuffer. The reference can be sent to other threads and
+    /// wrapped in a SharedArrayBuffer by other agents using
+    /// [`SharedArrayBuffer::from_shared_data_block`].
+    #[inline]
+    pub fn get_shared_data_block(self, agent: &Agent) -> SharedDataBlock {
+        agent[self].data_block.clone()
+    }
+
+    #[inline]
+    pub fn is_growable(self, agent: &Agent) -> bool {
+        agent[self].data_block.is_growable()
+    }
+
+    #[inline]
+    pub fn byte_length(self, agent: &Agent) -> usize {
+        agent[self].data_block.byte_length()
+    }
+
+    #[inline]
+    pub fn max_byte_length(self, agent: &Agent) -> usize {
+        agent[self].data_block.max_byte_length()
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -72,6 +127,28 @@ impl<'a> From<SharedArrayBuffer<'a>> for Value<'a> {
     }
 }
 
+impl<'a> TryFrom<Value<'a>> for SharedArrayBuffer<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Value::SharedArrayBuffer(sab) => Ok(sab),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for SharedArrayBuffer<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::SharedArrayBuffer(sab) => Ok(sab),
+            _ => Err(()),
+        }
+    }
+}
+
 impl<'a> From<SharedArrayBuffer<'a>> for Object<'a> {
     fn from(value: SharedArrayBuffer<'a>) -> Self {
         Object::SharedArrayBuffer(value)
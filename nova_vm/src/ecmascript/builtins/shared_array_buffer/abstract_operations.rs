@@ -0,0 +1,103 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::SharedArrayBuffer;
+use crate::{
+    ecmascript::{
+        builtins::ordinary::ordinary_create_from_constructor,
+        execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
+        types::{Function, SharedDataBlock},
+    },
+    engine::context::{Bindable, GcScope},
+};
+
+/// ### [25.2.2.1 AllocateSharedArrayBuffer ( constructor, byteLength \[ , maxByteLength \] )](https://tc39.es/ecma262/#sec-allocatesharedarraybuffer)
+///
+/// The abstract operation AllocateSharedArrayBuffer takes arguments
+/// *constructor* (a constructor) and *byteLength* (a non-negative integer)
+/// and optional argument *maxByteLength* (a non-negative integer or EMPTY)
+/// and returns either a normal completion containing a SharedArrayBuffer or
+/// a throw completion. It is used to create a SharedArrayBuffer.
+pub(crate) fn allocate_shared_array_buffer<'a>(
+    agent: &mut Agent,
+    constructor: Function,
+    byte_length: u64,
+    max_byte_length: Option<u64>,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, SharedArrayBuffer<'a>> {
+    let constructor = constructor.bind(gc.nogc());
+    // 1. Let slots be « [[ArrayBufferData]] ».
+    // 2. If maxByteLength is present and maxByteLength is not EMPTY, let
+    //    allocatingGrowableBuffer be true; otherwise let
+    //    allocatingGrowableBuffer be false.
+    // 3. If allocatingGrowableBuffer is true, then
+    if let Some(max_byte_length) = max_byte_length {
+        // a. If byteLength > maxByteLength, throw a RangeError exception.
+        if byte_length > max_byte_length {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Byte length is over maximum byte length",
+                gc.into_nogc(),
+            ));
+        }
+        // b. Append [[ArrayBufferByteLengthData]] and
+        //    [[ArrayBufferMaxByteLength]] to slots.
+    }
+    // 4. Else,
+    // a. Append [[ArrayBufferByteLength]] to slots.
+    // 5. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%SharedArrayBuffer.prototype%", slots).
+    let obj = ordinary_create_from_constructor(
+        agent,
+        constructor.unbind(),
+        ProtoIntrinsics::SharedArrayBuffer,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let obj = SharedArrayBuffer::try_from(obj.bind(gc)).unwrap();
+    // 6. If allocatingGrowableBuffer is true, let allocLength be
+    //    maxByteLength; otherwise let allocLength be byteLength.
+    let alloc_length = max_byte_length.unwrap_or(byte_length);
+    // 7. Let block be ? CreateSharedByteDataBlock(allocLength).
+    let block = SharedDataBlock::create_shared_byte_data_block(agent, alloc_length, gc)?;
+    // 9. If allocatingGrowableBuffer is true, then
+    let block = if max_byte_length.is_some() {
+        // a. Assert: byteLength ≤ maxByteLength.
+        // b. Let byteLengthBlock be ? CreateSharedByteDataBlock(8).
+        // c. Perform SetValueInBuffer(byteLengthBlock, 0, BIGUINT64, ℤ(byteLength), true, SEQ-CST).
+        // d. Set obj.[[ArrayBufferByteLengthData]] to byteLengthBlock.
+        // e. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
+        // NOTE: The byte length is stored in the Shared Data Block itself,
+        // and the allocation length is the maximum byte length.
+        block.into_growable(byte_length as usize)
+    } else {
+        // 10. Else,
+        // a. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        block
+    };
+    // 8. Set obj.[[ArrayBufferData]] to block.
+    agent[obj].data_block = block;
+    // 11. Return obj.
+    Ok(obj)
+}
+
+/// ### [25.2.2.3 HostGrowSharedArrayBuffer ( buffer, newByteLength )](https://tc39.es/ecma262/#sec-hostgrowsharedarraybuffer)
+///
+/// The host-defined abstract operation HostGrowSharedArrayBuffer takes
+/// arguments buffer (a SharedArrayBuffer) and newByteLength (a non-negative
+/// integer) and returns either a normal completion containing either HANDLED
+/// or UNHANDLED, or a throw completion. It gives the host an opportunity to
+/// perform implementation-defined growing of buffer. If the host chooses not
+/// to handle growing of buffer, it may return UNHANDLED for the default
+/// behaviour.
+///
+/// The default implementation of HostGrowSharedArrayBuffer is to return
+/// NormalCompletion(UNHANDLED).
+pub(crate) fn host_grow_shared_array_buffer(
+    _agent: &mut Agent,
+    _buffer: SharedArrayBuffer,
+    _new_byte_length: u64,
+) -> bool {
+    false
+}
@@ -3,7 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::{
-    ecmascript::types::OrdinaryObject,
+    ecmascript::types::{OrdinaryObject, SharedDataBlock},
     engine::context::{Bindable, NoGcScope},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
@@ -11,6 +11,21 @@ use crate::{
 #[derive(Debug, Clone, Default)]
 pub struct SharedArrayBufferHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// ### \[\[ArrayBufferData\]\]
+    ///
+    /// The Shared Data Block may be referenced by SharedArrayBuffers in other
+    /// agents as well. It also holds the \[\[ArrayBufferByteLengthData\]\]
+    /// and \[\[ArrayBufferMaxByteLength\]\] of growable SharedArrayBuffers.
+    pub(crate) data_block: SharedDataBlock,
+}
+
+impl SharedArrayBufferHeapData<'_> {
+    pub(crate) fn new(data_block: SharedDataBlock) -> Self {
+        Self {
+            object_index: None,
+            data_block,
+        }
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -30,10 +45,18 @@ unsafe impl Bindable for SharedArrayBufferHeapData<'_> {
 
 impl HeapMarkAndSweep for SharedArrayBufferHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
-        self.object_index.mark_values(queues);
+        let Self {
+            object_index,
+            data_block: _,
+        } = self;
+        object_index.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
-        self.object_index.sweep_values(compactions);
+        let Self {
+            object_index,
+            data_block: _,
+        } = self;
+        object_index.sweep_values(compactions);
     }
 }
@@ -15,7 +15,10 @@ use crate::{
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
         builtins::{
             ArgumentsList, ArrayBuffer, Behaviour, Builtin, BuiltinGetter,
-            array_buffer::{is_detached_buffer, is_fixed_length_array_buffer},
+            array_buffer::{
+                PreserveResizability, array_buffer_copy_and_detach, is_detached_buffer,
+                is_fixed_length_array_buffer,
+            },
         },
         execution::{Agent, JsResult, Realm, agent::ExceptionType},
         types::{
@@ -232,6 +235,9 @@ impl ArrayBufferPrototype {
         // this method as in-place growth or shrinkage.
         // 14. Set O.[[ArrayBufferData]] to newBlock.
         // 15. Set O.[[ArrayBufferByteLength]] to newByteLength.
+        // NOTE: Views of O keep their [[ByteLength]] and [[ByteOffset]]:
+        // length-tracking views compute their length from O on access, and
+        // fixed-length views come back in bounds if O grows again.
         o.resize(agent, new_byte_length);
 
         // 16. Return undefined.
@@ -366,7 +372,7 @@ impl ArrayBufferPrototype {
             // a. Let count be min(newLen, currentLen - first).
             let count = new_len.min(current_len - first);
             // b. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, count).
-            new.copy_array_buffer_data(agent, o, first, count);
+            new.copy_array_buffer_data(agent, 0, o, first, count);
         }
         // 28. Return new.
         Ok(new.into_value())
@@ -377,13 +383,24 @@ impl ArrayBufferPrototype {
     /// This method performs the following steps when called:
     fn transfer<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
+        let new_length = arguments.get(0).bind(gc.nogc());
         // 1. Let O be the this value.
+        let o = require_internal_slot_array_buffer(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
         // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, preserve-resizability).
-        Err(agent.todo("ArrayBuffer.prototype.transfer", gc.into_nogc()))
+        array_buffer_copy_and_detach(
+            agent,
+            o.unbind(),
+            new_length.unbind(),
+            PreserveResizability::PreserveResizability,
+            gc,
+        )
+        .map(|ab| ab.into_value())
     }
 
     /// ### [25.1.6.9 ArrayBuffer.prototype.transferToFixedLength ( [ newLength ] )](https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfertofixedlength)
@@ -391,16 +408,24 @@ impl ArrayBufferPrototype {
     /// This method performs the following steps when called:
     fn transfer_to_fixed_length<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
+        let new_length = arguments.get(0).bind(gc.nogc());
         // 1. Let O be the this value.
+        let o = require_internal_slot_array_buffer(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
         // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, fixed-length).
-        Err(agent.todo(
-            "ArrayBuffer.prototype.transferToFixedLength",
-            gc.into_nogc(),
-        ))
+        array_buffer_copy_and_detach(
+            agent,
+            o.unbind(),
+            new_length.unbind(),
+            PreserveResizability::FixedLength,
+            gc,
+        )
+        .map(|ab| ab.into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -2,14 +2,42 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::{sync::Arc, time::Duration};
+
 use crate::ecmascript::builtins::Behaviour;
-use crate::engine::context::GcScope;
+use crate::engine::Global;
+use crate::engine::context::{Bindable, GcScope, NoGcScope};
+use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
+        abstract_operations::type_conversion::{
+            to_big_int, to_big_int64, to_index, to_int32, to_integer_or_infinity, to_number,
+        },
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Builtin},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, String, Value},
+        builtins::{
+            ArgumentsList, Builtin,
+            array_buffer::{
+                AnyArrayBuffer, Ordering, get_modify_set_value_in_buffer, get_value_from_buffer,
+                set_value_in_buffer,
+            },
+            indexed_collections::typed_array_objects::abstract_operations::{
+                TypedArrayWithBufferWitnessRecords, is_typed_array_out_of_bounds,
+                make_typed_array_with_buffer_witness_record, typed_array_length,
+                validate_typed_array,
+            },
+            ordinary::ordinary_object_create_with_intrinsics,
+            promise::Promise,
+            promise_objects::promise_abstract_operations::promise_capability_records::PromiseCapability,
+            typed_array::TypedArray,
+        },
+        execution::{
+            Agent, AtomicsWaiter, JsResult, ProtoIntrinsics, Realm, WaitResult, WaiterList,
+            agent::{ExceptionType, InnerJob, Job},
+        },
+        types::{
+            BUILTIN_STRING_MEMORY, IntoNumeric, IntoValue, Number, Numeric, PropertyDescriptor,
+            ReadModifyWriteOp, String, Value, Viewable,
+        },
     },
     heap::WellKnownSymbolIndexes,
 };
@@ -123,121 +151,541 @@ impl Builtin for AtomicsObjectXor {
 }
 
 impl AtomicsObject {
+    /// ### [25.4.4 Atomics.add ( typedArray, index, value )](https://tc39.es/ecma262/#sec-atomics.add)
     fn add<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.add", gc.into_nogc()))
+        // 1. Let add be a new read-modify-write modification function with
+        //    parameters (xBytes, yBytes) that captures typedArray and performs
+        //    the following steps atomically when called:
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, add).
+        atomic_read_modify_write(agent, arguments, ReadModifyWriteOp::Add, gc)
     }
 
+    /// ### [25.4.5 Atomics.and ( typedArray, index, value )](https://tc39.es/ecma262/#sec-atomics.and)
     fn and<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.and", gc.into_nogc()))
+        // 1. Let and be a new read-modify-write modification function with
+        //    parameters (xBytes, yBytes) that captures nothing and performs
+        //    the following steps atomically when called:
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, and).
+        atomic_read_modify_write(agent, arguments, ReadModifyWriteOp::And, gc)
     }
 
+    /// ### [25.4.6 Atomics.compareExchange ( typedArray, index, expectedValue, replacementValue )](https://tc39.es/ecma262/#sec-atomics.compareexchange)
     fn compare_exchange<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.compareExchange", gc.into_nogc()))
+        let typed_array = arguments.get(0).scope(agent, gc.nogc());
+        let index = arguments.get(1).bind(gc.nogc());
+        let expected_value = arguments.get(2).scope(agent, gc.nogc());
+        let replacement_value = arguments.get(3).scope(agent, gc.nogc());
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let byte_index_in_buffer = validate_atomic_access_on_integer_typed_array(
+            agent,
+            typed_array.get(agent),
+            index.unbind(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let is_big_int = is_big_int_typed_array(typed_array.get(agent));
+        // 2. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 3. Let block be buffer.[[ArrayBufferData]].
+        // 4. If typedArray.[[ContentType]] is bigint, then
+        //   a. Let expected be ? ToBigInt(expectedValue).
+        //   b. Let replacement be ? ToBigInt(replacementValue).
+        // 5. Else,
+        //   a. Let expected be 𝔽(? ToIntegerOrInfinity(expectedValue)).
+        //   b. Let replacement be 𝔽(? ToIntegerOrInfinity(replacementValue)).
+        let expected =
+            to_atomic_operand(agent, is_big_int, expected_value.get(agent), gc.reborrow())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        let replacement = to_atomic_operand(
+            agent,
+            is_big_int,
+            replacement_value.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let expected = expected.get(agent).bind(gc.nogc());
+        let typed_array = TypedArray::try_from(typed_array.get(agent))
+            .unwrap()
+            .bind(gc.nogc());
+        // 6. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        revalidate_atomic_access(agent, typed_array, byte_index_in_buffer, gc.nogc()).unbind()?;
+        let buffer = typed_array.get_viewed_array_buffer(agent, gc.nogc());
+        let result = match typed_array {
+            TypedArray::Int8Array(_) => compare_exchange_in_buffer::<i8>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                expected,
+                replacement,
+                gc.nogc(),
+            ),
+            TypedArray::Uint8Array(_) => compare_exchange_in_buffer::<u8>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                expected,
+                replacement,
+                gc.nogc(),
+            ),
+            TypedArray::Int16Array(_) => compare_exchange_in_buffer::<i16>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                expected,
+                replacement,
+                gc.nogc(),
+            ),
+            TypedArray::Uint16Array(_) => compare_exchange_in_buffer::<u16>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                expected,
+                replacement,
+                gc.nogc(),
+            ),
+            TypedArray::Int32Array(_) => compare_exchange_in_buffer::<i32>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                expected,
+                replacement,
+                gc.nogc(),
+            ),
+            TypedArray::Uint32Array(_) => compare_exchange_in_buffer::<u32>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                expected,
+                replacement,
+                gc.nogc(),
+            ),
+            TypedArray::BigInt64Array(_) => compare_exchange_in_buffer::<i64>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                expected,
+                replacement,
+                gc.nogc(),
+            ),
+            TypedArray::BigUint64Array(_) => compare_exchange_in_buffer::<u64>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                expected,
+                replacement,
+                gc.nogc(),
+            ),
+            _ => unreachable!(),
+        };
+        Ok(result.into_value().unbind())
     }
 
+    /// ### [25.4.7 Atomics.exchange ( typedArray, index, value )](https://tc39.es/ecma262/#sec-atomics.exchange)
     fn exchange<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.exchange", gc.into_nogc()))
+        // 1. Let second be a new read-modify-write modification function with
+        //    parameters (oldBytes, newBytes) that captures nothing and
+        //    performs the following steps atomically when called:
+        //   a. Return newBytes.
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, second).
+        atomic_read_modify_write(agent, arguments, ReadModifyWriteOp::Exchange, gc)
     }
 
+    /// ### [25.4.8 Atomics.isLockFree ( size )](https://tc39.es/ecma262/#sec-atomics.islockfree)
     fn is_lock_free<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.isLockFree", gc.into_nogc()))
+        let size = arguments.get(0).bind(gc.nogc());
+        // 1. Let n be ? ToIntegerOrInfinity(size).
+        let n = to_integer_or_infinity(agent, size.unbind(), gc)?;
+        // 2. Let AR be the Agent Record of the surrounding agent.
+        let is_lock_free = if n == 1 {
+            // 3. If n = 1, return AR.[[IsLockFree1]].
+            cfg!(target_has_atomic = "8")
+        } else if n == 2 {
+            // 4. If n = 2, return AR.[[IsLockFree2]].
+            cfg!(target_has_atomic = "16")
+        } else if n == 4 {
+            // 5. If n = 4, return true.
+            true
+        } else if n == 8 {
+            // 6. If n = 8, return AR.[[IsLockFree8]].
+            cfg!(target_has_atomic = "64")
+        } else {
+            // 7. Return false.
+            false
+        };
+        Ok(is_lock_free.into())
     }
 
+    /// ### [25.4.9 Atomics.load ( typedArray, index )](https://tc39.es/ecma262/#sec-atomics.load)
     fn load<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.load", gc.into_nogc()))
+        let typed_array = arguments.get(0).scope(agent, gc.nogc());
+        let index = arguments.get(1).bind(gc.nogc());
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let byte_index_in_buffer = validate_atomic_access_on_integer_typed_array(
+            agent,
+            typed_array.get(agent),
+            index.unbind(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let typed_array = TypedArray::try_from(typed_array.get(agent))
+            .unwrap()
+            .bind(gc);
+        // 2. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        revalidate_atomic_access(agent, typed_array, byte_index_in_buffer, gc)?;
+        // 3. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        let buffer = typed_array.get_viewed_array_buffer(agent, gc);
+        // 4. Let elementType be TypedArrayElementType(typedArray).
+        // 5. Return GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, seq-cst).
+        let result = match typed_array {
+            TypedArray::Int8Array(_) => get_value_from_buffer::<i8>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                true,
+                Ordering::SeqCst,
+                None,
+                gc,
+            ),
+            TypedArray::Uint8Array(_) => get_value_from_buffer::<u8>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                true,
+                Ordering::SeqCst,
+                None,
+                gc,
+            ),
+            TypedArray::Int16Array(_) => get_value_from_buffer::<i16>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                true,
+                Ordering::SeqCst,
+                None,
+                gc,
+            ),
+            TypedArray::Uint16Array(_) => get_value_from_buffer::<u16>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                true,
+                Ordering::SeqCst,
+                None,
+                gc,
+            ),
+            TypedArray::Int32Array(_) => get_value_from_buffer::<i32>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                true,
+                Ordering::SeqCst,
+                None,
+                gc,
+            ),
+            TypedArray::Uint32Array(_) => get_value_from_buffer::<u32>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                true,
+                Ordering::SeqCst,
+                None,
+                gc,
+            ),
+            TypedArray::BigInt64Array(_) => get_value_from_buffer::<i64>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                true,
+                Ordering::SeqCst,
+                None,
+                gc,
+            ),
+            TypedArray::BigUint64Array(_) => get_value_from_buffer::<u64>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                true,
+                Ordering::SeqCst,
+                None,
+                gc,
+            ),
+            _ => unreachable!(),
+        };
+        Ok(result.into_value())
     }
 
+    /// ### [25.4.10 Atomics.or ( typedArray, index, value )](https://tc39.es/ecma262/#sec-atomics.or)
     fn or<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.or", gc.into_nogc()))
+        // 1. Let or be a new read-modify-write modification function with
+        //    parameters (xBytes, yBytes) that captures nothing and performs
+        //    the following steps atomically when called:
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, or).
+        atomic_read_modify_write(agent, arguments, ReadModifyWriteOp::Or, gc)
     }
 
+    /// ### [25.4.11 Atomics.store ( typedArray, index, value )](https://tc39.es/ecma262/#sec-atomics.store)
     fn store<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.store", gc.into_nogc()))
+        let typed_array = arguments.get(0).scope(agent, gc.nogc());
+        let index = arguments.get(1).bind(gc.nogc());
+        let value = arguments.get(2).scope(agent, gc.nogc());
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let byte_index_in_buffer = validate_atomic_access_on_integer_typed_array(
+            agent,
+            typed_array.get(agent),
+            index.unbind(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let is_big_int = is_big_int_typed_array(typed_array.get(agent));
+        // 2. If typedArray.[[ContentType]] is bigint, let v be ? ToBigInt(value).
+        // 3. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let v = to_atomic_operand(agent, is_big_int, value.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let v = v.bind(gc);
+        let typed_array = TypedArray::try_from(typed_array.get(agent))
+            .unwrap()
+            .bind(gc);
+        // 4. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        revalidate_atomic_access(agent, typed_array, byte_index_in_buffer, gc)?;
+        // 5. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        let buffer = typed_array.get_viewed_array_buffer(agent, gc);
+        // 6. Let elementType be TypedArrayElementType(typedArray).
+        // 7. Perform SetValueInBuffer(buffer, byteIndexInBuffer, elementType, v, true, seq-cst).
+        match typed_array {
+            TypedArray::Int8Array(_) => set_value_in_buffer::<i8>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                v,
+                true,
+                Ordering::SeqCst,
+                None,
+            ),
+            TypedArray::Uint8Array(_) => set_value_in_buffer::<u8>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                v,
+                true,
+                Ordering::SeqCst,
+                None,
+            ),
+            TypedArray::Int16Array(_) => set_value_in_buffer::<i16>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                v,
+                true,
+                Ordering::SeqCst,
+                None,
+            ),
+            TypedArray::Uint16Array(_) => set_value_in_buffer::<u16>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                v,
+                true,
+                Ordering::SeqCst,
+                None,
+            ),
+            TypedArray::Int32Array(_) => set_value_in_buffer::<i32>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                v,
+                true,
+                Ordering::SeqCst,
+                None,
+            ),
+            TypedArray::Uint32Array(_) => set_value_in_buffer::<u32>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                v,
+                true,
+                Ordering::SeqCst,
+                None,
+            ),
+            TypedArray::BigInt64Array(_) => set_value_in_buffer::<i64>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                v,
+                true,
+                Ordering::SeqCst,
+                None,
+            ),
+            TypedArray::BigUint64Array(_) => set_value_in_buffer::<u64>(
+                agent,
+                buffer,
+                byte_index_in_buffer,
+                v,
+                true,
+                Ordering::SeqCst,
+                None,
+            ),
+            _ => unreachable!(),
+        };
+        // 8. Return v.
+        Ok(v.into_value())
     }
 
+    /// ### [25.4.12 Atomics.sub ( typedArray, index, value )](https://tc39.es/ecma262/#sec-atomics.sub)
     fn sub<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.sub", gc.into_nogc()))
+        // 1. Let subtract be a new read-modify-write modification function
+        //    with parameters (xBytes, yBytes) that captures typedArray and
+        //    performs the following steps atomically when called:
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, subtract).
+        atomic_read_modify_write(agent, arguments, ReadModifyWriteOp::Sub, gc)
     }
 
+    /// ### [25.4.13 Atomics.wait ( typedArray, index, value, timeout )](https://tc39.es/ecma262/#sec-atomics.wait)
+    ///
+    /// This function puts the surrounding agent in a wait queue and suspends
+    /// it until notified or until the wait times out, returning a String
+    /// differentiating those cases.
     fn wait<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.wait", gc.into_nogc()))
+        // 1. Return ? DoWait(sync, typedArray, index, value, timeout).
+        do_wait(agent, WaitMode::Sync, arguments, gc)
     }
 
+    /// ### [25.4.14 Atomics.waitAsync ( typedArray, index, value, timeout )](https://tc39.es/ecma262/#sec-atomics.waitasync)
+    ///
+    /// This function returns a Promise that is resolved when the calling
+    /// agent is notified or the timeout is reached.
     fn wait_async<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.waitAsync", gc.into_nogc()))
+        // 1. Return ? DoWait(async, typedArray, index, value, timeout).
+        do_wait(agent, WaitMode::Async, arguments, gc)
     }
 
+    /// ### [25.4.15 Atomics.notify ( typedArray, index, count )](https://tc39.es/ecma262/#sec-atomics.notify)
+    ///
+    /// This function notifies some agents that are sleeping in the wait
+    /// queue.
     fn notify<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.notify", gc.into_nogc()))
+        let typed_array = arguments.get(0).bind(gc.nogc());
+        let index = arguments.get(1).bind(gc.nogc());
+        let count = arguments.get(2).scope(agent, gc.nogc());
+        // 1. Let taRecord be ? ValidateIntegerTypedArray(typedArray, true).
+        let ta_record = validate_integer_typed_array(agent, typed_array, true, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let buffer = ta_record
+            .object
+            .get_viewed_array_buffer(agent, gc.nogc())
+            .scope(agent, gc.nogc());
+        // 2. Let byteIndexInBuffer be ? ValidateAtomicAccess(taRecord, index).
+        let byte_index_in_buffer =
+            validate_atomic_access(agent, ta_record.unbind(), index.unbind(), gc.reborrow())
+                .unbind()?;
+        // 3. If count is undefined, then
+        let count = count.get(agent).bind(gc.nogc());
+        let c = if count.is_undefined() {
+            // a. Let c be +∞.
+            None
+        } else {
+            // 4. Else,
+            // a. Let intCount be ? ToIntegerOrInfinity(count).
+            let int_count =
+                to_integer_or_infinity(agent, count.unbind(), gc.reborrow()).unbind()?;
+            // b. Let c be max(intCount, 0).
+            if int_count.is_pos_infinity() {
+                None
+            } else {
+                Some(usize::try_from(int_count.into_i64().max(0)).unwrap_or(usize::MAX))
+            }
+        };
+        let gc = gc.into_nogc();
+        // 5. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        let buffer = buffer.get(agent).bind(gc);
+        // 6. Let block be buffer.[[ArrayBufferData]].
+        // 7. If IsSharedArrayBuffer(buffer) is false, return +0𝔽.
+        let Some(waiter_list) = get_waiter_list(agent, buffer) else {
+            return Ok(0.into());
+        };
+        // 8. Let WL be GetWaiterList(block, byteIndexInBuffer).
+        let n = waiter_list.notify(byte_index_in_buffer, c);
+        // 14. Return 𝔽(n).
+        Ok(Number::from_i64(agent, n as i64, gc).into_value())
     }
 
+    /// ### [25.4.16 Atomics.xor ( typedArray, index, value )](https://tc39.es/ecma262/#sec-atomics.xor)
     fn xor<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Atomics.xor", gc.into_nogc()))
+        // 1. Let xor be a new read-modify-write modification function with
+        //    parameters (xBytes, yBytes) that captures nothing and performs
+        //    the following steps atomically when called:
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, xor).
+        atomic_read_modify_write(agent, arguments, ReadModifyWriteOp::Xor, gc)
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -272,3 +720,539 @@ impl AtomicsObject {
             .build();
     }
 }
+
+/// Returns true if the value is a BigInt64Array or a BigUint64Array.
+fn is_big_int_typed_array(typed_array: Value) -> bool {
+    matches!(
+        typed_array,
+        Value::BigInt64Array(_) | Value::BigUint64Array(_)
+    )
+}
+
+/// Converts an operand of an Atomics operation to the numeric type of the
+/// TypedArray's elements.
+///
+/// If typedArray.\[\[ContentType]] is bigint, let v be ? ToBigInt(value).
+/// Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+fn to_atomic_operand<'gc>(
+    agent: &mut Agent,
+    is_big_int: bool,
+    value: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Numeric<'gc>> {
+    if is_big_int {
+        Ok(to_big_int(agent, value, gc)?.into_numeric())
+    } else {
+        let value = value.bind(gc.nogc());
+        let integer = to_integer_or_infinity(agent, value.unbind(), gc.reborrow()).unbind()?;
+        let number = if integer.is_pos_infinity() {
+            Number::pos_inf()
+        } else if integer.is_neg_infinity() {
+            Number::neg_inf()
+        } else {
+            Number::from_i64(agent, integer.into_i64(), gc.into_nogc())
+        };
+        Ok(number.into_numeric())
+    }
+}
+
+/// ### [25.4.3.1 ValidateIntegerTypedArray ( typedArray, waitable )](https://tc39.es/ecma262/#sec-validateintegertypedarray)
+///
+/// The abstract operation ValidateIntegerTypedArray takes arguments
+/// typedArray (an ECMAScript language value) and waitable (a Boolean) and
+/// returns either a normal completion containing a TypedArray With Buffer
+/// Witness Record, or a throw completion.
+pub(crate) fn validate_integer_typed_array<'a>(
+    agent: &mut Agent,
+    typed_array: Value,
+    waitable: bool,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, TypedArrayWithBufferWitnessRecords<'a>> {
+    // 1. Let taRecord be ? ValidateTypedArray(typedArray, unordered).
+    let ta_record = validate_typed_array(agent, typed_array, Ordering::Unordered, gc)?;
+    // 2. NOTE: Bounds checking is not a synchronizing operation when
+    //    typedArray's backing buffer is a growable SharedArrayBuffer.
+    if waitable {
+        // 3. If waitable is true, then
+        // a. If typedArray.[[TypedArrayName]] is neither "Int32Array" nor
+        //    "BigInt64Array", throw a TypeError exception.
+        if !matches!(
+            ta_record.object,
+            TypedArray::Int32Array(_) | TypedArray::BigInt64Array(_)
+        ) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected an Int32Array or BigInt64Array",
+                gc,
+            ));
+        }
+    } else {
+        // 4. Else,
+        // a. Let type be TypedArrayElementType(typedArray).
+        // b. If IsUnclampedIntegerElementType(type) is false and
+        //    IsBigIntElementType(type) is false, throw a TypeError exception.
+        if !matches!(
+            ta_record.object,
+            TypedArray::Int8Array(_)
+                | TypedArray::Uint8Array(_)
+                | TypedArray::Int16Array(_)
+                | TypedArray::Uint16Array(_)
+                | TypedArray::Int32Array(_)
+                | TypedArray::Uint32Array(_)
+                | TypedArray::BigInt64Array(_)
+                | TypedArray::BigUint64Array(_)
+        ) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected an integer TypedArray",
+                gc,
+            ));
+        }
+    }
+    // 5. Return taRecord.
+    Ok(ta_record)
+}
+
+/// ### [25.4.3.2 ValidateAtomicAccess ( taRecord, requestIndex )](https://tc39.es/ecma262/#sec-validateatomicaccess)
+///
+/// The abstract operation ValidateAtomicAccess takes arguments taRecord (a
+/// TypedArray With Buffer Witness Record) and requestIndex (an ECMAScript
+/// language value) and returns either a normal completion containing an
+/// integer or a throw completion.
+pub(crate) fn validate_atomic_access<'gc>(
+    agent: &mut Agent,
+    ta_record: TypedArrayWithBufferWitnessRecords,
+    request_index: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, usize> {
+    let ta_record = ta_record.bind(gc.nogc());
+    let request_index = request_index.bind(gc.nogc());
+    // 1. Let length be TypedArrayLength(taRecord).
+    let length = match ta_record.object {
+        TypedArray::Int8Array(_) | TypedArray::Uint8Array(_) => {
+            typed_array_length::<u8>(agent, &ta_record, gc.nogc())
+        }
+        TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => {
+            typed_array_length::<u16>(agent, &ta_record, gc.nogc())
+        }
+        TypedArray::Int32Array(_) | TypedArray::Uint32Array(_) => {
+            typed_array_length::<u32>(agent, &ta_record, gc.nogc())
+        }
+        TypedArray::BigInt64Array(_) | TypedArray::BigUint64Array(_) => {
+            typed_array_length::<u64>(agent, &ta_record, gc.nogc())
+        }
+        _ => unreachable!(),
+    };
+    let typed_array = ta_record.object.scope(agent, gc.nogc());
+    // 2. Let accessIndex be ? ToIndex(requestIndex).
+    let access_index = to_index(agent, request_index.unbind(), gc.reborrow()).unbind()? as u64;
+    // 3. Assert: accessIndex ≥ 0.
+    // 4. If accessIndex ≥ length, throw a RangeError exception.
+    if access_index >= length as u64 {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Index out of bounds",
+            gc.into_nogc(),
+        ));
+    }
+    // 5. Let typedArray be taRecord.[[Object]].
+    let typed_array = typed_array.get(agent).bind(gc.nogc());
+    // 6. Let elementSize be TypedArrayElementSize(typedArray).
+    let element_size = match typed_array {
+        TypedArray::Int8Array(_) | TypedArray::Uint8Array(_) => 1,
+        TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => 2,
+        TypedArray::Int32Array(_) | TypedArray::Uint32Array(_) => 4,
+        TypedArray::BigInt64Array(_) | TypedArray::BigUint64Array(_) => 8,
+        _ => unreachable!(),
+    };
+    // 7. Let offset be typedArray.[[ByteOffset]].
+    let offset = typed_array.byte_offset(agent);
+    // 8. Return (accessIndex × elementSize) + offset.
+    Ok(access_index as usize * element_size + offset)
+}
+
+/// ### [25.4.3.3 ValidateAtomicAccessOnIntegerTypedArray ( typedArray, requestIndex \[ , waitable \] )](https://tc39.es/ecma262/#sec-validateatomicaccessonintegertypedarray)
+///
+/// The abstract operation ValidateAtomicAccessOnIntegerTypedArray takes
+/// arguments typedArray (an ECMAScript language value) and requestIndex (an
+/// ECMAScript language value) and optional argument waitable (a Boolean) and
+/// returns either a normal completion containing an integer or a throw
+/// completion.
+pub(crate) fn validate_atomic_access_on_integer_typed_array<'gc>(
+    agent: &mut Agent,
+    typed_array: Value,
+    request_index: Value,
+    waitable: bool,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, usize> {
+    // 1. If waitable is not present, set waitable to false.
+    // 2. Let taRecord be ? ValidateIntegerTypedArray(typedArray, waitable).
+    let ta_record = validate_integer_typed_array(agent, typed_array, waitable, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 3. Return ? ValidateAtomicAccess(taRecord, requestIndex).
+    validate_atomic_access(agent, ta_record.unbind(), request_index, gc)
+}
+
+/// ### [25.4.3.4 RevalidateAtomicAccess ( typedArray, byteIndexInBuffer )](https://tc39.es/ecma262/#sec-revalidateatomicaccess)
+///
+/// The abstract operation RevalidateAtomicAccess takes arguments typedArray
+/// (a TypedArray) and byteIndexInBuffer (an integer) and returns either a
+/// normal completion containing unused or a throw completion. This operation
+/// revalidates the index within the backing buffer for atomic operations
+/// after all argument coercions are performed in Atomics methods, as argument
+/// coercions can have arbitrary side effects, which could cause the buffer to
+/// become out of bounds. This operation does not throw when typedArray's
+/// backing buffer is a SharedArrayBuffer.
+pub(crate) fn revalidate_atomic_access<'a>(
+    agent: &mut Agent,
+    typed_array: TypedArray,
+    byte_index_in_buffer: usize,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(typedArray, unordered).
+    let ta_record =
+        make_typed_array_with_buffer_witness_record(agent, typed_array, Ordering::Unordered, gc);
+    // 2. NOTE: Bounds checking is not a synchronizing operation when
+    //    typedArray's backing buffer is a growable SharedArrayBuffer.
+    // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    let is_out_of_bounds = match typed_array {
+        TypedArray::Int8Array(_) | TypedArray::Uint8Array(_) => {
+            is_typed_array_out_of_bounds::<u8>(agent, &ta_record, gc)
+        }
+        TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => {
+            is_typed_array_out_of_bounds::<u16>(agent, &ta_record, gc)
+        }
+        TypedArray::Int32Array(_) | TypedArray::Uint32Array(_) => {
+            is_typed_array_out_of_bounds::<u32>(agent, &ta_record, gc)
+        }
+        TypedArray::BigInt64Array(_) | TypedArray::BigUint64Array(_) => {
+            is_typed_array_out_of_bounds::<u64>(agent, &ta_record, gc)
+        }
+        _ => unreachable!(),
+    };
+    if is_out_of_bounds {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    }
+    // 4. Assert: byteIndexInBuffer ≥ typedArray.[[ByteOffset]].
+    debug_assert!(byte_index_in_buffer >= typed_array.byte_offset(agent));
+    // 5. If byteIndexInBuffer ≥ taRecord.[[CachedBufferByteLength]], throw a RangeError exception.
+    if byte_index_in_buffer >= ta_record.cached_buffer_byte_length.unwrap() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Index out of bounds",
+            gc,
+        ));
+    }
+    // 6. Return unused.
+    Ok(())
+}
+
+/// ### [25.4.3.17 AtomicReadModifyWrite ( typedArray, index, value, op )](https://tc39.es/ecma262/#sec-atomicreadmodifywrite)
+///
+/// The abstract operation AtomicReadModifyWrite takes arguments typedArray
+/// (an ECMAScript language value), index (an ECMAScript language value),
+/// value (an ECMAScript language value), and op (a read-modify-write
+/// modification function) and returns either a normal completion containing
+/// either a Number or a BigInt, or a throw completion. op takes two List of
+/// byte values arguments and returns a List of byte values. This operation
+/// atomically loads a value, combines it with another value, and stores the
+/// combination. It returns the loaded value.
+fn atomic_read_modify_write<'gc>(
+    agent: &mut Agent,
+    arguments: ArgumentsList,
+    op: ReadModifyWriteOp,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let typed_array = arguments.get(0).scope(agent, gc.nogc());
+    let index = arguments.get(1).bind(gc.nogc());
+    let value = arguments.get(2).scope(agent, gc.nogc());
+    // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+    let byte_index_in_buffer = validate_atomic_access_on_integer_typed_array(
+        agent,
+        typed_array.get(agent),
+        index.unbind(),
+        false,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let is_big_int = is_big_int_typed_array(typed_array.get(agent));
+    // 2. If typedArray.[[ContentType]] is bigint, let v be ? ToBigInt(value).
+    // 3. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+    let v = to_atomic_operand(agent, is_big_int, value.get(agent), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    let v = v.bind(gc);
+    let typed_array = TypedArray::try_from(typed_array.get(agent))
+        .unwrap()
+        .bind(gc);
+    // 4. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+    revalidate_atomic_access(agent, typed_array, byte_index_in_buffer, gc)?;
+    // 5. Let buffer be typedArray.[[ViewedArrayBuffer]].
+    let buffer = typed_array.get_viewed_array_buffer(agent, gc);
+    // 6. Let elementType be TypedArrayElementType(typedArray).
+    // 7. Return GetModifySetValueInBuffer(buffer, byteIndexInBuffer, elementType, v, op).
+    let result = match typed_array {
+        TypedArray::Int8Array(_) => {
+            get_modify_set_value_in_buffer::<i8>(agent, buffer, byte_index_in_buffer, v, op, gc)
+        }
+        TypedArray::Uint8Array(_) => {
+            get_modify_set_value_in_buffer::<u8>(agent, buffer, byte_index_in_buffer, v, op, gc)
+        }
+        TypedArray::Int16Array(_) => {
+            get_modify_set_value_in_buffer::<i16>(agent, buffer, byte_index_in_buffer, v, op, gc)
+        }
+        TypedArray::Uint16Array(_) => {
+            get_modify_set_value_in_buffer::<u16>(agent, buffer, byte_index_in_buffer, v, op, gc)
+        }
+        TypedArray::Int32Array(_) => {
+            get_modify_set_value_in_buffer::<i32>(agent, buffer, byte_index_in_buffer, v, op, gc)
+        }
+        TypedArray::Uint32Array(_) => {
+            get_modify_set_value_in_buffer::<u32>(agent, buffer, byte_index_in_buffer, v, op, gc)
+        }
+        TypedArray::BigInt64Array(_) => {
+            get_modify_set_value_in_buffer::<i64>(agent, buffer, byte_index_in_buffer, v, op, gc)
+        }
+        TypedArray::BigUint64Array(_) => {
+            get_modify_set_value_in_buffer::<u64>(agent, buffer, byte_index_in_buffer, v, op, gc)
+        }
+        _ => unreachable!(),
+    };
+    Ok(result.into_value())
+}
+
+/// The buffer access steps of Atomics.compareExchange: atomically replaces
+/// the value at byteIndexInBuffer with replacement if its raw bytes are equal
+/// to those of expected, and returns the value that was read.
+fn compare_exchange_in_buffer<'a, T: Viewable>(
+    agent: &mut Agent,
+    buffer: AnyArrayBuffer,
+    byte_index_in_buffer: usize,
+    expected: Numeric,
+    replacement: Numeric,
+    gc: NoGcScope<'a, '_>,
+) -> Numeric<'a> {
+    // 7. Let elementType be TypedArrayElementType(typedArray).
+    // 8. Let elementSize be TypedArrayElementSize(typedArray).
+    // 9. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+    // 10. Let expectedBytes be NumericToRawBytes(elementType, expected, isLittleEndian).
+    let expected_bytes = T::from_ne_value(agent, expected);
+    // 11. Let replacementBytes be NumericToRawBytes(elementType, replacement, isLittleEndian).
+    let replacement_bytes = T::from_ne_value(agent, replacement);
+    let raw_bytes_read = match buffer {
+        // 12. If IsSharedArrayBuffer(buffer) is true, then
+        //   a. Let rawBytesRead be AtomicCompareExchangeInSharedBlock(block, byteIndexInBuffer, elementSize, expectedBytes, replacementBytes).
+        AnyArrayBuffer::SharedArrayBuffer(buffer) => agent[buffer]
+            .data_block
+            .compare_exchange::<T>(byte_index_in_buffer, expected_bytes, replacement_bytes),
+        // 13. Else,
+        //   a. Let rawBytesRead be a List of length elementSize whose elements are the sequence of elementSize bytes starting with block[byteIndexInBuffer].
+        //   b. If ByteListEqual(rawBytesRead, expectedBytes) is true, then
+        //     i. Store the individual bytes of replacementBytes into block, starting at block[byteIndexInBuffer].
+        AnyArrayBuffer::ArrayBuffer(buffer) => agent[buffer]
+            .get_data_block_mut()
+            .compare_exchange_atomic::<T>(byte_index_in_buffer, expected_bytes, replacement_bytes),
+    }
+    .unwrap();
+    // 14. Return RawBytesToNumeric(elementType, rawBytesRead, isLittleEndian).
+    raw_bytes_read.into_ne_value(agent, gc)
+}
+
+/// ### [25.4.3.5 GetWaiterList ( block, i )](https://tc39.es/ecma262/#sec-getwaiterlist)
+///
+/// Returns the WaiterList of the buffer's Shared Data Block, or None if the
+/// buffer is not shared.
+fn get_waiter_list(agent: &Agent, buffer: AnyArrayBuffer) -> Option<Arc<WaiterList>> {
+    match buffer {
+        AnyArrayBuffer::ArrayBuffer(_) => None,
+        AnyArrayBuffer::SharedArrayBuffer(buffer) => {
+            Some(agent[buffer].data_block.waiter_list().clone())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WaitMode {
+    Sync,
+    Async,
+}
+
+/// ### [25.4.3.14 DoWait ( mode, typedArray, index, value, timeout )](https://tc39.es/ecma262/#sec-dowait)
+///
+/// The abstract operation DoWait takes arguments mode (sync or async),
+/// typedArray (an ECMAScript language value), index (an ECMAScript language
+/// value), value (an ECMAScript language value), and timeout (an ECMAScript
+/// language value) and returns either a normal completion containing either
+/// an Object, "not-equal", "timed-out", or "ok", or a throw completion.
+fn do_wait<'gc>(
+    agent: &mut Agent,
+    mode: WaitMode,
+    arguments: ArgumentsList,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let typed_array = arguments.get(0).bind(gc.nogc());
+    let index = arguments.get(1).scope(agent, gc.nogc());
+    let value = arguments.get(2).scope(agent, gc.nogc());
+    let timeout = arguments.get(3).scope(agent, gc.nogc());
+    // 1. Let taRecord be ? ValidateIntegerTypedArray(typedArray, true).
+    let ta_record = validate_integer_typed_array(agent, typed_array, true, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. Let buffer be taRecord.[[Object]].[[ViewedArrayBuffer]].
+    let buffer = ta_record.object.get_viewed_array_buffer(agent, gc.nogc());
+    // 3. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
+    let Some(waiter_list) = get_waiter_list(agent, buffer) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Expected a TypedArray backed by a SharedArrayBuffer",
+            gc.into_nogc(),
+        ));
+    };
+    let is_big_int = matches!(ta_record.object, TypedArray::BigInt64Array(_));
+    let buffer = buffer.scope(agent, gc.nogc());
+    // 4. Let i be ? ValidateAtomicAccess(taRecord, index).
+    let i = validate_atomic_access(agent, ta_record.unbind(), index.get(agent), gc.reborrow())
+        .unbind()?;
+    // 5. Let arrayTypeName be typedArray.[[TypedArrayName]].
+    let v = if is_big_int {
+        // 6. If arrayTypeName is "BigInt64Array", let v be ? ToBigInt64(value).
+        to_big_int64(agent, value.get(agent), gc.reborrow()).unbind()?
+    } else {
+        // 7. Else, let v be ? ToInt32(value).
+        i64::from(to_int32(agent, value.get(agent), gc.reborrow()).unbind()?)
+    };
+    // 8. Let q be ? ToNumber(timeout).
+    let q = to_number(agent, timeout.get(agent), gc.reborrow())
+        .unbind()?
+        .into_f64(agent);
+    // 9. If q is either NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be
+    //    0; else let t be max(ℝ(q), 0).
+    // Note: Timeouts too large to be represented are treated as +∞.
+    let t = if q.is_nan() {
+        None
+    } else {
+        Duration::try_from_secs_f64(q.max(0.0) / 1000.0).ok()
+    };
+    // 10. If mode is sync and AgentCanSuspend() is false, throw a TypeError
+    //     exception.
+    if mode == WaitMode::Sync && !agent.options.can_block {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Atomics.wait cannot be called in this agent",
+            gc.into_nogc(),
+        ));
+    }
+    let gc = gc.into_nogc();
+    // 11. Let block be buffer.[[ArrayBufferData]].
+    let AnyArrayBuffer::SharedArrayBuffer(buffer) = buffer.get(agent) else {
+        unreachable!()
+    };
+    let block = &agent[buffer].data_block;
+    // 12. Let WL be GetWaiterList(block, i).
+    // 18. Let elementType be TypedArrayElementType(typedArray).
+    // 19. Let w be GetValueFromBuffer(buffer, i, elementType, true, SEQ-CST).
+    // 20. If v ≠ w, then
+    let is_equal = || {
+        if is_big_int {
+            block.load::<i64>(i) == Some(v)
+        } else {
+            block.load::<i32>(i).map(i64::from) == Some(v)
+        }
+    };
+    let result = match mode {
+        WaitMode::Sync => {
+            // 13. If mode is sync, then
+            // a. Let promiseCapability be blocking.
+            // b. Let resultObject be undefined.
+            // 32. If mode is sync, return waiterRecord.[[Result]].
+            return Ok(waiter_list.wait(i, is_equal, t).into_value());
+        }
+        WaitMode::Async => waiter_list.wait_async(i, is_equal, t),
+    };
+    // 14. Else,
+    // a. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+    // b. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+    let result_object =
+        ordinary_object_create_with_intrinsics(agent, Some(ProtoIntrinsics::Object), None, gc);
+    let (is_async, value) = match result {
+        // 20. If v ≠ w, then
+        // 21. If t = 0 and mode is async, then
+        // c. Perform ! CreateDataPropertyOrThrow(resultObject, "async", false).
+        // d. Perform ! CreateDataPropertyOrThrow(resultObject, "value", result).
+        // e. Return resultObject.
+        Err(result) => (false, result.into_value()),
+        Ok(waiter) => {
+            let promise_capability = PromiseCapability::new(agent, gc);
+            let promise = promise_capability.promise();
+            // 30. Else if timeoutTime is finite, then
+            // a. Perform EnqueueAtomicsWaitAsyncTimeoutJob(WL, waiterRecord).
+            let job = Job {
+                realm: Some(agent.current_realm(gc).unbind()),
+                inner: InnerJob::AtomicsWaitAsync(AtomicsWaitAsyncJob {
+                    waiter: waiter.clone(),
+                    promise: Global::new(agent, promise.unbind()),
+                }),
+            };
+            agent.host_hooks.enqueue_atomics_wait_async_job(waiter, job);
+            (true, promise.into_value())
+        }
+    };
+    // 34. Perform ! CreateDataPropertyOrThrow(resultObject, "async", true).
+    result_object.property_storage().set(
+        agent,
+        BUILTIN_STRING_MEMORY.r#async.to_property_key(),
+        PropertyDescriptor::new_data_descriptor(is_async.into()),
+    );
+    // 35. Perform ! CreateDataPropertyOrThrow(resultObject, "value", promiseCapability.[[Promise]]).
+    result_object.property_storage().set(
+        agent,
+        BUILTIN_STRING_MEMORY.value.to_property_key(),
+        PropertyDescriptor::new_data_descriptor(value),
+    );
+    // 36. Return resultObject.
+    Ok(result_object.into_value())
+}
+
+/// The Job that settles the promise returned by `Atomics.waitAsync` once its
+/// waiter has been notified or has timed out.
+///
+/// This combines the Jobs of
+/// [25.4.3.13 EnqueueResolveInAgentJob](https://tc39.es/ecma262/#sec-enqueueresolveinagentjob)
+/// and
+/// [25.4.3.15 EnqueueAtomicsWaitAsyncTimeoutJob](https://tc39.es/ecma262/#sec-enqueueatomicswaitasynctimeoutjob).
+pub(crate) struct AtomicsWaitAsyncJob {
+    waiter: AtomicsWaiter,
+    promise: Global<Promise<'static>>,
+}
+
+impl AtomicsWaitAsyncJob {
+    pub(crate) fn run<'a>(self, agent: &mut Agent, gc: GcScope<'a, '_>) -> JsResult<'a, ()> {
+        let promise = self.promise.take(agent);
+        // a. Perform EnterCriticalSection(WL).
+        // b. If WL.[[Waiters]] contains waiterRecord, then
+        //   i. Let timeOfJobExecution be the time value (UTC) identifying the
+        //      current time.
+        //   ii. Assert: ℝ(timeOfJobExecution) ≥ waiterRecord.[[TimeoutTime]].
+        //   iii. Set waiterRecord.[[Result]] to "timed-out".
+        //   iv. Perform RemoveWaiter(WL, waiterRecord).
+        // c. Perform LeaveCriticalSection(WL).
+        let result = self.waiter.finish();
+        // Perform ! Call(promiseCapability.[[Resolve]], undefined, « resolution »).
+        PromiseCapability::from_promise(promise, true).resolve(agent, result.into_value(), gc);
+        Ok(())
+    }
+}
+
+impl WaitResult {
+    fn into_value(self) -> Value<'static> {
+        match self {
+            WaitResult::Ok => BUILTIN_STRING_MEMORY.ok.into_value(),
+            WaitResult::NotEqual => BUILTIN_STRING_MEMORY.not_equal.into_value(),
+            WaitResult::TimedOut => BUILTIN_STRING_MEMORY.timed_out.into_value(),
+        }
+    }
+}
@@ -1,14 +1,19 @@
-// This Source Code Form is subject to the terms of the Mozilla Public
-// License, v. 2.0. If a copy of the MPL was not distributed with this
-// file, You can obtain one at https://mozilla.org/MPL/2.0/.
-
 use crate::engine::context::{Bindable, GcScope};
+use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
+        abstract_operations::type_conversion::to_index,
         builders::builtin_function_builder::BuiltinFunctionBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsicConstructor},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, IntoObject, Object, PropertyKey, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsicConstructor,
+            array_buffer::get_array_buffer_max_byte_length_option,
+            shared_array_buffer::allocate_shared_array_buffer,
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{
+            BUILTIN_STRING_MEMORY, Function, IntoObject, IntoValue, Object, PropertyKey, String,
+            Value,
+        },
     },
     heap::{IntrinsicConstructorIndexes, WellKnownSymbolIndexes},
 };
@@ -39,14 +44,42 @@ impl Builtin for SharedArrayBufferGetSpecies {
 impl BuiltinGetter for SharedArrayBufferGetSpecies {}
 
 impl SharedArrayBufferConstructor {
+    /// ### [25.2.3.1 SharedArrayBuffer ( length \[ , options \] )](https://tc39.es/ecma262/#sec-sharedarraybuffer-length)
     fn constructor<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        _new_target: Option<Object>,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("SharedArrayBuffer", gc.into_nogc()))
+        let length = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).scope(agent, gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Constructor SharedArrayBuffer requires 'new'",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = new_target.scope(agent, gc.nogc());
+        // 2. Let byteLength be ? ToIndex(length).
+        let byte_length = to_index(agent, length.unbind(), gc.reborrow()).unbind()? as u64;
+        // 3. Let requestedMaxByteLength be ? GetArrayBufferMaxByteLengthOption(options).
+        let requested_max_byte_length =
+            get_array_buffer_max_byte_length_option(agent, options.get(agent), gc.reborrow())
+                .unbind()?
+                .map(|max_byte_length| max_byte_length as u64);
+        // 4. Return ? AllocateSharedArrayBuffer(NewTarget, byteLength, requestedMaxByteLength).
+        let new_target = Function::try_from(new_target.get(agent)).unwrap();
+        allocate_shared_array_buffer(
+            agent,
+            new_target,
+            byte_length,
+            requested_max_byte_length,
+            gc,
+        )
+        .map(|sab| sab.into_value())
     }
 
     /// ### [25.2.4.2 get SharedArrayBuffer \[ %Symbol.species% \]](https://tc39.es/ecma262/#sec-sharedarraybuffer-%symbol.species%)
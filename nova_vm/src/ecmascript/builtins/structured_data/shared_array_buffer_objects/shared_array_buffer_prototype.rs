@@ -1,15 +1,19 @@
-// This Source Code Form is subject to the terms of the Mozilla Public
-// License, v. 2.0. If a copy of the MPL was not distributed with this
-// file, You can obtain one at https://mozilla.org/MPL/2.0/.
-
 use crate::ecmascript::types::IntoValue;
-use crate::engine::context::GcScope;
+use crate::engine::context::{Bindable, GcScope, NoGcScope};
+use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
+        abstract_operations::{
+            operations_on_objects::{construct, species_constructor},
+            type_conversion::{to_index, to_integer_or_infinity},
+        },
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin, BuiltinGetter},
-        execution::{Agent, JsResult, Realm},
-        types::{BUILTIN_STRING_MEMORY, PropertyKey, String, Value},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter,
+            shared_array_buffer::{SharedArrayBuffer, host_grow_shared_array_buffer},
+        },
+        execution::{Agent, JsResult, Realm, agent::ExceptionType},
+        types::{BUILTIN_STRING_MEMORY, IntoFunction, IntoObject, PropertyKey, String, Value},
     },
     heap::WellKnownSymbolIndexes,
 };
@@ -58,49 +62,262 @@ impl Builtin for SharedArrayBufferPrototypeSlice {
 }
 
 impl SharedArrayBufferPrototype {
+    /// ### [25.2.5.1 get SharedArrayBuffer.prototype.byteLength](https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.bytelength)
+    ///
+    /// SharedArrayBuffer.prototype.byteLength is an accessor property whose
+    /// set accessor function is undefined.
     fn get_byte_length<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("SharedArrayBuffer.prototype.byteLength", gc.into_nogc()))
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let o = require_internal_slot_shared_array_buffer(agent, this_value, gc.into_nogc())?;
+        // 4. Let length be ArrayBufferByteLength(O, SEQ-CST).
+        // 5. Return 𝔽(length).
+        Ok((o.byte_length(agent) as i64).try_into().unwrap())
     }
 
+    /// ### [25.2.5.3 SharedArrayBuffer.prototype.grow ( newLength )](https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.grow)
     fn grow<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("SharedArrayBuffer.prototype.grow", gc.into_nogc()))
+        let new_length = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferMaxByteLength]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let o = require_internal_slot_shared_array_buffer(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        if !o.is_growable(agent) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Attempted to grow fixed length SharedArrayBuffer",
+                gc.into_nogc(),
+            ));
+        }
+        let scoped_o = o.scope(agent, gc.nogc());
+        // 4. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = to_index(agent, new_length.unbind(), gc.reborrow()).unbind()? as u64;
+        let gc = gc.into_nogc();
+        let o = scoped_o.get(agent).bind(gc);
+        // 5. Let hostHandled be ? HostGrowSharedArrayBuffer(O, newByteLength).
+        // 6. If hostHandled is HANDLED, return undefined.
+        if host_grow_shared_array_buffer(agent, o, new_byte_length) {
+            return Ok(Value::Undefined);
+        }
+        // 7. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 8. Let byteLengthBlock be O.[[ArrayBufferByteLengthData]].
+        let data_block = o.get_shared_data_block(agent);
+        // 9. Let currentByteLengthRawBytes be GetRawBytesFromSharedBlock(byteLengthBlock, 0, BIGUINT64, true, SEQ-CST).
+        let mut current_byte_length = data_block.byte_length();
+        // 10. Let newByteLengthRawBytes be NumericToRawBytes(BIGUINT64, ℤ(newByteLength), isLittleEndian).
+        // 11. Repeat,
+        loop {
+            // a. NOTE: This is a compare-and-exchange loop to ensure that
+            //    parallel, racing grows of the same buffer are totally
+            //    ordered, are not lost, and do not silently do nothing. The
+            //    loop exits if it was able to attempt to grow uncontended.
+            // b. Let currentByteLength be ℝ(RawBytesToNumeric(BIGUINT64, currentByteLengthRawBytes, isLittleEndian)).
+            // c. If newByteLength = currentByteLength, return undefined.
+            if new_byte_length == current_byte_length as u64 {
+                return Ok(Value::Undefined);
+            }
+            // d. If newByteLength < currentByteLength or newByteLength >
+            //    O.[[ArrayBufferMaxByteLength]], throw a RangeError exception.
+            if new_byte_length < current_byte_length as u64
+                || new_byte_length > data_block.max_byte_length() as u64
+            {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::RangeError,
+                    "SharedArrayBuffer cannot shrink or grow over its maximum byte length",
+                    gc,
+                ));
+            }
+            // e. Let byteLengthDelta be newByteLength - currentByteLength.
+            // f. If it is impossible to create a new Shared Data Block value
+            //    consisting of byteLengthDelta bytes, throw a RangeError
+            //    exception.
+            // g. NOTE: No new Shared Data Block is constructed and used
+            //    here. The observable behaviour of growable
+            //    SharedArrayBuffers is specified by allocating a max-sized
+            //    Shared Data Block at construction time, and this step
+            //    captures the requirement that implementations that run out
+            //    of memory must throw a RangeError.
+            // h. Let readByteLengthRawBytes be AtomicCompareExchangeInSharedBlock(byteLengthBlock, 0, 8, currentByteLengthRawBytes, newByteLengthRawBytes).
+            let read_byte_length = data_block
+                .compare_exchange_byte_length(current_byte_length, new_byte_length as usize);
+            // i. If ByteListEqual(readByteLengthRawBytes, currentByteLengthRawBytes) is true, return undefined.
+            if read_byte_length == current_byte_length {
+                return Ok(Value::Undefined);
+            }
+            // j. Set currentByteLengthRawBytes to readByteLengthRawBytes.
+            current_byte_length = read_byte_length;
+        }
     }
 
+    /// ### [25.2.5.4 get SharedArrayBuffer.prototype.growable](https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.growable)
+    ///
+    /// SharedArrayBuffer.prototype.growable is an accessor property whose set
+    /// accessor function is undefined.
     fn get_growable<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("SharedArrayBuffer.prototype.growable", gc.into_nogc()))
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let o = require_internal_slot_shared_array_buffer(agent, this_value, gc.into_nogc())?;
+        // 4. If IsFixedLengthArrayBuffer(O) is false, return true; otherwise return false.
+        Ok(o.is_growable(agent).into())
     }
 
+    /// ### [25.2.5.5 get SharedArrayBuffer.prototype.maxByteLength](https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.maxbytelength)
+    ///
+    /// SharedArrayBuffer.prototype.maxByteLength is an accessor property whose
+    /// set accessor function is undefined.
     fn get_max_byte_length<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("SharedArrayBuffer.prototype.maxByteLength", gc.into_nogc()))
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let o = require_internal_slot_shared_array_buffer(agent, this_value, gc.into_nogc())?;
+        // 4. If IsFixedLengthArrayBuffer(O) is true, then
+        //     a. Let length be O.[[ArrayBufferByteLength]].
+        // 5. Else,
+        //     a. Let length be O.[[ArrayBufferMaxByteLength]].
+        // 6. Return 𝔽(length).
+        Ok((o.max_byte_length(agent) as i64).try_into().unwrap())
     }
 
+    /// ### [25.2.5.6 SharedArrayBuffer.prototype.slice ( start, end )](https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.slice)
     fn slice<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("SharedArrayBuffer.prototype.slice", gc.into_nogc()))
+        let start = arguments.get(0).bind(gc.nogc());
+        let end = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let o = require_internal_slot_shared_array_buffer(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let len be ArrayBufferByteLength(O, SEQ-CST).
+        let len = o.byte_length(agent);
+
+        let scoped_o = o.scope(agent, gc.nogc());
+        // 5. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let relative_start =
+            to_integer_or_infinity(agent, start.unbind(), gc.reborrow()).unbind()?;
+        // 6. If relativeStart = -∞, let first be 0.
+        let first = if relative_start.is_neg_infinity() {
+            0
+        } else if relative_start.is_negative() {
+            // 7. Else if relativeStart < 0, let first be max(len + relativeStart, 0).
+            (len as i64 + relative_start.into_i64()).max(0) as usize
+        } else {
+            // 8. Else, let first be min(relativeStart, len).
+            (relative_start.into_i64() as usize).min(len)
+        };
+
+        // 9. If end is undefined, let relativeEnd be len;
+        let end = end.get(agent).bind(gc.nogc());
+        let final_end = if end.is_undefined() {
+            len
+        } else {
+            // else let relativeEnd be ? ToIntegerOrInfinity(end).
+            let relative_end =
+                to_integer_or_infinity(agent, end.unbind(), gc.reborrow()).unbind()?;
+            // 10. If relativeEnd = -∞, let final be 0.
+            if relative_end.is_neg_infinity() {
+                0
+            } else if relative_end.is_negative() {
+                // 11. Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
+                (len as i64 + relative_end.into_i64()).max(0) as usize
+            } else {
+                // 12. Else, let final be min(relativeEnd, len).
+                (relative_end.into_i64() as usize).min(len)
+            }
+        };
+
+        // 13. Let newLen be max(final - first, 0).
+        let new_len = final_end.saturating_sub(first);
+        // 14. Let ctor be ? SpeciesConstructor(O, %SharedArrayBuffer%).
+        let default_constructor = agent
+            .current_realm_record()
+            .intrinsics()
+            .shared_array_buffer()
+            .into_function();
+        let ctor = species_constructor(
+            agent,
+            scoped_o.get(agent).into_object(),
+            default_constructor,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // 15. Let new be ? Construct(ctor, « 𝔽(newLen) »).
+        let new = construct(
+            agent,
+            ctor.unbind(),
+            Some(ArgumentsList::from_mut_slice(&mut [(new_len as i64)
+                .try_into()
+                .unwrap()])),
+            None,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let new = new.bind(gc);
+        // 16. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
+        // 17. If IsSharedArrayBuffer(new) is false, throw a TypeError exception.
+        let Ok(new) = SharedArrayBuffer::try_from(new) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Construction did not return a SharedArrayBuffer",
+                gc,
+            ));
+        };
+        // 18. If new.[[ArrayBufferData]] is O.[[ArrayBufferData]], throw a TypeError exception.
+        let o = scoped_o.get(agent).bind(gc);
+        let from_buf = o.get_shared_data_block(agent);
+        let to_buf = new.get_shared_data_block(agent);
+        if to_buf.ptr_eq(&from_buf) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Construction returned the original SharedArrayBuffer",
+                gc,
+            ));
+        }
+        // 19. If ArrayBufferByteLength(new, SEQ-CST) < newLen, throw a TypeError exception.
+        if to_buf.byte_length() < new_len {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Construction returned a smaller SharedArrayBuffer than requested",
+                gc,
+            ));
+        }
+        // 20. Let fromBuf be O.[[ArrayBufferData]].
+        // 21. Let toBuf be new.[[ArrayBufferData]].
+        // 22. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
+        to_buf.copy_shared_data_block_bytes(0, &from_buf, first, new_len);
+        // 23. Return new.
+        Ok(new.into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -129,3 +346,21 @@ impl SharedArrayBufferPrototype {
             .build();
     }
 }
+
+#[inline]
+pub(crate) fn require_internal_slot_shared_array_buffer<'a>(
+    agent: &mut Agent,
+    o: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, SharedArrayBuffer<'a>> {
+    match o {
+        // 1. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 2. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        Value::SharedArrayBuffer(shared_array_buffer) => Ok(shared_array_buffer.unbind()),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Expected this to be SharedArrayBuffer",
+            gc,
+        )),
+    }
+}
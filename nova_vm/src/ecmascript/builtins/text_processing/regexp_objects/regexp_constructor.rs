@@ -2,23 +2,37 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use crate::ecmascript::abstract_operations::operations_on_objects::get;
+use crate::ecmascript::abstract_operations::testing_and_comparison::{is_reg_exp, same_value};
 use crate::ecmascript::builders::builtin_function_builder::BuiltinFunctionBuilder;
 use crate::ecmascript::builtins::ArgumentsList;
 use crate::ecmascript::builtins::Behaviour;
 use crate::ecmascript::builtins::Builtin;
 use crate::ecmascript::builtins::BuiltinGetter;
 use crate::ecmascript::builtins::BuiltinIntrinsicConstructor;
+#[cfg(feature = "annex-b-regexp")]
+use crate::ecmascript::builtins::regexp::legacy_features::{
+    LegacyRegExpStaticProperty, get_legacy_reg_exp_static_property, set_legacy_reg_exp_static_input,
+};
+use crate::ecmascript::builtins::regexp::{
+    reg_exp_alloc, reg_exp_initialize, reg_exp_initialize_from_string,
+};
 use crate::ecmascript::execution::Agent;
 use crate::ecmascript::execution::JsResult;
 use crate::ecmascript::execution::Realm;
 
 use crate::ecmascript::types::BUILTIN_STRING_MEMORY;
+use crate::ecmascript::types::Function;
+#[cfg(feature = "annex-b-regexp")]
+use crate::ecmascript::types::IntoFunction;
 use crate::ecmascript::types::IntoObject;
+use crate::ecmascript::types::IntoValue;
 use crate::ecmascript::types::Object;
 use crate::ecmascript::types::PropertyKey;
 use crate::ecmascript::types::String;
 use crate::ecmascript::types::Value;
 use crate::engine::context::{Bindable, GcScope};
+use crate::engine::rootable::Scopable;
 use crate::heap::IntrinsicConstructorIndexes;
 use crate::heap::WellKnownSymbolIndexes;
 
@@ -43,15 +57,303 @@ impl Builtin for RegExpGetSpecies {
 }
 impl BuiltinGetter for RegExpGetSpecies {}
 
+/// Declares a getter of a legacy RegExp static property.
+#[cfg(feature = "annex-b-regexp")]
+macro_rules! legacy_static_getter {
+    ($name: ident, $function_name: expr, $key: expr, $behaviour: path) => {
+        struct $name;
+        impl Builtin for $name {
+            const BEHAVIOUR: Behaviour = Behaviour::Regular($behaviour);
+            const LENGTH: u8 = 0;
+            const NAME: String<'static> = $function_name;
+            const KEY: Option<PropertyKey<'static>> = Some($key.to_property_key());
+        }
+        impl BuiltinGetter for $name {}
+    };
+}
+
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetInput,
+    BUILTIN_STRING_MEMORY.get_input,
+    BUILTIN_STRING_MEMORY.input,
+    RegExpConstructor::get_input
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetInputShort,
+    String::from_small_string("get $_"),
+    String::from_small_string("$_"),
+    RegExpConstructor::get_input
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetLastMatch,
+    BUILTIN_STRING_MEMORY.get_lastMatch,
+    BUILTIN_STRING_MEMORY.lastMatch,
+    RegExpConstructor::get_last_match
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetLastMatchShort,
+    String::from_small_string("get $&"),
+    String::from_small_string("$&"),
+    RegExpConstructor::get_last_match
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetLastParen,
+    BUILTIN_STRING_MEMORY.get_lastParen,
+    BUILTIN_STRING_MEMORY.lastParen,
+    RegExpConstructor::get_last_paren
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetLastParenShort,
+    String::from_small_string("get $+"),
+    String::from_small_string("$+"),
+    RegExpConstructor::get_last_paren
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetLeftContext,
+    BUILTIN_STRING_MEMORY.get_leftContext,
+    BUILTIN_STRING_MEMORY.leftContext,
+    RegExpConstructor::get_left_context
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetLeftContextShort,
+    String::from_small_string("get $`"),
+    String::from_small_string("$`"),
+    RegExpConstructor::get_left_context
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetRightContext,
+    BUILTIN_STRING_MEMORY.get_rightContext,
+    BUILTIN_STRING_MEMORY.rightContext,
+    RegExpConstructor::get_right_context
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetRightContextShort,
+    String::from_small_string("get $'"),
+    String::from_small_string("$'"),
+    RegExpConstructor::get_right_context
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetParen1,
+    String::from_small_string("get $1"),
+    String::from_small_string("$1"),
+    RegExpConstructor::get_paren1
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetParen2,
+    String::from_small_string("get $2"),
+    String::from_small_string("$2"),
+    RegExpConstructor::get_paren2
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetParen3,
+    String::from_small_string("get $3"),
+    String::from_small_string("$3"),
+    RegExpConstructor::get_paren3
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetParen4,
+    String::from_small_string("get $4"),
+    String::from_small_string("$4"),
+    RegExpConstructor::get_paren4
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetParen5,
+    String::from_small_string("get $5"),
+    String::from_small_string("$5"),
+    RegExpConstructor::get_paren5
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetParen6,
+    String::from_small_string("get $6"),
+    String::from_small_string("$6"),
+    RegExpConstructor::get_paren6
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetParen7,
+    String::from_small_string("get $7"),
+    String::from_small_string("$7"),
+    RegExpConstructor::get_paren7
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetParen8,
+    String::from_small_string("get $8"),
+    String::from_small_string("$8"),
+    RegExpConstructor::get_paren8
+);
+#[cfg(feature = "annex-b-regexp")]
+legacy_static_getter!(
+    RegExpGetParen9,
+    String::from_small_string("get $9"),
+    String::from_small_string("$9"),
+    RegExpConstructor::get_paren9
+);
+
+#[cfg(feature = "annex-b-regexp")]
+struct RegExpSetInput;
+#[cfg(feature = "annex-b-regexp")]
+impl Builtin for RegExpSetInput {
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RegExpConstructor::set_input);
+    const LENGTH: u8 = 1;
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.set_input;
+}
+#[cfg(feature = "annex-b-regexp")]
+struct RegExpSetInputShort;
+#[cfg(feature = "annex-b-regexp")]
+impl Builtin for RegExpSetInputShort {
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RegExpConstructor::set_input);
+    const LENGTH: u8 = 1;
+    const NAME: String<'static> = String::from_small_string("set $_");
+}
+
 impl RegExpConstructor {
+    /// ### [22.2.4.1 RegExp ( pattern, flags )](https://tc39.es/ecma262/#sec-regexp-pattern-flags)
+    ///
+    /// > #### Note
+    /// > If pattern is supplied using a StringLiteral, the usual escape
+    /// > sequence substitutions are performed before the String is processed
+    /// > by this function. If pattern must contain an escape sequence to be
+    /// > recognized by this function, any U+005C (REVERSE SOLIDUS) code
+    /// > points must be escaped within the StringLiteral to prevent them
+    /// > being removed when the contents of the StringLiteral are formed.
     fn constructor<'gc>(
         agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        _new_target: Option<Object>,
-        gc: GcScope<'gc, '_>,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp", gc.into_nogc()))
+        let nogc = gc.nogc();
+        let pattern = arguments.get(0).bind(nogc);
+        let flags = arguments.get(1).scope(agent, nogc);
+        let scoped_new_target = new_target.map(|n| n.bind(nogc).scope(agent, nogc));
+        let scoped_pattern = pattern.scope(agent, nogc);
+        // 1. Let patternIsRegExp be ? IsRegExp(pattern).
+        let pattern_is_reg_exp = is_reg_exp(agent, pattern.unbind(), gc.reborrow()).unbind()?;
+        // 2. If NewTarget is undefined, then
+        let new_target = if let Some(new_target) = scoped_new_target {
+            // 3. Else,
+            // a. Let newTarget be NewTarget.
+            Function::try_from(new_target.get(agent)).unwrap()
+        } else {
+            // a. Let newTarget be the active function object.
+            let new_target = agent.active_function_object(gc.nogc());
+            // b. If patternIsRegExp is true and flags is undefined, then
+            if pattern_is_reg_exp && flags.get(agent).is_undefined() {
+                let new_target = new_target.scope(agent, gc.nogc());
+                // i. Let patternConstructor be ? Get(pattern, "constructor").
+                let pattern_constructor = get(
+                    agent,
+                    Object::try_from(scoped_pattern.get(agent)).unwrap(),
+                    BUILTIN_STRING_MEMORY.constructor.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // ii. If SameValue(newTarget, patternConstructor) is true,
+                //     return pattern.
+                if same_value(
+                    agent,
+                    new_target.get(agent).bind(gc.nogc()),
+                    pattern_constructor,
+                ) {
+                    return Ok(scoped_pattern.get(agent));
+                }
+                new_target.get(agent)
+            } else {
+                new_target.unbind()
+            }
+        };
+        let new_target = new_target.scope(agent, gc.nogc());
+        let pattern = scoped_pattern.get(agent).bind(gc.nogc());
+        // 4. If pattern is an Object and pattern has a [[RegExpMatcher]]
+        //    internal slot, then
+        let (p, f) = if let Value::RegExp(pattern) = pattern {
+            // a. Let P be pattern.[[OriginalSource]].
+            let p = agent[pattern].original_source;
+            let flags = flags.get(agent);
+            if flags.is_undefined() {
+                // b. If flags is undefined, let F be pattern.[[OriginalFlags]].
+                let f = agent[pattern].original_flags;
+                let p = p.scope(agent, gc.nogc());
+                // 7. Let O be ? RegExpAlloc(newTarget).
+                let o = reg_exp_alloc(agent, new_target.get(agent), gc.reborrow())
+                    .unbind()?
+                    .bind(gc.nogc());
+                // 8. Return ? RegExpInitialize(O, P, F).
+                return reg_exp_initialize_from_string(
+                    agent,
+                    o.unbind(),
+                    p.get(agent),
+                    Some(f),
+                    gc,
+                )
+                .map(|o| o.into_value());
+            }
+            // c. Else, let F be flags.
+            (p.into_value().unbind(), flags)
+        } else if pattern_is_reg_exp {
+            // 5. Else if patternIsRegExp is true, then
+            let pattern = Object::try_from(pattern).unwrap();
+            // a. Let P be ? Get(pattern, "source").
+            let p = get(
+                agent,
+                pattern.unbind(),
+                BUILTIN_STRING_MEMORY.source.into(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            let flags = flags.get(agent).bind(gc.nogc());
+            // b. If flags is undefined, then
+            if flags.is_undefined() {
+                let p = p.scope(agent, gc.nogc());
+                // i. Let F be ? Get(pattern, "flags").
+                let f = get(
+                    agent,
+                    Object::try_from(scoped_pattern.get(agent)).unwrap(),
+                    BUILTIN_STRING_MEMORY.flags.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                (p.get(agent), f)
+            } else {
+                // c. Else,
+                // i. Let F be flags.
+                (p.unbind(), flags.unbind())
+            }
+        } else {
+            // 6. Else,
+            // a. Let P be pattern.
+            // b. Let F be flags.
+            (pattern.unbind(), flags.get(agent))
+        };
+        let p = p.scope(agent, gc.nogc());
+        let f = f.scope(agent, gc.nogc());
+        // 7. Let O be ? RegExpAlloc(newTarget).
+        let o = reg_exp_alloc(agent, new_target.get(agent), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 8. Return ? RegExpInitialize(O, P, F).
+        reg_exp_initialize(agent, o.unbind(), p.get(agent), f.get(agent), gc)
+            .map(|o| o.into_value())
     }
 
     fn get_species<'gc>(
@@ -63,14 +365,338 @@ impl RegExpConstructor {
         Ok(this_value.unbind())
     }
 
+    /// ### [get RegExp.input](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_input<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpInput]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Input,
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [set RegExp.input](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn set_input<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Perform ? SetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpInput]], val).
+        set_legacy_reg_exp_static_input(agent, this_value, arguments.get(0), gc)?;
+        Ok(Value::Undefined)
+    }
+
+    /// ### [get RegExp.lastMatch](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_last_match<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpLastMatch]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::LastMatch,
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.lastParen](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_last_paren<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpLastParen]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::LastParen,
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.leftContext](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_left_context<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpLeftContext]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::LeftContext,
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.rightContext](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_right_context<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpRightContext]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::RightContext,
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.$1](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_paren1<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpParen1]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Paren(1),
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.$2](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_paren2<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpParen2]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Paren(2),
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.$3](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_paren3<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpParen3]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Paren(3),
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.$4](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_paren4<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpParen4]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Paren(4),
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.$5](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_paren5<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpParen5]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Paren(5),
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.$6](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_paren6<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpParen6]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Paren(6),
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.$7](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_paren7<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpParen7]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Paren(7),
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.$8](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_paren8<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpParen8]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Paren(8),
+            gc.into_nogc(),
+        )
+    }
+
+    /// ### [get RegExp.$9](https://tc39.es/proposal-regexp-legacy-features/#additional-properties-of-the-regexp-constructor)
+    #[cfg(feature = "annex-b-regexp")]
+    fn get_paren9<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value,
+        //    [[RegExpParen9]]).
+        get_legacy_reg_exp_static_property(
+            agent,
+            this_value,
+            LegacyRegExpStaticProperty::Paren(9),
+            gc.into_nogc(),
+        )
+    }
+
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let regexp_prototype = intrinsics.reg_exp_prototype();
 
-        BuiltinFunctionBuilder::new_intrinsic_constructor::<RegExpConstructor>(agent, realm)
-            .with_property_capacity(2)
-            .with_prototype_property(regexp_prototype.into_object())
-            .with_builtin_function_getter_property::<RegExpGetSpecies>()
-            .build();
+        #[cfg(feature = "annex-b-regexp")]
+        let (get_input, set_input, get_input_short, set_input_short) = (
+            BuiltinFunctionBuilder::new::<RegExpGetInput>(agent, realm)
+                .build()
+                .into_function(),
+            BuiltinFunctionBuilder::new::<RegExpSetInput>(agent, realm)
+                .build()
+                .into_function(),
+            BuiltinFunctionBuilder::new::<RegExpGetInputShort>(agent, realm)
+                .build()
+                .into_function(),
+            BuiltinFunctionBuilder::new::<RegExpSetInputShort>(agent, realm)
+                .build()
+                .into_function(),
+        );
+
+        let builder =
+            BuiltinFunctionBuilder::new_intrinsic_constructor::<RegExpConstructor>(agent, realm)
+                .with_property_capacity(if cfg!(feature = "annex-b-regexp") {
+                    21
+                } else {
+                    2
+                })
+                .with_prototype_property(regexp_prototype.into_object())
+                .with_builtin_function_getter_property::<RegExpGetSpecies>();
+
+        #[cfg(feature = "annex-b-regexp")]
+        let builder = builder
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.input.to_property_key())
+                    .with_configurable(true)
+                    .with_enumerable(false)
+                    .with_getter_and_setter_functions(get_input, set_input)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(String::from_small_string("$_").to_property_key())
+                    .with_configurable(true)
+                    .with_enumerable(false)
+                    .with_getter_and_setter_functions(get_input_short, set_input_short)
+                    .build()
+            })
+            .with_builtin_function_getter_property::<RegExpGetLastMatch>()
+            .with_builtin_function_getter_property::<RegExpGetLastMatchShort>()
+            .with_builtin_function_getter_property::<RegExpGetLastParen>()
+            .with_builtin_function_getter_property::<RegExpGetLastParenShort>()
+            .with_builtin_function_getter_property::<RegExpGetLeftContext>()
+            .with_builtin_function_getter_property::<RegExpGetLeftContextShort>()
+            .with_builtin_function_getter_property::<RegExpGetRightContext>()
+            .with_builtin_function_getter_property::<RegExpGetRightContextShort>()
+            .with_builtin_function_getter_property::<RegExpGetParen1>()
+            .with_builtin_function_getter_property::<RegExpGetParen2>()
+            .with_builtin_function_getter_property::<RegExpGetParen3>()
+            .with_builtin_function_getter_property::<RegExpGetParen4>()
+            .with_builtin_function_getter_property::<RegExpGetParen5>()
+            .with_builtin_function_getter_property::<RegExpGetParen6>()
+            .with_builtin_function_getter_property::<RegExpGetParen7>()
+            .with_builtin_function_getter_property::<RegExpGetParen8>()
+            .with_builtin_function_getter_property::<RegExpGetParen9>();
+
+        builder.build();
     }
 }
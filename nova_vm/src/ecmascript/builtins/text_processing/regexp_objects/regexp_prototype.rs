@@ -2,24 +2,44 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use oxc_ast::ast::RegExpFlags;
+
 use crate::{
     ecmascript::{
         abstract_operations::{
-            operations_on_objects::get,
-            type_conversion::{to_boolean, to_string},
+            operations_on_objects::{
+                call_function, construct, create_array_from_list, get, length_of_array_like, set,
+                species_constructor,
+            },
+            testing_and_comparison::{is_callable, same_value},
+            type_conversion::{
+                to_boolean, to_integer_or_infinity, to_length, to_object, to_string, to_uint32,
+            },
         },
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsic},
+        builtins::{
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsic,
+            regexp::{advance_string_index, reg_exp_builtin_exec, reg_exp_exec},
+            text_processing::string_objects::string_prototype::get_substitution,
+        },
         execution::{Agent, JsResult, Realm, agent::ExceptionType},
-        types::{BUILTIN_STRING_MEMORY, IntoValue, Object, PropertyKey, String, Value},
+        types::{
+            BUILTIN_STRING_MEMORY, IntoFunction, IntoObject, IntoValue, Object, PropertyKey,
+            String, Value,
+        },
     },
     engine::{
-        context::{Bindable, GcScope},
+        ScopableCollection,
+        context::{Bindable, GcScope, NoGcScope},
         rootable::Scopable,
     },
     heap::{IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
 };
 
+use super::regexp_string_iterator_objects::RegExpStringIterator;
+#[cfg(feature = "annex-b-regexp")]
+use crate::ecmascript::builtins::regexp::{reg_exp_initialize, reg_exp_initialize_from_string};
+
 pub(crate) struct RegExpPrototype;
 
 struct RegExpPrototypeExec;
@@ -128,6 +148,14 @@ impl Builtin for RegExpPrototypeSplit {
     const LENGTH: u8 = 2;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(RegExpPrototype::split);
 }
+#[cfg(feature = "annex-b-regexp")]
+struct RegExpPrototypeCompile;
+#[cfg(feature = "annex-b-regexp")]
+impl Builtin for RegExpPrototypeCompile {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compile;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RegExpPrototype::compile);
+}
 struct RegExpPrototypeGetSticky;
 impl Builtin for RegExpPrototypeGetSticky {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_sticky;
@@ -167,22 +195,57 @@ impl Builtin for RegExpPrototypeGetUnicodeSets {
 impl BuiltinGetter for RegExpPrototypeGetUnicodeSets {}
 
 impl RegExpPrototype {
+    /// ### [22.2.6.2 RegExp.prototype.exec ( string )](https://tc39.es/ecma262/#sec-regexp.prototype.exec)
+    ///
+    /// This method searches string for an occurrence of the regular
+    /// expression pattern and returns an Array containing the results of the
+    /// match, or null if string did not match.
     fn exec<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.exec", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. Let R be the this value.
+        // 2. Perform ? RequireInternalSlot(R, [[RegExpMatcher]]).
+        let Value::RegExp(r) = this_value else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype.exec called on incompatible receiver",
+                gc.into_nogc(),
+            ));
+        };
+        // 3. Let S be ? ToString(string).
+        let (r, s) = if let Ok(s) = String::try_from(string) {
+            (r, s)
+        } else {
+            let scoped_r = r.scope(agent, gc.nogc());
+            let s = to_string(agent, string.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            (scoped_r.get(agent).bind(gc.nogc()), s)
+        };
+        // 4. Return ? RegExpBuiltinExec(R, S).
+        Ok(reg_exp_builtin_exec(agent, r.unbind(), s.unbind(), gc)?
+            .map_or(Value::Null, |a| a.into_value()))
     }
 
+    /// ### [22.2.6.3 get RegExp.prototype.dotAll](https://tc39.es/ecma262/#sec-get-regexp.prototype.dotall)
+    ///
+    /// RegExp.prototype.dotAll is an accessor property whose set accessor
+    /// function is undefined.
     fn get_dot_all<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.dotAll", gc.into_nogc()))
+        // 1. Let R be the this value.
+        // 2. Let cu be the code unit 0x0073 (LATIN SMALL LETTER S).
+        // 3. Return ? RegExpHasFlag(R, cu).
+        reg_exp_has_flag(agent, this_value, RegExpFlags::S, gc.into_nogc())
     }
 
     /// ### [22.2.6.4 get RegExp.prototype.flags](https://tc39.es/ecma262/#sec-get-regexp.prototype.flags)
@@ -275,6 +338,7 @@ impl RegExpPrototype {
         // 11. If multiline is true, append the code unit 0x006D (LATIN SMALL LETTER M) to codeUnits.
         if multiline {
             code_units[i] = b'm';
+            i += 1;
         };
 
         // 12. Let dotAll be ToBoolean(? Get(R, "dotAll")).
@@ -341,7 +405,7 @@ impl RegExpPrototype {
 
         // 19. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) to codeUnits.
         if sticky {
-            code_units[i] = b'v';
+            code_units[i] = b'y';
             i += 1;
         };
 
@@ -350,112 +414,865 @@ impl RegExpPrototype {
         Ok(Value::from_string(agent, res.to_string(), gc.nogc()).unbind())
     }
 
+    /// ### [22.2.6.5 get RegExp.prototype.global](https://tc39.es/ecma262/#sec-get-regexp.prototype.global)
+    ///
+    /// RegExp.prototype.global is an accessor property whose set accessor
+    /// function is undefined.
     fn get_global<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.global", gc.into_nogc()))
+        // 1. Let R be the this value.
+        // 2. Let cu be the code unit 0x0067 (LATIN SMALL LETTER G).
+        // 3. Return ? RegExpHasFlag(R, cu).
+        reg_exp_has_flag(agent, this_value, RegExpFlags::G, gc.into_nogc())
     }
 
+    /// ### [22.2.6.6 get RegExp.prototype.hasIndices](https://tc39.es/ecma262/#sec-get-regexp.prototype.hasindices)
+    ///
+    /// RegExp.prototype.hasIndices is an accessor property whose set accessor
+    /// function is undefined.
     fn get_has_indices<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.hasIndices", gc.into_nogc()))
+        // 1. Let R be the this value.
+        // 2. Let cu be the code unit 0x0064 (LATIN SMALL LETTER D).
+        // 3. Return ? RegExpHasFlag(R, cu).
+        reg_exp_has_flag(agent, this_value, RegExpFlags::D, gc.into_nogc())
     }
 
+    /// ### [22.2.6.7 get RegExp.prototype.ignoreCase](https://tc39.es/ecma262/#sec-get-regexp.prototype.ignorecase)
+    ///
+    /// RegExp.prototype.ignoreCase is an accessor property whose set accessor
+    /// function is undefined.
     fn get_ignore_case<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.ignoreCase", gc.into_nogc()))
+        // 1. Let R be the this value.
+        // 2. Let cu be the code unit 0x0069 (LATIN SMALL LETTER I).
+        // 3. Return ? RegExpHasFlag(R, cu).
+        reg_exp_has_flag(agent, this_value, RegExpFlags::I, gc.into_nogc())
     }
 
+    /// ### [22.2.6.8 RegExp.prototype \[ %Symbol.match% \] ( string )](https://tc39.es/ecma262/#sec-regexp.prototype-%symbol.match%)
     fn r#match<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.match", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. Let rx be the this value.
+        // 2. If rx is not an Object, throw a TypeError exception.
+        let Ok(rx) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype[Symbol.match] called on non-object",
+                gc.into_nogc(),
+            ));
+        };
+        let rx = rx.scope(agent, gc.nogc());
+        // 3. Let S be ? ToString(string).
+        let s = to_string(agent, string.unbind(), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. Let flags be ? ToString(? Get(rx, "flags")).
+        let flags = get_flags_string(agent, rx.get(agent), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 5. If flags does not contain "g", then
+        if !flags.as_str(agent).contains('g') {
+            // a. Return ? RegExpExec(rx, S).
+            return Ok(reg_exp_exec(agent, rx.get(agent), s.get(agent), gc)?
+                .map_or(Value::Null, |a| a.into_value()));
+        }
+        // 6. Else,
+        // a. If flags contains "u" or flags contains "v", let fullUnicode be
+        //    true. Otherwise, let fullUnicode be false.
+        let full_unicode = flags.as_str(agent).contains(['u', 'v']);
+        // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
+        set_last_index(agent, rx.get(agent), 0, gc.reborrow()).unbind()?;
+        // c. Let A be ! ArrayCreate(0).
+        // d. Let n be 0.
+        let mut a = Vec::<Value>::new().scope(agent, gc.nogc());
+        // e. Repeat,
+        loop {
+            // i. Let result be ? RegExpExec(rx, S).
+            let result = reg_exp_exec(agent, rx.get(agent), s.get(agent), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            // ii. If result is null, then
+            let Some(result) = result else {
+                let a = a.take(agent);
+                let gc = gc.into_nogc();
+                // 1. If n = 0, return null.
+                if a.is_empty() {
+                    return Ok(Value::Null);
+                }
+                // 2. Return A.
+                return Ok(create_array_from_list(agent, &a, gc).into_value());
+            };
+            // iii. Else,
+            // 1. Let matchStr be ? ToString(? Get(result, "0")).
+            let match_str = get_match_str(agent, result.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), matchStr).
+            a.push(agent, match_str.into_value());
+            // 3. If matchStr is the empty String, then
+            if match_str.is_empty_string() {
+                // a. Let thisIndex be ℝ(? ToLength(? Get(rx, "lastIndex"))).
+                // b. Let nextIndex be AdvanceStringIndex(S, thisIndex, fullUnicode).
+                // c. Perform ? Set(rx, "lastIndex", 𝔽(nextIndex), true).
+                advance_last_index(
+                    agent,
+                    rx.get(agent),
+                    s.get(agent),
+                    full_unicode,
+                    gc.reborrow(),
+                )
+                .unbind()?;
+            }
+            // 4. Set n to n + 1.
+        }
     }
 
+    /// ### [22.2.6.9 RegExp.prototype \[ %Symbol.matchAll% \] ( string )](https://tc39.es/ecma262/#sec-regexp-prototype-%symbol.matchall%)
     fn match_all<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.matchAll", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. Let R be the this value.
+        // 2. If R is not an Object, throw a TypeError exception.
+        let Ok(r) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype[Symbol.matchAll] called on non-object",
+                gc.into_nogc(),
+            ));
+        };
+        let r = r.scope(agent, gc.nogc());
+        // 3. Let S be ? ToString(string).
+        let s = to_string(agent, string.unbind(), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. Let C be ? SpeciesConstructor(R, %RegExp%).
+        let reg_exp_constructor = agent
+            .current_realm_record()
+            .intrinsics()
+            .reg_exp()
+            .into_function();
+        let c = species_constructor(agent, r.get(agent), reg_exp_constructor, gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 5. Let flags be ? ToString(? Get(R, "flags")).
+        let flags = get_flags_string(agent, r.get(agent), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 6. Let matcher be ? Construct(C, « R, flags »).
+        let matcher = construct(
+            agent,
+            c.get(agent),
+            Some(ArgumentsList::from_mut_slice(&mut [
+                r.get(agent).into_value(),
+                flags.get(agent).into_value(),
+            ])),
+            None,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .scope(agent, gc.nogc());
+        // 7. Let lastIndex be ? ToLength(? Get(R, "lastIndex")).
+        let last_index = get_last_index(agent, r.get(agent), gc.reborrow()).unbind()?;
+        // 8. Perform ? Set(matcher, "lastIndex", lastIndex, true).
+        set_last_index(agent, matcher.get(agent), last_index, gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let flags = flags.get(agent).bind(gc);
+        // 9. If flags contains "g", let global be true.
+        // 10. Else, let global be false.
+        let global = flags.as_str(agent).contains('g');
+        // 11. If flags contains "u" or flags contains "v", let fullUnicode be
+        //     true.
+        // 12. Else, let fullUnicode be false.
+        let full_unicode = flags.as_str(agent).contains(['u', 'v']);
+        // 13. Return CreateRegExpStringIterator(matcher, S, global, fullUnicode).
+        Ok(RegExpStringIterator::create(
+            agent,
+            matcher.get(agent),
+            s.get(agent),
+            global,
+            full_unicode,
+            gc,
+        )
+        .into_value())
     }
 
+    /// ### [22.2.6.10 get RegExp.prototype.multiline](https://tc39.es/ecma262/#sec-get-regexp.prototype.multiline)
+    ///
+    /// RegExp.prototype.multiline is an accessor property whose set accessor
+    /// function is undefined.
     fn get_multiline<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.multiline", gc.into_nogc()))
+        // 1. Let R be the this value.
+        // 2. Let cu be the code unit 0x006D (LATIN SMALL LETTER M).
+        // 3. Return ? RegExpHasFlag(R, cu).
+        reg_exp_has_flag(agent, this_value, RegExpFlags::M, gc.into_nogc())
     }
 
+    /// ### [22.2.6.11 RegExp.prototype \[ %Symbol.replace% \] ( string, replaceValue )](https://tc39.es/ecma262/#sec-regexp.prototype-%symbol.replace%)
     fn replace<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.replace", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let string = arguments.get(0).bind(gc.nogc());
+        let replace_value = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let rx be the this value.
+        // 2. If rx is not an Object, throw a TypeError exception.
+        let Ok(rx) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype[Symbol.replace] called on non-object",
+                gc.into_nogc(),
+            ));
+        };
+        let rx = rx.scope(agent, gc.nogc());
+        // 3. Let S be ? ToString(string).
+        let s = to_string(agent, string.unbind(), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. Let lengthS be the length of S.
+        let length_s = s.get(agent).utf16_len(agent);
+        // 5. Let functionalReplace be IsCallable(replaceValue).
+        let functional_replace =
+            is_callable(replace_value.get(agent), gc.nogc()).map(|f| f.scope(agent, gc.nogc()));
+        // 6. If functionalReplace is false, then
+        let replace_template = if functional_replace.is_none() {
+            // a. Set replaceValue to ? ToString(replaceValue).
+            let replace_value = to_string(agent, replace_value.get(agent), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            replace_value.as_str(agent).to_owned()
+        } else {
+            std::string::String::new()
+        };
+        // 7. Let flags be ? ToString(? Get(rx, "flags")).
+        let flags = get_flags_string(agent, rx.get(agent), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 8. If flags contains "g", let global be true. Otherwise, let global
+        //    be false.
+        let global = flags.as_str(agent).contains('g');
+        // 9. If global is true, then
+        // a. If flags contains "u" or flags contains "v", let fullUnicode be
+        //    true. Otherwise, let fullUnicode be false.
+        let full_unicode = flags.as_str(agent).contains(['u', 'v']);
+        if global {
+            // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
+            set_last_index(agent, rx.get(agent), 0, gc.reborrow()).unbind()?;
+        }
+        // 10. Let results be a new empty List.
+        let mut results = Vec::<Value>::new().scope(agent, gc.nogc());
+        // 11. Let done be false.
+        // 12. Repeat, while done is false,
+        loop {
+            // a. Let result be ? RegExpExec(rx, S).
+            let result = reg_exp_exec(agent, rx.get(agent), s.get(agent), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            // b. If result is null, then
+            let Some(result) = result else {
+                // i. Set done to true.
+                break;
+            };
+            // c. Else,
+            // i. Append result to results.
+            results.push(agent, result.into_value());
+            // ii. If global is false, then
+            if !global {
+                // 1. Set done to true.
+                break;
+            }
+            // iii. Else,
+            // 1. Let matchStr be ? ToString(? Get(result, "0")).
+            let match_str = get_match_str(agent, result.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            // 2. If matchStr is the empty String, then
+            if match_str.is_empty_string() {
+                // a. Let thisIndex be ℝ(? ToLength(? Get(rx, "lastIndex"))).
+                // b. Let nextIndex be AdvanceStringIndex(S, thisIndex, fullUnicode).
+                // c. Perform ? Set(rx, "lastIndex", 𝔽(nextIndex), true).
+                advance_last_index(
+                    agent,
+                    rx.get(agent),
+                    s.get(agent),
+                    full_unicode,
+                    gc.reborrow(),
+                )
+                .unbind()?;
+            }
+        }
+        let str = s
+            .get(agent)
+            .as_str(agent)
+            .encode_utf16()
+            .collect::<Vec<u16>>();
+        // 13. Let accumulatedResult be the empty String.
+        let mut accumulated_result = Vec::<u16>::with_capacity(str.len());
+        // 14. Let nextSourcePosition be 0.
+        let mut next_source_position = 0;
+        // 15. For each element result of results, do
+        for result in results.iter(agent) {
+            // a. Let resultLength be ? LengthOfArrayLike(result).
+            let result_length = length_of_array_like(
+                agent,
+                Object::try_from(result.get(gc.nogc())).unwrap().unbind(),
+                gc.reborrow(),
+            )
+            .unbind()?;
+            // b. Let nCaptures be max(resultLength - 1, 0).
+            let n_captures = (result_length - 1).max(0) as usize;
+            // c. Let matched be ? ToString(? Get(result, "0")).
+            let matched = get_match_str(
+                agent,
+                Object::try_from(result.get(gc.nogc())).unwrap().unbind(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .scope(agent, gc.nogc());
+            // d. Let matchLength be the length of matched.
+            let match_length = matched.get(agent).utf16_len(agent);
+            // e. Let position be ? ToIntegerOrInfinity(? Get(result, "index")).
+            let position = get(
+                agent,
+                Object::try_from(result.get(gc.nogc())).unwrap().unbind(),
+                BUILTIN_STRING_MEMORY.index.into(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            let position = to_integer_or_infinity(agent, position.unbind(), gc.reborrow())
+                .unbind()?
+                .into_i64();
+            // f. Set position to the result of clamping position between 0
+            //    and lengthS.
+            let position = position.clamp(0, length_s as i64) as usize;
+            // g. Let captures be a new empty List.
+            let mut captures = Vec::<Value>::new().scope(agent, gc.nogc());
+            // h. Let n be 1.
+            // i. Repeat, while n ≤ nCaptures,
+            for n in 1..=n_captures {
+                // i. Let capN be ? Get(result, ! ToString(𝔽(n))).
+                let cap_n = get(
+                    agent,
+                    Object::try_from(result.get(gc.nogc())).unwrap().unbind(),
+                    PropertyKey::try_from(n).unwrap(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // ii. If capN is not undefined, then
+                let cap_n = if cap_n.is_undefined() {
+                    cap_n
+                } else {
+                    // 1. Set capN to ? ToString(capN).
+                    to_string(agent, cap_n.unbind(), gc.reborrow())
+                        .unbind()?
+                        .bind(gc.nogc())
+                        .into_value()
+                };
+                // iii. Append capN to captures.
+                captures.push(agent, cap_n);
+                // iv. NOTE: When n = 1, the preceding step puts the first
+                //     element into captures (at index 0). More generally,
+                //     the nth capture (the first capture having index 1) is
+                //     put at index n - 1.
+                // v. Set n to n + 1.
+            }
+            // j. Let namedCaptures be ? Get(result, "groups").
+            let named_captures = get(
+                agent,
+                Object::try_from(result.get(gc.nogc())).unwrap().unbind(),
+                BUILTIN_STRING_MEMORY.groups.into(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // k. If functionalReplace is true, then
+            let replacement = if let Some(functional_replace) = &functional_replace {
+                // i. Let replacerArgs be the list-concatenation of « matched »,
+                //    captures, and « 𝔽(position), S ».
+                let captures = captures.take(agent);
+                let mut replacer_args = Vec::with_capacity(captures.len() + 4);
+                replacer_args.push(matched.get(agent).into_value());
+                replacer_args.extend(captures);
+                replacer_args.push(Value::try_from(position as i64).unwrap());
+                replacer_args.push(s.get(agent).into_value());
+                // ii. If namedCaptures is not undefined, then
+                if !named_captures.is_undefined() {
+                    // 1. Append namedCaptures to replacerArgs.
+                    replacer_args.push(named_captures.unbind());
+                }
+                // iii. Let replValue be ? Call(replaceValue, undefined, replacerArgs).
+                let repl_value = call_function(
+                    agent,
+                    functional_replace.get(agent),
+                    Value::Undefined,
+                    Some(ArgumentsList::from_mut_slice(&mut replacer_args)),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // iv. Let replacement be ? ToString(replValue).
+                to_string(agent, repl_value.unbind(), gc.reborrow())
+                    .unbind()?
+                    .bind(gc.nogc())
+            } else {
+                // l. Else,
+                // i. If namedCaptures is not undefined, then
+                let named_captures = if named_captures.is_undefined() {
+                    None
+                } else {
+                    // 1. Set namedCaptures to ? ToObject(namedCaptures).
+                    Some(to_object(agent, named_captures, gc.nogc()).unbind()?)
+                };
+                // ii. Let replacement be ? GetSubstitution(matched, S,
+                //     position, captures, namedCaptures, replaceValue).
+                let captures = captures
+                    .take(agent)
+                    .into_iter()
+                    .map(|capture| {
+                        String::try_from(capture)
+                            .ok()
+                            .map(|capture| capture.as_str(agent).to_owned())
+                    })
+                    .collect::<Vec<_>>();
+                let matched = matched.get(agent).as_str(agent).to_owned();
+                get_substitution(
+                    agent,
+                    &matched,
+                    &str,
+                    position,
+                    &captures,
+                    named_captures,
+                    &replace_template,
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc())
+            };
+            // m. If position ≥ nextSourcePosition, then
+            if position >= next_source_position {
+                // i. NOTE: position should not normally move backwards. If
+                //    it does, it is an indication of an ill-behaving RegExp
+                //    subclass or use of an access triggered side-effect to
+                //    change the global flag or other characteristics of rx.
+                //    In such cases, the corresponding substitution is
+                //    ignored.
+                // ii. Set accumulatedResult to the string-concatenation of
+                //     accumulatedResult, the substring of S from
+                //     nextSourcePosition to position, and replacement.
+                accumulated_result.extend_from_slice(&str[next_source_position..position]);
+                accumulated_result.extend(replacement.as_str(agent).encode_utf16());
+                // iii. Set nextSourcePosition to position + matchLength.
+                next_source_position = position + match_length;
+            }
+        }
+        // 16. If nextSourcePosition ≥ lengthS, return accumulatedResult.
+        // 17. Return the string-concatenation of accumulatedResult and the
+        //     substring of S from nextSourcePosition.
+        if next_source_position < length_s {
+            accumulated_result.extend_from_slice(&str[next_source_position..]);
+        }
+        let accumulated_result = std::string::String::from_utf16_lossy(&accumulated_result);
+        Ok(String::from_string(agent, accumulated_result, gc.into_nogc()).into_value())
     }
 
+    /// ### [22.2.6.12 RegExp.prototype \[ %Symbol.search% \] ( string )](https://tc39.es/ecma262/#sec-regexp.prototype-%symbol.search%)
     fn search<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.search", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. Let rx be the this value.
+        // 2. If rx is not an Object, throw a TypeError exception.
+        let Ok(rx) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype[Symbol.search] called on non-object",
+                gc.into_nogc(),
+            ));
+        };
+        let rx = rx.scope(agent, gc.nogc());
+        // 3. Let S be ? ToString(string).
+        let s = to_string(agent, string.unbind(), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. Let previousLastIndex be ? Get(rx, "lastIndex").
+        let previous_last_index = get(
+            agent,
+            rx.get(agent),
+            BUILTIN_STRING_MEMORY.lastIndex.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .scope(agent, gc.nogc());
+        // 5. If SameValue(previousLastIndex, +0𝔽) is false, then
+        if !same_value(agent, previous_last_index.get(agent), Value::from(0)) {
+            // a. Perform ? Set(rx, "lastIndex", +0𝔽, true).
+            set_last_index(agent, rx.get(agent), 0, gc.reborrow()).unbind()?;
+        }
+        // 6. Let result be ? RegExpExec(rx, S).
+        let result = reg_exp_exec(agent, rx.get(agent), s.get(agent), gc.reborrow())
+            .unbind()?
+            .map(|result| result.scope(agent, gc.nogc()));
+        // 7. Let currentLastIndex be ? Get(rx, "lastIndex").
+        let current_last_index = get(
+            agent,
+            rx.get(agent),
+            BUILTIN_STRING_MEMORY.lastIndex.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // 8. If SameValue(currentLastIndex, previousLastIndex) is false, then
+        if !same_value(
+            agent,
+            current_last_index,
+            previous_last_index.get(agent).bind(gc.nogc()),
+        ) {
+            // a. Perform ? Set(rx, "lastIndex", previousLastIndex, true).
+            set(
+                agent,
+                rx.get(agent),
+                BUILTIN_STRING_MEMORY.lastIndex.into(),
+                previous_last_index.get(agent),
+                true,
+                gc.reborrow(),
+            )
+            .unbind()?;
+        }
+        // 9. If result is null, return -1𝔽.
+        let Some(result) = result else {
+            return Ok(Value::from(-1));
+        };
+        // 10. Return ? Get(result, "index").
+        get(
+            agent,
+            result.get(agent),
+            BUILTIN_STRING_MEMORY.index.into(),
+            gc,
+        )
     }
 
+    /// ### [22.2.6.13 get RegExp.prototype.source](https://tc39.es/ecma262/#sec-get-regexp.prototype.source)
+    ///
+    /// RegExp.prototype.source is an accessor property whose set accessor
+    /// function is undefined.
     fn get_source<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.source", gc.into_nogc()))
+        let gc = gc.into_nogc();
+        let this_value = this_value.bind(gc);
+        // 1. Let R be the this value.
+        // 2. If R is not an Object, throw a TypeError exception.
+        let Ok(r) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype.source getter called on non-object",
+                gc,
+            ));
+        };
+        // 3. If R does not have an [[OriginalSource]] internal slot, then
+        let Object::RegExp(r) = r else {
+            // a. If SameValue(R, %RegExp.prototype%) is true, return "(?:)".
+            if r == agent
+                .current_realm_record()
+                .intrinsics()
+                .reg_exp_prototype()
+                .into_object()
+            {
+                return Ok(String::from_static_str(agent, "(?:)", gc).into_value());
+            }
+            // b. Otherwise, throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype.source getter called on incompatible receiver",
+                gc,
+            ));
+        };
+        // 4. Assert: R has an [[OriginalFlags]] internal slot.
+        // 5. Let src be R.[[OriginalSource]].
+        // 6. Let flags be R.[[OriginalFlags]].
+        let src = agent[r].original_source;
+        // 7. Return EscapeRegExpPattern(src, flags).
+        Ok(escape_reg_exp_pattern(agent, src, gc).into_value())
     }
 
+    /// ### [22.2.6.14 RegExp.prototype \[ %Symbol.split% \] ( string, limit )](https://tc39.es/ecma262/#sec-regexp.prototype-%symbol.split%)
+    ///
+    /// > #### Note
+    /// >
+    /// > This method ignores the value of the "global" and "sticky"
+    /// > properties of this RegExp object.
     fn split<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.split", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let string = arguments.get(0).bind(gc.nogc());
+        let limit = arguments.get(1).scope(agent, gc.nogc());
+        // 1. Let rx be the this value.
+        // 2. If rx is not an Object, throw a TypeError exception.
+        let Ok(rx) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype[Symbol.split] called on non-object",
+                gc.into_nogc(),
+            ));
+        };
+        let rx = rx.scope(agent, gc.nogc());
+        // 3. Let S be ? ToString(string).
+        let s = to_string(agent, string.unbind(), gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 4. Let C be ? SpeciesConstructor(rx, %RegExp%).
+        let reg_exp_constructor = agent
+            .current_realm_record()
+            .intrinsics()
+            .reg_exp()
+            .into_function();
+        let c = species_constructor(agent, rx.get(agent), reg_exp_constructor, gc.reborrow())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 5. Let flags be ? ToString(? Get(rx, "flags")).
+        let flags = get_flags_string(agent, rx.get(agent), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 6. If flags contains "u" or flags contains "v", let unicodeMatching
+        //    be true.
+        // 7. Else, let unicodeMatching be false.
+        let unicode_matching = flags.as_str(agent).contains(['u', 'v']);
+        // 8. If flags contains "y", let newFlags be flags.
+        let new_flags = if flags.as_str(agent).contains('y') {
+            flags
+        } else {
+            // 9. Else, let newFlags be the string-concatenation of flags and
+            //    "y".
+            let new_flags = format!("{}y", flags.as_str(agent));
+            String::from_string(agent, new_flags, gc.nogc())
+        };
+        // 10. Let splitter be ? Construct(C, « rx, newFlags »).
+        let splitter = construct(
+            agent,
+            c.get(agent),
+            Some(ArgumentsList::from_mut_slice(&mut [
+                rx.get(agent).into_value(),
+                new_flags.into_value().unbind(),
+            ])),
+            None,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .scope(agent, gc.nogc());
+        // 11. Let A be ! ArrayCreate(0).
+        // 12. Let lengthA be 0.
+        // 13. If limit is undefined, let lim be 2**32 - 1; else let lim be
+        //     ℝ(? ToUint32(limit)).
+        let limit = limit.get(agent).bind(gc.nogc());
+        let lim = if limit.is_undefined() {
+            u32::MAX
+        } else {
+            to_uint32(agent, limit.unbind(), gc.reborrow()).unbind()?
+        } as usize;
+        // 14. If lim = 0, return A.
+        if lim == 0 {
+            return Ok(create_array_from_list(agent, &[], gc.into_nogc()).into_value());
+        }
+        // 16. Let size be the length of S.
+        let size = s.get(agent).utf16_len(agent);
+        // 15. If S is the empty String, then
+        if size == 0 {
+            // a. Let z be ? RegExpExec(splitter, S).
+            let z = reg_exp_exec(agent, splitter.get(agent), s.get(agent), gc.reborrow())
+                .unbind()?
+                .is_some();
+            let gc = gc.into_nogc();
+            // b. If z is not null, return A.
+            if z {
+                return Ok(create_array_from_list(agent, &[], gc).into_value());
+            }
+            // c. Perform ! CreateDataPropertyOrThrow(A, "0", S).
+            // d. Return A.
+            return Ok(
+                create_array_from_list(agent, &[s.get(agent).into_value()], gc).into_value(),
+            );
+        }
+        let str = s
+            .get(agent)
+            .as_str(agent)
+            .encode_utf16()
+            .collect::<Vec<u16>>();
+        let mut a = Vec::<Value>::new().scope(agent, gc.nogc());
+        // 17. Let p be 0.
+        let mut p = 0;
+        // 18. Let q be p.
+        let mut q = p;
+        // 19. Repeat, while q < size,
+        while q < size {
+            // a. Perform ? Set(splitter, "lastIndex", 𝔽(q), true).
+            set_last_index(agent, splitter.get(agent), q, gc.reborrow()).unbind()?;
+            // b. Let z be ? RegExpExec(splitter, S).
+            let z = reg_exp_exec(agent, splitter.get(agent), s.get(agent), gc.reborrow())
+                .unbind()?
+                .map(|z| z.scope(agent, gc.nogc()));
+            // c. If z is null, then
+            let Some(z) = z else {
+                // i. Set q to AdvanceStringIndex(S, q, unicodeMatching).
+                q = advance_string_index(agent, s.get(agent), q, unicode_matching);
+                continue;
+            };
+            // d. Else,
+            // i. Let e be ℝ(? ToLength(? Get(splitter, "lastIndex"))).
+            let e = get_last_index(agent, splitter.get(agent), gc.reborrow()).unbind()?;
+            // ii. Set e to min(e, size).
+            let e = e.min(size);
+            // iii. If e = p, then
+            if e == p {
+                // 1. Set q to AdvanceStringIndex(S, q, unicodeMatching).
+                q = advance_string_index(agent, s.get(agent), q, unicode_matching);
+                continue;
+            }
+            // iv. Else,
+            // 1. Let T be the substring of S from p to q.
+            let t = std::string::String::from_utf16_lossy(&str[p..q]);
+            let t = String::from_string(agent, t, gc.nogc());
+            // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
+            a.push(agent, t.into_value());
+            // 3. Set lengthA to lengthA + 1.
+            // 4. If lengthA = lim, return A.
+            if a.len(agent) == lim {
+                let a = a.take(agent);
+                return Ok(create_array_from_list(agent, &a, gc.into_nogc()).into_value());
+            }
+            // 5. Set p to e.
+            p = e;
+            // 6. Let numberOfCaptures be ? LengthOfArrayLike(z).
+            let number_of_captures =
+                length_of_array_like(agent, z.get(agent), gc.reborrow()).unbind()?;
+            // 7. Set numberOfCaptures to max(numberOfCaptures - 1, 0).
+            let number_of_captures = (number_of_captures - 1).max(0) as usize;
+            // 8. Let i be 1.
+            // 9. Repeat, while i ≤ numberOfCaptures,
+            for i in 1..=number_of_captures {
+                // a. Let nextCapture be ? Get(z, ! ToString(𝔽(i))).
+                let next_capture = get(
+                    agent,
+                    z.get(agent),
+                    PropertyKey::try_from(i).unwrap(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // b. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), nextCapture).
+                a.push(agent, next_capture);
+                // c. Set i to i + 1.
+                // d. Set lengthA to lengthA + 1.
+                // e. If lengthA = lim, return A.
+                if a.len(agent) == lim {
+                    let a = a.take(agent);
+                    return Ok(create_array_from_list(agent, &a, gc.into_nogc()).into_value());
+                }
+            }
+            // 10. Set q to p.
+            q = p;
+        }
+        // 20. Let T be the substring of S from p to size.
+        let t = std::string::String::from_utf16_lossy(&str[p..size]);
+        let t = String::from_string(agent, t, gc.nogc());
+        // 21. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
+        a.push(agent, t.into_value());
+        // 22. Return A.
+        let a = a.take(agent);
+        Ok(create_array_from_list(agent, &a, gc.into_nogc()).into_value())
     }
 
+    /// ### [22.2.6.15 get RegExp.prototype.sticky](https://tc39.es/ecma262/#sec-get-regexp.prototype.sticky)
+    ///
+    /// RegExp.prototype.sticky is an accessor property whose set accessor
+    /// function is undefined.
     fn get_sticky<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.sticky", gc.into_nogc()))
+        // 1. Let R be the this value.
+        // 2. Let cu be the code unit 0x0079 (LATIN SMALL LETTER Y).
+        // 3. Return ? RegExpHasFlag(R, cu).
+        reg_exp_has_flag(agent, this_value, RegExpFlags::Y, gc.into_nogc())
     }
 
+    /// ### [22.2.6.16 RegExp.prototype.test ( S )](https://tc39.es/ecma262/#sec-regexp.prototype.test)
     fn test<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.test", gc.into_nogc()))
+        let this_value = this_value.bind(gc.nogc());
+        let s = arguments.get(0).bind(gc.nogc());
+        // 1. Let R be the this value.
+        // 2. If R is not an Object, throw a TypeError exception.
+        let Ok(r) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype.test called on non-object",
+                gc.into_nogc(),
+            ));
+        };
+        // 3. Let string be ? ToString(S).
+        let (r, string) = if let Ok(string) = String::try_from(s) {
+            (r, string)
+        } else {
+            let scoped_r = r.scope(agent, gc.nogc());
+            let string = to_string(agent, s.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            (scoped_r.get(agent).bind(gc.nogc()), string)
+        };
+        // 4. Let match be ? RegExpExec(R, string).
+        let r#match = reg_exp_exec(agent, r.unbind(), string.unbind(), gc)?;
+        // 5. If match is not null, return true; else return false.
+        Ok(r#match.is_some().into())
     }
 
     /// ### [22.2.6.17 RegExp.prototype.toString ( )](https://tc39.es/ecma262/#sec-regexp.prototype.tostring)
@@ -493,16 +1310,15 @@ impl RegExpPrototype {
             // Fast path for RegExp objects: This is not actually proper as it
             // does not take into account prototype mutations.
             let data = &agent[r];
-            let string_length = data.original_source.len(agent);
-            let flags_length = data.original_flags.iter().count();
-            let mut regexp_string =
-                std::string::String::with_capacity(1 + string_length + 1 + flags_length);
+            let source = data.original_source;
+            let flags = data.original_flags;
+            let source = escape_reg_exp_pattern(agent, source, nogc);
+            let string_length = source.len(agent);
+            let mut regexp_string = std::string::String::with_capacity(1 + string_length + 1 + 8);
             regexp_string.push('/');
-            regexp_string.push_str(data.original_source.as_str(agent));
+            regexp_string.push_str(source.as_str(agent));
             regexp_string.push('/');
-            data.original_flags.iter_names().for_each(|(flag, _)| {
-                regexp_string.push_str(flag);
-            });
+            regexp_string.push_str(flags_to_str(flags, &mut [0; 8]));
             return Ok(String::from_string(agent, regexp_string, nogc)
                 .into_value()
                 .unbind());
@@ -543,22 +1359,111 @@ impl RegExpPrototype {
         Ok(result.into_value())
     }
 
+    /// ### [22.2.6.18 get RegExp.prototype.unicode](https://tc39.es/ecma262/#sec-get-regexp.prototype.unicode)
+    ///
+    /// RegExp.prototype.unicode is an accessor property whose set accessor
+    /// function is undefined.
     fn get_unicode<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.unicode", gc.into_nogc()))
+        // 1. Let R be the this value.
+        // 2. Let cu be the code unit 0x0075 (LATIN SMALL LETTER U).
+        // 3. Return ? RegExpHasFlag(R, cu).
+        reg_exp_has_flag(agent, this_value, RegExpFlags::U, gc.into_nogc())
     }
 
+    /// ### [22.2.6.19 get RegExp.prototype.unicodeSets](https://tc39.es/ecma262/#sec-get-regexp.prototype.unicodesets)
+    ///
+    /// RegExp.prototype.unicodeSets is an accessor property whose set accessor
+    /// function is undefined.
     fn get_unicode_sets<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("RegExp.prototype.unicodeSets", gc.into_nogc()))
+        // 1. Let R be the this value.
+        // 2. Let cu be the code unit 0x0076 (LATIN SMALL LETTER V).
+        // 3. Return ? RegExpHasFlag(R, cu).
+        reg_exp_has_flag(agent, this_value, RegExpFlags::V, gc.into_nogc())
+    }
+
+    /// ### [B.2.4.1 RegExp.prototype.compile ( pattern, flags )](https://tc39.es/ecma262/#sec-regexp.prototype.compile)
+    ///
+    /// > #### Note
+    /// >
+    /// > The compile method completely reinitializes the this value RegExp
+    /// > with a new pattern and flags. An implementation may interpret use of
+    /// > this method as an assertion that the resulting RegExp object will be
+    /// > used multiple times and hence is a candidate for extra optimization.
+    #[cfg(feature = "annex-b-regexp")]
+    fn compile<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        let pattern = arguments.get(0).bind(gc.nogc());
+        let flags = arguments.get(1).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[RegExpMatcher]]).
+        let Value::RegExp(o) = this_value else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype.compile called on incompatible receiver",
+                gc.into_nogc(),
+            ));
+        };
+        // 3. Let thisRealm be the current Realm Record.
+        // 4. Let oRealm be the value of O’s [[Realm]] internal slot.
+        // 5. If SameValue(thisRealm, oRealm) is false, throw a TypeError
+        //    exception.
+        if agent[o].realm != Some(agent.current_realm_id_internal()) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype.compile called on a RegExp from another realm",
+                gc.into_nogc(),
+            ));
+        }
+        // 6. If the value of R’s [[LegacyFeaturesEnabled]] internal slot is
+        //    false, throw a TypeError exception.
+        if !agent[o].legacy_features_enabled {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "RegExp.prototype.compile called on a RegExp subclass instance",
+                gc.into_nogc(),
+            ));
+        }
+        // 7. If pattern is an Object and pattern has a [[RegExpMatcher]]
+        //    internal slot, then
+        if let Value::RegExp(pattern) = pattern {
+            // a. If flags is not undefined, throw a TypeError exception.
+            if !flags.is_undefined() {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Cannot supply flags when compiling one RegExp from another",
+                    gc.into_nogc(),
+                ));
+            }
+            // b. Let P be pattern.[[OriginalSource]].
+            let p = agent[pattern].original_source;
+            // c. Let F be pattern.[[OriginalFlags]].
+            let f = agent[pattern].original_flags;
+            // 9. Return ? RegExpInitialize(O, P, F).
+            reg_exp_initialize_from_string(agent, o.unbind(), p, Some(f), gc)
+                .map(|o| o.into_value())
+        } else {
+            // 8. Else,
+            // a. Let P be pattern.
+            // b. Let F be flags.
+            // 9. Return ? RegExpInitialize(O, P, F).
+            reg_exp_initialize(agent, o.unbind(), pattern.unbind(), flags.unbind(), gc)
+                .map(|o| o.into_value())
+        }
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
@@ -567,8 +1472,12 @@ impl RegExpPrototype {
         let this = intrinsics.reg_exp_prototype();
         let reg_exp_constructor = intrinsics.reg_exp();
 
-        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(19)
+        let builder = OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(if cfg!(feature = "annex-b-regexp") {
+                20
+            } else {
+                19
+            })
             .with_prototype(object_prototype)
             .with_constructor_property(reg_exp_constructor)
             .with_builtin_intrinsic_function_property::<RegExpPrototypeExec>()
@@ -588,7 +1497,207 @@ impl RegExpPrototype {
             .with_builtin_function_getter_property::<RegExpPrototypeGetMultiline>()
             .with_builtin_function_property::<RegExpPrototypeReplace>()
             .with_builtin_function_property::<RegExpPrototypeSearch>()
-            .with_builtin_function_property::<RegExpPrototypeSplit>()
-            .build();
+            .with_builtin_function_property::<RegExpPrototypeSplit>();
+
+        #[cfg(feature = "annex-b-regexp")]
+        let builder = builder.with_builtin_function_property::<RegExpPrototypeCompile>();
+
+        builder.build();
+    }
+}
+
+/// Perform ? ToString(? Get(rx, "flags")).
+fn get_flags_string<'a>(
+    agent: &mut Agent,
+    rx: Object,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, String<'a>> {
+    let flags = get(agent, rx, BUILTIN_STRING_MEMORY.flags.into(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    to_string(agent, flags.unbind(), gc)
+}
+
+/// Perform ? ToString(? Get(result, "0")) on a match result.
+pub(super) fn get_match_str<'a>(
+    agent: &mut Agent,
+    result: Object,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, String<'a>> {
+    let match_str = get(agent, result, PropertyKey::from(0u32), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    to_string(agent, match_str.unbind(), gc)
+}
+
+/// Perform ℝ(? ToLength(? Get(rx, "lastIndex"))).
+fn get_last_index<'a>(
+    agent: &mut Agent,
+    rx: Object,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, usize> {
+    let last_index = get(
+        agent,
+        rx,
+        BUILTIN_STRING_MEMORY.lastIndex.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    Ok(to_length(agent, last_index.unbind(), gc)? as usize)
+}
+
+/// Perform ? Set(rx, "lastIndex", 𝔽(index), true).
+fn set_last_index<'a>(
+    agent: &mut Agent,
+    rx: Object,
+    index: usize,
+    gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let index = Value::from_i64(agent, index as i64, gc.nogc());
+    set(
+        agent,
+        rx,
+        BUILTIN_STRING_MEMORY.lastIndex.into(),
+        index.unbind(),
+        true,
+        gc,
+    )
+}
+
+/// Advance the lastIndex of rx past an empty match:
+///
+/// 1. Let thisIndex be ℝ(? ToLength(? Get(rx, "lastIndex"))).
+/// 2. Let nextIndex be AdvanceStringIndex(S, thisIndex, fullUnicode).
+/// 3. Perform ? Set(rx, "lastIndex", 𝔽(nextIndex), true).
+pub(super) fn advance_last_index<'a>(
+    agent: &mut Agent,
+    rx: Object,
+    s: String,
+    full_unicode: bool,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let rx = rx.bind(gc.nogc()).scope(agent, gc.nogc());
+    let s = s.bind(gc.nogc()).scope(agent, gc.nogc());
+    let this_index = get_last_index(agent, rx.get(agent), gc.reborrow()).unbind()?;
+    let next_index = advance_string_index(agent, s.get(agent), this_index, full_unicode);
+    set_last_index(agent, rx.get(agent), next_index, gc)
+}
+
+/// ### [22.2.6.3.1 RegExpHasFlag ( R, codeUnit )](https://tc39.es/ecma262/#sec-regexphasflag)
+///
+/// The abstract operation RegExpHasFlag takes arguments R (an ECMAScript
+/// language value) and codeUnit (a code unit) and returns either a normal
+/// completion containing either a Boolean or undefined, or a throw
+/// completion.
+fn reg_exp_has_flag<'gc>(
+    agent: &mut Agent,
+    r: Value,
+    flag: RegExpFlags,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    // 1. If R is not an Object, throw a TypeError exception.
+    let Ok(r) = Object::try_from(r) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "RegExp flag getter called on non-object",
+            gc,
+        ));
+    };
+    // 2. If R does not have an [[OriginalFlags]] internal slot, then
+    let Object::RegExp(r) = r else {
+        // a. If SameValue(R, %RegExp.prototype%) is true, return undefined.
+        if r == agent
+            .current_realm_record()
+            .intrinsics()
+            .reg_exp_prototype()
+            .into_object()
+        {
+            return Ok(Value::Undefined);
+        }
+        // b. Otherwise, throw a TypeError exception.
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "RegExp flag getter called on incompatible receiver",
+            gc,
+        ));
+    };
+    // 3. Let flags be R.[[OriginalFlags]].
+    // 4. If flags contains codeUnit, return true.
+    // 5. Return false.
+    Ok(agent[r].original_flags.contains(flag).into())
+}
+
+/// ### [22.2.6.13.1 EscapeRegExpPattern ( P, F )](https://tc39.es/ecma262/#sec-escaperegexppattern)
+///
+/// The abstract operation EscapeRegExpPattern takes arguments P (a String)
+/// and F (a String) and returns a String. The returned String is such that
+/// `/${S}/${F}` evaluates to a RegExp object with the same behaviour as the
+/// original: Forward slashes outside character classes and line terminators
+/// are escaped, and the empty pattern is returned as "(?:)".
+fn escape_reg_exp_pattern<'gc>(
+    agent: &mut Agent,
+    p: String,
+    gc: NoGcScope<'gc, '_>,
+) -> String<'gc> {
+    let source = p.as_str(agent);
+    if source.is_empty() {
+        return String::from_static_str(agent, "(?:)", gc);
+    }
+    if !source
+        .chars()
+        .any(|c| matches!(c, '/' | '\n' | '\r' | '\u{2028}' | '\u{2029}'))
+    {
+        return p.bind(gc);
+    }
+    let mut escaped = std::string::String::with_capacity(source.len() + 2);
+    let mut in_class = false;
+    let mut chars = source.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                escaped.push(c);
+                if let Some(next) = chars.next() {
+                    escaped.push(next);
+                }
+            }
+            '[' => {
+                in_class = true;
+                escaped.push(c);
+            }
+            ']' => {
+                in_class = false;
+                escaped.push(c);
+            }
+            '/' if !in_class => escaped.push_str("\\/"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            _ => escaped.push(c),
+        }
+    }
+    String::from_string(agent, escaped, gc)
+}
+
+/// Write the flags in their canonical "dgimsuvy" order into the buffer.
+fn flags_to_str(flags: RegExpFlags, buffer: &mut [u8; 8]) -> &str {
+    let mut i = 0;
+    for (flag, code_unit) in [
+        (RegExpFlags::D, b'd'),
+        (RegExpFlags::G, b'g'),
+        (RegExpFlags::I, b'i'),
+        (RegExpFlags::M, b'm'),
+        (RegExpFlags::S, b's'),
+        (RegExpFlags::U, b'u'),
+        (RegExpFlags::V, b'v'),
+        (RegExpFlags::Y, b'y'),
+    ] {
+        if flags.contains(flag) {
+            buffer[i] = code_unit;
+            i += 1;
+        }
     }
+    // SAFETY: The buffer only contains ASCII characters.
+    unsafe { core::str::from_utf8_unchecked(&buffer[..i]) }
 }
@@ -0,0 +1,450 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::ecmascript::abstract_operations::operations_on_iterator_objects::create_iter_result_object;
+use crate::ecmascript::builtins::Behaviour;
+use crate::ecmascript::builtins::regexp::reg_exp_exec;
+use crate::ecmascript::execution::ProtoIntrinsics;
+use crate::ecmascript::execution::agent::ExceptionType;
+use crate::ecmascript::types::{
+    InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject,
+};
+use crate::engine::context::{Bindable, GcScope, NoGcScope};
+use crate::engine::rootable::{HeapRootData, Scopable};
+use crate::heap::indexes::RegExpStringIteratorIndex;
+use crate::heap::{CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues};
+use crate::{
+    ecmascript::{
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{ArgumentsList, Builtin},
+        execution::{Agent, JsResult, Realm},
+        types::{BUILTIN_STRING_MEMORY, String, Value},
+    },
+    heap::WellKnownSymbolIndexes,
+};
+
+use super::regexp_prototype::{advance_last_index, get_match_str};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct RegExpStringIterator<'a>(RegExpStringIteratorIndex<'a>);
+
+impl<'a> RegExpStringIterator<'a> {
+    /// ### [22.2.9.1 CreateRegExpStringIterator ( R, S, global, fullUnicode )](https://tc39.es/ecma262/#sec-createregexpstringiterator)
+    ///
+    /// The abstract operation CreateRegExpStringIterator takes arguments R
+    /// (an Object), S (a String), global (a Boolean), and fullUnicode (a
+    /// Boolean) and returns an Object.
+    pub(crate) fn create(
+        agent: &mut Agent,
+        r: Object,
+        s: String,
+        global: bool,
+        full_unicode: bool,
+        gc: NoGcScope<'a, '_>,
+    ) -> RegExpStringIterator<'a> {
+        agent
+            .heap
+            .create(RegExpStringIteratorHeapData {
+                backing_object: None,
+                iterating_reg_exp: Some(r.unbind()),
+                iterated_string: s.unbind(),
+                global,
+                full_unicode,
+            })
+            .bind(gc)
+    }
+
+    /// # Do not use this
+    /// This is only for Value discriminant creation.
+    pub(crate) const fn _def() -> Self {
+        Self(RegExpStringIteratorIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    pub(crate) fn get_data(self, agent: &Agent) -> &RegExpStringIteratorHeapData {
+        agent
+            .heap
+            .regexp_string_iterators
+            .get(self.get_index())
+            .expect("RegExpStringIterator use-after-free")
+            .as_ref()
+            .expect("RegExpStringIterator deleted")
+    }
+
+    pub(crate) fn get_data_mut(
+        self,
+        agent: &mut Agent,
+    ) -> &mut RegExpStringIteratorHeapData<'static> {
+        agent
+            .heap
+            .regexp_string_iterators
+            .get_mut(self.get_index())
+            .expect("RegExpStringIterator use-after-free")
+            .as_mut()
+            .expect("RegExpStringIterator deleted")
+    }
+
+    /// Set the iterator to its completed state, releasing the RegExp and
+    /// String.
+    fn complete(self, agent: &mut Agent) {
+        let data = self.get_data_mut(agent);
+        data.iterating_reg_exp = None;
+        data.iterated_string = String::EMPTY_STRING;
+    }
+}
+
+impl<'a> IntoValue<'a> for RegExpStringIterator<'a> {
+    fn into_value(self) -> Value<'a> {
+        Value::RegExpStringIterator(self)
+    }
+}
+
+impl<'a> IntoObject<'a> for RegExpStringIterator<'a> {
+    fn into_object(self) -> Object<'a> {
+        Object::RegExpStringIterator(self)
+    }
+}
+
+impl<'a> From<RegExpStringIterator<'a>> for Object<'a> {
+    fn from(iter: RegExpStringIterator<'a>) -> Self {
+        iter.into_object()
+    }
+}
+
+impl<'a> From<RegExpStringIterator<'a>> for Value<'a> {
+    fn from(iter: RegExpStringIterator<'a>) -> Self {
+        iter.into_value()
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for RegExpStringIterator<'a> {
+    type Error = ();
+
+    fn try_from(value: Value<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Value::RegExpStringIterator(iter) => Ok(iter),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for RegExpStringIterator<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::RegExpStringIterator(iter) => Ok(iter),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<HeapRootData> for RegExpStringIterator<'_> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: HeapRootData) -> Result<Self, Self::Error> {
+        if let HeapRootData::RegExpStringIterator(value) = value {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for RegExpStringIterator<'_> {
+    type Of<'a> = RegExpStringIterator<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> InternalSlots<'a> for RegExpStringIterator<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::RegExpStringIterator;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get_data(agent).backing_object.unbind()
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_data_mut(agent)
+                .backing_object
+                .replace(backing_object)
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for RegExpStringIterator<'a> {}
+
+impl HeapMarkAndSweep for RegExpStringIterator<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.regexp_string_iterators.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.regexp_string_iterators.shift_index(&mut self.0);
+    }
+}
+
+pub(crate) struct RegExpStringIteratorPrototype;
+
+struct RegExpStringIteratorPrototypeNext;
+impl Builtin for RegExpStringIteratorPrototypeNext {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.next;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(RegExpStringIteratorPrototype::next);
+}
+
+impl RegExpStringIteratorPrototype {
+    /// ### [22.2.9.2.1 %RegExpStringIteratorPrototype%.next ( )](https://tc39.es/ecma262/#sec-%regexpstringiteratorprototype%.next)
+    fn next<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Return ? GeneratorResume(this value, empty, "%RegExpStringIteratorPrototype%").
+        // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
+        let Value::RegExpStringIterator(generator) = this_value else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "not a RegExp string iterator",
+                gc.into_nogc(),
+            ));
+        };
+        // 2. If state is completed, return CreateIteratorResultObject(undefined, true).
+        let RegExpStringIteratorHeapData {
+            iterating_reg_exp,
+            iterated_string,
+            global,
+            full_unicode,
+            ..
+        } = generator.get_data(agent).unbind().bind(gc.nogc());
+        let Some(r) = iterating_reg_exp else {
+            return Ok(
+                create_iter_result_object(agent, Value::Undefined, true, gc.into_nogc())
+                    .into_value(),
+            );
+        };
+        // 3. Assert: state is either suspended-start or suspended-yield.
+        // Note: If the closure returns an abrupt completion, the generator
+        // is completed before the completion is propagated.
+        let generator = generator.scope(agent, gc.nogc());
+        let result = reg_exp_string_iterator_step(
+            agent,
+            r.unbind(),
+            iterated_string.unbind(),
+            global,
+            full_unicode,
+            gc.reborrow(),
+        )
+        .unbind();
+        let gc = gc.into_nogc();
+        match result.bind(gc) {
+            Ok(Some(r#match)) => {
+                // iii. If global is false, then
+                if !global {
+                    // 2. Return undefined.
+                    generator.get(agent).complete(agent);
+                }
+                // 1. Perform ? GeneratorYield(CreateIteratorResultObject(match, false)).
+                // vi. Perform ? GeneratorYield(CreateIteratorResultObject(match, false)).
+                Ok(create_iter_result_object(agent, r#match.into_value(), false, gc).into_value())
+            }
+            Ok(None) => {
+                // ii. If match is null, return undefined.
+                generator.get(agent).complete(agent);
+                Ok(create_iter_result_object(agent, Value::Undefined, true, gc).into_value())
+            }
+            Err(err) => {
+                generator.get(agent).complete(agent);
+                Err(err)
+            }
+        }
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.reg_exp_string_iterator_prototype();
+        let iterator_prototype = intrinsics.iterator_prototype();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(2)
+            .with_prototype(iterator_prototype)
+            .with_builtin_function_property::<RegExpStringIteratorPrototypeNext>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.RegExp_String_Iterator.into_value())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// ### [22.2.9.1 CreateRegExpStringIterator ( R, S, global, fullUnicode )](https://tc39.es/ecma262/#sec-createregexpstringiterator)
+///
+/// A single step of the closure created in step 1 of
+/// CreateRegExpStringIterator: Returns the next match, or None if the
+/// iterator is done.
+fn reg_exp_string_iterator_step<'a>(
+    agent: &mut Agent,
+    r: Object,
+    s: String,
+    global: bool,
+    full_unicode: bool,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Object<'a>>> {
+    let r = r.bind(gc.nogc());
+    let s = s.bind(gc.nogc());
+    let scoped_r = r.scope(agent, gc.nogc());
+    let scoped_s = s.scope(agent, gc.nogc());
+    // a. Repeat,
+    // i. Let match be ? RegExpExec(R, S).
+    let Some(r#match) = reg_exp_exec(agent, r.unbind(), s.unbind(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc())
+    else {
+        // ii. If match is null, return undefined.
+        return Ok(None);
+    };
+    // iii. If global is false, then
+    if !global {
+        // 1. Perform ? GeneratorYield(CreateIteratorResultObject(match, false)).
+        return Ok(Some(r#match.unbind().bind(gc.into_nogc())));
+    }
+    let scoped_match = r#match.scope(agent, gc.nogc());
+    // iv. Let matchStr be ? ToString(? Get(match, "0")).
+    let match_str = get_match_str(agent, r#match.unbind(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // v. If matchStr is the empty String, then
+    if match_str.is_empty_string() {
+        // 1. Let thisIndex be ℝ(? ToLength(? Get(R, "lastIndex"))).
+        // 2. Let nextIndex be AdvanceStringIndex(S, thisIndex, fullUnicode).
+        // 3. Perform ? Set(R, "lastIndex", 𝔽(nextIndex), true).
+        advance_last_index(
+            agent,
+            scoped_r.get(agent),
+            scoped_s.get(agent),
+            full_unicode,
+            gc.reborrow(),
+        )
+        .unbind()?;
+    }
+    // vi. Perform ? GeneratorYield(CreateIteratorResultObject(match, false)).
+    Ok(Some(scoped_match.get(agent).bind(gc.into_nogc())))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RegExpStringIteratorHeapData<'a> {
+    backing_object: Option<OrdinaryObject<'a>>,
+    /// \[\[IteratingRegExp]]
+    ///
+    /// None once the iterator has completed.
+    iterating_reg_exp: Option<Object<'a>>,
+    /// \[\[IteratedString]]
+    iterated_string: String<'a>,
+    /// \[\[Global]]
+    global: bool,
+    /// \[\[Unicode]]
+    full_unicode: bool,
+}
+
+impl Default for RegExpStringIteratorHeapData<'_> {
+    fn default() -> Self {
+        Self {
+            backing_object: None,
+            iterating_reg_exp: None,
+            iterated_string: String::EMPTY_STRING,
+            global: false,
+            full_unicode: false,
+        }
+    }
+}
+
+// SAFETY: Trivially safe.
+unsafe impl Bindable for RegExpStringIteratorHeapData<'_> {
+    type Of<'a> = RegExpStringIteratorHeapData<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        RegExpStringIteratorHeapData {
+            backing_object: self.backing_object.unbind(),
+            iterating_reg_exp: self.iterating_reg_exp.unbind(),
+            iterated_string: self.iterated_string.unbind(),
+            global: self.global,
+            full_unicode: self.full_unicode,
+        }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        RegExpStringIteratorHeapData {
+            backing_object: self.backing_object.bind(gc),
+            iterating_reg_exp: self.iterating_reg_exp.bind(gc),
+            iterated_string: self.iterated_string.bind(gc),
+            global: self.global,
+            full_unicode: self.full_unicode,
+        }
+    }
+}
+
+impl<'a> CreateHeapData<RegExpStringIteratorHeapData<'a>, RegExpStringIterator<'a>> for Heap {
+    fn create(&mut self, data: RegExpStringIteratorHeapData<'a>) -> RegExpStringIterator<'a> {
+        self.regexp_string_iterators.push(Some(data.unbind()));
+        self.alloc_counter += core::mem::size_of::<Option<RegExpStringIteratorHeapData<'static>>>();
+        RegExpStringIterator(RegExpStringIteratorIndex::last(
+            &self.regexp_string_iterators,
+        ))
+    }
+}
+
+impl HeapMarkAndSweep for RegExpStringIteratorHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            backing_object,
+            iterating_reg_exp,
+            iterated_string,
+            global: _,
+            full_unicode: _,
+        } = self;
+        backing_object.mark_values(queues);
+        iterating_reg_exp.mark_values(queues);
+        iterated_string.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            backing_object,
+            iterating_reg_exp,
+            iterated_string,
+            global: _,
+            full_unicode: _,
+        } = self;
+        backing_object.sweep_values(compactions);
+        iterating_reg_exp.sweep_values(compactions);
+        iterated_string.sweep_values(compactions);
+    }
+}
@@ -21,7 +21,9 @@ use crate::engine::rootable::Scopable;
 use crate::{
     ecmascript::{
         abstract_operations::{
-            operations_on_objects::{call_function, create_array_from_list, get_method},
+            operations_on_objects::{
+                call_function, create_array_from_list, get, get_method, get_v,
+            },
             testing_and_comparison::{is_callable, require_object_coercible},
             type_conversion::{
                 is_trimmable_whitespace, to_integer_or_infinity, to_length, to_number, to_string,
@@ -34,12 +36,18 @@ use crate::{
             primitive_objects::{PrimitiveObjectData, PrimitiveObjectHeapData},
         },
         execution::{Agent, JsResult, Realm, agent::ExceptionType},
-        types::{BUILTIN_STRING_MEMORY, IntoValue, Number, PropertyKey, String, Value},
+        types::{BUILTIN_STRING_MEMORY, IntoValue, Number, Object, PropertyKey, String, Value},
     },
     heap::{IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
 };
 
 use super::string_iterator_objects::StringIterator;
+#[cfg(feature = "regexp")]
+use crate::ecmascript::{
+    abstract_operations::operations_on_objects::invoke, builtins::regexp::reg_exp_create,
+};
+#[cfg(feature = "regexp")]
+use oxc_ast::ast::RegExpFlags;
 
 pub(crate) struct StringPrototype;
 
@@ -1065,22 +1073,40 @@ impl StringPrototype {
         Err(agent.todo("String.prototype.localeCompare", gc.into_nogc()))
     }
 
+    /// ### [22.1.3.13 String.prototype.match ( regexp )](https://tc39.es/ecma262/#sec-string.prototype.match)
     fn r#match<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("String.prototype.match", gc.into_nogc()))
+        string_reg_exp_method(
+            agent,
+            this_value,
+            arguments.get(0),
+            WellKnownSymbolIndexes::Match,
+            gc,
+        )
     }
 
+    /// ### [22.1.3.14 String.prototype.matchAll ( regexp )](https://tc39.es/ecma262/#sec-string.prototype.matchall)
+    ///
+    /// This method performs a regular expression match of the String
+    /// representing the this value against regexp and returns an iterator
+    /// that yields match results.
     fn match_all<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("String.prototype.matchAll", gc.into_nogc()))
+        string_reg_exp_method(
+            agent,
+            this_value,
+            arguments.get(0),
+            WellKnownSymbolIndexes::MatchAll,
+            gc,
+        )
     }
 
     /// ### [22.1.3.15 String.prototype.normalize ( \[ form \] )](https://tc39.es/ecma262/#sec-string.prototype.normalize)
@@ -1375,12 +1401,39 @@ impl StringPrototype {
             .unbind()?
             .bind(gc.nogc());
 
-        // Everything are strings: `"foo".replace("o", "a")` => use rust's replace
-        let result = s.as_str(agent).replacen(
-            search_string_root.as_str(agent),
-            replace_string.as_str(agent),
-            1,
-        );
+        let replace_string = replace_string.as_str(agent).to_owned();
+        let s = s.get(agent).bind(gc.nogc());
+        let subject = s.as_str(agent);
+        let matched = search_string_root.get(agent).as_str(agent).to_owned();
+        // 8. Let position be StringIndexOf(s, searchString, 0).
+        let Some(position) = subject.find(&matched) else {
+            // 9. If position is not-found, return s.
+            return Ok(s.unbind().into_value());
+        };
+        // 10. Let preceding be the substring of s from 0 to position.
+        let preceding = subject[..position].to_owned();
+        // 11. Let following be the substring of s from position + searchLength.
+        let following = subject[position + matched.len()..].to_owned();
+        let str = subject.encode_utf16().collect::<Vec<u16>>();
+        let position = s.utf16_index(agent, position);
+        // 13. Else,
+        // a. Assert: replaceValue is a String.
+        // b. Let captures be a new empty List.
+        // c. Let replacement be ! GetSubstitution(searchString, string, position, captures, undefined, replaceValue).
+        let replacement = get_substitution(
+            agent,
+            &matched,
+            &str,
+            position,
+            &[],
+            None,
+            &replace_string,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // 14. Return the string-concatenation of preceding, replacement, and following.
+        let result = format!("{}{}{}", preceding, replacement.as_str(agent), following);
         Ok(String::from_string(agent, result, gc.into_nogc()).into_value())
     }
 
@@ -1405,19 +1458,23 @@ impl StringPrototype {
         // 2. If searchValue is neither undefined nor null, then
         if !search_value.is_null() && !search_value.is_undefined() {
             // a. Let isRegExp be ? IsRegExp(searchValue).
-            let is_reg_exp = false;
-
             // b. If isRegExp is true, then
-            if is_reg_exp {
+            if is_reg_exp(agent, search_value.unbind(), gc.reborrow()).unbind()? {
                 // i. Let flags be ? Get(searchValue, "flags").
                 // ii. Perform ? RequireObjectCoercible(flags).
                 // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
-                todo!();
+                require_global_flags(
+                    agent,
+                    scoped_search_value.get(agent),
+                    "String.prototype.replaceAll called with a non-global RegExp",
+                    gc.reborrow(),
+                )
+                .unbind()?;
             }
 
             // c. Let replacer be ? GetMethod(searchValue, %Symbol.replace%).
             let symbol = WellKnownSymbolIndexes::Replace.into();
-            let replacer = get_method(agent, search_value.unbind(), symbol, gc.reborrow())
+            let replacer = get_method(agent, scoped_search_value.get(agent), symbol, gc.reborrow())
                 .unbind()?
                 .bind(gc.nogc());
 
@@ -1525,22 +1582,97 @@ impl StringPrototype {
         let replace_string = to_string(agent, replace_value.get(agent), gc.reborrow())
             .unbind()?
             .bind(gc.nogc());
-        // Everything are strings: `"foo".replaceAll("o", "a")` => use rust's replace
         search_string = search_string_root.get(agent).bind(gc.nogc());
-        let s = s.get(agent).bind(gc.nogc());
-        let result = s
-            .as_str(agent)
-            .replace(search_string.as_str(agent), replace_string.as_str(agent));
+        if !replace_string.as_str(agent).contains('$') {
+            // Everything are strings and the replacement contains no
+            // substitutions: `"foo".replaceAll("o", "a")` => use rust's replace
+            let result = s
+                .get(agent)
+                .as_str(agent)
+                .replace(search_string.as_str(agent), replace_string.as_str(agent));
+            return Ok(String::from_string(agent, result, gc.into_nogc()).into_value());
+        }
+        let replace_string = replace_string.as_str(agent).to_owned();
+        let matched = search_string.as_str(agent).to_owned();
+        let subject = s.get(agent).as_str(agent).to_owned();
+        let str = subject.encode_utf16().collect::<Vec<u16>>();
+
+        // 7. Let searchLength be the length of searchString.
+        let search_length = matched.len();
+        // 9. Let matchPositions be a new empty List.
+        let mut match_positions: Vec<usize> = vec![];
+        // 10. Let position be StringIndexOf(string, searchString, 0).
+        let mut position = 0;
+        // 11. Repeat, while position is not not-found,
+        while let Some(pos) = subject.get(position..).and_then(|rest| rest.find(&matched)) {
+            // a. Append position to matchPositions.
+            let p = position + pos;
+            match_positions.push(p);
+            // b. Set position to StringIndexOf(string, searchString, position + advanceBy).
+            // Note: 8. Let advanceBy be max(1, searchLength). An empty
+            // searchString advances by a whole character.
+            let advance_by = if search_length == 0 {
+                subject[p..].chars().next().map_or(1, char::len_utf8)
+            } else {
+                search_length
+            };
+            position = p + advance_by;
+        }
+
+        // 12. Let endOfLastMatch be 0.
+        let mut end_of_last_match = 0;
+        // 13. Let result be the empty String.
+        let mut result = std::string::String::with_capacity(subject.len());
+        // 14. For each element p of matchPositions, do
+        for p in match_positions {
+            // a. Let preserved be the substring of string from endOfLastMatch to p.
+            let preserved = &subject[end_of_last_match..p];
+            // c. Else,
+            // i. Assert: replaceValue is a String.
+            // ii. Let captures be a new empty List.
+            // iii. Let replacement be ! GetSubstitution(searchString, string, p, captures, undefined, replaceValue).
+            let position = s.get(agent).utf16_index(agent, p);
+            let replacement = get_substitution(
+                agent,
+                &matched,
+                &str,
+                position,
+                &[],
+                None,
+                &replace_string,
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // d. Set result to the string-concatenation of result, preserved, and replacement.
+            result.push_str(preserved);
+            result.push_str(replacement.as_str(agent));
+            // e. Set endOfLastMatch to p + searchLength.
+            end_of_last_match = p + search_length;
+        }
+        // 15. If endOfLastMatch < the length of string, then
+        if end_of_last_match < subject.len() {
+            // a. Set result to the string-concatenation of result and the substring of string from endOfLastMatch.
+            result.push_str(&subject[end_of_last_match..]);
+        }
+        // 16. Return result.
         Ok(String::from_string(agent, result, gc.into_nogc()).into_value())
     }
 
+    /// ### [22.1.3.23 String.prototype.search ( regexp )](https://tc39.es/ecma262/#sec-string.prototype.search)
     fn search<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
+        this_value: Value,
+        arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("String.prototype.search", gc.into_nogc()))
+        string_reg_exp_method(
+            agent,
+            this_value,
+            arguments.get(0),
+            WellKnownSymbolIndexes::Search,
+            gc,
+        )
     }
 
     fn slice<'gc>(
@@ -2573,6 +2705,331 @@ impl StringPrototype {
 /// fillString (an ECMAScript language value), and placement (start or end)
 /// and returns either a normal completion containing a String or a throw
 /// completion.
+/// Shared steps of String.prototype.match, String.prototype.matchAll, and
+/// String.prototype.search: If regexp has a method for the given well-known
+/// symbol then the call is delegated to it, otherwise a new RegExp is created
+/// from regexp and its method is invoked on the this value as a String.
+fn string_reg_exp_method<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    regexp: Value,
+    symbol: WellKnownSymbolIndexes,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let nogc = gc.nogc();
+    let this_value = this_value.bind(nogc);
+    let regexp = regexp.bind(nogc);
+    let match_all = matches!(symbol, WellKnownSymbolIndexes::MatchAll);
+    // 1. Let O be ? RequireObjectCoercible(this value).
+    let o = require_object_coercible(agent, this_value, nogc)
+        .unbind()?
+        .scope(agent, nogc);
+    let scoped_regexp = regexp.scope(agent, nogc);
+    // 2. If regexp is neither undefined nor null, then
+    if !regexp.is_undefined() && !regexp.is_null() {
+        // a. Let isRegExp be ? IsRegExp(regexp).
+        // b. If isRegExp is true, then
+        if match_all && is_reg_exp(agent, regexp.unbind(), gc.reborrow()).unbind()? {
+            // i. Let flags be ? Get(regexp, "flags").
+            // ii. Perform ? RequireObjectCoercible(flags).
+            // iii. If ? ToString(flags) does not contain "g", throw a
+            //      TypeError exception.
+            require_global_flags(
+                agent,
+                scoped_regexp.get(agent),
+                "String.prototype.matchAll called with a non-global RegExp",
+                gc.reborrow(),
+            )
+            .unbind()?;
+        }
+        // a. Let matcher be ? GetMethod(regexp, %Symbol.match%).
+        let matcher = get_method(
+            agent,
+            scoped_regexp.get(agent),
+            symbol.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // b. If matcher is not undefined, then
+        if let Some(matcher) = matcher {
+            // i. Return ? Call(matcher, regexp, « O »).
+            return call_function(
+                agent,
+                matcher.unbind(),
+                scoped_regexp.get(agent),
+                Some(ArgumentsList::from_mut_slice(&mut [o.get(agent)])),
+                gc,
+            );
+        }
+    }
+    // 3. Let S be ? ToString(O).
+    let s = to_string(agent, o.get(agent), gc.reborrow())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    #[cfg(feature = "regexp")]
+    {
+        // 4. Let rx be ? RegExpCreate(regexp, undefined).
+        // 4. Let rx be ? RegExpCreate(regexp, "g").
+        let flags = match_all.then_some(RegExpFlags::G);
+        let rx = reg_exp_create(agent, scoped_regexp.get(agent), flags, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // 5. Return ? Invoke(rx, %Symbol.match%, « S »).
+        invoke(
+            agent,
+            rx.into_value().unbind(),
+            symbol.into(),
+            Some(ArgumentsList::from_mut_slice(&mut [s
+                .get(agent)
+                .into_value()])),
+            gc,
+        )
+    }
+    #[cfg(not(feature = "regexp"))]
+    {
+        let _ = s;
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "RegExp support is not enabled",
+            gc.into_nogc(),
+        ))
+    }
+}
+
+/// Perform the flags check of String.prototype.matchAll and
+/// String.prototype.replaceAll on a RegExp searchValue: Get its "flags",
+/// require them to be coercible to an object, and throw a TypeError if
+/// ToString of them does not contain "g".
+fn require_global_flags<'gc>(
+    agent: &mut Agent,
+    search_value: Value,
+    message: &'static str,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let search_value = search_value.bind(gc.nogc());
+    // i. Let flags be ? Get(searchValue, "flags").
+    let flags = get_v(
+        agent,
+        search_value.unbind(),
+        BUILTIN_STRING_MEMORY.flags.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // ii. Perform ? RequireObjectCoercible(flags).
+    let flags = require_object_coercible(agent, flags, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // iii. If ? ToString(flags) does not contain "g", throw a TypeError
+    //      exception.
+    let flags = to_string(agent, flags.unbind(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    if !flags.as_str(agent).contains('g') {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            message,
+            gc.into_nogc(),
+        ));
+    }
+    Ok(())
+}
+
+/// ### [22.1.3.19.1 GetSubstitution ( matched, str, position, captures, namedCaptures, replacementTemplate )](https://tc39.es/ecma262/#sec-getsubstitution)
+///
+/// The abstract operation GetSubstitution takes arguments matched (a String),
+/// str (a String), position (a non-negative integer), captures (a List of
+/// either Strings or undefined), namedCaptures (an Object or undefined), and
+/// replacementTemplate (a String) and returns either a normal completion
+/// containing a String or a throw completion. For the purposes of this
+/// abstract operation, a decimal digit is a code unit in the inclusive
+/// interval from 0x0030 (DIGIT ZERO) to 0x0039 (DIGIT NINE).
+///
+/// The str is given as UTF-16 code units as position is a code unit index.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn get_substitution<'gc>(
+    agent: &mut Agent,
+    matched: &str,
+    str: &[u16],
+    position: usize,
+    captures: &[Option<std::string::String>],
+    named_captures: Option<Object>,
+    replacement_template: &str,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, String<'gc>> {
+    let named_captures = named_captures.map(|o| o.scope(agent, gc.nogc()));
+    // 1. Let stringLength be the length of str.
+    let string_length = str.len();
+    // 2. Assert: position ≤ stringLength.
+    debug_assert!(position <= string_length);
+    // 3. Let result be the empty String.
+    let mut result = std::string::String::with_capacity(replacement_template.len());
+    // 4. Let templateRemainder be replacementTemplate.
+    let mut template_remainder = replacement_template;
+    // 5. Repeat, while templateRemainder is not the empty String,
+    while !template_remainder.is_empty() {
+        // a. NOTE: The following steps isolate ref (a prefix of
+        //    templateRemainder), determine refReplacement (its replacement),
+        //    and then append that replacement to result.
+        let bytes = template_remainder.as_bytes();
+        let ref_length = match bytes {
+            // b. If templateRemainder starts with "$$", then
+            [b'$', b'$', ..] => {
+                // i. Let ref be "$$".
+                // ii. Let refReplacement be "$".
+                result.push('$');
+                2
+            }
+            // c. Else if templateRemainder starts with "$`", then
+            [b'$', b'`', ..] => {
+                // i. Let ref be "$`".
+                // ii. Let refReplacement be the substring of str from 0 to
+                //     position.
+                result.push_str(&std::string::String::from_utf16_lossy(&str[..position]));
+                2
+            }
+            // d. Else if templateRemainder starts with "$&", then
+            [b'$', b'&', ..] => {
+                // i. Let ref be "$&".
+                // ii. Let refReplacement be matched.
+                result.push_str(matched);
+                2
+            }
+            // e. Else if templateRemainder starts with "$'" (0x0024 (DOLLAR
+            //    SIGN) followed by 0x0027 (APOSTROPHE)), then
+            [b'$', b'\'', ..] => {
+                // i. Let ref be "$'".
+                // ii. Let matchLength be the length of matched.
+                let match_length = matched.encode_utf16().count();
+                // iii. Let tailPos be position + matchLength.
+                let tail_pos = position + match_length;
+                // iv. Let refReplacement be the substring of str from
+                //     min(tailPos, stringLength).
+                // v. NOTE: tailPos can exceed stringLength only if this
+                //    abstract operation was invoked by a call to the
+                //    intrinsic %Symbol.replace% method of %RegExp.prototype%
+                //    on an object whose "exec" property is not the intrinsic
+                //    %RegExp.prototype.exec%.
+                result.push_str(&std::string::String::from_utf16_lossy(
+                    &str[tail_pos.min(string_length)..],
+                ));
+                2
+            }
+            // f. Else if templateRemainder starts with "$" followed by 1 or
+            //    more decimal digits, then
+            [b'$', first @ b'0'..=b'9', rest @ ..] => {
+                let first = (first - b'0') as usize;
+                // i. If templateRemainder starts with "$" followed by 2 or
+                //    more decimal digits, let digitCount be 2. Otherwise,
+                //    let digitCount be 1.
+                // ii. Let digits be the substring of templateRemainder from 1
+                //     to 1 + digitCount.
+                // iii. Let index be ℝ(StringToNumber(digits)).
+                let (mut digit_count, mut index) = match rest.first() {
+                    Some(second @ b'0'..=b'9') => (2, first * 10 + (second - b'0') as usize),
+                    _ => (1, first),
+                };
+                // iv. Assert: 0 ≤ index ≤ 99.
+                // v. Let captureLen be the number of elements in captures.
+                let capture_len = captures.len();
+                // vi. If index > captureLen and digitCount = 2, then
+                if index > capture_len && digit_count == 2 {
+                    // 1. NOTE: When a two-digit replacement pattern specifies
+                    //    an index exceeding the count of capturing groups, it
+                    //    is reinterpreted as a one-digit replacement pattern
+                    //    followed by a literal digit.
+                    // 2. Set digitCount to 1.
+                    digit_count = 1;
+                    // 3. Set digits to the substring of digits from 0 to 1.
+                    // 4. Set index to ℝ(StringToNumber(digits)).
+                    index = first;
+                }
+                // vii. Let ref be the substring of templateRemainder from 0
+                //      to 1 + digitCount.
+                let r#ref = &template_remainder[..1 + digit_count];
+                // viii. If 1 ≤ index ≤ captureLen, then
+                if (1..=capture_len).contains(&index) {
+                    // 1. Let capture be captures[index - 1].
+                    // 2. If capture is undefined, then
+                    //     a. Let refReplacement be the empty String.
+                    // 3. Else,
+                    //     a. Let refReplacement be capture.
+                    if let Some(capture) = &captures[index - 1] {
+                        result.push_str(capture);
+                    }
+                } else {
+                    // ix. Else,
+                    // 1. Let refReplacement be ref.
+                    result.push_str(r#ref);
+                }
+                1 + digit_count
+            }
+            // g. Else if templateRemainder starts with "$<", then
+            [b'$', b'<', ..] => {
+                // i. Let gtPos be StringIndexOf(templateRemainder, ">", 0).
+                let gt_pos = template_remainder.find('>');
+                match (gt_pos, &named_captures) {
+                    // iii. Else,
+                    (Some(gt_pos), Some(named_captures)) => {
+                        // 1. Let ref be the substring of templateRemainder
+                        //    from 0 to gtPos + 1.
+                        // 2. Let groupName be the substring of
+                        //    templateRemainder from 2 to gtPos.
+                        let group_name = &template_remainder[2..gt_pos];
+                        // 3. Assert: namedCaptures is an Object.
+                        // 4. Let capture be ? Get(namedCaptures, groupName).
+                        let group_name = PropertyKey::from_str(agent, group_name, gc.nogc());
+                        let capture = get(
+                            agent,
+                            named_captures.get(agent),
+                            group_name.unbind(),
+                            gc.reborrow(),
+                        )
+                        .unbind()?
+                        .bind(gc.nogc());
+                        // 5. If capture is undefined, then
+                        //     a. Let refReplacement be the empty String.
+                        // 6. Else,
+                        if !capture.is_undefined() {
+                            // a. Let refReplacement be ? ToString(capture).
+                            let capture = to_string(agent, capture.unbind(), gc.reborrow())
+                                .unbind()?
+                                .bind(gc.nogc());
+                            result.push_str(capture.as_str(agent));
+                        }
+                        gt_pos + 1
+                    }
+                    // ii. If gtPos is not-found or namedCaptures is
+                    //     undefined, then
+                    _ => {
+                        // 1. Let ref be "$<".
+                        // 2. Let refReplacement be ref.
+                        result.push_str("$<");
+                        2
+                    }
+                }
+            }
+            // h. Else,
+            _ => {
+                // i. Let ref be the substring of templateRemainder from 0 to 1.
+                // ii. Let refReplacement be ref.
+                let c = template_remainder.chars().next().unwrap();
+                result.push(c);
+                c.len_utf8()
+            }
+        };
+        // i. Let refLength be the length of ref.
+        // j. Set templateRemainder to the substring of templateRemainder from
+        //    refLength.
+        template_remainder = &template_remainder[ref_length..];
+        // k. Set result to the string-concatenation of result and
+        //    refReplacement.
+    }
+    // 6. Return result.
+    Ok(String::from_string(agent, result, gc.into_nogc()))
+}
+
 fn string_padding_builtins_impl<'gc>(
     agent: &mut Agent,
     o: Value,
@@ -37,8 +37,9 @@ use crate::ecmascript::types::FLOAT_16_ARRAY_DISCRIMINANT;
 use self::data::TypedArrayHeapData;
 
 use super::{
-    ArrayBuffer,
-    array_buffer::{Ordering, ViewedArrayBufferByteLength, ViewedArrayBufferByteOffset},
+    array_buffer::{
+        AnyArrayBuffer, Ordering, ViewedArrayBufferByteLength, ViewedArrayBufferByteOffset,
+    },
     indexed_collections::typed_array_objects::abstract_operations::{
         is_typed_array_fixed_length, is_typed_array_out_of_bounds, is_valid_integer_index_generic,
         make_typed_array_with_buffer_witness_record, try_typed_array_set_element_generic,
@@ -164,7 +165,7 @@ impl<'a> TypedArray<'a> {
         self,
         agent: &Agent,
         _: NoGcScope<'gc, '_>,
-    ) -> ArrayBuffer<'gc> {
+    ) -> AnyArrayBuffer<'gc> {
         agent[self].viewed_array_buffer
     }
 
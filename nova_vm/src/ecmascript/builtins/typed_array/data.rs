@@ -4,9 +4,8 @@
 
 use crate::{
     ecmascript::{
-        builtins::{
-            ArrayBuffer,
-            array_buffer::{ViewedArrayBufferByteLength, ViewedArrayBufferByteOffset},
+        builtins::array_buffer::{
+            AnyArrayBuffer, ArrayBuffer, ViewedArrayBufferByteLength, ViewedArrayBufferByteOffset,
         },
         types::OrdinaryObject,
     },
@@ -70,7 +69,7 @@ impl From<usize> for TypedArrayArrayLength {
 pub struct TypedArrayHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
     /// ### [\[\[ViewedArrayBuffer\]\]](https://tc39.es/ecma262/#sec-properties-of-typedarray-instances)
-    pub(crate) viewed_array_buffer: ArrayBuffer<'a>,
+    pub(crate) viewed_array_buffer: AnyArrayBuffer<'a>,
     /// ### [\[\[ByteLength\]\]](https://tc39.es/ecma262/#sec-properties-of-typedarray-instances)
     pub(crate) byte_length: ViewedArrayBufferByteLength,
     /// ### [\[\[ByteOffset\]\]](https://tc39.es/ecma262/#sec-properties-of-typedarray-instances)
@@ -83,7 +82,7 @@ impl<'a> TypedArrayHeapData<'a> {
     pub fn new(object_index: Option<OrdinaryObject<'a>>) -> Self {
         Self {
             object_index,
-            viewed_array_buffer: ArrayBuffer::_def(),
+            viewed_array_buffer: AnyArrayBuffer::ArrayBuffer(ArrayBuffer::_def()),
             byte_length: Default::default(),
             byte_offset: Default::default(),
             array_length: Default::default(),
@@ -95,7 +94,7 @@ impl Default for TypedArrayHeapData<'_> {
     fn default() -> Self {
         Self {
             object_index: Default::default(),
-            viewed_array_buffer: ArrayBuffer::_def(),
+            viewed_array_buffer: AnyArrayBuffer::ArrayBuffer(ArrayBuffer::_def()),
             byte_length: Default::default(),
             byte_offset: Default::default(),
             array_length: Default::default(),
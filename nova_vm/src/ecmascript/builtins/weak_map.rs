@@ -79,6 +79,17 @@ impl<'a> From<WeakMap<'a>> for Object<'a> {
     }
 }
 
+impl<'a> TryFrom<Object<'a>> for WeakMap<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::WeakMap(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
 impl<'a> InternalSlots<'a> for WeakMap<'a> {
     const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::WeakMap;
 
@@ -2,28 +2,46 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use ahash::AHashMap;
+
 use crate::{
-    ecmascript::types::{OrdinaryObject, Value},
+    ecmascript::{
+        execution::WeakKey,
+        types::{OrdinaryObject, Value},
+    },
     engine::context::{Bindable, NoGcScope},
-    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+    heap::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues},
 };
 
 #[derive(Debug, Clone, Default)]
 pub struct WeakMapHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
-    // TODO: This isn't even close to a hashmap; HashMap won't allow inserting
-    // Value as key; f32 isn't hashable. And our f64s are found on the Heap and
-    // require fetching; What we actually should do is more like:
-    // pub(crate) map: HashMap<ValueHash, u32>
-    // pub(crate) key_values: ParallelVec<Option<Value>, Option<Value>>
-    // ValueHash is created using a Value.hash(agent) function and connects to
-    // an index; the index points to a key and value in parallel vector / Vec2.
-    // Note that empty slots are deleted values in the ParallelVec.
-    pub(crate) keys: Vec<Value<'a>>,
-    pub(crate) values: Vec<Value<'a>>,
-    // TODO: When an non-terminal (start or end) iterator exists for the Map,
-    // the items in the map cannot be compacted.
-    // pub(crate) observed: bool;
+    /// ### \[\[WeakMapData]]
+    ///
+    /// The entries of the WeakMap. The keys are held weakly: an entry's value
+    /// is only kept alive by the WeakMap for as long as its key is reachable
+    /// by other means.
+    pub(crate) weak_map_data: AHashMap<WeakKey<'a>, Value<'a>>,
+}
+
+impl WeakMapHeapData<'static> {
+    /// Mark the values of entries whose keys have been found reachable.
+    ///
+    /// Called repeatedly during garbage collection once the work queues have
+    /// been exhausted, until no further values are found; this gives the
+    /// entries ephemeron semantics.
+    pub(crate) fn mark_ephemeron_values(&self, bits: &HeapBits, queues: &mut WorkQueues) {
+        for (key, value) in self.weak_map_data.iter() {
+            if key.is_marked(bits) && !bits.is_marked(*value) {
+                value.mark_values(queues);
+            }
+        }
+    }
+
+    /// Remove entries whose keys were not found reachable.
+    pub(crate) fn sweep_weak_keys(&mut self, bits: &HeapBits) {
+        self.weak_map_data.retain(|key, _| key.is_marked(bits));
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -45,30 +63,27 @@ impl HeapMarkAndSweep for WeakMapHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
             object_index,
-            keys,
-            values,
+            weak_map_data: _,
         } = self;
+        // Note: The entries are marked separately, see
+        // mark_ephemeron_values.
         object_index.mark_values(queues);
-        for ele in keys {
-            ele.mark_values(queues);
-        }
-        for ele in values {
-            ele.mark_values(queues);
-        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Self {
             object_index,
-            keys,
-            values,
+            weak_map_data,
         } = self;
         object_index.sweep_values(compactions);
-        for ele in keys {
-            ele.sweep_values(compactions);
-        }
-        for ele in values {
-            ele.sweep_values(compactions);
-        }
+        // Note: Sweeping changes the keys' hashes so the map is rebuilt.
+        *weak_map_data = core::mem::take(weak_map_data)
+            .into_iter()
+            .map(|(mut key, mut value)| {
+                key.sweep_values(compactions);
+                value.sweep_values(compactions);
+                (key, value)
+            })
+            .collect();
     }
 }
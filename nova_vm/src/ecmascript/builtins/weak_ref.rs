@@ -78,6 +78,17 @@ impl<'a> From<WeakRef<'a>> for Object<'a> {
     }
 }
 
+impl<'a> TryFrom<Object<'a>> for WeakRef<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::WeakRef(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
 impl<'a> InternalSlots<'a> for WeakRef<'a> {
     const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::WeakRef;
 
@@ -3,24 +3,29 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::{
-    ecmascript::types::{OrdinaryObject, Value},
+    ecmascript::{execution::WeakKey, types::OrdinaryObject},
     engine::context::{Bindable, NoGcScope},
-    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+    heap::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct WeakRefHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
-    pub(crate) value: Value<'a>,
-    pub(crate) is_strong: bool,
+    /// ### \[\[WeakRefTarget]]
+    ///
+    /// The target is held weakly; it is set to None (EMPTY) once the target
+    /// has been garbage collected.
+    pub(crate) weak_ref_target: Option<WeakKey<'a>>,
 }
 
-impl Default for WeakRefHeapData<'_> {
-    fn default() -> Self {
-        Self {
-            object_index: None,
-            value: Value::Undefined,
-            is_strong: false,
+impl WeakRefHeapData<'static> {
+    /// Clear the target if it was not found reachable.
+    pub(crate) fn sweep_weak_target(&mut self, bits: &HeapBits) {
+        if self
+            .weak_ref_target
+            .is_some_and(|target| !target.is_marked(bits))
+        {
+            self.weak_ref_target = None;
         }
     }
 }
@@ -44,23 +49,18 @@ impl HeapMarkAndSweep for WeakRefHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
             object_index,
-            value,
-            is_strong,
+            weak_ref_target: _,
         } = self;
+        // Note: The target is held weakly and is not marked.
         object_index.mark_values(queues);
-        if *is_strong {
-            value.mark_values(queues);
-        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Self {
             object_index,
-            value,
-            is_strong,
+            weak_ref_target,
         } = self;
         object_index.sweep_values(compactions);
-        value.sweep_values(compactions);
-        *is_strong = false;
+        weak_ref_target.sweep_values(compactions);
     }
 }
@@ -79,6 +79,17 @@ impl<'a> From<WeakSet<'a>> for Object<'a> {
     }
 }
 
+impl<'a> TryFrom<Object<'a>> for WeakSet<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Object::WeakSet(data) => Ok(data),
+            _ => Err(()),
+        }
+    }
+}
+
 impl<'a> InternalSlots<'a> for WeakSet<'a> {
     const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::WeakSet;
 
@@ -2,15 +2,29 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use ahash::AHashSet;
+
 use crate::{
-    ecmascript::types::OrdinaryObject,
+    ecmascript::{execution::WeakKey, types::OrdinaryObject},
     engine::context::{Bindable, NoGcScope},
-    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+    heap::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues},
 };
 
 #[derive(Debug, Clone, Default)]
 pub struct WeakSetHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    /// ### \[\[WeakSetData]]
+    ///
+    /// The elements of the WeakSet. The elements are held weakly and are
+    /// removed once they are no longer reachable by other means.
+    pub(crate) weak_set_data: AHashSet<WeakKey<'a>>,
+}
+
+impl WeakSetHeapData<'static> {
+    /// Remove elements that were not found reachable.
+    pub(crate) fn sweep_weak_keys(&mut self, bits: &HeapBits) {
+        self.weak_set_data.retain(|key| key.is_marked(bits));
+    }
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -30,10 +44,27 @@ unsafe impl Bindable for WeakSetHeapData<'_> {
 
 impl HeapMarkAndSweep for WeakSetHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
-        self.object_index.mark_values(queues);
+        let Self {
+            object_index,
+            weak_set_data: _,
+        } = self;
+        // Note: The elements are held weakly and are not marked.
+        object_index.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
-        self.object_index.sweep_values(compactions);
+        let Self {
+            object_index,
+            weak_set_data,
+        } = self;
+        object_index.sweep_values(compactions);
+        // Note: Sweeping changes the elements' hashes so the set is rebuilt.
+        *weak_set_data = core::mem::take(weak_set_data)
+            .into_iter()
+            .map(|mut key| {
+                key.sweep_values(compactions);
+                key
+            })
+            .collect();
     }
 }
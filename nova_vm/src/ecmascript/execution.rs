@@ -7,6 +7,9 @@ mod default_host_hooks;
 mod environments;
 mod execution_context;
 mod realm;
+#[cfg(feature = "atomics")]
+mod waiter_list;
+mod weak_key;
 
 pub use agent::{Agent, JsResult};
 pub use default_host_hooks::DefaultHostHooks;
@@ -15,9 +18,16 @@ pub(crate) use environments::{
     ModuleEnvironment, ObjectEnvironment, PrivateEnvironment, ThisBindingStatus,
     get_this_environment, new_class_field_initializer_environment,
     new_class_static_element_environment, new_declarative_environment, new_function_environment,
-    new_global_environment,
+    new_global_environment, new_module_environment, new_object_environment,
+    new_private_environment,
 };
 pub(crate) use execution_context::*;
 pub(crate) use realm::{
-    ProtoIntrinsics, Realm, RealmRecord, initialize_default_realm, initialize_host_defined_realm,
+    ProtoIntrinsics, Realm, RealmRecord, TemplateRecord, initialize_default_realm,
+    initialize_host_defined_realm,
 };
+#[cfg(feature = "atomics")]
+pub use waiter_list::AtomicsWaiter;
+#[cfg(feature = "atomics")]
+pub(crate) use waiter_list::{WaitResult, WaiterList};
+pub(crate) use weak_key::{WeakKey, can_be_held_weakly};
@@ -10,22 +10,32 @@
 use ahash::AHashMap;
 
 use super::{
-    environments::{get_identifier_reference, try_get_identifier_reference}, initialize_default_realm, initialize_host_defined_realm, Environment, ExecutionContext, GlobalEnvironment, PrivateEnvironment, RealmRecord, Realm
+    environments::{get_identifier_reference, try_get_identifier_reference}, initialize_default_realm, initialize_host_defined_realm, Environment, ExecutionContext, GlobalEnvironment, PrivateEnvironment, RealmRecord, Realm, WeakKey
 };
 use crate::{
     ecmascript::{
         abstract_operations::type_conversion::to_string,
-        builtins::{control_abstraction_objects::promise_objects::promise_abstract_operations::promise_jobs::{PromiseReactionJob, PromiseResolveThenableJob}, error::ErrorHeapData, promise::Promise},
-        scripts_and_modules::{script::{parse_script, script_evaluation}, source_code::SourceCode, ScriptOrModule},
-        types::{Function, IntoValue, Object, Reference, String, Symbol, Value, ValueRootRepr},
+        builtins::{control_abstraction_objects::promise_objects::promise_abstract_operations::promise_jobs::{PromiseReactionJob, PromiseResolveThenableJob}, error::ErrorHeapData, finalization_registry::FinalizationRegistryCleanupJob, promise::Promise},
+        scripts_and_modules::{module::module_semantics::{finish_loading_imported_module, source_text_module_records::SourceTextModule, ModuleLoadingPayload, ModuleRequest, Referrer}, script::{parse_script, script_evaluation}, source_code::SourceCode, ScriptOrModule},
+        types::{Function, IntoValue, Object, OrdinaryObject, PropertyKey, Reference, String, Symbol, Value, ValueRootRepr},
     }, engine::{context::{Bindable, GcScope, NoGcScope}, rootable::{HeapRootCollectionData, HeapRootData, HeapRootRef, Rootable}, TryResult, Vm}, heap::{heap_gc::heap_gc, CompactionLists, CreateHeapData, HeapMarkAndSweep, PrimitiveHeapIndexable, WorkQueues}, Heap
 };
 use core::{any::Any, cell::RefCell, ptr::NonNull};
+#[cfg(feature = "atomics")]
+use super::AtomicsWaiter;
+#[cfg(feature = "atomics")]
+use crate::ecmascript::builtins::structured_data::atomics_object::AtomicsWaitAsyncJob;
 
 #[derive(Debug, Default)]
 pub struct Options {
     pub disable_gc: bool,
     pub print_internals: bool,
+    /// ### \[\[CanBlock\]\]
+    ///
+    /// Whether the agent may be suspended by `Atomics.wait`. Hosts should
+    /// leave this false for agents whose thread must not block, such as the
+    /// main thread of an event loop serving user interaction.
+    pub can_block: bool,
 }
 
 pub type JsResult<'a, T> = core::result::Result<T, JsError<'a>>;
@@ -103,6 +113,9 @@ impl HeapMarkAndSweep for JsError<'static> {
 pub(crate) enum InnerJob {
     PromiseResolveThenable(PromiseResolveThenableJob),
     PromiseReaction(PromiseReactionJob),
+    FinalizationRegistryCleanup(FinalizationRegistryCleanupJob),
+    #[cfg(feature = "atomics")]
+    AtomicsWaitAsync(AtomicsWaitAsyncJob),
 }
 
 pub struct Job {
@@ -110,6 +123,14 @@ pub struct Job {
     pub(crate) inner: InnerJob,
 }
 
+impl core::fmt::Debug for Job {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Job")
+            .field("realm", &self.realm)
+            .finish_non_exhaustive()
+    }
+}
+
 impl Job {
     fn realm(&self) -> Option<Realm<'static>> {
         self.realm
@@ -132,12 +153,19 @@ impl Job {
         let result = match self.inner {
             InnerJob::PromiseResolveThenable(job) => job.run(agent, gc),
             InnerJob::PromiseReaction(job) => job.run(agent, gc),
+            InnerJob::FinalizationRegistryCleanup(job) => job.run(agent, gc),
+            #[cfg(feature = "atomics")]
+            InnerJob::AtomicsWaitAsync(job) => job.run(agent, gc),
         };
 
         if pushed_context {
             agent.execution_context_stack.pop();
         }
 
+        // The Job is a synchronous sequence of ECMAScript executions; objects
+        // kept alive during it may now be collected.
+        agent.clear_kept_objects();
+
         result
     }
 }
@@ -167,6 +195,33 @@ pub trait HostHooks: core::fmt::Debug {
     /// ### [9.5.5 HostEnqueuePromiseJob ( job, realm )](https://tc39.es/ecma262/#sec-hostenqueuepromisejob)
     fn enqueue_promise_job(&self, job: Job);
 
+    /// ### [9.10.4.1 HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )](https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry)
+    ///
+    /// Called after garbage collection with a Job that calls the cleanup
+    /// callback of a FinalizationRegistry for each of its cells whose target
+    /// was collected. The host should schedule the Job to be run at some
+    /// future time, after any currently running ECMAScript code has
+    /// finished.
+    ///
+    /// The default implementation drops the Job: cleanup callbacks are never
+    /// called.
+    fn enqueue_finalization_registry_cleanup_job(&self, _job: Job) {}
+
+    /// ### [25.4.3.13 EnqueueResolveInAgentJob ( agentSignifier, promiseCapability, resolution )](https://tc39.es/ecma262/#sec-enqueueresolveinagentjob)
+    ///
+    /// ### [25.4.3.15 EnqueueAtomicsWaitAsyncTimeoutJob ( WL, waiterRecord )](https://tc39.es/ecma262/#sec-enqueueatomicswaitasynctimeoutjob)
+    ///
+    /// Called by `Atomics.waitAsync` with a Job that resolves the returned
+    /// promise once `waiter` has been notified or has timed out. The host
+    /// should run the Job after [AtomicsWaiter::is_ready] returns true, or
+    /// hand the waiter to a thread that calls [AtomicsWaiter::wait] and then
+    /// schedule the Job to be run.
+    ///
+    /// The default implementation drops the Job: the promise returned by
+    /// `Atomics.waitAsync` never settles.
+    #[cfg(feature = "atomics")]
+    fn enqueue_atomics_wait_async_job(&self, _waiter: AtomicsWaiter, _job: Job) {}
+
     /// ### [27.2.1.9 HostPromiseRejectionTracker ( promise, operation )](https://tc39.es/ecma262/#sec-host-promise-rejection-tracker)
     fn promise_rejection_tracker(
         &self,
@@ -176,6 +231,91 @@ pub trait HostHooks: core::fmt::Debug {
         // The default implementation of HostPromiseRejectionTracker is to return unused.
     }
 
+    /// ### [16.2.1.10 HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )](https://tc39.es/ecma262/#sec-HostLoadImportedModule)
+    ///
+    /// Load the module requested by `module_request` on behalf of
+    /// `referrer`. The module is loaded either as a part of a
+    /// LoadRequestedModules call or by a dynamic `import()` call, as
+    /// indicated by `payload`. The host-defined value passed to
+    /// LoadRequestedModules is available through
+    /// [ModuleLoadingPayload::host_defined].
+    ///
+    /// The host must call
+    /// [finish_loading_imported_module](crate::ecmascript::scripts_and_modules::module::module_semantics::finish_loading_imported_module)
+    /// with `referrer`, `module_request`, `payload` and the result of loading
+    /// the module. This can happen either before returning or later, for
+    /// example once the module source has been fetched: `referrer` and
+    /// `payload` can be rooted to keep them alive until then, and the module
+    /// request can be recreated with
+    /// [ModuleRequest::new](crate::ecmascript::scripts_and_modules::module::module_semantics::ModuleRequest::new).
+    /// If the same `referrer` and `module_request` pair is loaded multiple
+    /// times, the result must be the same Module Record every time.
+    ///
+    /// The module is usually created using
+    /// [parse_module](crate::ecmascript::scripts_and_modules::module::module_semantics::source_text_module_records::parse_module),
+    /// [parse_json_module](crate::ecmascript::scripts_and_modules::module::module_semantics::synthetic_module_records::parse_json_module)
+    /// for `with { type: "json" }` imports, or
+    /// [create_synthetic_module](crate::ecmascript::scripts_and_modules::module::module_semantics::synthetic_module_records::create_synthetic_module)
+    /// for modules defined by the host.
+    fn load_imported_module<'gc>(
+        &self,
+        agent: &mut Agent,
+        referrer: Referrer<'gc>,
+        module_request: &ModuleRequest<'gc>,
+        payload: ModuleLoadingPayload<'gc>,
+        gc: NoGcScope<'gc, '_>,
+    ) {
+        // By default module loading is not supported.
+        let error_message = format!(
+            "Cannot load module '{}'.",
+            module_request.specifier().as_str(agent)
+        );
+        let error = agent.throw_exception(ExceptionType::TypeError, error_message, gc);
+        finish_loading_imported_module(agent, referrer, module_request, payload, Err(error), gc);
+    }
+
+    /// ### [16.2.1.12.1 HostGetSupportedImportAttributes ( )](https://tc39.es/ecma262/#sec-hostgetsupportedimportattributes)
+    ///
+    /// Returns the import attribute keys that the host supports. Imports with
+    /// any other attribute keys fail to load with a SyntaxError.
+    fn get_supported_import_attributes(&self) -> &[&'static str] {
+        // The default implementation of HostGetSupportedImportAttributes is
+        // to return a new empty List.
+        &[]
+    }
+
+    /// ### [13.3.12.1.1 HostGetImportMetaProperties ( moduleRecord )](https://tc39.es/ecma262/#sec-hostgetimportmetaproperties)
+    ///
+    /// Returns the properties to define on the `import.meta` object of
+    /// `module`, such as `url` or `resolve`. This is called once per module,
+    /// when `import.meta` is first evaluated.
+    fn get_import_meta_properties<'gc>(
+        &self,
+        _agent: &mut Agent,
+        _module: SourceTextModule<'gc>,
+        _gc: NoGcScope<'gc, '_>,
+    ) -> Vec<(PropertyKey<'gc>, Value<'gc>)> {
+        // The default implementation of HostGetImportMetaProperties is to
+        // return a new empty List.
+        vec![]
+    }
+
+    /// ### [13.3.12.1.2 HostFinalizeImportMeta ( importMeta, moduleRecord )](https://tc39.es/ecma262/#sec-hostfinalizeimportmeta)
+    ///
+    /// Allows the host to perform any extraordinary operations to prepare
+    /// the `import.meta` object of `module` before it is exposed to
+    /// ECMAScript code.
+    fn finalize_import_meta(
+        &self,
+        _agent: &mut Agent,
+        _import_meta: OrdinaryObject,
+        _module: SourceTextModule,
+        _gc: NoGcScope,
+    ) {
+        // The default implementation of HostFinalizeImportMeta is to return
+        // UNUSED.
+    }
+
     /// Get access to the Host data, useful to share state between calls of built-in functions.
     ///
     /// Note: This will panic if not implemented manually.
@@ -292,6 +432,7 @@ impl GcAgent {
         assert!(self.agent.execution_context_stack.is_empty());
         let result = self.agent.run_in_realm(realm, func);
         assert!(self.agent.execution_context_stack.is_empty());
+        self.agent.clear_kept_objects();
         assert!(self.agent.vm_stack.is_empty());
         self.agent.stack_refs.borrow_mut().clear();
         result
@@ -329,6 +470,18 @@ pub struct Agent {
     pub(crate) stack_ref_collections: RefCell<Vec<HeapRootCollectionData>>,
     /// Temporary storage for on-stack VMs.
     pub(crate) vm_stack: Vec<NonNull<Vm>>,
+    /// ### \[\[ModuleAsyncEvaluationCount]]
+    ///
+    /// Initially 1. Used to assign unique incrementing values to the
+    /// \[\[AsyncEvaluationOrder]] field of modules that are asynchronous or
+    /// have asynchronous dependencies.
+    pub(crate) module_async_evaluation_count: u32,
+    /// ### \[\[KeptAlive]]
+    ///
+    /// Objects and Symbols that are kept alive until the end of the current
+    /// synchronous sequence of ECMAScript executions, even if they are only
+    /// held weakly.
+    pub(crate) kept_alive: Vec<WeakKey<'static>>,
 }
 
 impl Agent {
@@ -343,9 +496,35 @@ impl Agent {
             stack_refs: RefCell::new(Vec::with_capacity(64)),
             stack_ref_collections: RefCell::new(Vec::with_capacity(32)),
             vm_stack: Vec::with_capacity(16),
+            module_async_evaluation_count: 1,
+            kept_alive: Vec::new(),
         }
     }
 
+    /// ### [9.11 ClearKeptObjects ( )](https://tc39.es/ecma262/#sec-clear-kept-objects)
+    ///
+    /// ECMAScript implementations are expected to call ClearKeptObjects when
+    /// a synchronous sequence of ECMAScript executions completes.
+    pub fn clear_kept_objects(&mut self) {
+        // 1. Let agentRecord be the surrounding agent's Agent Record.
+        // 2. Set agentRecord.[[KeptAlive]] to a new empty List.
+        self.kept_alive.clear();
+        // 3. Return UNUSED.
+    }
+
+    /// ### [9.12 AddToKeptObjects ( value )](https://tc39.es/ecma262/#sec-addtokeptobjects)
+    ///
+    /// When the abstract operation AddToKeptObjects is called with a target
+    /// object or symbol, it adds the target to a list that will point
+    /// strongly at the target until ClearKeptObjects is called.
+    #[cfg(feature = "weak-refs")]
+    pub(crate) fn add_to_kept_objects(&mut self, value: WeakKey) {
+        // 1. Let agentRecord be the surrounding agent's Agent Record.
+        // 2. Append value to agentRecord.[[KeptAlive]].
+        self.kept_alive.push(value.unbind());
+        // 3. Return UNUSED.
+    }
+
     pub fn gc(&mut self, gc: GcScope) {
         let mut root_realms = self
             .heap
@@ -660,14 +839,14 @@ impl Agent {
     }
 
     /// Sets the running execution context's PrivateEnvironment.
-    pub(crate) fn set_current_private_environment(&mut self, env: PrivateEnvironment) {
+    pub(crate) fn set_current_private_environment(&mut self, env: Option<PrivateEnvironment>) {
         self.execution_context_stack
             .last_mut()
             .unwrap()
             .ecmascript_code
             .as_mut()
             .unwrap()
-            .private_environment = Some(env.unbind());
+            .private_environment = env.unbind();
     }
 
     pub(crate) fn running_execution_context_mut(&mut self) -> &mut ExecutionContext {
@@ -814,6 +993,8 @@ pub enum ExceptionType {
     SyntaxError,
     TypeError,
     UriError,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    SuppressedError,
 }
 
 impl TryFrom<u16> for ExceptionType {
@@ -829,6 +1010,8 @@ impl TryFrom<u16> for ExceptionType {
             5 => Ok(Self::SyntaxError),
             6 => Ok(Self::TypeError),
             7 => Ok(Self::UriError),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            8 => Ok(Self::SuppressedError),
             _ => Err(()),
         }
     }
@@ -844,15 +1027,20 @@ impl HeapMarkAndSweep for Agent {
             stack_refs,
             stack_ref_collections,
             vm_stack,
+            kept_alive,
             options: _,
             symbol_id: _,
             global_symbol_registry: _,
             host_hooks: _,
+            module_async_evaluation_count: _,
         } = self;
 
         execution_context_stack.iter().for_each(|ctx| {
             ctx.mark_values(queues);
         });
+        kept_alive
+            .iter()
+            .for_each(|value| value.mark_values(queues));
         stack_refs
             .borrow()
             .iter()
@@ -890,15 +1078,20 @@ impl HeapMarkAndSweep for Agent {
             stack_refs,
             stack_ref_collections,
             vm_stack,
+            kept_alive,
             options: _,
             symbol_id: _,
             global_symbol_registry: _,
             host_hooks: _,
+            module_async_evaluation_count: _,
         } = self;
 
         execution_context_stack
             .iter_mut()
             .for_each(|entry| entry.sweep_values(compactions));
+        kept_alive
+            .iter_mut()
+            .for_each(|entry| entry.sweep_values(compactions));
         stack_refs
             .borrow_mut()
             .iter_mut()
@@ -41,8 +41,9 @@ pub(crate) use function_environment::{
     new_class_static_element_environment, new_function_environment,
 };
 pub(crate) use global_environment::{GlobalEnvironmentRecord, new_global_environment};
-pub(crate) use object_environment::ObjectEnvironmentRecord;
-pub(crate) use private_environment::PrivateEnvironmentRecord;
+pub(crate) use module_environment::{ModuleEnvironmentRecord, new_module_environment};
+pub(crate) use object_environment::{ObjectEnvironmentRecord, new_object_environment};
+pub(crate) use private_environment::{PrivateEnvironmentRecord, new_private_environment};
 
 use crate::engine::TryResult;
 use crate::engine::context::{Bindable, GcScope, GcToken, NoGcScope};
@@ -209,6 +210,7 @@ create_environment_index!(
 );
 create_environment_index!(FunctionEnvironmentRecord, FunctionEnvironment, function);
 create_environment_index!(GlobalEnvironmentRecord, GlobalEnvironment, global);
+create_environment_index!(ModuleEnvironmentRecord, ModuleEnvironment, module);
 create_environment_index!(ObjectEnvironmentRecord, ObjectEnvironment, object);
 create_environment_index!(PrivateEnvironmentRecord, PrivateEnvironment, private);
 
@@ -230,93 +232,9 @@ impl<'a> From<ObjectEnvironment<'a>> for Environment<'a> {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ModuleEnvironment<'a>(
-    NonZeroU32,
-    PhantomData<DeclarativeEnvironmentRecord>,
-    PhantomData<&'a GcToken>,
-);
-
-impl core::fmt::Debug for ModuleEnvironment<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "ModuleEnvironment({:?})", self.into_index())
-    }
-}
-
-impl ModuleEnvironment<'_> {
-    /// Creates a new index from a u32.
-    ///
-    /// ## Panics
-    /// - If the value is equal to 0.
-    pub(crate) const fn from_u32(value: u32) -> Self {
-        assert!(value != 0);
-        // SAFETY: Number is not 0 and will not overflow to zero.
-        // This check is done manually to allow const context.
-        Self(
-            unsafe { NonZeroU32::new_unchecked(value) },
-            PhantomData,
-            PhantomData,
-        )
-    }
-
-    pub(crate) const fn into_index(self) -> usize {
-        self.0.get() as usize - 1
-    }
-
-    pub(crate) const fn into_u32(self) -> u32 {
-        self.0.get()
-    }
-
-    pub(crate) fn last(vec: &[Option<DeclarativeEnvironmentRecord>]) -> Self {
-        Self::from_u32(vec.len() as u32)
-    }
-}
-
-// SAFETY: Property implemented as a lifetime transmute.
-unsafe impl Bindable for ModuleEnvironment<'_> {
-    type Of<'a> = ModuleEnvironment<'a>;
-
-    #[inline(always)]
-    fn unbind(self) -> Self::Of<'static> {
-        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
-    }
-
-    #[inline(always)]
-    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
-        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
-    }
-}
-
-impl Rootable for ModuleEnvironment<'_> {
-    type RootRepr = HeapRootRef;
-
-    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
-        Err(HeapRootData::ModuleEnvironment(value.unbind()))
-    }
-
-    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
-        Err(*value)
-    }
-
-    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
-        heap_ref
-    }
-
-    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
-        match heap_data {
-            HeapRootData::ModuleEnvironment(object) => Some(object),
-            _ => None,
-        }
-    }
-}
-
-impl HeapMarkAndSweep for ModuleEnvironment<'_> {
-    fn mark_values(&self, _queues: &mut WorkQueues) {
-        todo!()
-    }
-
-    fn sweep_values(&mut self, _compactions: &CompactionLists) {
-        todo!()
+impl<'a> From<ModuleEnvironment<'a>> for Environment<'a> {
+    fn from(value: ModuleEnvironment<'a>) -> Self {
+        Environment::Module(value)
     }
 }
 
@@ -335,7 +253,7 @@ pub(crate) enum Environment<'a> {
     Declarative(DeclarativeEnvironment<'a>) = 1,
     Function(FunctionEnvironment<'a>),
     Global(GlobalEnvironment<'a>),
-    // Module(ModuleEnvironment<'a>),
+    Module(ModuleEnvironment<'a>),
     Object(ObjectEnvironment<'a>),
 }
 
@@ -345,6 +263,7 @@ impl Environment<'_> {
             Environment::Declarative(index) => index.get_outer_env(agent, gc),
             Environment::Function(index) => index.get_outer_env(agent, gc),
             Environment::Global(_) => None,
+            Environment::Module(index) => index.get_outer_env(agent, gc),
             Environment::Object(index) => index.get_outer_env(agent, gc),
         }
     }
@@ -363,6 +282,7 @@ impl Environment<'_> {
             Environment::Declarative(idx) => TryResult::Continue(idx.has_binding(agent, name)),
             Environment::Function(idx) => TryResult::Continue(idx.has_binding(agent, name)),
             Environment::Global(idx) => idx.try_has_binding(agent, name, gc),
+            Environment::Module(idx) => TryResult::Continue(idx.has_binding(agent, name)),
             Environment::Object(idx) => idx.try_has_binding(agent, name, gc),
         }
     }
@@ -381,6 +301,7 @@ impl Environment<'_> {
             Environment::Declarative(idx) => Ok(idx.has_binding(agent, name)),
             Environment::Function(idx) => Ok(idx.has_binding(agent, name)),
             Environment::Global(idx) => idx.has_binding(agent, name, gc),
+            Environment::Module(idx) => Ok(idx.has_binding(agent, name)),
             Environment::Object(idx) => idx.has_binding(agent, name, gc),
         }
     }
@@ -409,6 +330,10 @@ impl Environment<'_> {
             Environment::Global(idx) => {
                 TryResult::Continue(idx.create_mutable_binding(agent, name, is_deletable, gc))
             }
+            Environment::Module(idx) => {
+                idx.create_mutable_binding(agent, name, is_deletable);
+                TryResult::Continue(Ok(()))
+            }
             Environment::Object(idx) => {
                 idx.try_create_mutable_binding(agent, name, is_deletable, gc)
             }
@@ -439,6 +364,10 @@ impl Environment<'_> {
             Environment::Global(idx) => {
                 idx.create_mutable_binding(agent, name, is_deletable, gc.into_nogc())
             }
+            Environment::Module(idx) => {
+                idx.create_mutable_binding(agent, name, is_deletable);
+                Ok(())
+            }
             Environment::Object(idx) => idx.create_mutable_binding(agent, name, is_deletable, gc),
         }
     }
@@ -467,6 +396,10 @@ impl Environment<'_> {
                 Ok(())
             }
             Environment::Global(idx) => idx.create_immutable_binding(agent, name, is_strict, gc),
+            Environment::Module(idx) => {
+                idx.create_immutable_binding(agent, name, is_strict);
+                Ok(())
+            }
             Environment::Object(idx) => {
                 idx.create_immutable_binding(agent, name, is_strict);
                 Ok(())
@@ -497,6 +430,10 @@ impl Environment<'_> {
                 TryResult::Continue(Ok(()))
             }
             Environment::Global(idx) => idx.try_initialize_binding(agent, name, value, gc),
+            Environment::Module(idx) => {
+                idx.initialize_binding(agent, name, value);
+                TryResult::Continue(Ok(()))
+            }
             Environment::Object(idx) => idx.try_initialize_binding(agent, name, value, gc),
         }
     }
@@ -524,6 +461,10 @@ impl Environment<'_> {
                 Ok(())
             }
             Environment::Global(idx) => idx.initialize_binding(agent, name, value, gc),
+            Environment::Module(idx) => {
+                idx.initialize_binding(agent, name, value);
+                Ok(())
+            }
             Environment::Object(idx) => idx.initialize_binding(agent, name, value, gc),
         }
     }
@@ -553,6 +494,9 @@ impl Environment<'_> {
             Environment::Global(idx) => {
                 idx.try_set_mutable_binding(agent, name, value, is_strict, gc)
             }
+            Environment::Module(idx) => {
+                TryResult::Continue(idx.set_mutable_binding(agent, name, value, is_strict, gc))
+            }
             Environment::Object(idx) => {
                 idx.try_set_mutable_binding(agent, name, value, is_strict, gc)
             }
@@ -582,6 +526,9 @@ impl Environment<'_> {
                 idx.set_mutable_binding(agent, name, value, is_strict, gc.into_nogc())
             }
             Environment::Global(idx) => idx.set_mutable_binding(agent, name, value, is_strict, gc),
+            Environment::Module(idx) => {
+                idx.set_mutable_binding(agent, name, value, is_strict, gc.into_nogc())
+            }
             Environment::Object(idx) => idx.set_mutable_binding(agent, name, value, is_strict, gc),
         }
     }
@@ -610,6 +557,9 @@ impl Environment<'_> {
                 TryResult::Continue(idx.get_binding_value(agent, name, is_strict, gc))
             }
             Environment::Global(idx) => idx.try_get_binding_value(agent, name, is_strict, gc),
+            Environment::Module(idx) => {
+                TryResult::Continue(idx.get_binding_value(agent, name, is_strict, gc))
+            }
             Environment::Object(idx) => idx.try_get_binding_value(agent, name, is_strict, gc),
         }
     }
@@ -638,6 +588,9 @@ impl Environment<'_> {
                 idx.get_binding_value(agent, name, is_strict, gc.into_nogc())
             }
             Environment::Global(idx) => idx.get_binding_value(agent, name, is_strict, gc),
+            Environment::Module(idx) => {
+                idx.get_binding_value(agent, name, is_strict, gc.into_nogc())
+            }
             Environment::Object(idx) => idx.get_binding_value(agent, name, is_strict, gc),
         }
     }
@@ -660,6 +613,9 @@ impl Environment<'_> {
             }
             Environment::Function(idx) => TryResult::Continue(Ok(idx.delete_binding(agent, name))),
             Environment::Global(idx) => idx.try_delete_binding(agent, name, gc),
+            // NOTE: Module code is always strict mode code and strict mode
+            // code cannot delete bindings.
+            Environment::Module(_) => unreachable!(),
             Environment::Object(idx) => {
                 TryResult::Continue(Ok(idx.try_delete_binding(agent, name, gc)?))
             }
@@ -682,6 +638,7 @@ impl Environment<'_> {
             Environment::Declarative(idx) => Ok(idx.delete_binding(agent, name)),
             Environment::Function(idx) => Ok(idx.delete_binding(agent, name)),
             Environment::Global(idx) => idx.delete_binding(agent, name, gc),
+            Environment::Module(_) => unreachable!(),
             Environment::Object(idx) => idx.delete_binding(agent, name, gc),
         }
     }
@@ -695,6 +652,7 @@ impl Environment<'_> {
             Environment::Declarative(_) => false,
             Environment::Function(idx) => idx.has_this_binding(agent),
             Environment::Global(_) => true,
+            Environment::Module(_) => true,
             Environment::Object(_) => false,
         }
     }
@@ -708,6 +666,7 @@ impl Environment<'_> {
             Environment::Declarative(idx) => idx.has_super_binding(),
             Environment::Function(idx) => idx.has_super_binding(agent),
             Environment::Global(idx) => idx.has_super_binding(),
+            Environment::Module(idx) => idx.has_super_binding(),
             Environment::Object(idx) => idx.has_super_binding(),
         }
     }
@@ -721,6 +680,7 @@ impl Environment<'_> {
             Environment::Declarative(idx) => idx.with_base_object(),
             Environment::Function(idx) => idx.with_base_object(),
             Environment::Global(idx) => idx.with_base_object(),
+            Environment::Module(idx) => idx.with_base_object(),
             Environment::Object(idx) => idx.with_base_object(agent),
         }
     }
@@ -749,8 +709,8 @@ impl core::fmt::Debug for Environment<'_> {
             }
             Environment::Function(d) => write!(f, "FunctionEnvironment({:?})", d.into_u32_index()),
             Environment::Global(d) => write!(f, "GlobalEnvironment({:?})", d.into_u32_index()),
+            Environment::Module(d) => write!(f, "ModuleEnvironment({:?})", d.into_u32_index()),
             Environment::Object(d) => write!(f, "ObjectEnvironment({:?})", d.into_u32_index()),
-            // EnvironmentIndex::Module(d) => {}
         }
     }
 }
@@ -769,6 +729,9 @@ impl Rootable for Environment<'_> {
             Environment::Global(global_environment_index) => Err(HeapRootData::GlobalEnvironment(
                 global_environment_index.unbind(),
             )),
+            Environment::Module(module_environment_index) => Err(HeapRootData::ModuleEnvironment(
+                module_environment_index.unbind(),
+            )),
             Environment::Object(object_environment_index) => Err(HeapRootData::ObjectEnvironment(
                 object_environment_index.unbind(),
             )),
@@ -794,6 +757,9 @@ impl Rootable for Environment<'_> {
             HeapRootData::GlobalEnvironment(global_environment_index) => {
                 Some(Environment::Global(global_environment_index))
             }
+            HeapRootData::ModuleEnvironment(module_environment_index) => {
+                Some(Environment::Module(module_environment_index))
+            }
             HeapRootData::ObjectEnvironment(object_environment_index) => {
                 Some(Environment::Object(object_environment_index))
             }
@@ -808,6 +774,7 @@ impl HeapMarkAndSweep for Environment<'static> {
             Environment::Declarative(idx) => idx.mark_values(queues),
             Environment::Function(idx) => idx.mark_values(queues),
             Environment::Global(idx) => idx.mark_values(queues),
+            Environment::Module(idx) => idx.mark_values(queues),
             Environment::Object(idx) => idx.mark_values(queues),
         }
     }
@@ -817,6 +784,7 @@ impl HeapMarkAndSweep for Environment<'static> {
             Environment::Declarative(idx) => idx.sweep_values(compactions),
             Environment::Function(idx) => idx.sweep_values(compactions),
             Environment::Global(idx) => idx.sweep_values(compactions),
+            Environment::Module(idx) => idx.sweep_values(compactions),
             Environment::Object(idx) => idx.sweep_values(compactions),
         }
     }
@@ -827,6 +795,7 @@ pub struct Environments {
     pub(crate) declarative: Vec<Option<DeclarativeEnvironmentRecord>>,
     pub(crate) function: Vec<Option<FunctionEnvironmentRecord>>,
     pub(crate) global: Vec<Option<GlobalEnvironmentRecord>>,
+    pub(crate) module: Vec<Option<ModuleEnvironmentRecord>>,
     pub(crate) object: Vec<Option<ObjectEnvironmentRecord>>,
     pub(crate) private: Vec<Option<PrivateEnvironmentRecord>>,
 }
@@ -837,6 +806,7 @@ impl Default for Environments {
             declarative: Vec::with_capacity(256),
             function: Vec::with_capacity(1024),
             global: Vec::with_capacity(1),
+            module: Vec::with_capacity(0),
             object: Vec::with_capacity(1024),
             private: Vec::with_capacity(0),
         }
@@ -874,6 +844,7 @@ pub(crate) fn try_get_identifier_reference<'a>(
             strict,
             // [[ThisValue]]: EMPTY
             this_value: None,
+            private_name: None,
         })
         // }.
     }
@@ -893,6 +864,7 @@ pub(crate) fn try_get_identifier_reference<'a>(
                 strict,
                 // [[ThisValue]]: EMPTY
                 this_value: None,
+                private_name: None,
             });
             // }.
         };
@@ -931,6 +903,7 @@ pub(crate) fn get_identifier_reference<'a, 'b>(
             strict,
             // [[ThisValue]]: EMPTY
             this_value: None,
+            private_name: None,
         });
         // }.
     };
@@ -966,6 +939,7 @@ pub(crate) fn get_identifier_reference<'a, 'b>(
             strict,
             // [[ThisValue]]: EMPTY
             this_value: None,
+            private_name: None,
         })
         // }.
     }
@@ -1007,18 +981,31 @@ impl Environments {
         GlobalEnvironment::from_u32(self.global.len() as u32)
     }
 
+    pub(crate) fn push_module_environment<'a>(
+        &mut self,
+        env: ModuleEnvironmentRecord,
+        _: NoGcScope<'a, '_>,
+    ) -> ModuleEnvironment<'a> {
+        self.module.push(Some(env));
+        ModuleEnvironment::from_u32(self.module.len() as u32)
+    }
+
     pub(crate) fn push_object_environment<'a>(
         &mut self,
         env: ObjectEnvironmentRecord,
-        decl_env: DeclarativeEnvironmentRecord,
         _: NoGcScope<'a, '_>,
-    ) -> (ObjectEnvironment<'a>, DeclarativeEnvironment<'a>) {
+    ) -> ObjectEnvironment<'a> {
         self.object.push(Some(env));
-        self.declarative.push(Some(decl_env));
-        (
-            ObjectEnvironment::from_u32(self.object.len() as u32),
-            DeclarativeEnvironment::from_u32(self.declarative.len() as u32),
-        )
+        ObjectEnvironment::from_u32(self.object.len() as u32)
+    }
+
+    pub(crate) fn push_private_environment<'a>(
+        &mut self,
+        env: PrivateEnvironmentRecord,
+        _: NoGcScope<'a, '_>,
+    ) -> PrivateEnvironment<'a> {
+        self.private.push(Some(env));
+        PrivateEnvironment::from_u32(self.private.len() as u32)
     }
 
     pub(crate) fn get_declarative_environment(
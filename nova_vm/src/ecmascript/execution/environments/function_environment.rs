@@ -5,14 +5,16 @@
 use super::{
     DeclarativeEnvironment, DeclarativeEnvironmentRecord, Environment, FunctionEnvironment,
 };
-use crate::ecmascript::types::OrdinaryObject;
 use crate::engine::context::{Bindable, NoGcScope};
 use crate::engine::unwrap_try;
 use crate::{
     ecmascript::{
         builtins::{ECMAScriptFunction, ThisMode},
         execution::{Agent, JsResult, agent::ExceptionType},
-        types::{Function, InternalMethods, IntoFunction, IntoValue, Object, String, Value},
+        types::{
+            BUILTIN_STRING_MEMORY, Function, InternalMethods, IntoFunction, IntoObject, IntoValue,
+            Object, String, Value,
+        },
     },
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
@@ -62,6 +64,11 @@ pub struct FunctionEnvironmentRecord {
     /// undefined.
     new_target: Option<Object<'static>>,
 
+    /// True if this Environment Record was created for the evaluation of a
+    /// class static element. The \[\[HomeObject]] of a static element's
+    /// evaluation function is the class constructor itself.
+    is_class_static_element: bool,
+
     /// Function Environment Records support all of the Declarative Environment
     /// Record methods listed in Table 16 and share the same specifications for
     /// all of those methods except for HasThisBinding and HasSuperBinding.
@@ -78,6 +85,7 @@ impl HeapMarkAndSweep for FunctionEnvironmentRecord {
             this_binding_status: _,
             function_object,
             new_target,
+            is_class_static_element: _,
             declarative_environment,
         } = self;
         declarative_environment.mark_values(queues);
@@ -92,6 +100,7 @@ impl HeapMarkAndSweep for FunctionEnvironmentRecord {
             this_binding_status: _,
             function_object,
             new_target,
+            is_class_static_element: _,
             declarative_environment,
         } = self;
         declarative_environment.sweep_values(compactions);
@@ -139,6 +148,8 @@ pub(crate) fn new_function_environment<'a>(
         // 5. Set env.[[NewTarget]] to newTarget.
         new_target: new_target.unbind(),
 
+        is_class_static_element: false,
+
         // 6. Set env.[[OuterEnv]] to F.[[Environment]].
         declarative_environment: declarative_environment.unbind(),
     };
@@ -178,6 +189,8 @@ pub(crate) fn new_class_static_element_environment<'a>(
         // 5. Set env.[[NewTarget]] to newTarget.
         new_target: None,
 
+        is_class_static_element: true,
+
         // 6. Set env.[[OuterEnv]] to F.[[Environment]].
         declarative_environment: declarative_environment.unbind(),
     };
@@ -206,6 +219,7 @@ pub(crate) fn new_class_field_initializer_environment<'a>(
             this_binding_status: ThisBindingStatus::Initialized,
             function_object: class_constructor.unbind(),
             new_target: None,
+            is_class_static_element: false,
             declarative_environment: declarative_environment.unbind(),
         },
         gc,
@@ -454,6 +468,12 @@ impl FunctionEnvironment<'_> {
             return false;
         }
 
+        // Note: Class static elements and default class constructors always
+        // have a home object.
+        if env_rec.is_class_static_element {
+            return true;
+        }
+
         // 2. If envRec.[[FunctionObject]].[[HomeObject]] is undefined, return false; otherwise, return true.
         match env_rec.function_object {
             Function::BoundFunction(_) => todo!(),
@@ -462,9 +482,9 @@ impl FunctionEnvironment<'_> {
                 agent[func].ecmascript_function.home_object.is_some()
             }
             Function::BuiltinGeneratorFunction => todo!(),
-            Function::BuiltinConstructorFunction(_) => unreachable!(),
+            Function::BuiltinConstructorFunction(_) => true,
             Function::BuiltinPromiseResolvingFunction(_) => unreachable!(),
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(_) => unreachable!(),
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -483,18 +503,34 @@ impl FunctionEnvironment<'_> {
 
         // 1. Let home be envRec.[[FunctionObject]].[[HomeObject]].
         let home = match env_rec.function_object {
+            // Note: Class static elements do not have a function object of
+            // their own; their home object is the class constructor.
+            function_object if env_rec.is_class_static_element => function_object.into_object(),
             Function::BoundFunction(_) => todo!(),
             Function::BuiltinFunction(_) => unreachable!(),
             // 2. If home is undefined, return undefined.
             Function::ECMAScriptFunction(func) => agent[func].ecmascript_function.home_object?,
             Function::BuiltinGeneratorFunction => todo!(),
-            Function::BuiltinConstructorFunction(_) => unreachable!(),
+            Function::BuiltinConstructorFunction(func) => {
+                // Note: Default class constructors are only used for class
+                // field initializers. Their home object is the class
+                // prototype, which is stored as a non-configurable
+                // "prototype" property on the constructor.
+                let prototype = unwrap_try(func.try_get(
+                    agent,
+                    BUILTIN_STRING_MEMORY.prototype.into(),
+                    func.into_value(),
+                    gc,
+                ));
+                Object::try_from(prototype).unwrap()
+            }
             Function::BuiltinPromiseResolvingFunction(_) => unreachable!(),
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(_) => unreachable!(),
             Function::BuiltinProxyRevokerFunction => todo!(),
         };
         // 3. Assert: home is an ordinary object.
-        let home = OrdinaryObject::try_from(home).unwrap();
+        // Note: The home object of static methods is the class constructor,
+        // which has ordinary [[GetPrototypeOf]] behaviour.
         // 4. Return ! home.[[GetPrototypeOf]]().
         Some(unwrap_try(home.try_get_prototype_of(agent, gc)))
     }
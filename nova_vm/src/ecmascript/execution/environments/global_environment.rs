@@ -126,10 +126,11 @@ pub(crate) fn new_global_environment<'a>(
     let dcl_rec = DeclarativeEnvironmentRecord::new(None);
     agent.heap.alloc_counter += core::mem::size_of::<Option<ObjectEnvironmentRecord>>()
         + core::mem::size_of::<Option<DeclarativeEnvironmentRecord>>();
-    let (object_record, declarative_record) = agent
+    let object_record = agent.heap.environments.push_object_environment(obj_rec, gc);
+    let declarative_record = agent
         .heap
         .environments
-        .push_object_environment(obj_rec, dcl_rec, gc);
+        .push_declarative_environment(dcl_rec, gc);
 
     // 3. Let env be a new Global Environment Record.
     agent.heap.alloc_counter += core::mem::size_of::<Option<GlobalEnvironmentRecord>>();
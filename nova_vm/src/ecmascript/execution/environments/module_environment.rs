@@ -2,7 +2,18 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::DeclarativeEnvironmentRecord;
+use ahash::AHashMap;
+
+use super::{DeclarativeEnvironment, DeclarativeEnvironmentRecord, Environment, ModuleEnvironment};
+use crate::{
+    ecmascript::{
+        execution::{Agent, JsResult, agent::ExceptionType},
+        scripts_and_modules::module::module_semantics::abstract_module_records::AbstractModule,
+        types::{Object, String, Value},
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
 
 /// ### [9.1.1.5 Module Environment Records](https://tc39.es/ecma262/#sec-module-environment-records)
 /// A Module Environment Record is a Declarative Environment Record that is
@@ -15,9 +26,310 @@ use super::DeclarativeEnvironmentRecord;
 /// Record methods listed in Table 16 and share the same specifications for all
 /// of those methods except for GetBindingValue, DeleteBinding, HasThisBinding
 /// and GetThisBinding.
-///
-/// NOTE: There is no data-wise difference between a DeclarativeEnvironment and
-/// a ModuleEnvironment, so we treat them exactly the same way.
 #[derive(Debug, Clone)]
-#[repr(transparent)]
-pub struct ModuleEnvironmentRecord(DeclarativeEnvironmentRecord);
+pub struct ModuleEnvironmentRecord {
+    /// The normal mutable and immutable bindings of the module are stored in
+    /// a Declarative Environment Record.
+    ///
+    /// TODO: Use Struct of Arrays to keep the DeclarativeEnvironment alignside
+    /// ModuleEnvironment
+    declarative_environment: DeclarativeEnvironment<'static>,
+
+    /// The environment's immutable import bindings.
+    indirect_bindings: AHashMap<String<'static>, IndirectBinding>,
+}
+
+/// An immutable import binding: Accessing the binding indirectly accesses
+/// the binding with the given name in the target module's environment.
+#[derive(Debug, Clone, Copy)]
+struct IndirectBinding {
+    module: AbstractModule<'static>,
+    name: String<'static>,
+}
+
+impl HeapMarkAndSweep for ModuleEnvironmentRecord {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            declarative_environment,
+            indirect_bindings,
+        } = self;
+        declarative_environment.mark_values(queues);
+        for (key, binding) in indirect_bindings.iter() {
+            key.mark_values(queues);
+            binding.module.mark_values(queues);
+            binding.name.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            declarative_environment,
+            indirect_bindings,
+        } = self;
+        declarative_environment.sweep_values(compactions);
+        let mut replacements = Vec::new();
+        // Sweep all binding values, while also sweeping keys and making note
+        // of all changes in them: Those need to be updated in a separate loop.
+        for (key, binding) in indirect_bindings.iter_mut() {
+            binding.module.sweep_values(compactions);
+            binding.name.sweep_values(compactions);
+            if let String::String(old_key) = key {
+                let old_key = *old_key;
+                let mut new_key = old_key;
+                new_key.sweep_values(compactions);
+                if old_key != new_key {
+                    replacements.push((old_key, new_key));
+                }
+            }
+        }
+        // Note: Replacement keys are in indeterminate order, we need to sort
+        // them so that "cascading" replacements are applied in the correct
+        // order.
+        replacements.sort();
+        for (old_key, new_key) in replacements.into_iter() {
+            let binding = indirect_bindings.remove(&old_key.into()).unwrap();
+            let did_insert = indirect_bindings.insert(new_key.into(), binding).is_none();
+            assert!(did_insert, "Failed to insert binding {new_key:#?}");
+        }
+    }
+}
+
+impl ModuleEnvironment<'_> {
+    pub(crate) fn get_outer_env<'a>(
+        self,
+        agent: &Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> Option<Environment<'a>> {
+        agent[self].declarative_environment.get_outer_env(agent, gc)
+    }
+
+    fn get_indirect_binding(self, agent: &Agent, name: String) -> Option<IndirectBinding> {
+        agent[self].indirect_bindings.get(&name.unbind()).copied()
+    }
+
+    /// ### [9.1.1.1.1 HasBinding ( N )](https://tc39.es/ecma262/#sec-declarative-environment-records-hasbinding-n)
+    pub(crate) fn has_binding(self, agent: &Agent, name: String) -> bool {
+        agent[self].indirect_bindings.contains_key(&name.unbind())
+            || agent[self].declarative_environment.has_binding(agent, name)
+    }
+
+    /// ### [9.1.1.1.2 CreateMutableBinding ( N, D )](https://tc39.es/ecma262/#sec-declarative-environment-records-createmutablebinding-n-d)
+    pub(crate) fn create_mutable_binding(
+        self,
+        agent: &mut Agent,
+        name: String,
+        is_deletable: bool,
+    ) {
+        agent[self]
+            .declarative_environment
+            .create_mutable_binding(agent, name, is_deletable);
+    }
+
+    /// ### [9.1.1.1.3 CreateImmutableBinding ( N, S )](https://tc39.es/ecma262/#sec-declarative-environment-records-createimmutablebinding-n-s)
+    pub(crate) fn create_immutable_binding(self, agent: &mut Agent, name: String, is_strict: bool) {
+        agent[self]
+            .declarative_environment
+            .create_immutable_binding(agent, name, is_strict);
+    }
+
+    /// ### [9.1.1.1.4 InitializeBinding ( N, V )](https://tc39.es/ecma262/#sec-declarative-environment-records-initializebinding-n-v)
+    pub(crate) fn initialize_binding(self, agent: &mut Agent, name: String, value: Value) {
+        agent[self]
+            .declarative_environment
+            .initialize_binding(agent, name, value);
+    }
+
+    /// ### [9.1.1.1.5 SetMutableBinding ( N, V, S )](https://tc39.es/ecma262/#sec-declarative-environment-records-setmutablebinding-n-v-s)
+    ///
+    /// Import bindings are immutable strict bindings: Attempting to set one
+    /// always throws a TypeError.
+    pub(crate) fn set_mutable_binding<'a>(
+        self,
+        agent: &mut Agent,
+        name: String,
+        value: Value,
+        is_strict: bool,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        if self.get_indirect_binding(agent, name).is_some() {
+            let error_message = format!(
+                "Cannot assign to immutable import binding '{}'.",
+                name.as_str(agent)
+            );
+            return Err(agent.throw_exception(ExceptionType::TypeError, error_message, gc));
+        }
+        agent[self]
+            .declarative_environment
+            .set_mutable_binding(agent, name, value, is_strict, gc)
+    }
+
+    /// ### [9.1.1.5.1 GetBindingValue ( N, S )](https://tc39.es/ecma262/#sec-module-environment-records-getbindingvalue-n-s)
+    ///
+    /// The GetBindingValue concrete method of a Module Environment Record
+    /// envRec takes arguments N (a String) and S (a Boolean) and returns
+    /// either a normal completion containing an ECMAScript language value or a
+    /// throw completion. It returns the value of its bound identifier whose
+    /// name is N. However, if the binding is an indirect binding the value of
+    /// the target binding is returned. If the binding exists but is
+    /// uninitialized a ReferenceError is thrown.
+    pub(crate) fn get_binding_value<'a>(
+        self,
+        agent: &mut Agent,
+        name: String,
+        is_strict: bool,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, Value<'a>> {
+        // 1. Assert: S is true.
+        debug_assert!(is_strict);
+        // 2. Assert: envRec has a binding for N.
+        // 3. If the binding for N is an indirect binding, then
+        if let Some(IndirectBinding {
+            module: m,
+            name: n2,
+        }) = self.get_indirect_binding(agent, name)
+        {
+            // a. Let M and N2 be the indirection values provided when this
+            //    binding for N was created.
+            // b. Let targetEnv be M.[[Environment]].
+            let target_env = m.environment(agent, gc);
+            // c. If targetEnv is EMPTY, throw a ReferenceError exception.
+            let Some(target_env) = target_env else {
+                let error_message = format!(
+                    "Module for import binding '{}' has not been linked.",
+                    name.as_str(agent)
+                );
+                return Err(agent.throw_exception(
+                    ExceptionType::ReferenceError,
+                    error_message,
+                    gc,
+                ));
+            };
+            // d. Return ? targetEnv.GetBindingValue(N2, true).
+            return target_env.get_binding_value(agent, n2, true, gc);
+        }
+        // 4. If the binding for N in envRec is an uninitialized binding, throw
+        //    a ReferenceError exception.
+        // 5. Return the value currently bound to N in envRec.
+        let declarative_environment = agent[self].declarative_environment;
+        let value = declarative_environment
+            .get_binding(agent, name)
+            .and_then(|binding| binding.value);
+        match value {
+            Some(value) => Ok(value.bind(gc)),
+            None => {
+                let error_message = format!(
+                    "Cannot access '{}' before initialization.",
+                    name.as_str(agent)
+                );
+                Err(agent.throw_exception(ExceptionType::ReferenceError, error_message, gc))
+            }
+        }
+    }
+
+    /// ### [9.1.1.5.3 HasThisBinding ( )](https://tc39.es/ecma262/#sec-module-environment-records-hasthisbinding)
+    ///
+    /// The HasThisBinding concrete method of a Module Environment Record
+    /// envRec takes no arguments and returns true.
+    pub(crate) fn has_this_binding(self) -> bool {
+        // 1. Return true.
+        true
+    }
+
+    /// ### [9.1.1.5.4 GetThisBinding ( )](https://tc39.es/ecma262/#sec-module-environment-records-getthisbinding)
+    ///
+    /// The GetThisBinding concrete method of a Module Environment Record
+    /// envRec takes no arguments and returns a normal completion containing
+    /// undefined.
+    pub(crate) fn get_this_binding(self) -> Value<'static> {
+        // 1. Return undefined.
+        Value::Undefined
+    }
+
+    /// ### [9.1.1.1.9 HasSuperBinding ( )](https://tc39.es/ecma262/#sec-declarative-environment-records-hassuperbinding)
+    pub(crate) fn has_super_binding(self) -> bool {
+        // 1. Return false.
+        false
+    }
+
+    /// ### [9.1.1.1.10 WithBaseObject ( )](https://tc39.es/ecma262/#sec-declarative-environment-records-withbaseobject)
+    pub(crate) fn with_base_object(self) -> Option<Object<'static>> {
+        // 1. Return undefined.
+        None
+    }
+
+    /// ### [9.1.1.5.5 CreateImportBinding ( N, M, N2 )](https://tc39.es/ecma262/#sec-createimportbinding)
+    ///
+    /// The CreateImportBinding concrete method of a Module Environment Record
+    /// envRec takes arguments N (a String), M (a Module Record), and N2 (a
+    /// String) and returns UNUSED. It creates a new initialized immutable
+    /// indirect binding for the name N. A binding must not already exist in
+    /// this Environment Record for N. N2 is the name of a binding that exists
+    /// in M's Module Environment Record. Accesses to the value of the new
+    /// binding will indirectly access the bound value of the target binding.
+    pub(crate) fn create_import_binding(
+        self,
+        agent: &mut Agent,
+        name: String,
+        module: AbstractModule,
+        target_name: String,
+    ) {
+        // 1. Assert: envRec does not already have a binding for N.
+        debug_assert!(!self.has_binding(agent, name));
+        // 2. Assert: When M.[[Environment]] is instantiated, it will have a
+        //    direct binding for N2.
+        // 3. Create an immutable indirect binding in envRec for N that
+        //    references M and N2 as its target binding and record that the
+        //    binding is initialized.
+        agent[self].indirect_bindings.insert(
+            name.unbind(),
+            IndirectBinding {
+                module: module.unbind(),
+                name: target_name.unbind(),
+            },
+        );
+        // 4. Return UNUSED.
+    }
+}
+
+impl HeapMarkAndSweep for ModuleEnvironment<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.module_environments.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let self_index = self.into_u32_index();
+        *self = Self::from_u32_index(
+            self_index
+                - compactions
+                    .module_environments
+                    .get_shift_for_index(self_index),
+        );
+    }
+}
+
+/// ### [9.1.2.6 NewModuleEnvironment ( E )](https://tc39.es/ecma262/#sec-newmoduleenvironment)
+///
+/// The abstract operation NewModuleEnvironment takes argument E (an
+/// Environment Record) and returns a Module Environment Record.
+pub(crate) fn new_module_environment<'a>(
+    agent: &mut Agent,
+    outer_env: Environment,
+    gc: NoGcScope<'a, '_>,
+) -> ModuleEnvironment<'a> {
+    // 1. Let env be a new Module Environment Record containing no bindings.
+    // 2. Set env.[[OuterEnv]] to E.
+    let declarative_environment = agent
+        .heap
+        .environments
+        .push_declarative_environment(DeclarativeEnvironmentRecord::new(Some(outer_env)), gc);
+    agent.heap.alloc_counter += core::mem::size_of::<Option<DeclarativeEnvironmentRecord>>()
+        + core::mem::size_of::<Option<ModuleEnvironmentRecord>>();
+    // 3. Return env.
+    agent.heap.environments.push_module_environment(
+        ModuleEnvironmentRecord {
+            declarative_environment: declarative_environment.unbind(),
+            indirect_bindings: AHashMap::default(),
+        },
+        gc,
+    )
+}
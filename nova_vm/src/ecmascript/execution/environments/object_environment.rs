@@ -88,6 +88,24 @@ impl ObjectEnvironmentRecord {
     }
 }
 
+/// ### [9.1.2.3 NewObjectEnvironment ( O, W, E )](https://tc39.es/ecma262/#sec-newobjectenvironment)
+///
+/// Allocates a new Object Environment Record on the heap. This is used by
+/// `with` statements, which create their environments at runtime.
+pub(crate) fn new_object_environment<'a>(
+    agent: &mut Agent,
+    binding_object: Object,
+    is_with_environment: bool,
+    outer_env: OuterEnv,
+    gc: NoGcScope<'a, '_>,
+) -> ObjectEnvironment<'a> {
+    agent.heap.alloc_counter += core::mem::size_of::<Option<ObjectEnvironmentRecord>>();
+    agent.heap.environments.push_object_environment(
+        ObjectEnvironmentRecord::new(binding_object, is_with_environment, outer_env),
+        gc,
+    )
+}
+
 impl HeapMarkAndSweep for ObjectEnvironmentRecord {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
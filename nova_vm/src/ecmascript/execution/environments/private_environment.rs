@@ -5,27 +5,16 @@
 use ahash::AHashMap;
 
 use crate::{
-    ecmascript::types::{Function, Value},
-    engine::context::Bindable,
+    ecmascript::{
+        execution::Agent,
+        types::{PrivateElement, PrivateName, push_private_method},
+    },
+    engine::context::{Bindable, NoGcScope},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
 use super::PrivateEnvironment;
 
-#[derive(Debug)]
-pub enum PrivateName {
-    Field(Option<Value<'static>>),
-    Method(Option<Function<'static>>),
-    /// Accessor(get, set)
-    Accessor(Option<Function<'static>>, Option<Function<'static>>),
-}
-
-impl PrivateName {
-    pub fn description(&self) -> &'static str {
-        "identifier"
-    }
-}
-
 /// ### [9.2 PrivateEnvironment Records](https://tc39.es/ecma262/#sec-privateenvironment-records)
 ///
 /// A PrivateEnvironment Record is a specification mechanism used to track
@@ -46,17 +35,42 @@ pub struct PrivateEnvironmentRecord {
 
     /// ### \[\[Names\]\]
     ///
-    /// The Private Names declared by this class.
+    /// The Private Names declared by this class, keyed by their
+    /// \[\[Description]].
     pub(crate) names: AHashMap<String, PrivateName>,
+
+    /// ### \[\[PrivateMethods\]\]
+    ///
+    /// The private methods and accessors of instances of the class.
+    ///
+    /// The specification stores these in the class constructor. Each class
+    /// evaluation creates exactly one constructor and one PrivateEnvironment
+    /// Record, and the field initializers of the constructor always run with
+    /// this PrivateEnvironment Record, so Nova stores the list here instead.
+    pub(crate) instance_private_methods: Vec<PrivateElement<'static>>,
 }
 
 impl HeapMarkAndSweep for PrivateEnvironmentRecord {
-    fn mark_values(&self, _queues: &mut WorkQueues) {
-        todo!()
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            outer_private_environment,
+            names: _,
+            instance_private_methods,
+        } = self;
+        outer_private_environment.mark_values(queues);
+        instance_private_methods.as_slice().mark_values(queues);
     }
 
-    fn sweep_values(&mut self, _compactions: &CompactionLists) {
-        todo!()
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            outer_private_environment,
+            names: _,
+            instance_private_methods,
+        } = self;
+        outer_private_environment.sweep_values(compactions);
+        instance_private_methods
+            .as_mut_slice()
+            .sweep_values(compactions);
     }
 }
 
@@ -64,26 +78,89 @@ impl HeapMarkAndSweep for PrivateEnvironmentRecord {
 ///
 /// The abstract operation NewPrivateEnvironment takes argument outerPrivEnv (a
 /// PrivateEnvironment Record or null) and returns a PrivateEnvironment Record.
-pub(crate) fn new_private_environment(
+pub(crate) fn new_private_environment<'a>(
+    agent: &mut Agent,
     outer_private_environment: Option<PrivateEnvironment>,
-) -> PrivateEnvironmentRecord {
+    gc: NoGcScope<'a, '_>,
+) -> PrivateEnvironment<'a> {
+    agent.heap.alloc_counter += core::mem::size_of::<Option<PrivateEnvironmentRecord>>();
     // 1. Let names be a new empty List.
     // 2. Return the PrivateEnvironment Record {
-    PrivateEnvironmentRecord {
+    let record = PrivateEnvironmentRecord {
         // [[OuterPrivateEnvironment]]: outerPrivEnv,
         outer_private_environment: outer_private_environment.unbind(),
         // [[Names]]: names
         names: Default::default(),
-    }
+        instance_private_methods: Default::default(),
+    };
     // }.
+    agent.heap.environments.push_private_environment(record, gc)
+}
+
+impl PrivateEnvironment<'_> {
+    /// Add a new Private Name with the given \[\[Description]] to this
+    /// PrivateEnvironment Record, unless one already exists.
+    ///
+    /// A class may only declare a Private Name twice if the declarations are
+    /// a getter and setter pair.
+    pub(crate) fn add_private_name(self, agent: &mut Agent, description: &str) {
+        // i. If classPrivateEnvironment.[[Names]] contains a Private Name pn
+        //    such that pn.[[Description]] is dn, then
+        if agent[self].names.contains_key(description) {
+            // 1. Assert: This is only possible for getter/setter pairs.
+            return;
+        }
+        // ii. Else,
+        // 1. Let name be a new Private Name whose [[Description]] is dn.
+        let name = PrivateName::new(agent);
+        // 2. Append name to classPrivateEnvironment.[[Names]].
+        agent[self].names.insert(description.to_string(), name);
+    }
+
+    /// ### [9.2.1.2 ResolvePrivateIdentifier ( privateEnv, identifier )](https://tc39.es/ecma262/#sec-resolve-private-identifier)
+    ///
+    /// The abstract operation ResolvePrivateIdentifier takes arguments
+    /// privateEnv (a PrivateEnvironment Record) and identifier (a String) and
+    /// returns a Private Name.
+    pub(crate) fn resolve_private_identifier(self, agent: &Agent, identifier: &str) -> PrivateName {
+        let mut private_env = self.unbind();
+        loop {
+            let env = &agent[private_env];
+            // 1. Let names be privateEnv.[[Names]].
+            // 2. For each Private Name pn of names, do
+            // a. If pn.[[Description]] is identifier, then
+            if let Some(name) = env.names.get(identifier) {
+                // i. Return pn.
+                return *name;
+            }
+            // 3. Let outerPrivateEnvironment be privateEnv.[[OuterPrivateEnvironment]].
+            // 4. Assert: outerPrivateEnvironment is not null.
+            // 5. Return ResolvePrivateIdentifier(outerPrivateEnvironment, identifier).
+            private_env = env
+                .outer_private_environment
+                .expect("Could not resolve Private Name");
+        }
+    }
+
+    /// Append a private method or accessor to the \[\[PrivateMethods]] of
+    /// instances of the class.
+    pub(crate) fn add_instance_private_method(self, agent: &mut Agent, element: PrivateElement) {
+        push_private_method(&mut agent[self].instance_private_methods, element);
+    }
 }
 
 impl HeapMarkAndSweep for PrivateEnvironment<'static> {
-    fn mark_values(&self, _queues: &mut WorkQueues) {
-        todo!()
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.private_environments.push(*self);
     }
 
-    fn sweep_values(&mut self, _compactions: &CompactionLists) {
-        todo!()
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let self_index = self.into_u32_index();
+        *self = Self::from_u32_index(
+            self_index
+                - compactions
+                    .private_environments
+                    .get_shift_for_index(self_index),
+        );
     }
 }
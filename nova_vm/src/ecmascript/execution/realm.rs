@@ -6,11 +6,17 @@ mod intrinsics;
 
 use super::new_global_environment;
 use super::{Agent, ExecutionContext, JsResult, environments::GlobalEnvironment};
+#[cfg(feature = "annex-b-regexp")]
+use crate::ecmascript::builtins::regexp::legacy_features::LegacyRegExpStatics;
 use crate::engine::context::{Bindable, GcScope, GcToken, NoGcScope};
 use crate::engine::rootable::{HeapRootData, HeapRootRef, Rootable, Scopable};
 use crate::{
     ecmascript::{
         abstract_operations::operations_on_objects::define_property_or_throw,
+        builtins::Array,
+        scripts_and_modules::{
+            module::module_semantics::LoadedModuleRequest, source_code::SourceCode,
+        },
         types::{
             BUILTIN_STRING_MEMORY, IntoValue, Number, Object, OrdinaryObject, PropertyDescriptor,
             PropertyKey, Value,
@@ -197,22 +203,31 @@ pub struct RealmRecord<'a> {
     /// Realm Record's \[\[TemplateMap]]. Each \[\[Site]] value is a Parse Node
     /// that is a TemplateLiteral. The associated \[\[Array]] value is the
     /// corresponding template object that is passed to a tag function.
-    /// NOTE: The template data is included in the AST.
-    template_map: (),
+    ///
+    /// Parse Nodes are identified by their source code and the offset of the
+    /// template literal within it. Holding the source code alive keeps the
+    /// site identity unique for the lifetime of the realm.
+    pub(crate) template_map: Vec<TemplateRecord<'a>>,
 
     /// ### \[\[LoadedModules]]
     ///
     /// A map from the specifier strings imported by this realm to the resolved
     /// Module Record. The list does not contain two different Records with the
     /// same \[\[Specifier]].
-    // TODO: Include this once we support modules.
-    loaded_modules: (),
+    pub(crate) loaded_modules: Vec<LoadedModuleRequest<'a>>,
 
     /// ### \[\[HostDefined]]
     ///
     /// Field reserved for use by hosts that need to associate additional
     /// information with a Realm Record.
     pub(crate) host_defined: Option<&'static dyn Any>,
+
+    /// ### Legacy RegExp static properties
+    ///
+    /// The \[\[RegExpInput]], \[\[RegExpLastMatch]] etc. internal slots of
+    /// this realm's %RegExp% constructor.
+    #[cfg(feature = "annex-b-regexp")]
+    pub(crate) legacy_reg_exp_statics: LegacyRegExpStatics<'a>,
 }
 
 unsafe impl Send for RealmRecord<'_> {}
@@ -249,13 +264,19 @@ impl HeapMarkAndSweep for RealmRecord<'static> {
             intrinsics,
             global_object,
             global_env,
-            template_map: _,
-            loaded_modules: _,
+            template_map,
+            loaded_modules,
             host_defined: _,
+            #[cfg(feature = "annex-b-regexp")]
+            legacy_reg_exp_statics,
         } = self;
         intrinsics.mark_values(queues);
         global_env.mark_values(queues);
         global_object.mark_values(queues);
+        template_map.as_slice().mark_values(queues);
+        loaded_modules.as_slice().mark_values(queues);
+        #[cfg(feature = "annex-b-regexp")]
+        legacy_reg_exp_statics.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -264,13 +285,56 @@ impl HeapMarkAndSweep for RealmRecord<'static> {
             intrinsics,
             global_object,
             global_env,
-            template_map: _,
-            loaded_modules: _,
+            template_map,
+            loaded_modules,
             host_defined: _,
+            #[cfg(feature = "annex-b-regexp")]
+            legacy_reg_exp_statics,
         } = self;
         intrinsics.sweep_values(compactions);
         global_env.sweep_values(compactions);
         global_object.sweep_values(compactions);
+        template_map.as_mut_slice().sweep_values(compactions);
+        loaded_modules.as_mut_slice().sweep_values(compactions);
+        #[cfg(feature = "annex-b-regexp")]
+        legacy_reg_exp_statics.sweep_values(compactions);
+    }
+}
+
+/// An entry of a Realm Record's \[\[TemplateMap]].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TemplateRecord<'a> {
+    /// ### \[\[Site]]
+    ///
+    /// The source code containing the TemplateLiteral Parse Node.
+    pub(crate) source_code: SourceCode<'a>,
+    /// The offset of the \[\[Site]] Parse Node in its source code.
+    pub(crate) site: u32,
+    /// ### \[\[Array]]
+    ///
+    /// The template object for the site.
+    pub(crate) array: Array<'a>,
+}
+
+impl HeapMarkAndSweep for TemplateRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            source_code,
+            site: _,
+            array,
+        } = self;
+        source_code.mark_values(queues);
+        array.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            source_code,
+            site: _,
+            array,
+        } = self;
+        source_code.sweep_values(compactions);
+        array.sweep_values(compactions);
     }
 }
 
@@ -294,11 +358,13 @@ pub(crate) fn create_realm<'gc>(agent: &mut Agent, gc: NoGcScope<'gc, '_>) -> Re
         global_env: None,
 
         // 6. Set realmRec.[[TemplateMap]] to a new empty List.
-        template_map: (),
+        template_map: vec![],
 
         // NOTE: These fields are implicitly empty.
         host_defined: None,
-        loaded_modules: (),
+        loaded_modules: vec![],
+        #[cfg(feature = "annex-b-regexp")]
+        legacy_reg_exp_statics: Default::default(),
     };
 
     // 7. Return realmRec.
@@ -495,6 +561,10 @@ pub(crate) fn set_default_global_bindings<'a>(
         #[cfg(feature = "array-buffer")]
         define_property!(intrinsic ArrayBuffer, array_buffer);
 
+        // AsyncDisposableStack ( )
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        define_property!(intrinsic AsyncDisposableStack, async_disposable_stack);
+
         // 19.3.4 BigInt ( . . . )
         define_property!(intrinsic BigInt, big_int);
 
@@ -517,6 +587,10 @@ pub(crate) fn set_default_global_bindings<'a>(
         #[cfg(feature = "date")]
         define_property!(intrinsic Date, date);
 
+        // DisposableStack ( )
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        define_property!(intrinsic DisposableStack, disposable_stack);
+
         // 19.3.10 Error ( . . . )
         define_property!(intrinsic Error, error);
 
@@ -592,6 +666,10 @@ pub(crate) fn set_default_global_bindings<'a>(
         // 19.3.31 String ( . . . )
         define_property!(intrinsic String, string);
 
+        // SuppressedError ( . . . )
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        define_property!(intrinsic SuppressedError, suppressed_error);
+
         // 19.3.32 Symbol ( . . . )
         define_property!(intrinsic Symbol, symbol);
 
@@ -651,6 +729,17 @@ pub(crate) fn set_default_global_bindings<'a>(
         define_property!(intrinsic Reflect, reflect);
     }
 
+    // B.2.1 Additional Properties of the Global Object
+    {
+        // B.2.1.1 escape ( string )
+        #[cfg(feature = "annex-b-global")]
+        define_property!(intrinsic escape, escape);
+
+        // B.2.1.2 unescape ( string )
+        #[cfg(feature = "annex-b-global")]
+        define_property!(intrinsic unescape, unescape);
+    }
+
     // 3. Return global.
     Ok(global.get(agent).bind(gc.into_nogc()))
 }
@@ -3,6 +3,22 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use super::Realm;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::control_abstraction_objects::{
+    async_disposable_stack_objects::{
+        async_disposable_stack_constructor::AsyncDisposableStackConstructor,
+        async_disposable_stack_prototype::AsyncDisposableStackPrototype,
+    },
+    disposable_stack_objects::{
+        disposable_stack_constructor::DisposableStackConstructor,
+        disposable_stack_prototype::DisposableStackPrototype,
+    },
+};
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::fundamental_objects::error_objects::{
+    suppressed_error_constructors::SuppressedErrorConstructor,
+    suppressed_error_prototypes::SuppressedErrorPrototype,
+};
 #[cfg(feature = "set")]
 use crate::ecmascript::builtins::keyed_collections::set_objects::{
     set_constructor::SetConstructor,
@@ -40,7 +56,7 @@ use crate::ecmascript::builtins::structured_data::shared_array_buffer_objects::{
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::text_processing::regexp_objects::{
     regexp_constructor::RegExpConstructor, regexp_prototype::RegExpPrototype,
-    regexp_string_iterator_prototype::RegExpStringIteratorPrototype,
+    regexp_string_iterator_objects::RegExpStringIteratorPrototype,
 };
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{
@@ -169,6 +185,9 @@ pub enum ProtoIntrinsics {
     #[cfg(feature = "array-buffer")]
     ArrayBuffer,
     ArrayIterator,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AsyncDisposableStack,
+    AsyncFromSyncIterator,
     AsyncFunction,
     AsyncGenerator,
     AsyncGeneratorFunction,
@@ -182,6 +201,8 @@ pub enum ProtoIntrinsics {
     DataView,
     #[cfg(feature = "date")]
     Date,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack,
     Error,
     EvalError,
     FinalizationRegistry,
@@ -210,6 +231,8 @@ pub enum ProtoIntrinsics {
     ReferenceError,
     #[cfg(feature = "regexp")]
     RegExp,
+    #[cfg(feature = "regexp")]
+    RegExpStringIterator,
     #[cfg(feature = "set")]
     Set,
     #[cfg(feature = "set")]
@@ -218,6 +241,8 @@ pub enum ProtoIntrinsics {
     SharedArrayBuffer,
     String,
     StringIterator,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    SuppressedError,
     Symbol,
     SyntaxError,
     TypeError,
@@ -286,6 +311,10 @@ impl Intrinsics {
         NativeErrorConstructors::create_intrinsic(agent, realm);
         AggregateErrorPrototype::create_intrinsic(agent, realm);
         AggregateErrorConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        SuppressedErrorPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        SuppressedErrorConstructor::create_intrinsic(agent, realm);
         NumberPrototype::create_intrinsic(agent, realm);
         NumberConstructor::create_intrinsic(agent, realm, gc);
         BigIntPrototype::create_intrinsic(agent, realm);
@@ -368,6 +397,14 @@ impl Intrinsics {
         AsyncGeneratorPrototype::create_intrinsic(agent, realm);
         AsyncFunctionPrototype::create_intrinsic(agent, realm);
         AsyncFunctionConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        DisposableStackPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        DisposableStackConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        AsyncDisposableStackPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        AsyncDisposableStackConstructor::create_intrinsic(agent, realm);
         ReflectObject::create_intrinsic(agent, realm);
         ProxyConstructor::create_intrinsic(agent, realm);
         IteratorConstructor::create_intrinsic(agent, realm);
@@ -385,6 +422,9 @@ impl Intrinsics {
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::ArrayBuffer => self.array_buffer_prototype().into(),
             ProtoIntrinsics::ArrayIterator => self.array_iterator_prototype().into(),
+            ProtoIntrinsics::AsyncFromSyncIterator => {
+                self.async_from_sync_iterator_prototype().into()
+            }
             ProtoIntrinsics::BigInt => self.big_int_prototype().into(),
             ProtoIntrinsics::Boolean => self.boolean_prototype().into(),
             ProtoIntrinsics::Error => self.error_prototype().into(),
@@ -403,6 +443,12 @@ impl Intrinsics {
             ProtoIntrinsics::TypeError => self.type_error_prototype().into(),
             ProtoIntrinsics::UriError => self.uri_error_prototype().into(),
             ProtoIntrinsics::AggregateError => self.aggregate_error_prototype().into(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::AsyncDisposableStack => self.async_disposable_stack_prototype().into(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::DisposableStack => self.disposable_stack_prototype().into(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::SuppressedError => self.suppressed_error_prototype().into(),
             ProtoIntrinsics::AsyncFunction => self.async_function_prototype().into(),
             ProtoIntrinsics::AsyncGenerator => self.async_generator_prototype().into(),
             ProtoIntrinsics::AsyncGeneratorFunction => {
@@ -435,6 +481,10 @@ impl Intrinsics {
             ProtoIntrinsics::Promise => self.promise_prototype().into(),
             #[cfg(feature = "regexp")]
             ProtoIntrinsics::RegExp => self.reg_exp_prototype().into(),
+            #[cfg(feature = "regexp")]
+            ProtoIntrinsics::RegExpStringIterator => {
+                self.reg_exp_string_iterator_prototype().into()
+            }
             #[cfg(feature = "set")]
             ProtoIntrinsics::Set => self.set_prototype().into(),
             #[cfg(feature = "set")]
@@ -573,6 +623,37 @@ impl Intrinsics {
             .into()
     }
 
+    /// %AsyncDisposableStack.prototype.disposeAsync%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn async_disposable_stack_prototype_dispose_async(
+        &self,
+    ) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::AsyncDisposableStackPrototypeDisposeAsync
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    /// %AsyncDisposableStack.prototype%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn async_disposable_stack_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::AsyncDisposableStackPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    /// %AsyncDisposableStack%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn async_disposable_stack(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::AsyncDisposableStack
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn async_disposable_stack_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::AsyncDisposableStack.get_object_index(self.object_index_base)
+    }
+
     /// %AsyncFromSyncIteratorPrototype%
     pub(crate) fn async_from_sync_iterator_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::AsyncFromSyncIteratorPrototype
@@ -580,6 +661,13 @@ impl Intrinsics {
             .into()
     }
 
+    /// %AsyncFromSyncIteratorPrototype.next%
+    pub(crate) fn async_from_sync_iterator_prototype_next(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::AsyncFromSyncIteratorPrototypeNext
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
     /// %AsyncFunction.prototype%
     pub(crate) fn async_function_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::AsyncFunctionPrototype
@@ -791,6 +879,35 @@ impl Intrinsics {
             .into()
     }
 
+    /// %DisposableStack.prototype.dispose%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn disposable_stack_prototype_dispose(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::DisposableStackPrototypeDispose
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    /// %DisposableStack.prototype%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn disposable_stack_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::DisposableStackPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    /// %DisposableStack%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn disposable_stack(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::DisposableStack
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn disposable_stack_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::DisposableStack.get_object_index(self.object_index_base)
+    }
+
     /// %encodeURI%
     pub(crate) fn encode_uri(&self) -> BuiltinFunction<'static> {
         IntrinsicFunctionIndexes::EncodeURI
@@ -823,6 +940,7 @@ impl Intrinsics {
         IntrinsicConstructorIndexes::Error.get_object_index(self.object_index_base)
     }
 
+    #[cfg(feature = "annex-b-global")]
     /// %escape%
     pub(crate) fn escape(&self) -> BuiltinFunction<'static> {
         IntrinsicFunctionIndexes::Escape
@@ -1397,6 +1515,27 @@ impl Intrinsics {
             .into()
     }
 
+    /// %SuppressedError.prototype%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn suppressed_error_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::SuppressedErrorPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    /// %SuppressedError%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn suppressed_error(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::SuppressedError
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn suppressed_error_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::SuppressedError.get_object_index(self.object_index_base)
+    }
+
     /// %Symbol.prototype%
     pub(crate) fn symbol_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::SymbolPrototype
@@ -1567,6 +1706,7 @@ impl Intrinsics {
         IntrinsicConstructorIndexes::Uint8ClampedArray.get_object_index(self.object_index_base)
     }
 
+    #[cfg(feature = "annex-b-global")]
     /// %unescape%
     pub(crate) fn unescape(&self) -> BuiltinFunction<'static> {
         IntrinsicFunctionIndexes::Unescape
@@ -1670,8 +1810,17 @@ impl HeapMarkAndSweep for Intrinsics {
         #[cfg(feature = "array-buffer")]
         self.array_buffer().mark_values(queues);
         self.array_iterator_prototype().mark_values(queues);
+        self.async_from_sync_iterator_prototype_next()
+            .mark_values(queues);
         self.async_from_sync_iterator_prototype()
             .mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.async_disposable_stack_prototype_dispose_async()
+            .mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.async_disposable_stack_prototype().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.async_disposable_stack().mark_values(queues);
         self.async_function_prototype().mark_values(queues);
         self.async_function().mark_values(queues);
         self.async_generator_function_prototype()
@@ -1705,10 +1854,18 @@ impl HeapMarkAndSweep for Intrinsics {
         self.date().mark_values(queues);
         self.decode_uri().mark_values(queues);
         self.decode_uri_component().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.disposable_stack_prototype_dispose()
+            .mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.disposable_stack_prototype().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.disposable_stack().mark_values(queues);
         self.encode_uri().mark_values(queues);
         self.encode_uri_component().mark_values(queues);
         self.error_prototype().mark_values(queues);
         self.error().mark_values(queues);
+        #[cfg(feature = "annex-b-global")]
         self.escape().mark_values(queues);
         self.eval().mark_values(queues);
         self.eval_error_prototype().mark_values(queues);
@@ -1789,6 +1946,10 @@ impl HeapMarkAndSweep for Intrinsics {
         self.string_prototype().mark_values(queues);
         self.string().mark_values(queues);
         self.string_iterator_prototype().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.suppressed_error_prototype().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.suppressed_error().mark_values(queues);
         self.symbol_prototype().mark_values(queues);
         self.symbol().mark_values(queues);
         self.syntax_error_prototype().mark_values(queues);
@@ -1821,6 +1982,7 @@ impl HeapMarkAndSweep for Intrinsics {
         self.uint8_clamped_array().mark_values(queues);
         #[cfg(feature = "array-buffer")]
         self.uint8_clamped_array_prototype().mark_values(queues);
+        #[cfg(feature = "annex-b-global")]
         self.unescape().mark_values(queues);
         self.uri_error_prototype().mark_values(queues);
         self.uri_error().mark_values(queues);
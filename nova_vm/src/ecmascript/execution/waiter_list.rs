@@ -0,0 +1,320 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [25.4.1 Waiter Record](https://tc39.es/ecma262/#sec-waiter-record)
+//!
+//! ### [25.4.2 WaiterList Records](https://tc39.es/ecma262/#sec-waiterlist-records)
+//!
+//! The waiters of `Atomics.wait` and `Atomics.waitAsync`, and the critical
+//! section that `Atomics.notify` uses to wake them. WaiterLists are shared
+//! between all agents in the agent cluster, so they are synchronised using
+//! the standard library's `Mutex` and `Condvar` instead of the GC heap.
+
+use std::{
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+/// ### [25.4.1 Waiter Record](https://tc39.es/ecma262/#sec-waiter-record)
+#[derive(Debug)]
+struct WaiterRecord {
+    /// Identifies the waiter within its WaiterList; this takes the place of
+    /// the \[\[AgentSignifier\]\] and \[\[PromiseCapability\]\] fields.
+    id: u64,
+    /// The byte index in the Shared Data Block that the waiter waits on.
+    byte_index: usize,
+    /// Set by NotifyWaiter. A notified waiter is no longer considered to be
+    /// in the WaiterList; it only stays in the list until the waiting agent
+    /// has observed the notification.
+    notified: bool,
+}
+
+#[derive(Debug, Default)]
+struct Waiters {
+    next_id: u64,
+    records: Vec<WaiterRecord>,
+}
+
+impl Waiters {
+    /// ### [25.4.3.8 AddWaiter ( WL, waiterRecord )](https://tc39.es/ecma262/#sec-addwaiter)
+    fn add_waiter(&mut self, byte_index: usize) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.records.push(WaiterRecord {
+            id,
+            byte_index,
+            notified: false,
+        });
+        id
+    }
+
+    /// ### [25.4.3.9 RemoveWaiter ( WL, waiterRecord )](https://tc39.es/ecma262/#sec-removewaiter)
+    ///
+    /// Removes the waiter from the WaiterList and returns true if it had been
+    /// notified.
+    fn remove_waiter(&mut self, id: u64) -> bool {
+        let index = self
+            .records
+            .iter()
+            .position(|record| record.id == id)
+            .expect("Waiter was removed from its WaiterList twice");
+        self.records.swap_remove(index).notified
+    }
+
+    fn is_notified(&self, id: u64) -> bool {
+        self.records
+            .iter()
+            .find(|record| record.id == id)
+            .is_some_and(|record| record.notified)
+    }
+}
+
+/// The result of a call to `Atomics.wait`, or the value that the promise
+/// returned by `Atomics.waitAsync` is resolved with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WaitResult {
+    /// ### "ok"
+    Ok,
+    /// ### "not-equal"
+    NotEqual,
+    /// ### "timed-out"
+    TimedOut,
+}
+
+/// ### [25.4.2 WaiterList Records](https://tc39.es/ecma262/#sec-waiterlist-records)
+///
+/// The spec has one WaiterList per byte index of a Shared Data Block. In Nova
+/// a Shared Data Block has a single WaiterList that all of its byte indexes
+/// share, and each Waiter Record remembers the byte index it waits on.
+#[derive(Debug, Default)]
+pub(crate) struct WaiterList {
+    /// The WaiterList's critical section and its \[\[Waiters\]\].
+    waiters: Mutex<Waiters>,
+    /// Used to suspend agents waiting on the WaiterList until they are
+    /// notified.
+    condvar: Condvar,
+}
+
+impl WaiterList {
+    /// ### [25.4.3.6 EnterCriticalSection ( WL )](https://tc39.es/ecma262/#sec-entercriticalsection)
+    fn enter_critical_section(&self) -> MutexGuard<'_, Waiters> {
+        // Note: The WaiterList is never left in an inconsistent state, so a
+        // panic in another agent holding the lock can be ignored.
+        self.waiters
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Suspends the current thread until the waiter has been notified or
+    /// its timeout time has passed.
+    fn suspend_until_ready<'a>(
+        &self,
+        mut waiters: MutexGuard<'a, Waiters>,
+        id: u64,
+        deadline: Option<Instant>,
+    ) -> MutexGuard<'a, Waiters> {
+        loop {
+            if waiters.is_notified(id) {
+                return waiters;
+            }
+            match deadline {
+                None => {
+                    waiters = self
+                        .condvar
+                        .wait(waiters)
+                        .unwrap_or_else(|poisoned| poisoned.into_inner());
+                }
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return waiters;
+                    }
+                    waiters = self
+                        .condvar
+                        .wait_timeout(waiters, deadline - now)
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .0;
+                }
+            }
+        }
+    }
+
+    /// The critical section of
+    /// [25.4.3.14 DoWait](https://tc39.es/ecma262/#sec-dowait) with mode
+    /// sync.
+    ///
+    /// `is_equal` reads the value at the byte index inside the critical
+    /// section and compares it with the expected value. If they are equal,
+    /// the current thread is suspended until the waiter is notified or
+    /// `timeout` has passed; a `timeout` of None waits forever.
+    pub(crate) fn wait(
+        &self,
+        byte_index: usize,
+        is_equal: impl FnOnce() -> bool,
+        timeout: Option<Duration>,
+    ) -> WaitResult {
+        // 17. Perform EnterCriticalSection(WL).
+        let mut waiters = self.enter_critical_section();
+        // 18. Let elementType be TypedArrayElementType(typedArray).
+        // 19. Let w be GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, SEQ-CST).
+        // 20. If v ≠ w, then
+        if !is_equal() {
+            // a. Perform LeaveCriticalSection(WL).
+            // b. If mode is sync, return "not-equal".
+            return WaitResult::NotEqual;
+        }
+        // 22. Let thisAgent be AgentSignifier().
+        // 23. Let now be the time value (UTC) identifying the current time.
+        // 24. Let additionalTimeout be an implementation-defined non-negative mathematical value.
+        // 25. Let timeoutTime be ℝ(now) + t + additionalTimeout.
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        // 26. NOTE: When t is +∞, timeoutTime is also +∞.
+        // 27. Let waiterRecord be a new Waiter Record { [[AgentSignifier]]: thisAgent, [[PromiseCapability]]: promiseCapability, [[TimeoutTime]]: timeoutTime, [[Result]]: "ok" }.
+        // 28. Perform AddWaiter(WL, waiterRecord).
+        let id = waiters.add_waiter(byte_index);
+        // 29. If mode is sync, then
+        // a. Perform SuspendThisAgent(WL, waiterRecord).
+        let mut waiters = self.suspend_until_ready(waiters, id, deadline);
+        // 31. Perform LeaveCriticalSection(WL).
+        // 32. If mode is sync, return waiterRecord.[[Result]].
+        if waiters.remove_waiter(id) {
+            WaitResult::Ok
+        } else {
+            WaitResult::TimedOut
+        }
+    }
+
+    /// The critical section of
+    /// [25.4.3.14 DoWait](https://tc39.es/ecma262/#sec-dowait) with mode
+    /// async.
+    ///
+    /// Returns the result directly if the wait finished without waiting, and
+    /// otherwise an [AtomicsWaiter] that the host uses to find out when the
+    /// wait has finished.
+    pub(crate) fn wait_async(
+        self: &Arc<Self>,
+        byte_index: usize,
+        is_equal: impl FnOnce() -> bool,
+        timeout: Option<Duration>,
+    ) -> Result<AtomicsWaiter, WaitResult> {
+        // 17. Perform EnterCriticalSection(WL).
+        let mut waiters = self.enter_critical_section();
+        // 20. If v ≠ w, then
+        if !is_equal() {
+            // a. Perform LeaveCriticalSection(WL).
+            // c. Perform ! Call(capability.[[Resolve]], undefined, « "not-equal" »).
+            return Err(WaitResult::NotEqual);
+        }
+        // 21. If t = 0 and mode is async, then
+        if timeout == Some(Duration::ZERO) {
+            // a. NOTE: There is no special handling of synchronous immediate
+            //    timeouts. Asynchronous immediate timeouts have special
+            //    handling in order to fail fast and avoid unnecessary Promise
+            //    jobs.
+            // b. Perform LeaveCriticalSection(WL).
+            // c. Perform ! Call(capability.[[Resolve]], undefined, « "timed-out" »).
+            return Err(WaitResult::TimedOut);
+        }
+        // 25. Let timeoutTime be ℝ(now) + t + additionalTimeout.
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        // 28. Perform AddWaiter(WL, waiterRecord).
+        let id = waiters.add_waiter(byte_index);
+        // 30. Else if timeoutTime is finite, then
+        // a. Perform EnqueueAtomicsWaitAsyncTimeoutJob(WL, waiterRecord).
+        // 31. Perform LeaveCriticalSection(WL).
+        Ok(AtomicsWaiter {
+            waiter_list: self.clone(),
+            id,
+            deadline,
+        })
+    }
+
+    /// The critical section of
+    /// [25.4.15 Atomics.notify](https://tc39.es/ecma262/#sec-atomics.notify).
+    ///
+    /// Notifies at most `count` waiters waiting on `byte_index`, or all of
+    /// them if `count` is None, and returns the number of waiters notified.
+    pub(crate) fn notify(&self, byte_index: usize, count: Option<usize>) -> usize {
+        // 9. Perform EnterCriticalSection(WL).
+        let mut waiters = self.enter_critical_section();
+        // 10. Let S be RemoveWaiters(WL, c).
+        let mut n = 0;
+        for record in waiters
+            .records
+            .iter_mut()
+            .filter(|record| record.byte_index == byte_index && !record.notified)
+        {
+            if count.is_some_and(|count| n >= count) {
+                break;
+            }
+            // 11. For each element W of S, do
+            // a. Perform NotifyWaiter(WL, W).
+            record.notified = true;
+            n += 1;
+        }
+        if n > 0 {
+            self.condvar.notify_all();
+        }
+        // 12. Perform LeaveCriticalSection(WL).
+        // 13. Let n be the number of elements in S.
+        // 14. Return 𝔽(n).
+        n
+    }
+}
+
+/// A pending `Atomics.waitAsync` call.
+///
+/// Each AtomicsWaiter is handed to the host together with the Job that
+/// resolves the promise returned by `Atomics.waitAsync`. The Job should be
+/// run once [`is_ready`](AtomicsWaiter::is_ready) returns true; the waiter
+/// can be sent to another thread that calls [`wait`](AtomicsWaiter::wait) to
+/// find out when that is.
+#[derive(Debug, Clone)]
+pub struct AtomicsWaiter {
+    waiter_list: Arc<WaiterList>,
+    id: u64,
+    deadline: Option<Instant>,
+}
+
+impl AtomicsWaiter {
+    /// The time at which the wait times out, or None if it only finishes when
+    /// notified.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Returns true if the waiter has been notified or its timeout has
+    /// passed.
+    pub fn is_ready(&self) -> bool {
+        let waiters = self.waiter_list.enter_critical_section();
+        waiters.is_notified(self.id)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Blocks the current thread until the waiter has been notified or its
+    /// timeout has passed.
+    pub fn wait(&self) {
+        let waiters = self.waiter_list.enter_critical_section();
+        drop(
+            self.waiter_list
+                .suspend_until_ready(waiters, self.id, self.deadline),
+        );
+    }
+
+    /// Removes the waiter from its WaiterList and returns the result of the
+    /// wait, first blocking until the waiter is ready if necessary.
+    pub(crate) fn finish(self) -> WaitResult {
+        let waiters = self.waiter_list.enter_critical_section();
+        let mut waiters = self
+            .waiter_list
+            .suspend_until_ready(waiters, self.id, self.deadline);
+        if waiters.remove_waiter(self.id) {
+            WaitResult::Ok
+        } else {
+            WaitResult::TimedOut
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        execution::Agent,
+        types::{IntoValue, Object, Symbol, Value},
+    },
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues},
+};
+
+/// A Value that can be held weakly: an Object or a Symbol that is not
+/// registered in the GlobalSymbolRegistry.
+///
+/// These are the keys of WeakMaps and the elements of WeakSets; a WeakKey
+/// does not keep its referent alive during garbage collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum WeakKey<'a> {
+    Object(Object<'a>),
+    Symbol(Symbol<'a>),
+}
+
+impl WeakKey<'_> {
+    /// Returns true if the referent of the WeakKey has been marked as
+    /// reachable.
+    pub(crate) fn is_marked(self, bits: &HeapBits) -> bool {
+        match self {
+            WeakKey::Object(object) => bits.is_object_marked(object),
+            WeakKey::Symbol(symbol) => bits.symbols[symbol.get_index()],
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for WeakKey<'_> {
+    type Of<'a> = WeakKey<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl<'a> IntoValue<'a> for WeakKey<'a> {
+    fn into_value(self) -> Value<'a> {
+        match self {
+            WeakKey::Object(object) => object.into_value(),
+            WeakKey::Symbol(symbol) => symbol.into_value(),
+        }
+    }
+}
+
+impl<'a> From<WeakKey<'a>> for Value<'a> {
+    fn from(value: WeakKey<'a>) -> Self {
+        value.into_value()
+    }
+}
+
+impl<'a> TryFrom<Value<'a>> for WeakKey<'a> {
+    type Error = ();
+
+    /// Convert an Object or Symbol Value into a WeakKey. Note that this does
+    /// not check if a Symbol is registered; see [can_be_held_weakly].
+    fn try_from(value: Value<'a>) -> Result<Self, Self::Error> {
+        match value {
+            Value::Symbol(symbol) => Ok(WeakKey::Symbol(symbol)),
+            _ => Ok(WeakKey::Object(Object::try_from(value)?)),
+        }
+    }
+}
+
+impl HeapMarkAndSweep for WeakKey<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            WeakKey::Object(object) => object.mark_values(queues),
+            WeakKey::Symbol(symbol) => symbol.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            WeakKey::Object(object) => object.sweep_values(compactions),
+            WeakKey::Symbol(symbol) => symbol.sweep_values(compactions),
+        }
+    }
+}
+
+/// ### [9.14 CanBeHeldWeakly ( v )](https://tc39.es/ecma262/#sec-canbeheldweakly)
+///
+/// The abstract operation CanBeHeldWeakly takes argument v (an ECMAScript
+/// language value) and returns a Boolean. It returns true if and only if v is
+/// suitable for use as a weak reference. Only values that are suitable for use
+/// as a weak reference may be a key of a WeakMap, an element of a WeakSet, the
+/// target of a WeakRef, or one of the targets of a FinalizationRegistry.
+///
+/// In Nova, the value is returned as a WeakKey if it can be held weakly.
+pub(crate) fn can_be_held_weakly<'a>(agent: &Agent, v: Value<'a>) -> Option<WeakKey<'a>> {
+    match v {
+        // 2. If v is a Symbol and KeyForSymbol(v) is undefined, return true.
+        Value::Symbol(symbol) => {
+            let is_registered = agent
+                .global_symbol_registry
+                .values()
+                .any(|registered| *registered == symbol.unbind());
+            if is_registered {
+                None
+            } else {
+                Some(WeakKey::Symbol(symbol))
+            }
+        }
+        _ => match Object::try_from(v) {
+            // 1. If v is an Object, return true.
+            Ok(object) => Some(WeakKey::Object(object)),
+            // 3. Return false.
+            Err(_) => None,
+        },
+    }
+}
@@ -7,9 +7,9 @@ use crate::{
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
-use self::script::Script;
-
-use super::builtins::module::Module;
+use self::{
+    module::module_semantics::source_text_module_records::SourceTextModule, script::Script,
+};
 
 pub mod module;
 pub mod script;
@@ -18,14 +18,14 @@ pub mod source_code;
 #[derive(Clone, Copy)]
 pub(crate) enum ScriptOrModule<'a> {
     Script(Script<'a>),
-    Module(Module<'a>),
+    SourceTextModule(SourceTextModule<'a>),
 }
 
 impl core::fmt::Debug for ScriptOrModule<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             ScriptOrModule::Script(script) => script.fmt(f),
-            ScriptOrModule::Module(module) => module.fmt(f),
+            ScriptOrModule::SourceTextModule(module) => module.fmt(f),
         }
     }
 }
@@ -49,14 +49,14 @@ impl HeapMarkAndSweep for ScriptOrModule<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         match self {
             ScriptOrModule::Script(idx) => idx.mark_values(queues),
-            ScriptOrModule::Module(idx) => idx.mark_values(queues),
+            ScriptOrModule::SourceTextModule(idx) => idx.mark_values(queues),
         }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         match self {
             ScriptOrModule::Script(idx) => idx.sweep_values(compactions),
-            ScriptOrModule::Module(idx) => idx.sweep_values(compactions),
+            ScriptOrModule::SourceTextModule(idx) => idx.sweep_values(compactions),
         }
     }
 }
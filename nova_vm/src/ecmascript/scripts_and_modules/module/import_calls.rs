@@ -0,0 +1,373 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [13.3.10 Import Calls](https://tc39.es/ecma262/#sec-import-calls)
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::{enumerable_own_properties, enumerable_properties_kind, get},
+            type_conversion::to_string,
+        },
+        builtins::{
+            control_abstraction_objects::promise_objects::{
+                promise_abstract_operations::{
+                    promise_capability_records::PromiseCapability,
+                    promise_reaction_records::PromiseReactionHandler,
+                },
+                promise_prototype::inner_promise_then,
+            },
+            promise::Promise,
+        },
+        execution::{
+            Agent, JsResult,
+            agent::{ExceptionType, get_active_script_or_module},
+        },
+        scripts_and_modules::ScriptOrModule,
+        types::{BUILTIN_STRING_MEMORY, IntoValue, Object, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::Scopable,
+    },
+};
+
+use super::module_semantics::{
+    ImportAttribute, ModuleLoadingPayload, ModuleRequest, Referrer,
+    abstract_module_records::AbstractModule, all_import_attributes_supported, get_module_namespace,
+};
+
+/// ### [13.3.10.1 EvaluateImportCall ( specifierExpression \[ , optionsExpression \] )](https://tc39.es/ecma262/#sec-evaluate-import-call)
+///
+/// The abstract operation EvaluateImportCall takes argument
+/// specifierExpression (a Parse Node) and optional argument
+/// optionsExpression (a Parse Node) and returns either a normal completion
+/// containing a Promise or an abrupt completion.
+///
+/// > Note: The specifier and options expressions are evaluated by the
+/// > bytecode before calling this function, with `options` being undefined
+/// > if optionsExpression was not present.
+pub(crate) fn evaluate_import_call<'gc>(
+    agent: &mut Agent,
+    specifier: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> Promise<'gc> {
+    let specifier = specifier.bind(gc.nogc());
+    let options = options.bind(gc.nogc());
+    // 7. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+    let promise_capability = PromiseCapability::new(agent, gc.nogc());
+    let promise = promise_capability.promise().scope(agent, gc.nogc());
+    // 8. Let specifierString be Completion(ToString(specifier)).
+    // 9. IfAbruptRejectPromise(specifierString, promiseCapability).
+    // 10. Let attributes be a new empty List.
+    // 11. If options is not undefined, then
+    // 12. Let moduleRequest be a new ModuleRequest Record {
+    //     [[Specifier]]: specifierString, [[Attributes]]: attributes }.
+    let module_request =
+        create_module_request(agent, specifier.unbind(), options.unbind(), gc.reborrow()).unbind();
+    let gc = gc.into_nogc();
+    let module_request = module_request.bind(gc);
+    // SAFETY: promise is not shared.
+    let promise = unsafe { promise.take(agent) }.bind(gc);
+    let promise_capability = PromiseCapability::from_promise(promise, true);
+    let module_request = match module_request {
+        Ok(module_request) => module_request,
+        Err(error) => {
+            promise_capability.reject(agent, error.value(), gc);
+            return promise;
+        }
+    };
+    // 1. Let referrer be GetActiveScriptOrModule().
+    // NOTE: The active script or module cannot change while the specifier
+    // and options are evaluated, so we can get it here.
+    let referrer = match get_active_script_or_module(agent, gc) {
+        Some(ScriptOrModule::Script(script)) => Referrer::Script(script),
+        Some(ScriptOrModule::SourceTextModule(module)) => Referrer::SourceTextModule(module),
+        // 2. If referrer is null, set referrer to the current Realm Record.
+        None => Referrer::Realm(agent.current_realm(gc)),
+    };
+    // 13. Perform HostLoadImportedModule(referrer, moduleRequest, EMPTY,
+    //     promiseCapability).
+    let host_hooks = agent.host_hooks;
+    host_hooks.load_imported_module(
+        agent,
+        referrer,
+        &module_request,
+        ModuleLoadingPayload::PromiseCapability(promise_capability.promise()),
+        gc,
+    );
+    // 14. Return promiseCapability.[[Promise]].
+    promise
+}
+
+/// Steps 8 to 12 of EvaluateImportCall: Returns the ModuleRequest Record of
+/// the call, or the error that the promise should be rejected with.
+fn create_module_request<'gc>(
+    agent: &mut Agent,
+    specifier: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ModuleRequest<'gc>> {
+    let specifier = specifier.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 8. Let specifierString be Completion(ToString(specifier)).
+    // 9. IfAbruptRejectPromise(specifierString, promiseCapability).
+    let specifier_string = to_string(agent, specifier.unbind(), gc.reborrow())
+        .unbind()?
+        .scope(agent, gc.nogc());
+    // 10. Let attributes be a new empty List.
+    let mut attributes: Vec<ImportAttribute> = vec![];
+    // 11. If options is not undefined, then
+    let options = options.get(agent).bind(gc.nogc());
+    if !options.is_undefined() {
+        // a. If options is not an Object, then
+        let Ok(options) = Object::try_from(options) else {
+            // i. Let error be a newly created TypeError object.
+            // ii. Perform ! Call(promiseCapability.[[Reject]], undefined,
+            //     « error »).
+            // iii. Return promiseCapability.[[Promise]].
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "The second argument of import() must be an object",
+                gc.into_nogc(),
+            ));
+        };
+        // b. Let attributesObj be Completion(Get(options, "with")).
+        // c. IfAbruptRejectPromise(attributesObj, promiseCapability).
+        let attributes_obj = get(
+            agent,
+            options.unbind(),
+            BUILTIN_STRING_MEMORY.with.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // d. If attributesObj is not undefined, then
+        if !attributes_obj.is_undefined() {
+            // i. If attributesObj is not an Object, then
+            let Ok(attributes_obj) = Object::try_from(attributes_obj) else {
+                // 1. Let error be a newly created TypeError object.
+                // 2. Perform ! Call(promiseCapability.[[Reject]], undefined,
+                //    « error »).
+                // 3. Return promiseCapability.[[Promise]].
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "The 'with' option of import() must be an object",
+                    gc.into_nogc(),
+                ));
+            };
+            // ii. Let entries be
+            //     Completion(EnumerableOwnProperties(attributesObj, KEY+VALUE)).
+            // iii. IfAbruptRejectPromise(entries, promiseCapability).
+            let entries = enumerable_own_properties::<
+                enumerable_properties_kind::EnumerateKeysAndValues,
+            >(agent, attributes_obj.unbind(), gc.reborrow())
+            .unbind()?;
+            let gc = gc.into_nogc();
+            let entries = entries.bind(gc);
+            // iv. For each element entry of entries, do
+            for entry in entries {
+                // 1. Let key be ! Get(entry, "0").
+                // 2. Let value be ! Get(entry, "1").
+                let Value::Array(entry) = entry else {
+                    unreachable!()
+                };
+                let (key, value) = match entry.as_slice(agent) {
+                    [Some(key), Some(value)] => (*key, *value),
+                    _ => unreachable!(),
+                };
+                // 3. If key is a String, then
+                let Ok(key) = String::try_from(key) else {
+                    continue;
+                };
+                // a. If value is not a String, then
+                let Ok(value) = String::try_from(value) else {
+                    // i. Let error be a newly created TypeError object.
+                    // ii. Perform ! Call(promiseCapability.[[Reject]],
+                    //     undefined, « error »).
+                    // iii. Return promiseCapability.[[Promise]].
+                    let error_message = format!(
+                        "Import attribute '{}' must have a string value",
+                        key.as_str(agent)
+                    );
+                    return Err(agent.throw_exception(ExceptionType::TypeError, error_message, gc));
+                };
+                // b. Append the ImportAttribute Record { [[Key]]: key,
+                //    [[Value]]: value } to attributes.
+                attributes.push(ImportAttribute { key, value });
+            }
+            return finish_module_request(agent, specifier_string.get(agent), attributes, gc);
+        }
+    }
+    finish_module_request(
+        agent,
+        specifier_string.get(agent),
+        attributes,
+        gc.into_nogc(),
+    )
+}
+
+/// Steps 11.e to 12 of EvaluateImportCall.
+fn finish_module_request<'gc>(
+    agent: &mut Agent,
+    specifier_string: String,
+    mut attributes: Vec<ImportAttribute>,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, ModuleRequest<'gc>> {
+    // e. If AllImportAttributesSupported(attributes) is false, then
+    if !all_import_attributes_supported(agent, &attributes) {
+        // i. Let error be a newly created TypeError object.
+        // ii. Perform ! Call(promiseCapability.[[Reject]], undefined,
+        //     « error »).
+        // iii. Return promiseCapability.[[Promise]].
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Unsupported import attributes",
+            gc,
+        ));
+    }
+    // f. Sort attributes according to the lexicographic order of their
+    //    [[Key]] field, treating the value of each such field as a sequence
+    //    of UTF-16 code unit values.
+    attributes.sort_by(|a, b| {
+        a.key
+            .as_str(agent)
+            .encode_utf16()
+            .cmp(b.key.as_str(agent).encode_utf16())
+    });
+    // 12. Let moduleRequest be a new ModuleRequest Record {
+    //     [[Specifier]]: specifierString, [[Attributes]]: attributes }.
+    let attributes = attributes
+        .into_iter()
+        .map(|attribute| attribute.unbind())
+        .collect();
+    Ok(ModuleRequest::new(specifier_string.unbind(), attributes).bind(gc))
+}
+
+/// ### [13.3.10.2 ContinueDynamicImport ( promiseCapability, moduleCompletion )](https://tc39.es/ecma262/#sec-ContinueDynamicImport)
+///
+/// The abstract operation ContinueDynamicImport takes arguments
+/// promiseCapability (a PromiseCapability Record) and moduleCompletion
+/// (either a normal completion containing a Module Record or a throw
+/// completion) and returns UNUSED. It completes the process of a dynamic
+/// import originally started by an import() call, resolving or rejecting
+/// the promise returned by that call as appropriate.
+pub(crate) fn continue_dynamic_import<'a>(
+    agent: &mut Agent,
+    promise_capability: PromiseCapability<'a>,
+    module_completion: JsResult<'a, AbstractModule<'a>>,
+    gc: NoGcScope<'a, '_>,
+) {
+    // 1. If moduleCompletion is an abrupt completion, then
+    // 2. Let module be moduleCompletion.[[Value]].
+    let module = match module_completion {
+        Ok(module) => module,
+        Err(error) => {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined,
+            //    « moduleCompletion.[[Value]] »).
+            promise_capability.reject(agent, error.value(), gc);
+            // b. Return UNUSED.
+            return;
+        }
+    };
+    // 3. Let loadPromise be module.LoadRequestedModules().
+    let load_promise = module.load_requested_modules(agent, None, gc);
+    // 4. Let rejectedClosure be a new Abstract Closure with parameters
+    //    (reason) that captures promiseCapability and performs the following
+    //    steps when called:
+    //    a. Perform ! Call(promiseCapability.[[Reject]], undefined,
+    //       « reason »).
+    //    b. Return NormalCompletion(undefined).
+    // 5. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+    // 6. Let linkAndEvaluateClosure be a new Abstract Closure with no
+    //    parameters that captures module, promiseCapability, and onRejected
+    //    and performs the following steps when called:
+    // 7. Let linkAndEvaluate be CreateBuiltinFunction(linkAndEvaluateClosure,
+    //    0, "", « »).
+    let handler = PromiseReactionHandler::DynamicImport {
+        promise: promise_capability.promise(),
+        module,
+    };
+    // 8. Perform PerformPromiseThen(loadPromise, linkAndEvaluate, onRejected).
+    inner_promise_then(agent, load_promise, handler, handler, None, gc);
+    // 9. Return UNUSED.
+}
+
+/// The linkAndEvaluateClosure created in step 6 of
+/// [ContinueDynamicImport](https://tc39.es/ecma262/#sec-ContinueDynamicImport).
+pub(crate) fn dynamic_import_link_and_evaluate(
+    agent: &mut Agent,
+    promise: Promise,
+    module: AbstractModule,
+    mut gc: GcScope,
+) {
+    let promise = promise.bind(gc.nogc());
+    let module = module.bind(gc.nogc());
+    // a. Let link be Completion(module.Link()).
+    // b. If link is an abrupt completion, then
+    if let Err(error) = module.link(agent, gc.nogc()) {
+        // i. Perform ! Call(promiseCapability.[[Reject]], undefined,
+        //    « link.[[Value]] »).
+        PromiseCapability::from_promise(promise, true).reject(agent, error.value(), gc.nogc());
+        // ii. Return NormalCompletion(undefined).
+        return;
+    }
+    let scoped_promise = promise.scope(agent, gc.nogc());
+    let scoped_module = module.scope(agent, gc.nogc());
+    // c. Let evaluatePromise be module.Evaluate().
+    let evaluate_promise = module
+        .unbind()
+        .evaluate(agent, gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    // d. Let fulfilledClosure be a new Abstract Closure with no parameters
+    //    that captures module and promiseCapability and performs the
+    //    following steps when called:
+    // e. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 0, "",
+    //    « »).
+    // SAFETY: scoped_promise and scoped_module are not shared.
+    let handler = unsafe {
+        PromiseReactionHandler::DynamicImportEvaluate {
+            promise: scoped_promise.take(agent),
+            module: scoped_module.take(agent),
+        }
+    };
+    // f. Perform PerformPromiseThen(evaluatePromise, onFulfilled, onRejected).
+    inner_promise_then(agent, evaluate_promise, handler, handler, None, gc.nogc());
+    // g. Return UNUSED.
+}
+
+/// The fulfilledClosure created in step 6.d of
+/// [ContinueDynamicImport](https://tc39.es/ecma262/#sec-ContinueDynamicImport).
+pub(crate) fn dynamic_import_resolve_namespace(
+    agent: &mut Agent,
+    promise: Promise,
+    module: AbstractModule,
+    gc: GcScope,
+) {
+    let promise = promise.bind(gc.nogc());
+    let module = module.bind(gc.nogc());
+    // i. Let namespace be GetModuleNamespace(module).
+    let namespace = get_module_namespace(agent, module, gc.nogc());
+    // ii. Perform ! Call(promiseCapability.[[Resolve]], undefined,
+    //     « namespace »).
+    PromiseCapability::from_promise(promise, true)
+        .unbind()
+        .resolve(agent, namespace.into_value().unbind(), gc);
+    // iii. Return NormalCompletion(undefined).
+}
+
+/// The rejectedClosure created in step 4 of
+/// [ContinueDynamicImport](https://tc39.es/ecma262/#sec-ContinueDynamicImport).
+pub(crate) fn dynamic_import_reject(
+    agent: &mut Agent,
+    promise: Promise,
+    reason: Value,
+    gc: NoGcScope,
+) {
+    // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « reason »).
+    PromiseCapability::from_promise(promise, true).reject(agent, reason, gc);
+    // b. Return NormalCompletion(undefined).
+}
@@ -0,0 +1,770 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [16.2.1 Module Semantics](https://tc39.es/ecma262/#sec-module-semantics)
+
+use core::{
+    any::Any,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            control_abstraction_objects::promise_objects::promise_abstract_operations::promise_capability_records::PromiseCapability,
+            module::{Module, module_namespace_create},
+            promise::Promise,
+        },
+        execution::{Agent, JsResult, Realm},
+        scripts_and_modules::script::{HostDefined, Script},
+        types::String,
+    },
+    engine::{
+        context::{Bindable, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues, indexes::BaseIndex,
+    },
+};
+
+use self::{
+    abstract_module_records::AbstractModule, cyclic_module_records::continue_module_loading,
+    source_text_module_records::SourceTextModule,
+};
+use super::import_calls::continue_dynamic_import;
+
+pub mod abstract_module_records;
+pub mod cyclic_module_records;
+pub mod source_text_module_records;
+pub mod synthetic_module_records;
+
+/// ### [ImportAttribute Records](https://tc39.es/ecma262/#table-importattribute-fields)
+///
+/// An ImportAttribute Record consists of a key and a value of an import
+/// attribute given in a WithClause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportAttribute<'a> {
+    /// ### \[\[Key]]
+    ///
+    /// The attribute key.
+    pub(crate) key: String<'a>,
+    /// ### \[\[Value]]
+    ///
+    /// The attribute value.
+    pub(crate) value: String<'a>,
+}
+
+impl<'a> ImportAttribute<'a> {
+    /// Create an ImportAttribute Record from a key and a value.
+    pub fn new(key: String<'a>, value: String<'a>) -> Self {
+        Self { key, value }
+    }
+
+    /// The attribute key.
+    pub fn key(self) -> String<'a> {
+        self.key
+    }
+
+    /// The attribute value.
+    pub fn value(self) -> String<'a> {
+        self.value
+    }
+}
+
+/// ### [16.2.1.3 ModuleRequest Records](https://tc39.es/ecma262/#sec-modulerequest-record)
+///
+/// A ModuleRequest Record represents the request to import a module with
+/// given import attributes.
+#[derive(Debug, Clone)]
+pub struct ModuleRequest<'a> {
+    /// ### \[\[Specifier]]
+    ///
+    /// The module specifier.
+    pub(crate) specifier: String<'a>,
+    /// ### \[\[Attributes]]
+    ///
+    /// The import attributes.
+    pub(crate) attributes: Box<[ImportAttribute<'a>]>,
+}
+
+impl<'a> ModuleRequest<'a> {
+    /// Create a ModuleRequest Record from a specifier and a list of import
+    /// attributes.
+    ///
+    /// Hosts that finish loading a module asynchronously can use this to
+    /// recreate the request passed to
+    /// [HostHooks::load_imported_module](crate::ecmascript::execution::agent::HostHooks::load_imported_module).
+    pub fn new(specifier: String<'a>, attributes: Box<[ImportAttribute<'a>]>) -> Self {
+        Self {
+            specifier,
+            attributes,
+        }
+    }
+
+    /// The module specifier.
+    pub fn specifier(&self) -> String<'a> {
+        self.specifier
+    }
+
+    /// The import attributes of the request.
+    pub fn attributes(&self) -> &[ImportAttribute<'a>] {
+        &self.attributes
+    }
+
+    /// Get the value of the import attribute with the given key.
+    pub fn get_attribute(&self, agent: &Agent, key: &str) -> Option<String<'a>> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key.as_str(agent) == key)
+            .map(|attribute| attribute.value)
+    }
+}
+
+impl PartialEq for ModuleRequest<'_> {
+    /// ### [16.2.1.3.1 ModuleRequestsEqual ( left, right )](https://tc39.es/ecma262/#sec-ModuleRequestsEqual)
+    ///
+    /// The abstract operation ModuleRequestsEqual takes arguments left (a
+    /// ModuleRequest Record or a LoadedModuleRequest Record) and right (a
+    /// ModuleRequest Record or a LoadedModuleRequest Record) and returns a
+    /// Boolean.
+    fn eq(&self, other: &Self) -> bool {
+        // 1. If left.[[Specifier]] is not right.[[Specifier]], return false.
+        if self.specifier != other.specifier {
+            return false;
+        }
+        // 2. Let leftAttrs be left.[[Attributes]].
+        // 3. Let rightAttrs be right.[[Attributes]].
+        // 4. Let leftAttrsCount be the number of elements in leftAttrs.
+        // 5. Let rightAttrsCount be the number of elements in rightAttrs.
+        // 6. If leftAttrsCount ≠ rightAttrsCount, return false.
+        if self.attributes.len() != other.attributes.len() {
+            return false;
+        }
+        // 7. For each ImportAttribute Record l of leftAttrs, do
+        // a. If rightAttrs does not contain an ImportAttribute Record r such
+        //    that l.[[Key]] is r.[[Key]] and l.[[Value]] is r.[[Value]],
+        //    return false.
+        // 8. Return true.
+        self.attributes
+            .iter()
+            .all(|attribute| other.attributes.contains(attribute))
+    }
+}
+
+impl Eq for ModuleRequest<'_> {}
+
+/// ### [LoadedModuleRequest Records](https://tc39.es/ecma262/#table-loadedmodulerequest-fields)
+///
+/// A LoadedModuleRequest Record represents a request to import a module
+/// together with the resulting Module Record.
+#[derive(Debug, Clone)]
+pub(crate) struct LoadedModuleRequest<'a> {
+    /// ### \[\[Specifier]] and \[\[Attributes]]
+    ///
+    /// The module request that was loaded.
+    pub(crate) request: ModuleRequest<'a>,
+    /// ### \[\[Module]]
+    ///
+    /// The loaded module corresponding to this module request.
+    pub(crate) module: AbstractModule<'a>,
+}
+
+/// The referrer of a module loading request: The Script Record, Cyclic
+/// Module Record or Realm Record that \[\[LoadedModules]] is updated on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Referrer<'a> {
+    Script(Script<'a>),
+    SourceTextModule(SourceTextModule<'a>),
+    Realm(Realm<'a>),
+}
+
+impl<'a> From<Script<'a>> for Referrer<'a> {
+    fn from(value: Script<'a>) -> Self {
+        Self::Script(value)
+    }
+}
+
+impl<'a> From<SourceTextModule<'a>> for Referrer<'a> {
+    fn from(value: SourceTextModule<'a>) -> Self {
+        Self::SourceTextModule(value)
+    }
+}
+
+impl<'a> From<Realm<'a>> for Referrer<'a> {
+    fn from(value: Realm<'a>) -> Self {
+        Self::Realm(value)
+    }
+}
+
+impl Referrer<'_> {
+    /// ### \[\[HostDefined]]
+    ///
+    /// Host-defined data associated with the referrer.
+    pub fn host_defined(self, agent: &Agent) -> Option<&dyn Any> {
+        match self {
+            Referrer::Script(script) => agent[script].host_defined.as_deref(),
+            Referrer::SourceTextModule(module) => {
+                agent[module].abstract_record.host_defined.as_deref()
+            }
+            Referrer::Realm(realm) => agent[realm].host_defined,
+        }
+    }
+
+    /// ### \[\[LoadedModules]]
+    pub(crate) fn loaded_modules(
+        self,
+        agent: &mut Agent,
+    ) -> &mut Vec<LoadedModuleRequest<'static>> {
+        match self {
+            Referrer::Script(script) => &mut agent[script].loaded_modules,
+            Referrer::SourceTextModule(module) => &mut agent[module].cyclic_record.loaded_modules,
+            Referrer::Realm(realm) => &mut agent[realm].loaded_modules,
+        }
+    }
+}
+
+/// ### [GraphLoadingState Records](https://tc39.es/ecma262/#graphloadingstate-record)
+///
+/// The state of a LoadRequestedModules call. This is passed to
+/// [HostHooks::load_imported_module](crate::ecmascript::execution::agent::HostHooks::load_imported_module)
+/// as its payload.
+#[derive(Debug)]
+pub struct GraphLoadingStateRecord<'a> {
+    /// ### \[\[PromiseCapability]]
+    ///
+    /// The promise to resolve when the loading process finishes.
+    pub(crate) promise_capability: PromiseCapability<'a>,
+    /// ### \[\[IsLoading]]
+    ///
+    /// It is true if the loading process has not finished yet, neither
+    /// successfully nor with an error.
+    pub(crate) is_loading: bool,
+    /// ### \[\[PendingModulesCount]]
+    ///
+    /// It tracks the number of pending HostLoadImportedModule calls.
+    pub(crate) pending_modules_count: u32,
+    /// ### \[\[Visited]]
+    ///
+    /// It is a list of the Cyclic Module Records that have been already
+    /// loaded by the current loading process, to avoid infinite loops with
+    /// circular dependencies.
+    pub(crate) visited: Vec<SourceTextModule<'a>>,
+    /// ### \[\[HostDefined]]
+    ///
+    /// It contains host-defined data to pass from the LoadRequestedModules
+    /// caller to HostLoadImportedModule.
+    pub(crate) host_defined: Option<HostDefined>,
+}
+
+unsafe impl Send for GraphLoadingStateRecord<'_> {}
+
+/// A heap-allocated [GraphLoadingStateRecord].
+///
+/// The record lives on the heap so that a host can hold on to it, for
+/// example through a [Global](crate::engine::Global), and finish loading
+/// the requested module asynchronously.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct GraphLoadingState<'a>(BaseIndex<'a, GraphLoadingStateRecord<'static>>);
+
+impl GraphLoadingState<'_> {
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    /// Host-defined data passed to LoadRequestedModules.
+    pub fn host_defined(self, agent: &Agent) -> Option<&dyn Any> {
+        agent[self].host_defined.as_deref()
+    }
+}
+
+/// The payload of a
+/// [HostHooks::load_imported_module](crate::ecmascript::execution::agent::HostHooks::load_imported_module)
+/// call.
+///
+/// The payload can be rooted, so that the host can finish loading the module
+/// after the call has returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleLoadingPayload<'a> {
+    /// The module is loaded as a part of a LoadRequestedModules call.
+    GraphLoadingState(GraphLoadingState<'a>),
+    /// The module is loaded by a dynamic `import()` call. The promise is the
+    /// \[\[Promise]] of the call's PromiseCapability Record.
+    PromiseCapability(Promise<'a>),
+}
+
+impl ModuleLoadingPayload<'_> {
+    /// Host-defined data passed to LoadRequestedModules. This is always None
+    /// for dynamic `import()` calls.
+    pub fn host_defined<'a>(&self, agent: &'a Agent) -> Option<&'a dyn Any> {
+        match self {
+            ModuleLoadingPayload::GraphLoadingState(state) => state.host_defined(agent),
+            ModuleLoadingPayload::PromiseCapability(_) => None,
+        }
+    }
+}
+
+/// ### [16.2.1.9 GetImportedModule ( referrer, request )](https://tc39.es/ecma262/#sec-GetImportedModule)
+///
+/// The abstract operation GetImportedModule takes arguments referrer (a
+/// Cyclic Module Record) and request (a ModuleRequest Record) and returns a
+/// Module Record.
+///
+/// > Note: Nova returns None if the request has not been loaded; the
+/// > specification asserts that this never happens.
+pub(crate) fn get_imported_module<'a>(
+    agent: &Agent,
+    referrer: SourceTextModule,
+    request: &ModuleRequest,
+    gc: NoGcScope<'a, '_>,
+) -> Option<AbstractModule<'a>> {
+    // 1. Let records be a List consisting of each LoadedModuleRequest Record r
+    //    of referrer.[[LoadedModules]] such that ModuleRequestsEqual(r,
+    //    request) is true.
+    // 2. Assert: records has exactly one element, since LoadRequestedModules
+    //    has completed successfully on referrer prior to invoking this
+    //    abstract operation.
+    // 3. Let record be the sole element of records.
+    // 4. Return record.[[Module]].
+    agent[referrer]
+        .cyclic_record
+        .loaded_modules
+        .iter()
+        .find(|record| record.request == *request)
+        .map(|record| record.module.bind(gc))
+}
+
+/// ### [16.2.1.11 FinishLoadingImportedModule ( referrer, moduleRequest, payload, result )](https://tc39.es/ecma262/#sec-FinishLoadingImportedModule)
+///
+/// The abstract operation FinishLoadingImportedModule takes arguments
+/// referrer (a Script Record, a Cyclic Module Record, or a Realm Record),
+/// moduleRequest (a ModuleRequest Record), payload (a GraphLoadingState
+/// Record or a PromiseCapability Record), and result (either a normal
+/// completion containing a Module Record or a throw completion) and returns
+/// UNUSED.
+///
+/// Hosts must call this from
+/// [HostHooks::load_imported_module](crate::ecmascript::execution::agent::HostHooks::load_imported_module)
+/// with the result of loading the requested module.
+pub fn finish_loading_imported_module<'a>(
+    agent: &mut Agent,
+    referrer: Referrer<'a>,
+    module_request: &ModuleRequest<'a>,
+    payload: ModuleLoadingPayload<'a>,
+    result: JsResult<'a, AbstractModule<'a>>,
+    gc: NoGcScope<'a, '_>,
+) {
+    // 1. If result is a normal completion, then
+    if let Ok(module) = result {
+        let loaded_modules = referrer.loaded_modules(agent);
+        // a. If referrer.[[LoadedModules]] contains a LoadedModuleRequest
+        //    Record record such that ModuleRequestsEqual(record,
+        //    moduleRequest) is true, then
+        if let Some(record) = loaded_modules
+            .iter()
+            .find(|record| record.request == *module_request)
+        {
+            // i. Assert: record.[[Module]] and result.[[Value]] are the same
+            //    Module Record.
+            debug_assert_eq!(record.module, module.unbind());
+        } else {
+            // b. Else,
+            // i. Append the LoadedModuleRequest Record {
+            //    [[Specifier]]: moduleRequest.[[Specifier]],
+            //    [[Attributes]]: moduleRequest.[[Attributes]],
+            //    [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
+            loaded_modules.push(LoadedModuleRequest {
+                request: module_request.clone().unbind(),
+                module: module.unbind(),
+            });
+        }
+    }
+    match payload {
+        // 2. If payload is a GraphLoadingState Record, then
+        ModuleLoadingPayload::GraphLoadingState(state) => {
+            // a. Perform ContinueModuleLoading(payload, result).
+            continue_module_loading(agent, state, result, gc);
+        }
+        // 3. Else,
+        ModuleLoadingPayload::PromiseCapability(promise) => {
+            // a. Perform ContinueDynamicImport(payload, result).
+            let promise_capability = PromiseCapability::from_promise(promise, true);
+            continue_dynamic_import(agent, promise_capability, result, gc);
+        }
+    }
+    // 4. Return UNUSED.
+}
+
+/// ### [16.2.1.12 AllImportAttributesSupported ( attributes )](https://tc39.es/ecma262/#sec-AllImportAttributesSupported)
+///
+/// The abstract operation AllImportAttributesSupported takes argument
+/// attributes (a List of ImportAttribute Records) and returns a Boolean.
+pub(crate) fn all_import_attributes_supported(
+    agent: &Agent,
+    attributes: &[ImportAttribute],
+) -> bool {
+    // 1. Let supported be HostGetSupportedImportAttributes().
+    let supported = agent.host_hooks.get_supported_import_attributes();
+    // 2. For each ImportAttribute Record attribute of attributes, do
+    // a. If supported does not contain attribute.[[Key]], return false.
+    // 3. Return true.
+    attributes
+        .iter()
+        .all(|attribute| supported.contains(&attribute.key.as_str(agent)))
+}
+
+/// ### [16.2.1.13 GetModuleNamespace ( module )](https://tc39.es/ecma262/#sec-getmodulenamespace)
+///
+/// The abstract operation GetModuleNamespace takes argument module (an
+/// instance of a concrete subclass of Module Record) and returns a Module
+/// Namespace Object. It retrieves the Module Namespace Object representing
+/// module's exports, lazily creating it the first time it was requested, and
+/// storing it in module.\[\[Namespace]] for future retrieval.
+pub(crate) fn get_module_namespace<'a>(
+    agent: &mut Agent,
+    module: AbstractModule,
+    gc: NoGcScope<'a, '_>,
+) -> Module<'a> {
+    let module = module.bind(gc);
+    // 1. Assert: If module is a Cyclic Module Record, then module.[[Status]]
+    //    is not NEW or UNLINKED.
+    // 2. Let namespace be module.[[Namespace]].
+    // 3. If namespace is EMPTY, then
+    if let Some(namespace) = module.namespace(agent, gc) {
+        // 4. Return namespace.
+        return namespace;
+    }
+    // a. Let exportedNames be module.GetExportedNames().
+    let exported_names = module.get_exported_names(agent, &mut vec![], gc);
+    // b. Let unambiguousNames be a new empty List.
+    // c. For each element name of exportedNames, do
+    let unambiguous_names = exported_names
+        .into_iter()
+        .filter(|&name| {
+            // i. Let resolution be module.ResolveExport(name).
+            let resolution = module.resolve_export(agent, name, &mut vec![], gc);
+            // ii. If resolution is a ResolvedBinding Record, append name to
+            //     unambiguousNames.
+            matches!(
+                resolution,
+                Some(abstract_module_records::ResolveExportResult::Resolved(_))
+            )
+        })
+        .collect::<Vec<_>>();
+    // d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames).
+    // 4. Return namespace.
+    module_namespace_create(agent, module, unambiguous_names, gc)
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ImportAttribute<'_> {
+    type Of<'a> = ImportAttribute<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for ImportAttribute<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self { key, value } = self;
+        key.mark_values(queues);
+        value.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self { key, value } = self;
+        key.sweep_values(compactions);
+        value.sweep_values(compactions);
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ModuleRequest<'_> {
+    type Of<'a> = ModuleRequest<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for ModuleRequest<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            specifier,
+            attributes,
+        } = self;
+        specifier.mark_values(queues);
+        attributes.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            specifier,
+            attributes,
+        } = self;
+        specifier.sweep_values(compactions);
+        attributes.sweep_values(compactions);
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for LoadedModuleRequest<'_> {
+    type Of<'a> = LoadedModuleRequest<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for LoadedModuleRequest<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self { request, module } = self;
+        request.mark_values(queues);
+        module.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self { request, module } = self;
+        request.sweep_values(compactions);
+        module.sweep_values(compactions);
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for Referrer<'_> {
+    type Of<'a> = Referrer<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl Rootable for Referrer<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        match value {
+            Self::Script(script) => Err(HeapRootData::Script(script.unbind())),
+            Self::SourceTextModule(module) => Err(HeapRootData::SourceTextModule(module.unbind())),
+            Self::Realm(realm) => Err(HeapRootData::Realm(realm.unbind())),
+        }
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::Script(script) => Some(Self::Script(script)),
+            HeapRootData::SourceTextModule(module) => Some(Self::SourceTextModule(module)),
+            HeapRootData::Realm(realm) => Some(Self::Realm(realm)),
+            _ => None,
+        }
+    }
+}
+
+impl Index<GraphLoadingState<'_>> for Agent {
+    type Output = GraphLoadingStateRecord<'static>;
+
+    fn index(&self, index: GraphLoadingState) -> &Self::Output {
+        &self.heap.graph_loading_states[index]
+    }
+}
+
+impl IndexMut<GraphLoadingState<'_>> for Agent {
+    fn index_mut(&mut self, index: GraphLoadingState) -> &mut Self::Output {
+        &mut self.heap.graph_loading_states[index]
+    }
+}
+
+impl Index<GraphLoadingState<'_>> for Vec<Option<GraphLoadingStateRecord<'static>>> {
+    type Output = GraphLoadingStateRecord<'static>;
+
+    fn index(&self, index: GraphLoadingState) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("GraphLoadingState out of bounds")
+            .as_ref()
+            .expect("GraphLoadingState slot empty")
+    }
+}
+
+impl IndexMut<GraphLoadingState<'_>> for Vec<Option<GraphLoadingStateRecord<'static>>> {
+    fn index_mut(&mut self, index: GraphLoadingState) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("GraphLoadingState out of bounds")
+            .as_mut()
+            .expect("GraphLoadingState slot empty")
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for GraphLoadingState<'_> {
+    type Of<'a> = GraphLoadingState<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for GraphLoadingState<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.graph_loading_states.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.graph_loading_states.shift_index(&mut self.0);
+    }
+}
+
+impl Rootable for GraphLoadingState<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::GraphLoadingState(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        if let HeapRootData::GraphLoadingState(data) = heap_data {
+            Some(data)
+        } else {
+            None
+        }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for GraphLoadingStateRecord<'_> {
+    type Of<'a> = GraphLoadingStateRecord<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for GraphLoadingStateRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            promise_capability,
+            is_loading: _,
+            pending_modules_count: _,
+            visited,
+            host_defined: _,
+        } = self;
+        promise_capability.mark_values(queues);
+        visited.as_slice().mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            promise_capability,
+            is_loading: _,
+            pending_modules_count: _,
+            visited,
+            host_defined: _,
+        } = self;
+        promise_capability.sweep_values(compactions);
+        visited.as_mut_slice().sweep_values(compactions);
+    }
+}
+
+impl<'a> CreateHeapData<GraphLoadingStateRecord<'a>, GraphLoadingState<'a>> for Heap {
+    fn create(&mut self, data: GraphLoadingStateRecord<'a>) -> GraphLoadingState<'a> {
+        self.graph_loading_states.push(Some(data.unbind()));
+        self.alloc_counter += core::mem::size_of::<Option<GraphLoadingStateRecord<'static>>>();
+        GraphLoadingState(BaseIndex::last(&self.graph_loading_states))
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ModuleLoadingPayload<'_> {
+    type Of<'a> = ModuleLoadingPayload<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl Rootable for ModuleLoadingPayload<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        match value {
+            Self::GraphLoadingState(state) => Err(HeapRootData::GraphLoadingState(state.unbind())),
+            Self::PromiseCapability(promise) => Err(HeapRootData::Promise(promise.unbind())),
+        }
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::GraphLoadingState(state) => Some(Self::GraphLoadingState(state)),
+            HeapRootData::Promise(promise) => Some(Self::PromiseCapability(promise)),
+            _ => None,
+        }
+    }
+}
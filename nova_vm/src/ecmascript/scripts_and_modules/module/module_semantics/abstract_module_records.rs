@@ -0,0 +1,333 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [16.2.1.4 Abstract Module Records](https://tc39.es/ecma262/#sec-abstract-module-records)
+//!
+//! A Module Record encapsulates structural information about the imports and
+//! exports of a single module. This information is used to link the imports
+//! and exports of sets of connected modules. A Module Record includes four
+//! fields that are only used when evaluating a module.
+
+use crate::{
+    ecmascript::{
+        builtins::{module::Module, promise::Promise},
+        execution::{Agent, JsResult, ModuleEnvironment, Realm},
+        scripts_and_modules::script::HostDefined,
+        types::String,
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+    },
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+use super::{
+    cyclic_module_records::{evaluate, link, load_requested_modules},
+    source_text_module_records::SourceTextModule,
+    synthetic_module_records::SyntheticModule,
+};
+
+/// ### [Module Record](https://tc39.es/ecma262/#table-module-record-fields)
+///
+/// The fields shared by all Module Records.
+#[derive(Debug)]
+pub(crate) struct AbstractModuleRecord<'a> {
+    /// ### \[\[Realm]]
+    ///
+    /// The Realm within which this module was created.
+    pub(crate) realm: Realm<'a>,
+    /// ### \[\[Environment]]
+    ///
+    /// The Environment Record containing the top level bindings for this
+    /// module. This field is set when the module is linked.
+    pub(crate) environment: Option<ModuleEnvironment<'a>>,
+    /// ### \[\[Namespace]]
+    ///
+    /// The Module Namespace Object if one has been created for this module.
+    pub(crate) namespace: Option<Module<'a>>,
+    /// ### \[\[HostDefined]]
+    ///
+    /// Field reserved for use by host environments that need to associate
+    /// additional information with a module.
+    pub(crate) host_defined: Option<HostDefined>,
+}
+
+impl<'a> AbstractModuleRecord<'a> {
+    pub(crate) fn new(realm: Realm<'a>, host_defined: Option<HostDefined>) -> Self {
+        Self {
+            realm,
+            environment: None,
+            namespace: None,
+            host_defined,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for AbstractModuleRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            realm,
+            environment,
+            namespace,
+            host_defined: _,
+        } = self;
+        realm.mark_values(queues);
+        environment.mark_values(queues);
+        namespace.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            realm,
+            environment,
+            namespace,
+            host_defined: _,
+        } = self;
+        realm.sweep_values(compactions);
+        environment.sweep_values(compactions);
+        namespace.sweep_values(compactions);
+    }
+}
+
+/// A reference to any concrete Module Record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbstractModule<'a> {
+    SourceTextModule(SourceTextModule<'a>),
+    SyntheticModule(SyntheticModule<'a>),
+}
+
+impl<'a> From<SourceTextModule<'a>> for AbstractModule<'a> {
+    fn from(value: SourceTextModule<'a>) -> Self {
+        Self::SourceTextModule(value)
+    }
+}
+
+impl<'a> AbstractModule<'a> {
+    fn record(self, agent: &Agent) -> &AbstractModuleRecord<'static> {
+        match self {
+            Self::SourceTextModule(m) => &agent[m].abstract_record,
+            Self::SyntheticModule(m) => &agent[m].abstract_record,
+        }
+    }
+
+    fn record_mut(self, agent: &mut Agent) -> &mut AbstractModuleRecord<'static> {
+        match self {
+            Self::SourceTextModule(m) => &mut agent[m].abstract_record,
+            Self::SyntheticModule(m) => &mut agent[m].abstract_record,
+        }
+    }
+
+    /// ### \[\[Realm]]
+    pub(crate) fn realm(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Realm<'a> {
+        self.record(agent).realm.bind(gc)
+    }
+
+    /// ### \[\[Environment]]
+    pub(crate) fn environment(
+        self,
+        agent: &Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> Option<ModuleEnvironment<'a>> {
+        self.record(agent).environment.bind(gc)
+    }
+
+    pub(crate) fn set_environment(self, agent: &mut Agent, environment: ModuleEnvironment) {
+        self.record_mut(agent).environment = Some(environment.unbind());
+    }
+
+    /// ### \[\[Namespace]]
+    pub(crate) fn namespace(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Option<Module<'a>> {
+        self.record(agent).namespace.bind(gc)
+    }
+
+    pub(crate) fn set_namespace(self, agent: &mut Agent, namespace: Module) {
+        self.record_mut(agent).namespace = Some(namespace.unbind());
+    }
+
+    /// ### [LoadRequestedModules ( \[ hostDefined \] )](https://tc39.es/ecma262/#table-abstract-methods-of-module-records)
+    ///
+    /// Prepares the module for linking by recursively loading all its
+    /// dependencies, and returns a promise.
+    pub(crate) fn load_requested_modules(
+        self,
+        agent: &mut Agent,
+        host_defined: Option<HostDefined>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Promise<'a> {
+        match self {
+            Self::SourceTextModule(m) => load_requested_modules(agent, m, host_defined, gc),
+            Self::SyntheticModule(m) => m.load_requested_modules(agent, gc),
+        }
+    }
+
+    /// ### [GetExportedNames ( \[ exportStarSet \] )](https://tc39.es/ecma262/#table-abstract-methods-of-module-records)
+    ///
+    /// Return a list of all names that are either directly or indirectly
+    /// exported from this module.
+    ///
+    /// LoadRequestedModules must have completed successfully prior to
+    /// invoking this method.
+    pub(crate) fn get_exported_names(
+        self,
+        agent: &Agent,
+        export_star_set: &mut Vec<SourceTextModule<'a>>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Vec<String<'a>> {
+        match self {
+            Self::SourceTextModule(m) => m.get_exported_names(agent, export_star_set, gc),
+            Self::SyntheticModule(m) => m.get_exported_names(agent, gc),
+        }
+    }
+
+    /// ### [ResolveExport ( exportName \[ , resolveSet \] )](https://tc39.es/ecma262/#table-abstract-methods-of-module-records)
+    ///
+    /// Return the binding of a name exported by this module. Bindings are
+    /// represented by a ResolvedBinding Record, of the form { \[\[Module]]:
+    /// Module Record, \[\[BindingName]]: String | NAMESPACE }. If the export
+    /// is a Module Namespace Object without a direct binding in any module,
+    /// \[\[BindingName]] will be set to NAMESPACE. Return null if the name
+    /// cannot be resolved, or AMBIGUOUS if multiple bindings were found.
+    ///
+    /// Each time this operation is called with a specific exportName,
+    /// resolveSet pair as arguments it must return the same result.
+    ///
+    /// LoadRequestedModules must have completed successfully prior to
+    /// invoking this method.
+    pub(crate) fn resolve_export(
+        self,
+        agent: &Agent,
+        export_name: String,
+        resolve_set: &mut Vec<(SourceTextModule<'a>, String<'a>)>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Option<ResolveExportResult<'a>> {
+        match self {
+            Self::SourceTextModule(m) => m.resolve_export(agent, export_name, resolve_set, gc),
+            Self::SyntheticModule(m) => m.resolve_export(agent, export_name, gc),
+        }
+    }
+
+    /// ### [Link ( )](https://tc39.es/ecma262/#table-abstract-methods-of-module-records)
+    ///
+    /// Prepare the module for evaluation by transitively resolving all module
+    /// dependencies and creating a Module Environment Record.
+    ///
+    /// LoadRequestedModules must have completed successfully prior to
+    /// invoking this method.
+    pub(crate) fn link(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsResult<'a, ()> {
+        match self {
+            Self::SourceTextModule(m) => link(agent, m, gc),
+            Self::SyntheticModule(m) => {
+                m.link(agent, gc);
+                Ok(())
+            }
+        }
+    }
+
+    /// ### [Evaluate ( )](https://tc39.es/ecma262/#table-abstract-methods-of-module-records)
+    ///
+    /// Returns a promise for the evaluation of this module and its
+    /// dependencies, resolving on successful evaluation or if it has already
+    /// been evaluated successfully, and rejecting for an evaluation error or
+    /// if it has already been evaluated unsuccessfully. If the promise is
+    /// rejected, hosts are expected to handle the promise rejection and
+    /// rethrow the evaluation error.
+    ///
+    /// Link must have completed successfully prior to invoking this method.
+    pub(crate) fn evaluate(self, agent: &mut Agent, gc: GcScope<'a, '_>) -> Promise<'a> {
+        match self {
+            Self::SourceTextModule(m) => evaluate(agent, m, gc),
+            Self::SyntheticModule(m) => m.evaluate(agent, gc.into_nogc()),
+        }
+    }
+}
+
+/// ### [ResolvedBinding Record](https://tc39.es/ecma262/#resolvedbinding-record)
+///
+/// A ResolvedBinding Record identifies the binding that an exported name
+/// resolves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ResolvedBinding<'a> {
+    /// ### \[\[Module]]
+    ///
+    /// The Module Record that contains the binding.
+    pub(crate) module: AbstractModule<'a>,
+    /// ### \[\[BindingName]]
+    ///
+    /// The name of the binding in the Module Record, or NAMESPACE if the
+    /// binding is the Module Namespace Object of the module.
+    pub(crate) binding_name: ResolvedBindingName<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResolvedBindingName<'a> {
+    String(String<'a>),
+    Namespace,
+}
+
+/// The result of a successful ResolveExport call: Either a ResolvedBinding
+/// Record or AMBIGUOUS. A failed call returns null, represented as None.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResolveExportResult<'a> {
+    Ambiguous,
+    Resolved(ResolvedBinding<'a>),
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for AbstractModule<'_> {
+    type Of<'a> = AbstractModule<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl Rootable for AbstractModule<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        match value {
+            Self::SourceTextModule(m) => Err(HeapRootData::SourceTextModule(m.unbind())),
+            Self::SyntheticModule(m) => Err(HeapRootData::SyntheticModule(m.unbind())),
+        }
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::SourceTextModule(m) => Some(Self::SourceTextModule(m)),
+            HeapRootData::SyntheticModule(m) => Some(Self::SyntheticModule(m)),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for AbstractModule<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::SourceTextModule(m) => m.mark_values(queues),
+            Self::SyntheticModule(m) => m.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::SourceTextModule(m) => m.sweep_values(compactions),
+            Self::SyntheticModule(m) => m.sweep_values(compactions),
+        }
+    }
+}
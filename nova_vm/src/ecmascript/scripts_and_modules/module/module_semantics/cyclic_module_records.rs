@@ -0,0 +1,1222 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [16.2.1.5 Cyclic Module Records](https://tc39.es/ecma262/#sec-cyclic-module-records)
+//!
+//! A Cyclic Module Record is used to represent information about a module
+//! that can participate in dependency cycles with other modules that are
+//! subclasses of the Cyclic Module Record type. Module Records that are not
+//! subclasses of the Cyclic Module Record type must not participate in
+//! dependency cycles with Source Text Module Records.
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            control_abstraction_objects::promise_objects::{
+                promise_abstract_operations::{
+                    promise_capability_records::PromiseCapability,
+                    promise_reaction_records::PromiseReactionHandler,
+                },
+                promise_prototype::inner_promise_then,
+            },
+            promise::{Promise, data::PromiseState},
+        },
+        execution::{
+            Agent, JsResult,
+            agent::{ExceptionType, JsError},
+        },
+        scripts_and_modules::script::HostDefined,
+        types::Value,
+    },
+    engine::{
+        context::{Bindable, GcScope, NoGcScope},
+        rootable::{Scopable, Scoped},
+        unwrap_try,
+    },
+    heap::{CompactionLists, CreateHeapData, HeapMarkAndSweep, WorkQueues},
+};
+
+use super::{
+    GraphLoadingState, GraphLoadingStateRecord, LoadedModuleRequest, ModuleLoadingPayload,
+    ModuleRequest, Referrer, abstract_module_records::AbstractModule,
+    all_import_attributes_supported, get_imported_module,
+    source_text_module_records::SourceTextModule,
+};
+
+/// ### \[\[Status]]
+///
+/// Initially NEW. Transitions to UNLINKED, LINKING, LINKED, EVALUATING,
+/// possibly EVALUATING-ASYNC, EVALUATED (in that order) as the module
+/// progresses throughout its lifecycle. EVALUATING-ASYNC indicates this
+/// module is queued to execute on completion of its asynchronous dependencies
+/// or it is a module whose \[\[HasTLA]] field is true that has been executed
+/// and is pending top-level completion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CyclicModuleRecordStatus {
+    New,
+    Unlinked,
+    Linking,
+    Linked,
+    Evaluating,
+    EvaluatingAsync,
+    Evaluated,
+}
+
+/// ### \[\[AsyncEvaluationOrder]]
+///
+/// This field is initially set to UNSET, and remains UNSET for fully
+/// synchronous modules. For modules that are either themselves asynchronous
+/// or have an asynchronous dependency, it is set to an integer that
+/// determines the order in which execution of pending modules is queued. Once
+/// the pending module is executed, the field is set to DONE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AsyncEvaluationOrder {
+    Unset,
+    Order(u32),
+    Done,
+}
+
+/// ### [Cyclic Module Record](https://tc39.es/ecma262/#table-cyclic-module-fields)
+///
+/// The additional fields of Cyclic Module Records.
+#[derive(Debug)]
+pub(crate) struct CyclicModuleRecord<'a> {
+    /// ### \[\[Status]]
+    pub(crate) status: CyclicModuleRecordStatus,
+    /// ### \[\[EvaluationError]]
+    ///
+    /// A throw completion representing the exception that occurred during
+    /// evaluation. undefined if no exception occurred or if \[\[Status]] is
+    /// not EVALUATED.
+    pub(crate) evaluation_error: Option<JsError<'a>>,
+    /// ### \[\[DFSIndex]]
+    ///
+    /// Auxiliary field used during Link and Evaluate only. If \[\[Status]] is
+    /// either LINKING or EVALUATING, this non-negative number records the
+    /// point at which the module was first visited during the depth-first
+    /// traversal of the dependency graph.
+    pub(crate) dfs_index: Option<u32>,
+    /// ### \[\[DFSAncestorIndex]]
+    ///
+    /// Auxiliary field used during Link and Evaluate only. If \[\[Status]] is
+    /// either LINKING or EVALUATING, this is either the module's own
+    /// \[\[DFSIndex]] or that of an "earlier" module in the same strongly
+    /// connected component.
+    pub(crate) dfs_ancestor_index: Option<u32>,
+    /// ### \[\[RequestedModules]]
+    ///
+    /// A List of the ModuleRequest Records associated with the imports in
+    /// this module. The List is in source text occurrence order of the
+    /// imports.
+    pub(crate) requested_modules: Box<[ModuleRequest<'a>]>,
+    /// ### \[\[LoadedModules]]
+    ///
+    /// A map from the specifier strings used by the module represented by
+    /// this record to request the importation of a module with the relative
+    /// import attributes to the resolved Module Record. The list does not
+    /// contain two different Records r1 and r2 such that
+    /// ModuleRequestsEqual(r1, r2) is true.
+    pub(crate) loaded_modules: Vec<LoadedModuleRequest<'a>>,
+    /// ### \[\[CycleRoot]]
+    ///
+    /// The first visited module of the cycle, the root DFS ancestor of the
+    /// strongly connected component. For a module not in a cycle, this would
+    /// be the module itself. Once Evaluate has completed, a module's
+    /// \[\[DFSAncestorIndex]] is the \[\[DFSIndex]] of its \[\[CycleRoot]].
+    pub(crate) cycle_root: Option<SourceTextModule<'a>>,
+    /// ### \[\[HasTLA]]
+    ///
+    /// Whether this module is individually asynchronous (for example, if it's
+    /// a Source Text Module Record containing a top-level await). Having an
+    /// asynchronous dependency does not mean this field is true. This field
+    /// must not change after the module is parsed.
+    pub(crate) has_top_level_await: bool,
+    /// ### \[\[AsyncEvaluationOrder]]
+    pub(crate) async_evaluation_order: AsyncEvaluationOrder,
+    /// ### \[\[TopLevelCapability]]
+    ///
+    /// If this module is the \[\[CycleRoot]] of some cycle, and Evaluate() was
+    /// called on some module in that cycle, this field contains the
+    /// PromiseCapability Record for that entire evaluation. It is used to
+    /// settle the Promise object that is returned from the Evaluate()
+    /// abstract method. This field will be empty for any dependencies of that
+    /// module, unless a top-level Evaluate() has been initiated for some of
+    /// those dependencies.
+    pub(crate) top_level_capability: Option<PromiseCapability<'a>>,
+    /// ### \[\[AsyncParentModules]]
+    ///
+    /// If this module or a dependency has \[\[HasTLA]] true, and execution is
+    /// in progress, this tracks the parent importers of this module for the
+    /// top-level execution job. These parent modules will not start executing
+    /// before this module has successfully completed execution.
+    pub(crate) async_parent_modules: Vec<SourceTextModule<'a>>,
+    /// ### \[\[PendingAsyncDependencies]]
+    ///
+    /// If this module has any asynchronous dependencies, this tracks the
+    /// number of asynchronous dependency modules remaining to execute for
+    /// this module. A module with asynchronous dependencies will be executed
+    /// when this field reaches 0 and there are no execution errors.
+    pub(crate) pending_async_dependencies: u32,
+}
+
+impl<'a> CyclicModuleRecord<'a> {
+    pub(crate) fn new(
+        requested_modules: Box<[ModuleRequest<'a>]>,
+        has_top_level_await: bool,
+    ) -> Self {
+        Self {
+            status: CyclicModuleRecordStatus::New,
+            evaluation_error: None,
+            dfs_index: None,
+            dfs_ancestor_index: None,
+            requested_modules,
+            loaded_modules: vec![],
+            cycle_root: None,
+            has_top_level_await,
+            async_evaluation_order: AsyncEvaluationOrder::Unset,
+            top_level_capability: None,
+            async_parent_modules: vec![],
+            pending_async_dependencies: 0,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for CyclicModuleRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            status: _,
+            evaluation_error,
+            dfs_index: _,
+            dfs_ancestor_index: _,
+            requested_modules,
+            loaded_modules,
+            cycle_root,
+            has_top_level_await: _,
+            async_evaluation_order: _,
+            top_level_capability,
+            async_parent_modules,
+            pending_async_dependencies: _,
+        } = self;
+        evaluation_error.mark_values(queues);
+        for request in requested_modules.iter() {
+            request.mark_values(queues);
+        }
+        loaded_modules.as_slice().mark_values(queues);
+        cycle_root.mark_values(queues);
+        top_level_capability.mark_values(queues);
+        async_parent_modules.as_slice().mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            status: _,
+            evaluation_error,
+            dfs_index: _,
+            dfs_ancestor_index: _,
+            requested_modules,
+            loaded_modules,
+            cycle_root,
+            has_top_level_await: _,
+            async_evaluation_order: _,
+            top_level_capability,
+            async_parent_modules,
+            pending_async_dependencies: _,
+        } = self;
+        evaluation_error.sweep_values(compactions);
+        for request in requested_modules.iter_mut() {
+            request.sweep_values(compactions);
+        }
+        loaded_modules.as_mut_slice().sweep_values(compactions);
+        cycle_root.sweep_values(compactions);
+        top_level_capability.sweep_values(compactions);
+        async_parent_modules
+            .as_mut_slice()
+            .sweep_values(compactions);
+    }
+}
+
+/// ### [16.2.1.5.1 LoadRequestedModules ( \[ hostDefined \] )](https://tc39.es/ecma262/#sec-LoadRequestedModules)
+///
+/// The LoadRequestedModules concrete method of a Cyclic Module Record module
+/// takes optional argument hostDefined (anything) and returns a Promise. It
+/// populates the \[\[LoadedModules]] of all the Module Records in the
+/// dependency graph of module (most of the work is done by the auxiliary
+/// function InnerModuleLoading). It takes an optional hostDefined parameter
+/// that is passed to the HostLoadImportedModule hook.
+pub(crate) fn load_requested_modules<'a>(
+    agent: &mut Agent,
+    module: SourceTextModule,
+    host_defined: Option<HostDefined>,
+    gc: NoGcScope<'a, '_>,
+) -> Promise<'a> {
+    let module = module.bind(gc);
+    // 1. If hostDefined is not present, let hostDefined be EMPTY.
+    // 2. Let pc be ! NewPromiseCapability(%Promise%).
+    let pc = PromiseCapability::new(agent, gc);
+    let promise = pc.promise();
+    // 3. Let state be the GraphLoadingState Record {
+    let state = agent.heap.create(GraphLoadingStateRecord {
+        // [[PromiseCapability]]: pc,
+        promise_capability: pc,
+        // [[IsLoading]]: true,
+        is_loading: true,
+        // [[PendingModulesCount]]: 1,
+        pending_modules_count: 1,
+        // [[Visited]]: « »,
+        visited: vec![],
+        // [[HostDefined]]: hostDefined
+        host_defined,
+    });
+    // }.
+    // 4. Perform InnerModuleLoading(state, module).
+    inner_module_loading(agent, state, module.into(), gc);
+    // 5. Return pc.[[Promise]].
+    promise
+}
+
+/// ### [16.2.1.5.1.1 InnerModuleLoading ( state, module )](https://tc39.es/ecma262/#sec-InnerModuleLoading)
+///
+/// The abstract operation InnerModuleLoading takes arguments state (a
+/// GraphLoadingState Record) and module (a Module Record) and returns
+/// UNUSED. It is used by LoadRequestedModules to recursively perform the
+/// actual loading process for module's dependency graph.
+fn inner_module_loading<'a>(
+    agent: &mut Agent,
+    state: GraphLoadingState<'a>,
+    module: AbstractModule<'a>,
+    gc: NoGcScope<'a, '_>,
+) {
+    // 1. Assert: state.[[IsLoading]] is true.
+    debug_assert!(agent[state].is_loading);
+    // 2. If module is a Cyclic Module Record, module.[[Status]] is NEW, and
+    //    state.[[Visited]] does not contain module, then
+    if let AbstractModule::SourceTextModule(module) = module {
+        if agent[module].cyclic_record.status == CyclicModuleRecordStatus::New
+            && !agent[state].visited.contains(&module.unbind())
+        {
+            // a. Append module to state.[[Visited]].
+            agent[state].visited.push(module.unbind());
+            // b. Let requestedModulesCount be the number of elements in
+            //    module.[[RequestedModules]].
+            let requested_modules_count = agent[module].cyclic_record.requested_modules.len();
+            // c. Set state.[[PendingModulesCount]] to
+            //    state.[[PendingModulesCount]] + requestedModulesCount.
+            agent[state].pending_modules_count += requested_modules_count as u32;
+            // d. For each ModuleRequest Record request of
+            //    module.[[RequestedModules]], do
+            for i in 0..requested_modules_count {
+                let request = agent[module].cyclic_record.requested_modules[i]
+                    .clone()
+                    .bind(gc);
+                if !all_import_attributes_supported(agent, &request.attributes) {
+                    // i. If AllImportAttributesSupported(request.[[Attributes]])
+                    //    is false, then
+                    // 1. Let error be ThrowCompletion(a newly created
+                    //    SyntaxError object).
+                    let error_message = format!(
+                        "Unsupported import attributes for module '{}'.",
+                        request.specifier.as_str(agent)
+                    );
+                    let error =
+                        agent.throw_exception(ExceptionType::SyntaxError, error_message, gc);
+                    // 2. Perform ContinueModuleLoading(state, error).
+                    continue_module_loading(agent, state, Err(error), gc);
+                } else if let Some(record) = agent[module]
+                    .cyclic_record
+                    .loaded_modules
+                    .iter()
+                    .find(|record| record.request == request)
+                {
+                    // ii. Else if module.[[LoadedModules]] contains a
+                    //     LoadedModuleRequest Record record such that
+                    //     ModuleRequestsEqual(record, request) is true, then
+                    // 1. Perform InnerModuleLoading(state, record.[[Module]]).
+                    let loaded_module = record.module.bind(gc);
+                    inner_module_loading(agent, state, loaded_module, gc);
+                } else {
+                    // iii. Else,
+                    // 1. Perform HostLoadImportedModule(module, request,
+                    //    state.[[HostDefined]], state).
+                    // 2. NOTE: HostLoadImportedModule will call
+                    //    FinishLoadingImportedModule, which re-enters the
+                    //    graph loading process through ContinueModuleLoading.
+                    let host_hooks = agent.host_hooks;
+                    host_hooks.load_imported_module(
+                        agent,
+                        Referrer::SourceTextModule(module),
+                        &request,
+                        ModuleLoadingPayload::GraphLoadingState(state),
+                        gc,
+                    );
+                }
+                // iv. If state.[[IsLoading]] is false, return UNUSED.
+                if !agent[state].is_loading {
+                    return;
+                }
+            }
+        }
+    }
+    let record = &mut agent[state];
+    // 3. Assert: state.[[PendingModulesCount]] ≥ 1.
+    debug_assert!(record.pending_modules_count >= 1);
+    // 4. Set state.[[PendingModulesCount]] to state.[[PendingModulesCount]] - 1.
+    record.pending_modules_count -= 1;
+    // 5. If state.[[PendingModulesCount]] = 0, then
+    if record.pending_modules_count == 0 {
+        // a. Set state.[[IsLoading]] to false.
+        record.is_loading = false;
+        // b. For each Cyclic Module Record loaded of state.[[Visited]], do
+        for i in 0..agent[state].visited.len() {
+            let loaded = agent[state].visited[i];
+            // i. If loaded.[[Status]] is NEW, set loaded.[[Status]] to
+            //    UNLINKED.
+            let record = &mut agent[loaded].cyclic_record;
+            if record.status == CyclicModuleRecordStatus::New {
+                record.status = CyclicModuleRecordStatus::Unlinked;
+            }
+        }
+        // c. Perform ! Call(state.[[PromiseCapability]].[[Resolve]],
+        //    undefined, « undefined »).
+        let promise_capability = agent[state].promise_capability.clone().bind(gc);
+        unwrap_try(promise_capability.try_resolve(agent, Value::Undefined, gc));
+    }
+    // 6. Return UNUSED.
+}
+
+/// ### [16.2.1.5.1.2 ContinueModuleLoading ( state, moduleCompletion )](https://tc39.es/ecma262/#sec-ContinueModuleLoading)
+///
+/// The abstract operation ContinueModuleLoading takes arguments state (a
+/// GraphLoadingState Record) and moduleCompletion (either a normal
+/// completion containing a Module Record or a throw completion) and returns
+/// UNUSED. It is used to re-enter the loading process after a call to
+/// HostLoadImportedModule.
+pub(crate) fn continue_module_loading<'a>(
+    agent: &mut Agent,
+    state: GraphLoadingState<'a>,
+    module_completion: JsResult<'a, AbstractModule<'a>>,
+    gc: NoGcScope<'a, '_>,
+) {
+    // 1. If state.[[IsLoading]] is false, return UNUSED.
+    if !agent[state].is_loading {
+        return;
+    }
+    match module_completion {
+        // 2. If moduleCompletion is a normal completion, then
+        Ok(module) => {
+            // a. Perform InnerModuleLoading(state, moduleCompletion.[[Value]]).
+            inner_module_loading(agent, state, module, gc);
+        }
+        // 3. Else,
+        Err(error) => {
+            // a. Set state.[[IsLoading]] to false.
+            agent[state].is_loading = false;
+            // b. Perform ! Call(state.[[PromiseCapability]].[[Reject]],
+            //    undefined, « moduleCompletion.[[Value]] »).
+            let promise_capability = agent[state].promise_capability.clone().bind(gc);
+            promise_capability.reject(agent, error.value(), gc);
+        }
+    }
+    // 4. Return UNUSED.
+}
+
+/// ### [16.2.1.5.2 Link ( )](https://tc39.es/ecma262/#sec-moduledeclarationlinking)
+///
+/// The Link concrete method of a Cyclic Module Record module takes no
+/// arguments and returns either a normal completion containing UNUSED or a
+/// throw completion. On success, Link transitions this module's \[\[Status]]
+/// from UNLINKED to LINKED. On failure, an exception is thrown and this
+/// module's \[\[Status]] remains UNLINKED. (Most of the work is done by the
+/// auxiliary function InnerModuleLinking.)
+pub(crate) fn link<'a>(
+    agent: &mut Agent,
+    module: SourceTextModule,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let module = module.bind(gc);
+    // 1. Assert: module.[[Status]] is one of UNLINKED, LINKED,
+    //    EVALUATING-ASYNC, or EVALUATED.
+    debug_assert!(matches!(
+        agent[module].cyclic_record.status,
+        CyclicModuleRecordStatus::Unlinked
+            | CyclicModuleRecordStatus::Linked
+            | CyclicModuleRecordStatus::EvaluatingAsync
+            | CyclicModuleRecordStatus::Evaluated
+    ));
+    // 2. Let stack be a new empty List.
+    let mut stack = vec![];
+    // 3. Let result be Completion(InnerModuleLinking(module, stack, 0)).
+    let result = inner_module_linking(agent, module.into(), &mut stack, 0, gc);
+    // 4. If result is an abrupt completion, then
+    if let Err(err) = result {
+        // a. For each Cyclic Module Record m of stack, do
+        for m in stack {
+            // i. Assert: m.[[Status]] is LINKING.
+            debug_assert_eq!(
+                agent[m].cyclic_record.status,
+                CyclicModuleRecordStatus::Linking
+            );
+            // ii. Set m.[[Status]] to UNLINKED.
+            agent[m].cyclic_record.status = CyclicModuleRecordStatus::Unlinked;
+        }
+        // b. Assert: module.[[Status]] is UNLINKED.
+        debug_assert_eq!(
+            agent[module].cyclic_record.status,
+            CyclicModuleRecordStatus::Unlinked
+        );
+        // c. Return ? result.
+        return Err(err);
+    }
+    // 5. Assert: module.[[Status]] is one of LINKED, EVALUATING-ASYNC, or
+    //    EVALUATED.
+    debug_assert!(matches!(
+        agent[module].cyclic_record.status,
+        CyclicModuleRecordStatus::Linked
+            | CyclicModuleRecordStatus::EvaluatingAsync
+            | CyclicModuleRecordStatus::Evaluated
+    ));
+    // 6. Assert: stack is empty.
+    debug_assert!(stack.is_empty());
+    // 7. Return UNUSED.
+    Ok(())
+}
+
+/// ### [16.2.1.5.2.1 InnerModuleLinking ( module, stack, index )](https://tc39.es/ecma262/#sec-InnerModuleLinking)
+///
+/// The abstract operation InnerModuleLinking takes arguments module (a Module
+/// Record), stack (a List of Cyclic Module Records), and index (a
+/// non-negative integer) and returns either a normal completion containing a
+/// non-negative integer or a throw completion. It is used by Link to perform
+/// the actual linking process for module, as well as recursively on all other
+/// modules in the dependency graph. The stack and index parameters, as well
+/// as a module's \[\[DFSIndex]] and \[\[DFSAncestorIndex]] fields, keep track
+/// of the depth-first search (DFS) traversal. In particular,
+/// \[\[DFSAncestorIndex]] is used to discover strongly connected components
+/// (SCCs), such that all modules in an SCC transition to LINKED together.
+fn inner_module_linking<'a>(
+    agent: &mut Agent,
+    module: AbstractModule<'a>,
+    stack: &mut Vec<SourceTextModule<'a>>,
+    mut index: u32,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, u32> {
+    // 1. If module is not a Cyclic Module Record, then
+    let module = match module {
+        AbstractModule::SourceTextModule(module) => module,
+        _ => {
+            // a. Perform ? module.Link().
+            module.link(agent, gc)?;
+            // b. Return index.
+            return Ok(index);
+        }
+    };
+    let record = &mut agent[module].cyclic_record;
+    // 2. If module.[[Status]] is one of LINKING, LINKED, EVALUATING-ASYNC, or
+    //    EVALUATED, then
+    if matches!(
+        record.status,
+        CyclicModuleRecordStatus::Linking
+            | CyclicModuleRecordStatus::Linked
+            | CyclicModuleRecordStatus::EvaluatingAsync
+            | CyclicModuleRecordStatus::Evaluated
+    ) {
+        // a. Return index.
+        return Ok(index);
+    }
+    // 3. Assert: module.[[Status]] is UNLINKED.
+    debug_assert_eq!(record.status, CyclicModuleRecordStatus::Unlinked);
+    // 4. Set module.[[Status]] to LINKING.
+    record.status = CyclicModuleRecordStatus::Linking;
+    // 5. Let moduleIndex be index.
+    let module_index = index;
+    // 6. Set module.[[DFSIndex]] to index.
+    record.dfs_index = Some(index);
+    // 7. Set module.[[DFSAncestorIndex]] to index.
+    record.dfs_ancestor_index = Some(index);
+    // 8. Set index to index + 1.
+    index += 1;
+    let requested_modules_count = record.requested_modules.len();
+    // 9. Append module to stack.
+    stack.push(module);
+    // 10. For each ModuleRequest Record request of
+    //     module.[[RequestedModules]], do
+    for i in 0..requested_modules_count {
+        let request = agent[module].cyclic_record.requested_modules[i]
+            .clone()
+            .bind(gc);
+        // a. Let requiredModule be GetImportedModule(module, request).
+        let Some(required_module) = get_imported_module(agent, module, &request, gc) else {
+            let error_message =
+                format!("Cannot find module '{}'.", request.specifier.as_str(agent));
+            return Err(agent.throw_exception(ExceptionType::TypeError, error_message, gc));
+        };
+        // b. Set index to ? InnerModuleLinking(requiredModule, stack, index).
+        index = inner_module_linking(agent, required_module, stack, index, gc)?;
+        // c. If requiredModule is a Cyclic Module Record, then
+        let AbstractModule::SourceTextModule(required_module) = required_module else {
+            continue;
+        };
+        let required_record = &agent[required_module].cyclic_record;
+        // i. Assert: requiredModule.[[Status]] is one of LINKING, LINKED,
+        //    EVALUATING-ASYNC, or EVALUATED.
+        debug_assert!(matches!(
+            required_record.status,
+            CyclicModuleRecordStatus::Linking
+                | CyclicModuleRecordStatus::Linked
+                | CyclicModuleRecordStatus::EvaluatingAsync
+                | CyclicModuleRecordStatus::Evaluated
+        ));
+        // ii. Assert: requiredModule.[[Status]] is LINKING if and only if
+        //     stack contains requiredModule.
+        debug_assert_eq!(
+            required_record.status == CyclicModuleRecordStatus::Linking,
+            stack.contains(&required_module)
+        );
+        // iii. If requiredModule.[[Status]] is LINKING, then
+        if required_record.status == CyclicModuleRecordStatus::Linking {
+            // 1. Set module.[[DFSAncestorIndex]] to
+            //    min(module.[[DFSAncestorIndex]],
+            //    requiredModule.[[DFSAncestorIndex]]).
+            let required_dfs_ancestor_index = required_record.dfs_ancestor_index.unwrap();
+            let record = &mut agent[module].cyclic_record;
+            record.dfs_ancestor_index = Some(
+                record
+                    .dfs_ancestor_index
+                    .unwrap()
+                    .min(required_dfs_ancestor_index),
+            );
+        }
+    }
+    // 11. Perform ? module.InitializeEnvironment().
+    module.initialize_environment(agent, gc)?;
+    // 12. Assert: module occurs exactly once in stack.
+    debug_assert_eq!(stack.iter().filter(|m| **m == module).count(), 1);
+    let record = &agent[module].cyclic_record;
+    let dfs_ancestor_index = record.dfs_ancestor_index.unwrap();
+    // 13. Assert: module.[[DFSAncestorIndex]] ≤ moduleIndex.
+    debug_assert!(dfs_ancestor_index <= module_index);
+    // 14. If module.[[DFSAncestorIndex]] = moduleIndex, then
+    if dfs_ancestor_index == module_index {
+        // a. Let done be false.
+        // b. Repeat, while done is false,
+        loop {
+            // i. Let requiredModule be the last element of stack.
+            // ii. Remove the last element of stack.
+            let required_module = stack.pop().unwrap();
+            // iii. Assert: requiredModule is a Cyclic Module Record.
+            // iv. Set requiredModule.[[Status]] to LINKED.
+            agent[required_module].cyclic_record.status = CyclicModuleRecordStatus::Linked;
+            // v. If requiredModule and module are the same Module Record, set
+            //    done to true.
+            if required_module == module {
+                break;
+            }
+        }
+    }
+    // 15. Return index.
+    Ok(index)
+}
+
+/// ### [16.2.1.5.3 Evaluate ( )](https://tc39.es/ecma262/#sec-moduleevaluation)
+///
+/// The Evaluate concrete method of a Cyclic Module Record module takes no
+/// arguments and returns a Promise. Evaluate transitions this module's
+/// \[\[Status]] from LINKED to either EVALUATING-ASYNC or EVALUATED. The
+/// first time it is called on a module in a given strongly connected
+/// component, Evaluate creates and returns a Promise which resolves when the
+/// module has finished evaluating. This Promise is stored in the
+/// \[\[TopLevelCapability]] field of the \[\[CycleRoot]] for the component.
+/// Future invocations of Evaluate on any module in the component return the
+/// same Promise. (Most of the work is done by the auxiliary function
+/// InnerModuleEvaluation.)
+pub(crate) fn evaluate<'a>(
+    agent: &mut Agent,
+    module: SourceTextModule,
+    mut gc: GcScope<'a, '_>,
+) -> Promise<'a> {
+    let mut module = module.bind(gc.nogc());
+    // 1. Assert: This call to Evaluate is not happening at the same time as
+    //    another call to Evaluate within the surrounding agent.
+    // 2. Assert: module.[[Status]] is one of LINKED, EVALUATING-ASYNC, or
+    //    EVALUATED.
+    let status = agent[module].cyclic_record.status;
+    debug_assert!(matches!(
+        status,
+        CyclicModuleRecordStatus::Linked
+            | CyclicModuleRecordStatus::EvaluatingAsync
+            | CyclicModuleRecordStatus::Evaluated
+    ));
+    // 3. If module.[[Status]] is either EVALUATING-ASYNC or EVALUATED, then
+    if matches!(
+        status,
+        CyclicModuleRecordStatus::EvaluatingAsync | CyclicModuleRecordStatus::Evaluated
+    ) {
+        // a. Assert: module.[[CycleRoot]] is not EMPTY.
+        // b. Set module to module.[[CycleRoot]].
+        module = agent[module]
+            .cyclic_record
+            .cycle_root
+            .unwrap()
+            .bind(gc.nogc());
+    }
+    // 4. If module.[[TopLevelCapability]] is not EMPTY, then
+    if let Some(capability) = &agent[module].cyclic_record.top_level_capability {
+        // a. Return module.[[TopLevelCapability]].[[Promise]].
+        return capability.promise().bind(gc.into_nogc());
+    }
+    // 5. Let stack be a new empty List.
+    let mut stack = vec![];
+    // 6. Let capability be ! NewPromiseCapability(%Promise%).
+    let capability = PromiseCapability::new(agent, gc.nogc());
+    let promise = capability.promise().scope(agent, gc.nogc());
+    // 7. Set module.[[TopLevelCapability]] to capability.
+    agent[module].cyclic_record.top_level_capability = Some(capability.unbind());
+    let scoped_module = module.scope(agent, gc.nogc());
+    // 8. Let result be Completion(InnerModuleEvaluation(module, stack, 0)).
+    let result =
+        inner_module_evaluation(agent, module.unbind().into(), &mut stack, 0, gc.reborrow())
+            .unbind()
+            .bind(gc.nogc());
+    let module = scoped_module.get(agent).bind(gc.nogc());
+    let capability = agent[module]
+        .cyclic_record
+        .top_level_capability
+        .clone()
+        .unwrap()
+        .bind(gc.nogc());
+    match result {
+        // 9. If result is an abrupt completion, then
+        Err(error) => {
+            // a. For each Cyclic Module Record m of stack, do
+            for m in stack {
+                let m = m.get(agent);
+                let record = &mut agent[m].cyclic_record;
+                // i. Assert: m.[[Status]] is EVALUATING.
+                debug_assert_eq!(record.status, CyclicModuleRecordStatus::Evaluating);
+                // ii. Assert: m.[[AsyncEvaluationOrder]] is UNSET.
+                debug_assert_eq!(record.async_evaluation_order, AsyncEvaluationOrder::Unset);
+                // iii. Set m.[[Status]] to EVALUATED.
+                record.status = CyclicModuleRecordStatus::Evaluated;
+                // iv. Set m.[[EvaluationError]] to result.
+                record.evaluation_error = Some(error.unbind());
+            }
+            // b. Assert: module.[[Status]] is EVALUATED.
+            debug_assert_eq!(
+                agent[module].cyclic_record.status,
+                CyclicModuleRecordStatus::Evaluated
+            );
+            // c. Assert: module.[[EvaluationError]] and result are the same
+            //    Completion Record.
+            // d. Perform ! Call(capability.[[Reject]], undefined, « result.[[Value]] »).
+            capability.reject(agent, error.value(), gc.nogc());
+        }
+        // 10. Else,
+        Ok(_) => {
+            let record = &agent[module].cyclic_record;
+            // a. Assert: module.[[Status]] is either EVALUATING-ASYNC or
+            //    EVALUATED.
+            debug_assert!(matches!(
+                record.status,
+                CyclicModuleRecordStatus::EvaluatingAsync | CyclicModuleRecordStatus::Evaluated
+            ));
+            // b. If module.[[AsyncEvaluationOrder]] is UNSET, then
+            if record.async_evaluation_order == AsyncEvaluationOrder::Unset {
+                // i. Assert: module.[[Status]] is EVALUATED.
+                debug_assert_eq!(record.status, CyclicModuleRecordStatus::Evaluated);
+                // ii. Perform ! Call(capability.[[Resolve]], undefined, « undefined »).
+                capability
+                    .unbind()
+                    .resolve(agent, Value::Undefined, gc.reborrow());
+            }
+            // c. Assert: stack is empty.
+            debug_assert!(stack.is_empty());
+        }
+    }
+    // 11. Return capability.[[Promise]].
+    promise.get(agent).bind(gc.into_nogc())
+}
+
+/// ### [16.2.1.5.3.1 InnerModuleEvaluation ( module, stack, index )](https://tc39.es/ecma262/#sec-innermoduleevaluation)
+///
+/// The abstract operation InnerModuleEvaluation takes arguments module (a
+/// Module Record), stack (a List of Cyclic Module Records), and index (a
+/// non-negative integer) and returns either a normal completion containing a
+/// non-negative integer or a throw completion. It is used by Evaluate to
+/// perform the actual evaluation process for module, as well as recursively
+/// on all other modules in the dependency graph. The stack and index
+/// parameters, as well as module's \[\[DFSIndex]] and \[\[DFSAncestorIndex]]
+/// fields, are used the same way as in InnerModuleLinking.
+fn inner_module_evaluation<'a, 'b>(
+    agent: &mut Agent,
+    module: AbstractModule,
+    stack: &mut Vec<Scoped<'b, SourceTextModule<'static>>>,
+    mut index: u32,
+    mut gc: GcScope<'a, 'b>,
+) -> JsResult<'a, u32> {
+    // 1. If module is not a Cyclic Module Record, then
+    let module = match module.bind(gc.nogc()) {
+        AbstractModule::SourceTextModule(module) => module,
+        module => {
+            // a. Let promise be ! module.Evaluate().
+            let promise = module.unbind().evaluate(agent, gc.reborrow()).unbind();
+            let gc = gc.into_nogc();
+            let promise = promise.bind(gc);
+            match agent[promise].promise_state {
+                // b. Assert: promise.[[PromiseState]] is not PENDING.
+                PromiseState::Pending { .. } => unreachable!(),
+                // c. If promise.[[PromiseState]] is REJECTED, then
+                PromiseState::Rejected { promise_result, .. } => {
+                    // i. Return ThrowCompletion(promise.[[PromiseResult]]).
+                    return Err(JsError::new(promise_result.bind(gc)));
+                }
+                // d. Return index.
+                PromiseState::Fulfilled { .. } => return Ok(index),
+            }
+        }
+    };
+    let record = &mut agent[module].cyclic_record;
+    // 2. If module.[[Status]] is either EVALUATING-ASYNC or EVALUATED, then
+    if matches!(
+        record.status,
+        CyclicModuleRecordStatus::EvaluatingAsync | CyclicModuleRecordStatus::Evaluated
+    ) {
+        return match record.evaluation_error {
+            // a. If module.[[EvaluationError]] is EMPTY, return index.
+            None => Ok(index),
+            // b. Otherwise, return ? module.[[EvaluationError]].
+            Some(error) => Err(error.bind(gc.into_nogc())),
+        };
+    }
+    // 3. If module.[[Status]] is EVALUATING, return index.
+    if record.status == CyclicModuleRecordStatus::Evaluating {
+        return Ok(index);
+    }
+    // 4. Assert: module.[[Status]] is LINKED.
+    debug_assert_eq!(record.status, CyclicModuleRecordStatus::Linked);
+    // 5. Set module.[[Status]] to EVALUATING.
+    record.status = CyclicModuleRecordStatus::Evaluating;
+    // 6. Let moduleIndex be index.
+    let module_index = index;
+    // 7. Set module.[[DFSIndex]] to index.
+    record.dfs_index = Some(index);
+    // 8. Set module.[[DFSAncestorIndex]] to index.
+    record.dfs_ancestor_index = Some(index);
+    // 9. Set module.[[PendingAsyncDependencies]] to 0.
+    record.pending_async_dependencies = 0;
+    // 10. Set index to index + 1.
+    index += 1;
+    let requested_modules_count = record.requested_modules.len();
+    // 11. Append module to stack.
+    let scoped_module = module.scope(agent, gc.nogc());
+    stack.push(scoped_module.clone());
+    // 12. For each ModuleRequest Record request of
+    //     module.[[RequestedModules]], do
+    for i in 0..requested_modules_count {
+        let module = scoped_module.get(agent).bind(gc.nogc());
+        let request = agent[module].cyclic_record.requested_modules[i]
+            .clone()
+            .bind(gc.nogc());
+        // a. Let requiredModule be GetImportedModule(module, request).
+        let required_module = get_imported_module(agent, module, &request, gc.nogc()).unwrap();
+        // b. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
+        index =
+            inner_module_evaluation(agent, required_module.unbind(), stack, index, gc.reborrow())
+                .unbind()?;
+        // c. If requiredModule is a Cyclic Module Record, then
+        let module = scoped_module.get(agent).bind(gc.nogc());
+        let request = agent[module].cyclic_record.requested_modules[i]
+            .clone()
+            .bind(gc.nogc());
+        let AbstractModule::SourceTextModule(mut required_module) =
+            get_imported_module(agent, module, &request, gc.nogc()).unwrap()
+        else {
+            continue;
+        };
+        let required_record = &agent[required_module].cyclic_record;
+        // i. Assert: requiredModule.[[Status]] is one of EVALUATING,
+        //    EVALUATING-ASYNC, or EVALUATED.
+        debug_assert!(matches!(
+            required_record.status,
+            CyclicModuleRecordStatus::Evaluating
+                | CyclicModuleRecordStatus::EvaluatingAsync
+                | CyclicModuleRecordStatus::Evaluated
+        ));
+        // ii. Assert: requiredModule.[[Status]] is EVALUATING if and only if
+        //     stack contains requiredModule.
+        // iii. If requiredModule.[[Status]] is EVALUATING, then
+        if required_record.status == CyclicModuleRecordStatus::Evaluating {
+            // 1. Set module.[[DFSAncestorIndex]] to
+            //    min(module.[[DFSAncestorIndex]],
+            //    requiredModule.[[DFSAncestorIndex]]).
+            let required_dfs_ancestor_index = required_record.dfs_ancestor_index.unwrap();
+            let record = &mut agent[module].cyclic_record;
+            record.dfs_ancestor_index = Some(
+                record
+                    .dfs_ancestor_index
+                    .unwrap()
+                    .min(required_dfs_ancestor_index),
+            );
+        } else {
+            // iv. Else,
+            // 1. Set requiredModule to requiredModule.[[CycleRoot]].
+            required_module = required_record.cycle_root.unwrap().bind(gc.nogc());
+            let required_record = &agent[required_module].cyclic_record;
+            // 2. Assert: requiredModule.[[Status]] is either EVALUATING-ASYNC
+            //    or EVALUATED.
+            debug_assert!(matches!(
+                required_record.status,
+                CyclicModuleRecordStatus::EvaluatingAsync | CyclicModuleRecordStatus::Evaluated
+            ));
+            // 3. If requiredModule.[[EvaluationError]] is not EMPTY, return ?
+            //    requiredModule.[[EvaluationError]].
+            if let Some(error) = required_record.evaluation_error {
+                return Err(error.bind(gc.into_nogc()));
+            }
+        }
+        // v. If requiredModule.[[AsyncEvaluationOrder]] is an integer, then
+        if let AsyncEvaluationOrder::Order(_) =
+            agent[required_module].cyclic_record.async_evaluation_order
+        {
+            // 1. Set module.[[PendingAsyncDependencies]] to
+            //    module.[[PendingAsyncDependencies]] + 1.
+            agent[module].cyclic_record.pending_async_dependencies += 1;
+            // 2. Append module to requiredModule.[[AsyncParentModules]].
+            agent[required_module]
+                .cyclic_record
+                .async_parent_modules
+                .push(module.unbind());
+        }
+    }
+    let module = scoped_module.get(agent).bind(gc.nogc());
+    let record = &agent[module].cyclic_record;
+    let pending_async_dependencies = record.pending_async_dependencies;
+    // 13. If module.[[PendingAsyncDependencies]] > 0 or module.[[HasTLA]] is
+    //     true, then
+    if pending_async_dependencies > 0 || record.has_top_level_await {
+        // a. Assert: module.[[AsyncEvaluationOrder]] is UNSET.
+        debug_assert_eq!(record.async_evaluation_order, AsyncEvaluationOrder::Unset);
+        // b. Set module.[[AsyncEvaluationOrder]] to
+        //    IncrementModuleAsyncEvaluationCount().
+        let order = increment_module_async_evaluation_count(agent);
+        agent[module].cyclic_record.async_evaluation_order = AsyncEvaluationOrder::Order(order);
+        // c. If module.[[PendingAsyncDependencies]] = 0, perform
+        //    ExecuteAsyncModule(module).
+        if pending_async_dependencies == 0 {
+            execute_async_module(agent, module.unbind(), gc.reborrow());
+        }
+    } else {
+        // 14. Else,
+        // a. Perform ? module.ExecuteModule().
+        module
+            .unbind()
+            .execute_module(agent, None, gc.reborrow())
+            .unbind()?;
+    }
+    let module = scoped_module.get(agent).bind(gc.nogc());
+    // 15. Assert: module occurs exactly once in stack.
+    let dfs_ancestor_index = agent[module].cyclic_record.dfs_ancestor_index.unwrap();
+    // 16. Assert: module.[[DFSAncestorIndex]] ≤ moduleIndex.
+    debug_assert!(dfs_ancestor_index <= module_index);
+    // 17. If module.[[DFSAncestorIndex]] = moduleIndex, then
+    if dfs_ancestor_index == module_index {
+        // a. Let done be false.
+        // b. Repeat, while done is false,
+        loop {
+            // i. Let requiredModule be the last element of stack.
+            // ii. Remove the last element of stack.
+            let required_module = stack.pop().unwrap().get(agent).bind(gc.nogc());
+            // iii. Assert: requiredModule is a Cyclic Module Record.
+            let record = &mut agent[required_module].cyclic_record;
+            // iv. Assert: requiredModule.[[AsyncEvaluationOrder]] is either an
+            //     integer or UNSET.
+            debug_assert_ne!(record.async_evaluation_order, AsyncEvaluationOrder::Done);
+            record.status = if record.async_evaluation_order == AsyncEvaluationOrder::Unset {
+                // v. If requiredModule.[[AsyncEvaluationOrder]] is UNSET, set
+                //    requiredModule.[[Status]] to EVALUATED.
+                CyclicModuleRecordStatus::Evaluated
+            } else {
+                // vi. Otherwise, set requiredModule.[[Status]] to
+                //     EVALUATING-ASYNC.
+                CyclicModuleRecordStatus::EvaluatingAsync
+            };
+            // viii. Set requiredModule.[[CycleRoot]] to module.
+            record.cycle_root = Some(module.unbind());
+            // vii. If requiredModule and module are the same Module Record,
+            //      set done to true.
+            if required_module == module {
+                break;
+            }
+        }
+    }
+    // 18. Return index.
+    Ok(index)
+}
+
+/// ### [16.2.1.5.3.2 ExecuteAsyncModule ( module )](https://tc39.es/ecma262/#sec-execute-async-module)
+///
+/// The abstract operation ExecuteAsyncModule takes argument module (a Cyclic
+/// Module Record) and returns UNUSED.
+fn execute_async_module(agent: &mut Agent, module: SourceTextModule, gc: GcScope) {
+    let module = module.bind(gc.nogc());
+    // 1. Assert: module.[[Status]] is either EVALUATING or EVALUATING-ASYNC.
+    debug_assert!(matches!(
+        agent[module].cyclic_record.status,
+        CyclicModuleRecordStatus::Evaluating | CyclicModuleRecordStatus::EvaluatingAsync
+    ));
+    // 2. Assert: module.[[HasTLA]] is true.
+    debug_assert!(agent[module].cyclic_record.has_top_level_await);
+    // 3. Let capability be ! NewPromiseCapability(%Promise%).
+    let capability = PromiseCapability::new(agent, gc.nogc());
+    // 4. Let fulfilledClosure be a new Abstract Closure with no parameters
+    //    that captures module and performs the following steps when called:
+    //    a. Perform AsyncModuleExecutionFulfilled(module).
+    //    b. Return undefined.
+    // 5. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 0, "", « »).
+    // 6. Let rejectedClosure be a new Abstract Closure with parameters
+    //    (error) that captures module and performs the following steps when
+    //    called:
+    //    a. Perform AsyncModuleExecutionRejected(module, error).
+    //    b. Return undefined.
+    // 7. Let onRejected be CreateBuiltinFunction(rejectedClosure, 0, "", « »).
+    let handler = PromiseReactionHandler::AsyncModule(module);
+    // 8. Perform PerformPromiseThen(capability.[[Promise]], onFulfilled,
+    //    onRejected).
+    inner_promise_then(
+        agent,
+        capability.promise(),
+        handler,
+        handler,
+        None,
+        gc.nogc(),
+    );
+    // 9. Perform ! module.ExecuteModule(capability).
+    let _ = module
+        .unbind()
+        .execute_module(agent, Some(capability.unbind()), gc);
+    // 10. Return UNUSED.
+}
+
+/// ### [16.2.1.5.3.3 GatherAvailableAncestors ( module, execList )](https://tc39.es/ecma262/#sec-gather-available-ancestors)
+///
+/// The abstract operation GatherAvailableAncestors takes arguments module (a
+/// Cyclic Module Record) and execList (a List of Cyclic Module Records) and
+/// returns UNUSED.
+fn gather_available_ancestors<'a>(
+    agent: &mut Agent,
+    module: SourceTextModule<'a>,
+    exec_list: &mut Vec<SourceTextModule<'a>>,
+) {
+    // 1. For each Cyclic Module Record m of module.[[AsyncParentModules]], do
+    let async_parent_modules = agent[module].cyclic_record.async_parent_modules.clone();
+    for m in async_parent_modules {
+        let cycle_root = agent[m].cyclic_record.cycle_root.unwrap();
+        // a. If execList does not contain m and
+        //    m.[[CycleRoot]].[[EvaluationError]] is EMPTY, then
+        if !exec_list.contains(&m) && agent[cycle_root].cyclic_record.evaluation_error.is_none() {
+            let record = &mut agent[m].cyclic_record;
+            // i. Assert: m.[[Status]] is EVALUATING-ASYNC.
+            debug_assert_eq!(record.status, CyclicModuleRecordStatus::EvaluatingAsync);
+            // ii. Assert: m.[[EvaluationError]] is EMPTY.
+            debug_assert!(record.evaluation_error.is_none());
+            // iii. Assert: m.[[AsyncEvaluationOrder]] is an integer.
+            debug_assert!(matches!(
+                record.async_evaluation_order,
+                AsyncEvaluationOrder::Order(_)
+            ));
+            // iv. Assert: m.[[PendingAsyncDependencies]] > 0.
+            debug_assert!(record.pending_async_dependencies > 0);
+            // v. Set m.[[PendingAsyncDependencies]] to
+            //    m.[[PendingAsyncDependencies]] - 1.
+            record.pending_async_dependencies -= 1;
+            // vi. If m.[[PendingAsyncDependencies]] = 0, then
+            if record.pending_async_dependencies == 0 {
+                let has_top_level_await = record.has_top_level_await;
+                // 1. Append m to execList.
+                exec_list.push(m);
+                // 2. If m.[[HasTLA]] is false, perform
+                //    GatherAvailableAncestors(m, execList).
+                if !has_top_level_await {
+                    gather_available_ancestors(agent, m, exec_list);
+                }
+            }
+        }
+    }
+    // 2. Return UNUSED.
+}
+
+/// ### [16.2.1.5.3.4 AsyncModuleExecutionFulfilled ( module )](https://tc39.es/ecma262/#sec-async-module-execution-fulfilled)
+///
+/// The abstract operation AsyncModuleExecutionFulfilled takes argument module
+/// (a Cyclic Module Record) and returns UNUSED.
+pub(crate) fn async_module_execution_fulfilled(
+    agent: &mut Agent,
+    module: SourceTextModule,
+    mut gc: GcScope,
+) {
+    let module = module.bind(gc.nogc());
+    let record = &mut agent[module].cyclic_record;
+    // 1. If module.[[Status]] is EVALUATED, then
+    if record.status == CyclicModuleRecordStatus::Evaluated {
+        // a. Assert: module.[[EvaluationError]] is not EMPTY.
+        debug_assert!(record.evaluation_error.is_some());
+        // b. Return UNUSED.
+        return;
+    }
+    // 2. Assert: module.[[Status]] is EVALUATING-ASYNC.
+    debug_assert_eq!(record.status, CyclicModuleRecordStatus::EvaluatingAsync);
+    // 3. Assert: module.[[AsyncEvaluationOrder]] is an integer.
+    debug_assert!(matches!(
+        record.async_evaluation_order,
+        AsyncEvaluationOrder::Order(_)
+    ));
+    // 4. Assert: module.[[EvaluationError]] is EMPTY.
+    debug_assert!(record.evaluation_error.is_none());
+    // 5. Set module.[[AsyncEvaluationOrder]] to DONE.
+    record.async_evaluation_order = AsyncEvaluationOrder::Done;
+    // 6. Set module.[[Status]] to EVALUATED.
+    record.status = CyclicModuleRecordStatus::Evaluated;
+    let scoped_module = module.scope(agent, gc.nogc());
+    // 7. If module.[[TopLevelCapability]] is not EMPTY, then
+    if let Some(capability) = agent[module].cyclic_record.top_level_capability.clone() {
+        // a. Assert: module.[[CycleRoot]] and module are the same Module
+        //    Record.
+        debug_assert_eq!(agent[module].cyclic_record.cycle_root, Some(module));
+        // b. Perform ! Call(module.[[TopLevelCapability]].[[Resolve]],
+        //    undefined, « undefined »).
+        capability.resolve(agent, Value::Undefined, gc.reborrow());
+    }
+    let module = scoped_module.get(agent).bind(gc.nogc());
+    // 8. Let execList be a new empty List.
+    let mut exec_list = vec![];
+    // 9. Perform GatherAvailableAncestors(module, execList).
+    gather_available_ancestors(agent, module, &mut exec_list);
+    // 10. Assert: All elements of execList have their
+    //     [[AsyncEvaluationOrder]] field set to an integer,
+    //     [[PendingAsyncDependencies]] field set to 0, and [[EvaluationError]]
+    //     field set to EMPTY.
+    // 11. Let sortedExecList be a List whose elements are the elements of
+    //     execList, sorted by their [[AsyncEvaluationOrder]] field in
+    //     ascending order.
+    exec_list.sort_by_key(|m| match agent[*m].cyclic_record.async_evaluation_order {
+        AsyncEvaluationOrder::Order(order) => order,
+        _ => unreachable!(),
+    });
+    let sorted_exec_list = exec_list
+        .into_iter()
+        .map(|m| m.scope(agent, gc.nogc()))
+        .collect::<Vec<_>>();
+    // 12. For each Cyclic Module Record m of sortedExecList, do
+    for scoped_m in sorted_exec_list {
+        let m = scoped_m.get(agent).bind(gc.nogc());
+        let record = &agent[m].cyclic_record;
+        if record.status == CyclicModuleRecordStatus::Evaluated {
+            // a. If m.[[Status]] is EVALUATED, then
+            // i. Assert: m.[[EvaluationError]] is not EMPTY.
+            debug_assert!(record.evaluation_error.is_some());
+        } else if record.has_top_level_await {
+            // b. Else if m.[[HasTLA]] is true, then
+            // i. Perform ExecuteAsyncModule(m).
+            execute_async_module(agent, m.unbind(), gc.reborrow());
+        } else {
+            // c. Else,
+            // i. Let result be m.ExecuteModule().
+            let result = m
+                .unbind()
+                .execute_module(agent, None, gc.reborrow())
+                .unbind()
+                .bind(gc.nogc());
+            let m = scoped_m.get(agent).bind(gc.nogc());
+            // ii. If result is an abrupt completion, then
+            if let Err(error) = result {
+                // 1. Perform AsyncModuleExecutionRejected(m, result.[[Value]]).
+                async_module_execution_rejected(agent, m, error.value(), gc.nogc());
+            } else {
+                // iii. Else,
+                let record = &mut agent[m].cyclic_record;
+                // 1. Set m.[[AsyncEvaluationOrder]] to DONE.
+                record.async_evaluation_order = AsyncEvaluationOrder::Done;
+                // 2. Set m.[[Status]] to EVALUATED.
+                record.status = CyclicModuleRecordStatus::Evaluated;
+                // 3. If m.[[TopLevelCapability]] is not EMPTY, then
+                if let Some(capability) = record.top_level_capability.clone() {
+                    // a. Assert: m.[[CycleRoot]] and m are the same Module
+                    //    Record.
+                    debug_assert_eq!(record.cycle_root, Some(m.unbind()));
+                    // b. Perform ! Call(m.[[TopLevelCapability]].[[Resolve]],
+                    //    undefined, « undefined »).
+                    capability.resolve(agent, Value::Undefined, gc.reborrow());
+                }
+            }
+        }
+    }
+    // 13. Return UNUSED.
+}
+
+/// ### [16.2.1.5.3.5 AsyncModuleExecutionRejected ( module, error )](https://tc39.es/ecma262/#sec-async-module-execution-rejected)
+///
+/// The abstract operation AsyncModuleExecutionRejected takes arguments module
+/// (a Cyclic Module Record) and error (an ECMAScript language value) and
+/// returns UNUSED.
+pub(crate) fn async_module_execution_rejected(
+    agent: &mut Agent,
+    module: SourceTextModule,
+    error: Value,
+    gc: NoGcScope,
+) {
+    let record = &mut agent[module].cyclic_record;
+    // 1. If module.[[Status]] is EVALUATED, then
+    if record.status == CyclicModuleRecordStatus::Evaluated {
+        // a. Assert: module.[[EvaluationError]] is not EMPTY.
+        debug_assert!(record.evaluation_error.is_some());
+        // b. Return UNUSED.
+        return;
+    }
+    // 2. Assert: module.[[Status]] is EVALUATING-ASYNC.
+    debug_assert_eq!(record.status, CyclicModuleRecordStatus::EvaluatingAsync);
+    // 3. Assert: module.[[AsyncEvaluationOrder]] is an integer.
+    debug_assert!(matches!(
+        record.async_evaluation_order,
+        AsyncEvaluationOrder::Order(_)
+    ));
+    // 4. Assert: module.[[EvaluationError]] is EMPTY.
+    debug_assert!(record.evaluation_error.is_none());
+    // 5. Set module.[[EvaluationError]] to ThrowCompletion(error).
+    record.evaluation_error = Some(JsError::new(error.unbind()));
+    // 6. Set module.[[Status]] to EVALUATED.
+    record.status = CyclicModuleRecordStatus::Evaluated;
+    // 7. Set module.[[AsyncEvaluationOrder]] to DONE.
+    record.async_evaluation_order = AsyncEvaluationOrder::Done;
+    // 8. NOTE: module.[[AsyncEvaluationOrder]] is set to DONE for symmetry
+    //    with AsyncModuleExecutionFulfilled. In InnerModuleEvaluation, the
+    //    value of a module's [[AsyncEvaluationOrder]] internal slot is unused
+    //    when its [[EvaluationError]] internal slot is not EMPTY.
+    // 9. For each Cyclic Module Record m of module.[[AsyncParentModules]], do
+    let async_parent_modules = record.async_parent_modules.clone();
+    for m in async_parent_modules {
+        // a. Perform AsyncModuleExecutionRejected(m, error).
+        async_module_execution_rejected(agent, m, error, gc);
+    }
+    // 10. If module.[[TopLevelCapability]] is not EMPTY, then
+    if let Some(capability) = agent[module].cyclic_record.top_level_capability.clone() {
+        // a. Assert: module.[[CycleRoot]] and module are the same Module
+        //    Record.
+        // b. Perform ! Call(module.[[TopLevelCapability]].[[Reject]],
+        //    undefined, « error »).
+        capability.reject(agent, error, gc);
+    }
+    // 11. Return UNUSED.
+}
+
+/// ### [16.2.1.5.3.6 IncrementModuleAsyncEvaluationCount ( )](https://tc39.es/ecma262/#sec-IncrementModuleAsyncEvaluationCount)
+///
+/// The abstract operation IncrementModuleAsyncEvaluationCount takes no
+/// arguments and returns an integer.
+fn increment_module_async_evaluation_count(agent: &mut Agent) -> u32 {
+    // 1. Let AR be the Agent Record of the surrounding agent.
+    // 2. Let count be AR.[[ModuleAsyncEvaluationCount]].
+    let count = agent.module_async_evaluation_count;
+    // 3. Set AR.[[ModuleAsyncEvaluationCount]] to count + 1.
+    agent.module_async_evaluation_count += 1;
+    // 4. Return count.
+    count
+}
@@ -0,0 +1,2270 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [16.2.1.7 Source Text Module Records](https://tc39.es/ecma262/#sec-source-text-module-records)
+//!
+//! A Source Text Module Record is used to represent information about a
+//! module that was defined from ECMAScript source text (11) that was parsed
+//! using the goal symbol Module. Its fields contain digested information
+//! about the names that are imported by the module and its concrete methods
+//! use this digest to link and evaluate the module.
+
+use core::{
+    any::Any,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Index, IndexMut},
+};
+
+use ahash::AHashSet;
+use oxc_ast::ast::{
+    BindingIdentifier, Declaration, ExportDefaultDeclarationKind, ImportDeclarationSpecifier,
+    Program, Statement, WithClause,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_span::SourceType;
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            control_abstraction_objects::{
+                async_function_objects::await_reaction::AwaitReaction,
+                promise_objects::{
+                    promise_abstract_operations::{
+                        promise_capability_records::PromiseCapability,
+                        promise_reaction_records::PromiseReactionHandler,
+                    },
+                    promise_prototype::inner_promise_then,
+                },
+            },
+            promise::Promise,
+        },
+        execution::{
+            Agent, ECMAScriptCode, Environment, ExecutionContext, JsResult, Realm,
+            agent::ExceptionType, new_module_environment,
+        },
+        scripts_and_modules::{ScriptOrModule, script::HostDefined, source_code::SourceCode},
+        syntax_directed_operations::{
+            miscellaneous::instantiate_function_object,
+            scope_analysis::{
+                LexicallyScopedDeclaration, VarScopedDeclaration,
+                module_lexically_scoped_declarations, module_var_scoped_declarations,
+            },
+        },
+        types::{BUILTIN_STRING_MEMORY, IntoValue, OrdinaryObject, String, Value},
+    },
+    engine::{
+        Executable, ExecutionResult, Instruction, InstructionIter, Vm,
+        context::{Bindable, GcScope, GcToken, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable, Scopable},
+    },
+    heap::{CompactionLists, CreateHeapData, HeapMarkAndSweep, WorkQueues},
+};
+
+use super::{
+    ImportAttribute, ModuleRequest,
+    abstract_module_records::{
+        AbstractModuleRecord, ResolveExportResult, ResolvedBinding, ResolvedBindingName,
+    },
+    cyclic_module_records::{CyclicModuleRecord, evaluate, link, load_requested_modules},
+    get_imported_module, get_module_namespace,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SourceTextModule<'a>(
+    u32,
+    PhantomData<SourceTextModuleRecord<'static>>,
+    PhantomData<&'a GcToken>,
+);
+
+impl core::fmt::Debug for SourceTextModule<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SourceTextModule({:?})", self.into_u32())
+    }
+}
+
+impl SourceTextModule<'_> {
+    /// Creates a module identififer from a usize.
+    ///
+    /// ## Panics
+    /// If the given index is greater than `u32::MAX`.
+    pub(crate) const fn from_index(value: usize) -> Self {
+        assert!(value <= u32::MAX as usize);
+        Self::from_u32(value as u32)
+    }
+
+    /// Creates a module identififer from a u32.
+    pub(crate) const fn from_u32(value: u32) -> Self {
+        Self(value, PhantomData, PhantomData)
+    }
+
+    pub(crate) fn last(modules: &[Option<SourceTextModuleRecord>]) -> Self {
+        let index = modules.len() - 1;
+        Self::from_index(index)
+    }
+
+    pub(crate) const fn into_index(self) -> usize {
+        self.0 as usize
+    }
+
+    pub(crate) const fn into_u32(self) -> u32 {
+        self.0
+    }
+
+    /// ### [LoadRequestedModules ( \[ hostDefined \] )](https://tc39.es/ecma262/#sec-LoadRequestedModules)
+    ///
+    /// Populates the \[\[LoadedModules]] of all the Module Records in the
+    /// dependency graph of this module using the
+    /// [HostHooks::load_imported_module](crate::ecmascript::execution::agent::HostHooks::load_imported_module)
+    /// hook. The returned promise is resolved when the whole graph has been
+    /// loaded, or rejected if loading any module fails. The optional
+    /// `host_defined` value is passed to the hook through its payload.
+    pub fn load_requested_modules<'a>(
+        self,
+        agent: &mut Agent,
+        host_defined: Option<HostDefined>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Promise<'a> {
+        load_requested_modules(agent, self, host_defined, gc)
+    }
+
+    /// ### \[\[HostDefined]]
+    ///
+    /// Host-defined data passed to
+    /// [parse_module](crate::ecmascript::scripts_and_modules::module::module_semantics::source_text_module_records::parse_module).
+    pub fn host_defined(self, agent: &Agent) -> Option<&dyn Any> {
+        agent[self].abstract_record.host_defined.as_deref()
+    }
+
+    /// Get the module specifiers requested by this module in source text
+    /// occurrence order.
+    pub fn requested_modules<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Vec<String<'a>> {
+        agent[self]
+            .cyclic_record
+            .requested_modules
+            .iter()
+            .map(|request| request.specifier.bind(gc))
+            .collect()
+    }
+
+    /// ### [Link ( )](https://tc39.es/ecma262/#sec-moduledeclarationlinking)
+    ///
+    /// Prepare the module for evaluation by transitively resolving all module
+    /// dependencies and creating a Module Environment Record.
+    pub fn link<'a>(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsResult<'a, ()> {
+        link(agent, self, gc)
+    }
+
+    /// ### [Evaluate ( )](https://tc39.es/ecma262/#sec-moduleevaluation)
+    ///
+    /// Returns a promise for the evaluation of this module and its
+    /// dependencies, resolving on successful evaluation or if it has already
+    /// been evaluated successfully, and rejecting for an evaluation error or
+    /// if it has already been evaluated unsuccessfully.
+    pub fn evaluate<'a>(self, agent: &mut Agent, gc: GcScope<'a, '_>) -> Promise<'a> {
+        evaluate(agent, self, gc)
+    }
+
+    /// ### [16.2.1.7.2.1 GetExportedNames ( \[ exportStarSet \] )](https://tc39.es/ecma262/#sec-getexportednames)
+    ///
+    /// The GetExportedNames concrete method of a Source Text Module Record
+    /// module takes optional argument exportStarSet (a List of Source Text
+    /// Module Records) and returns a List of Strings.
+    pub(crate) fn get_exported_names<'a>(
+        self,
+        agent: &Agent,
+        export_star_set: &mut Vec<SourceTextModule<'a>>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Vec<String<'a>> {
+        let module = self.bind(gc);
+        // 1. Assert: module.[[Status]] is not NEW.
+        // 2. If exportStarSet is not present, set exportStarSet to a new empty
+        //    List.
+        // 3. If exportStarSet contains module, then
+        if export_star_set.contains(&module) {
+            // a. Assert: We've reached the starting point of an export *
+            //    circularity.
+            // b. Return a new empty List.
+            return vec![];
+        }
+        // 4. Append module to exportStarSet.
+        export_star_set.push(module);
+        let record = &agent[module];
+        // 5. Let exportedNames be a new empty List.
+        let mut exported_names = Vec::with_capacity(
+            record.local_export_entries.len() + record.indirect_export_entries.len(),
+        );
+        // 6. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+        for e in record.local_export_entries.iter() {
+            // a. Assert: module provides the direct binding for this export.
+            // b. Assert: e.[[ExportName]] is not null.
+            // c. Append e.[[ExportName]] to exportedNames.
+            exported_names.push(e.export_name.unwrap().bind(gc));
+        }
+        // 7. For each ExportEntry Record e of module.[[IndirectExportEntries]],
+        //    do
+        for e in record.indirect_export_entries.iter() {
+            // a. Assert: module imports a specific binding for this export.
+            // b. Assert: e.[[ExportName]] is not null.
+            // c. Append e.[[ExportName]] to exportedNames.
+            exported_names.push(e.export_name.unwrap().bind(gc));
+        }
+        // 8. For each ExportEntry Record e of module.[[StarExportEntries]], do
+        for e in record.star_export_entries.iter() {
+            // a. Assert: e.[[ModuleRequest]] is not null.
+            // b. Let requestedModule be GetImportedModule(module,
+            //    e.[[ModuleRequest]]).
+            let Some(requested_module) =
+                get_imported_module(agent, module, e.module_request.as_ref().unwrap(), gc)
+            else {
+                continue;
+            };
+            // c. Let starNames be requestedModule.GetExportedNames(exportStarSet).
+            let star_names = requested_module.get_exported_names(agent, export_star_set, gc);
+            // d. For each element n of starNames, do
+            for n in star_names {
+                // i. If n is not "default", then
+                // 1. If exportedNames does not contain n, then
+                if n != BUILTIN_STRING_MEMORY.default && !exported_names.contains(&n) {
+                    // a. Append n to exportedNames.
+                    exported_names.push(n);
+                }
+            }
+        }
+        // 9. Return exportedNames.
+        exported_names
+    }
+
+    /// ### [16.2.1.7.2.2 ResolveExport ( exportName \[ , resolveSet \] )](https://tc39.es/ecma262/#sec-resolveexport)
+    ///
+    /// The ResolveExport concrete method of a Source Text Module Record module
+    /// takes argument exportName (a String) and optional argument resolveSet
+    /// (a List of Records with fields \[\[Module]] (a Module Record) and
+    /// \[\[ExportName]] (a String)) and returns a ResolvedBinding Record,
+    /// null, or AMBIGUOUS.
+    ///
+    /// ResolveExport attempts to resolve an imported binding to the actual
+    /// defining module and local binding name. The defining module may be the
+    /// module represented by the Module Record this method was invoked on or
+    /// some other module that is imported by that module. The parameter
+    /// resolveSet is used to detect unresolved circular import/export paths.
+    pub(crate) fn resolve_export<'a>(
+        self,
+        agent: &Agent,
+        export_name: String,
+        resolve_set: &mut Vec<(SourceTextModule<'a>, String<'a>)>,
+        gc: NoGcScope<'a, '_>,
+    ) -> Option<ResolveExportResult<'a>> {
+        let module = self.bind(gc);
+        let export_name = export_name.bind(gc);
+        // 1. Assert: module.[[Status]] is not NEW.
+        // 2. If resolveSet is not present, set resolveSet to a new empty List.
+        // 3. For each Record { [[Module]], [[ExportName]] } r of resolveSet, do
+        // a. If module and r.[[Module]] are the same Module Record and
+        //    exportName is r.[[ExportName]], then
+        if resolve_set.contains(&(module, export_name)) {
+            // i. Assert: This is a circular import request.
+            // ii. Return null.
+            return None;
+        }
+        // 4. Append the Record { [[Module]]: module, [[ExportName]]:
+        //    exportName } to resolveSet.
+        resolve_set.push((module, export_name));
+        let record = &agent[module];
+        // 5. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+        for e in record.local_export_entries.iter() {
+            // a. If e.[[ExportName]] is exportName, then
+            if e.export_name == Some(export_name.unbind()) {
+                // i. Assert: module provides the direct binding for this
+                //    export.
+                // ii. Return ResolvedBinding Record { [[Module]]: module,
+                //     [[BindingName]]: e.[[LocalName]] }.
+                return Some(ResolveExportResult::Resolved(ResolvedBinding {
+                    module: module.into(),
+                    binding_name: ResolvedBindingName::String(e.local_name.unwrap().bind(gc)),
+                }));
+            }
+        }
+        // 6. For each ExportEntry Record e of module.[[IndirectExportEntries]],
+        //    do
+        for e in record.indirect_export_entries.iter() {
+            // a. If e.[[ExportName]] is exportName, then
+            if e.export_name == Some(export_name.unbind()) {
+                // i. Assert: e.[[ModuleRequest]] is not null.
+                // ii. Let importedModule be GetImportedModule(module,
+                //     e.[[ModuleRequest]]).
+                let imported_module =
+                    get_imported_module(agent, module, e.module_request.as_ref().unwrap(), gc)?;
+                match e.import_name.unwrap() {
+                    // iii. If e.[[ImportName]] is ALL, then
+                    ExportImportName::All => {
+                        // 1. Assert: module does not provide the direct
+                        //    binding for this export.
+                        // 2. Return ResolvedBinding Record { [[Module]]:
+                        //    importedModule, [[BindingName]]: NAMESPACE }.
+                        return Some(ResolveExportResult::Resolved(ResolvedBinding {
+                            module: imported_module,
+                            binding_name: ResolvedBindingName::Namespace,
+                        }));
+                    }
+                    // iv. Else,
+                    ExportImportName::Name(import_name) => {
+                        // 1. Assert: module imports a specific binding for
+                        //    this export.
+                        // 2. Assert: e.[[ImportName]] is a String.
+                        // 3. Return importedModule.ResolveExport(e.[[ImportName]],
+                        //    resolveSet).
+                        return imported_module.resolve_export(agent, import_name, resolve_set, gc);
+                    }
+                    ExportImportName::AllButDefault => unreachable!(),
+                }
+            }
+        }
+        // 7. If exportName is "default", then
+        if export_name == BUILTIN_STRING_MEMORY.default {
+            // a. Assert: A default export was not explicitly defined by this
+            //    module.
+            // b. Return null.
+            // c. NOTE: A default export cannot be provided by an export * from
+            //    "mod" declaration.
+            return None;
+        }
+        // 8. Let starResolution be null.
+        let mut star_resolution: Option<ResolvedBinding> = None;
+        // 9. For each ExportEntry Record e of module.[[StarExportEntries]], do
+        for e in record.star_export_entries.iter() {
+            // a. Assert: e.[[ModuleRequest]] is not null.
+            // b. Let importedModule be GetImportedModule(module,
+            //    e.[[ModuleRequest]]).
+            let Some(imported_module) =
+                get_imported_module(agent, module, e.module_request.as_ref().unwrap(), gc)
+            else {
+                continue;
+            };
+            // c. Let resolution be importedModule.ResolveExport(exportName,
+            //    resolveSet).
+            let resolution = imported_module.resolve_export(agent, export_name, resolve_set, gc);
+            match resolution {
+                // d. If resolution is AMBIGUOUS, return AMBIGUOUS.
+                Some(ResolveExportResult::Ambiguous) => {
+                    return Some(ResolveExportResult::Ambiguous);
+                }
+                // e. If resolution is not null, then
+                // i. Assert: resolution is a ResolvedBinding Record.
+                Some(ResolveExportResult::Resolved(resolution)) => {
+                    match star_resolution {
+                        // ii. If starResolution is null, then
+                        None => {
+                            // 1. Set starResolution to resolution.
+                            star_resolution = Some(resolution);
+                        }
+                        // iii. Else,
+                        Some(star_resolution) => {
+                            // 1. Assert: There is more than one * import that
+                            //    includes the requested name.
+                            // 2. If resolution.[[Module]] and
+                            //    starResolution.[[Module]] are not the same
+                            //    Module Record, return AMBIGUOUS.
+                            // 3. If resolution.[[BindingName]] is not
+                            //    starResolution.[[BindingName]], return
+                            //    AMBIGUOUS.
+                            if resolution != star_resolution {
+                                return Some(ResolveExportResult::Ambiguous);
+                            }
+                        }
+                    }
+                }
+                None => {}
+            }
+        }
+        // 10. Return starResolution.
+        star_resolution.map(ResolveExportResult::Resolved)
+    }
+
+    /// ### [16.2.1.7.3.1 InitializeEnvironment ( )](https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment)
+    ///
+    /// The InitializeEnvironment concrete method of a Source Text Module
+    /// Record module takes no arguments and returns either a normal
+    /// completion containing UNUSED or a throw completion.
+    pub(crate) fn initialize_environment<'a>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let module = self.bind(gc);
+        // 1. For each ExportEntry Record e of module.[[IndirectExportEntries]],
+        //    do
+        for i in 0..agent[module].indirect_export_entries.len() {
+            // a. Assert: e.[[ExportName]] is not null.
+            let export_name = agent[module].indirect_export_entries[i]
+                .export_name
+                .unwrap()
+                .bind(gc);
+            // b. Let resolution be module.ResolveExport(e.[[ExportName]]).
+            let resolution = module.resolve_export(agent, export_name, &mut vec![], gc);
+            // c. If resolution is either null or AMBIGUOUS, throw a
+            //    SyntaxError exception.
+            // d. Assert: resolution is a ResolvedBinding Record.
+            if !matches!(resolution, Some(ResolveExportResult::Resolved(_))) {
+                return Err(throw_unresolvable_export(
+                    agent,
+                    export_name,
+                    resolution,
+                    gc,
+                ));
+            }
+        }
+        // 2. Assert: All named exports from module are resolvable.
+        // 3. Let realm be module.[[Realm]].
+        // 4. Assert: realm is not undefined.
+        let realm = agent[module].abstract_record.realm.bind(gc);
+        // 5. Let env be NewModuleEnvironment(realm.[[GlobalEnv]]).
+        let global_env = agent[realm].global_env.unwrap().bind(gc);
+        let env = new_module_environment(agent, Environment::Global(global_env), gc);
+        // 6. Set module.[[Environment]] to env.
+        agent[module].abstract_record.environment = Some(env.unbind());
+        // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
+        for i in 0..agent[module].import_entries.len() {
+            let ImportEntry {
+                module_request,
+                import_name,
+                local_name,
+            } = agent[module].import_entries[i].clone().bind(gc);
+            // a. Let importedModule be GetImportedModule(module,
+            //    in.[[ModuleRequest]]).
+            let Some(imported_module) = get_imported_module(agent, module, &module_request, gc)
+            else {
+                let error_message = format!(
+                    "Cannot find module '{}'.",
+                    module_request.specifier.as_str(agent)
+                );
+                return Err(agent.throw_exception(ExceptionType::TypeError, error_message, gc));
+            };
+            match import_name {
+                // b. If in.[[ImportName]] is NAMESPACE-OBJECT, then
+                ImportName::NamespaceObject => {
+                    // i. Let namespace be GetModuleNamespace(importedModule).
+                    let namespace = get_module_namespace(agent, imported_module, gc);
+                    // ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                    env.create_immutable_binding(agent, local_name, true);
+                    // iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                    env.initialize_binding(agent, local_name, namespace.into_value());
+                }
+                // c. Else,
+                ImportName::Name(import_name) => {
+                    // i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
+                    let resolution =
+                        imported_module.resolve_export(agent, import_name, &mut vec![], gc);
+                    // ii. If resolution is either null or AMBIGUOUS, throw a
+                    //     SyntaxError exception.
+                    let Some(ResolveExportResult::Resolved(resolution)) = resolution else {
+                        return Err(throw_unresolvable_export(
+                            agent,
+                            import_name,
+                            resolution,
+                            gc,
+                        ));
+                    };
+                    match resolution.binding_name {
+                        // iii. If resolution.[[BindingName]] is NAMESPACE, then
+                        ResolvedBindingName::Namespace => {
+                            // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
+                            let namespace = get_module_namespace(agent, resolution.module, gc);
+                            // 2. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                            env.create_immutable_binding(agent, local_name, true);
+                            // 3. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                            env.initialize_binding(agent, local_name, namespace.into_value());
+                        }
+                        // iv. Else,
+                        ResolvedBindingName::String(binding_name) => {
+                            // 1. Perform env.CreateImportBinding(in.[[LocalName]],
+                            //    resolution.[[Module]], resolution.[[BindingName]]).
+                            env.create_import_binding(
+                                agent,
+                                local_name,
+                                resolution.module,
+                                binding_name,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        // 8. Let moduleContext be a new ECMAScript code execution context.
+        // 9. Set the Function of moduleContext to null.
+        // 10. Assert: module.[[Realm]] is not undefined.
+        // 11. Set the Realm of moduleContext to module.[[Realm]].
+        // 12. Set the ScriptOrModule of moduleContext to module.
+        // 13. Set the VariableEnvironment of moduleContext to module.[[Environment]].
+        // 14. Set the LexicalEnvironment of moduleContext to module.[[Environment]].
+        // 15. Set the PrivateEnvironment of moduleContext to null.
+        // 16. Set module.[[Context]] to moduleContext.
+        let module_context = module.create_module_context(agent);
+        // 17. Push moduleContext onto the execution context stack;
+        //     moduleContext is now the running execution context.
+        agent.push_execution_context(module_context);
+        // 18. Let code be module.[[ECMAScriptCode]].
+        // SAFETY: The Program is owned by the SourceTextModuleRecord and its
+        // contents live in the SourceCode's Allocator, which is kept alive by
+        // the module. The Program is not moved or dropped during this call.
+        let code = unsafe {
+            core::mem::transmute::<&Program, &'static Program<'static>>(
+                &agent[module].ecmascript_code,
+            )
+        };
+        // 19. Let varDeclarations be the VarScopedDeclarations of code.
+        let var_declarations = module_var_scoped_declarations(code);
+        // 20. Let declaredVarNames be a new empty List.
+        let mut declared_var_names = AHashSet::default();
+        // 21. For each element d of varDeclarations, do
+        for d in var_declarations {
+            // a. For each element dn of the BoundNames of d, do
+            let mut bound_names = vec![];
+            let mut closure = |identifier: &BindingIdentifier<'static>| {
+                bound_names.push(identifier.name);
+            };
+            match d {
+                VarScopedDeclaration::Variable(decl) => decl.id.bound_names(&mut closure),
+                VarScopedDeclaration::Function(decl) => decl.bound_names(&mut closure),
+            }
+            for dn in bound_names {
+                // i. If declaredVarNames does not contain dn, then
+                if declared_var_names.insert(dn) {
+                    let dn = String::from_str(agent, dn.as_str(), gc);
+                    // 1. Perform ! env.CreateMutableBinding(dn, false).
+                    env.create_mutable_binding(agent, dn, false);
+                    // 2. Perform ! env.InitializeBinding(dn, undefined).
+                    env.initialize_binding(agent, dn, Value::Undefined);
+                    // 3. Append dn to declaredVarNames.
+                }
+            }
+        }
+        // 22. Let lexDeclarations be the LexicallyScopedDeclarations of code.
+        let lex_declarations = module_lexically_scoped_declarations(code);
+        // 23. Let privateEnv be null.
+        let private_env = None;
+        // 24. For each element d of lexDeclarations, do
+        for d in lex_declarations {
+            // a. For each element dn of the BoundNames of d, do
+            match d {
+                LexicallyScopedDeclaration::Variable(decl) => {
+                    let is_const = decl.kind.is_const();
+                    let mut bound_names = vec![];
+                    decl.id.bound_names(&mut |identifier| {
+                        bound_names.push(identifier.name);
+                    });
+                    for dn in bound_names {
+                        let dn = String::from_str(agent, dn.as_str(), gc);
+                        if is_const {
+                            // i. If IsConstantDeclaration of d is true, then
+                            // 1. Perform ! env.CreateImmutableBinding(dn, true).
+                            env.create_immutable_binding(agent, dn, true);
+                        } else {
+                            // ii. Else,
+                            // 1. Perform ! env.CreateMutableBinding(dn, false).
+                            env.create_mutable_binding(agent, dn, false);
+                        }
+                    }
+                }
+                LexicallyScopedDeclaration::Function(decl) => {
+                    // NOTE: An anonymous default exported function has the
+                    // bound name "*default*".
+                    let dn = match &decl.id {
+                        Some(id) => String::from_str(agent, id.name.as_str(), gc),
+                        None => BUILTIN_STRING_MEMORY._default_,
+                    };
+                    // ii. Else,
+                    // 1. Perform ! env.CreateMutableBinding(dn, false).
+                    env.create_mutable_binding(agent, dn, false);
+                    // iii. If d is either a FunctionDeclaration, a
+                    //      GeneratorDeclaration, an
+                    //      AsyncFunctionDeclaration, or an
+                    //      AsyncGeneratorDeclaration, then
+                    // 1. Let fo be InstantiateFunctionObject of d with
+                    //    arguments env and privateEnv.
+                    let fo = instantiate_function_object(
+                        agent,
+                        decl,
+                        Environment::Module(env),
+                        private_env,
+                        gc,
+                    );
+                    // 2. Perform ! env.InitializeBinding(dn, fo).
+                    env.initialize_binding(agent, dn, fo.into_value());
+                }
+                LexicallyScopedDeclaration::Class(decl) => {
+                    // NOTE: An anonymous default exported class has the bound
+                    // name "*default*".
+                    let dn = match &decl.id {
+                        Some(id) => String::from_str(agent, id.name.as_str(), gc),
+                        None => BUILTIN_STRING_MEMORY._default_,
+                    };
+                    // ii. Else,
+                    // 1. Perform ! env.CreateMutableBinding(dn, false).
+                    env.create_mutable_binding(agent, dn, false);
+                }
+                LexicallyScopedDeclaration::DefaultExport => {
+                    // ii. Else,
+                    // 1. Perform ! env.CreateMutableBinding(dn, false).
+                    env.create_mutable_binding(agent, BUILTIN_STRING_MEMORY._default_, false);
+                }
+            }
+        }
+        // 25. Remove moduleContext from the execution context stack.
+        agent.pop_execution_context();
+        // 26. Return UNUSED.
+        Ok(())
+    }
+
+    /// Creates the module's execution context. The specification stores the
+    /// context in the module's \[\[Context]] field during
+    /// InitializeEnvironment; Nova recreates it on demand as it contains no
+    /// module-specific mutable state.
+    fn create_module_context(self, agent: &Agent) -> ExecutionContext {
+        let record = &agent[self];
+        let env = Environment::Module(record.abstract_record.environment.unwrap());
+        ExecutionContext {
+            // Set the Function of moduleContext to null.
+            function: None,
+            // Set the Realm of moduleContext to module.[[Realm]].
+            realm: record.abstract_record.realm,
+            // Set the ScriptOrModule of moduleContext to module.
+            script_or_module: Some(ScriptOrModule::SourceTextModule(self.unbind())),
+            ecmascript_code: Some(ECMAScriptCode {
+                // Set the VariableEnvironment of moduleContext to
+                // module.[[Environment]].
+                variable_environment: env,
+                // Set the LexicalEnvironment of moduleContext to
+                // module.[[Environment]].
+                lexical_environment: env,
+                // Set the PrivateEnvironment of moduleContext to null.
+                private_environment: None,
+                // Module code is always strict mode code.
+                is_strict_mode: true,
+                source_code: record.source_code,
+            }),
+        }
+    }
+
+    /// ### [16.2.1.7.3.2 ExecuteModule ( \[ capability \] )](https://tc39.es/ecma262/#sec-source-text-module-record-execute-module)
+    ///
+    /// The ExecuteModule concrete method of a Source Text Module Record module
+    /// takes optional argument capability (a PromiseCapability Record) and
+    /// returns either a normal completion containing UNUSED or a throw
+    /// completion.
+    pub(crate) fn execute_module<'a>(
+        self,
+        agent: &mut Agent,
+        capability: Option<PromiseCapability>,
+        mut gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let module = self.bind(gc.nogc());
+        let capability = capability.bind(gc.nogc());
+        // 1. Let moduleContext be a new ECMAScript code execution context.
+        // 2. Set the Function of moduleContext to null.
+        // 3. Set the Realm of moduleContext to module.[[Realm]].
+        // 4. Set the ScriptOrModule of moduleContext to module.
+        // 5. Assert: module has been linked and declarations in its module
+        //    environment have been instantiated.
+        // 6. Set the VariableEnvironment of moduleContext to
+        //    module.[[Environment]].
+        // 7. Set the LexicalEnvironment of moduleContext to
+        //    module.[[Environment]].
+        let module_context = module.create_module_context(agent);
+        // 8. Suspend the running execution context.
+        let bytecode = agent[module]
+            .compiled_bytecode
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // 9. If module.[[HasTLA]] is false, then
+        let Some(capability) = capability else {
+            // a. Assert: capability is not present.
+            debug_assert!(!agent[module].cyclic_record.has_top_level_await);
+            // b. Push moduleContext onto the execution context stack;
+            //    moduleContext is now the running execution context.
+            agent.push_execution_context(module_context);
+            // c. Let result be Completion(Evaluation of
+            //    module.[[ECMAScriptCode]]).
+            let result = Vm::execute(agent, bytecode, None, gc.reborrow())
+                .into_js_result()
+                .unbind()
+                .bind(gc.into_nogc());
+            // d. Suspend moduleContext and remove it from the execution
+            //    context stack.
+            // e. Resume the context that is now on the top of the execution
+            //    context stack as the running execution context.
+            agent.pop_execution_context();
+            // f. If result is an abrupt completion, then
+            // i. Return ? result.
+            result?;
+            // 11. Return UNUSED.
+            return Ok(());
+        };
+        // 10. Else,
+        // a. Assert: capability is a PromiseCapability Record.
+        debug_assert!(agent[module].cyclic_record.has_top_level_await);
+        let promise = capability.promise().scope(agent, gc.nogc());
+        let must_be_unresolved = capability.must_be_unresolved;
+        // b. Perform AsyncBlockStart(capability, module.[[ECMAScriptCode]],
+        //    moduleContext).
+        agent.push_execution_context(module_context);
+        match Vm::execute(agent, bytecode, None, gc.reborrow()) {
+            ExecutionResult::Return(_) => {
+                // [27.7.5.2 AsyncBlockStart ( promiseCapability, asyncBody, asyncContext )](https://tc39.es/ecma262/#sec-asyncblockstart)
+                // 2. d. Remove acAsyncContext from the execution context stack
+                //       and restore the execution context that is at the top of
+                //       the execution context stack as the running execution
+                //       context.
+                agent.pop_execution_context();
+                let promise = promise.get(agent).bind(gc.nogc());
+                let capability = PromiseCapability::from_promise(promise, must_be_unresolved);
+                // e. If result is a normal completion, then
+                // i. Perform ! Call(promiseCapability.[[Resolve]], undefined,
+                //    « undefined »).
+                capability
+                    .unbind()
+                    .resolve(agent, Value::Undefined, gc.reborrow());
+            }
+            ExecutionResult::Throw(err) => {
+                let err = err.unbind().bind(gc.nogc());
+                // 2. d. Remove acAsyncContext from the execution context stack
+                //       and restore the execution context that is at the top of
+                //       the execution context stack as the running execution
+                //       context.
+                agent.pop_execution_context();
+                let promise = promise.get(agent).bind(gc.nogc());
+                let capability = PromiseCapability::from_promise(promise, must_be_unresolved);
+                // g. i. Assert: result is a throw completion.
+                //    ii. Perform ! Call(promiseCapability.[[Reject]],
+                //        undefined, « result.[[Value]] »).
+                capability.reject(agent, err.value(), gc.nogc());
+            }
+            ExecutionResult::Await { vm, awaited_value } => {
+                // [27.7.5.3 Await ( value )](https://tc39.es/ecma262/#await)
+                // 2. Let promise be ? PromiseResolve(%Promise%, value).
+                let resolve_promise =
+                    Promise::resolve(agent, awaited_value.unbind(), gc.reborrow())
+                        .unbind()
+                        .bind(gc.nogc());
+                // 8. Remove asyncContext from the execution context stack and
+                //    restore the execution context that is at the top of the
+                //    execution context stack as the running execution context.
+                let execution_context = agent.pop_execution_context().unwrap();
+                let promise = promise.get(agent).bind(gc.nogc());
+                let capability = PromiseCapability::from_promise(promise, must_be_unresolved);
+                let module =
+                    execution_context.script_or_module.map(
+                        |script_or_module| match script_or_module {
+                            ScriptOrModule::SourceTextModule(module) => module,
+                            ScriptOrModule::Script(_) => unreachable!(),
+                        },
+                    );
+                // `handler` corresponds to the `fulfilledClosure` and
+                // `rejectedClosure` functions, which resume execution of the
+                // module.
+                let handler = PromiseReactionHandler::Await(agent.heap.create(AwaitReaction {
+                    vm: Some(vm),
+                    async_function: None,
+                    async_module: module,
+                    execution_context: Some(execution_context),
+                    return_promise_capability: capability.unbind(),
+                }));
+                // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+                inner_promise_then(
+                    agent,
+                    resolve_promise.unbind(),
+                    handler,
+                    handler,
+                    None,
+                    gc.nogc(),
+                );
+            }
+            ExecutionResult::Yield { .. } => unreachable!(),
+        }
+        // 11. Return UNUSED.
+        Ok(())
+    }
+}
+
+/// Throws the SyntaxError for an import or indirect export whose name
+/// resolved to null or AMBIGUOUS.
+fn throw_unresolvable_export<'a>(
+    agent: &mut Agent,
+    name: String,
+    resolution: Option<ResolveExportResult>,
+    gc: NoGcScope<'a, '_>,
+) -> crate::ecmascript::execution::agent::JsError<'a> {
+    let error_message = if matches!(resolution, Some(ResolveExportResult::Ambiguous)) {
+        format!("Ambiguous export '{}'.", name.as_str(agent))
+    } else {
+        format!("Could not resolve export '{}'.", name.as_str(agent))
+    };
+    agent.throw_exception(ExceptionType::SyntaxError, error_message, gc)
+}
+
+impl Index<SourceTextModule<'_>> for Agent {
+    type Output = SourceTextModuleRecord<'static>;
+
+    fn index(&self, index: SourceTextModule) -> &Self::Output {
+        &self.heap.source_text_modules[index]
+    }
+}
+
+impl IndexMut<SourceTextModule<'_>> for Agent {
+    fn index_mut(&mut self, index: SourceTextModule) -> &mut Self::Output {
+        &mut self.heap.source_text_modules[index]
+    }
+}
+
+impl Index<SourceTextModule<'_>> for Vec<Option<SourceTextModuleRecord<'static>>> {
+    type Output = SourceTextModuleRecord<'static>;
+
+    fn index(&self, index: SourceTextModule) -> &Self::Output {
+        self.get(index.into_index())
+            .expect("SourceTextModuleIdentifier out of bounds")
+            .as_ref()
+            .expect("SourceTextModuleIdentifier slot empty")
+    }
+}
+
+impl IndexMut<SourceTextModule<'_>> for Vec<Option<SourceTextModuleRecord<'static>>> {
+    fn index_mut(&mut self, index: SourceTextModule) -> &mut Self::Output {
+        self.get_mut(index.into_index())
+            .expect("SourceTextModuleIdentifier out of bounds")
+            .as_mut()
+            .expect("SourceTextModuleIdentifier slot empty")
+    }
+}
+
+impl HeapMarkAndSweep for SourceTextModule<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.source_text_modules.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let self_index = self.into_u32();
+        *self = Self::from_u32(
+            self_index
+                - compactions
+                    .source_text_modules
+                    .get_shift_for_index(self_index),
+        );
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for SourceTextModule<'_> {
+    type Of<'a> = SourceTextModule<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl Rootable for SourceTextModule<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::SourceTextModule(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::SourceTextModule(module) => Some(module),
+            _ => None,
+        }
+    }
+}
+
+/// ### \[\[ImportName]]
+///
+/// The name under which the desired binding is exported by the module
+/// identified by \[\[ModuleRequest]]. The value NAMESPACE-OBJECT indicates
+/// that the import request is for the target module's namespace object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ImportName<'a> {
+    Name(String<'a>),
+    NamespaceObject,
+}
+
+/// ### [ImportEntry Record](https://tc39.es/ecma262/#importentry-record)
+#[derive(Debug, Clone)]
+pub(crate) struct ImportEntry<'a> {
+    /// ### \[\[ModuleRequest]]
+    ///
+    /// ModuleRequest Record representing the ModuleSpecifier and import
+    /// attributes of the ImportDeclaration.
+    pub(crate) module_request: ModuleRequest<'a>,
+    /// ### \[\[ImportName]]
+    pub(crate) import_name: ImportName<'a>,
+    /// ### \[\[LocalName]]
+    ///
+    /// The name that is used to locally access the imported value from
+    /// within the importing module.
+    pub(crate) local_name: String<'a>,
+}
+
+/// ### \[\[ImportName]]
+///
+/// The name under which the desired binding is exported by the module
+/// identified by \[\[ModuleRequest]]. ALL and ALL-BUT-DEFAULT indicate that
+/// the export request is for all exported bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExportImportName<'a> {
+    Name(String<'a>),
+    All,
+    AllButDefault,
+}
+
+/// ### [ExportEntry Record](https://tc39.es/ecma262/#exportentry-record)
+#[derive(Debug, Clone)]
+pub(crate) struct ExportEntry<'a> {
+    /// ### \[\[ExportName]]
+    ///
+    /// The name used to export this binding by this module. None if the
+    /// export is an `export * from` declaration.
+    pub(crate) export_name: Option<String<'a>>,
+    /// ### \[\[ModuleRequest]]
+    ///
+    /// ModuleRequest Record representing the ModuleSpecifier and import
+    /// attributes of the ExportDeclaration. None if the ExportDeclaration
+    /// does not have a ModuleSpecifier.
+    pub(crate) module_request: Option<ModuleRequest<'a>>,
+    /// ### \[\[ImportName]]
+    ///
+    /// None if the ExportDeclaration does not have a ModuleSpecifier.
+    pub(crate) import_name: Option<ExportImportName<'a>>,
+    /// ### \[\[LocalName]]
+    ///
+    /// The name that is used to locally access the exported value from within
+    /// the importing module. None if the exported value is not locally
+    /// accessible from within the module.
+    pub(crate) local_name: Option<String<'a>>,
+}
+
+/// ### [Source Text Module Record](https://tc39.es/ecma262/#table-additional-fields-of-source-text-module-records)
+#[derive(Debug)]
+pub struct SourceTextModuleRecord<'a> {
+    /// Fields shared by all Module Records.
+    pub(crate) abstract_record: AbstractModuleRecord<'a>,
+    /// Fields shared by all Cyclic Module Records.
+    pub(crate) cyclic_record: CyclicModuleRecord<'a>,
+    /// ### \[\[ECMAScriptCode]]
+    ///
+    /// The result of parsing the source text of this module using Module as
+    /// the goal symbol.
+    ///
+    /// Note: The Program's drop code is never run. The referred structures
+    /// live in the SourceCode heap data in its contained Allocator. The bump
+    /// allocator drops all of the data in a single go. All that needs to be
+    /// dropped here is the local Program itself, not any of its referred
+    /// parts.
+    pub(crate) ecmascript_code: ManuallyDrop<Program<'static>>,
+    /// ### \[\[ImportMeta]]
+    ///
+    /// An object exposed through the `import.meta` meta property. It is
+    /// EMPTY until it is accessed by ECMAScript code.
+    pub(crate) import_meta: Option<OrdinaryObject<'a>>,
+    /// Source text of the module
+    ///
+    /// The source text is kept in the heap strings vector, through the
+    /// SourceCode struct.
+    pub(crate) source_code: SourceCode<'a>,
+    /// Bytecode of the module body. The module is compiled during parsing to
+    /// determine if it contains top-level await.
+    pub(crate) compiled_bytecode: Option<Executable<'a>>,
+    /// ### \[\[ImportEntries]]
+    ///
+    /// A List of ImportEntry records derived from the code of this module.
+    pub(crate) import_entries: Box<[ImportEntry<'a>]>,
+    /// ### \[\[LocalExportEntries]]
+    ///
+    /// A List of ExportEntry records derived from the code of this module
+    /// that correspond to declarations that occur within the module.
+    pub(crate) local_export_entries: Box<[ExportEntry<'a>]>,
+    /// ### \[\[IndirectExportEntries]]
+    ///
+    /// A List of ExportEntry records derived from the code of this module
+    /// that correspond to reexported imports that occur within the module or
+    /// exports from `export * as namespace` declarations.
+    pub(crate) indirect_export_entries: Box<[ExportEntry<'a>]>,
+    /// ### \[\[StarExportEntries]]
+    ///
+    /// A List of ExportEntry records derived from the code of this module
+    /// that correspond to `export *` declarations that occur within the
+    /// module, not including `export * as namespace` declarations.
+    pub(crate) star_export_entries: Box<[ExportEntry<'a>]>,
+}
+
+unsafe impl Send for SourceTextModuleRecord<'_> {}
+
+pub type ModuleOrErrors<'a> = Result<SourceTextModule<'a>, Vec<OxcDiagnostic>>;
+
+impl<'a> CreateHeapData<SourceTextModuleRecord<'a>, SourceTextModule<'a>> for crate::heap::Heap {
+    fn create(&mut self, data: SourceTextModuleRecord<'a>) -> SourceTextModule<'a> {
+        self.source_text_modules.push(Some(data.unbind()));
+        self.alloc_counter += core::mem::size_of::<Option<SourceTextModuleRecord<'static>>>();
+        SourceTextModule::last(&self.source_text_modules)
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for SourceTextModuleRecord<'_> {
+    type Of<'a> = SourceTextModuleRecord<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ImportEntry<'_> {
+    type Of<'a> = ImportEntry<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for ExportEntry<'_> {
+    type Of<'a> = ExportEntry<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for ImportEntry<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            module_request,
+            import_name,
+            local_name,
+        } = self;
+        module_request.mark_values(queues);
+        if let ImportName::Name(import_name) = import_name {
+            import_name.mark_values(queues);
+        }
+        local_name.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            module_request,
+            import_name,
+            local_name,
+        } = self;
+        module_request.sweep_values(compactions);
+        if let ImportName::Name(import_name) = import_name {
+            import_name.sweep_values(compactions);
+        }
+        local_name.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for ExportEntry<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            export_name,
+            module_request,
+            import_name,
+            local_name,
+        } = self;
+        export_name.mark_values(queues);
+        module_request.mark_values(queues);
+        if let Some(ExportImportName::Name(import_name)) = import_name {
+            import_name.mark_values(queues);
+        }
+        local_name.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            export_name,
+            module_request,
+            import_name,
+            local_name,
+        } = self;
+        export_name.sweep_values(compactions);
+        module_request.sweep_values(compactions);
+        if let Some(ExportImportName::Name(import_name)) = import_name {
+            import_name.sweep_values(compactions);
+        }
+        local_name.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for SourceTextModuleRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            abstract_record,
+            cyclic_record,
+            ecmascript_code: _,
+            import_meta,
+            source_code,
+            compiled_bytecode,
+            import_entries,
+            local_export_entries,
+            indirect_export_entries,
+            star_export_entries,
+        } = self;
+        abstract_record.mark_values(queues);
+        cyclic_record.mark_values(queues);
+        import_meta.mark_values(queues);
+        source_code.mark_values(queues);
+        compiled_bytecode.mark_values(queues);
+        for entry in import_entries.iter() {
+            entry.mark_values(queues);
+        }
+        for entry in local_export_entries
+            .iter()
+            .chain(indirect_export_entries.iter())
+            .chain(star_export_entries.iter())
+        {
+            entry.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            abstract_record,
+            cyclic_record,
+            ecmascript_code: _,
+            import_meta,
+            source_code,
+            compiled_bytecode,
+            import_entries,
+            local_export_entries,
+            indirect_export_entries,
+            star_export_entries,
+        } = self;
+        abstract_record.sweep_values(compactions);
+        cyclic_record.sweep_values(compactions);
+        import_meta.sweep_values(compactions);
+        source_code.sweep_values(compactions);
+        compiled_bytecode.sweep_values(compactions);
+        for entry in import_entries.iter_mut() {
+            entry.sweep_values(compactions);
+        }
+        for entry in local_export_entries
+            .iter_mut()
+            .chain(indirect_export_entries.iter_mut())
+            .chain(star_export_entries.iter_mut())
+        {
+            entry.sweep_values(compactions);
+        }
+    }
+}
+
+/// ### [16.2.1.7.1 ParseModule ( sourceText, realm, hostDefined )](https://tc39.es/ecma262/#sec-parsemodule)
+///
+/// The abstract operation ParseModule takes arguments sourceText (ECMAScript
+/// source text), realm (a Realm Record), and hostDefined (anything) and
+/// returns a Source Text Module Record or a non-empty List of SyntaxError
+/// objects. It creates a Source Text Module Record based upon the result of
+/// parsing sourceText as a Module.
+pub fn parse_module<'a>(
+    agent: &mut Agent,
+    source_text: String,
+    realm: Realm,
+    host_defined: Option<HostDefined>,
+    gc: NoGcScope<'a, '_>,
+) -> ModuleOrErrors<'a> {
+    // 1. Let body be ParseText(sourceText, Module).
+    let mut source_type = SourceType::default().with_module(true);
+    if cfg!(feature = "typescript") {
+        source_type = source_type.with_typescript(true);
+    }
+
+    // SAFETY: SourceTextModule keeps the SourceCode reference alive in the
+    // Heap, thus making the Program's references point to a live Allocator.
+    let parse_result = unsafe { SourceCode::parse_source(agent, source_text, source_type, gc) };
+
+    let (body, source_code) = match parse_result {
+        // 2. If body is a List of errors, return body.
+        Ok(result) => result,
+        Err(errors) => {
+            return Err(errors);
+        }
+    };
+
+    // 3. Let requestedModules be the ModuleRequests of body.
+    let mut requested_modules: Vec<ModuleRequest> = vec![];
+    let mut add_request = |agent: &mut Agent, specifier: &str, with_clause: Option<&WithClause>| {
+        let specifier = String::from_str(agent, specifier, gc);
+        // WithClauseToAttributes
+        let mut attributes = with_clause.map_or(vec![], |with_clause| {
+            with_clause
+                .with_entries
+                .iter()
+                .map(|entry| ImportAttribute {
+                    key: String::from_str(agent, entry.key.as_atom().as_str(), gc),
+                    value: String::from_str(agent, entry.value.value.as_str(), gc),
+                })
+                .collect()
+        });
+        // Sort attributes according to the lexicographic order of their
+        // [[Key]] field, treating the value of each such field as a sequence
+        // of UTF-16 code unit values.
+        attributes.sort_by(|a, b| {
+            a.key
+                .as_str(agent)
+                .encode_utf16()
+                .cmp(b.key.as_str(agent).encode_utf16())
+        });
+        let request = ModuleRequest::new(specifier, attributes.into_boxed_slice());
+        if !requested_modules.contains(&request) {
+            requested_modules.push(request.clone());
+        }
+        request
+    };
+    // 4. Let importEntries be the ImportEntries of body.
+    let mut import_entries: Vec<ImportEntry> = vec![];
+    let mut export_entries: Vec<ExportEntry> = vec![];
+    for statement in body.body.iter() {
+        match statement {
+            Statement::ImportDeclaration(decl) => {
+                let module_request = add_request(
+                    agent,
+                    decl.source.value.as_str(),
+                    decl.with_clause.as_deref(),
+                );
+                let Some(specifiers) = &decl.specifiers else {
+                    continue;
+                };
+                for specifier in specifiers.iter() {
+                    let (import_name, local_name) = match specifier {
+                        // ImportSpecifier : ModuleExportName as ImportedBinding
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => (
+                            ImportName::Name(String::from_str(
+                                agent,
+                                specifier.imported.name().as_str(),
+                                gc,
+                            )),
+                            specifier.local.name.as_str(),
+                        ),
+                        // ImportedDefaultBinding : ImportedBinding
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => (
+                            ImportName::Name(BUILTIN_STRING_MEMORY.default),
+                            specifier.local.name.as_str(),
+                        ),
+                        // NameSpaceImport : * as ImportedBinding
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                            (ImportName::NamespaceObject, specifier.local.name.as_str())
+                        }
+                    };
+                    import_entries.push(ImportEntry {
+                        module_request: module_request.clone(),
+                        import_name,
+                        local_name: String::from_str(agent, local_name, gc),
+                    });
+                }
+            }
+            Statement::ExportAllDeclaration(decl) => {
+                let module_request = add_request(
+                    agent,
+                    decl.source.value.as_str(),
+                    decl.with_clause.as_deref(),
+                );
+                export_entries.push(match &decl.exported {
+                    // ExportFromClause : * as ModuleExportName
+                    Some(exported) => ExportEntry {
+                        export_name: Some(String::from_str(agent, exported.name().as_str(), gc)),
+                        module_request: Some(module_request),
+                        import_name: Some(ExportImportName::All),
+                        local_name: None,
+                    },
+                    // ExportFromClause : *
+                    None => ExportEntry {
+                        export_name: None,
+                        module_request: Some(module_request),
+                        import_name: Some(ExportImportName::AllButDefault),
+                        local_name: None,
+                    },
+                });
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = &decl.declaration {
+                    // ExportDeclaration : export VariableStatement
+                    // ExportDeclaration : export Declaration
+                    let mut names = vec![];
+                    match declaration {
+                        Declaration::VariableDeclaration(decl) => decl.bound_names(&mut |id| {
+                            names.push(id.name);
+                        }),
+                        Declaration::FunctionDeclaration(decl) => decl.bound_names(&mut |id| {
+                            names.push(id.name);
+                        }),
+                        Declaration::ClassDeclaration(decl) => decl.bound_names(&mut |id| {
+                            names.push(id.name);
+                        }),
+                        // Note: TypeScript type declarations bind no values.
+                        _ => {}
+                    }
+                    for name in names {
+                        let name = String::from_str(agent, name.as_str(), gc);
+                        export_entries.push(ExportEntry {
+                            export_name: Some(name),
+                            module_request: None,
+                            import_name: None,
+                            local_name: Some(name),
+                        });
+                    }
+                    continue;
+                }
+                let module_request = decl.source.as_ref().map(|source| {
+                    add_request(agent, source.value.as_str(), decl.with_clause.as_deref())
+                });
+                for specifier in decl.specifiers.iter() {
+                    let local = String::from_str(agent, specifier.local.name().as_str(), gc);
+                    let exported = String::from_str(agent, specifier.exported.name().as_str(), gc);
+                    export_entries.push(if module_request.is_some() {
+                        // ExportSpecifier : ModuleExportName as ModuleExportName
+                        // (with a FromClause)
+                        ExportEntry {
+                            export_name: Some(exported),
+                            module_request: module_request.clone(),
+                            import_name: Some(ExportImportName::Name(local)),
+                            local_name: None,
+                        }
+                    } else {
+                        // ExportSpecifier : ModuleExportName as ModuleExportName
+                        ExportEntry {
+                            export_name: Some(exported),
+                            module_request: None,
+                            import_name: None,
+                            local_name: Some(local),
+                        }
+                    });
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                let local_name = match &decl.declaration {
+                    // ExportDeclaration : export default HoistableDeclaration
+                    ExportDefaultDeclarationKind::FunctionDeclaration(decl) => decl
+                        .id
+                        .as_ref()
+                        .map(|id| String::from_str(agent, id.name.as_str(), gc)),
+                    // ExportDeclaration : export default ClassDeclaration
+                    ExportDefaultDeclarationKind::ClassDeclaration(decl) => decl
+                        .id
+                        .as_ref()
+                        .map(|id| String::from_str(agent, id.name.as_str(), gc)),
+                    // ExportDeclaration : export default AssignmentExpression ;
+                    _ => None,
+                };
+                export_entries.push(ExportEntry {
+                    export_name: Some(BUILTIN_STRING_MEMORY.default),
+                    module_request: None,
+                    import_name: None,
+                    local_name: Some(local_name.unwrap_or(BUILTIN_STRING_MEMORY._default_)),
+                });
+            }
+            _ => {}
+        }
+    }
+    // 5. Let importedBoundNames be ImportedLocalNames(importEntries).
+    // 6. Let indirectExportEntries be a new empty List.
+    let mut indirect_export_entries = vec![];
+    // 7. Let localExportEntries be a new empty List.
+    let mut local_export_entries = vec![];
+    // 8. Let starExportEntries be a new empty List.
+    let mut star_export_entries = vec![];
+    // 9. Let exportEntries be the ExportEntries of body.
+    // 10. For each ExportEntry Record ee of exportEntries, do
+    for ee in export_entries {
+        // a. If ee.[[ModuleRequest]] is null, then
+        if ee.module_request.is_none() {
+            // i. If importedBoundNames does not contain ee.[[LocalName]], then
+            let ie = import_entries
+                .iter()
+                .find(|ie| Some(ie.local_name) == ee.local_name);
+            match ie {
+                None => {
+                    // 1. Append ee to localExportEntries.
+                    local_export_entries.push(ee);
+                }
+                // ii. Else,
+                // 1. Let ie be the element of importEntries whose
+                //    [[LocalName]] is ee.[[LocalName]].
+                // 2. If ie.[[ImportName]] is NAMESPACE-OBJECT, then
+                Some(ImportEntry {
+                    import_name: ImportName::NamespaceObject,
+                    ..
+                }) => {
+                    // a. NOTE: This is a re-export of an imported module
+                    //    namespace object.
+                    // b. Append ee to localExportEntries.
+                    local_export_entries.push(ee);
+                }
+                // 3. Else,
+                Some(ImportEntry {
+                    module_request,
+                    import_name: ImportName::Name(import_name),
+                    ..
+                }) => {
+                    // a. NOTE: This is a re-export of a single name.
+                    // b. Append the ExportEntry Record { [[ModuleRequest]]:
+                    //    ie.[[ModuleRequest]], [[ImportName]]:
+                    //    ie.[[ImportName]], [[LocalName]]: null,
+                    //    [[ExportName]]: ee.[[ExportName]] } to
+                    //    indirectExportEntries.
+                    indirect_export_entries.push(ExportEntry {
+                        export_name: ee.export_name,
+                        module_request: Some(module_request.clone()),
+                        import_name: Some(ExportImportName::Name(*import_name)),
+                        local_name: None,
+                    });
+                }
+            }
+        } else if ee.import_name == Some(ExportImportName::AllButDefault) {
+            // b. Else if ee.[[ImportName]] is ALL-BUT-DEFAULT, then
+            // i. Assert: ee.[[ExportName]] is null.
+            debug_assert!(ee.export_name.is_none());
+            // ii. Append ee to starExportEntries.
+            star_export_entries.push(ee);
+        } else {
+            // c. Else,
+            // i. Append ee to indirectExportEntries.
+            indirect_export_entries.push(ee);
+        }
+    }
+    // 11. Let async be body Contains await.
+    // NOTE: This is determined below after compiling the module body.
+    // 12. Return Source Text Module Record {
+    let module_record = SourceTextModuleRecord {
+        // [[Realm]]: realm, [[Environment]]: EMPTY, [[Namespace]]: EMPTY,
+        // [[HostDefined]]: hostDefined,
+        abstract_record: AbstractModuleRecord::new(realm.unbind(), host_defined),
+        // [[CycleRoot]]: EMPTY, [[HasTLA]]: async, [[AsyncEvaluationOrder]]:
+        // UNSET, [[TopLevelCapability]]: EMPTY, [[AsyncParentModules]]: « »,
+        // [[PendingAsyncDependencies]]: EMPTY, [[Status]]: NEW,
+        // [[EvaluationError]]: EMPTY, [[RequestedModules]]: requestedModules,
+        // [[LoadedModules]]: « »,
+        cyclic_record: CyclicModuleRecord::new(
+            requested_modules.into_iter().map(|r| r.unbind()).collect(),
+            false,
+        ),
+        // [[ECMAScriptCode]]: body,
+        ecmascript_code: ManuallyDrop::new(body),
+        // [[ImportMeta]]: EMPTY,
+        import_meta: None,
+        source_code: source_code.unbind(),
+        compiled_bytecode: None,
+        // [[ImportEntries]]: importEntries,
+        import_entries: import_entries.into_iter().map(|e| e.unbind()).collect(),
+        // [[LocalExportEntries]]: localExportEntries,
+        local_export_entries: local_export_entries
+            .into_iter()
+            .map(|e| e.unbind())
+            .collect(),
+        // [[IndirectExportEntries]]: indirectExportEntries,
+        indirect_export_entries: indirect_export_entries
+            .into_iter()
+            .map(|e| e.unbind())
+            .collect(),
+        // [[StarExportEntries]]: starExportEntries,
+        star_export_entries: star_export_entries
+            .into_iter()
+            .map(|e| e.unbind())
+            .collect(),
+    };
+    // }.
+    let module = agent.heap.create(module_record);
+    let bytecode = Executable::compile_module(agent, module, gc);
+    // NOTE: Function bodies, arrow functions and class initializers are
+    // compiled separately, so any Await instruction in the module's own
+    // bytecode must come from a top-level await.
+    let has_top_level_await = InstructionIter::new(&agent[bytecode].instructions)
+        .any(|(_, instr)| instr.kind == Instruction::Await);
+    let record = &mut agent[module];
+    record.compiled_bytecode = Some(bytecode.unbind());
+    record.cyclic_record.has_top_level_await = has_top_level_await;
+    Ok(module)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ecmascript::{
+            builtins::promise::{Promise, data::PromiseState},
+            execution::{
+                Agent, JsResult,
+                agent::{ExceptionType, HostHooks, Job, Options},
+                initialize_default_realm,
+            },
+            scripts_and_modules::module::module_semantics::{
+                ImportAttribute, ModuleLoadingPayload, ModuleRequest, Referrer,
+                abstract_module_records::AbstractModule, finish_loading_imported_module,
+                synthetic_module_records::create_synthetic_module,
+            },
+            types::{IntoValue, PropertyKey, String, Value},
+        },
+        engine::{
+            Global,
+            context::{Bindable, GcScope, NoGcScope},
+        },
+    };
+    use core::{any::Any, cell::RefCell};
+    use std::collections::VecDeque;
+
+    #[cfg(feature = "json")]
+    use crate::ecmascript::scripts_and_modules::module::module_semantics::synthetic_module_records::parse_json_module;
+
+    use super::{SourceTextModule, parse_module};
+
+    /// A module load that the host has not finished yet.
+    #[derive(Debug)]
+    struct PendingLoad {
+        referrer: Global<Referrer<'static>>,
+        specifier: Global<String<'static>>,
+        attributes: Vec<(Global<String<'static>>, Global<String<'static>>)>,
+        payload: Global<ModuleLoadingPayload<'static>>,
+    }
+
+    /// Host hooks that load modules from a static list of module names and
+    /// source texts. The module "native" is a synthetic module exporting
+    /// `answer`. Each listed module is parsed only once, so that repeated
+    /// imports of the same specifier resolve to the same Module Record.
+    ///
+    /// If `defer_loads` is true, loads are only finished by
+    /// [finish_pending_loads].
+    #[derive(Debug)]
+    struct TestHostHooks {
+        modules: &'static [(&'static str, &'static str)],
+        module_map: RefCell<Vec<(&'static str, Global<AbstractModule<'static>>)>>,
+        promise_jobs: RefCell<VecDeque<Job>>,
+        defer_loads: bool,
+        pending_loads: RefCell<Vec<PendingLoad>>,
+    }
+
+    impl HostHooks for TestHostHooks {
+        fn enqueue_promise_job(&self, job: Job) {
+            self.promise_jobs.borrow_mut().push_back(job);
+        }
+
+        fn load_imported_module<'gc>(
+            &self,
+            agent: &mut Agent,
+            referrer: Referrer<'gc>,
+            module_request: &ModuleRequest<'gc>,
+            payload: ModuleLoadingPayload<'gc>,
+            gc: NoGcScope<'gc, '_>,
+        ) {
+            if self.defer_loads {
+                let attributes = module_request
+                    .attributes()
+                    .iter()
+                    .map(|attribute| {
+                        (
+                            Global::new(agent, attribute.key().unbind()),
+                            Global::new(agent, attribute.value().unbind()),
+                        )
+                    })
+                    .collect();
+                let pending_load = PendingLoad {
+                    referrer: Global::new(agent, referrer.unbind()),
+                    specifier: Global::new(agent, module_request.specifier().unbind()),
+                    attributes,
+                    payload: Global::new(agent, payload.unbind()),
+                };
+                self.pending_loads.borrow_mut().push(pending_load);
+                return;
+            }
+            let result = self.load(agent, module_request, gc);
+            finish_loading_imported_module(agent, referrer, module_request, payload, result, gc);
+        }
+
+        fn get_supported_import_attributes(&self) -> &[&'static str] {
+            &["type"]
+        }
+
+        fn get_import_meta_properties<'gc>(
+            &self,
+            agent: &mut Agent,
+            _module: SourceTextModule<'gc>,
+            gc: NoGcScope<'gc, '_>,
+        ) -> Vec<(PropertyKey<'gc>, Value<'gc>)> {
+            let key = PropertyKey::from_static_str(agent, "url", gc);
+            let value = String::from_static_str(agent, "test:module", gc);
+            vec![(key, value.into_value())]
+        }
+
+        fn get_host_data(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    impl TestHostHooks {
+        fn load<'gc>(
+            &self,
+            agent: &mut Agent,
+            module_request: &ModuleRequest<'gc>,
+            gc: NoGcScope<'gc, '_>,
+        ) -> JsResult<'gc, AbstractModule<'gc>> {
+            let realm = agent.current_realm(gc);
+            let specifier = module_request.specifier().as_str(agent).to_owned();
+            if specifier == "native" {
+                let name = String::from_static_str(agent, "answer", gc);
+                return Ok(create_synthetic_module(
+                    agent,
+                    &[(name, Value::from(42))],
+                    realm,
+                    None,
+                    gc,
+                )
+                .into());
+            }
+            let Some((name, source)) = self.modules.iter().find(|(name, _)| *name == specifier)
+            else {
+                let error_message = format!("Cannot find module '{}'.", specifier);
+                return Err(agent.throw_exception(ExceptionType::TypeError, error_message, gc));
+            };
+            if let Some((_, module)) = self
+                .module_map
+                .borrow()
+                .iter()
+                .find(|(loaded, _)| loaded == name)
+            {
+                return Ok(module.get(agent, gc));
+            }
+            let source_text = String::from_static_str(agent, source, gc);
+            let is_json = module_request
+                .get_attribute(agent, "type")
+                .is_some_and(|value| value.as_str(agent) == "json");
+            let module: AbstractModule = if is_json {
+                #[cfg(feature = "json")]
+                {
+                    parse_json_module(agent, source_text, realm, None, gc)?.into()
+                }
+                #[cfg(not(feature = "json"))]
+                unreachable!()
+            } else {
+                parse_module(agent, source_text, realm, None, gc)
+                    .map_err(|errors| {
+                        agent.throw_exception(ExceptionType::SyntaxError, errors[0].to_string(), gc)
+                    })?
+                    .into()
+            };
+            let global = Global::new(agent, module.unbind());
+            self.module_map.borrow_mut().push((name, global));
+            Ok(module)
+        }
+    }
+
+    fn create_agent(modules: &'static [(&'static str, &'static str)]) -> Agent {
+        create_agent_with_deferred_loads(modules, false)
+    }
+
+    fn create_agent_with_deferred_loads(
+        modules: &'static [(&'static str, &'static str)],
+        defer_loads: bool,
+    ) -> Agent {
+        let host_hooks: &'static TestHostHooks = Box::leak(Box::new(TestHostHooks {
+            modules,
+            module_map: Default::default(),
+            promise_jobs: Default::default(),
+            defer_loads,
+            pending_loads: Default::default(),
+        }));
+        Agent::new(Options::default(), host_hooks)
+    }
+
+    fn get_host_hooks(agent: &Agent) -> &'static TestHostHooks {
+        agent
+            .host_hooks
+            .get_host_data()
+            .downcast_ref::<TestHostHooks>()
+            .unwrap()
+    }
+
+    /// Finishes the module loads deferred by the host, including any loads
+    /// that finishing them starts. Returns the number of finished loads.
+    fn finish_pending_loads(agent: &mut Agent, gc: NoGcScope) -> usize {
+        let host_hooks = get_host_hooks(agent);
+        let mut finished = 0;
+        loop {
+            let pending_load = host_hooks.pending_loads.borrow_mut().pop();
+            let Some(pending_load) = pending_load else {
+                break;
+            };
+            let PendingLoad {
+                referrer,
+                specifier,
+                attributes,
+                payload,
+            } = pending_load;
+            let referrer = referrer.take(agent).bind(gc);
+            let specifier = specifier.take(agent).bind(gc);
+            let attributes = attributes
+                .into_iter()
+                .map(|(key, value)| {
+                    ImportAttribute::new(key.take(agent).bind(gc), value.take(agent).bind(gc))
+                })
+                .collect();
+            let payload = payload.take(agent).bind(gc);
+            let module_request = ModuleRequest::new(specifier, attributes);
+            let result = host_hooks.load(agent, &module_request, gc);
+            finish_loading_imported_module(agent, referrer, &module_request, payload, result, gc);
+            finished += 1;
+        }
+        finished
+    }
+
+    fn run_jobs(agent: &mut Agent, mut gc: GcScope) {
+        let host_hooks = get_host_hooks(agent);
+        loop {
+            let job = host_hooks.promise_jobs.borrow_mut().pop_front();
+            let Some(job) = job else {
+                break;
+            };
+            job.run(agent, gc.reborrow()).unwrap();
+        }
+    }
+
+    fn get_result(agent: &mut Agent, mut gc: GcScope) -> Value<'static> {
+        let source_text = String::from_static_str(agent, "result", gc.nogc());
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap()
+            .unbind()
+    }
+
+    fn parse<'a>(
+        agent: &mut Agent,
+        source: &'static str,
+        gc: GcScope<'a, '_>,
+    ) -> SourceTextModule<'a> {
+        let gc = gc.into_nogc();
+        let realm = agent.current_realm(gc);
+        let source_text = String::from_static_str(agent, source, gc);
+        parse_module(agent, source_text, realm, None, gc).unwrap()
+    }
+
+    fn is_fulfilled(agent: &Agent, promise: Promise) -> bool {
+        matches!(agent[promise].promise_state, PromiseState::Fulfilled { .. })
+    }
+
+    fn is_rejected(agent: &Agent, promise: Promise) -> bool {
+        matches!(agent[promise].promise_state, PromiseState::Rejected { .. })
+    }
+
+    /// Loads, links and evaluates the module and returns the value of
+    /// `globalThis.result`.
+    fn run_module(agent: &mut Agent, module: SourceTextModule, mut gc: GcScope) -> Value<'static> {
+        let module = module.unbind();
+        let promise = module.load_requested_modules(agent, None, gc.nogc());
+        assert!(is_fulfilled(agent, promise));
+        module.link(agent, gc.nogc()).unwrap();
+        let promise = module.evaluate(agent, gc.reborrow()).unbind();
+        assert!(is_fulfilled(agent, promise));
+        get_result(agent, gc)
+    }
+
+    #[test]
+    fn empty_module() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(&mut agent, "", gc.reborrow()).unbind();
+        let promise = module.load_requested_modules(&mut agent, None, gc.nogc());
+        assert!(is_fulfilled(&agent, promise));
+        module.link(&mut agent, gc.nogc()).unwrap();
+        let promise = module.evaluate(&mut agent, gc.reborrow()).unbind();
+
+        assert!(is_fulfilled(&agent, promise));
+    }
+
+    #[test]
+    fn module_side_effects() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "export const a = 2; export function f() { return a + 1; } globalThis.result = f();",
+            gc.reborrow(),
+        )
+        .unbind();
+        let result = run_module(&mut agent, module, gc.reborrow());
+        assert_eq!(result, Value::from(3));
+    }
+
+    #[test]
+    fn module_imports() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[(
+            "./dep.js",
+            "export let count = 1; export default function () { count += 1; }",
+        )]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "import increment, { count } from \"./dep.js\"; import * as ns from \"./dep.js\"; increment(); globalThis.result = count + ns.count;",
+            gc.reborrow(),
+        )
+        .unbind();
+        let specifier = String::from_static_str(&mut agent, "./dep.js", gc.nogc());
+        assert_eq!(module.requested_modules(&agent, gc.nogc()), vec![specifier]);
+        let result = run_module(&mut agent, module, gc.reborrow());
+        assert_eq!(result, Value::from(4));
+    }
+
+    #[test]
+    fn unresolvable_import_fails_to_link() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[("./dep.js", "export const a = 1;")]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(&mut agent, "import { b } from \"./dep.js\";", gc.reborrow()).unbind();
+        let promise = module.load_requested_modules(&mut agent, None, gc.nogc());
+        assert!(is_fulfilled(&agent, promise));
+
+        assert!(module.link(&mut agent, gc.nogc()).is_err());
+    }
+
+    #[test]
+    fn missing_module_fails_to_load() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[("./a.js", "import \"./missing.js\";")]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(&mut agent, "import \"./a.js\";", gc.reborrow()).unbind();
+        let promise = module.load_requested_modules(&mut agent, None, gc.nogc());
+
+        assert!(is_rejected(&agent, promise));
+    }
+
+    #[test]
+    fn unsupported_import_attribute_fails_to_load() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[("./dep.js", "")]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "import \"./dep.js\" with { integrity: \"sha384-abc\" };",
+            gc.reborrow(),
+        )
+        .unbind();
+        let promise = module.load_requested_modules(&mut agent, None, gc.nogc());
+
+        assert!(is_rejected(&agent, promise));
+    }
+
+    #[test]
+    fn import_attributes_are_sorted_by_utf16_code_units() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        // U+FFFF sorts before U+10000 in UTF-8, but after it in UTF-16.
+        let module = parse(
+            &mut agent,
+            "import \"./dep.js\" with { \"\u{FFFF}\": \"a\", \"\u{10000}\": \"b\", type: \"c\" };",
+            gc.reborrow(),
+        )
+        .unbind();
+        let keys = agent[module].cyclic_record.requested_modules[0]
+            .attributes()
+            .iter()
+            .map(|attribute| attribute.key().as_str(&agent).to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["type", "\u{10000}", "\u{FFFF}"]);
+    }
+
+    #[test]
+    fn circular_imports() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[
+            (
+                "./a.js",
+                "import { b } from \"./b.js\"; export const a = 1; export function getB() { return b; }",
+            ),
+            (
+                "./b.js",
+                "import { a, getB } from \"./a.js\"; export const b = 2; globalThis.result = () => a + getB();",
+            ),
+        ]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "import \"./a.js\"; globalThis.result = globalThis.result();",
+            gc.reborrow(),
+        )
+        .unbind();
+        let result = run_module(&mut agent, module, gc.reborrow());
+        assert_eq!(result, Value::from(3));
+    }
+
+    /// Loads, links and evaluates the module, runs all queued jobs and returns
+    /// the promise returned by Evaluate.
+    fn evaluate_async_module<'a>(
+        agent: &mut Agent,
+        module: SourceTextModule,
+        mut gc: GcScope<'a, '_>,
+    ) -> Promise<'a> {
+        let module = module.unbind();
+        let promise = module.load_requested_modules(agent, None, gc.nogc());
+        assert!(is_fulfilled(agent, promise));
+        module.link(agent, gc.nogc()).unwrap();
+        let promise = module.evaluate(agent, gc.reborrow()).unbind();
+        assert!(!is_fulfilled(agent, promise) && !is_rejected(agent, promise));
+        let promise = Global::new(agent, promise);
+        run_jobs(agent, gc.reborrow());
+        promise.take(agent).bind(gc.into_nogc())
+    }
+
+    #[test]
+    fn top_level_await() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[(
+            "./dep.js",
+            "globalThis.result = [\"dep\"]; export const value = await Promise.resolve(2); result.push(\"dep awaited\");",
+        )]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "import { value } from \"./dep.js\"; result.push(\"main\"); result.push(value * await 21);",
+            gc.reborrow(),
+        )
+        .unbind();
+        let promise = evaluate_async_module(&mut agent, module, gc.reborrow()).unbind();
+        assert!(is_fulfilled(&agent, promise));
+        let source_text = String::from_static_str(&mut agent, "result.join()", gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap()
+            .unbind();
+        let expected = String::from_static_str(&mut agent, "dep,dep awaited,main,42", gc.nogc());
+        assert_eq!(result, expected.into_value());
+    }
+
+    #[test]
+    fn top_level_await_in_cycle() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[
+            (
+                "./a.js",
+                "import \"./b.js\"; result.push(\"a\"); await null; result.push(\"a awaited\");",
+            ),
+            (
+                "./b.js",
+                "import \"./a.js\"; import \"./c.js\"; result.push(\"b\");",
+            ),
+            (
+                "./c.js",
+                "globalThis.result = [\"c\"]; await null; result.push(\"c awaited\");",
+            ),
+        ]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "import \"./a.js\"; import \"./b.js\"; result.push(\"main\");",
+            gc.reborrow(),
+        )
+        .unbind();
+        let promise = evaluate_async_module(&mut agent, module, gc.reborrow()).unbind();
+        assert!(is_fulfilled(&agent, promise));
+        let source_text = String::from_static_str(&mut agent, "result.join()", gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap()
+            .unbind();
+        let expected =
+            String::from_static_str(&mut agent, "c,c awaited,b,a,a awaited,main", gc.nogc());
+        assert_eq!(result, expected.into_value());
+    }
+
+    #[test]
+    fn top_level_await_rejection_propagates_to_async_parents() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[
+            ("./a.js", "import \"./b.js\"; result.push(\"a\");"),
+            (
+                "./b.js",
+                "globalThis.result = [\"b\"]; await null; throw new RangeError(\"b failed\");",
+            ),
+            ("./c.js", "import \"./a.js\"; result.push(\"c\");"),
+        ]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "import \"./a.js\"; import \"./c.js\"; result.push(\"main\");",
+            gc.reborrow(),
+        )
+        .unbind();
+        let promise = evaluate_async_module(&mut agent, module, gc.reborrow()).unbind();
+        assert!(is_rejected(&agent, promise));
+        let PromiseState::Rejected {
+            promise_result: error,
+            ..
+        } = agent[promise].promise_state
+        else {
+            unreachable!()
+        };
+        let error = Global::new(&agent, error);
+        let source_text = String::from_static_str(&mut agent, "result.join()", gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap()
+            .unbind();
+        let expected = String::from_static_str(&mut agent, "b", gc.nogc());
+        assert_eq!(result, expected.into_value());
+        let error = error.take(&agent);
+        let message = error
+            .string_repr(&mut agent, gc.reborrow())
+            .as_str(&agent)
+            .to_owned();
+        assert_eq!(message, "RangeError: b failed");
+    }
+
+    #[test]
+    fn synthetic_module_import() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "import { answer } from \"native\"; import * as ns from \"native\"; globalThis.result = answer + ns.answer;",
+            gc.reborrow(),
+        )
+        .unbind();
+        let result = run_module(&mut agent, module, gc.reborrow());
+        assert_eq!(result, Value::from(84));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_module_import() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[("./data.json", "{ \"value\": [1, 2, 3] }")]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "import data from \"./data.json\" with { type: \"json\" }; globalThis.result = data.value[2];",
+            gc.reborrow(),
+        )
+        .unbind();
+        let result = run_module(&mut agent, module, gc.reborrow());
+        assert_eq!(result, Value::from(3));
+    }
+
+    #[test]
+    fn dynamic_import_from_module() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[("./dep.js", "export const value = 42;")]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "globalThis.result = 0; import(\"./dep.js\").then(ns => { globalThis.result = ns.value; });",
+            gc.reborrow(),
+        )
+        .unbind();
+        let result = run_module(&mut agent, module, gc.reborrow());
+        assert_eq!(result, Value::from(0));
+        run_jobs(&mut agent, gc.reborrow());
+        let result = get_result(&mut agent, gc.reborrow());
+        assert_eq!(result, Value::from(42));
+    }
+
+    #[test]
+    fn dynamic_import_from_script() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[("./dep.js", "export default function () { return 7; }")]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "import(\"./dep.js\", {}).then(ns => { globalThis.result = ns.default(); });",
+            gc.nogc(),
+        );
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        run_jobs(&mut agent, gc.reborrow());
+        let result = get_result(&mut agent, gc.reborrow());
+        assert_eq!(result, Value::from(7));
+    }
+
+    #[test]
+    fn dynamic_import_rejects() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[("./dep.js", "throw new RangeError();")]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "globalThis.result = []; \
+             const check = (ctor) => (e) => { globalThis.result.push(e instanceof ctor); }; \
+             import(\"./missing.js\").catch(check(TypeError)); \
+             import(\"./dep.js\").catch(check(RangeError)); \
+             import(\"./dep.js\", 1).catch(check(TypeError)); \
+             import(\"./dep.js\", { with: { integrity: \"sha384-abc\" } }).catch(check(TypeError));",
+            gc.nogc(),
+        );
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        run_jobs(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "result.length === 4 && result.every(x => x)",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(result, Value::Boolean(true));
+    }
+
+    #[test]
+    fn import_meta() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent(&[]);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "const meta = import.meta; globalThis.result = meta === import.meta && Object.getPrototypeOf(meta) === null && meta.url === \"test:module\";",
+            gc.reborrow(),
+        )
+        .unbind();
+        let result = run_module(&mut agent, module, gc.reborrow());
+        assert_eq!(result, Value::Boolean(true));
+    }
+
+    #[test]
+    fn asynchronous_module_loading() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = create_agent_with_deferred_loads(
+            &[
+                (
+                    "./a.js",
+                    "import { b } from \"./b.js\"; export const a = b + 1;",
+                ),
+                ("./b.js", "export const b = 1;"),
+            ],
+            true,
+        );
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let module = parse(
+            &mut agent,
+            "import { a } from \"./a.js\"; import { answer } from \"native\"; globalThis.result = a + answer;",
+            gc.reborrow(),
+        )
+        .unbind();
+        let module = Global::new(&agent, module);
+        let promise = module
+            .get(&mut agent, gc.nogc())
+            .load_requested_modules(&mut agent, None, gc.nogc())
+            .unbind();
+        assert_eq!(get_host_hooks(&agent).pending_loads.borrow().len(), 2);
+        assert!(!is_fulfilled(&agent, promise));
+        let promise = Global::new(&agent, promise);
+
+        // The graph loading state must survive garbage collection while the
+        // host holds on to it.
+        agent.gc(gc.reborrow());
+        assert_eq!(finish_pending_loads(&mut agent, gc.nogc()), 3);
+        let promise = promise.take(&agent);
+        assert!(is_fulfilled(&agent, promise));
+
+        let module = module.take(&agent);
+        module.link(&mut agent, gc.nogc()).unwrap();
+        let promise = module.evaluate(&mut agent, gc.reborrow()).unbind();
+        assert!(is_fulfilled(&agent, promise));
+        let result = get_result(&mut agent, gc.reborrow());
+        assert_eq!(result, Value::from(44));
+    }
+
+    #[test]
+    fn asynchronous_dynamic_import() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent =
+            create_agent_with_deferred_loads(&[("./dep.js", "export const value = 42;")], true);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "globalThis.result = 0; import(\"./dep.js\").then(ns => { globalThis.result = ns.value; });",
+            gc.nogc(),
+        );
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        run_jobs(&mut agent, gc.reborrow());
+        assert_eq!(get_result(&mut agent, gc.reborrow()), Value::from(0));
+
+        agent.gc(gc.reborrow());
+        assert_eq!(finish_pending_loads(&mut agent, gc.nogc()), 1);
+        run_jobs(&mut agent, gc.reborrow());
+        let result = get_result(&mut agent, gc.reborrow());
+        assert_eq!(result, Value::from(42));
+    }
+}
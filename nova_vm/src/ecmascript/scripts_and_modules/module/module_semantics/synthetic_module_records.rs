@@ -0,0 +1,499 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [16.2.1.8 Synthetic Module Records](https://tc39.es/ecma262/#sec-synthetic-module-records)
+//!
+//! A Synthetic Module Record is used to represent information about a module
+//! that is defined by specifications. Its exported names are statically
+//! defined at creation, while their corresponding values can change over time
+//! using SetSyntheticModuleExport. It has no imports or dependencies.
+//!
+//! > Note: A Synthetic Module Record could be used for defining a variety of
+//! > module types: for example, JSON modules or CSS modules.
+//!
+//! Embedders can use Synthetic Module Records to expose native modules to
+//! ECMAScript code: The module's exports are given as a list of names and
+//! values, and the host's module loading hook returns the module when the
+//! module's name is requested.
+
+use core::{
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    ecmascript::{
+        builtins::{
+            control_abstraction_objects::promise_objects::promise_abstract_operations::promise_capability_records::PromiseCapability,
+            promise::Promise,
+        },
+        execution::{Agent, Environment, JsResult, Realm, new_module_environment},
+        scripts_and_modules::script::HostDefined,
+        types::{BUILTIN_STRING_MEMORY, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcToken, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+        unwrap_try,
+    },
+    heap::{CompactionLists, CreateHeapData, HeapMarkAndSweep, WorkQueues},
+};
+
+use super::abstract_module_records::{
+    AbstractModule, AbstractModuleRecord, ResolveExportResult, ResolvedBinding, ResolvedBindingName,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SyntheticModule<'a>(
+    u32,
+    PhantomData<SyntheticModuleRecord<'static>>,
+    PhantomData<&'a GcToken>,
+);
+
+impl core::fmt::Debug for SyntheticModule<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SyntheticModule({:?})", self.into_u32())
+    }
+}
+
+impl SyntheticModule<'_> {
+    /// Creates a module identififer from a usize.
+    ///
+    /// ## Panics
+    /// If the given index is greater than `u32::MAX`.
+    pub(crate) const fn from_index(value: usize) -> Self {
+        assert!(value <= u32::MAX as usize);
+        Self::from_u32(value as u32)
+    }
+
+    /// Creates a module identififer from a u32.
+    pub(crate) const fn from_u32(value: u32) -> Self {
+        Self(value, PhantomData, PhantomData)
+    }
+
+    pub(crate) fn last(modules: &[Option<SyntheticModuleRecord>]) -> Self {
+        let index = modules.len() - 1;
+        Self::from_index(index)
+    }
+
+    pub(crate) const fn into_index(self) -> usize {
+        self.0 as usize
+    }
+
+    pub(crate) const fn into_u32(self) -> u32 {
+        self.0
+    }
+
+    /// ### [16.2.1.8.4.1 LoadRequestedModules ( )](https://tc39.es/ecma262/#sec-smr-LoadRequestedModules)
+    ///
+    /// The LoadRequestedModules concrete method of a Synthetic Module Record
+    /// module takes no arguments and returns a Promise.
+    pub(crate) fn load_requested_modules<'a>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> Promise<'a> {
+        // 1. Return ! PromiseResolve(%Promise%, undefined).
+        let pc = PromiseCapability::new(agent, gc);
+        let promise = pc.promise();
+        unwrap_try(pc.try_resolve(agent, Value::Undefined, gc));
+        promise
+    }
+
+    /// ### [16.2.1.8.4.2 GetExportedNames ( )](https://tc39.es/ecma262/#sec-smr-getexportednames)
+    ///
+    /// The GetExportedNames concrete method of a Synthetic Module Record
+    /// module takes no arguments and returns a List of Strings.
+    pub(crate) fn get_exported_names<'a>(
+        self,
+        agent: &Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> Vec<String<'a>> {
+        // 1. Return module.[[ExportNames]].
+        agent[self]
+            .export_names
+            .iter()
+            .map(|name| name.bind(gc))
+            .collect()
+    }
+
+    /// ### [16.2.1.8.4.3 ResolveExport ( exportName )](https://tc39.es/ecma262/#sec-smr-resolveexport)
+    ///
+    /// The ResolveExport concrete method of a Synthetic Module Record module
+    /// takes argument exportName (a String) and returns a ResolvedBinding
+    /// Record or null.
+    pub(crate) fn resolve_export<'a>(
+        self,
+        agent: &Agent,
+        export_name: String,
+        gc: NoGcScope<'a, '_>,
+    ) -> Option<ResolveExportResult<'a>> {
+        let module = self.bind(gc);
+        let export_name = export_name.bind(gc);
+        // 1. If module.[[ExportNames]] does not contain exportName, return
+        //    null.
+        if !agent[module].export_names.contains(&export_name.unbind()) {
+            return None;
+        }
+        // 2. Return ResolvedBinding Record { [[Module]]: module,
+        //    [[BindingName]]: exportName }.
+        Some(ResolveExportResult::Resolved(ResolvedBinding {
+            module: module.into(),
+            binding_name: ResolvedBindingName::String(export_name),
+        }))
+    }
+
+    /// ### [16.2.1.8.4.4 Link ( )](https://tc39.es/ecma262/#sec-smr-Link)
+    ///
+    /// The Link concrete method of a Synthetic Module Record module takes no
+    /// arguments and returns a normal completion containing UNUSED.
+    pub(crate) fn link(self, agent: &mut Agent, gc: NoGcScope) {
+        let module = self.bind(gc);
+        // NOTE: A Synthetic Module Record can be imported by multiple module
+        // graphs; its environment is only created once.
+        if agent[module].abstract_record.environment.is_some() {
+            return;
+        }
+        // 1. Let realm be module.[[Realm]].
+        let realm = agent[module].abstract_record.realm.bind(gc);
+        // 2. Let env be NewModuleEnvironment(realm.[[GlobalEnv]]).
+        let global_env = agent[realm].global_env.unwrap().bind(gc);
+        let env = new_module_environment(agent, Environment::Global(global_env), gc);
+        // 3. Set module.[[Environment]] to env.
+        agent[module].abstract_record.environment = Some(env.unbind());
+        // 4. For each String exportName of module.[[ExportNames]], do
+        for i in 0..agent[module].export_names.len() {
+            let export_name = agent[module].export_names[i].bind(gc);
+            // a. Perform ! env.CreateMutableBinding(exportName, false).
+            env.create_mutable_binding(agent, export_name, false);
+            // b. Perform ! env.InitializeBinding(exportName, undefined).
+            env.initialize_binding(agent, export_name, Value::Undefined);
+        }
+        // 5. Return NormalCompletion(UNUSED).
+    }
+
+    /// ### [16.2.1.8.4.5 Evaluate ( )](https://tc39.es/ecma262/#sec-smr-Evaluate)
+    ///
+    /// The Evaluate concrete method of a Synthetic Module Record module takes
+    /// no arguments and returns a Promise.
+    pub(crate) fn evaluate<'a>(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> Promise<'a> {
+        let module = self.bind(gc);
+        // 1. Let moduleContext be a new ECMAScript code execution context.
+        // 2. Set the Function of moduleContext to null.
+        // 3. Set the Realm of moduleContext to module.[[Realm]].
+        // 4. Set the ScriptOrModule of moduleContext to module.
+        // 5. Set the VariableEnvironment of moduleContext to
+        //    module.[[Environment]].
+        // 6. Set the LexicalEnvironment of moduleContext to
+        //    module.[[Environment]].
+        // 7. Suspend the running execution context.
+        // 8. Push moduleContext onto the execution context stack;
+        //    moduleContext is now the running execution context.
+        // NOTE: The evaluation steps of Nova's Synthetic Module Records do not
+        // run any ECMAScript code, so no execution context is needed.
+        // 9. Let steps be module.[[EvaluationSteps]].
+        // 10. Let result be Completion(steps(module)).
+        let result = (0..agent[module].export_names.len()).try_for_each(|i| {
+            let export_name = agent[module].export_names[i].bind(gc);
+            let export_value = agent[module].export_values[i].bind(gc);
+            set_synthetic_module_export(agent, module, export_name, export_value, gc)
+        });
+        // 11. Suspend moduleContext and remove it from the execution context
+        //     stack.
+        // 12. Resume the context that is now on the top of the execution
+        //     context stack as the running execution context.
+        // 13. Let pc be ! NewPromiseCapability(%Promise%).
+        let pc = PromiseCapability::new(agent, gc);
+        let promise = pc.promise();
+        match result {
+            // 14. IfAbruptRejectPromise(result, pc).
+            Err(error) => pc.reject(agent, error.value(), gc),
+            // 15. Perform ! Call(pc.[[Resolve]], undefined, « undefined »).
+            Ok(()) => unwrap_try(pc.try_resolve(agent, Value::Undefined, gc)),
+        }
+        // 16. Return pc.[[Promise]].
+        promise
+    }
+}
+
+/// ### [16.2.1.8.3 SetSyntheticModuleExport ( module, exportName, exportValue )](https://tc39.es/ecma262/#sec-setsyntheticmoduleexport)
+///
+/// The abstract operation SetSyntheticModuleExport takes arguments module (a
+/// Synthetic Module Record), exportName (a String), and exportValue (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing UNUSED or a throw completion. It can be used to set or change
+/// the exported value for an existing export of a Synthetic Module Record.
+fn set_synthetic_module_export<'a>(
+    agent: &mut Agent,
+    module: SyntheticModule,
+    export_name: String,
+    export_value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    // 1. Assert: module.[[ExportNames]] contains exportName.
+    debug_assert!(agent[module].export_names.contains(&export_name.unbind()));
+    // 2. Let envRec be module.[[Environment]].
+    // 3. Assert: envRec is not EMPTY.
+    let env_rec = agent[module].abstract_record.environment.unwrap();
+    // 4. Perform envRec.SetMutableBinding(exportName, exportValue, true).
+    env_rec.set_mutable_binding(agent, export_name, export_value, true, gc)
+    // 5. Return UNUSED.
+}
+
+/// Create a Synthetic Module Record with the given exports.
+///
+/// The module's \[\[ExportNames]] are the names of `exports`, and its
+/// \[\[EvaluationSteps]] set each export to its corresponding value.
+pub fn create_synthetic_module<'a>(
+    agent: &mut Agent,
+    exports: &[(String, Value)],
+    realm: Realm,
+    host_defined: Option<HostDefined>,
+    gc: NoGcScope<'a, '_>,
+) -> SyntheticModule<'a> {
+    let (export_names, export_values): (Vec<_>, Vec<_>) = exports
+        .iter()
+        .map(|(name, value)| (name.unbind(), value.unbind()))
+        .unzip();
+    agent
+        .heap
+        .create(SyntheticModuleRecord {
+            abstract_record: AbstractModuleRecord::new(realm.unbind(), host_defined),
+            export_names: export_names.into_boxed_slice(),
+            export_values: export_values.into_boxed_slice(),
+        })
+        .bind(gc)
+}
+
+/// ### [16.2.1.8.1 CreateDefaultExportSyntheticModule ( defaultExport )](https://tc39.es/ecma262/#sec-create-default-export-synthetic-module)
+///
+/// The abstract operation CreateDefaultExportSyntheticModule takes argument
+/// defaultExport (an ECMAScript language value) and returns a Synthetic
+/// Module Record. It creates a Synthetic Module Record whose default export
+/// is defaultExport.
+pub fn create_default_export_synthetic_module<'a>(
+    agent: &mut Agent,
+    default_export: Value,
+    realm: Realm,
+    host_defined: Option<HostDefined>,
+    gc: NoGcScope<'a, '_>,
+) -> SyntheticModule<'a> {
+    // 1. Let realm be the current Realm Record.
+    // 2. Let setDefaultExport be a new Abstract Closure with parameters
+    //    (module) that captures defaultExport and performs the following
+    //    steps when called:
+    //    a. Perform SetSyntheticModuleExport(module, "default",
+    //       defaultExport).
+    //    b. Return NormalCompletion(UNUSED).
+    // 3. Return the Synthetic Module Record { [[Realm]]: realm,
+    //    [[Environment]]: EMPTY, [[Namespace]]: EMPTY, [[HostDefined]]:
+    //    undefined, [[ExportNames]]: « "default" », [[EvaluationSteps]]:
+    //    setDefaultExport }.
+    create_synthetic_module(
+        agent,
+        &[(BUILTIN_STRING_MEMORY.default, default_export)],
+        realm,
+        host_defined,
+        gc,
+    )
+}
+
+/// ### [16.2.1.8.2 ParseJSONModule ( source )](https://tc39.es/ecma262/#sec-parse-json-module)
+///
+/// The abstract operation ParseJSONModule takes argument source (a String)
+/// and returns either a normal completion containing a Synthetic Module
+/// Record, or a throw completion.
+#[cfg(feature = "json")]
+pub fn parse_json_module<'a>(
+    agent: &mut Agent,
+    source_text: String,
+    realm: Realm,
+    host_defined: Option<HostDefined>,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, SyntheticModule<'a>> {
+    use crate::ecmascript::{
+        builtins::structured_data::json_object::value_from_json, execution::agent::ExceptionType,
+    };
+    // 1. Let json be ? Call(%JSON.parse%, undefined, « source »).
+    // NOTE: JSON.parse without a reviver cannot call into user code, so the
+    // JSON text is parsed directly.
+    let json_value = match sonic_rs::from_str::<sonic_rs::Value>(source_text.as_str(agent)) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(agent.throw_exception(ExceptionType::SyntaxError, error.to_string(), gc));
+        }
+    };
+    let json = value_from_json(agent, &json_value, gc);
+    // 2. Return CreateDefaultExportSyntheticModule(json).
+    Ok(create_default_export_synthetic_module(
+        agent,
+        json,
+        realm,
+        host_defined,
+        gc,
+    ))
+}
+
+/// ### [Synthetic Module Record](https://tc39.es/ecma262/#table-additional-fields-of-synthetic-module-records)
+#[derive(Debug)]
+pub struct SyntheticModuleRecord<'a> {
+    /// Fields shared by all Module Records.
+    pub(crate) abstract_record: AbstractModuleRecord<'a>,
+    /// ### \[\[ExportNames]]
+    ///
+    /// The names of the exports of the module. This list does not contain
+    /// duplicates.
+    pub(crate) export_names: Box<[String<'a>]>,
+    /// ### \[\[EvaluationSteps]]
+    ///
+    /// The initialization logic to perform upon evaluation of the module:
+    /// Each export in \[\[ExportNames]] is set to the value at the same
+    /// index.
+    pub(crate) export_values: Box<[Value<'a>]>,
+}
+
+// SAFETY: The only non-Send data is the host-defined data, which the engine
+// never accesses; it is only handed back to the host on the owning thread.
+unsafe impl Send for SyntheticModuleRecord<'_> {}
+
+impl<'a> From<SyntheticModule<'a>> for AbstractModule<'a> {
+    fn from(value: SyntheticModule<'a>) -> Self {
+        Self::SyntheticModule(value)
+    }
+}
+
+impl Index<SyntheticModule<'_>> for Agent {
+    type Output = SyntheticModuleRecord<'static>;
+
+    fn index(&self, index: SyntheticModule) -> &Self::Output {
+        &self.heap.synthetic_modules[index]
+    }
+}
+
+impl IndexMut<SyntheticModule<'_>> for Agent {
+    fn index_mut(&mut self, index: SyntheticModule) -> &mut Self::Output {
+        &mut self.heap.synthetic_modules[index]
+    }
+}
+
+impl Index<SyntheticModule<'_>> for Vec<Option<SyntheticModuleRecord<'static>>> {
+    type Output = SyntheticModuleRecord<'static>;
+
+    fn index(&self, index: SyntheticModule) -> &Self::Output {
+        self.get(index.into_index())
+            .expect("SyntheticModuleIdentifier out of bounds")
+            .as_ref()
+            .expect("SyntheticModuleIdentifier slot empty")
+    }
+}
+
+impl IndexMut<SyntheticModule<'_>> for Vec<Option<SyntheticModuleRecord<'static>>> {
+    fn index_mut(&mut self, index: SyntheticModule) -> &mut Self::Output {
+        self.get_mut(index.into_index())
+            .expect("SyntheticModuleIdentifier out of bounds")
+            .as_mut()
+            .expect("SyntheticModuleIdentifier slot empty")
+    }
+}
+
+impl<'a> CreateHeapData<SyntheticModuleRecord<'a>, SyntheticModule<'a>> for crate::heap::Heap {
+    fn create(&mut self, data: SyntheticModuleRecord<'a>) -> SyntheticModule<'a> {
+        self.synthetic_modules.push(Some(data.unbind()));
+        self.alloc_counter += core::mem::size_of::<Option<SyntheticModuleRecord<'static>>>();
+        SyntheticModule::last(&self.synthetic_modules)
+    }
+}
+
+impl HeapMarkAndSweep for SyntheticModule<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.synthetic_modules.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let self_index = self.into_u32();
+        *self = Self::from_u32(
+            self_index
+                - compactions
+                    .synthetic_modules
+                    .get_shift_for_index(self_index),
+        );
+    }
+}
+
+impl HeapMarkAndSweep for SyntheticModuleRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            abstract_record,
+            export_names,
+            export_values,
+        } = self;
+        abstract_record.mark_values(queues);
+        export_names.mark_values(queues);
+        export_values.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            abstract_record,
+            export_names,
+            export_values,
+        } = self;
+        abstract_record.sweep_values(compactions);
+        export_names.sweep_values(compactions);
+        export_values.sweep_values(compactions);
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for SyntheticModule<'_> {
+    type Of<'a> = SyntheticModule<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for SyntheticModuleRecord<'_> {
+    type Of<'a> = SyntheticModuleRecord<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl Rootable for SyntheticModule<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::SyntheticModule(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::SyntheticModule(module) => Some(module),
+            _ => None,
+        }
+    }
+}
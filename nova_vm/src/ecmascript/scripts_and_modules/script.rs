@@ -10,7 +10,7 @@ use crate::{
             Agent, ECMAScriptCode, Environment, ExecutionContext, GlobalEnvironment, JsResult,
             Realm, agent::ExceptionType,
         },
-        scripts_and_modules::ScriptOrModule,
+        scripts_and_modules::{ScriptOrModule, module::module_semantics::LoadedModuleRequest},
         syntax_directed_operations::{
             miscellaneous::instantiate_function_object,
             scope_analysis::{
@@ -155,7 +155,7 @@ pub struct ScriptRecord<'a> {
     /// A map from the specifier strings imported by this script to the
     /// resolved Module Record. The list does not contain two different Records
     /// with the same \[\[Specifier]].
-    pub(crate) loaded_modules: (),
+    pub(crate) loaded_modules: Vec<LoadedModuleRequest<'a>>,
 
     /// ### \[\[HostDefined]]
     ///
@@ -232,24 +232,26 @@ impl HeapMarkAndSweep for ScriptRecord<'static> {
         let Self {
             realm,
             ecmascript_code: _,
-            loaded_modules: _,
+            loaded_modules,
             host_defined: _,
             source_code,
         } = self;
         realm.mark_values(queues);
         source_code.mark_values(queues);
+        loaded_modules.as_slice().mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Self {
             realm,
             ecmascript_code: _,
-            loaded_modules: _,
+            loaded_modules,
             host_defined: _,
             source_code,
         } = self;
         realm.sweep_values(compactions);
         source_code.sweep_values(compactions);
+        loaded_modules.as_mut_slice().sweep_values(compactions);
     }
 }
 
@@ -299,7 +301,7 @@ pub fn parse_script<'a>(
         // [[ECMAScriptCode]]: script,
         ecmascript_code: ManuallyDrop::new(program),
         // [[LoadedModules]]: « »,
-        loaded_modules: (),
+        loaded_modules: vec![],
         // [[HostDefined]]: hostDefined,
         host_defined,
         source_code: source_code.unbind(),
@@ -1989,4 +1991,1734 @@ mod test {
             .unwrap();
         assert_eq!(result, Value::Undefined);
     }
+
+    #[test]
+    fn class_private_members() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "class A {
+                #x = 1;
+                static #count = 0;
+                #inc() { this.#x++; return this.#x; }
+                get #double() { return this.#x * 2; }
+                set #double(v) { this.#x = v / 2; }
+                static create() { A.#count += 1; return new A(); }
+                run() { this.#inc(); this.#double = 20; return this.#double + A.#count; }
+            }
+            A.create().run()",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(result, Value::Integer(SmallInteger::from(21)));
+    }
+
+    #[test]
+    fn class_private_brand_checks() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "class A {
+                #x;
+                static has(o) { return #x in o; }
+                static get(o) {
+                    try { return o.#x; } catch (e) { return e instanceof TypeError; }
+                }
+            }
+            A.has(new A()) && !A.has({}) && A.get({})",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(result, Value::Boolean(true));
+    }
+
+    #[test]
+    fn class_private_fields_on_proxies() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "class Base { constructor(o) { return o; } }
+            class Stamp extends Base {
+                #x = 'x';
+                static has(o) { return #x in o; }
+                static get(o) { return o.#x; }
+                static set(o, v) { o.#x = v; }
+            }
+            var proxy = new Proxy({}, {});
+            var functionProxy = new Proxy(function () {}, {});
+            new Stamp(proxy);
+            new Stamp(functionProxy);
+            (function () { new Stamp(new Proxy({}, {})); })();
+            var log = [Stamp.has(proxy), Stamp.get(proxy), Stamp.has(new Proxy({}, {}))];
+            Stamp.set(proxy, 'y');
+            try { new Stamp(proxy); } catch (e) { log.push(e instanceof TypeError); }",
+            gc.nogc(),
+        );
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        agent.gc(gc.reborrow());
+        // The private elements of the unreachable proxy have been removed.
+        assert_eq!(agent.heap.private_elements.len(), 2);
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "log.push(Stamp.get(proxy), Stamp.get(functionProxy)); log.join()",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(&mut agent, "true,x,false,true,y,x", gc.nogc())
+        );
+    }
+
+    #[test]
+    fn try_finally_completions() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "let log = '';
+            function f() {
+                try { return 'a'; } finally { log += 'f'; }
+            }
+            function g() {
+                try { throw 1; } finally { return 'b'; }
+            }
+            for (let i = 0; i < 3; i++) {
+                try {
+                    if (i === 0) continue;
+                    if (i === 1) { try { throw 2; } catch (e) { break; } }
+                } finally {
+                    log += i;
+                }
+            }
+            try {
+                try { throw new Error('c'); } finally { log += 'x'; }
+            } catch (e) {
+                log += e.message;
+            }
+            log + f() + g()",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(&mut agent, "01xcab", gc.nogc())
+        );
+    }
+
+    #[test]
+    fn finally_exits_abruptly() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "let log = '';
+            function f() {
+                for (let i = 0; i < 100; i++) {
+                    try { throw i; } finally { if (i < 98) continue; else break; }
+                }
+                try { return 'r'; } finally { log += 'a'; }
+            }
+            function g() {
+                for (const x of ['b']) {
+                    try { return 'lost'; } finally { log += x; break; }
+                }
+                try { throw 1; } catch (e) {
+                    try { throw 2; } finally { log += e; return 'g'; }
+                }
+            }
+            function h() {
+                try {
+                    try { return 'lost'; } finally { return 'h'; }
+                } finally { log += 'c'; }
+            }
+            const outer = 'o';
+            for (let j = 0; j < 1; j++) {
+                try { throw 3; } catch (e) { try { break; } finally { log += e; } }
+            }
+            [f(), g(), h(), outer].join() + log",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(&mut agent, "r,g,h,o3ab1c", gc.nogc())
+        );
+    }
+
+    #[test]
+    fn generator_return_runs_finally() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "let cleanedUp = false;
+            function* gen() {
+                try { yield 1; yield 2; } finally { cleanedUp = true; }
+            }
+            const it = gen();
+            it.next();
+            const result = it.return(42);
+            cleanedUp && result.done ? result.value : 0",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(result, Value::Integer(SmallInteger::from(42)));
+    }
+
+    #[test]
+    fn async_generator_return_runs_finally() {
+        use crate::ecmascript::execution::agent::{HostHooks, Job};
+        use core::cell::RefCell;
+
+        #[derive(Debug, Default)]
+        struct JobQueueHostHooks {
+            promise_jobs: RefCell<Vec<Job>>,
+        }
+
+        impl HostHooks for JobQueueHostHooks {
+            fn enqueue_promise_job(&self, job: Job) {
+                self.promise_jobs.borrow_mut().push(job);
+            }
+        }
+
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let host_hooks: &'static JobQueueHostHooks = Box::leak(Box::default());
+        let mut agent = Agent::new(Options::default(), host_hooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var log = [];
+            async function* gen() {
+                try {
+                    yield 1;
+                    yield 2;
+                } finally {
+                    log.push('cleanup');
+                    await null;
+                    log.push('awaited');
+                }
+            }
+            async function* catcher() {
+                try { yield 1; } catch (e) { log.push('caught ' + e); yield 2; }
+            }
+            (async () => {
+                const it = gen();
+                await it.next();
+                const result = await it.return(42);
+                log.push(result.value + ':' + result.done);
+                log.push((await it.next()).done);
+                for await (const value of gen()) {
+                    log.push(value);
+                    break;
+                }
+                const it2 = catcher();
+                await it2.next();
+                log.push((await it2.throw('err')).value);
+                const it3 = gen();
+                await it3.next();
+                try { await it3.return(Promise.reject('rejected')); } catch (e) { log.push(e); }
+            })();",
+            gc.nogc(),
+        );
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        loop {
+            let promise_jobs = core::mem::take(&mut *host_hooks.promise_jobs.borrow_mut());
+            if promise_jobs.is_empty() {
+                break;
+            }
+            for job in promise_jobs {
+                job.run(&mut agent, gc.reborrow()).unwrap();
+            }
+        }
+
+        let source_text = String::from_static_str(&mut agent, "log.join()", gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "cleanup,awaited,42:true,true,1,cleanup,awaited,caught err,2,cleanup,awaited,rejected",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    fn labelled_break_and_continue() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "let log = '';
+            outer: for (let i = 0; i < 3; i++) {
+                for (const j of [0, 1, 2]) {
+                    if (j === 1) continue outer;
+                    if (i === 2) break outer;
+                    log += i + '' + j + ',';
+                }
+            }
+            block: {
+                let x = 'a';
+                {
+                    let y = 'b';
+                    log += x + y;
+                    break block;
+                }
+                log += 'unreachable';
+            }
+            loop: while (true) {
+                switch (log.length) {
+                    default:
+                        try {
+                            break loop;
+                        } finally {
+                            log += '!';
+                        }
+                }
+            }
+            log",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(&mut agent, "00,10,ab!", gc.nogc())
+        );
+    }
+
+    #[test]
+    fn with_statement() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var x = 'outer';
+            var o = {
+                x: 'inner',
+                hidden: 'shadowed',
+                f() { return this === o; },
+                [Symbol.unscopables]: { hidden: true },
+            };
+            var hidden = 'visible';
+            var log = '';
+            with (o) {
+                log += x + ',' + hidden + ',' + f();
+                x = 'assigned';
+            }
+            log + ',' + o.x + ',' + x",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(&mut agent, "inner,visible,true,assigned,outer", gc.nogc())
+        );
+    }
+
+    #[test]
+    fn tagged_templates() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var sites = [];
+            var obj = {
+                prefix: '>',
+                tag(strings, ...values) {
+                    sites.push(strings);
+                    return this.prefix + strings.raw.join('|') + values.join(',');
+                },
+            };
+            function run(x) { return obj.tag`a${x}\\n${x + 1}c`; }
+            var result = run(1) + run(2);
+            result + (sites[0] === sites[1]) + Object.isFrozen(sites[0]) +
+                Object.isFrozen(sites[0].raw) + sites[0][1] + String.raw`x${1}y\\u`",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                ">a|\\n|c1,2>a|\\n|c2,3truetruetrue\nx1y\\u",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    fn super_property_access() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "class A {
+                constructor() { this.v = 1; }
+                get g() { return 'g' + this.v; }
+                m(x) { return 'A' + x + this.v; }
+                static s() { return 'sA'; }
+            }
+            class B extends A {
+                constructor() { super(); this.v = 2; }
+                m(x) { return 'B' + super.m(x) + super['m'](x) + super.g; }
+                static s() { return 'sB' + super.s(); }
+                set w(v) { super.z = v; }
+                static y = super.s();
+                field = super.g;
+            }
+            class C extends A {
+                f = super.m(1);
+            }
+            var b = new B();
+            b.w = 5;
+            var o = Object.setPrototypeOf({
+                hi() { return (() => super.hi())() + '!'; },
+            }, { hi() { return 'hi'; } });
+            b.m(0) + B.s() + b.z + B.y + b.field + o.hi() + new C().f",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(&mut agent, "BA02A02g2sBsA5sAg1hi!A11", gc.nogc())
+        );
+    }
+
+    #[test]
+    fn destructuring_for_of_and_object_rest_assignment() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var log = [];
+            var list = [{ a: 1, b: [2, 3], c: 4 }, { a: 5, b: [6], d: 7 }];
+            for (const { a, b: [x, y = 'y'], ...rest } of list) {
+                log.push(a + x + y + JSON.stringify(rest));
+            }
+            for (let [k, { length }] of Object.entries({ foo: 'abc' })) {
+                log.push(k + length);
+            }
+            for (var [first, ...others] in { xyz: 0 }) {
+                log.push(first + others.join(''));
+            }
+            var key = 'p', target = {}, a, r, n, m;
+            ({ a, [key]: target.p = 'def', ...r } = { a: 'A', q: 'Q', s: 'S' });
+            log.push(a + target.p + JSON.stringify(r));
+            ({ x: { y: [n, ...m] }, ...target.rest } = { x: { y: [1, 2, 3] }, z: 'z' });
+            log.push(n + m.join('') + JSON.stringify(target.rest));
+            for ({ a, ...r } of [{ a: 'B', t: 'T' }]) {
+                log.push(a + JSON.stringify(r));
+            }
+            const { [key]: p = 'P', ...leftover } = { q: 'Q' };
+            log.push(p + JSON.stringify(leftover));
+            log.join(';')",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                r#"6{"c":4};11y{"d":7};foo3;xyz;Adef{"q":"Q","s":"S"};123{"z":"z"};B{"t":"T"};P{"q":"Q"}"#,
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "regexp")]
+    fn regexp_exec_and_test() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var log = [];
+            var m = /(\d+)-(?<word>[a-z]+)/.exec('x 12-ab y');
+            log.push(m.index + m[0] + m[1] + m.groups.word + m.length);
+            log.push(String(/(?<=\$)\d+(?!\d)/.exec('cost $42.')));
+            log.push(String(/(?<!\$)\b\d+/.exec('$1 and 2')));
+            log.push(/(a|b)\1/.test('ba bb') + ',' + /^(?:(a)|b)+$/.exec('ab')[1]);
+            log.push(/<.+?>/.exec('<a><b>')[0] + /a{2,3}?/.exec('aaaa')[0] + /x*?y/.exec('xxy')[0]);
+            var g = /o/g, found = [];
+            while (g.test('foo boo')) found.push(g.lastIndex);
+            log.push(found.join(',') + ':' + g.lastIndex);
+            var y = /a/y;
+            y.lastIndex = 1;
+            log.push(y.test('ba') + ',' + y.lastIndex + ',' + y.test('ba') + ',' + y.lastIndex);
+            log.push(/^.$/.test('\u{1F600}') + ',' + /^.$/u.test('\u{1F600}') + ',' + /\u{1F600}/u.exec('a\u{1F600}').index);
+            log.push(/ſ/i.test('s') + ',' + /ſ/iu.test('s') + ',' + /[a-z]+/i.exec('12ABc')[0]);
+            var d = /(?<x>b)(c)?/d.exec('abd');
+            log.push(JSON.stringify(d.indices) + JSON.stringify(d.indices.groups));
+            var r = new RegExp('a/b', 'gi');
+            log.push(r.source + r.flags + r.global + r.sticky + String(r) + (RegExp(r) === r));
+            log.push(String(new RegExp(/x/g, 'y')) + new RegExp('').source + RegExp.prototype.source + RegExp.prototype.global);
+            var lits = [];
+            for (var i = 0; i < 2; i++) lits.push(/q/g);
+            log.push(lits[0] !== lits[1]);
+            try { new RegExp('(', ''); } catch (e) { log.push(e.name); }
+            try { new RegExp('a', 'gg'); } catch (e) { log.push(e.name); }
+            log.push(/^(a+)+$/.test('aaaaaaaaaa!') + ',' + /(a*)*b/.exec('aab')[1].length + ',' + /(?:a|())*?b/.exec('ab')[0]);
+            log.push(/^\w+$/m.exec('foo\nbar')[0] + /bar$/m.test('foo bar\nbaz') + /a.c/s.test('a\nc') + /a.c/.test('a\nc'));
+            log.join(';')"#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                r#"212-ab12ab3;42;2;true,undefined;<a>aaxxy;2,3,6,7:0;true,2,false,0;false,true,1;false,true,ABc;[[1,2],[1,2],null]{"x":[1,2]};a\/bgitruefalse/a\/b/gitrue;/x/y(?:)(?:)undefined;true;SyntaxError;SyntaxError;false,2,ab;footruetruefalse"#,
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "regexp")]
+    fn regexp_unicode_sets_and_property_escapes() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var log = [];
+            log.push(/\p{L}+/u.exec('12abcÄ文1')[0] + /\p{Lu}/u.test('abc') + /\P{Lu}+/u.exec('ABcd')[0]);
+            log.push(/\p{Script=Greek}+/u.exec('abαβγ')[0] + /\p{sc=Grek}/u.test('a') + /\p{scx=Deva}+/u.exec('a॑क')[0].length);
+            log.push(/\p{ASCII_Hex_Digit}+/u.exec('xyzBEEFg')[0] + /\p{Lu}/iu.test('a') + /\P{Lu}/iv.test('A'));
+            var v = /[\p{L}--[a-z]]+/v;
+            log.push(v.unicodeSets + v.flags + v.exec('abcÄX')[0] + /[[a-z]&&[^aeiou]]+/v.exec('aebcd')[0]);
+            log.push(/[\q{abc|d}x]/v.exec('zabc')[0] + /^[\q{abc|ab}]c/v.test('abcc') + /[\q{ABC}]/vi.exec('xabc').index);
+            log.push(/(?i:a)b/.test('AB') + ',' + /(?i:a)b/.test('Ab') + ',' + /(?-i:a)b/i.test('aB'));
+            try { new RegExp('\\p{Foo}', 'u'); } catch (e) { log.push(e.name); }
+            try { new RegExp('a', 'uv'); } catch (e) { log.push(e.name); }
+            log.join(';')"#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "abcÄ文falsecd;αβγfalse2;BEEFtruefalse;truevÄXbcd;abctrue1;false,true,true;SyntaxError;SyntaxError",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "regexp")]
+    fn regexp_symbol_methods() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var log = [];
+            log.push('a1b22c333'.match(/\d+/g).join(',') + '|' + 'abc'.match(/b/).index + '|' + 'abc'.match(/x/g));
+            var it = 'a1b2'.matchAll(/[a-z](\d)/g), parts = [];
+            for (var m of it) parts.push(m[0] + m[1] + m.index);
+            log.push(parts.join(','));
+            log.push('2024-05'.replace(/(\d+)-(\d+)/, '$2/$1') + '|' + 'ab'.replace(/(?<x>a)/, '[$<x>$&]') + '|' + 'aaa'.replace(/a/g, function (m, i) { return i; }) + '|' + 'x'.replace(/x/, '$$$`$\''));
+            log.push('a,b,,c'.split(/,/).length + '|' + 'a1b2c'.split(/(\d)/).join('.') + '|' + 'a1b2c'.split(/\d/, 2).join('.') + '|' + ''.split(/x/).length + '|' + 'abc'.split(/(?:)/).join('.'));
+            log.push('hello'.search(/l+/) + '|' + 'hello'.search(/z/));
+            var re = /x/g;
+            re.exec = function (s) { return this.lastIndex++ < 2 ? { 0: 'y', length: 1, index: this.lastIndex - 1 } : null; };
+            log.push('abc'.replace(re, 'Z') + '|' + RegExp.prototype[Symbol.match].call(re, 'q').join(','));
+            try { 'a'.replaceAll(/a/, 'b'); } catch (e) { log.push(e.name); }
+            try { 'a'.matchAll(/a/); } catch (e) { log.push(e.name); }
+            log.join(';')"#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "1,22,333|1|null;a110,b222;05/2024|[aa]b|012|$;4|a.1.b.2.c|a.b|1|a.b.c;2|-1;ZZc|y,y;TypeError;TypeError",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "annex-b-regexp")]
+    fn regexp_legacy_static_properties() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var log = [];
+            /(\d+)-(\d+)/.test('on 2024-05 ok');
+            log.push(RegExp.$1 + ',' + RegExp.$2 + ',' + RegExp.$3 + '|' + RegExp.lastMatch + '|' + RegExp['$&'] + '|' + RegExp.lastParen + '|' + RegExp.leftContext + '|' + RegExp["$'"] + '|' + RegExp.input + '|' + RegExp.$_);
+            'abc'.replace(/b/, 'x');
+            log.push(RegExp.$1 === '' && RegExp.lastMatch === 'b' && RegExp.rightContext === 'c');
+            RegExp.input = 42;
+            log.push(typeof RegExp.input + RegExp.$_);
+            var re = /a/g;
+            log.push(re.compile('(b)+', 'i') === re, re.source, re.flags, re.lastIndex, re.test('xBb'), RegExp.$1);
+            try { re.compile(/x/, 'g'); } catch (e) { log.push(e.name); }
+            try { Object.getOwnPropertyDescriptor(RegExp, 'lastMatch').get.call({}); } catch (e) { log.push(e.name); }
+            class Sub extends RegExp {}
+            new Sub('(q)').exec('q');
+            try { RegExp.$1; } catch (e) { log.push('invalidated ' + e.name); }
+            try { new Sub('a').compile('b'); } catch (e) { log.push(e.name); }
+            log.join(';')"#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "2024,05,|2024-05|2024-05|05|on | ok|on 2024-05 ok|on 2024-05 ok;true;string42;true;(b)+;i;0;true;b;TypeError;TypeError;invalidated TypeError;TypeError",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "weak-refs")]
+    fn weak_map_and_weak_set_ephemerons() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var key = {};
+            var sym = Symbol('sym');
+            var wm = new WeakMap([[key, 'kept']]);
+            var ws = new WeakSet([key]);
+            (function () {
+                var tmp = {};
+                var a = {};
+                var b = {};
+                wm.set(tmp, 'lost');
+                ws.add(tmp);
+                wm.set(a, b).set(b, a);
+            })();
+            var outer = {};
+            var inner = {};
+            wm.set(sym, key).set(outer, inner).set(inner, 'inner');
+            var log = [wm.get(key), wm.has(sym), ws.has(key), wm.get(1), wm.has('a'), wm.delete(null), ws.has(1)];
+            try { wm.set(1, 1); } catch (e) { log.push(e.name); }
+            try { ws.add('a'); } catch (e) { log.push(e.name); }
+            try { WeakMap(); } catch (e) { log.push(e.name); }
+            try { WeakSet.prototype.has.call(wm, key); } catch (e) { log.push(e.name); }
+            log.push(Object.prototype.toString.call(ws));
+            log.join()"#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "kept,true,true,,false,false,false,TypeError,TypeError,TypeError,TypeError,[object WeakSet]",
+                gc.nogc()
+            )
+        );
+
+        agent.gc(gc.reborrow());
+        // The entries of tmp, and of a and b which only refer to one another
+        // through the WeakMap, have been removed. The entry of inner is kept
+        // as inner is reachable through the value of outer.
+        assert_eq!(agent.heap.weak_maps.len(), 1);
+        assert_eq!(
+            agent.heap.weak_maps[0]
+                .as_ref()
+                .unwrap()
+                .weak_map_data
+                .len(),
+            4
+        );
+        assert_eq!(agent.heap.weak_sets.len(), 1);
+        assert_eq!(
+            agent.heap.weak_sets[0]
+                .as_ref()
+                .unwrap()
+                .weak_set_data
+                .len(),
+            1
+        );
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "[wm.get(key), wm.get(sym) === key, wm.get(outer) === inner, wm.get(inner), ws.has(key), wm.delete(key), wm.has(key)].join()",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "kept,true,true,inner,true,true,false",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "weak-refs")]
+    fn weak_ref_and_finalization_registry() {
+        use crate::ecmascript::execution::agent::{HostHooks, Job};
+        use core::cell::RefCell;
+
+        #[derive(Debug, Default)]
+        struct CleanupHostHooks {
+            cleanup_jobs: RefCell<Vec<Job>>,
+        }
+
+        impl HostHooks for CleanupHostHooks {
+            fn enqueue_promise_job(&self, _job: Job) {}
+
+            fn enqueue_finalization_registry_cleanup_job(&self, job: Job) {
+                self.cleanup_jobs.borrow_mut().push(job);
+            }
+        }
+
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let host_hooks: &'static CleanupHostHooks = Box::leak(Box::default());
+        let mut agent = Agent::new(Options::default(), host_hooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var log = [];
+            var registry = new FinalizationRegistry(function (held) { log.push(held); });
+            var token = {};
+            var target = {};
+            var ref = new WeakRef(target);
+            var lostRef = new WeakRef({});
+            registry.register({}, 'collected');
+            registry.register({}, 'unregistered', token);
+            registry.register(target, 'kept', target);
+            var result = [ref.deref() === target, lostRef.deref() !== undefined, registry.register(target, 1), registry.unregister(token), registry.unregister(token)];
+            try { WeakRef(target); } catch (e) { result.push(e.name); }
+            try { new WeakRef(1); } catch (e) { result.push(e.name); }
+            try { new FinalizationRegistry(); } catch (e) { result.push(e.name); }
+            try { registry.register(target, target); } catch (e) { result.push(e.name); }
+            try { registry.register(target, 1, 1); } catch (e) { result.push(e.name); }
+            try { registry.unregister(1); } catch (e) { result.push(e.name); }
+            try { WeakRef.prototype.deref.call(registry); } catch (e) { result.push(e.name); }
+            result.push(Object.prototype.toString.call(ref), Object.prototype.toString.call(registry));
+            result.join()"#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "true,true,,true,false,TypeError,TypeError,TypeError,TypeError,TypeError,TypeError,TypeError,[object WeakRef],[object FinalizationRegistry]",
+                gc.nogc()
+            )
+        );
+
+        // The WeakRef targets were kept alive until the end of the script.
+        agent.clear_kept_objects();
+        agent.gc(gc.reborrow());
+        let cleanup_jobs = core::mem::take(&mut *host_hooks.cleanup_jobs.borrow_mut());
+        assert_eq!(cleanup_jobs.len(), 1);
+        for job in cleanup_jobs {
+            job.run(&mut agent, gc.reborrow()).unwrap();
+        }
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "[lostRef.deref(), ref.deref() === target, log.join('|')].join()",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(&mut agent, ",true,collected", gc.nogc())
+        );
+    }
+
+    #[test]
+    fn promise_combinators_and_finally() {
+        use crate::ecmascript::execution::agent::{HostHooks, Job};
+        use core::cell::RefCell;
+
+        #[derive(Debug, Default)]
+        struct JobQueueHostHooks {
+            promise_jobs: RefCell<Vec<Job>>,
+        }
+
+        impl HostHooks for JobQueueHostHooks {
+            fn enqueue_promise_job(&self, job: Job) {
+                self.promise_jobs.borrow_mut().push(job);
+            }
+        }
+
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let host_hooks: &'static JobQueueHostHooks = Box::leak(Box::default());
+        let mut agent = Agent::new(Options::default(), host_hooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var log = [];
+            Promise.all([1, Promise.resolve(2), { then(f) { f(3); } }]).then(v => log.push('all:' + v.join('|')));
+            Promise.all([1, Promise.reject('no')]).catch(e => log.push('all-rejected:' + e));
+            Promise.all(1).catch(e => log.push('all-iterable:' + e.name));
+            Promise.allSettled([1, Promise.reject(2)]).then(v => log.push('allSettled:' + v.map(r => r.status + '=' + (r.value ?? r.reason)).join('|')));
+            Promise.any([Promise.reject(1), 2]).then(v => log.push('any:' + v));
+            Promise.any([Promise.reject(1), Promise.reject(2)]).catch(e => log.push('any-rejected:' + e.name + '=' + e.errors.join('|')));
+            Promise.any([]).catch(e => log.push('any-empty:' + e.errors.length));
+            Promise.race([new Promise(() => {}), Promise.resolve('fast')]).then(v => log.push('race:' + v));
+            var closed = false;
+            var iterable = { [Symbol.iterator]() { return { next() { return { value: 1, done: false }; }, return() { closed = true; return {}; } }; } };
+            var promiseResolve = Promise.resolve;
+            Promise.resolve = function () { throw 'thrown'; };
+            Promise.all(iterable).catch(e => log.push('closed:' + closed + '=' + e));
+            Promise.resolve = promiseResolve;
+            Promise.resolve(1).finally(() => log.push('finally')).then(v => log.push('finally-value:' + v));
+            Promise.reject(2).finally(() => {}).catch(e => log.push('finally-reason:' + e));
+            Promise.resolve(1).finally(() => { throw 3; }).catch(e => log.push('finally-throw:' + e));"#,
+            gc.nogc(),
+        );
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        loop {
+            let promise_jobs = core::mem::take(&mut *host_hooks.promise_jobs.borrow_mut());
+            if promise_jobs.is_empty() {
+                break;
+            }
+            for job in promise_jobs {
+                job.run(&mut agent, gc.reborrow()).unwrap();
+            }
+        }
+
+        let source_text = String::from_static_str(&mut agent, "log.sort().join()", gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "all-iterable:TypeError,all-rejected:no,all:1|2|3,allSettled:fulfilled=1|rejected=2,any-empty:0,any-rejected:AggregateError=1|2,any:2,closed:true=thrown,finally,finally-reason:2,finally-throw:3,finally-value:1,race:fast",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "atomics")]
+    fn atomics_read_modify_write() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var i8 = new Int8Array(4);
+            var u16 = new Uint16Array(new ArrayBuffer(8), 2, 2);
+            var big = new BigInt64Array(2);
+            var result = [
+                Atomics.store(i8, 0, 127), Atomics.add(i8, 0, 1), Atomics.load(i8, 0),
+                Atomics.sub(u16, 1, 1), Atomics.load(u16, 1),
+                Atomics.or(i8, 1, 6), Atomics.and(i8, 1, 3), Atomics.xor(i8, 1, 1), Atomics.exchange(i8, 1, 9), i8[1],
+                Atomics.compareExchange(i8, 2, 1, 5), i8[2], Atomics.compareExchange(i8, 2, 0, 5), i8[2],
+                Atomics.store(i8, 3, -0) === 0 && 1 / Atomics.store(i8, 3, -0),
+                Atomics.add(big, 1, 5n), Atomics.compareExchange(big, 1, 5n, -1n), Atomics.load(big, 1),
+                Atomics.isLockFree(4), Atomics.isLockFree(3),
+            ];
+            try { Atomics.load(new Float64Array(1), 0); } catch (e) { result.push(e.name); }
+            try { Atomics.load(new Uint8ClampedArray(1), 0); } catch (e) { result.push(e.name); }
+            try { Atomics.load(i8, 4); } catch (e) { result.push(e.name); }
+            try { Atomics.add(big, 0, 1); } catch (e) { result.push(e.name); }
+            try { Atomics.store({}, 0, 0); } catch (e) { result.push(e.name); }
+            result.join()"#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "127,127,-128,0,65535,0,6,2,3,9,0,0,0,5,Infinity,0,5,-1,true,false,TypeError,TypeError,RangeError,TypeError,TypeError",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "atomics")]
+    fn atomics_wait_and_notify_unshared() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var i32 = new Int32Array(4);
+            var result = [Atomics.notify(i32, 0), Atomics.notify(i32, 1, 2)];
+            try { Atomics.wait(i32, 0, 0, 0); } catch (e) { result.push(e.name); }
+            try { Atomics.waitAsync(i32, 0, 0); } catch (e) { result.push(e.name); }
+            try { Atomics.wait(new Uint32Array(1), 0, 0); } catch (e) { result.push(e.name); }
+            try { Atomics.notify(new Int8Array(1), 0); } catch (e) { result.push(e.name); }
+            try { Atomics.notify(i32, 4); } catch (e) { result.push(e.name); }
+            var coerced = false;
+            try { Atomics.wait(i32, 0, { valueOf() { coerced = true; return 0; } }); } catch (e) { result.push(coerced); }
+            result.join()"#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "0,0,TypeError,TypeError,TypeError,TypeError,RangeError,false",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    fn generator_yield_delegate() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var log = [];
+            function* inner() {
+                try {
+                    var x = yield 1;
+                    log.push('x' + x);
+                    yield 2;
+                } catch (e) {
+                    log.push('caught ' + e);
+                    yield 3;
+                } finally {
+                    log.push('inner finally');
+                }
+                return 'r';
+            }
+            function* outer() {
+                var r = yield* inner();
+                log.push('returned ' + r);
+                yield* [4, 5];
+                return 6;
+            }
+            var g = outer();
+            var res = [g.next().value, g.next('a').value];
+            res.push(g.throw('err').value, g.next().value, g.next().value, g.next().value);
+            var g2 = outer();
+            g2.next();
+            var ret = g2.return('done');
+            var iterResult = { value: 'v', done: false };
+            var it = { [Symbol.iterator]() { return { next() { return iterResult; } }; } };
+            var same = (function* () { yield* it; })().next() === iterResult;
+            var noThrow = (function* () { yield* { [Symbol.iterator]() { return { next() { return { done: false }; }, return() { log.push('closed'); return {}; } }; } }; })();
+            noThrow.next();
+            var threw;
+            try { noThrow.throw(1); } catch (e) { threw = e instanceof TypeError; }
+            res.join() + '|' + log.join() + '|' + ret.value + ret.done + same + threw",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "1,2,3,4,5,6|xa,caught err,inner finally,returned r,inner finally,closed|donetruetruetrue",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    fn async_generator_yield_delegate_early_exit() {
+        use crate::ecmascript::execution::agent::{HostHooks, Job};
+        use core::cell::RefCell;
+
+        #[derive(Debug, Default)]
+        struct JobQueueHostHooks {
+            promise_jobs: RefCell<Vec<Job>>,
+        }
+
+        impl HostHooks for JobQueueHostHooks {
+            fn enqueue_promise_job(&self, job: Job) {
+                self.promise_jobs.borrow_mut().push(job);
+            }
+        }
+
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let host_hooks: &'static JobQueueHostHooks = Box::leak(Box::default());
+        let mut agent = Agent::new(Options::default(), host_hooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var log = [];
+            async function* inner() {
+                try {
+                    yield 'a';
+                    yield 'b';
+                } finally {
+                    log.push('inner finally');
+                    await null;
+                }
+            }
+            async function* outer() {
+                try {
+                    const r = yield* inner();
+                    log.push('returned ' + r);
+                } finally {
+                    log.push('outer finally');
+                }
+            }
+            (async () => {
+                for await (const value of outer()) {
+                    log.push(value);
+                    break;
+                }
+                const it = outer();
+                log.push((await it.next()).value);
+                const result = await it.return('r');
+                log.push(result.value + ':' + result.done);
+                const values = [];
+                for await (const value of outer()) values.push(value);
+                log.push(values.join(''));
+                const fromSync = (async function* () { yield* [Promise.resolve('s1'), 's2']; })();
+                for await (const value of fromSync) {
+                    log.push(value);
+                    break;
+                }
+            })();",
+            gc.nogc(),
+        );
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        loop {
+            let promise_jobs = core::mem::take(&mut *host_hooks.promise_jobs.borrow_mut());
+            if promise_jobs.is_empty() {
+                break;
+            }
+            for job in promise_jobs {
+                job.run(&mut agent, gc.reborrow()).unwrap();
+            }
+        }
+
+        let source_text = String::from_static_str(&mut agent, "log.join()", gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "a,inner finally,outer finally,a,inner finally,outer finally,r:true,inner finally,returned undefined,outer finally,ab,s1",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    fn using_declarations() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var log = [];
+            function res(name) {
+                return { [Symbol.dispose]() { log.push(name); } };
+            }
+            {
+                using a = res('a'), b = res('b');
+                log.push('body');
+            }
+            function f() {
+                using c = res('c');
+                return 'ret';
+            }
+            log.push(f());
+            for (using d of [res('d1'), res('d2')]) {
+                if (log.length > 10) break;
+            }
+            var suppressed;
+            try {
+                using e = { [Symbol.dispose]() { throw 'dispose'; } };
+                throw 'body';
+            } catch (err) {
+                suppressed = err instanceof SuppressedError && err.error + ':' + err.suppressed;
+            }
+            var stack = new DisposableStack();
+            stack.defer(() => log.push('deferred'));
+            stack.use(res('used'));
+            var moved = stack.move();
+            moved.dispose();
+            log.join() + '|' + suppressed + '|' + stack.disposed + moved.disposed",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "body,b,a,c,ret,d1,d2,used,deferred|dispose:body|truetrue",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "proposal-explicit-resource-management"))]
+    fn using_declarations_without_proposal() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let realm = agent.current_realm(gc.nogc());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "{ using a = null; } async function f() { await using b = null; }",
+            gc.nogc(),
+        );
+        assert!(parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).is_err());
+    }
+
+    #[test]
+    fn promise_combinators_with_subclasses() {
+        use crate::ecmascript::execution::agent::{HostHooks, Job};
+        use core::cell::RefCell;
+
+        #[derive(Debug, Default)]
+        struct JobQueueHostHooks {
+            promise_jobs: RefCell<Vec<Job>>,
+        }
+
+        impl HostHooks for JobQueueHostHooks {
+            fn enqueue_promise_job(&self, job: Job) {
+                self.promise_jobs.borrow_mut().push(job);
+            }
+        }
+
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let host_hooks: &'static JobQueueHostHooks = Box::leak(Box::default());
+        let mut agent = Agent::new(Options::default(), host_hooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var log = [];
+            class MyPromise extends Promise {}
+            var all = MyPromise.all([1, Promise.resolve(2), MyPromise.resolve(3)]);
+            log.push('instance:' + (all instanceof MyPromise) + (MyPromise.resolve(all) === all));
+            all.then(v => log.push('all:' + v.join('|')));
+            MyPromise.all([MyPromise.reject('no')]).catch(e => log.push('all-rejected:' + e));
+            MyPromise.allSettled([MyPromise.reject(1)]).then(v => log.push('allSettled:' + v[0].status));
+            MyPromise.any([MyPromise.reject(1), 2]).then(v => log.push('any:' + v));
+            MyPromise.race([new Promise(() => {}), 'fast']).then(v => log.push('race:' + v));
+            function NotAPromise(executor) {
+                executor(v => log.push('resolved:' + v), e => log.push('rejected:' + e));
+            }
+            NotAPromise.resolve = v => ({ then(f) { f(v * 10); } });
+            Promise.all.call(NotAPromise, [1, 2]);"#,
+            gc.nogc(),
+        );
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        loop {
+            let promise_jobs = core::mem::take(&mut *host_hooks.promise_jobs.borrow_mut());
+            if promise_jobs.is_empty() {
+                break;
+            }
+            for job in promise_jobs {
+                job.run(&mut agent, gc.reborrow()).unwrap();
+            }
+        }
+
+        let source_text = String::from_static_str(&mut agent, "log.sort().join()", gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "all-rejected:no,all:1|2|3,allSettled:rejected,any:2,instance:truetrue,race:fast,resolved:10,20",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "array-buffer")]
+    fn typed_array_set_slice_subarray() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var u8 = new Uint8Array([1, 2, 3, 4, 5, 6, 7, 8]);
+            u8.set([9, 10], 6);
+            u8.set(u8.subarray(0, 4), 2);
+            u8.set(new Int16Array(u8.buffer, 0, 4), 4);
+            var result = [u8.join()];
+            try { u8.set([1], 8); } catch (e) { result.push(e.name); }
+            try { u8.set([], -1); } catch (e) { result.push(e.name); }
+            try { u8.set(new BigInt64Array(1)); } catch (e) { result.push(e.name); }
+            var f32 = new Float32Array(3);
+            f32.set(new Int8Array([-1, 2]), 1);
+            var s = u8.slice(-3);
+            result.push(f32.join(), s.join(), s.buffer !== u8.buffer);
+            var m = new Uint8Array([1, 2, 3]);
+            m.constructor = { [Symbol.species]: Float64Array };
+            var sl = m.slice(1);
+            result.push(sl instanceof Float64Array, sl.join());
+            var rab = new ArrayBuffer(4, { maxByteLength: 8 });
+            var tracking = new Uint8Array(rab);
+            var sub = tracking.subarray(1);
+            var fixed = tracking.subarray(1, 3);
+            rab.resize(8);
+            result.push(sub.length, fixed.length, sub.byteOffset);
+            var mapped = new Int8Array([1, 2, 3]).map(function (x, i) { return x * 100 + i; });
+            result.push(mapped.join(), mapped instanceof Int8Array);
+            var w = new Float32Array([1, 2, 3]);
+            result.push(w.with(-1, 0.5).join(), w.join());
+            try { w.with(3, 0); } catch (e) { result.push(e.name); }
+            var t = new Float64Array([3, -0, NaN, 0, -1]);
+            result.push(t.toSorted().join(), t.join());
+            result.push(new Uint8Array([1, 3, 2]).toSorted(function (a, b) { return b - a; }).join());
+            result.push(new Uint8Array([1, 2, 3]).toLocaleString());
+            result.join('|')",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "1,2,1,2,1,1,3,9|RangeError|RangeError|TypeError|0,-1,2|1,3,9|true|true|2,3|7|2|1|100,-55,46|true|1,2,0.5|1,2,3|RangeError|-1,0,0,3,NaN|3,0,NaN,0,-1|3,2,1|1,2,3",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "shared-array-buffer")]
+    fn shared_array_buffer_across_threads() {
+        use crate::ecmascript::builtins::SharedArrayBuffer;
+
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var sab = new SharedArrayBuffer(4, { maxByteLength: 16 }); sab",
+            gc.nogc(),
+        );
+        let sab = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        let sab = SharedArrayBuffer::try_from(sab).unwrap();
+        let data_block = sab.get_shared_data_block(&agent);
+        data_block.store(0, 0x0102_0304u32);
+
+        let other_data_block = data_block.clone();
+        std::thread::spawn(move || {
+            let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+            let mut gc = GcScope::new(&mut gc, &mut scope);
+            let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+            initialize_default_realm(&mut agent, gc.reborrow());
+            let sab = SharedArrayBuffer::from_shared_data_block(
+                &mut agent,
+                other_data_block.clone(),
+                gc.nogc(),
+            );
+            let global = agent.current_global_object(gc.nogc());
+            let key = PropertyKey::from_static_str(&mut agent, "sab", gc.nogc());
+            create_data_property_or_throw(
+                &mut agent,
+                global.unbind(),
+                key.unbind(),
+                sab.into_value().unbind(),
+                gc.reborrow(),
+            )
+            .unwrap();
+            let source_text =
+                String::from_static_str(&mut agent, "sab.grow(12); sab.slice(0, 4)", gc.nogc());
+            let copy = agent
+                .run_script(source_text.unbind(), gc.reborrow())
+                .unwrap();
+            let copy = SharedArrayBuffer::try_from(copy).unwrap();
+            assert_eq!(copy.byte_length(&agent), 4);
+            assert_eq!(
+                copy.get_shared_data_block(&agent).load::<u32>(0),
+                Some(0x0102_0304)
+            );
+            other_data_block.store(8, 42u32);
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(data_block.load::<u32>(8), Some(42));
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var result = [sab.byteLength, sab.maxByteLength, sab.growable];
+            try { sab.grow(8); } catch (e) { result.push(e.name); }
+            try { sab.grow(17); } catch (e) { result.push(e.name); }
+            try { new SharedArrayBuffer(4).grow(4); } catch (e) { result.push(e.name); }
+            result.join()",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "12,16,true,RangeError,RangeError,TypeError",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "shared-array-buffer")]
+    fn typed_array_over_shared_array_buffer() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var sab = new SharedArrayBuffer(8, { maxByteLength: 16 });
+            var u8 = new Uint8Array(sab);
+            var u16 = new Uint16Array(sab, 2, 2);
+            u8.set([1, 2, 3, 4, 5]);
+            u8.copyWithin(5, 0, 3);
+            var copy = new Uint8Array(u16);
+            var slice = u8.slice(1, 4);
+            sab.grow(12);
+            u8.fill(9, 8);
+            var result = [
+                u8.buffer === sab, u8.join(''), u16[0], u16[1], u16.byteLength,
+                copy.join(''), slice.buffer instanceof ArrayBuffer, slice.join(''),
+                new Float64Array(sab, 8, 0).length, u8.toSorted().join(''),
+            ];
+            result.join()",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "true,123451239999,1027,261,4,35,true,234,0,112233459999",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "atomics")]
+    fn atomics_read_modify_write_shared() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            r#"var i8 = new Int8Array(new SharedArrayBuffer(4));
+            var u16 = new Uint16Array(new SharedArrayBuffer(8), 2, 2);
+            var big = new BigInt64Array(new SharedArrayBuffer(16));
+            var result = [
+                Atomics.store(i8, 0, 127), Atomics.add(i8, 0, 1), Atomics.load(i8, 0),
+                Atomics.sub(u16, 1, 1), Atomics.load(u16, 1),
+                Atomics.or(i8, 1, 6), Atomics.and(i8, 1, 3), Atomics.xor(i8, 1, 1), Atomics.exchange(i8, 1, 9), i8[1],
+                Atomics.compareExchange(i8, 2, 1, 5), i8[2], Atomics.compareExchange(i8, 2, 0, 5), i8[2],
+                Atomics.store(i8, 3, -0) === 0 && 1 / Atomics.store(i8, 3, -0),
+                Atomics.add(big, 1, 5n), Atomics.compareExchange(big, 1, 5n, -1n), Atomics.load(big, 1),
+                Atomics.isLockFree(4), Atomics.isLockFree(3),
+            ];
+            try { Atomics.load(new Float64Array(1), 0); } catch (e) { result.push(e.name); }
+            try { Atomics.load(new Uint8ClampedArray(1), 0); } catch (e) { result.push(e.name); }
+            try { Atomics.load(i8, 4); } catch (e) { result.push(e.name); }
+            try { Atomics.add(big, 0, 1); } catch (e) { result.push(e.name); }
+            try { Atomics.store({}, 0, 0); } catch (e) { result.push(e.name); }
+            result.join()"#,
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "127,127,-128,0,65535,0,6,2,3,9,0,0,0,5,Infinity,0,5,-1,true,false,TypeError,TypeError,RangeError,TypeError,TypeError",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "atomics")]
+    fn atomics_read_modify_write_across_threads() {
+        use crate::ecmascript::builtins::SharedArrayBuffer;
+
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var i32 = new Int32Array(new SharedArrayBuffer(12)); i32.buffer",
+            gc.nogc(),
+        );
+        let sab = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        let sab = SharedArrayBuffer::try_from(sab).unwrap();
+        let data_block = sab.get_shared_data_block(&agent);
+
+        let threads = (0..4)
+            .map(|_| {
+                let data_block = data_block.clone();
+                std::thread::spawn(move || {
+                    let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+                    let mut gc = GcScope::new(&mut gc, &mut scope);
+                    let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+                    initialize_default_realm(&mut agent, gc.reborrow());
+                    let sab = SharedArrayBuffer::from_shared_data_block(
+                        &mut agent,
+                        data_block,
+                        gc.nogc(),
+                    );
+                    let global = agent.current_global_object(gc.nogc());
+                    let key = PropertyKey::from_static_str(&mut agent, "sab", gc.nogc());
+                    create_data_property_or_throw(
+                        &mut agent,
+                        global.unbind(),
+                        key.unbind(),
+                        sab.into_value().unbind(),
+                        gc.reborrow(),
+                    )
+                    .unwrap();
+                    let source_text = String::from_static_str(
+                        &mut agent,
+                        "var i32 = new Int32Array(sab);
+                        for (var k = 0; k < 1000; k++) {
+                            Atomics.add(i32, 0, 1);
+                            Atomics.sub(i32, 1, 1);
+                            var old;
+                            do { old = Atomics.load(i32, 2); }
+                            while (Atomics.compareExchange(i32, 2, old, old + 2) !== old);
+                        }",
+                        gc.nogc(),
+                    );
+                    agent
+                        .run_script(source_text.unbind(), gc.reborrow())
+                        .unwrap();
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "[Atomics.load(i32, 0), Atomics.load(i32, 1), Atomics.load(i32, 2)].join()",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(&mut agent, "4000,-4000,8000", gc.nogc())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "atomics")]
+    fn atomics_wait_and_notify_across_threads() {
+        use crate::ecmascript::builtins::SharedArrayBuffer;
+
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var sab = new SharedArrayBuffer(12); var i32 = new Int32Array(sab); sab",
+            gc.nogc(),
+        );
+        let sab = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        let sab = SharedArrayBuffer::try_from(sab).unwrap();
+        let data_block = sab.get_shared_data_block(&agent).clone();
+
+        let waiter = std::thread::spawn(move || {
+            let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+            let mut gc = GcScope::new(&mut gc, &mut scope);
+            let options = Options {
+                can_block: true,
+                ..Default::default()
+            };
+            let mut agent = Agent::new(options, &DefaultHostHooks);
+            initialize_default_realm(&mut agent, gc.reborrow());
+            let sab = SharedArrayBuffer::from_shared_data_block(&mut agent, data_block, gc.nogc());
+            let global = agent.current_global_object(gc.nogc());
+            let key = PropertyKey::from_static_str(&mut agent, "sab", gc.nogc());
+            create_data_property_or_throw(
+                &mut agent,
+                global.unbind(),
+                key.unbind(),
+                sab.into_value().unbind(),
+                gc.reborrow(),
+            )
+            .unwrap();
+            let source_text = String::from_static_str(
+                &mut agent,
+                "var i32 = new Int32Array(sab);
+                var result = [Atomics.wait(i32, 1, 5), Atomics.wait(i32, 1, 0, 0), Atomics.wait(i32, 0, 0)];
+                result.push(Atomics.load(i32, 2));
+                result.join()",
+                gc.nogc(),
+            );
+            let result = agent
+                .run_script(source_text.unbind(), gc.reborrow())
+                .unwrap();
+            assert_eq!(
+                result.unbind(),
+                Value::from_static_str(&mut agent, "not-equal,timed-out,ok,7", gc.nogc())
+            );
+        });
+
+        // Keep notifying until the other agent has been woken up from its
+        // wait on index 0.
+        let source_text = String::from_static_str(
+            &mut agent,
+            "Atomics.store(i32, 2, 7); Atomics.notify(i32, 0)",
+            gc.nogc(),
+        );
+        let source_text = source_text.scope(&mut agent, gc.nogc());
+        loop {
+            let woken = agent
+                .run_script(source_text.get(&agent), gc.reborrow())
+                .unwrap();
+            if woken.unbind() == Value::from(1) {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        waiter.join().unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "array-buffer")]
+    fn array_buffer_transfer() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var ab = new ArrayBuffer(8, { maxByteLength: 16 });
+            var u8 = new Uint8Array(ab);
+            u8[0] = 1;
+            u8[7] = 7;
+            var t = ab.transfer();
+            var result = [ab.detached, ab.byteLength, u8.length, t.byteLength, t.resizable, t.maxByteLength, new Uint8Array(t)[7]];
+            var f = t.transferToFixedLength(12);
+            var f8 = new Uint8Array(f);
+            result.push(t.detached, f.resizable, f.byteLength, f8[0], f8[7], f8[11]);
+            try { ab.transfer(); } catch (e) { result.push(e.name); }
+            var r = new ArrayBuffer(4, { maxByteLength: 8 });
+            try { r.transfer(9); } catch (e) { result.push(e.name, r.detached); }
+            result.push(r.transfer(2).byteLength, r.detached);
+            result.join()",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "true,0,0,8,true,16,7,true,false,12,1,7,0,TypeError,RangeError,false,2,true",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    fn uri_encode_decode() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var s = 'a b&c/ü€😀';
+            var result = [
+                encodeURIComponent(s),
+                encodeURI('http://x.y/a b?q=1&r=ü#h'),
+                decodeURIComponent(encodeURIComponent(s)) === s,
+                decodeURI(encodeURI(s)) === s,
+                decodeURI('%23%20%2f%41'),
+                decodeURIComponent('%23%20%2f%41'),
+                decodeURI('😀%41%F0%9F%98%80'),
+            ];
+            for (var bad of ['%', '%4', '%zz', '%C0%80', '%E2%82', '%E2%82%2A', '%ED%A0%80', '%F8%80%80%80']) {
+                try { decodeURI(bad); result.push('no error'); } catch (e) { result.push(e.name); }
+            }
+            result.join('|')",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "a%20b%26c%2F%C3%BC%E2%82%AC%F0%9F%98%80|http://x.y/a%20b?q=1&r=%C3%BC#h|true|true|%23 %2fA|# /A|😀A😀|URIError|URIError|URIError|URIError|URIError|URIError|URIError|URIError",
+                gc.nogc()
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "annex-b-global")]
+    fn escape_unescape() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var s = 'a b+_@ü€';
+            [escape(s), unescape(escape(s)) === s, unescape('%u20AC%41%zz%4%u12'), unescape('%u004')].join('|')",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(
+            result.unbind(),
+            Value::from_static_str(
+                &mut agent,
+                "a%20b+_@%FC%u20AC|true|\u{20ac}A%zz%4%u12|%u004",
+                gc.nogc()
+            )
+        );
+    }
 }
@@ -11,6 +11,8 @@ use core::{fmt::Debug, ops::Index, ptr::NonNull};
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
+#[cfg(not(feature = "proposal-explicit-resource-management"))]
+use oxc_ast::{AstKind, ast::VariableDeclarationKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::{Parser, ParserReturn};
 use oxc_semantic::{SemanticBuilder, SemanticBuilderReturn};
@@ -109,9 +111,30 @@ impl<'a> SourceCode<'a> {
             return Err(errors);
         }
 
-        let SemanticBuilderReturn { errors, .. } = SemanticBuilder::new()
+        let SemanticBuilderReturn { errors, semantic } = SemanticBuilder::new()
             .with_check_syntax_error(true)
             .build(&program);
+        // Without the explicit resource management proposal, `using` and
+        // `await using` declarations are not part of the grammar.
+        #[cfg(not(feature = "proposal-explicit-resource-management"))]
+        let errors = {
+            let mut errors = errors;
+            errors.extend(semantic.nodes().iter().filter_map(|node| {
+                let AstKind::VariableDeclaration(decl) = node.kind() else {
+                    return None;
+                };
+                matches!(
+                    decl.kind,
+                    VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing
+                )
+                .then(|| {
+                    OxcDiagnostic::error("Using declarations are not supported")
+                        .with_label(decl.span)
+                })
+            }));
+            errors
+        };
+        drop(semantic);
 
         if !errors.is_empty() {
             // Drop program before dropping allocator.
@@ -6,3 +6,4 @@ pub(crate) mod class_definitions;
 pub(crate) mod function_definitions;
 pub(crate) mod miscellaneous;
 pub(crate) mod scope_analysis;
+pub(crate) mod template_literals;
@@ -12,7 +12,7 @@ use crate::{
             ArgumentsList, BuiltinConstructorFunction, ordinary::ordinary_create_from_constructor,
         },
         execution::{Agent, JsResult, ProtoIntrinsics, agent::ExceptionType},
-        types::{Function, InternalMethods, Object},
+        types::{Function, InternalMethods, IntoFunction, Object},
     },
     engine::{
         context::{Bindable, GcScope},
@@ -49,7 +49,13 @@ pub(crate) fn base_class_default_constructor<'a>(
     .bind(gc.nogc());
     let scoped_result = result.scope(agent, gc.nogc());
     // vi. Perform ? InitializeInstanceElements(result, F).
-    initialize_instance_elements(agent, result.unbind(), f.get(agent), gc.reborrow()).unbind()?;
+    initialize_instance_elements(
+        agent,
+        result.unbind(),
+        f.get(agent).into_function(),
+        gc.reborrow(),
+    )
+    .unbind()?;
 
     // vii. Return result.
     Ok(scoped_result.get(agent).bind(gc.into_nogc()))
@@ -98,7 +104,13 @@ pub(crate) fn derived_class_default_constructor<'a>(
     .bind(gc.nogc());
     let scoped_result = result.scope(agent, gc.nogc());
     // vi. Perform ? InitializeInstanceElements(result, F).
-    initialize_instance_elements(agent, result.unbind(), f.get(agent), gc.reborrow()).unbind()?;
+    initialize_instance_elements(
+        agent,
+        result.unbind(),
+        f.get(agent).into_function(),
+        gc.reborrow(),
+    )
+    .unbind()?;
 
     // vii. Return result.
     Ok(scoped_result.get(agent).bind(gc.into_nogc()))
@@ -249,6 +249,7 @@ pub(crate) struct CompileFunctionBodyData<'a> {
     pub(crate) is_strict: bool,
     pub(crate) is_lexical: bool,
     pub(crate) is_concise_body: bool,
+    pub(crate) is_async_generator: bool,
 }
 
 impl CompileFunctionBodyData<'static> {
@@ -267,6 +268,7 @@ impl CompileFunctionBodyData<'static> {
             is_strict: ecmascript_function.strict,
             is_lexical: ecmascript_function.this_mode == ThisMode::Lexical,
             is_concise_body: ecmascript_function.is_concise_arrow_function,
+            is_async_generator: ecmascript_function.is_async && ecmascript_function.is_generator,
         }
     }
 }
@@ -381,6 +383,7 @@ pub(crate) fn evaluate_async_function_body<'a>(
             let handler = PromiseReactionHandler::Await(agent.heap.create(AwaitReaction {
                 vm: Some(vm),
                 async_function: Some(scoped_function_object.get(agent)),
+                async_module: None,
                 execution_context: Some(agent.running_execution_context().clone()),
                 return_promise_capability: promise_capability,
             }));
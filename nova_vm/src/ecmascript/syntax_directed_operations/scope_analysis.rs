@@ -137,9 +137,11 @@ impl<'a> LexicallyDeclaredNames<'a> for Statement<'a> {
             Statement::ExportAllDeclaration(_decl) => {}
             // ModuleItem : ExportDeclaration
             Statement::ExportNamedDeclaration(decl) => {
-                if matches!(decl.declaration, Some(Declaration::VariableDeclaration(_))) {
-                    // 1. If ExportDeclaration is export VariableStatement, return a new empty List.
-                    return;
+                if let Some(Declaration::VariableDeclaration(decl)) = &decl.declaration {
+                    if decl.kind.is_var() {
+                        // 1. If ExportDeclaration is export VariableStatement, return a new empty List.
+                        return;
+                    }
                 }
                 // 2. Return the BoundNames of ExportDeclaration.
                 decl.bound_names(f)
@@ -408,16 +410,27 @@ impl<'a> LexicallyScopedDeclarations<'a> for Statement<'a> {
                 // export NamedExports ;
                 // export VariableStatement
                 // 1. Return a new empty List.
-                if let Some(Declaration::VariableDeclaration(decl)) = &decl.declaration {
-                    if decl.kind.is_var() {
-                        return;
+                match &decl.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => {
+                        if decl.kind.is_var() {
+                            return;
+                        }
+                        // ExportDeclaration : export Declaration
+                        // 1. Return a List whose sole element is DeclarationPart of Declaration.
+                        for decl in &decl.declarations {
+                            f(LexicallyScopedDeclaration::Variable(decl));
+                        }
                     }
-                    // ExportDeclaration : export Declaration
-                    // 1. Return a List whose sole element is DeclarationPart of Declaration.
-                    debug_assert_eq!(decl.declarations.len(), 1);
-                    f(LexicallyScopedDeclaration::Variable(decl.declarations.first().unwrap()));
+                    Some(Declaration::FunctionDeclaration(decl)) => {
+                        f(LexicallyScopedDeclaration::Function(decl));
+                    }
+                    Some(Declaration::ClassDeclaration(decl)) => {
+                        f(LexicallyScopedDeclaration::Class(decl));
+                    }
+                    Some(_) => unreachable!(),
+                    // No declaration means this is NamedExports (possibly in an ExportFromClause)
+                    None => {}
                 }
-                // No declaration means this is NamedExports (possibly in an ExportFromClause)
             },
             Statement::ExportDefaultDeclaration(decl) => {
                 match &decl.declaration {
@@ -426,22 +439,11 @@ impl<'a> LexicallyScopedDeclarations<'a> for Statement<'a> {
                         // 1. Return a List whose sole element is DeclarationPart of HoistableDeclaration.
                         f(LexicallyScopedDeclaration::Function(decl));
                     },
-                    ExportDefaultDeclarationKind::FunctionExpression(expr) => {
-                        f(LexicallyScopedDeclaration::Function(expr));
-                    },
                     // ExportDeclaration : export default ClassDeclaration
                     // 1. Return a List whose sole element is ClassDeclaration.
                     ExportDefaultDeclarationKind::ClassDeclaration(decl) => {
                         f(LexicallyScopedDeclaration::Class(decl));
                     },
-                    ExportDefaultDeclarationKind::ClassExpression(expr) => {
-                        f(LexicallyScopedDeclaration::Class(expr));
-                    }
-                    // ExportDeclaration : export default AssignmentExpression ;
-                    ExportDefaultDeclarationKind::AssignmentExpression(_) => {
-                        // 1. Return a List whose sole element is this ExportDeclaration.
-                        f(LexicallyScopedDeclaration::DefaultExport);
-                    }
                     ExportDefaultDeclarationKind::JSXElement(_) |
                     ExportDefaultDeclarationKind::JSXFragment(_) |
                     ExportDefaultDeclarationKind::TSAsExpression(_) |
@@ -450,7 +452,11 @@ impl<'a> LexicallyScopedDeclarations<'a> for Statement<'a> {
                     ExportDefaultDeclarationKind::TSNonNullExpression(_) |
                     ExportDefaultDeclarationKind::TSSatisfiesExpression(_) |
                     ExportDefaultDeclarationKind::TSTypeAssertion(_) => unreachable!(),
-                    _ => {},
+                    // ExportDeclaration : export default AssignmentExpression ;
+                    _ => {
+                        // 1. Return a List whose sole element is this ExportDeclaration.
+                        f(LexicallyScopedDeclaration::DefaultExport);
+                    },
                 }
             }
             Statement::TSEnumDeclaration(_) |
@@ -0,0 +1,138 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use oxc_ast::ast;
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_objects::{
+            create_array_from_list, define_property_or_throw, integrity::Frozen,
+            set_integrity_level,
+        },
+        builtins::Array,
+        execution::{Agent, TemplateRecord},
+        types::{BUILTIN_STRING_MEMORY, IntoObject, IntoValue, PropertyDescriptor, String, Value},
+    },
+    engine::{
+        context::{Bindable, GcScope},
+        rootable::Scopable,
+    },
+};
+
+/// ### [13.2.8.4 GetTemplateObject ( templateLiteral )](https://tc39.es/ecma262/#sec-gettemplateobject)
+///
+/// The abstract operation GetTemplateObject takes argument templateLiteral (a
+/// Parse Node) and returns an Array.
+pub(crate) fn get_template_object<'a>(
+    agent: &mut Agent,
+    template_literal: &ast::TemplateLiteral,
+    mut gc: GcScope<'a, '_>,
+) -> Array<'a> {
+    let source_code = agent.current_source_code(gc.nogc());
+    let site = template_literal.span.start;
+    // 1. Let realm be the current Realm Record.
+    // 2. Let templateRegistry be realm.[[TemplateMap]].
+    // 3. For each element e of templateRegistry, do
+    //    a. If e.[[Site]] is the same Parse Node as templateLiteral, then
+    if let Some(e) = agent
+        .current_realm_record()
+        .template_map
+        .iter()
+        .find(|e| e.source_code == source_code && e.site == site)
+    {
+        // i. Return e.[[Array]].
+        return e.array.bind(gc.into_nogc());
+    }
+    let source_code = source_code.scope(agent, gc.nogc());
+    // 4. Let rawStrings be the TemplateStrings of templateLiteral with
+    //    argument true.
+    // 5. Assert: rawStrings is a List of Strings.
+    let raw_strings = template_literal
+        .quasis
+        .iter()
+        .map(|quasi| String::from_str(agent, quasi.value.raw.as_str(), gc.nogc()).into_value())
+        .collect::<Vec<_>>();
+    // 6. Let cookedStrings be the TemplateStrings of templateLiteral with
+    //    argument false.
+    let cooked_strings = template_literal
+        .quasis
+        .iter()
+        .map(|quasi| {
+            quasi
+                .value
+                .cooked
+                .as_ref()
+                .map_or(Value::Undefined, |cooked| {
+                    String::from_str(agent, cooked.as_str(), gc.nogc()).into_value()
+                })
+        })
+        .collect::<Vec<_>>();
+    // 7. Let count be the number of elements in the List cookedStrings.
+    // 8. Assert: count ≤ 2**32 - 1.
+    // 9. Let template be ! ArrayCreate(count).
+    // 10. Let rawObj be ! ArrayCreate(count).
+    // 11. Let index be 0.
+    // 12. Repeat, while index < count,
+    //     a. Let prop be ! ToString(𝔽(index)).
+    //     b. Let cookedValue be cookedStrings[index].
+    //     c. Perform ! DefinePropertyOrThrow(template, prop,
+    //        PropertyDescriptor { [[Value]]: cookedValue, [[Writable]]: false,
+    //        [[Enumerable]]: true, [[Configurable]]: false }).
+    //     d. Let rawValue be the String value rawStrings[index].
+    //     e. Perform ! DefinePropertyOrThrow(rawObj, prop,
+    //        PropertyDescriptor { [[Value]]: rawValue, [[Writable]]: false,
+    //        [[Enumerable]]: true, [[Configurable]]: false }).
+    //     f. Set index to index + 1.
+    // Note: The elements are created as ordinary data properties and made
+    // non-writable and non-configurable by the SetIntegrityLevel calls below.
+    let template =
+        create_array_from_list(agent, &cooked_strings, gc.nogc()).scope(agent, gc.nogc());
+    let raw_obj = create_array_from_list(agent, &raw_strings, gc.nogc());
+    // 13. Perform ! SetIntegrityLevel(rawObj, FROZEN).
+    let scoped_raw_obj = raw_obj.scope(agent, gc.nogc());
+    set_integrity_level::<Frozen>(agent, raw_obj.into_object().unbind(), gc.reborrow())
+        .unbind()
+        .unwrap();
+    // 14. Perform ! DefinePropertyOrThrow(template, "raw", PropertyDescriptor {
+    //     [[Value]]: rawObj, [[Writable]]: false, [[Enumerable]]: false,
+    //     [[Configurable]]: false }).
+    let raw_obj = scoped_raw_obj.get(agent).bind(gc.nogc());
+    let template_obj = template.get(agent).bind(gc.nogc());
+    define_property_or_throw(
+        agent,
+        template_obj.unbind(),
+        BUILTIN_STRING_MEMORY.raw.into(),
+        PropertyDescriptor {
+            value: Some(raw_obj.into_value().unbind()),
+            writable: Some(false),
+            get: None,
+            set: None,
+            enumerable: Some(false),
+            configurable: Some(false),
+        },
+        gc.reborrow(),
+    )
+    .unbind()
+    .unwrap();
+    // 15. Perform ! SetIntegrityLevel(template, FROZEN).
+    let template_obj = template.get(agent).bind(gc.nogc());
+    set_integrity_level::<Frozen>(agent, template_obj.into_object().unbind(), gc.reborrow())
+        .unbind()
+        .unwrap();
+    // 16. Append the Record { [[Site]]: templateLiteral, [[Array]]: template }
+    //     to realm.[[TemplateMap]].
+    let gc = gc.into_nogc();
+    let template = template.get(agent).bind(gc);
+    let source_code = source_code.get(agent).bind(gc);
+    agent
+        .current_realm_record_mut()
+        .template_map
+        .push(TemplateRecord {
+            source_code: source_code.unbind(),
+            site,
+            array: template.unbind(),
+        });
+    // 17. Return template.
+    template
+}
@@ -13,3 +13,5 @@ pub use language::{
 };
 pub use spec::PropertyDescriptor;
 pub(crate) use spec::*;
+#[cfg(feature = "shared-array-buffer")]
+pub use spec::{SharedDataBlock, SharedDataBlockCopy, SharedDataBlockCopyMut};
@@ -42,10 +42,10 @@ pub use string::{BUILTIN_STRING_MEMORY, BUILTIN_STRINGS_LIST, HeapString, String
 pub use symbol::{Symbol, SymbolHeapData};
 #[cfg(feature = "date")]
 pub(crate) use value::DATE_DISCRIMINANT;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) use value::DISPOSABLE_STACK_DISCRIMINANT;
 #[cfg(feature = "proposal-float16array")]
 pub(crate) use value::FLOAT_16_ARRAY_DISCRIMINANT;
-#[cfg(feature = "regexp")]
-pub(crate) use value::REGEXP_DISCRIMINANT;
 #[cfg(feature = "shared-array-buffer")]
 pub(crate) use value::SHARED_ARRAY_BUFFER_DISCRIMINANT;
 pub use value::Value;
@@ -71,6 +71,8 @@ pub(crate) use value::{
     UINT_8_ARRAY_DISCRIMINANT, UINT_8_CLAMPED_ARRAY_DISCRIMINANT, UINT_16_ARRAY_DISCRIMINANT,
     UINT_32_ARRAY_DISCRIMINANT,
 };
+#[cfg(feature = "regexp")]
+pub(crate) use value::{REGEXP_DISCRIMINANT, REGEXP_STRING_ITERATOR_DISCRIMINANT};
 #[cfg(feature = "set")]
 pub(crate) use value::{SET_DISCRIMINANT, SET_ITERATOR_DISCRIMINANT};
 #[cfg(feature = "weak-refs")]
@@ -6,23 +6,35 @@ mod data;
 pub mod into_function;
 
 use super::{
+    InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, PropertyKey,
+    String, Value,
     value::{
         BOUND_FUNCTION_DISCRIMINANT, BUILTIN_CONSTRUCTOR_FUNCTION_DISCRIMINANT,
         BUILTIN_FUNCTION_DISCRIMINANT, BUILTIN_GENERATOR_FUNCTION_DISCRIMINANT,
         BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
         ECMASCRIPT_FUNCTION_DISCRIMINANT,
-    }, InternalMethods, IntoObject, IntoValue, Object, OrdinaryObject, InternalSlots, PropertyKey, Value, String
+    },
+};
+use crate::engine::{
+    TryResult,
+    context::{Bindable, GcScope, NoGcScope},
 };
-use crate::engine::{context::{ Bindable, GcScope, NoGcScope}, TryResult};
 use crate::{
     ecmascript::{
         builtins::{
-            bound_function::BoundFunction, control_abstraction_objects::promise_objects::promise_abstract_operations::promise_resolving_functions::BuiltinPromiseResolvingFunction, ArgumentsList, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction
+            ArgumentsList, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction,
+            bound_function::BoundFunction,
+            control_abstraction_objects::promise_objects::promise_abstract_operations::{
+                promise_collector_functions::BuiltinPromiseCollectorFunction,
+                promise_resolving_functions::BuiltinPromiseResolvingFunction,
+            },
         },
         execution::{Agent, JsResult, ProtoIntrinsics},
         types::PropertyDescriptor,
-    }, engine::rootable::{HeapRootData, HeapRootRef, Rootable}, heap::{CompactionLists, HeapMarkAndSweep, WorkQueues}
+    },
+    engine::rootable::{HeapRootData, HeapRootRef, Rootable},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
 pub(crate) use data::*;
@@ -47,7 +59,8 @@ pub enum Function<'a> {
         BUILTIN_CONSTRUCTOR_FUNCTION_DISCRIMINANT,
     BuiltinPromiseResolvingFunction(BuiltinPromiseResolvingFunction<'a>) =
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
-    BuiltinPromiseCollectorFunction = BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
+    BuiltinPromiseCollectorFunction(BuiltinPromiseCollectorFunction<'a>) =
+        BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
     BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
 }
 
@@ -64,7 +77,9 @@ impl core::fmt::Debug for Function<'_> {
             Function::BuiltinPromiseResolvingFunction(d) => {
                 write!(f, "BuiltinPromiseResolvingFunction({d:?})")
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(d) => {
+                write!(f, "BuiltinPromiseCollectorFunction({d:?})")
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -109,8 +124,8 @@ impl<'a> TryFrom<Object<'a>> for Function<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 Ok(Function::BuiltinPromiseResolvingFunction(data))
             }
-            Object::BuiltinPromiseCollectorFunction => {
-                Ok(Function::BuiltinPromiseCollectorFunction)
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                Ok(Function::BuiltinPromiseCollectorFunction(data))
             }
             Object::BuiltinProxyRevokerFunction => Ok(Function::BuiltinProxyRevokerFunction),
             _ => Err(()),
@@ -132,7 +147,9 @@ impl<'a> TryFrom<Value<'a>> for Function<'a> {
             Value::BuiltinPromiseResolvingFunction(data) => {
                 Ok(Function::BuiltinPromiseResolvingFunction(data))
             }
-            Value::BuiltinPromiseCollectorFunction => Ok(Function::BuiltinPromiseCollectorFunction),
+            Value::BuiltinPromiseCollectorFunction(data) => {
+                Ok(Function::BuiltinPromiseCollectorFunction(data))
+            }
             Value::BuiltinProxyRevokerFunction => Ok(Function::BuiltinProxyRevokerFunction),
             _ => Err(()),
         }
@@ -152,7 +169,9 @@ impl<'a> From<Function<'a>> for Object<'a> {
             Function::BuiltinPromiseResolvingFunction(data) => {
                 Object::BuiltinPromiseResolvingFunction(data.unbind())
             }
-            Function::BuiltinPromiseCollectorFunction => Object::BuiltinPromiseCollectorFunction,
+            Function::BuiltinPromiseCollectorFunction(data) => {
+                Object::BuiltinPromiseCollectorFunction(data.unbind())
+            }
             Function::BuiltinProxyRevokerFunction => Object::BuiltinProxyRevokerFunction,
         }
     }
@@ -171,7 +190,9 @@ impl<'a> From<Function<'a>> for Value<'a> {
             Function::BuiltinPromiseResolvingFunction(data) => {
                 Value::BuiltinPromiseResolvingFunction(data.unbind())
             }
-            Function::BuiltinPromiseCollectorFunction => Value::BuiltinPromiseCollectorFunction,
+            Function::BuiltinPromiseCollectorFunction(data) => {
+                Value::BuiltinPromiseCollectorFunction(data.unbind())
+            }
             Function::BuiltinProxyRevokerFunction => Value::BuiltinProxyRevokerFunction,
         }
     }
@@ -186,7 +207,7 @@ impl Function<'_> {
             Function::BuiltinPromiseResolvingFunction(_) => false,
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(_) => true,
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(_) => false,
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -239,7 +260,7 @@ impl<'a> InternalSlots<'a> for Function<'a> {
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(d) => agent[d].object_index,
             Function::BuiltinPromiseResolvingFunction(d) => agent[d].object_index,
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(d) => agent[d].object_index,
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -284,7 +305,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(x) => x.try_get_prototype_of(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_get_prototype_of(agent, gc),
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => x.try_get_prototype_of(agent, gc),
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -304,7 +325,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.try_set_prototype_of(agent, prototype, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.try_set_prototype_of(agent, prototype, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -317,7 +340,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(x) => x.try_is_extensible(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_is_extensible(agent, gc),
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => x.try_is_extensible(agent, gc),
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -330,7 +353,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(x) => x.try_prevent_extensions(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_prevent_extensions(agent, gc),
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => x.try_prevent_extensions(agent, gc),
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -352,7 +375,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.try_get_own_property(agent, property_key, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.try_get_own_property(agent, property_key, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -381,7 +406,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -401,7 +428,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.try_has_property(agent, property_key, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.try_has_property(agent, property_key, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -423,7 +452,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.internal_has_property(agent, property_key, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.internal_has_property(agent, property_key, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -444,7 +475,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.try_get(agent, property_key, receiver, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.try_get(agent, property_key, receiver, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -467,7 +500,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.internal_get(agent, property_key, receiver, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.internal_get(agent, property_key, receiver, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -491,7 +526,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.try_set(agent, property_key, value, receiver, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.try_set(agent, property_key, value, receiver, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -519,7 +556,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.internal_set(agent, property_key, value, receiver, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.internal_set(agent, property_key, value, receiver, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -537,7 +576,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(x) => x.try_delete(agent, property_key, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_delete(agent, property_key, gc),
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => x.try_delete(agent, property_key, gc),
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -554,7 +593,7 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(x) => x.try_own_property_keys(agent, gc),
             Function::BuiltinPromiseResolvingFunction(x) => x.try_own_property_keys(agent, gc),
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => x.try_own_property_keys(agent, gc),
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -577,7 +616,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.internal_call(agent, this_argument, arguments, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.internal_call(agent, this_argument, arguments, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -602,7 +643,9 @@ impl<'a> InternalMethods<'a> for Function<'a> {
             Function::BuiltinPromiseResolvingFunction(x) => {
                 x.internal_construct(agent, arguments, new_target, gc)
             }
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => {
+                x.internal_construct(agent, arguments, new_target, gc)
+            }
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -617,7 +660,7 @@ impl HeapMarkAndSweep for Function<'static> {
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(x) => x.mark_values(queues),
             Function::BuiltinPromiseResolvingFunction(x) => x.mark_values(queues),
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => x.mark_values(queues),
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -630,7 +673,7 @@ impl HeapMarkAndSweep for Function<'static> {
             Function::BuiltinGeneratorFunction => todo!(),
             Function::BuiltinConstructorFunction(x) => x.sweep_values(compactions),
             Function::BuiltinPromiseResolvingFunction(x) => x.sweep_values(compactions),
-            Function::BuiltinPromiseCollectorFunction => todo!(),
+            Function::BuiltinPromiseCollectorFunction(x) => x.sweep_values(compactions),
             Function::BuiltinProxyRevokerFunction => todo!(),
         }
     }
@@ -668,8 +711,8 @@ impl Rootable for Function<'_> {
             Self::BuiltinPromiseResolvingFunction(d) => {
                 Err(HeapRootData::BuiltinPromiseResolvingFunction(d.unbind()))
             }
-            Self::BuiltinPromiseCollectorFunction => {
-                Err(HeapRootData::BuiltinPromiseCollectorFunction)
+            Self::BuiltinPromiseCollectorFunction(d) => {
+                Err(HeapRootData::BuiltinPromiseCollectorFunction(d.unbind()))
             }
             Self::BuiltinProxyRevokerFunction => Err(HeapRootData::BuiltinProxyRevokerFunction),
         }
@@ -705,8 +748,10 @@ impl Rootable for Function<'_> {
                     builtin_promise_resolving_function,
                 ))
             }
-            HeapRootData::BuiltinPromiseCollectorFunction => {
-                Some(Self::BuiltinPromiseCollectorFunction)
+            HeapRootData::BuiltinPromiseCollectorFunction(builtin_promise_collector_function) => {
+                Some(Self::BuiltinPromiseCollectorFunction(
+                    builtin_promise_collector_function,
+                ))
             }
             HeapRootData::BuiltinProxyRevokerFunction => Some(Self::BuiltinProxyRevokerFunction),
             // Note: We use a catch-all here as we expect function variant
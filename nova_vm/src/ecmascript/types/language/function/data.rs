@@ -92,6 +92,9 @@ pub struct ECMAScriptFunctionHeapData<'a> {
     pub(crate) ecmascript_function: ECMAScriptFunctionObjectHeapData<'a>,
     /// Stores the compiled bytecode of an ECMAScript function.
     pub(crate) compiled_bytecode: Option<Executable<'a>>,
+    /// Stores the compiled bytecode of class field initializers of a derived
+    /// class constructor.
+    pub(crate) compiled_initializer_bytecode: Option<Executable<'a>>,
     pub(crate) name: Option<String<'a>>,
 }
 
@@ -15,10 +15,10 @@ use core::hash::Hash;
 
 #[cfg(feature = "date")]
 use super::value::DATE_DISCRIMINANT;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use super::value::DISPOSABLE_STACK_DISCRIMINANT;
 #[cfg(feature = "proposal-float16array")]
 use super::value::FLOAT_16_ARRAY_DISCRIMINANT;
-#[cfg(feature = "regexp")]
-use super::value::REGEXP_DISCRIMINANT;
 #[cfg(feature = "shared-array-buffer")]
 use super::value::SHARED_ARRAY_BUFFER_DISCRIMINANT;
 #[cfg(feature = "array-buffer")]
@@ -29,6 +29,8 @@ use super::value::{
     UINT_8_ARRAY_DISCRIMINANT, UINT_8_CLAMPED_ARRAY_DISCRIMINANT, UINT_16_ARRAY_DISCRIMINANT,
     UINT_32_ARRAY_DISCRIMINANT,
 };
+#[cfg(feature = "regexp")]
+use super::value::{REGEXP_DISCRIMINANT, REGEXP_STRING_ITERATOR_DISCRIMINANT};
 #[cfg(feature = "weak-refs")]
 use super::value::{WEAK_MAP_DISCRIMINANT, WEAK_REF_DISCRIMINANT, WEAK_SET_DISCRIMINANT};
 use super::{
@@ -49,10 +51,14 @@ use super::{
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "regexp")]
+use crate::ecmascript::builtins::text_processing::regexp_objects::regexp_string_iterator_objects::RegExpStringIterator;
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::builtins::{weak_map::WeakMap, weak_ref::WeakRef, weak_set::WeakSet};
 #[cfg(feature = "set")]
@@ -76,7 +82,11 @@ use crate::{
             bound_function::BoundFunction,
             control_abstraction_objects::{
                 generator_objects::Generator,
-                promise_objects::promise_abstract_operations::promise_resolving_functions::BuiltinPromiseResolvingFunction,
+                iteration::async_from_sync_iterator_objects::AsyncFromSyncIterator,
+                promise_objects::promise_abstract_operations::{
+                    promise_collector_functions::BuiltinPromiseCollectorFunction,
+                    promise_resolving_functions::BuiltinPromiseResolvingFunction,
+                },
             },
             embedder_object::EmbedderObject,
             error::Error,
@@ -100,7 +110,8 @@ use crate::{
         rootable::HeapRootData,
     },
     heap::{
-        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues, indexes::ObjectIndex,
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
+        indexes::{IntoBaseIndex, ObjectIndex},
     },
 };
 
@@ -128,7 +139,8 @@ pub enum Object<'a> {
         BUILTIN_CONSTRUCTOR_FUNCTION_DISCRIMINANT,
     BuiltinPromiseResolvingFunction(BuiltinPromiseResolvingFunction<'a>) =
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
-    BuiltinPromiseCollectorFunction = BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
+    BuiltinPromiseCollectorFunction(BuiltinPromiseCollectorFunction<'a>) =
+        BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
     BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
     PrimitiveObject(PrimitiveObject<'a>) = PRIMITIVE_OBJECT_DISCRIMINANT,
     Arguments(OrdinaryObject<'a>) = ARGUMENTS_DISCRIMINANT,
@@ -139,6 +151,8 @@ pub enum Object<'a> {
     DataView(DataView<'a>) = DATA_VIEW_DISCRIMINANT,
     #[cfg(feature = "date")]
     Date(Date<'a>) = DATE_DISCRIMINANT,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack(DisposableStack<'a>) = DISPOSABLE_STACK_DISCRIMINANT,
     Error(Error<'a>) = ERROR_DISCRIMINANT,
     FinalizationRegistry(FinalizationRegistry<'a>) = FINALIZATION_REGISTRY_DISCRIMINANT,
     Map(Map<'a>) = MAP_DISCRIMINANT,
@@ -180,19 +194,21 @@ pub enum Object<'a> {
     Float32Array(TypedArrayIndex<'a>) = FLOAT_32_ARRAY_DISCRIMINANT,
     #[cfg(feature = "array-buffer")]
     Float64Array(TypedArrayIndex<'a>) = FLOAT_64_ARRAY_DISCRIMINANT,
-    AsyncFromSyncIterator = ASYNC_FROM_SYNC_ITERATOR_DISCRIMINANT,
+    AsyncFromSyncIterator(AsyncFromSyncIterator<'a>) = ASYNC_FROM_SYNC_ITERATOR_DISCRIMINANT,
     AsyncGenerator(AsyncGenerator<'a>) = ASYNC_GENERATOR_DISCRIMINANT,
     ArrayIterator(ArrayIterator<'a>) = ARRAY_ITERATOR_DISCRIMINANT,
     #[cfg(feature = "set")]
     SetIterator(SetIterator<'a>) = SET_ITERATOR_DISCRIMINANT,
     MapIterator(MapIterator<'a>) = MAP_ITERATOR_DISCRIMINANT,
     StringIterator(StringIterator<'a>) = STRING_ITERATOR_DISCRIMINANT,
+    #[cfg(feature = "regexp")]
+    RegExpStringIterator(RegExpStringIterator<'a>) = REGEXP_STRING_ITERATOR_DISCRIMINANT,
     Generator(Generator<'a>) = GENERATOR_DISCRIMINANT,
     Module(Module<'a>) = MODULE_DISCRIMINANT,
     EmbedderObject(EmbedderObject<'a>) = EMBEDDER_OBJECT_DISCRIMINANT,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct OrdinaryObject<'a>(pub(crate) ObjectIndex<'a>);
 
 impl<'a> IntoValue<'a> for Object<'a> {
@@ -209,7 +225,9 @@ impl<'a> IntoValue<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 Value::BuiltinPromiseResolvingFunction(data.unbind())
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                Value::BuiltinPromiseCollectorFunction(data.unbind())
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => Value::PrimitiveObject(data.unbind()),
             Object::Arguments(data) => Value::Arguments(data.unbind()),
@@ -220,6 +238,8 @@ impl<'a> IntoValue<'a> for Object<'a> {
             Object::DataView(data) => Value::DataView(data.unbind()),
             #[cfg(feature = "date")]
             Object::Date(data) => Value::Date(data.unbind()),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => Value::DisposableStack(data.unbind()),
             Object::Error(data) => Value::Error(data.unbind()),
             Object::FinalizationRegistry(data) => Value::FinalizationRegistry(data.unbind()),
             Object::Map(data) => Value::Map(data.unbind()),
@@ -261,13 +281,15 @@ impl<'a> IntoValue<'a> for Object<'a> {
             Object::Float32Array(data) => Value::Float32Array(data.unbind()),
             #[cfg(feature = "array-buffer")]
             Object::Float64Array(data) => Value::Float64Array(data.unbind()),
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => Value::AsyncFromSyncIterator(data),
             Object::AsyncGenerator(data) => Value::AsyncGenerator(data),
             Object::ArrayIterator(data) => Value::ArrayIterator(data.unbind()),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => Value::SetIterator(data.unbind()),
             Object::MapIterator(data) => Value::MapIterator(data.unbind()),
             Object::StringIterator(data) => Value::StringIterator(data.unbind()),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => Value::RegExpStringIterator(data.unbind()),
             Object::Generator(data) => Value::Generator(data.unbind()),
             Object::Module(data) => Value::Module(data.unbind()),
             Object::EmbedderObject(data) => Value::EmbedderObject(data.unbind()),
@@ -336,6 +358,12 @@ impl<'a> From<ObjectIndex<'a>> for OrdinaryObject<'a> {
     }
 }
 
+impl<'a> IntoBaseIndex<'a, ObjectHeapData<'static>> for OrdinaryObject<'a> {
+    fn into_base_index(self) -> ObjectIndex<'a> {
+        self.0
+    }
+}
+
 impl<'a> From<OrdinaryObject<'a>> for Value<'a> {
     fn from(value: OrdinaryObject<'a>) -> Self {
         Self::Object(value)
@@ -445,7 +473,9 @@ impl<'a> From<Object<'a>> for Value<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 Value::BuiltinPromiseResolvingFunction(data.unbind())
             }
-            Object::BuiltinPromiseCollectorFunction => Value::BuiltinPromiseCollectorFunction,
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                Value::BuiltinPromiseCollectorFunction(data.unbind())
+            }
             Object::BuiltinProxyRevokerFunction => Value::BuiltinProxyRevokerFunction,
             Object::PrimitiveObject(data) => Value::PrimitiveObject(data.unbind()),
             Object::Arguments(data) => Value::Arguments(data.unbind()),
@@ -456,6 +486,8 @@ impl<'a> From<Object<'a>> for Value<'a> {
             Object::DataView(data) => Value::DataView(data.unbind()),
             #[cfg(feature = "date")]
             Object::Date(data) => Value::Date(data.unbind()),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => Value::DisposableStack(data.unbind()),
             Object::Error(data) => Value::Error(data.unbind()),
             Object::FinalizationRegistry(data) => Value::FinalizationRegistry(data.unbind()),
             Object::Map(data) => Value::Map(data.unbind()),
@@ -497,13 +529,15 @@ impl<'a> From<Object<'a>> for Value<'a> {
             Object::Float32Array(data) => Value::Float32Array(data.unbind()),
             #[cfg(feature = "array-buffer")]
             Object::Float64Array(data) => Value::Float64Array(data.unbind()),
-            Object::AsyncFromSyncIterator => Value::AsyncFromSyncIterator,
+            Object::AsyncFromSyncIterator(data) => Value::AsyncFromSyncIterator(data),
             Object::AsyncGenerator(data) => Value::AsyncGenerator(data),
             Object::ArrayIterator(data) => Value::ArrayIterator(data.unbind()),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => Value::SetIterator(data.unbind()),
             Object::MapIterator(data) => Value::MapIterator(data.unbind()),
             Object::StringIterator(data) => Value::StringIterator(data.unbind()),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => Value::RegExpStringIterator(data.unbind()),
             Object::Generator(data) => Value::Generator(data.unbind()),
             Object::Module(data) => Value::Module(data.unbind()),
             Object::EmbedderObject(data) => Value::EmbedderObject(data.unbind()),
@@ -530,6 +564,8 @@ impl<'a> TryFrom<Value<'a>> for Object<'a> {
             Value::Array(x) => Ok(Object::from(x)),
             #[cfg(feature = "date")]
             Value::Date(x) => Ok(Object::Date(x)),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(x) => Ok(Object::DisposableStack(x)),
             Value::Error(x) => Ok(Object::from(x)),
             Value::BoundFunction(x) => Ok(Object::from(x)),
             Value::BuiltinFunction(x) => Ok(Object::from(x)),
@@ -539,7 +575,9 @@ impl<'a> TryFrom<Value<'a>> for Object<'a> {
             Value::BuiltinPromiseResolvingFunction(data) => {
                 Ok(Object::BuiltinPromiseResolvingFunction(data))
             }
-            Value::BuiltinPromiseCollectorFunction => Ok(Object::BuiltinPromiseCollectorFunction),
+            Value::BuiltinPromiseCollectorFunction(data) => {
+                Ok(Object::BuiltinPromiseCollectorFunction(data))
+            }
             Value::BuiltinProxyRevokerFunction => Ok(Object::BuiltinProxyRevokerFunction),
             Value::PrimitiveObject(data) => Ok(Object::PrimitiveObject(data)),
             Value::Arguments(data) => Ok(Object::Arguments(data)),
@@ -587,13 +625,15 @@ impl<'a> TryFrom<Value<'a>> for Object<'a> {
             Value::Float32Array(data) => Ok(Object::Float32Array(data)),
             #[cfg(feature = "array-buffer")]
             Value::Float64Array(data) => Ok(Object::Float64Array(data)),
-            Value::AsyncFromSyncIterator => Ok(Object::AsyncFromSyncIterator),
+            Value::AsyncFromSyncIterator(data) => Ok(Object::AsyncFromSyncIterator(data)),
             Value::AsyncGenerator(data) => Ok(Object::AsyncGenerator(data)),
             Value::ArrayIterator(data) => Ok(Object::ArrayIterator(data)),
             #[cfg(feature = "set")]
             Value::SetIterator(data) => Ok(Object::SetIterator(data)),
             Value::MapIterator(data) => Ok(Object::MapIterator(data)),
             Value::StringIterator(data) => Ok(Object::StringIterator(data)),
+            #[cfg(feature = "regexp")]
+            Value::RegExpStringIterator(data) => Ok(Object::RegExpStringIterator(data)),
             Value::Generator(data) => Ok(Object::Generator(data)),
             Value::Module(data) => Ok(Object::Module(data)),
             Value::EmbedderObject(data) => Ok(Object::EmbedderObject(data)),
@@ -605,6 +645,144 @@ impl<'a> Object<'a> {
     pub fn property_storage(self) -> PropertyStorage<'a> {
         PropertyStorage::new(self)
     }
+
+    /// Get the object that holds the \[\[PrivateElements]] of this object.
+    /// For exotic objects this is their backing object, which is created if
+    /// `create` is true and the object does not yet have one. Objects that
+    /// cannot have a backing object hold their private elements themselves.
+    ///
+    /// Returns None if the object does not have a backing object and
+    /// `create` is false, or if the object cannot hold private elements.
+    pub(crate) fn private_elements_holder(
+        self,
+        agent: &mut Agent,
+        create: bool,
+    ) -> Option<Object<'static>> {
+        fn private_elements_holder<'a>(
+            data: impl InternalSlots<'a>,
+            agent: &mut Agent,
+            create: bool,
+        ) -> Option<Object<'static>> {
+            if let Some(backing_object) = data.get_backing_object(agent) {
+                Some(backing_object.into_object())
+            } else if create {
+                Some(data.create_backing_object(agent).into_object())
+            } else {
+                None
+            }
+        }
+
+        match self {
+            Object::Object(data) => Some(data.unbind().into_object()),
+            Object::Array(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "array-buffer")]
+            Object::ArrayBuffer(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "date")]
+            Object::Date(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => private_elements_holder(data, agent, create),
+            Object::Error(data) => private_elements_holder(data, agent, create),
+            Object::BoundFunction(data) => private_elements_holder(data, agent, create),
+            Object::BuiltinFunction(data) => private_elements_holder(data, agent, create),
+            Object::ECMAScriptFunction(data) => private_elements_holder(data, agent, create),
+            Object::BuiltinGeneratorFunction => Some(self.unbind()),
+            Object::BuiltinConstructorFunction(data) => {
+                private_elements_holder(data, agent, create)
+            }
+            Object::BuiltinPromiseResolvingFunction(data) => {
+                private_elements_holder(data, agent, create)
+            }
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                private_elements_holder(data, agent, create)
+            }
+            Object::BuiltinProxyRevokerFunction => Some(self.unbind()),
+            Object::PrimitiveObject(data) => private_elements_holder(data, agent, create),
+            Object::Arguments(data) => Some(data.unbind().into_object()),
+            #[cfg(feature = "array-buffer")]
+            Object::DataView(data) => private_elements_holder(data, agent, create),
+            Object::FinalizationRegistry(data) => private_elements_holder(data, agent, create),
+            Object::Map(data) => private_elements_holder(data, agent, create),
+            Object::Promise(data) => private_elements_holder(data, agent, create),
+            // Proxies do not have a backing object.
+            Object::Proxy(_) => Some(self.unbind()),
+            #[cfg(feature = "regexp")]
+            Object::RegExp(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "set")]
+            Object::Set(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "shared-array-buffer")]
+            Object::SharedArrayBuffer(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "weak-refs")]
+            Object::WeakMap(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "weak-refs")]
+            Object::WeakRef(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "weak-refs")]
+            Object::WeakSet(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "array-buffer")]
+            Object::Int8Array(data) => {
+                private_elements_holder(TypedArray::Int8Array(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::Uint8Array(data) => {
+                private_elements_holder(TypedArray::Uint8Array(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::Uint8ClampedArray(data) => {
+                private_elements_holder(TypedArray::Uint8ClampedArray(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::Int16Array(data) => {
+                private_elements_holder(TypedArray::Int16Array(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::Uint16Array(data) => {
+                private_elements_holder(TypedArray::Uint16Array(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::Int32Array(data) => {
+                private_elements_holder(TypedArray::Int32Array(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::Uint32Array(data) => {
+                private_elements_holder(TypedArray::Uint32Array(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::BigInt64Array(data) => {
+                private_elements_holder(TypedArray::BigInt64Array(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::BigUint64Array(data) => {
+                private_elements_holder(TypedArray::BigUint64Array(data), agent, create)
+            }
+            #[cfg(feature = "proposal-float16array")]
+            Object::Float16Array(data) => {
+                private_elements_holder(TypedArray::Float16Array(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::Float32Array(data) => {
+                private_elements_holder(TypedArray::Float32Array(data), agent, create)
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::Float64Array(data) => {
+                private_elements_holder(TypedArray::Float64Array(data), agent, create)
+            }
+            Object::AsyncFromSyncIterator(data) => private_elements_holder(data, agent, create),
+            Object::AsyncGenerator(data) => private_elements_holder(data, agent, create),
+            Object::ArrayIterator(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "set")]
+            Object::SetIterator(data) => private_elements_holder(data, agent, create),
+            Object::MapIterator(data) => private_elements_holder(data, agent, create),
+            Object::StringIterator(data) => private_elements_holder(data, agent, create),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => private_elements_holder(data, agent, create),
+            Object::Generator(data) => private_elements_holder(data, agent, create),
+            // Module namespace objects cannot create a backing object.
+            Object::Module(data) => data
+                .get_backing_object(agent)
+                .map(|backing_object| backing_object.into_object()),
+            // Embedder objects do not have a backing object.
+            Object::EmbedderObject(_) => Some(self.unbind()),
+        }
+    }
 }
 
 impl Hash for Object<'_> {
@@ -618,7 +796,7 @@ impl Hash for Object<'_> {
             Object::BuiltinGeneratorFunction => {}
             Object::BuiltinConstructorFunction(data) => data.get_index().hash(state),
             Object::BuiltinPromiseResolvingFunction(data) => data.get_index().hash(state),
-            Object::BuiltinPromiseCollectorFunction => {}
+            Object::BuiltinPromiseCollectorFunction(data) => data.get_index().hash(state),
             Object::BuiltinProxyRevokerFunction => {}
             Object::PrimitiveObject(data) => data.get_index().hash(state),
             Object::Arguments(data) => data.get_index().hash(state),
@@ -629,6 +807,8 @@ impl Hash for Object<'_> {
             Object::DataView(data) => data.get_index().hash(state),
             #[cfg(feature = "date")]
             Object::Date(data) => data.get_index().hash(state),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.get_index().hash(state),
             Object::Error(data) => data.get_index().hash(state),
             Object::FinalizationRegistry(data) => data.get_index().hash(state),
             Object::Map(data) => data.get_index().hash(state),
@@ -670,13 +850,15 @@ impl Hash for Object<'_> {
             Object::Float32Array(data) => data.into_index().hash(state),
             #[cfg(feature = "array-buffer")]
             Object::Float64Array(data) => data.into_index().hash(state),
-            Object::AsyncFromSyncIterator => {}
+            Object::AsyncFromSyncIterator(data) => data.get_index().hash(state),
             Object::AsyncGenerator(data) => data.get_index().hash(state),
             Object::ArrayIterator(data) => data.get_index().hash(state),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.get_index().hash(state),
             Object::MapIterator(data) => data.get_index().hash(state),
             Object::StringIterator(data) => data.get_index().hash(state),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.get_index().hash(state),
             Object::Generator(data) => data.get_index().hash(state),
             Object::Module(data) => data.get_index().hash(state),
             Object::EmbedderObject(data) => data.get_index().hash(state),
@@ -705,6 +887,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_extensible(agent),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_extensible(agent),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_extensible(agent),
             Object::Error(data) => data.internal_extensible(agent),
             Object::BoundFunction(data) => data.internal_extensible(agent),
             Object::BuiltinFunction(data) => data.internal_extensible(agent),
@@ -712,7 +896,7 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinGeneratorFunction => todo!(),
             Object::BuiltinConstructorFunction(data) => data.internal_extensible(agent),
             Object::BuiltinPromiseResolvingFunction(data) => data.internal_extensible(agent),
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => data.internal_extensible(agent),
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_extensible(agent),
             Object::Arguments(data) => data.internal_extensible(agent),
@@ -764,13 +948,15 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::Float32Array(data) => TypedArray::Float32Array(data).internal_extensible(agent),
             #[cfg(feature = "array-buffer")]
             Object::Float64Array(data) => TypedArray::Float64Array(data).internal_extensible(agent),
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.internal_extensible(agent),
             Object::AsyncGenerator(data) => data.internal_extensible(agent),
             Object::ArrayIterator(data) => data.internal_extensible(agent),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_extensible(agent),
             Object::MapIterator(data) => data.internal_extensible(agent),
             Object::StringIterator(data) => data.internal_extensible(agent),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.internal_extensible(agent),
             Object::Generator(data) => data.internal_extensible(agent),
             Object::Module(data) => data.internal_extensible(agent),
             Object::EmbedderObject(data) => data.internal_extensible(agent),
@@ -785,6 +971,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_set_extensible(agent, value),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_set_extensible(agent, value),
             Object::Error(data) => data.internal_set_extensible(agent, value),
             Object::BoundFunction(data) => data.internal_set_extensible(agent, value),
             Object::BuiltinFunction(idx) => idx.internal_set_extensible(agent, value),
@@ -794,7 +982,9 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_set_extensible(agent, value)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_set_extensible(agent, value)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_set_extensible(agent, value),
             Object::Arguments(data) => data.internal_set_extensible(agent, value),
@@ -864,7 +1054,7 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_set_extensible(agent, value)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.internal_set_extensible(agent, value),
             Object::AsyncGenerator(data) => data.internal_set_extensible(agent, value),
             Object::ArrayIterator(data) => data.internal_set_extensible(agent, value),
             #[cfg(feature = "set")]
@@ -872,6 +1062,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::MapIterator(data) => data.internal_set_extensible(agent, value),
             Object::Generator(data) => data.internal_set_extensible(agent, value),
             Object::StringIterator(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.internal_set_extensible(agent, value),
             Object::Module(data) => data.internal_set_extensible(agent, value),
             Object::EmbedderObject(data) => data.internal_set_extensible(agent, value),
         }
@@ -885,6 +1077,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_prototype(agent),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_prototype(agent),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_prototype(agent),
             Object::Error(data) => data.internal_prototype(agent),
             Object::BoundFunction(data) => data.internal_prototype(agent),
             Object::BuiltinFunction(data) => data.internal_prototype(agent),
@@ -892,7 +1086,7 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinGeneratorFunction => todo!(),
             Object::BuiltinConstructorFunction(data) => data.internal_prototype(agent),
             Object::BuiltinPromiseResolvingFunction(data) => data.internal_prototype(agent),
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => data.internal_prototype(agent),
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_prototype(agent),
             Object::Arguments(data) => data.internal_prototype(agent),
@@ -944,13 +1138,15 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::Float32Array(data) => TypedArray::Float32Array(data).internal_prototype(agent),
             #[cfg(feature = "array-buffer")]
             Object::Float64Array(data) => TypedArray::Float64Array(data).internal_prototype(agent),
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.internal_prototype(agent),
             Object::AsyncGenerator(data) => data.internal_prototype(agent),
             Object::ArrayIterator(data) => data.internal_prototype(agent),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_prototype(agent),
             Object::MapIterator(data) => data.internal_prototype(agent),
             Object::StringIterator(data) => data.internal_prototype(agent),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.internal_prototype(agent),
             Object::Generator(data) => data.internal_prototype(agent),
             Object::Module(data) => data.internal_prototype(agent),
             Object::EmbedderObject(data) => data.internal_prototype(agent),
@@ -965,6 +1161,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_set_prototype(agent, prototype),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_set_prototype(agent, prototype),
             Object::Error(data) => data.internal_set_prototype(agent, prototype),
             Object::BoundFunction(data) => data.internal_set_prototype(agent, prototype),
             Object::BuiltinFunction(data) => data.internal_set_prototype(agent, prototype),
@@ -976,7 +1174,9 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_set_prototype(agent, prototype)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_set_prototype(agent, prototype)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_set_prototype(agent, prototype),
             Object::Arguments(data) => data.internal_set_prototype(agent, prototype),
@@ -1046,13 +1246,15 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_set_prototype(agent, prototype)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.internal_set_prototype(agent, prototype),
             Object::AsyncGenerator(data) => data.internal_set_prototype(agent, prototype),
             Object::ArrayIterator(data) => data.internal_set_prototype(agent, prototype),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_set_prototype(agent, prototype),
             Object::MapIterator(data) => data.internal_set_prototype(agent, prototype),
             Object::StringIterator(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.internal_set_prototype(agent, prototype),
             Object::Generator(data) => data.internal_set_prototype(agent, prototype),
             Object::Module(data) => data.internal_set_prototype(agent, prototype),
             Object::EmbedderObject(data) => data.internal_set_prototype(agent, prototype),
@@ -1073,6 +1275,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_get_prototype_of(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_get_prototype_of(agent, gc),
             Object::Error(data) => data.try_get_prototype_of(agent, gc),
             Object::BoundFunction(data) => data.try_get_prototype_of(agent, gc),
             Object::BuiltinFunction(data) => data.try_get_prototype_of(agent, gc),
@@ -1080,7 +1284,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinGeneratorFunction => todo!(),
             Object::BuiltinConstructorFunction(data) => data.try_get_prototype_of(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.try_get_prototype_of(agent, gc),
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => data.try_get_prototype_of(agent, gc),
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_get_prototype_of(agent, gc),
             Object::Arguments(data) => data.try_get_prototype_of(agent, gc),
@@ -1148,13 +1352,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_get_prototype_of(agent, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.try_get_prototype_of(agent, gc),
             Object::AsyncGenerator(data) => data.try_get_prototype_of(agent, gc),
             Object::ArrayIterator(data) => data.try_get_prototype_of(agent, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_get_prototype_of(agent, gc),
             Object::MapIterator(data) => data.try_get_prototype_of(agent, gc),
             Object::StringIterator(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.try_get_prototype_of(agent, gc),
             Object::Generator(data) => data.try_get_prototype_of(agent, gc),
             Object::Module(data) => data.try_get_prototype_of(agent, gc),
             Object::EmbedderObject(data) => data.try_get_prototype_of(agent, gc),
@@ -1173,6 +1379,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_get_prototype_of(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_get_prototype_of(agent, gc),
             Object::Error(data) => data.internal_get_prototype_of(agent, gc),
             Object::BoundFunction(data) => data.internal_get_prototype_of(agent, gc),
             Object::BuiltinFunction(data) => data.internal_get_prototype_of(agent, gc),
@@ -1182,7 +1390,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_get_prototype_of(agent, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_get_prototype_of(agent, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_get_prototype_of(agent, gc),
             Object::Arguments(data) => data.internal_get_prototype_of(agent, gc),
@@ -1252,13 +1462,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_get_prototype_of(agent, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.internal_get_prototype_of(agent, gc),
             Object::AsyncGenerator(data) => data.internal_get_prototype_of(agent, gc),
             Object::ArrayIterator(data) => data.internal_get_prototype_of(agent, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_get_prototype_of(agent, gc),
             Object::MapIterator(data) => data.internal_get_prototype_of(agent, gc),
             Object::StringIterator(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.internal_get_prototype_of(agent, gc),
             Object::Generator(data) => data.internal_get_prototype_of(agent, gc),
             Object::Module(data) => data.internal_get_prototype_of(agent, gc),
             Object::EmbedderObject(data) => data.internal_get_prototype_of(agent, gc),
@@ -1278,6 +1490,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Error(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::BoundFunction(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::BuiltinFunction(data) => data.try_set_prototype_of(agent, prototype, gc),
@@ -1289,7 +1503,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.try_set_prototype_of(agent, prototype, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.try_set_prototype_of(agent, prototype, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Arguments(data) => data.try_set_prototype_of(agent, prototype, gc),
@@ -1359,13 +1575,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_set_prototype_of(agent, prototype, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::AsyncGenerator(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::ArrayIterator(data) => data.try_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::MapIterator(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::StringIterator(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Generator(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Module(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::EmbedderObject(data) => data.try_set_prototype_of(agent, prototype, gc),
@@ -1385,6 +1603,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::Error(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::BoundFunction(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::BuiltinFunction(data) => data.internal_set_prototype_of(agent, prototype, gc),
@@ -1398,7 +1618,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_set_prototype_of(agent, prototype, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_set_prototype_of(agent, prototype, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::Arguments(data) => data.internal_set_prototype_of(agent, prototype, gc),
@@ -1470,13 +1692,19 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_set_prototype_of(agent, prototype, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => {
+                data.internal_set_prototype_of(agent, prototype, gc)
+            }
             Object::AsyncGenerator(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::ArrayIterator(data) => data.internal_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::MapIterator(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::StringIterator(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => {
+                data.internal_set_prototype_of(agent, prototype, gc)
+            }
             Object::Generator(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::Module(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::EmbedderObject(data) => data.internal_set_prototype_of(agent, prototype, gc),
@@ -1491,6 +1719,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_is_extensible(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_is_extensible(agent, gc),
             Object::Error(data) => data.try_is_extensible(agent, gc),
             Object::BoundFunction(data) => data.try_is_extensible(agent, gc),
             Object::BuiltinFunction(data) => data.try_is_extensible(agent, gc),
@@ -1498,7 +1728,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinGeneratorFunction => todo!(),
             Object::BuiltinConstructorFunction(data) => data.try_is_extensible(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.try_is_extensible(agent, gc),
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => data.try_is_extensible(agent, gc),
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_is_extensible(agent, gc),
             Object::Arguments(data) => data.try_is_extensible(agent, gc),
@@ -1556,13 +1786,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_is_extensible(agent, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.try_is_extensible(agent, gc),
             Object::AsyncGenerator(data) => data.try_is_extensible(agent, gc),
             Object::ArrayIterator(data) => data.try_is_extensible(agent, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_is_extensible(agent, gc),
             Object::MapIterator(data) => data.try_is_extensible(agent, gc),
             Object::StringIterator(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.try_is_extensible(agent, gc),
             Object::Generator(data) => data.try_is_extensible(agent, gc),
             Object::Module(data) => data.try_is_extensible(agent, gc),
             Object::EmbedderObject(data) => data.try_is_extensible(agent, gc),
@@ -1581,6 +1813,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_is_extensible(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_is_extensible(agent, gc),
             Object::Error(data) => data.internal_is_extensible(agent, gc),
             Object::BoundFunction(data) => data.internal_is_extensible(agent, gc),
             Object::BuiltinFunction(data) => data.internal_is_extensible(agent, gc),
@@ -1588,7 +1822,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinGeneratorFunction => todo!(),
             Object::BuiltinConstructorFunction(data) => data.internal_is_extensible(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.internal_is_extensible(agent, gc),
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => data.internal_is_extensible(agent, gc),
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_is_extensible(agent, gc),
             Object::Arguments(data) => data.internal_is_extensible(agent, gc),
@@ -1658,13 +1892,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_is_extensible(agent, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.internal_is_extensible(agent, gc),
             Object::AsyncGenerator(data) => data.internal_is_extensible(agent, gc),
             Object::ArrayIterator(data) => data.internal_is_extensible(agent, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_is_extensible(agent, gc),
             Object::MapIterator(data) => data.internal_is_extensible(agent, gc),
             Object::StringIterator(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.internal_is_extensible(agent, gc),
             Object::Generator(data) => data.internal_is_extensible(agent, gc),
             Object::Module(data) => data.internal_is_extensible(agent, gc),
             Object::EmbedderObject(data) => data.internal_is_extensible(agent, gc),
@@ -1679,6 +1915,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_prevent_extensions(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_prevent_extensions(agent, gc),
             Object::Error(data) => data.try_prevent_extensions(agent, gc),
             Object::BoundFunction(data) => data.try_prevent_extensions(agent, gc),
             Object::BuiltinFunction(data) => data.try_prevent_extensions(agent, gc),
@@ -1686,7 +1924,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinGeneratorFunction => todo!(),
             Object::BuiltinConstructorFunction(data) => data.try_prevent_extensions(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.try_prevent_extensions(agent, gc),
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => data.try_prevent_extensions(agent, gc),
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_prevent_extensions(agent, gc),
             Object::Arguments(data) => data.try_prevent_extensions(agent, gc),
@@ -1756,13 +1994,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_prevent_extensions(agent, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.try_prevent_extensions(agent, gc),
             Object::AsyncGenerator(data) => data.try_prevent_extensions(agent, gc),
             Object::ArrayIterator(data) => data.try_prevent_extensions(agent, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_prevent_extensions(agent, gc),
             Object::MapIterator(data) => data.try_prevent_extensions(agent, gc),
             Object::StringIterator(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.try_prevent_extensions(agent, gc),
             Object::Generator(data) => data.try_prevent_extensions(agent, gc),
             Object::Module(data) => data.try_prevent_extensions(agent, gc),
             Object::EmbedderObject(data) => data.try_prevent_extensions(agent, gc),
@@ -1781,6 +2021,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_prevent_extensions(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_prevent_extensions(agent, gc),
             Object::Error(data) => data.internal_prevent_extensions(agent, gc),
             Object::BoundFunction(data) => data.internal_prevent_extensions(agent, gc),
             Object::BuiltinFunction(data) => data.internal_prevent_extensions(agent, gc),
@@ -1790,7 +2032,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_prevent_extensions(agent, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_prevent_extensions(agent, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_prevent_extensions(agent, gc),
             Object::Arguments(data) => data.internal_prevent_extensions(agent, gc),
@@ -1860,13 +2104,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_prevent_extensions(agent, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.internal_prevent_extensions(agent, gc),
             Object::AsyncGenerator(data) => data.internal_prevent_extensions(agent, gc),
             Object::ArrayIterator(data) => data.internal_prevent_extensions(agent, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_prevent_extensions(agent, gc),
             Object::MapIterator(data) => data.internal_prevent_extensions(agent, gc),
             Object::StringIterator(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.internal_prevent_extensions(agent, gc),
             Object::Generator(data) => data.internal_prevent_extensions(agent, gc),
             Object::Module(data) => data.internal_prevent_extensions(agent, gc),
             Object::EmbedderObject(data) => data.internal_prevent_extensions(agent, gc),
@@ -1886,6 +2132,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_get_own_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_get_own_property(agent, property_key, gc),
             Object::Error(data) => data.try_get_own_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.try_get_own_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.try_get_own_property(agent, property_key, gc),
@@ -1897,7 +2145,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.try_get_own_property(agent, property_key, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.try_get_own_property(agent, property_key, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_get_own_property(agent, property_key, gc),
             Object::Arguments(data) => data.try_get_own_property(agent, property_key, gc),
@@ -1969,13 +2219,19 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_get_own_property(agent, property_key, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => {
+                data.try_get_own_property(agent, property_key, gc)
+            }
             Object::AsyncGenerator(data) => data.try_get_own_property(agent, property_key, gc),
             Object::ArrayIterator(data) => data.try_get_own_property(agent, property_key, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_get_own_property(agent, property_key, gc),
             Object::MapIterator(data) => data.try_get_own_property(agent, property_key, gc),
             Object::StringIterator(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => {
+                data.try_get_own_property(agent, property_key, gc)
+            }
             Object::Generator(data) => data.try_get_own_property(agent, property_key, gc),
             Object::Module(data) => data.try_get_own_property(agent, property_key, gc),
             Object::EmbedderObject(data) => data.try_get_own_property(agent, property_key, gc),
@@ -1995,6 +2251,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_get_own_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => {
+                data.internal_get_own_property(agent, property_key, gc)
+            }
             Object::Error(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => {
@@ -2010,7 +2270,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_get_own_property(agent, property_key, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_get_own_property(agent, property_key, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => {
                 data.internal_get_own_property(agent, property_key, gc)
@@ -2085,13 +2347,19 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_get_own_property(agent, property_key, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => {
+                data.internal_get_own_property(agent, property_key, gc)
+            }
             Object::AsyncGenerator(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::ArrayIterator(data) => data.internal_get_own_property(agent, property_key, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::MapIterator(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::StringIterator(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => {
+                data.internal_get_own_property(agent, property_key, gc)
+            }
             Object::Generator(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::Module(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::EmbedderObject(data) => data.internal_get_own_property(agent, property_key, gc),
@@ -2120,6 +2388,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Date(idx) => {
                 idx.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(idx) => {
+                idx.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Error(idx) => {
                 idx.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2139,7 +2411,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
@@ -2263,7 +2537,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 property_descriptor,
                 gc,
             ),
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => {
+                data.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::AsyncGenerator(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2280,6 +2556,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::StringIterator(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => {
+                data.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Generator(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2314,6 +2594,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Date(idx) => {
                 idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(idx) => {
+                idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Error(idx) => {
                 idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2333,7 +2617,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
@@ -2433,7 +2719,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             #[cfg(feature = "array-buffer")]
             Object::Float64Array(data) => TypedArray::Float64Array(data)
                 .internal_define_own_property(agent, property_key, property_descriptor, gc),
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => {
+                data.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::AsyncGenerator(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2450,6 +2738,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::StringIterator(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => {
+                data.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Generator(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2475,6 +2767,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_has_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_has_property(agent, property_key, gc),
             Object::Error(data) => data.try_has_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.try_has_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.try_has_property(agent, property_key, gc),
@@ -2486,7 +2780,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.try_has_property(agent, property_key, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.try_has_property(agent, property_key, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_has_property(agent, property_key, gc),
             Object::Arguments(data) => data.try_has_property(agent, property_key, gc),
@@ -2556,13 +2852,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_has_property(agent, property_key, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.try_has_property(agent, property_key, gc),
             Object::AsyncGenerator(data) => data.try_has_property(agent, property_key, gc),
             Object::ArrayIterator(data) => data.try_has_property(agent, property_key, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_has_property(agent, property_key, gc),
             Object::MapIterator(data) => data.try_has_property(agent, property_key, gc),
             Object::StringIterator(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.try_has_property(agent, property_key, gc),
             Object::Generator(data) => data.try_has_property(agent, property_key, gc),
             Object::Module(data) => data.try_has_property(agent, property_key, gc),
             Object::EmbedderObject(data) => data.try_has_property(agent, property_key, gc),
@@ -2582,6 +2880,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_has_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_has_property(agent, property_key, gc),
             Object::Error(data) => data.internal_has_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.internal_has_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.internal_has_property(agent, property_key, gc),
@@ -2593,7 +2893,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_has_property(agent, property_key, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_has_property(agent, property_key, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_has_property(agent, property_key, gc),
             Object::Arguments(data) => data.internal_has_property(agent, property_key, gc),
@@ -2665,13 +2967,19 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_has_property(agent, property_key, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => {
+                data.internal_has_property(agent, property_key, gc)
+            }
             Object::AsyncGenerator(data) => data.internal_has_property(agent, property_key, gc),
             Object::ArrayIterator(data) => data.internal_has_property(agent, property_key, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_has_property(agent, property_key, gc),
             Object::MapIterator(data) => data.internal_has_property(agent, property_key, gc),
             Object::StringIterator(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => {
+                data.internal_has_property(agent, property_key, gc)
+            }
             Object::Generator(data) => data.internal_has_property(agent, property_key, gc),
             Object::Module(data) => data.internal_has_property(agent, property_key, gc),
             Object::EmbedderObject(data) => data.internal_has_property(agent, property_key, gc),
@@ -2692,6 +3000,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_get(agent, property_key, receiver, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Error(data) => data.try_get(agent, property_key, receiver, gc),
             Object::BoundFunction(data) => data.try_get(agent, property_key, receiver, gc),
             Object::BuiltinFunction(data) => data.try_get(agent, property_key, receiver, gc),
@@ -2703,7 +3013,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.try_get(agent, property_key, receiver, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.try_get(agent, property_key, receiver, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Arguments(data) => data.try_get(agent, property_key, receiver, gc),
@@ -2773,13 +3085,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_get(agent, property_key, receiver, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.try_get(agent, property_key, receiver, gc),
             Object::AsyncGenerator(data) => data.try_get(agent, property_key, receiver, gc),
             Object::ArrayIterator(data) => data.try_get(agent, property_key, receiver, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_get(agent, property_key, receiver, gc),
             Object::MapIterator(data) => data.try_get(agent, property_key, receiver, gc),
             Object::StringIterator(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Generator(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Module(data) => data.try_get(agent, property_key, receiver, gc),
             Object::EmbedderObject(data) => data.try_get(agent, property_key, receiver, gc),
@@ -2800,6 +3114,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_get(agent, property_key, receiver, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::Error(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::BoundFunction(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::BuiltinFunction(data) => data.internal_get(agent, property_key, receiver, gc),
@@ -2813,7 +3129,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_get(agent, property_key, receiver, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_get(agent, property_key, receiver, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::Arguments(data) => data.internal_get(agent, property_key, receiver, gc),
@@ -2885,13 +3203,19 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_get(agent, property_key, receiver, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => {
+                data.internal_get(agent, property_key, receiver, gc)
+            }
             Object::AsyncGenerator(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::ArrayIterator(data) => data.internal_get(agent, property_key, receiver, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::MapIterator(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::StringIterator(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => {
+                data.internal_get(agent, property_key, receiver, gc)
+            }
             Object::Generator(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::Module(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::EmbedderObject(data) => data.internal_get(agent, property_key, receiver, gc),
@@ -2913,6 +3237,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_set(agent, property_key, value, receiver, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::Error(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::BoundFunction(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::BuiltinFunction(data) => data.try_set(agent, property_key, value, receiver, gc),
@@ -2926,7 +3252,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.try_set(agent, property_key, value, receiver, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.try_set(agent, property_key, value, receiver, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::Arguments(data) => data.try_set(agent, property_key, value, receiver, gc),
@@ -3004,13 +3332,19 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_set(agent, property_key, value, receiver, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => {
+                data.try_set(agent, property_key, value, receiver, gc)
+            }
             Object::AsyncGenerator(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::ArrayIterator(data) => data.try_set(agent, property_key, value, receiver, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::MapIterator(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::StringIterator(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => {
+                data.try_set(agent, property_key, value, receiver, gc)
+            }
             Object::Generator(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::Module(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::EmbedderObject(data) => data.try_set(agent, property_key, value, receiver, gc),
@@ -3034,6 +3368,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             }
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
             Object::Error(data) => data.internal_set(agent, property_key, value, receiver, gc),
             Object::BoundFunction(data) => {
                 data.internal_set(agent, property_key, value, receiver, gc)
@@ -3051,7 +3389,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_set(agent, property_key, value, receiver, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => {
                 data.internal_set(agent, property_key, value, receiver, gc)
@@ -3151,7 +3491,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 receiver,
                 gc,
             ),
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
             Object::AsyncGenerator(data) => {
                 data.internal_set(agent, property_key, value, receiver, gc)
             }
@@ -3168,6 +3510,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::StringIterator(data) => {
                 data.internal_set(agent, property_key, value, receiver, gc)
             }
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
             Object::Generator(data) => data.internal_set(agent, property_key, value, receiver, gc),
             Object::Module(data) => data.internal_set(agent, property_key, value, receiver, gc),
             Object::EmbedderObject(data) => {
@@ -3189,6 +3535,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_delete(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_delete(agent, property_key, gc),
             Object::Error(data) => data.try_delete(agent, property_key, gc),
             Object::BoundFunction(data) => data.try_delete(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.try_delete(agent, property_key, gc),
@@ -3198,7 +3546,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.try_delete(agent, property_key, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.try_delete(agent, property_key, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_delete(agent, property_key, gc),
             Object::Arguments(data) => data.try_delete(agent, property_key, gc),
@@ -3268,13 +3618,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_delete(agent, property_key, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.try_delete(agent, property_key, gc),
             Object::AsyncGenerator(data) => data.try_delete(agent, property_key, gc),
             Object::ArrayIterator(data) => data.try_delete(agent, property_key, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_delete(agent, property_key, gc),
             Object::MapIterator(data) => data.try_delete(agent, property_key, gc),
             Object::StringIterator(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.try_delete(agent, property_key, gc),
             Object::Generator(data) => data.try_delete(agent, property_key, gc),
             Object::Module(data) => data.try_delete(agent, property_key, gc),
             Object::EmbedderObject(data) => data.try_delete(agent, property_key, gc),
@@ -3294,6 +3646,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_delete(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_delete(agent, property_key, gc),
             Object::Error(data) => data.internal_delete(agent, property_key, gc),
             Object::BoundFunction(data) => data.internal_delete(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.internal_delete(agent, property_key, gc),
@@ -3305,7 +3659,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_delete(agent, property_key, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_delete(agent, property_key, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_delete(agent, property_key, gc),
             Object::Arguments(data) => data.internal_delete(agent, property_key, gc),
@@ -3375,13 +3731,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_delete(agent, property_key, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.internal_delete(agent, property_key, gc),
             Object::AsyncGenerator(data) => data.internal_delete(agent, property_key, gc),
             Object::ArrayIterator(data) => data.internal_delete(agent, property_key, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_delete(agent, property_key, gc),
             Object::MapIterator(data) => data.internal_delete(agent, property_key, gc),
             Object::StringIterator(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.internal_delete(agent, property_key, gc),
             Object::Generator(data) => data.internal_delete(agent, property_key, gc),
             Object::Module(data) => data.internal_delete(agent, property_key, gc),
             Object::EmbedderObject(data) => data.internal_delete(agent, property_key, gc),
@@ -3400,6 +3758,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_own_property_keys(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_own_property_keys(agent, gc),
             Object::Error(data) => data.try_own_property_keys(agent, gc),
             Object::BoundFunction(data) => data.try_own_property_keys(agent, gc),
             Object::BuiltinFunction(data) => data.try_own_property_keys(agent, gc),
@@ -3407,7 +3767,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinGeneratorFunction => todo!(),
             Object::BuiltinConstructorFunction(data) => data.try_own_property_keys(agent, gc),
             Object::BuiltinPromiseResolvingFunction(data) => data.try_own_property_keys(agent, gc),
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => data.try_own_property_keys(agent, gc),
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_own_property_keys(agent, gc),
             Object::Arguments(data) => data.try_own_property_keys(agent, gc),
@@ -3475,13 +3835,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).try_own_property_keys(agent, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.try_own_property_keys(agent, gc),
             Object::AsyncGenerator(data) => data.try_own_property_keys(agent, gc),
             Object::ArrayIterator(data) => data.try_own_property_keys(agent, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.try_own_property_keys(agent, gc),
             Object::MapIterator(data) => data.try_own_property_keys(agent, gc),
             Object::StringIterator(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.try_own_property_keys(agent, gc),
             Object::Generator(data) => data.try_own_property_keys(agent, gc),
             Object::Module(data) => data.try_own_property_keys(agent, gc),
             Object::EmbedderObject(data) => data.try_own_property_keys(agent, gc),
@@ -3500,6 +3862,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_own_property_keys(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_own_property_keys(agent, gc),
             Object::Error(data) => data.internal_own_property_keys(agent, gc),
             Object::BoundFunction(data) => data.internal_own_property_keys(agent, gc),
             Object::BuiltinFunction(data) => data.internal_own_property_keys(agent, gc),
@@ -3509,7 +3873,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinPromiseResolvingFunction(data) => {
                 data.internal_own_property_keys(agent, gc)
             }
-            Object::BuiltinPromiseCollectorFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                data.internal_own_property_keys(agent, gc)
+            }
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_own_property_keys(agent, gc),
             Object::Arguments(data) => data.internal_own_property_keys(agent, gc),
@@ -3579,13 +3945,15 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Float64Array(data) => {
                 TypedArray::Float64Array(data).internal_own_property_keys(agent, gc)
             }
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.internal_own_property_keys(agent, gc),
             Object::AsyncGenerator(data) => data.internal_own_property_keys(agent, gc),
             Object::ArrayIterator(data) => data.internal_own_property_keys(agent, gc),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.internal_own_property_keys(agent, gc),
             Object::MapIterator(data) => data.internal_own_property_keys(agent, gc),
             Object::StringIterator(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.internal_own_property_keys(agent, gc),
             Object::Generator(data) => data.internal_own_property_keys(agent, gc),
             Object::Module(data) => data.internal_own_property_keys(agent, gc),
             Object::EmbedderObject(data) => data.internal_own_property_keys(agent, gc),
@@ -3641,15 +4009,17 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::ArrayBuffer(data) => data.mark_values(queues),
             #[cfg(feature = "date")]
             Object::Date(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.mark_values(queues),
             Object::Error(data) => data.mark_values(queues),
             Object::BoundFunction(data) => data.mark_values(queues),
             Object::BuiltinFunction(data) => data.mark_values(queues),
             Object::ECMAScriptFunction(data) => data.mark_values(queues),
-            Object::BuiltinGeneratorFunction => todo!(),
+            Object::BuiltinGeneratorFunction => {}
             Object::BuiltinConstructorFunction(data) => data.mark_values(queues),
             Object::BuiltinPromiseResolvingFunction(data) => data.mark_values(queues),
-            Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => data.mark_values(queues),
+            Object::BuiltinProxyRevokerFunction => {}
             Object::PrimitiveObject(data) => data.mark_values(queues),
             Object::Arguments(data) => data.mark_values(queues),
             #[cfg(feature = "array-buffer")]
@@ -3694,13 +4064,15 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::Float32Array(data) => data.mark_values(queues),
             #[cfg(feature = "array-buffer")]
             Object::Float64Array(data) => data.mark_values(queues),
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.mark_values(queues),
             Object::AsyncGenerator(data) => data.mark_values(queues),
             Object::ArrayIterator(data) => data.mark_values(queues),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.mark_values(queues),
             Object::MapIterator(data) => data.mark_values(queues),
             Object::StringIterator(data) => data.mark_values(queues),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.mark_values(queues),
             Object::Generator(data) => data.mark_values(queues),
             Object::Module(data) => data.mark_values(queues),
             Object::EmbedderObject(data) => data.mark_values(queues),
@@ -3713,11 +4085,11 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::BoundFunction(data) => data.sweep_values(compactions),
             Object::BuiltinFunction(data) => data.sweep_values(compactions),
             Object::ECMAScriptFunction(data) => data.sweep_values(compactions),
-            Object::BuiltinGeneratorFunction => todo!(),
+            Object::BuiltinGeneratorFunction => {}
             Object::BuiltinConstructorFunction(data) => data.sweep_values(compactions),
             Object::BuiltinPromiseResolvingFunction(data) => data.sweep_values(compactions),
-            Object::BuiltinPromiseCollectorFunction => todo!(),
-            Object::BuiltinProxyRevokerFunction => todo!(),
+            Object::BuiltinPromiseCollectorFunction(data) => data.sweep_values(compactions),
+            Object::BuiltinProxyRevokerFunction => {}
             Object::PrimitiveObject(data) => data.sweep_values(compactions),
             Object::Arguments(data) => data.sweep_values(compactions),
             Object::Array(data) => data.sweep_values(compactions),
@@ -3727,6 +4099,8 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::DataView(data) => data.sweep_values(compactions),
             #[cfg(feature = "date")]
             Object::Date(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.sweep_values(compactions),
             Object::Error(data) => data.sweep_values(compactions),
             Object::FinalizationRegistry(data) => data.sweep_values(compactions),
             Object::Map(data) => data.sweep_values(compactions),
@@ -3768,13 +4142,15 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::Float32Array(data) => data.sweep_values(compactions),
             #[cfg(feature = "array-buffer")]
             Object::Float64Array(data) => data.sweep_values(compactions),
-            Object::AsyncFromSyncIterator => todo!(),
+            Object::AsyncFromSyncIterator(data) => data.sweep_values(compactions),
             Object::AsyncGenerator(data) => data.sweep_values(compactions),
             Object::ArrayIterator(data) => data.sweep_values(compactions),
             #[cfg(feature = "set")]
             Object::SetIterator(data) => data.sweep_values(compactions),
             Object::MapIterator(data) => data.sweep_values(compactions),
             Object::StringIterator(data) => data.sweep_values(compactions),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => data.sweep_values(compactions),
             Object::Generator(data) => data.sweep_values(compactions),
             Object::Module(data) => data.sweep_values(compactions),
             Object::EmbedderObject(data) => data.sweep_values(compactions),
@@ -3829,8 +4205,10 @@ impl TryFrom<HeapRootData> for Object<'_> {
                     builtin_promise_resolving_function,
                 ))
             }
-            HeapRootData::BuiltinPromiseCollectorFunction => {
-                Ok(Self::BuiltinPromiseCollectorFunction)
+            HeapRootData::BuiltinPromiseCollectorFunction(builtin_promise_collector_function) => {
+                Ok(Self::BuiltinPromiseCollectorFunction(
+                    builtin_promise_collector_function,
+                ))
             }
             HeapRootData::BuiltinProxyRevokerFunction => Ok(Self::BuiltinProxyRevokerFunction),
             HeapRootData::PrimitiveObject(primitive_object) => {
@@ -3844,6 +4222,10 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::DataView(data_view) => Ok(Self::DataView(data_view)),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => Ok(Self::Date(date)),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            HeapRootData::DisposableStack(disposable_stack) => {
+                Ok(Self::DisposableStack(disposable_stack))
+            }
             HeapRootData::Error(error) => Ok(Self::Error(error)),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 Ok(Self::FinalizationRegistry(finalization_registry))
@@ -3889,22 +4271,30 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::Float32Array(base_index) => Ok(Self::Float32Array(base_index)),
             #[cfg(feature = "array-buffer")]
             HeapRootData::Float64Array(base_index) => Ok(Self::Float64Array(base_index)),
-            HeapRootData::AsyncFromSyncIterator => Ok(Self::AsyncFromSyncIterator),
+            HeapRootData::AsyncFromSyncIterator(data) => Ok(Self::AsyncFromSyncIterator(data)),
             HeapRootData::AsyncGenerator(r#gen) => Ok(Self::AsyncGenerator(r#gen)),
             HeapRootData::ArrayIterator(array_iterator) => Ok(Self::ArrayIterator(array_iterator)),
             #[cfg(feature = "set")]
             HeapRootData::SetIterator(set_iterator) => Ok(Self::SetIterator(set_iterator)),
             HeapRootData::MapIterator(map_iterator) => Ok(Self::MapIterator(map_iterator)),
             HeapRootData::StringIterator(map_iterator) => Ok(Self::StringIterator(map_iterator)),
+            #[cfg(feature = "regexp")]
+            HeapRootData::RegExpStringIterator(map_iterator) => {
+                Ok(Self::RegExpStringIterator(map_iterator))
+            }
             HeapRootData::Generator(generator) => Ok(Self::Generator(generator)),
             HeapRootData::Module(module) => Ok(Self::Module(module)),
             HeapRootData::EmbedderObject(embedder_object) => {
                 Ok(Self::EmbedderObject(embedder_object))
             }
-            HeapRootData::PromiseReaction(_)
+            HeapRootData::GraphLoadingState(_)
+            | HeapRootData::PromiseGroup(_)
+            | HeapRootData::PromiseReaction(_)
             | HeapRootData::Executable(_)
             | HeapRootData::Realm(_)
             | HeapRootData::Script(_)
+            | HeapRootData::SourceTextModule(_)
+            | HeapRootData::SyntheticModule(_)
             | HeapRootData::SourceCode(_)
             | HeapRootData::DeclarativeEnvironment(_)
             | HeapRootData::FunctionEnvironment(_)
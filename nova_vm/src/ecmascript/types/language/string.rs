@@ -30,7 +30,7 @@ use crate::{
 };
 
 pub use data::StringHeapData;
-use wtf8::Wtf8Buf;
+use wtf8::{Wtf8, Wtf8Buf};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -474,6 +474,16 @@ impl<'a> String<'a> {
         }
     }
 
+    pub fn as_wtf8<'string, 'agent: 'string>(
+        &'string self,
+        agent: &'agent impl Index<HeapString<'static>, Output = StringHeapData>,
+    ) -> &'string Wtf8 {
+        match self {
+            String::String(s) => agent[s.unbind()].as_wtf8(),
+            String::SmallString(s) => Wtf8::from_str(s.as_str()),
+        }
+    }
+
     /// If x and y have the same length and the same code units in the same
     /// positions, return true; otherwise, return false.
     pub fn eq(
@@ -13,10 +13,14 @@ use super::{
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "regexp")]
+use crate::ecmascript::builtins::text_processing::regexp_objects::regexp_string_iterator_objects::RegExpStringIterator;
 #[cfg(feature = "set")]
 use crate::ecmascript::builtins::{
     keyed_collections::set_objects::set_iterator_objects::set_iterator::SetIterator, set::Set,
@@ -36,7 +40,11 @@ use crate::{
             bound_function::BoundFunction,
             control_abstraction_objects::{
                 generator_objects::Generator,
-                promise_objects::promise_abstract_operations::promise_resolving_functions::BuiltinPromiseResolvingFunction,
+                iteration::async_from_sync_iterator_objects::AsyncFromSyncIterator,
+                promise_objects::promise_abstract_operations::{
+                    promise_collector_functions::BuiltinPromiseCollectorFunction,
+                    promise_resolving_functions::BuiltinPromiseResolvingFunction,
+                },
             },
             embedder_object::EmbedderObject,
             error::Error,
@@ -141,7 +149,7 @@ pub enum Value<'a> {
     /// [ClassDefinitionEvaluation](https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation).
     BuiltinConstructorFunction(BuiltinConstructorFunction<'a>),
     BuiltinPromiseResolvingFunction(BuiltinPromiseResolvingFunction<'a>),
-    BuiltinPromiseCollectorFunction,
+    BuiltinPromiseCollectorFunction(BuiltinPromiseCollectorFunction<'a>),
     BuiltinProxyRevokerFunction,
 
     // Boolean, Number, String, Symbol, BigInt objects
@@ -165,6 +173,8 @@ pub enum Value<'a> {
     DataView(DataView<'a>),
     #[cfg(feature = "date")]
     Date(Date<'a>),
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack(DisposableStack<'a>),
     Error(Error<'a>),
     FinalizationRegistry(FinalizationRegistry<'a>),
     Map(Map<'a>),
@@ -210,14 +220,15 @@ pub enum Value<'a> {
     Float64Array(TypedArrayIndex<'a>),
 
     // Iterator objects
-    // TODO: Figure out if these are needed at all.
-    AsyncFromSyncIterator,
+    AsyncFromSyncIterator(AsyncFromSyncIterator<'a>),
     AsyncGenerator(AsyncGenerator<'a>),
     ArrayIterator(ArrayIterator<'a>),
     #[cfg(feature = "set")]
     SetIterator(SetIterator<'a>),
     MapIterator(MapIterator<'a>),
     StringIterator(StringIterator<'a>),
+    #[cfg(feature = "regexp")]
+    RegExpStringIterator(RegExpStringIterator<'a>),
     Generator(Generator<'a>),
 
     // ECMAScript Module
@@ -268,6 +279,9 @@ pub(crate) const ARRAY_BUFFER_DISCRIMINANT: u8 =
     value_discriminant(Value::ArrayBuffer(ArrayBuffer::_def()));
 #[cfg(feature = "date")]
 pub(crate) const DATE_DISCRIMINANT: u8 = value_discriminant(Value::Date(Date::_def()));
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) const DISPOSABLE_STACK_DISCRIMINANT: u8 =
+    value_discriminant(Value::DisposableStack(DisposableStack::_def()));
 pub(crate) const ERROR_DISCRIMINANT: u8 = value_discriminant(Value::Error(Error::_def()));
 pub(crate) const BUILTIN_FUNCTION_DISCRIMINANT: u8 =
     value_discriminant(Value::BuiltinFunction(BuiltinFunction::_def()));
@@ -286,8 +300,9 @@ pub(crate) const BUILTIN_CONSTRUCTOR_FUNCTION_DISCRIMINANT: u8 = value_discrimin
 pub(crate) const BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT: u8 = value_discriminant(
     Value::BuiltinPromiseResolvingFunction(BuiltinPromiseResolvingFunction::_def()),
 );
-pub(crate) const BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT: u8 =
-    value_discriminant(Value::BuiltinPromiseCollectorFunction);
+pub(crate) const BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT: u8 = value_discriminant(
+    Value::BuiltinPromiseCollectorFunction(BuiltinPromiseCollectorFunction::_def()),
+);
 pub(crate) const BUILTIN_PROXY_REVOKER_FUNCTION: u8 =
     value_discriminant(Value::BuiltinProxyRevokerFunction);
 pub(crate) const PRIMITIVE_OBJECT_DISCRIMINANT: u8 =
@@ -349,7 +364,7 @@ pub(crate) const FLOAT_32_ARRAY_DISCRIMINANT: u8 =
 pub(crate) const FLOAT_64_ARRAY_DISCRIMINANT: u8 =
     value_discriminant(Value::Float64Array(TypedArrayIndex::from_u32_index(0)));
 pub(crate) const ASYNC_FROM_SYNC_ITERATOR_DISCRIMINANT: u8 =
-    value_discriminant(Value::AsyncFromSyncIterator);
+    value_discriminant(Value::AsyncFromSyncIterator(AsyncFromSyncIterator::_def()));
 pub(crate) const ASYNC_GENERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::AsyncGenerator(AsyncGenerator::_def()));
 pub(crate) const ARRAY_ITERATOR_DISCRIMINANT: u8 =
@@ -361,6 +376,9 @@ pub(crate) const MAP_ITERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::MapIterator(MapIterator::_def()));
 pub(crate) const STRING_ITERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::StringIterator(StringIterator::_def()));
+#[cfg(feature = "regexp")]
+pub(crate) const REGEXP_STRING_ITERATOR_DISCRIMINANT: u8 =
+    value_discriminant(Value::RegExpStringIterator(RegExpStringIterator::_def()));
 pub(crate) const GENERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::Generator(Generator::_def()));
 pub(crate) const MODULE_DISCRIMINANT: u8 = value_discriminant(Value::Module(Module::_def()));
@@ -717,7 +735,10 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
-            Value::BuiltinPromiseCollectorFunction => todo!(),
+            Value::BuiltinPromiseCollectorFunction(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::BuiltinProxyRevokerFunction => todo!(),
             Value::PrimitiveObject(data) => {
                 discriminant.hash(hasher);
@@ -746,6 +767,11 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Error(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -856,7 +882,10 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.into_index().hash(hasher);
             }
-            Value::AsyncFromSyncIterator => todo!(),
+            Value::AsyncFromSyncIterator(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::AsyncGenerator(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -878,6 +907,11 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "regexp")]
+            Value::RegExpStringIterator(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Generator(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -951,7 +985,10 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
-            Value::BuiltinPromiseCollectorFunction => todo!(),
+            Value::BuiltinPromiseCollectorFunction(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::BuiltinProxyRevokerFunction => todo!(),
             Value::PrimitiveObject(data) => {
                 discriminant.hash(hasher);
@@ -980,6 +1017,11 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Error(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -1090,7 +1132,10 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.into_index().hash(hasher);
             }
-            Value::AsyncFromSyncIterator => todo!(),
+            Value::AsyncFromSyncIterator(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::AsyncGenerator(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -1112,6 +1157,11 @@ impl<'a> Value<'a> {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "regexp")]
+            Value::RegExpStringIterator(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Generator(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -1266,8 +1316,10 @@ impl Rootable for Value<'_> {
                     builtin_promise_resolving_function.unbind(),
                 ))
             }
-            Self::BuiltinPromiseCollectorFunction => {
-                Err(HeapRootData::BuiltinPromiseCollectorFunction)
+            Self::BuiltinPromiseCollectorFunction(builtin_promise_collector_function) => {
+                Err(HeapRootData::BuiltinPromiseCollectorFunction(
+                    builtin_promise_collector_function.unbind(),
+                ))
             }
             Self::BuiltinProxyRevokerFunction => Err(HeapRootData::BuiltinProxyRevokerFunction),
             Self::PrimitiveObject(primitive_object) => {
@@ -1285,6 +1337,10 @@ impl Rootable for Value<'_> {
             Self::DataView(data_view) => Err(HeapRootData::DataView(data_view.unbind())),
             #[cfg(feature = "date")]
             Self::Date(date) => Err(HeapRootData::Date(date.unbind())),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Self::DisposableStack(disposable_stack) => {
+                Err(HeapRootData::DisposableStack(disposable_stack.unbind()))
+            }
             Self::Error(error) => Err(HeapRootData::Error(error.unbind())),
             Self::FinalizationRegistry(finalization_registry) => Err(
                 HeapRootData::FinalizationRegistry(finalization_registry.unbind()),
@@ -1336,7 +1392,9 @@ impl Rootable for Value<'_> {
             }
             #[cfg(feature = "array-buffer")]
             Self::Float64Array(base_index) => Err(HeapRootData::Float64Array(base_index.unbind())),
-            Self::AsyncFromSyncIterator => Err(HeapRootData::AsyncFromSyncIterator),
+            Self::AsyncFromSyncIterator(data) => {
+                Err(HeapRootData::AsyncFromSyncIterator(data.unbind()))
+            }
             Self::AsyncGenerator(r#gen) => Err(HeapRootData::AsyncGenerator(r#gen.unbind())),
 
             Self::ArrayIterator(array_iterator) => {
@@ -1353,6 +1411,10 @@ impl Rootable for Value<'_> {
             Self::StringIterator(generator) => {
                 Err(HeapRootData::StringIterator(generator.unbind()))
             }
+            #[cfg(feature = "regexp")]
+            Self::RegExpStringIterator(generator) => {
+                Err(HeapRootData::RegExpStringIterator(generator.unbind()))
+            }
             Self::Module(module) => Err(HeapRootData::Module(module.unbind())),
             Self::EmbedderObject(embedder_object) => {
                 Err(HeapRootData::EmbedderObject(embedder_object.unbind()))
@@ -1404,8 +1466,10 @@ impl Rootable for Value<'_> {
                     builtin_promise_resolving_function,
                 ))
             }
-            HeapRootData::BuiltinPromiseCollectorFunction => {
-                Some(Self::BuiltinPromiseCollectorFunction)
+            HeapRootData::BuiltinPromiseCollectorFunction(builtin_promise_collector_function) => {
+                Some(Self::BuiltinPromiseCollectorFunction(
+                    builtin_promise_collector_function,
+                ))
             }
             HeapRootData::BuiltinProxyRevokerFunction => Some(Self::BuiltinProxyRevokerFunction),
             HeapRootData::PrimitiveObject(primitive_object) => {
@@ -1419,6 +1483,10 @@ impl Rootable for Value<'_> {
             HeapRootData::DataView(data_view) => Some(Self::DataView(data_view)),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => Some(Self::Date(date)),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            HeapRootData::DisposableStack(disposable_stack) => {
+                Some(Self::DisposableStack(disposable_stack))
+            }
             HeapRootData::Error(error) => Some(Self::Error(error)),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 Some(Self::FinalizationRegistry(finalization_registry))
@@ -1466,7 +1534,7 @@ impl Rootable for Value<'_> {
             HeapRootData::Float32Array(base_index) => Some(Self::Float32Array(base_index)),
             #[cfg(feature = "array-buffer")]
             HeapRootData::Float64Array(base_index) => Some(Self::Float64Array(base_index)),
-            HeapRootData::AsyncFromSyncIterator => Some(Self::AsyncFromSyncIterator),
+            HeapRootData::AsyncFromSyncIterator(data) => Some(Self::AsyncFromSyncIterator(data)),
             HeapRootData::AsyncGenerator(r#gen) => Some(Self::AsyncGenerator(r#gen)),
 
             HeapRootData::ArrayIterator(array_iterator) => {
@@ -1476,6 +1544,10 @@ impl Rootable for Value<'_> {
             HeapRootData::SetIterator(set_iterator) => Some(Self::SetIterator(set_iterator)),
             HeapRootData::MapIterator(map_iterator) => Some(Self::MapIterator(map_iterator)),
             HeapRootData::StringIterator(generator) => Some(Self::StringIterator(generator)),
+            #[cfg(feature = "regexp")]
+            HeapRootData::RegExpStringIterator(generator) => {
+                Some(Self::RegExpStringIterator(generator))
+            }
             HeapRootData::Generator(generator) => Some(Self::Generator(generator)),
             HeapRootData::Module(module) => Some(Self::Module(module)),
             HeapRootData::EmbedderObject(embedder_object) => {
@@ -1484,7 +1556,11 @@ impl Rootable for Value<'_> {
             HeapRootData::Executable(_)
             | HeapRootData::Realm(_)
             | HeapRootData::Script(_)
+            | HeapRootData::SourceTextModule(_)
+            | HeapRootData::SyntheticModule(_)
             | HeapRootData::SourceCode(_)
+            | HeapRootData::GraphLoadingState(_)
+            | HeapRootData::PromiseGroup(_)
             | HeapRootData::PromiseReaction(_)
             | HeapRootData::DeclarativeEnvironment(_)
             | HeapRootData::FunctionEnvironment(_)
@@ -1533,6 +1609,8 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::ArrayBuffer(data) => data.mark_values(queues),
             #[cfg(feature = "date")]
             Value::Date(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(data) => data.mark_values(queues),
             Value::Error(data) => data.mark_values(queues),
             Value::BoundFunction(data) => data.mark_values(queues),
             Value::BuiltinFunction(data) => data.mark_values(queues),
@@ -1584,15 +1662,17 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::BuiltinGeneratorFunction => todo!(),
             Value::BuiltinConstructorFunction(data) => data.mark_values(queues),
             Value::BuiltinPromiseResolvingFunction(data) => data.mark_values(queues),
-            Value::BuiltinPromiseCollectorFunction => todo!(),
+            Value::BuiltinPromiseCollectorFunction(data) => data.mark_values(queues),
             Value::BuiltinProxyRevokerFunction => todo!(),
-            Value::AsyncFromSyncIterator => todo!(),
+            Value::AsyncFromSyncIterator(data) => data.mark_values(queues),
             Value::AsyncGenerator(data) => data.mark_values(queues),
             Value::ArrayIterator(data) => data.mark_values(queues),
             #[cfg(feature = "set")]
             Value::SetIterator(data) => data.mark_values(queues),
             Value::MapIterator(data) => data.mark_values(queues),
             Value::StringIterator(data) => data.mark_values(queues),
+            #[cfg(feature = "regexp")]
+            Value::RegExpStringIterator(data) => data.mark_values(queues),
             Value::Generator(data) => data.mark_values(queues),
             Value::Module(data) => data.mark_values(queues),
             Value::EmbedderObject(data) => data.mark_values(queues),
@@ -1620,6 +1700,8 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::ArrayBuffer(data) => data.sweep_values(compactions),
             #[cfg(feature = "date")]
             Value::Date(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(data) => data.sweep_values(compactions),
             Value::Error(data) => data.sweep_values(compactions),
             Value::BoundFunction(data) => data.sweep_values(compactions),
             Value::BuiltinFunction(data) => data.sweep_values(compactions),
@@ -1671,15 +1753,17 @@ impl HeapMarkAndSweep for Value<'static> {
             Value::BuiltinGeneratorFunction => todo!(),
             Value::BuiltinConstructorFunction(data) => data.sweep_values(compactions),
             Value::BuiltinPromiseResolvingFunction(data) => data.sweep_values(compactions),
-            Value::BuiltinPromiseCollectorFunction => todo!(),
+            Value::BuiltinPromiseCollectorFunction(data) => data.sweep_values(compactions),
             Value::BuiltinProxyRevokerFunction => todo!(),
-            Value::AsyncFromSyncIterator => todo!(),
+            Value::AsyncFromSyncIterator(data) => data.sweep_values(compactions),
             Value::AsyncGenerator(data) => data.sweep_values(compactions),
             Value::ArrayIterator(data) => data.sweep_values(compactions),
             #[cfg(feature = "set")]
             Value::SetIterator(data) => data.sweep_values(compactions),
             Value::MapIterator(data) => data.sweep_values(compactions),
             Value::StringIterator(data) => data.sweep_values(compactions),
+            #[cfg(feature = "regexp")]
+            Value::RegExpStringIterator(data) => data.sweep_values(compactions),
             Value::Generator(data) => data.sweep_values(compactions),
             Value::Module(data) => data.sweep_values(compactions),
             Value::EmbedderObject(data) => data.sweep_values(compactions),
@@ -3,9 +3,15 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod data_block;
+mod private_element;
+mod private_name;
 mod property_descriptor;
 mod reference;
 #[cfg(feature = "array-buffer")]
 pub(crate) use data_block::*;
+#[cfg(feature = "shared-array-buffer")]
+pub use data_block::{SharedDataBlock, SharedDataBlockCopy, SharedDataBlockCopyMut};
+pub(crate) use private_element::{PrivateElement, PrivateElementKind, push_private_method};
+pub(crate) use private_name::PrivateName;
 pub use property_descriptor::PropertyDescriptor;
 pub(crate) use reference::*;
@@ -8,6 +8,7 @@ use core::{
     mem::MaybeUninit,
     ops::{Deref, DerefMut},
     ptr::{self, NonNull, read_unaligned, write_unaligned},
+    sync::atomic::{AtomicU8, AtomicU16, AtomicU32, AtomicU64, Ordering},
 };
 use std::{
     alloc::{Layout, alloc_zeroed, dealloc, handle_alloc_error, realloc},
@@ -31,6 +32,12 @@ use crate::{
 
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::execution::ProtoIntrinsics;
+#[cfg(feature = "atomics")]
+use crate::ecmascript::execution::WaiterList;
+#[cfg(feature = "shared-array-buffer")]
+use core::sync::atomic::AtomicUsize;
+#[cfg(feature = "shared-array-buffer")]
+use std::sync::Arc;
 
 /// Sentinel pointer for a detached data block.
 ///
@@ -914,6 +921,108 @@ impl Viewable for f64 {
     }
 }
 
+/// A read-modify-write modification function of the Atomics object.
+///
+/// The spec defines these as Abstract Closures over lists of byte values;
+/// here they are performed directly by the matching atomic instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReadModifyWriteOp {
+    Add,
+    And,
+    Exchange,
+    Or,
+    Sub,
+    Xor,
+}
+
+/// Sequentially consistent atomic operations on the integer bits of a
+/// Viewable, performed through a raw pointer.
+trait AtomicBits {
+    /// ## Safety
+    ///
+    /// `ptr` must be valid for reads and aligned to the size of the atomic.
+    unsafe fn load_bits(ptr: *mut u8) -> u64;
+    /// ## Safety
+    ///
+    /// `ptr` must be valid for writes and aligned to the size of the atomic.
+    unsafe fn store_bits(ptr: *mut u8, bits: u64);
+    /// ## Safety
+    ///
+    /// `ptr` must be valid for reads and writes and aligned to the size of
+    /// the atomic.
+    unsafe fn modify_bits(ptr: *mut u8, op: ReadModifyWriteOp, bits: u64) -> u64;
+    /// ## Safety
+    ///
+    /// `ptr` must be valid for reads and writes and aligned to the size of
+    /// the atomic.
+    unsafe fn compare_exchange_bits(ptr: *mut u8, expected: u64, replacement: u64) -> u64;
+}
+
+macro_rules! impl_atomic_bits {
+    ($atomic:ty, $int:ty) => {
+        impl AtomicBits for $atomic {
+            unsafe fn load_bits(ptr: *mut u8) -> u64 {
+                // SAFETY: Caller guarantees that ptr is valid and aligned.
+                let atomic = unsafe { <$atomic>::from_ptr(ptr.cast()) };
+                atomic.load(Ordering::SeqCst) as u64
+            }
+
+            unsafe fn store_bits(ptr: *mut u8, bits: u64) {
+                // SAFETY: Caller guarantees that ptr is valid and aligned.
+                let atomic = unsafe { <$atomic>::from_ptr(ptr.cast()) };
+                atomic.store(bits as $int, Ordering::SeqCst);
+            }
+
+            unsafe fn modify_bits(ptr: *mut u8, op: ReadModifyWriteOp, bits: u64) -> u64 {
+                // SAFETY: Caller guarantees that ptr is valid and aligned.
+                let atomic = unsafe { <$atomic>::from_ptr(ptr.cast()) };
+                let value = bits as $int;
+                let previous = match op {
+                    ReadModifyWriteOp::Add => atomic.fetch_add(value, Ordering::SeqCst),
+                    ReadModifyWriteOp::And => atomic.fetch_and(value, Ordering::SeqCst),
+                    ReadModifyWriteOp::Exchange => atomic.swap(value, Ordering::SeqCst),
+                    ReadModifyWriteOp::Or => atomic.fetch_or(value, Ordering::SeqCst),
+                    ReadModifyWriteOp::Sub => atomic.fetch_sub(value, Ordering::SeqCst),
+                    ReadModifyWriteOp::Xor => atomic.fetch_xor(value, Ordering::SeqCst),
+                };
+                previous as u64
+            }
+
+            unsafe fn compare_exchange_bits(ptr: *mut u8, expected: u64, replacement: u64) -> u64 {
+                // SAFETY: Caller guarantees that ptr is valid and aligned.
+                let atomic = unsafe { <$atomic>::from_ptr(ptr.cast()) };
+                match atomic.compare_exchange(
+                    expected as $int,
+                    replacement as $int,
+                    Ordering::SeqCst,
+                    Ordering::SeqCst,
+                ) {
+                    Ok(previous) | Err(previous) => previous as u64,
+                }
+            }
+        }
+    };
+}
+
+impl_atomic_bits!(AtomicU8, u8);
+impl_atomic_bits!(AtomicU16, u16);
+impl_atomic_bits!(AtomicU32, u32);
+impl_atomic_bits!(AtomicU64, u64);
+
+/// Calls an [AtomicBits] method on the atomic type matching the size of the
+/// Viewable `T`.
+macro_rules! with_atomic_bits {
+    ($t:ty, $method:ident($($arg:expr),*)) => {
+        match core::mem::size_of::<$t>() {
+            1 => <AtomicU8 as AtomicBits>::$method($($arg),*),
+            2 => <AtomicU16 as AtomicBits>::$method($($arg),*),
+            4 => <AtomicU32 as AtomicBits>::$method($($arg),*),
+            8 => <AtomicU64 as AtomicBits>::$method($($arg),*),
+            _ => unreachable!(),
+        }
+    };
+}
+
 impl DataBlock {
     /// Sentinel value for detached DataBlocks.
     ///
@@ -1090,6 +1199,76 @@ impl DataBlock {
         }
     }
 
+    /// Get a pointer to an integer Viewable at the given byte offset for
+    /// atomic access, or None if the Viewable does not fit in the DataBlock.
+    fn atomic_ptr<T: Viewable>(&self, byte_offset: usize) -> Option<*mut u8> {
+        debug_assert!(!T::IS_FLOAT);
+        let size = core::mem::size_of::<T>();
+        if byte_offset + size > self.byte_length {
+            return None;
+        }
+        self.ptr.map(|data| {
+            // SAFETY: The T being accessed is checked to be fully within the
+            // length of the data allocation.
+            let ptr = unsafe { data.as_ptr().byte_add(byte_offset) };
+            // Note: The DataBlock is allocated at 8 byte alignment and
+            // TypedArray byte offsets are multiples of their element size.
+            debug_assert_eq!(ptr.align_offset(size), 0);
+            ptr
+        })
+    }
+
+    /// Atomically load an integer Viewable at the given byte offset with
+    /// sequentially consistent ordering.
+    pub fn load_atomic<T: Viewable>(&self, byte_offset: usize) -> Option<T> {
+        let ptr = self.atomic_ptr::<T>(byte_offset)?;
+        // SAFETY: ptr points to an aligned T within the allocation.
+        let bits = unsafe { with_atomic_bits!(T, load_bits(ptr)) };
+        Some(T::from_bits(bits))
+    }
+
+    /// Atomically store an integer Viewable at the given byte offset with
+    /// sequentially consistent ordering.
+    pub fn store_atomic<T: Viewable>(&mut self, byte_offset: usize, value: T) {
+        if let Some(ptr) = self.atomic_ptr::<T>(byte_offset) {
+            // SAFETY: ptr points to an aligned T within the allocation.
+            unsafe { with_atomic_bits!(T, store_bits(ptr, value.into_bits())) }
+        }
+    }
+
+    /// Atomically perform a read-modify-write operation on an integer
+    /// Viewable at the given byte offset, returning the previous value.
+    pub fn modify_atomic<T: Viewable>(
+        &mut self,
+        byte_offset: usize,
+        op: ReadModifyWriteOp,
+        value: T,
+    ) -> Option<T> {
+        let ptr = self.atomic_ptr::<T>(byte_offset)?;
+        // SAFETY: ptr points to an aligned T within the allocation.
+        let bits = unsafe { with_atomic_bits!(T, modify_bits(ptr, op, value.into_bits())) };
+        Some(T::from_bits(bits))
+    }
+
+    /// Atomically replace an integer Viewable at the given byte offset if it
+    /// is equal to `expected`, returning the previous value.
+    pub fn compare_exchange_atomic<T: Viewable>(
+        &mut self,
+        byte_offset: usize,
+        expected: T,
+        replacement: T,
+    ) -> Option<T> {
+        let ptr = self.atomic_ptr::<T>(byte_offset)?;
+        // SAFETY: ptr points to an aligned T within the allocation.
+        let bits = unsafe {
+            with_atomic_bits!(
+                T,
+                compare_exchange_bits(ptr, expected.into_bits(), replacement.into_bits())
+            )
+        };
+        Some(T::from_bits(bits))
+    }
+
     /// ### [6.2.9.1 CreateByteDataBlock ( size )](https://tc39.es/ecma262/#sec-createbytedatablock)
     ///
     /// The abstract operation CreateByteDataBlock takes argument size (a
@@ -1124,34 +1303,6 @@ impl DataBlock {
         }
     }
 
-    /// ### [6.2.9.2 CreateSharedByteDataBlock ( size )](https://tc39.es/ecma262/#sec-createsharedbytedatablock)
-    ///
-    /// The abstract operation CreateSharedByteDataBlock takes argument size (a
-    /// non-negative integer) and returns either a normal completion containing
-    /// a Shared Data Block or a throw completion.
-    pub fn create_shared_byte_data_block<'a>(
-        agent: &mut Agent,
-        size: u64,
-        gc: NoGcScope<'a, '_>,
-    ) -> JsResult<'a, Self> {
-        // 1. Let db be a new Shared Data Block value consisting of size bytes. If it is impossible to create such a Shared Data Block, throw a RangeError exception.
-        if let Ok(size) = usize::try_from(size) {
-            // 2. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
-            // 3. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
-            // 4. Let zero be « 0 ».
-            // 5. For each index i of db, do
-            // a. Append WriteSharedMemory { [[Order]]: INIT, [[NoTear]]: true, [[Block]]: db, [[ByteIndex]]: i, [[ElementSize]]: 1, [[Payload]]: zero } to eventsRecord.[[EventList]].
-            Ok(Self::new(size))
-        } else {
-            Err(agent.throw_exception_with_static_message(
-                ExceptionType::TypeError,
-                "Invalid Shared Data Block length",
-                gc,
-            ))
-        }
-        // 6. Return db.
-    }
-
     /// ### [6.2.9.3 CopyDataBlockBytes ( toBlock, toIndex, fromBlock, fromIndex, count )](https://tc39.es/ecma262/#sec-copydatablockbytes)
     ///
     /// The abstract operation CopyDataBlockBytes takes arguments toBlock (a
@@ -1273,6 +1424,463 @@ impl DataBlock {
     }
 }
 
+#[cfg(feature = "shared-array-buffer")]
+/// Reinterpret the bytes of a Viewable as an unsigned integer of the same
+/// size, held in the low bits of a u64.
+fn into_raw_bits<T: Viewable>(value: T) -> u64 {
+    let size = core::mem::size_of::<T>();
+    let mut bytes = [0u8; 8];
+    // SAFETY: Viewables are plain numeric types of at most 8 bytes.
+    unsafe { write_unaligned(bytes.as_mut_ptr().cast::<T>(), value) };
+    let bits = u64::from_ne_bytes(bytes);
+    if cfg!(target_endian = "big") {
+        bits >> (64 - 8 * size)
+    } else {
+        bits
+    }
+}
+
+#[cfg(feature = "shared-array-buffer")]
+/// Reinterpret the low bits of a u64 as the bytes of a Viewable.
+fn from_raw_bits<T: Viewable>(bits: u64) -> T {
+    let size = core::mem::size_of::<T>();
+    let bits = if cfg!(target_endian = "big") {
+        bits << (64 - 8 * size)
+    } else {
+        bits
+    };
+    let bytes = bits.to_ne_bytes();
+    // SAFETY: Viewables are plain numeric types of at most 8 bytes, and any
+    // bit pattern is a valid value for them.
+    unsafe { read_unaligned(bytes.as_ptr().cast::<T>()) }
+}
+
+#[cfg(feature = "shared-array-buffer")]
+/// # Shared Data Block
+///
+/// A Data Block that can be referenced by multiple agents at the same time,
+/// possibly from different threads. Cloning a SharedDataBlock creates a new
+/// reference to the same block; the memory is deallocated when the last
+/// reference is dropped.
+///
+/// All reads and writes of a Shared Data Block are performed with atomic
+/// instructions using sequentially consistent ordering, so that concurrent
+/// accesses from other agents are never data races. Accesses that are not
+/// aligned to their size are performed byte by byte, and may tear.
+///
+/// A growable Shared Data Block allocates its maximum byte length up front:
+/// its memory is never moved, only its byte length grows.
+#[derive(Debug, Clone, Default)]
+pub struct SharedDataBlock(Arc<SharedDataBlockRecord>);
+
+#[cfg(feature = "shared-array-buffer")]
+#[derive(Debug, Default)]
+struct SharedDataBlockRecord {
+    ptr: Option<NonNull<u8>>,
+    /// Size of the allocation.
+    capacity: usize,
+    /// ### \[\[ArrayBufferByteLength\]\] / \[\[ArrayBufferByteLengthData\]\]
+    ///
+    /// The current byte length of the block. This only ever grows.
+    byte_length: AtomicUsize,
+    /// ### \[\[ArrayBufferMaxByteLength\]\]
+    ///
+    /// Set only for growable blocks.
+    max_byte_length: Option<usize>,
+    /// The waiters of Atomics.wait and Atomics.waitAsync on this block.
+    #[cfg(feature = "atomics")]
+    waiter_list: Arc<WaiterList>,
+}
+
+#[cfg(feature = "shared-array-buffer")]
+// SAFETY: The data of a Shared Data Block is only accessed through atomic
+// instructions, and the allocation is never moved or freed while the record
+// is alive.
+unsafe impl Send for SharedDataBlockRecord {}
+#[cfg(feature = "shared-array-buffer")]
+// SAFETY: See above.
+unsafe impl Sync for SharedDataBlockRecord {}
+
+#[cfg(feature = "shared-array-buffer")]
+impl Drop for SharedDataBlockRecord {
+    fn drop(&mut self) {
+        if let Some(ptr) = self.ptr {
+            let layout = Layout::from_size_align(self.capacity, 8).unwrap();
+            // SAFETY: The allocation was made with this layout and is no
+            // longer referenced by any SharedDataBlock.
+            unsafe { dealloc(ptr.as_ptr(), layout) }
+        }
+    }
+}
+
+#[cfg(feature = "shared-array-buffer")]
+impl SharedDataBlock {
+    /// ### [6.2.9.2 CreateSharedByteDataBlock ( size )](https://tc39.es/ecma262/#sec-createsharedbytedatablock)
+    ///
+    /// The abstract operation CreateSharedByteDataBlock takes argument size (a
+    /// non-negative integer) and returns either a normal completion containing
+    /// a Shared Data Block or a throw completion.
+    pub(crate) fn create_shared_byte_data_block<'a>(
+        agent: &mut Agent,
+        size: u64,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, Self> {
+        // 1. Let db be a new Shared Data Block value consisting of size
+        //    bytes. If it is impossible to create such a Shared Data Block,
+        //    throw a RangeError exception.
+        let layout = usize::try_from(size)
+            .ok()
+            .and_then(|size| Layout::from_size_align(size, 8).ok());
+        let Some(layout) = layout else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Invalid Shared Data Block length",
+                gc,
+            ));
+        };
+        let ptr = if layout.size() == 0 {
+            None
+        } else {
+            // 2. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+            // 3. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+            // 4. Let zero be « 0 ».
+            // 5. For each index i of db, do
+            // a. Append WriteSharedMemory { [[Order]]: INIT, [[NoTear]]: true, [[Block]]: db, [[ByteIndex]]: i, [[ElementSize]]: 1, [[Payload]]: zero } to eventsRecord.[[EventList]].
+            // SAFETY: Size of allocation is non-zero.
+            let data = unsafe { alloc_zeroed(layout) };
+            if data.is_null() {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::RangeError,
+                    "Failed to allocate Shared Data Block",
+                    gc,
+                ));
+            }
+            NonNull::new(data)
+        };
+        // 6. Return db.
+        Ok(Self(Arc::new(SharedDataBlockRecord {
+            ptr,
+            capacity: layout.size(),
+            byte_length: AtomicUsize::new(layout.size()),
+            max_byte_length: None,
+            #[cfg(feature = "atomics")]
+            waiter_list: Default::default(),
+        })))
+    }
+
+    /// Turn a newly created Shared Data Block into a growable block with the
+    /// given current byte length. The maximum byte length of the block is
+    /// its allocated size.
+    pub(crate) fn into_growable(mut self, byte_length: usize) -> Self {
+        let record =
+            Arc::get_mut(&mut self.0).expect("Cannot make a referenced Shared Data Block growable");
+        assert!(byte_length <= record.capacity);
+        record.max_byte_length = Some(record.capacity);
+        *record.byte_length.get_mut() = byte_length;
+        self
+    }
+
+    /// Returns true if both SharedDataBlocks refer to the same block.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Returns true if the block is growable.
+    pub fn is_growable(&self) -> bool {
+        self.0.max_byte_length.is_some()
+    }
+
+    /// The current byte length of the block.
+    pub fn byte_length(&self) -> usize {
+        self.0.byte_length.load(Ordering::SeqCst)
+    }
+
+    /// The maximum byte length of a growable block, or the byte length of a
+    /// fixed-length block.
+    pub fn max_byte_length(&self) -> usize {
+        self.0.max_byte_length.unwrap_or(self.0.capacity)
+    }
+
+    /// Atomically replace the byte length of a growable block with
+    /// `new_byte_length` if it is equal to `current_byte_length`, returning
+    /// the byte length that was read.
+    ///
+    /// `new_byte_length` must not exceed the maximum byte length.
+    pub(crate) fn compare_exchange_byte_length(
+        &self,
+        current_byte_length: usize,
+        new_byte_length: usize,
+    ) -> usize {
+        debug_assert!(self.is_growable());
+        assert!(new_byte_length <= self.max_byte_length());
+        match self.0.byte_length.compare_exchange(
+            current_byte_length,
+            new_byte_length,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        ) {
+            Ok(read) | Err(read) => read,
+        }
+    }
+
+    #[cfg(feature = "atomics")]
+    pub(crate) fn waiter_list(&self) -> &Arc<WaiterList> {
+        &self.0.waiter_list
+    }
+
+    /// Get a pointer to `size` bytes at the given byte offset, or None if
+    /// they are not within the current byte length of the block.
+    fn as_ptr(&self, byte_offset: usize, size: usize) -> Option<*mut u8> {
+        let end = byte_offset.checked_add(size)?;
+        if end > self.byte_length() {
+            return None;
+        }
+        // SAFETY: The bytes are within the allocation, as the byte length
+        // never exceeds the capacity.
+        self.0
+            .ptr
+            .map(|data| unsafe { data.as_ptr().byte_add(byte_offset) })
+    }
+
+    /// Load a Viewable from the given byte offset with sequentially
+    /// consistent ordering, or None if the Viewable does not fit in the
+    /// block.
+    pub fn load<T: Viewable>(&self, byte_offset: usize) -> Option<T> {
+        let size = core::mem::size_of::<T>();
+        let ptr = self.as_ptr(byte_offset, size)?;
+        if ptr.align_offset(size) == 0 {
+            // SAFETY: ptr points to an aligned T within the allocation.
+            let bits = unsafe { with_atomic_bits!(T, load_bits(ptr)) };
+            Some(from_raw_bits(bits))
+        } else {
+            let mut bytes = [0u8; 8];
+            for (i, byte) in bytes[..size].iter_mut().enumerate() {
+                // SAFETY: ptr points to size bytes within the allocation.
+                *byte = unsafe { AtomicU8::from_ptr(ptr.add(i)) }.load(Ordering::SeqCst);
+            }
+            // SAFETY: The bytes hold a T.
+            Some(unsafe { read_unaligned(bytes.as_ptr().cast::<T>()) })
+        }
+    }
+
+    /// Store a Viewable at the given byte offset with sequentially consistent
+    /// ordering. Nothing is stored if the Viewable does not fit in the block.
+    pub fn store<T: Viewable>(&self, byte_offset: usize, value: T) {
+        let size = core::mem::size_of::<T>();
+        let Some(ptr) = self.as_ptr(byte_offset, size) else {
+            return;
+        };
+        if ptr.align_offset(size) == 0 {
+            // SAFETY: ptr points to an aligned T within the allocation.
+            unsafe { with_atomic_bits!(T, store_bits(ptr, into_raw_bits(value))) }
+        } else {
+            let mut bytes = [0u8; 8];
+            // SAFETY: The bytes can hold a T.
+            unsafe { write_unaligned(bytes.as_mut_ptr().cast::<T>(), value) };
+            for (i, byte) in bytes[..size].iter().enumerate() {
+                // SAFETY: ptr points to size bytes within the allocation.
+                unsafe { AtomicU8::from_ptr(ptr.add(i)) }.store(*byte, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Atomically perform a read-modify-write operation on an integer
+    /// Viewable at the given byte offset, returning the previous value.
+    pub(crate) fn modify<T: Viewable>(
+        &self,
+        byte_offset: usize,
+        op: ReadModifyWriteOp,
+        value: T,
+    ) -> Option<T> {
+        debug_assert!(!T::IS_FLOAT);
+        let size = core::mem::size_of::<T>();
+        let ptr = self.as_ptr(byte_offset, size)?;
+        debug_assert_eq!(ptr.align_offset(size), 0);
+        // SAFETY: ptr points to an aligned T within the allocation.
+        let bits = unsafe { with_atomic_bits!(T, modify_bits(ptr, op, value.into_bits())) };
+        Some(T::from_bits(bits))
+    }
+
+    /// ### [25.4.3.16 AtomicCompareExchangeInSharedBlock ( block, byteIndexInBuffer, elementSize, expectedBytes, replacementBytes )](https://tc39.es/ecma262/#sec-atomiccompareexchangeinsharedblock)
+    ///
+    /// Atomically replace an integer Viewable at the given byte offset if it
+    /// is equal to `expected`, returning the previous value.
+    pub(crate) fn compare_exchange<T: Viewable>(
+        &self,
+        byte_offset: usize,
+        expected: T,
+        replacement: T,
+    ) -> Option<T> {
+        debug_assert!(!T::IS_FLOAT);
+        let size = core::mem::size_of::<T>();
+        let ptr = self.as_ptr(byte_offset, size)?;
+        debug_assert_eq!(ptr.align_offset(size), 0);
+        // SAFETY: ptr points to an aligned T within the allocation.
+        let bits = unsafe {
+            with_atomic_bits!(
+                T,
+                compare_exchange_bits(ptr, expected.into_bits(), replacement.into_bits())
+            )
+        };
+        Some(T::from_bits(bits))
+    }
+
+    /// ### [6.2.9.3 CopyDataBlockBytes ( toBlock, toIndex, fromBlock, fromIndex, count )](https://tc39.es/ecma262/#sec-copydatablockbytes)
+    ///
+    /// Copy bytes from a Shared Data Block into another, distinct Shared Data
+    /// Block. Each byte is read and written with sequentially consistent
+    /// ordering.
+    pub(crate) fn copy_shared_data_block_bytes(
+        &self,
+        to_index: usize,
+        from_block: &Self,
+        from_index: usize,
+        count: usize,
+    ) {
+        // 1. Assert: fromBlock and toBlock are distinct values.
+        assert!(!self.ptr_eq(from_block));
+        if count == 0 {
+            return;
+        }
+        // 3. Assert: fromIndex + count ≤ fromSize.
+        let from_ptr = from_block.as_ptr(from_index, count).unwrap();
+        // 5. Assert: toIndex + count ≤ toSize.
+        let to_ptr = self.as_ptr(to_index, count).unwrap();
+        // 6. Repeat, while count > 0,
+        for i in 0..count {
+            // SAFETY: Both pointers point to count bytes within their
+            // allocations.
+            let byte = unsafe { AtomicU8::from_ptr(from_ptr.add(i)) }.load(Ordering::SeqCst);
+            unsafe { AtomicU8::from_ptr(to_ptr.add(i)) }.store(byte, Ordering::SeqCst);
+        }
+        // 7. Return UNUSED.
+    }
+
+    /// Read bytes starting at the given byte offset into `bytes`. Each byte
+    /// is read with sequentially consistent ordering.
+    ///
+    /// The bytes must be within the current byte length of the block.
+    pub(crate) fn load_bytes(&self, byte_offset: usize, bytes: &mut [u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        let ptr = self.as_ptr(byte_offset, bytes.len()).unwrap();
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            // SAFETY: ptr points to bytes.len() bytes within the allocation.
+            *byte = unsafe { AtomicU8::from_ptr(ptr.add(i)) }.load(Ordering::SeqCst);
+        }
+    }
+
+    /// Write `bytes` into the block starting at the given byte offset. Each
+    /// byte is written with sequentially consistent ordering.
+    ///
+    /// The bytes must be within the current byte length of the block.
+    pub(crate) fn store_bytes(&self, byte_offset: usize, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        let ptr = self.as_ptr(byte_offset, bytes.len()).unwrap();
+        for (i, byte) in bytes.iter().enumerate() {
+            // SAFETY: ptr points to bytes.len() bytes within the allocation.
+            unsafe { AtomicU8::from_ptr(ptr.add(i)) }.store(*byte, Ordering::SeqCst);
+        }
+    }
+
+    /// Copy the current contents of the block into an 8 byte aligned
+    /// buffer.
+    pub(crate) fn copy_bytes(&self) -> SharedDataBlockCopy {
+        let byte_length = self.byte_length();
+        let mut copy = SharedDataBlockCopy {
+            data: vec![0; byte_length.div_ceil(8)].into_boxed_slice(),
+            byte_length,
+        };
+        self.load_bytes(0, &mut copy);
+        copy
+    }
+
+    /// Copy the current contents of the block into an 8 byte aligned buffer
+    /// that writes the bytes changed through it back into the block when
+    /// dropped.
+    pub(crate) fn copy_bytes_mut(&self) -> SharedDataBlockCopyMut {
+        let copy = self.copy_bytes();
+        SharedDataBlockCopyMut {
+            block: self.clone(),
+            original: copy.to_vec().into_boxed_slice(),
+            copy,
+        }
+    }
+}
+
+#[cfg(feature = "shared-array-buffer")]
+/// A copy of the bytes of a Shared Data Block, aligned to 8 bytes so that the
+/// bytes can be reinterpreted as a slice of any Viewable.
+#[derive(Debug)]
+pub struct SharedDataBlockCopy {
+    data: Box<[u64]>,
+    byte_length: usize,
+}
+
+#[cfg(feature = "shared-array-buffer")]
+impl Deref for SharedDataBlockCopy {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: data holds at least byte_length initialized bytes.
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr().cast::<u8>(), self.byte_length) }
+    }
+}
+
+#[cfg(feature = "shared-array-buffer")]
+impl DerefMut for SharedDataBlockCopy {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: data holds at least byte_length initialized bytes.
+        unsafe {
+            core::slice::from_raw_parts_mut(self.data.as_mut_ptr().cast::<u8>(), self.byte_length)
+        }
+    }
+}
+
+#[cfg(feature = "shared-array-buffer")]
+/// A mutable copy of the bytes of a Shared Data Block. When dropped, the
+/// bytes that were changed in the copy are written back into the block;
+/// bytes that were not changed are left alone so that concurrent writes to
+/// them by other agents are not overwritten.
+#[derive(Debug)]
+pub struct SharedDataBlockCopyMut {
+    block: SharedDataBlock,
+    original: Box<[u8]>,
+    copy: SharedDataBlockCopy,
+}
+
+#[cfg(feature = "shared-array-buffer")]
+impl Deref for SharedDataBlockCopyMut {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.copy
+    }
+}
+
+#[cfg(feature = "shared-array-buffer")]
+impl DerefMut for SharedDataBlockCopyMut {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.copy
+    }
+}
+
+#[cfg(feature = "shared-array-buffer")]
+impl Drop for SharedDataBlockCopyMut {
+    fn drop(&mut self) {
+        // Note: A Shared Data Block never shrinks, so all bytes of the copy
+        // are still within the block.
+        for (i, (original, byte)) in self.original.iter().zip(self.copy.iter()).enumerate() {
+            if original != byte {
+                self.block.store_bytes(i, core::slice::from_ref(byte));
+            }
+        }
+    }
+}
+
 #[test]
 fn new_data_block() {
     let db = DataBlock::new(0);
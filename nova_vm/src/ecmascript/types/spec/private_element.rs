@@ -0,0 +1,127 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::PrivateName;
+use crate::{
+    ecmascript::types::{Function, Value},
+    engine::context::{Bindable, NoGcScope},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### [6.2.10 The PrivateElement Specification Type](https://tc39.es/ecma262/#sec-privateelement-specification-type)
+///
+/// The PrivateElement type is a Record used in the specification of private
+/// class fields, methods, and accessors.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PrivateElement<'a> {
+    /// ### \[\[Key]]
+    ///
+    /// The name of the field, method, or accessor.
+    pub(crate) key: PrivateName,
+
+    /// ### \[\[Kind]]
+    ///
+    /// The kind of the element, together with its \[\[Value]] or \[\[Get]]
+    /// and \[\[Set]] fields.
+    pub(crate) kind: PrivateElementKind<'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PrivateElementKind<'a> {
+    /// \[\[Kind]]: field
+    ///
+    /// Contains the \[\[Value]] of the field.
+    Field(Value<'a>),
+    /// \[\[Kind]]: method
+    ///
+    /// Contains the \[\[Value]] of the method.
+    Method(Function<'a>),
+    /// \[\[Kind]]: accessor
+    Accessor {
+        /// \[\[Get]]
+        get: Option<Function<'a>>,
+        /// \[\[Set]]
+        set: Option<Function<'a>>,
+    },
+}
+
+/// Append a private method or accessor to a container of PrivateElements.
+///
+/// If the container already contains an accessor with the same \[\[Key]], the
+/// two accessors are combined into one.
+pub(crate) fn push_private_method(
+    container: &mut Vec<PrivateElement<'static>>,
+    element: PrivateElement,
+) {
+    // iv. If container contains a PrivateElement pe such that
+    //     pe.[[Key]] is element.[[Key]], then
+    if let Some(pe) = container.iter_mut().find(|pe| pe.key == element.key) {
+        // 1. Assert: element.[[Kind]] and pe.[[Kind]] are both accessor.
+        let (
+            PrivateElementKind::Accessor {
+                get: pe_get,
+                set: pe_set,
+            },
+            PrivateElementKind::Accessor { get, set },
+        ) = (&mut pe.kind, element.kind)
+        else {
+            unreachable!()
+        };
+        // 2. If element.[[Get]] is undefined, then
+        if get.is_none() {
+            // a. Let combined be PrivateElement { [[Key]]: element.[[Key]],
+            //    [[Kind]]: accessor, [[Get]]: pe.[[Get]], [[Set]]: element.[[Set]] }.
+            *pe_set = set.unbind();
+        } else {
+            // 3. Else,
+            // a. Let combined be PrivateElement { [[Key]]: element.[[Key]],
+            //    [[Kind]]: accessor, [[Get]]: element.[[Get]], [[Set]]: pe.[[Set]] }.
+            *pe_get = get.unbind();
+        }
+        // 4. Replace pe in container with combined.
+    } else {
+        // v. Else,
+        // 1. Append element to container.
+        container.push(element.unbind());
+    }
+}
+
+// SAFETY: Property implemented as a lifetime transmute.
+unsafe impl Bindable for PrivateElement<'_> {
+    type Of<'a> = PrivateElement<'a>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'static>>(self) }
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        unsafe { core::mem::transmute::<Self, Self::Of<'a>>(self) }
+    }
+}
+
+impl HeapMarkAndSweep for PrivateElement<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match &self.kind {
+            PrivateElementKind::Field(value) => value.mark_values(queues),
+            PrivateElementKind::Method(function) => function.mark_values(queues),
+            PrivateElementKind::Accessor { get, set } => {
+                get.mark_values(queues);
+                set.mark_values(queues);
+            }
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match &mut self.kind {
+            PrivateElementKind::Field(value) => value.sweep_values(compactions),
+            PrivateElementKind::Method(function) => function.sweep_values(compactions),
+            PrivateElementKind::Accessor { get, set } => {
+                get.sweep_values(compactions);
+                set.sweep_values(compactions);
+            }
+        }
+    }
+}
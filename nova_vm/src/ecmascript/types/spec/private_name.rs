@@ -0,0 +1,30 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::ecmascript::execution::Agent;
+
+/// ### [6.2.12 Private Names](https://tc39.es/ecma262/#sec-private-names)
+///
+/// The Private Name specification type is used to describe a globally unique
+/// value (one which differs from any other Private Name, even if they are
+/// otherwise indistinguishable) which represents the key of a private class
+/// element (field, method, or accessor). Each Private Name has an associated
+/// immutable \[\[Description]] which is a String value.
+///
+/// In Nova a Private Name is only an identity: its \[\[Description]] is held
+/// by the PrivateEnvironment Record that declares it. As Private Names carry
+/// no heap data, they do not participate in garbage collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct PrivateName(u32);
+
+impl PrivateName {
+    /// Create a new Private Name, distinct from all other Private Names.
+    pub(crate) fn new(agent: &mut Agent) -> Self {
+        let index = agent.heap.private_name_counter;
+        agent.heap.private_name_counter = index
+            .checked_add(1)
+            .expect("Private Name counter overflowed");
+        Self(index)
+    }
+}
@@ -2,7 +2,11 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::ecmascript::abstract_operations::operations_on_objects::try_set;
+use core::ops::ControlFlow;
+
+use crate::ecmascript::abstract_operations::operations_on_objects::{
+    private_get, private_set, try_private_get, try_private_set, try_set,
+};
 use crate::ecmascript::types::IntoValue;
 use crate::engine::TryResult;
 use crate::engine::context::{Bindable, GcScope, NoGcScope};
@@ -13,9 +17,9 @@ use crate::{
         execution::{
             Environment,
             agent::{self, ExceptionType},
-            get_global_object,
+            get_global_object, get_this_environment,
         },
-        types::{InternalMethods, Object, PropertyKey, String, Value},
+        types::{InternalMethods, Object, PrivateName, PropertyKey, String, Value},
     },
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
@@ -39,8 +43,17 @@ pub struct Reference<'a> {
     ///
     /// The name of the binding. Always a String if \[\[Base]] value is an
     /// Environment Record.
+    ///
+    /// For Private References this holds the \[\[Description]] of the
+    /// Private Name, which is only used for error messages.
     pub(crate) referenced_name: PropertyKey<'a>,
 
+    /// The Private Name of a Private Reference.
+    ///
+    /// If set, the Reference Record is a Private Reference and its
+    /// \[\[ReferencedName]] is this Private Name.
+    pub(crate) private_name: Option<PrivateName>,
+
     /// ### \[\[Strict]]
     ///
     /// true if the Reference Record originated in strict mode code, false
@@ -109,10 +122,9 @@ pub(crate) fn is_super_reference(reference: &Reference) -> bool {
 ///
 /// The abstract operation IsPrivateReference takes argument V (a Reference
 /// Record) and returns a Boolean.
-pub(crate) fn is_private_reference(_: &Reference) -> bool {
+pub(crate) fn is_private_reference(reference: &Reference) -> bool {
     // 1. If V.[[ReferencedName]] is a Private Name, return true; otherwise return false.
-    // matches!(reference.referenced_name, PropertyKey::PrivateName)
-    false
+    reference.private_name.is_some()
 }
 
 /// ### [6.2.5.5 GetValue ( V )](https://tc39.es/ecma262/#sec-getvalue)
@@ -136,6 +148,12 @@ pub(crate) fn get_value<'gc>(
             // and the ordinary object [[Get]] internal method. An
             // implementation might choose to avoid the actual
             // creation of the object.
+            if let Some(private_name) = reference.private_name {
+                let base_obj = to_object(agent, value, gc.nogc()).unbind()?.bind(gc.nogc());
+                // b. If IsPrivateReference(V) is true, then
+                // i. Return ? PrivateGet(baseObj, V.[[ReferencedName]]).
+                return private_get(agent, base_obj.unbind(), private_name, gc);
+            }
             if let Ok(object) = Object::try_from(value) {
                 // c. Return ? baseObj.[[Get]](V.[[ReferencedName]], GetThisValue(V)).
                 object.internal_get(
@@ -250,6 +268,15 @@ pub(crate) fn try_get_value<'gc>(
             // and the ordinary object [[Get]] internal method. An
             // implementation might choose to avoid the actual
             // creation of the object.
+            if let Some(private_name) = reference.private_name {
+                let base_obj = match to_object(agent, value, gc) {
+                    Ok(base_obj) => base_obj,
+                    Err(err) => return TryResult::Continue(Err(err)),
+                };
+                // b. If IsPrivateReference(V) is true, then
+                // i. Return ? PrivateGet(baseObj, V.[[ReferencedName]]).
+                return try_private_get(agent, base_obj, private_name, gc);
+            }
             if let Ok(object) = Object::try_from(value) {
                 // c. Return ? baseObj.[[Get]](V.[[ReferencedName]], GetThisValue(V)).
                 TryResult::Continue(Ok(object.try_get(
@@ -392,9 +419,9 @@ pub(crate) fn put_value<'a>(
         };
         let base_obj = to_object(agent, base, gc.nogc()).unbind()?.bind(gc.nogc());
         // b. If IsPrivateReference(V) is true, then
-        if is_private_reference(v) {
+        if let Some(private_name) = v.private_name {
             // i. Return ? PrivateSet(baseObj, V.[[ReferencedName]], W).
-            todo!();
+            return private_set(agent, base_obj.unbind(), private_name, w, gc);
         }
         // c. Let succeeded be ? baseObj.[[Set]](V.[[ReferencedName]], W, GetThisValue(V)).
         let this_value = get_this_value(v);
@@ -490,9 +517,12 @@ pub(crate) fn try_put_value<'a>(
             Err(err) => return TryResult::Continue(Err(err)),
         };
         // b. If IsPrivateReference(V) is true, then
-        if is_private_reference(v) {
+        if let Some(private_name) = v.private_name {
             // i. Return ? PrivateSet(baseObj, V.[[ReferencedName]], W).
-            todo!();
+            return match try_private_set(agent, base_obj, private_name, w, gc) {
+                ControlFlow::Continue(result) => TryResult::Continue(result),
+                ControlFlow::Break(_) => TryResult::Break(()),
+            };
         }
         // c. Let succeeded be ? baseObj.[[Set]](V.[[ReferencedName]], W, GetThisValue(V)).
         let this_value = get_this_value(v);
@@ -603,6 +633,42 @@ pub(crate) fn get_this_value<'a>(reference: &Reference<'a>) -> Value<'a> {
         })
 }
 
+/// ### [13.3.7.3 MakeSuperPropertyReference ( actualThis, propertyKey, strict )](https://tc39.es/ecma262/#sec-makesuperpropertyreference)
+///
+/// The abstract operation MakeSuperPropertyReference takes arguments
+/// actualThis (an ECMAScript language value), propertyKey (a property key),
+/// and strict (a Boolean) and returns a Super Reference Record.
+pub(crate) fn make_super_property_reference<'a>(
+    agent: &mut Agent,
+    actual_this: Value<'a>,
+    property_key: PropertyKey<'a>,
+    strict: bool,
+    gc: NoGcScope<'a, '_>,
+) -> Reference<'a> {
+    // 1. Let env be GetThisEnvironment().
+    let Environment::Function(env) = get_this_environment(agent, gc) else {
+        unreachable!()
+    };
+    // 2. Assert: env.HasSuperBinding() is true.
+    debug_assert!(env.has_super_binding(agent));
+    // 3. Let baseValue be ! env.GetSuperBase().
+    let base_value = match env.get_super_base(agent, gc) {
+        None => Value::Undefined,
+        Some(None) => Value::Null,
+        Some(Some(base)) => base.into_value(),
+    };
+    // 4. Return the Reference Record { [[Base]]: baseValue,
+    //    [[ReferencedName]]: propertyKey, [[Strict]]: strict,
+    //    [[ThisValue]]: actualThis }.
+    Reference {
+        base: Base::Value(base_value),
+        referenced_name: property_key,
+        private_name: None,
+        strict,
+        this_value: Some(actual_this),
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) enum Base<'a> {
     Value(Value<'a>),
@@ -632,6 +698,7 @@ impl HeapMarkAndSweep for Reference<'static> {
             referenced_name,
             strict: _,
             this_value,
+            private_name: _,
         } = self;
         base.mark_values(queues);
         referenced_name.mark_values(queues);
@@ -644,6 +711,7 @@ impl HeapMarkAndSweep for Reference<'static> {
             referenced_name,
             strict: _,
             this_value,
+            private_name: _,
         } = self;
         base.sweep_values(compactions);
         referenced_name.sweep_values(compactions);
@@ -16,7 +16,7 @@ use super::{
 use crate::ecmascript::builtins::regexp::reg_exp_create_literal;
 use crate::{
     ecmascript::{
-        execution::Agent,
+        execution::{Agent, agent::ExceptionType},
         syntax_directed_operations::{
             function_definitions::{CompileFunctionBodyData, ContainsExpression},
             scope_analysis::{LexicallyScopedDeclaration, LexicallyScopedDeclarations},
@@ -59,6 +59,8 @@ pub(crate) struct CompileContext<'agent, 'gc, 'scope> {
     function_expressions: Vec<FunctionExpression<'gc>>,
     /// Arrow function expressions being built
     arrow_function_expressions: Vec<ArrowFunctionExpression>,
+    /// Tagged template literals being built
+    template_literals: Vec<SendableRef<ast::TemplateLiteral<'static>>>,
     class_initializer_bytecodes: Vec<(Option<Executable<'gc>>, bool)>,
     /// NamedEvaluation name parameter
     name_identifier: Option<NamedEvaluationParameter>,
@@ -69,14 +71,24 @@ pub(crate) struct CompileContext<'agent, 'gc, 'scope> {
     /// Current depth of the lexical scope away from our continue/break target.
     current_depth_of_loop_scope: Option<u16>,
     /// `continue;` statement jumps that were present in the current loop.
-    current_continue: Option<Vec<JumpIndex>>,
+    current_continue: Option<Vec<BreakOrContinueJump>>,
     /// `break;` statement jumps that were present in the current loop.
-    current_break: Option<Vec<JumpIndex>>,
+    current_break: Option<Vec<BreakOrContinueJump>>,
+    /// Labels of the labelled statements directly enclosing the statement
+    /// being compiled. These become the label set of the next loop, switch
+    /// or labelled statement body.
+    current_label_set: Vec<std::string::String>,
+    /// `return` statement jumps that were present in the current try
+    /// statement. If this is `None`, returns are compiled as a `Return`
+    /// instruction directly.
+    current_return: Option<Vec<JumpIndex>>,
     /// `?.` chain jumps that were present in a chain expression.
     optional_chains: Option<Vec<JumpIndex>>,
     /// In a `(a?.b).unbind()?.bind(gc.nogc()).()` chain the evaluation of `(a?.b)` must be considered a
     /// reference.
     is_call_optional_chain_this: bool,
+    /// If true, the function body being compiled is an async generator's.
+    is_async_generator: bool,
 }
 
 impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
@@ -91,14 +103,18 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
             constants: Vec::new(),
             function_expressions: Vec::new(),
             arrow_function_expressions: Vec::new(),
+            template_literals: Vec::new(),
             class_initializer_bytecodes: Vec::new(),
             name_identifier: None,
             lexical_binding_state: false,
             current_depth_of_loop_scope: None,
             current_continue: None,
             current_break: None,
+            current_label_set: Vec::new(),
+            current_return: None,
             optional_chains: None,
             is_call_optional_chain_this: false,
+            is_async_generator: false,
         }
     }
 
@@ -174,6 +190,29 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         self.add_instruction(Instruction::PutValue);
     }
 
+    /// Compile a class private field with an optional initializer into the
+    /// current context.
+    pub(crate) fn compile_class_private_field(
+        &mut self,
+        private_identifier: &ast::PrivateIdentifier,
+        value: &Option<ast::Expression<'_>>,
+    ) {
+        let description = String::from_str(self.agent, private_identifier.name.as_str(), self.gc);
+        // Resolve 'this' into the stack.
+        self.add_instruction(Instruction::ResolveThisBinding);
+        self.add_instruction(Instruction::Load);
+        if let Some(value) = value {
+            value.compile(self);
+            if is_reference(value) {
+                self.add_instruction(Instruction::GetValue);
+            }
+        } else {
+            self.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
+        }
+        // Define the private field on 'this'.
+        self.add_instruction_with_identifier(Instruction::ClassDefinePrivateField, description);
+    }
+
     /// Compile a function body into the current context.
     ///
     /// This is useful when the function body is part of a larger whole, namely
@@ -185,6 +224,8 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
             eprintln!();
         }
 
+        self.is_async_generator = data.is_async_generator;
+
         function_declaration_instantiation::instantiation(
             self,
             data.params,
@@ -198,7 +239,24 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         // heap operations.
         let body: &[Statement] = unsafe { core::mem::transmute(data.body.statements.as_slice()) };
 
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let disposable_scope = self.enter_disposable_scope_for(body);
         self.compile_statements(body);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        if let Some(disposable_scope) = disposable_scope {
+            self.exit_disposable_scope(disposable_scope);
+        }
+    }
+
+    /// Compile the top-level statements of a module into the current context.
+    pub(super) fn compile_module_body(&mut self, body: &[Statement]) {
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let disposable_scope = self.enter_disposable_scope_for(body);
+        self.compile_statements(body);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        if let Some(disposable_scope) = disposable_scope {
+            self.exit_disposable_scope(disposable_scope);
+        }
     }
 
     pub(super) fn compile_statements(&mut self, body: &[Statement]) {
@@ -222,6 +280,7 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
             constants: self.constants.unbind().into_boxed_slice(),
             function_expressions: self.function_expressions.unbind().into_boxed_slice(),
             arrow_function_expressions: self.arrow_function_expressions.into_boxed_slice(),
+            template_literals: self.template_literals.into_boxed_slice(),
             class_initializer_bytecodes: self
                 .class_initializer_bytecodes
                 .into_iter()
@@ -275,6 +334,202 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         self._push_instruction(instruction);
     }
 
+    /// Enter a loop or switch statement. Break and continue jumps compiled
+    /// inside it are collected until [`Self::exit_jump_target`].
+    fn enter_breakable_statement(&mut self) -> JumpTargetScope {
+        self.enter_jump_target(true)
+    }
+
+    /// Enter the body of a labelled statement that is not a loop or switch
+    /// statement. Only labelled break jumps can target it.
+    fn enter_labelled_statement(&mut self) -> JumpTargetScope {
+        self.enter_jump_target(false)
+    }
+
+    fn enter_jump_target(&mut self, breakable: bool) -> JumpTargetScope {
+        JumpTargetScope {
+            label_set: core::mem::take(&mut self.current_label_set),
+            breakable,
+            previous_depth_of_loop_scope: self.current_depth_of_loop_scope.replace(0),
+            previous_break: self.current_break.replace(vec![]),
+            previous_continue: self.current_continue.replace(vec![]),
+        }
+    }
+
+    /// Take the break jumps that target the current statement.
+    fn take_own_breaks(&mut self, scope: &JumpTargetScope) -> Vec<JumpIndex> {
+        let breaks = self.current_break.as_mut().unwrap();
+        let (own, other) = core::mem::take(breaks)
+            .into_iter()
+            .partition::<Vec<_>, _>(|jump| scope.is_target_of(jump));
+        *breaks = other;
+        own.into_iter().map(|jump| jump.jump).collect()
+    }
+
+    /// Take the continue jumps that target the current loop.
+    fn take_own_continues(&mut self, scope: &JumpTargetScope) -> Vec<JumpIndex> {
+        let continues = self.current_continue.as_mut().unwrap();
+        let (own, other) = core::mem::take(continues)
+            .into_iter()
+            .partition::<Vec<_>, _>(|jump| scope.is_target_of(jump));
+        *continues = other;
+        own.into_iter().map(|jump| jump.jump).collect()
+    }
+
+    /// Exit a statement entered with [`Self::enter_breakable_statement`] or
+    /// [`Self::enter_labelled_statement`]. Any remaining break and continue
+    /// jumps target enclosing statements: They are forwarded there after
+    /// running `cleanup`, which should undo what the statement has set up
+    /// outside of the declarative environments it counted.
+    fn exit_jump_target(&mut self, scope: JumpTargetScope, cleanup: impl Fn(&mut Self)) {
+        let breaks = self.current_break.take().unwrap();
+        let continues = self.current_continue.take().unwrap();
+        self.current_break = scope.previous_break;
+        self.current_continue = scope.previous_continue;
+        self.current_depth_of_loop_scope = scope.previous_depth_of_loop_scope;
+        if breaks.is_empty() && continues.is_empty() {
+            return;
+        }
+        let jump_over_exits = self.add_instruction_with_jump_slot(Instruction::Jump);
+        self.compile_forwarded_jumps(
+            breaks,
+            continues,
+            scope.previous_depth_of_loop_scope,
+            cleanup,
+        );
+        self.set_jump_target_here(jump_over_exits);
+    }
+
+    /// Compile exit paths for break and continue jumps that leave the current
+    /// statement: Each path runs `on_exit`, exits `depth_of_loop_scope`
+    /// declarative environments and then jumps on towards its target.
+    fn compile_forwarded_jumps(
+        &mut self,
+        mut breaks: Vec<BreakOrContinueJump>,
+        mut continues: Vec<BreakOrContinueJump>,
+        depth_of_loop_scope: Option<u16>,
+        on_exit: impl Fn(&mut Self),
+    ) {
+        for is_continue in [false, true] {
+            let jumps = if is_continue {
+                &mut continues
+            } else {
+                &mut breaks
+            };
+            while let Some(first) = jumps.first() {
+                // Jumps to the same target share an exit path.
+                let label = first.label.clone();
+                let (path, rest) = core::mem::take(jumps)
+                    .into_iter()
+                    .partition::<Vec<_>, _>(|jump| jump.label == label);
+                *jumps = rest;
+                for jump in path {
+                    self.set_jump_target_here(jump.jump);
+                }
+                on_exit(self);
+                for _ in 0..depth_of_loop_scope.unwrap_or(0) {
+                    self.add_instruction(Instruction::ExitDeclarativeEnvironment);
+                }
+                let jump = BreakOrContinueJump {
+                    label,
+                    jump: self.add_instruction_with_jump_slot(Instruction::Jump),
+                };
+                if is_continue {
+                    self.current_continue.as_mut().unwrap().push(jump);
+                } else {
+                    self.current_break.as_mut().unwrap().push(jump);
+                }
+            }
+        }
+    }
+
+    /// Return the current result value from the function. Inside a try
+    /// statement this instead jumps to the statement's exit path so that
+    /// finally blocks can be run.
+    fn add_return(&mut self) {
+        if self.current_return.is_some() {
+            let return_jump = self.add_instruction_with_jump_slot(Instruction::Jump);
+            self.current_return.as_mut().unwrap().push(return_jump);
+        } else {
+            self.add_instruction(Instruction::Return);
+        }
+    }
+
+    /// Enter a scope whose `using` declarations add resources to a new
+    /// dispose capability. Break, continue and return jumps compiled inside
+    /// it are collected until [`Self::exit_disposable_scope`], which disposes
+    /// of the resources on all paths out of the scope.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    fn enter_disposable_scope(&mut self, is_async: bool) -> DisposableScope {
+        let depth_of_loop_scope = self.current_depth_of_loop_scope;
+        let previous_break = self.current_break.replace(vec![]);
+        let previous_continue = self.current_continue.replace(vec![]);
+        let previous_return = self.current_return.replace(vec![]);
+        self.add_instruction(if is_async {
+            Instruction::EnterAsyncDisposableScope
+        } else {
+            Instruction::EnterDisposableScope
+        });
+        let jump_to_dispose_with_error =
+            self.add_instruction_with_jump_slot(Instruction::PushExceptionJumpTarget);
+        DisposableScope {
+            is_async,
+            depth_of_loop_scope,
+            previous_break,
+            previous_continue,
+            previous_return,
+            jump_to_dispose_with_error,
+        }
+    }
+
+    /// Enter a disposable scope if the statements directly contain `using`
+    /// declarations.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    fn enter_disposable_scope_for(&mut self, body: &[Statement]) -> Option<DisposableScope> {
+        has_using_declarations(body).map(|is_async| self.enter_disposable_scope(is_async))
+    }
+
+    /// Exit a scope entered with [`Self::enter_disposable_scope`]: Perform
+    /// DisposeResources on the scope's dispose capability on normal, throw,
+    /// break, continue and return completions.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    fn exit_disposable_scope(&mut self, scope: DisposableScope) {
+        let DisposableScope {
+            is_async,
+            depth_of_loop_scope,
+            previous_break,
+            previous_continue,
+            previous_return,
+            jump_to_dispose_with_error,
+        } = scope;
+        let exits = TryStatementExits::take(self, true);
+        self.current_break = previous_break;
+        self.current_continue = previous_continue;
+        self.current_return = previous_return;
+
+        // Normal completion.
+        self.add_instruction(Instruction::PopExceptionJumpTarget);
+        compile_dispose_resources(self, is_async);
+        let jump_to_end = self.add_instruction_with_jump_slot(Instruction::Jump);
+
+        // Throw completion: The thrown value is in the result register.
+        // Disposing of the resources always ends in a throw, possibly of a
+        // SuppressedError.
+        self.set_jump_target_here(jump_to_dispose_with_error);
+        self.add_instruction(Instruction::DisposeResourcesWithError);
+        if is_async {
+            self.add_instruction(Instruction::Await);
+        }
+
+        // Break, continue and return completions.
+        exits.compile_exit_paths(self, depth_of_loop_scope, |ctx, _| {
+            ctx.add_instruction(Instruction::PopExceptionJumpTarget);
+            compile_dispose_resources(ctx, is_async);
+        });
+
+        self.set_jump_target_here(jump_to_end);
+    }
+
     fn add_instruction_with_jump_slot(&mut self, instruction: Instruction) -> JumpIndex {
         debug_assert_eq!(instruction.argument_count(), 2);
         debug_assert!(instruction.has_jump_slot());
@@ -443,6 +698,19 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         self.add_index(index);
     }
 
+    fn add_template_literal(&mut self, template_literal: &ast::TemplateLiteral) {
+        let instruction = Instruction::GetTemplateObject;
+        debug_assert_eq!(instruction.argument_count(), 1);
+        self._push_instruction(instruction);
+        self.template_literals.push(SendableRef::new(unsafe {
+            core::mem::transmute::<&ast::TemplateLiteral<'_>, &'static ast::TemplateLiteral<'static>>(
+                template_literal,
+            )
+        }));
+        let index = self.template_literals.len() - 1;
+        self.add_index(index);
+    }
+
     fn add_jump_index(&mut self) -> JumpIndex {
         self.add_double_index(0);
         JumpIndex {
@@ -472,6 +740,84 @@ pub(crate) struct JumpIndex {
     pub(crate) index: usize,
 }
 
+/// Jump of a `break` or `continue` statement to its target statement.
+pub(crate) struct BreakOrContinueJump {
+    /// Label of the target statement, or `None` if the innermost enclosing
+    /// loop (or switch statement for `break`) is targeted.
+    label: Option<std::string::String>,
+    jump: JumpIndex,
+}
+
+/// State of the enclosing break and continue targets, saved while compiling
+/// a loop, switch or labelled statement.
+pub(crate) struct JumpTargetScope {
+    /// Labels of the statement.
+    label_set: Vec<std::string::String>,
+    /// If true, unlabelled break and continue jumps target the statement.
+    breakable: bool,
+    previous_depth_of_loop_scope: Option<u16>,
+    previous_break: Option<Vec<BreakOrContinueJump>>,
+    previous_continue: Option<Vec<BreakOrContinueJump>>,
+}
+
+impl JumpTargetScope {
+    fn is_target_of(&self, jump: &BreakOrContinueJump) -> bool {
+        match &jump.label {
+            Some(label) => self.label_set.contains(label),
+            None => self.breakable,
+        }
+    }
+}
+
+/// State of a scope containing `using` declarations, saved while compiling
+/// the scope.
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) struct DisposableScope {
+    /// If true, the scope contains `await using` declarations and its
+    /// resources are disposed of asynchronously.
+    is_async: bool,
+    depth_of_loop_scope: Option<u16>,
+    previous_break: Option<Vec<BreakOrContinueJump>>,
+    previous_continue: Option<Vec<BreakOrContinueJump>>,
+    previous_return: Option<Vec<JumpIndex>>,
+    jump_to_dispose_with_error: JumpIndex,
+}
+
+/// Returns `Some(is_async)` if the statements directly contain `using` or
+/// `await using` declarations.
+#[cfg(feature = "proposal-explicit-resource-management")]
+fn has_using_declarations(body: &[Statement]) -> Option<bool> {
+    let mut result = None;
+    for statement in body {
+        if let Statement::VariableDeclaration(decl) = statement {
+            match decl.kind {
+                ast::VariableDeclarationKind::Using => {
+                    result.get_or_insert(false);
+                }
+                ast::VariableDeclarationKind::AwaitUsing => return Some(true),
+                _ => {}
+            }
+        }
+    }
+    result
+}
+
+/// Perform DisposeResources on the current dispose capability, keeping the
+/// result value.
+#[cfg(feature = "proposal-explicit-resource-management")]
+fn compile_dispose_resources(ctx: &mut CompileContext, is_async: bool) {
+    if is_async {
+        // Note: Asynchronous disposal puts a promise in the result register,
+        // so the result value is kept on the stack while it is awaited.
+        ctx.add_instruction(Instruction::Load);
+        ctx.add_instruction(Instruction::DisposeResources);
+        ctx.add_instruction(Instruction::Await);
+        ctx.add_instruction(Instruction::Store);
+    } else {
+        ctx.add_instruction(Instruction::DisposeResources);
+    }
+}
+
 pub(crate) trait CompileEvaluation {
     fn compile(&self, ctx: &mut CompileContext);
 }
@@ -803,6 +1149,7 @@ impl CompileEvaluation for ast::Function<'_> {
                 }),
                 identifier,
                 compiled_bytecode: None,
+                compiled_initializer_bytecode: None,
             },
         );
     }
@@ -841,7 +1188,7 @@ impl CompileEvaluation for ast::ObjectExpression<'_> {
                         ast::PropertyKey::ObjectExpression(init) => init.compile(ctx),
                         ast::PropertyKey::ParenthesizedExpression(init) => init.compile(ctx),
                         ast::PropertyKey::PrivateFieldExpression(init) => init.compile(ctx),
-                        ast::PropertyKey::PrivateIdentifier(_init) => todo!(),
+                        ast::PropertyKey::PrivateIdentifier(_) => unreachable!(),
                         ast::PropertyKey::PrivateInExpression(init) => init.compile(ctx),
                         #[cfg(feature = "regexp")]
                         ast::PropertyKey::RegExpLiteral(init) => init.compile(ctx),
@@ -904,6 +1251,34 @@ impl CompileEvaluation for ast::ObjectExpression<'_> {
                         ctx.add_instruction(Instruction::Load);
                     }
                     match prop.kind {
+                        ast::PropertyKind::Init if prop.method => {
+                            // Methods need a [[HomeObject]] for super
+                            // property references, so they are defined with
+                            // MakeMethod like class methods are.
+                            let ast::Expression::FunctionExpression(function_expression) =
+                                &prop.value
+                            else {
+                                unreachable!()
+                            };
+                            ctx.add_instruction_with_function_expression_and_immediate(
+                                Instruction::ObjectDefineMethod,
+                                FunctionExpression {
+                                    expression: SendableRef::new(unsafe {
+                                        core::mem::transmute::<
+                                            &ast::Function<'_>,
+                                            &'static ast::Function<'static>,
+                                        >(
+                                            function_expression
+                                        )
+                                    }),
+                                    identifier: None,
+                                    compiled_bytecode: None,
+                                    compiled_initializer_bytecode: None,
+                                },
+                                // enumerable: true,
+                                true.into(),
+                            );
+                        }
                         ast::PropertyKind::Init => {
                             if !is_proto_setter && is_anonymous_function_definition(&prop.value) {
                                 ctx.name_identifier = Some(NamedEvaluationParameter::Stack);
@@ -946,6 +1321,7 @@ impl CompileEvaluation for ast::ObjectExpression<'_> {
                                     }),
                                     identifier: None,
                                     compiled_bytecode: None,
+                                    compiled_initializer_bytecode: None,
                                 },
                                 // enumerable: true,
                                 true.into(),
@@ -1199,6 +1575,25 @@ impl CompileEvaluation for ast::MemberExpression<'_> {
 
 impl CompileEvaluation for ast::ComputedMemberExpression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
+        if matches!(self.object, ast::Expression::Super(_)) {
+            // ### SuperProperty : super [ Expression ]
+            // 1. Let env be GetThisEnvironment().
+            // 2. Let actualThis be ? env.GetThisBinding().
+            ctx.add_instruction(Instruction::ResolveThisBinding);
+            ctx.add_instruction(Instruction::Load);
+            // 3. Let propertyNameReference be ? Evaluation of Expression.
+            self.expression.compile(ctx);
+            // 4. Let propertyNameValue be ? GetValue(propertyNameReference).
+            if is_reference(&self.expression) {
+                ctx.add_instruction(Instruction::GetValue);
+            }
+            // 5. Let propertyKey be ? ToPropertyKey(propertyNameValue).
+            // 6. Let strict be IsStrict(this SuperProperty).
+            // 7. Return MakeSuperPropertyReference(actualThis, propertyKey, strict).
+            ctx.add_instruction(Instruction::MakeSuperPropertyReferenceWithExpressionKey);
+            return;
+        }
+
         // 1. Let baseReference be ? Evaluation of MemberExpression.
         self.object.compile(ctx);
 
@@ -1249,6 +1644,16 @@ impl CompileEvaluation for ast::ComputedMemberExpression<'_> {
 
 impl CompileEvaluation for ast::StaticMemberExpression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
+        if matches!(self.object, ast::Expression::Super(_)) {
+            // ### SuperProperty : super . IdentifierName
+            let identifier = String::from_str(ctx.agent, self.property.name.as_str(), ctx.gc);
+            ctx.add_instruction_with_identifier(
+                Instruction::MakeSuperPropertyReferenceWithIdentifierKey,
+                identifier,
+            );
+            return;
+        }
+
         // 1. Let baseReference be ? Evaluation of MemberExpression.
         self.object.compile(ctx);
 
@@ -1290,8 +1695,42 @@ impl CompileEvaluation for ast::StaticMemberExpression<'_> {
 }
 
 impl CompileEvaluation for ast::PrivateFieldExpression<'_> {
-    fn compile(&self, _ctx: &mut CompileContext) {
-        todo!()
+    fn compile(&self, ctx: &mut CompileContext) {
+        // 1. Let baseReference be ? Evaluation of MemberExpression.
+        self.object.compile(ctx);
+
+        // 2. Let baseValue be ? GetValue(baseReference).
+        if is_reference(&self.object) {
+            ctx.add_instruction(Instruction::GetValue);
+        }
+
+        if self.optional {
+            // Optional Chains
+
+            // Load copy of baseValue to stack.
+            ctx.add_instruction(Instruction::LoadCopy);
+            // 3. If baseValue is either undefined or null, then
+            ctx.add_instruction(Instruction::IsNullOrUndefined);
+            // a. Return undefined
+
+            // To return undefined we jump over the property access.
+            let jump_over_property_access =
+                ctx.add_instruction_with_jump_slot(Instruction::JumpIfTrue);
+
+            // Register our jump slot to the chain nullish case handling.
+            ctx.optional_chains
+                .as_mut()
+                .unwrap()
+                .push(jump_over_property_access);
+
+            // Return copy of baseValue from stack if it is not.
+            ctx.add_instruction(Instruction::Store);
+        }
+
+        // 3. Let fieldNameString be the StringValue of PrivateIdentifier.
+        let field_name_string = String::from_str(ctx.agent, self.field.name.as_str(), ctx.gc);
+        // 4. Return MakePrivateReference(baseValue, fieldNameString).
+        ctx.add_instruction_with_identifier(Instruction::MakePrivateReference, field_name_string);
     }
 }
 
@@ -1406,8 +1845,29 @@ impl CompileEvaluation for ast::ConditionalExpression<'_> {
 }
 
 impl CompileEvaluation for ast::ImportExpression<'_> {
-    fn compile(&self, _ctx: &mut CompileContext) {
-        todo!()
+    /// ### [13.3.10.1 EvaluateImportCall ( specifierExpression \[ , optionsExpression \] )](https://tc39.es/ecma262/#sec-evaluate-import-call)
+    fn compile(&self, ctx: &mut CompileContext) {
+        // 3. Let specifierRef be ? Evaluation of specifierExpression.
+        self.source.compile(ctx);
+        // 4. Let specifier be ? GetValue(specifierRef).
+        if is_reference(&self.source) {
+            ctx.add_instruction(Instruction::GetValue);
+        }
+        ctx.add_instruction(Instruction::Load);
+        // 5. If optionsExpression is present, then
+        if let Some(options) = &self.options {
+            // a. Let optionsRef be ? Evaluation of optionsExpression.
+            options.compile(ctx);
+            // b. Let options be ? GetValue(optionsRef).
+            if is_reference(options) {
+                ctx.add_instruction(Instruction::GetValue);
+            }
+        } else {
+            // 6. Else,
+            // a. Let options be undefined.
+            ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
+        }
+        ctx.add_instruction(Instruction::ImportCall);
     }
 }
 
@@ -1415,15 +1875,32 @@ impl CompileEvaluation for ast::MetaProperty<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
         if self.meta.name == "new" && self.property.name == "target" {
             ctx.add_instruction(Instruction::GetNewTarget);
+        } else if self.meta.name == "import" && self.property.name == "meta" {
+            ctx.add_instruction(Instruction::ImportMeta);
         } else {
-            todo!();
+            unreachable!();
         }
     }
 }
 
 impl CompileEvaluation for ast::PrivateInExpression<'_> {
-    fn compile(&self, _ctx: &mut CompileContext) {
-        todo!()
+    /// RelationalExpression : PrivateIdentifier in ShiftExpression
+    fn compile(&self, ctx: &mut CompileContext) {
+        // 1. Let privateIdentifier be the StringValue of PrivateIdentifier.
+        let private_identifier = String::from_str(ctx.agent, self.left.name.as_str(), ctx.gc);
+        // 2. Let rRef be ? Evaluation of ShiftExpression.
+        self.right.compile(ctx);
+        // 3. Let rVal be ? GetValue(rRef).
+        if is_reference(&self.right) {
+            ctx.add_instruction(Instruction::GetValue);
+        }
+        // 4. If rVal is not an Object, throw a TypeError exception.
+        // 5. Let privateEnv be the running execution context's PrivateEnvironment.
+        // 6. Assert: privateEnv is not null.
+        // 7. Let privateName be ResolvePrivateIdentifier(privateEnv, privateIdentifier).
+        // 8. If PrivateElementFind(rVal, privateName) is not empty, return true.
+        // 9. Return false.
+        ctx.add_instruction_with_identifier(Instruction::HasPrivateElement, private_identifier);
     }
 }
 #[cfg(feature = "regexp")]
@@ -1436,8 +1913,27 @@ impl CompileEvaluation for ast::RegExpLiteral<'_> {
             ast::RegExpPattern::Pattern(_) => unreachable!(),
         };
         let pattern = String::from_str(ctx.agent, pattern, ctx.gc);
-        let regexp = reg_exp_create_literal(ctx.agent, pattern, Some(self.regex.flags), ctx.gc);
-        ctx.add_instruction_with_constant(Instruction::StoreConstant, regexp);
+        match reg_exp_create_literal(ctx.agent, pattern, Some(self.regex.flags), ctx.gc) {
+            Ok(regexp) => {
+                // Note: Each evaluation of a RegExp literal creates a new
+                // RegExp object. The compiled pattern is shared between them
+                // through the template object stored as a constant.
+                ctx.add_instruction_with_constant(Instruction::StoreConstant, regexp);
+                ctx.add_instruction(Instruction::InstantiateRegExpLiteral);
+            }
+            Err(message) => {
+                let message = String::from_string(
+                    ctx.agent,
+                    format!("Invalid regular expression: {message}"),
+                    ctx.gc,
+                );
+                ctx.add_instruction_with_constant(Instruction::StoreConstant, message);
+                ctx.add_instruction_with_immediate(
+                    Instruction::ThrowError,
+                    ExceptionType::SyntaxError as usize,
+                );
+            }
+        }
     }
 }
 
@@ -1451,13 +1947,63 @@ impl CompileEvaluation for ast::SequenceExpression<'_> {
 
 impl CompileEvaluation for ast::Super {
     fn compile(&self, _ctx: &mut CompileContext) {
-        todo!()
+        // Note: `super` only appears as the object of a SuperProperty or as
+        // the callee of a SuperCall, both of which are handled by their
+        // parent expressions.
+        unreachable!("`super` evaluated outside of SuperProperty or SuperCall")
     }
 }
 
+/// ### [13.3.11.1 Runtime Semantics: Evaluation](https://tc39.es/ecma262/#sec-tagged-templates-runtime-semantics-evaluation)
+///
+/// MemberExpression : MemberExpression TemplateLiteral
+///
+/// CallExpression : CallExpression TemplateLiteral
 impl CompileEvaluation for ast::TaggedTemplateExpression<'_> {
-    fn compile(&self, _ctx: &mut CompileContext) {
-        todo!()
+    fn compile(&self, ctx: &mut CompileContext) {
+        // 1. Let tagRef be ? Evaluation of MemberExpression.
+        self.tag.compile(ctx);
+        let need_pop_reference = if is_reference(&self.tag) {
+            // 2. Let tagFunc be ? GetValue(tagRef).
+            ctx.add_instruction(Instruction::GetValueKeepReference);
+            // Note: The substitutions are evaluated after the tag reference
+            // so it must be kept on the reference stack to preserve `this`.
+            if !self.quasi.expressions.is_empty() {
+                ctx.add_instruction(Instruction::PushReference);
+                true
+            } else {
+                false
+            }
+        } else {
+            false
+        };
+        ctx.add_instruction(Instruction::Load);
+        // 3. Let thisCall be this MemberExpression.
+        // 4. Let tailCall be IsInTailPosition(thisCall).
+        // 5. Return ? EvaluateCall(tagFunc, tagRef, TemplateLiteral, tailCall).
+
+        // ### 13.3.8.1 Runtime Semantics: ArgumentListEvaluation
+        // TemplateLiteral : SubstitutionTemplate
+        // 1. Let templateLiteral be this TemplateLiteral.
+        // 2. Let siteObj be GetTemplateObject(templateLiteral).
+        ctx.add_template_literal(&self.quasi);
+        ctx.add_instruction(Instruction::Load);
+        // 3. Let remaining be ? ArgumentListEvaluation of SubstitutionTemplate.
+        for expression in &self.quasi.expressions {
+            expression.compile(ctx);
+            if is_reference(expression) {
+                ctx.add_instruction(Instruction::GetValue);
+            }
+            ctx.add_instruction(Instruction::Load);
+        }
+        // 4. Return the list-concatenation of « siteObj » and remaining.
+        if need_pop_reference {
+            ctx.add_instruction(Instruction::PopReference);
+        }
+        ctx.add_instruction_with_immediate(
+            Instruction::EvaluateCall,
+            self.quasi.expressions.len() + 1,
+        );
     }
 }
 
@@ -1514,10 +2060,161 @@ impl CompileEvaluation for ast::ThisExpression {
     }
 }
 
+/// Compile AsyncIteratorClose of the current iterator with a normal
+/// completion. This pops the iterator off the iterator stack.
+fn compile_async_iterator_close(ctx: &mut CompileContext) {
+    // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+    // 4. If innerResult is a normal completion, then
+    //    b. If return is undefined, return ? completion.
+    //    c. Set innerResult to Completion(Call(return, iterator)).
+    let jump_to_end = ctx.add_instruction_with_jump_slot(Instruction::AsyncIteratorClose);
+    //    d. If innerResult is a normal completion, set innerResult to
+    //       Completion(Await(innerResult.[[Value]])).
+    ctx.add_instruction(Instruction::Await);
+    // 7. If innerResult.[[Value]] is not an Object, throw a TypeError exception.
+    ctx.add_instruction(Instruction::IsObject);
+    let jump_over_throw = ctx.add_instruction_with_jump_slot(Instruction::JumpIfTrue);
+    let error_message = String::from_static_str(
+        ctx.agent,
+        "Invalid iterator 'return' method return value",
+        ctx.gc,
+    );
+    ctx.add_instruction_with_constant(Instruction::StoreConstant, error_message);
+    ctx.add_instruction_with_immediate(Instruction::ThrowError, ExceptionType::TypeError as usize);
+    ctx.set_jump_target_here(jump_over_throw);
+    ctx.set_jump_target_here(jump_to_end);
+}
+
+/// ### [15.5.5 Runtime Semantics: Evaluation](https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluation)
+///
+/// YieldExpression : yield * AssignmentExpression
+fn compile_yield_delegate(argument: &ast::Expression, ctx: &mut CompileContext) {
+    // 1. Let generatorKind be GetGeneratorKind().
+    // 2. Assert: generatorKind is either sync or async.
+    let is_async = ctx.is_async_generator;
+    // 3. Let exprRef be ? Evaluation of AssignmentExpression.
+    argument.compile(ctx);
+    // 4. Let value be ? GetValue(exprRef).
+    if is_reference(argument) {
+        ctx.add_instruction(Instruction::GetValue);
+    }
+    // 5. Let iteratorRecord be ? GetIterator(value, generatorKind).
+    // 6. Let iterator be iteratorRecord.[[Iterator]].
+    ctx.add_instruction(if is_async {
+        Instruction::GetIteratorAsync
+    } else {
+        Instruction::GetIteratorSync
+    });
+    // 7. Let received be NormalCompletion(undefined).
+    ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
+    // 8. Repeat,
+    let repeat_jump = ctx.get_jump_index_to_here();
+    // a. If received is a normal completion, then
+    //    i. Let innerResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]], « received.[[Value]] »).
+    ctx.add_instruction(Instruction::IteratorCallNextMethodWithValue);
+    //    ii. If generatorKind is async, set innerResult to ? Await(innerResult).
+    if is_async {
+        ctx.add_instruction(Instruction::Await);
+    }
+    let check_inner_result = ctx.get_jump_index_to_here();
+    //    iii. If innerResult is not an Object, throw a TypeError exception.
+    //    iv. Let done be ? IteratorComplete(innerResult).
+    //    v. If done is true, then
+    let jump_to_done = ctx.add_instruction_with_jump_slot(Instruction::IteratorComplete);
+    let yield_inner_result = ctx.get_jump_index_to_here();
+    if is_async {
+        //    vi. If generatorKind is async, set received to
+        //        Completion(AsyncGeneratorYield(? IteratorValue(innerResult))).
+        ctx.add_instruction(Instruction::IteratorValue);
+    }
+    //    vii. Else, set received to Completion(GeneratorYield(innerResult)).
+    ctx.add_instruction(Instruction::YieldDelegate);
+    let jump_to_throw = ctx.add_instruction_with_jump_slot(Instruction::YieldThrowTarget);
+    let jump_to_return = ctx.add_instruction_with_jump_slot(Instruction::YieldReturnTarget);
+    ctx.add_jump_instruction_to_index(Instruction::Jump, repeat_jump);
+
+    // b. Else if received is a throw completion, then
+    ctx.set_jump_target_here(jump_to_throw);
+    //    i. Let throw be ? GetMethod(iterator, "throw").
+    //    ii. If throw is not undefined, then
+    //        1. Let innerResult be ? Call(throw, iterator, « received.[[Value]] »).
+    let jump_to_no_throw = ctx.add_instruction_with_jump_slot(Instruction::IteratorCallThrowMethod);
+    //        2. If generatorKind is async, set innerResult to ? Await(innerResult).
+    if is_async {
+        ctx.add_instruction(Instruction::Await);
+    }
+    //        3. NOTE: Exceptions from the inner iterator throw method are
+    //           propagated. Normal completions from an inner throw method
+    //           are processed similarly to an inner next.
+    ctx.add_jump_instruction_to_index(Instruction::Jump, check_inner_result);
+    //    iii. Else,
+    ctx.set_jump_target_here(jump_to_no_throw);
+    //         1. NOTE: If iterator does not have a throw method, this throw
+    //            is going to terminate the yield* loop. But first we need to
+    //            give iterator a chance to clean up.
+    //         2. Let closeCompletion be NormalCompletion(empty).
+    if is_async {
+        //     3. If generatorKind is async, perform ? AsyncIteratorClose(iteratorRecord, closeCompletion).
+        compile_async_iterator_close(ctx);
+    } else {
+        //     4. Else, perform ? IteratorClose(iteratorRecord, closeCompletion).
+        ctx.add_instruction(Instruction::IteratorClose);
+    }
+    //         5. NOTE: The next step throws a TypeError to indicate that
+    //            there was a yield* protocol violation: iterator does not
+    //            have a throw method.
+    //         6. Throw a TypeError exception.
+    let error_message =
+        String::from_static_str(ctx.agent, "Iterator does not have a throw method", ctx.gc);
+    ctx.add_instruction_with_constant(Instruction::StoreConstant, error_message);
+    ctx.add_instruction_with_immediate(Instruction::ThrowError, ExceptionType::TypeError as usize);
+
+    // c. Else,
+    //    i. Assert: received is a return completion.
+    ctx.set_jump_target_here(jump_to_return);
+    //    ii. Let return be ? GetMethod(iterator, "return").
+    //    iv. Let innerReturnResult be ? Call(return, iterator, « received.[[Value]] »).
+    let jump_to_no_return =
+        ctx.add_instruction_with_jump_slot(Instruction::IteratorCallReturnMethod);
+    //    v. If generatorKind is async, set innerReturnResult to ? Await(innerReturnResult).
+    if is_async {
+        ctx.add_instruction(Instruction::Await);
+    }
+    //    vi. If innerReturnResult is not an Object, throw a TypeError exception.
+    //    vii. Let done be ? IteratorComplete(innerReturnResult).
+    //    viii. If done is true, then
+    let jump_to_return_done = ctx.add_instruction_with_jump_slot(Instruction::IteratorComplete);
+    //    ix. If generatorKind is async, set received to
+    //        Completion(AsyncGeneratorYield(? IteratorValue(innerReturnResult))).
+    //    x. Else, set received to Completion(GeneratorYield(innerReturnResult)).
+    ctx.add_jump_instruction_to_index(Instruction::Jump, yield_inner_result);
+    //    viii. If done is true, then
+    ctx.set_jump_target_here(jump_to_return_done);
+    //          1. Set value to ? IteratorValue(innerReturnResult).
+    ctx.add_instruction(Instruction::IteratorValue);
+    //    iii. If return is undefined, then
+    //         1. Set value to received.[[Value]].
+    ctx.set_jump_target_here(jump_to_no_return);
+    //         2. If generatorKind is async, then
+    //            a. Set value to ? Await(value).
+    if is_async {
+        ctx.add_instruction(Instruction::Await);
+    }
+    //         3. Return ReturnCompletion(value).
+    ctx.add_return();
+
+    // a. v. If done is true, then
+    ctx.set_jump_target_here(jump_to_done);
+    //       1. Return ? IteratorValue(innerResult).
+    ctx.add_instruction(Instruction::IteratorValue);
+}
+
 impl CompileEvaluation for ast::YieldExpression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
         if self.delegate {
-            todo!("`yield*` is not yet supported");
+            // Note: yield* always has an argument.
+            compile_yield_delegate(self.argument.as_ref().unwrap(), ctx);
+            return;
         }
         if let Some(arg) = &self.argument {
             // YieldExpression : yield AssignmentExpression
@@ -1534,6 +2231,12 @@ impl CompileEvaluation for ast::YieldExpression<'_> {
         }
         // 3. Return ? Yield(value).
         ctx.add_instruction(Instruction::Yield);
+        if ctx.current_return.is_some() {
+            // If the generator is resumed with a return completion, we need
+            // to run the finally blocks of the try statements we're in.
+            let return_jump = ctx.add_instruction_with_jump_slot(Instruction::YieldReturnTarget);
+            ctx.current_return.as_mut().unwrap().push(return_jump);
+        }
     }
 }
 
@@ -1596,7 +2299,7 @@ impl CompileEvaluation for ast::UpdateExpression<'_> {
         match &self.argument {
             ast::SimpleAssignmentTarget::AssignmentTargetIdentifier(x) => x.compile(ctx),
             ast::SimpleAssignmentTarget::ComputedMemberExpression(x) => x.compile(ctx),
-            ast::SimpleAssignmentTarget::PrivateFieldExpression(_) => todo!(),
+            ast::SimpleAssignmentTarget::PrivateFieldExpression(x) => x.compile(ctx),
             ast::SimpleAssignmentTarget::StaticMemberExpression(x) => x.compile(ctx),
             ast::SimpleAssignmentTarget::TSAsExpression(_)
             | ast::SimpleAssignmentTarget::TSNonNullExpression(_)
@@ -1649,7 +2352,40 @@ impl CompileEvaluation for ast::ReturnStatement<'_> {
         } else {
             ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
         }
-        ctx.add_instruction(Instruction::Return);
+        ctx.add_return();
+    }
+}
+
+/// ### [14.11.2 Runtime Semantics: Evaluation](https://tc39.es/ecma262/#sec-with-statement-runtime-semantics-evaluation)
+///
+/// WithStatement : with ( Expression ) Statement
+impl CompileEvaluation for ast::WithStatement<'_> {
+    fn compile(&self, ctx: &mut CompileContext) {
+        // 1. Let val be ? Evaluation of Expression.
+        self.object.compile(ctx);
+        // 2. Let obj be ? ToObject(? GetValue(val)).
+        if is_reference(&self.object) {
+            ctx.add_instruction(Instruction::GetValue);
+        }
+        ctx.add_instruction(Instruction::ToObject);
+        // 3. Let oldEnv be the running execution context's LexicalEnvironment.
+        // 4. Let newEnv be NewObjectEnvironment(obj, true, oldEnv).
+        // 5. Set the running execution context's LexicalEnvironment to newEnv.
+        ctx.add_instruction(Instruction::EnterObjectEnvironment);
+        if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
+            *i += 1;
+        }
+        // 6. Let C be Completion(Evaluation of Statement).
+        // Note: the result value was taken by EnterObjectEnvironment, so the
+        // completion value starts out as undefined.
+        ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
+        self.body.compile(ctx);
+        // 7. Set the running execution context's LexicalEnvironment to oldEnv.
+        ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
+        if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
+            *i -= 1;
+        }
+        // 8. Return ? UpdateEmpty(C, undefined).
     }
 }
 
@@ -2028,9 +2764,19 @@ fn complex_object_pattern(
                     identifier_string,
                 );
             }
-            ast::PropertyKey::PrivateIdentifier(_) => todo!(),
+            ast::PropertyKey::PrivateIdentifier(_) => unreachable!(),
             _ => {
-                property.key.to_expression().compile(ctx);
+                // Keep a copy of the object on the stack for the following
+                // properties; EvaluatePropertyAccessWithExpressionKey pops
+                // its base value off the stack.
+                ctx.add_instruction(Instruction::Store);
+                ctx.add_instruction(Instruction::LoadCopy);
+                ctx.add_instruction(Instruction::Load);
+                let key = property.key.to_expression();
+                key.compile(ctx);
+                if is_reference(key) {
+                    ctx.add_instruction(Instruction::GetValue);
+                }
                 ctx.add_instruction(Instruction::EvaluatePropertyAccessWithExpressionKey);
             }
         }
@@ -2101,6 +2847,7 @@ fn complex_object_pattern(
             unreachable!()
         };
 
+        ctx.add_instruction(Instruction::Store);
         // We have kept the references for all of the properties read in the reference stack, so we
         // can now use them to exclude those properties from the rest object.
         ctx.add_instruction_with_immediate(
@@ -2187,82 +2934,106 @@ impl CompileEvaluation for ast::VariableDeclaration<'_> {
                     ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
                 }
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ast::VariableDeclarationKind::Let
+            | ast::VariableDeclarationKind::Const
+            | ast::VariableDeclarationKind::Using
+            | ast::VariableDeclarationKind::AwaitUsing => compile_lexical_declaration(self, ctx),
+            #[cfg(not(feature = "proposal-explicit-resource-management"))]
             ast::VariableDeclarationKind::Let | ast::VariableDeclarationKind::Const => {
-                for decl in &self.declarations {
-                    let ast::BindingPatternKind::BindingIdentifier(identifier) = &decl.id.kind
-                    else {
-                        ctx.lexical_binding_state = true;
-                        let init = decl.init.as_ref().unwrap();
+                compile_lexical_declaration(self, ctx)
+            }
+            #[cfg(not(feature = "proposal-explicit-resource-management"))]
+            ast::VariableDeclarationKind::Using | ast::VariableDeclarationKind::AwaitUsing => {
+                unreachable!()
+            }
+        }
+    }
+}
 
-                        //  LexicalBinding : BindingPattern Initializer
-                        // 1. Let rhs be ? Evaluation of Initializer.
-                        init.compile(ctx);
-                        // 2. Let value be ? GetValue(rhs).
-                        if is_reference(init) {
-                            ctx.add_instruction(Instruction::GetValue);
-                        }
-                        // 3. Let env be the running execution context's LexicalEnvironment.
-                        // 4. Return ? BindingInitialization of BindingPattern with arguments value and env.
-                        ctx.add_instruction(Instruction::Load);
-                        match &decl.id.kind {
-                            ast::BindingPatternKind::BindingIdentifier(_) => unreachable!(),
-                            ast::BindingPatternKind::ObjectPattern(pattern) => pattern.compile(ctx),
-                            ast::BindingPatternKind::ArrayPattern(pattern) => pattern.compile(ctx),
-                            ast::BindingPatternKind::AssignmentPattern(_) => unreachable!(),
-                        }
-                        return;
-                    };
+/// ### [14.3.1.2 Runtime Semantics: Evaluation](https://tc39.es/ecma262/#sec-let-and-const-declarations-runtime-semantics-evaluation)
+///
+/// Also covers `using` and `await using` declarations, which add the value of
+/// each binding to the scope's dispose capability before initializing it.
+fn compile_lexical_declaration(declaration: &ast::VariableDeclaration, ctx: &mut CompileContext) {
+    for decl in &declaration.declarations {
+        let ast::BindingPatternKind::BindingIdentifier(identifier) = &decl.id.kind else {
+            ctx.lexical_binding_state = true;
+            let init = decl.init.as_ref().unwrap();
 
-                    // 1. Let lhs be ! ResolveBinding(StringValue of BindingIdentifier).
-                    let identifier_string =
-                        String::from_str(ctx.agent, identifier.name.as_str(), ctx.gc);
-                    let identifier = ctx.add_identifier(identifier_string);
-                    ctx.add_instruction_with_immediate(Instruction::ResolveBinding, identifier);
+            //  LexicalBinding : BindingPattern Initializer
+            // 1. Let rhs be ? Evaluation of Initializer.
+            init.compile(ctx);
+            // 2. Let value be ? GetValue(rhs).
+            if is_reference(init) {
+                ctx.add_instruction(Instruction::GetValue);
+            }
+            // 3. Let env be the running execution context's LexicalEnvironment.
+            // 4. Return ? BindingInitialization of BindingPattern with arguments value and env.
+            ctx.add_instruction(Instruction::Load);
+            match &decl.id.kind {
+                ast::BindingPatternKind::BindingIdentifier(_) => unreachable!(),
+                ast::BindingPatternKind::ObjectPattern(pattern) => pattern.compile(ctx),
+                ast::BindingPatternKind::ArrayPattern(pattern) => pattern.compile(ctx),
+                ast::BindingPatternKind::AssignmentPattern(_) => unreachable!(),
+            }
+            return;
+        };
 
-                    let Some(init) = &decl.init else {
-                        // LexicalBinding : BindingIdentifier
-                        // 2. Perform ! InitializeReferencedBinding(lhs, undefined).
-                        ctx.add_instruction_with_constant(
-                            Instruction::StoreConstant,
-                            Value::Undefined,
-                        );
-                        ctx.add_instruction(Instruction::InitializeReferencedBinding);
-                        // 3. Return empty.
-                        ctx.add_instruction_with_constant(
-                            Instruction::StoreConstant,
-                            Value::Undefined,
-                        );
-                        return;
-                    };
+        // 1. Let lhs be ! ResolveBinding(StringValue of BindingIdentifier).
+        let identifier_string = String::from_str(ctx.agent, identifier.name.as_str(), ctx.gc);
+        let identifier = ctx.add_identifier(identifier_string);
+        ctx.add_instruction_with_immediate(Instruction::ResolveBinding, identifier);
 
-                    //  LexicalBinding : BindingIdentifier Initializer
-                    ctx.add_instruction(Instruction::PushReference);
-                    // 3. If IsAnonymousFunctionDefinition(Initializer) is true, then
-                    if is_anonymous_function_definition(init) {
-                        // a. Let value be ? NamedEvaluation of Initializer with argument bindingId.
-                        ctx.add_instruction_with_immediate(Instruction::LoadConstant, identifier);
-                        ctx.name_identifier = Some(NamedEvaluationParameter::Stack);
-                        init.compile(ctx);
-                    } else {
-                        // 4. Else,
-                        // a. Let rhs be ? Evaluation of Initializer.
-                        init.compile(ctx);
-                        // b. Let value be ? GetValue(rhs).
-                        if is_reference(init) {
-                            ctx.add_instruction(Instruction::GetValue);
-                        }
-                    }
+        let Some(init) = &decl.init else {
+            // LexicalBinding : BindingIdentifier
+            // 2. Perform ! InitializeReferencedBinding(lhs, undefined).
+            ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
+            ctx.add_instruction(Instruction::InitializeReferencedBinding);
+            // 3. Return empty.
+            ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
+            return;
+        };
 
-                    // 5. Perform ! InitializeReferencedBinding(lhs, value).
-                    ctx.add_instruction(Instruction::PopReference);
-                    ctx.add_instruction(Instruction::InitializeReferencedBinding);
-                    // 6. Return empty.
-                    ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
-                }
+        //  LexicalBinding : BindingIdentifier Initializer
+        ctx.add_instruction(Instruction::PushReference);
+        // 3. If IsAnonymousFunctionDefinition(Initializer) is true, then
+        if is_anonymous_function_definition(init) {
+            // a. Let value be ? NamedEvaluation of Initializer with argument bindingId.
+            ctx.add_instruction_with_immediate(Instruction::LoadConstant, identifier);
+            ctx.name_identifier = Some(NamedEvaluationParameter::Stack);
+            init.compile(ctx);
+        } else {
+            // 4. Else,
+            // a. Let rhs be ? Evaluation of Initializer.
+            init.compile(ctx);
+            // b. Let value be ? GetValue(rhs).
+            if is_reference(init) {
+                ctx.add_instruction(Instruction::GetValue);
             }
-            ast::VariableDeclarationKind::Using => todo!(),
-            ast::VariableDeclarationKind::AwaitUsing => todo!(),
         }
+
+        // UsingDeclaration : using BindingList ;
+        // AwaitUsingDeclaration : CoverAwaitExpressionAndAwaitUsingDeclarationHead BindingList ;
+        // Note: InitializeReferencedBinding with a hint of
+        // sync-dispose or async-dispose performs
+        // AddDisposableResource(env.[[DisposeCapability]], value, hint).
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        match declaration.kind {
+            ast::VariableDeclarationKind::Using => {
+                ctx.add_instruction(Instruction::AddDisposableResource)
+            }
+            ast::VariableDeclarationKind::AwaitUsing => {
+                ctx.add_instruction(Instruction::AddAsyncDisposableResource)
+            }
+            _ => {}
+        }
+
+        // 5. Perform ! InitializeReferencedBinding(lhs, value).
+        ctx.add_instruction(Instruction::PopReference);
+        ctx.add_instruction(Instruction::InitializeReferencedBinding);
+        // 6. Return empty.
+        ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
     }
 }
 
@@ -2275,6 +3046,8 @@ impl CompileEvaluation for ast::BlockStatement<'_> {
         }
         let did_enter_declarative_environment =
             block_declaration_instantiation::instantiation(ctx, self);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let disposable_scope = ctx.enter_disposable_scope_for(&self.body);
         for ele in &self.body {
             ele.compile(ctx);
         }
@@ -2282,6 +3055,11 @@ impl CompileEvaluation for ast::BlockStatement<'_> {
             // Block did not end in a return so we overwrite the result with undefined.
             ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
         }
+        // Set blockValue to DisposeResources(blockEnv.[[DisposeCapability]], blockValue).
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        if let Some(disposable_scope) = disposable_scope {
+            ctx.exit_disposable_scope(disposable_scope);
+        }
         if did_enter_declarative_environment {
             ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
             if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
@@ -2293,12 +3071,12 @@ impl CompileEvaluation for ast::BlockStatement<'_> {
 
 impl CompileEvaluation for ast::ForStatement<'_> {
     fn compile<'gc>(&self, ctx: &mut CompileContext<'_, 'gc, '_>) {
-        let previous_depth_of_loop = ctx.current_depth_of_loop_scope.replace(0);
-        let previous_continue = ctx.current_continue.replace(vec![]);
-        let previous_break = ctx.current_break.replace(vec![]);
+        let scope = ctx.enter_breakable_statement();
 
         let mut per_iteration_lets: Vec<String<'_>> = vec![];
         let mut is_lexical = false;
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let mut disposable_scope = None;
 
         if let Some(init) = &self.init {
             match init {
@@ -2381,6 +3159,19 @@ impl CompileEvaluation for ast::ForStatement<'_> {
                         }
                         // 6. Set the running execution context's LexicalEnvironment to loopEnv.
                     }
+                    // Note: `using` and `await using` declarations add their
+                    // values to loopEnv.[[DisposeCapability]], which is
+                    // disposed of when the loop is exited.
+                    #[cfg(feature = "proposal-explicit-resource-management")]
+                    match init.kind {
+                        ast::VariableDeclarationKind::Using => {
+                            disposable_scope = Some(ctx.enter_disposable_scope(false));
+                        }
+                        ast::VariableDeclarationKind::AwaitUsing => {
+                            disposable_scope = Some(ctx.enter_disposable_scope(true));
+                        }
+                        _ => {}
+                    }
                     init.compile(ctx);
                 }
                 ast::ForStatementInit::YieldExpression(init) => init.compile(ctx),
@@ -2458,8 +3249,7 @@ impl CompileEvaluation for ast::ForStatement<'_> {
 
         self.body.compile(ctx);
 
-        let own_continues = ctx.current_continue.take().unwrap();
-        for continue_entry in own_continues {
+        for continue_entry in ctx.take_own_continues(&scope) {
             ctx.set_jump_target_here(continue_entry);
         }
 
@@ -2473,24 +3263,27 @@ impl CompileEvaluation for ast::ForStatement<'_> {
         ctx.add_jump_instruction_to_index(Instruction::Jump, loop_jump);
         ctx.set_jump_target_here(end_jump);
 
-        let own_breaks = ctx.current_break.take().unwrap();
-        for break_entry in own_breaks {
+        for break_entry in ctx.take_own_breaks(&scope) {
             ctx.set_jump_target_here(break_entry);
         }
-        if is_lexical {
-            // Lexical binding loops have an extra declarative environment that
-            // we need to exit from once we exit the loop.
-            ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        if let Some(disposable_scope) = disposable_scope {
+            ctx.exit_disposable_scope(disposable_scope);
         }
-        ctx.current_break = previous_break;
-        ctx.current_continue = previous_continue;
-        ctx.current_depth_of_loop_scope = previous_depth_of_loop;
+        // Lexical binding loops have an extra declarative environment that
+        // we need to exit from once we exit the loop.
+        let exit_loop_environment = |ctx: &mut CompileContext| {
+            if is_lexical {
+                ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
+            }
+        };
+        exit_loop_environment(ctx);
+        ctx.exit_jump_target(scope, exit_loop_environment);
     }
 }
 
 impl CompileEvaluation for ast::SwitchStatement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
-        let previous_break = ctx.current_break.replace(vec![]);
         // 1. Let exprRef be ? Evaluation of Expression.
         self.discriminant.compile(ctx);
         if is_reference(&self.discriminant) {
@@ -2504,6 +3297,9 @@ impl CompileEvaluation for ast::SwitchStatement<'_> {
         // 5. Perform BlockDeclarationInstantiation(CaseBlock, blockEnv).
         let did_enter_declarative_environment =
             block_declaration_instantiation::instantiation(ctx, self);
+        // Note: The block environment is counted in the enclosing statement's
+        // depth, so break statements targeting the switch don't exit it.
+        let scope = ctx.enter_breakable_statement();
 
         // 7. Let R be Completion(CaseBlockEvaluation of CaseBlock with argument switchValue).
         let mut has_default = false;
@@ -2572,11 +3368,10 @@ impl CompileEvaluation for ast::SwitchStatement<'_> {
             }
         }
 
-        let own_breaks = ctx.current_break.take().unwrap();
-        for break_entry in own_breaks {
+        for break_entry in ctx.take_own_breaks(&scope) {
             ctx.set_jump_target_here(break_entry);
         }
-        ctx.current_break = previous_break;
+        ctx.exit_jump_target(scope, |_| {});
 
         // 8. Set the running execution context's LexicalEnvironment to oldEnv.
         if did_enter_declarative_environment {
@@ -2599,82 +3394,263 @@ impl CompileEvaluation for ast::ThrowStatement<'_> {
     }
 }
 
-impl CompileEvaluation for ast::TryStatement<'_> {
-    fn compile(&self, ctx: &mut CompileContext) {
-        if self.finalizer.is_some() {
-            todo!();
+/// Break, continue and return jumps that leave a try statement's block or
+/// catch clause.
+struct TryStatementExits {
+    breaks: Vec<BreakOrContinueJump>,
+    continues: Vec<BreakOrContinueJump>,
+    returns: Vec<JumpIndex>,
+}
+
+impl TryStatementExits {
+    /// Take the exits collected so far, leaving empty lists in their place.
+    /// Return jumps are only collected if `returns` is true.
+    fn take(ctx: &mut CompileContext, returns: bool) -> Self {
+        Self {
+            breaks: ctx.current_break.replace(vec![]).unwrap(),
+            continues: ctx.current_continue.replace(vec![]).unwrap(),
+            returns: if returns {
+                ctx.current_return.replace(vec![]).unwrap()
+            } else {
+                vec![]
+            },
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.breaks.is_empty() && self.continues.is_empty() && self.returns.is_empty()
+    }
+
+    /// Compile an exit path for each jump target present: The path runs
+    /// `on_exit` and then continues on to the enclosing jump target. Break
+    /// and continue paths also exit the `depth_of_loop_scope` declarative
+    /// environments that their target is outside of.
+    fn compile_exit_paths(
+        self,
+        ctx: &mut CompileContext,
+        depth_of_loop_scope: Option<u16>,
+        on_exit: impl Fn(&mut CompileContext, bool),
+    ) {
+        ctx.compile_forwarded_jumps(self.breaks, self.continues, depth_of_loop_scope, |ctx| {
+            on_exit(ctx, false)
+        });
+        if !self.returns.is_empty() {
+            for return_entry in self.returns {
+                ctx.set_jump_target_here(return_entry);
+            }
+            on_exit(ctx, true);
+            ctx.add_return();
+        }
+    }
+}
+
+/// Compile a finally block that is run while a thrown or returned value is
+/// saved on the stack. If the block itself exits with a break, continue or
+/// return statement, the saved value is popped off the stack before the jump
+/// continues on to its target.
+fn compile_finalizer_with_saved_value(ctx: &mut CompileContext, finalizer: &ast::BlockStatement) {
+    let has_return_exits = ctx.current_return.is_some();
+    let previous_break = ctx.current_break.replace(vec![]);
+    let previous_continue = ctx.current_continue.replace(vec![]);
+    let previous_return = if has_return_exits {
+        ctx.current_return.replace(vec![])
+    } else {
+        None
+    };
+
+    finalizer.compile(ctx);
+
+    let exits = TryStatementExits::take(ctx, has_return_exits);
+    ctx.current_break = previous_break;
+    ctx.current_continue = previous_continue;
+    ctx.current_return = previous_return;
+    if exits.is_empty() {
+        return;
+    }
+    let jump_over_exits = ctx.add_instruction_with_jump_slot(Instruction::Jump);
+    // Note: The jumps have already exited their declarative environments.
+    exits.compile_exit_paths(ctx, None, |ctx, is_return| {
+        if is_return {
+            // Discard the saved value while keeping the return value.
+            ctx.add_instruction(Instruction::LoadStoreSwap);
+            ctx.add_instruction(Instruction::Store);
+        } else {
+            // The result value may be empty: Discard the saved value by
+            // storing it and then replacing it.
+            ctx.add_instruction(Instruction::Store);
+            ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
         }
+    });
+    ctx.set_jump_target_here(jump_over_exits);
+}
 
-        let jump_to_catch =
-            ctx.add_instruction_with_jump_slot(Instruction::PushExceptionJumpTarget);
+impl CompileEvaluation for ast::TryStatement<'_> {
+    /// ### [14.15.3 Runtime Semantics: Evaluation](https://tc39.es/ecma262/#sec-try-statement-runtime-semantics-evaluation)
+    fn compile(&self, ctx: &mut CompileContext) {
+        let finalizer = self.finalizer.as_deref();
+        // Note: Leaving the try block or catch clause with a break, continue
+        // or return statement must pop the exception jump targets we push,
+        // and run the finally block. Those jumps are collected while
+        // compiling the block and clause, and are then routed through exit
+        // paths that do exactly that. Returns only need to be routed if there
+        // is a finally block to run, either here or in an enclosing try
+        // statement.
+        let depth_of_loop_scope = ctx.current_depth_of_loop_scope;
+        let has_return_exits = finalizer.is_some() || ctx.current_return.is_some();
+        let mut previous_break = ctx.current_break.replace(vec![]);
+        let mut previous_continue = ctx.current_continue.replace(vec![]);
+        let mut previous_return = if has_return_exits {
+            ctx.current_return.replace(vec![])
+        } else {
+            None
+        };
+        // Note: The exit paths exit the declarative environments between
+        // this statement and the jump target after running the finally block,
+        // so jumps from within only exit the environments they entered here.
+        if let Some(depth) = ctx.current_depth_of_loop_scope.as_mut() {
+            *depth = 0;
+        }
+
+        let jump_to_finally = finalizer
+            .map(|_| ctx.add_instruction_with_jump_slot(Instruction::PushExceptionJumpTarget));
+        let jump_to_catch = self
+            .handler
+            .as_ref()
+            .map(|_| ctx.add_instruction_with_jump_slot(Instruction::PushExceptionJumpTarget));
+
+        // TryStatement : try Block Catch
+        // 1. Let B be Completion(Evaluation of Block).
         self.block.compile(ctx);
-        ctx.add_instruction(Instruction::PopExceptionJumpTarget);
-        let jump_to_end = ctx.add_instruction_with_jump_slot(Instruction::Jump);
 
-        let catch_clause = self.handler.as_ref().unwrap();
-        ctx.set_jump_target_here(jump_to_catch);
+        if let Some(catch_clause) = &self.handler {
+            ctx.add_instruction(Instruction::PopExceptionJumpTarget);
+            let jump_to_end = ctx.add_instruction_with_jump_slot(Instruction::Jump);
 
-        if let Some(exception_param) = &catch_clause.param {
-            // 1. Let oldEnv be the running execution context's LexicalEnvironment.
-            // 2. Let catchEnv be NewDeclarativeEnvironment(oldEnv).
-            // 4. Set the running execution context's LexicalEnvironment to catchEnv.
-            // Note: We skip the declarative environment if there is no catch
-            // param as it's not observable.
-            ctx.add_instruction(Instruction::EnterDeclarativeEnvironment);
-            if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
-                *i += 1;
-            }
-            // 3. For each element argName of the BoundNames of CatchParameter, do
-            // a. Perform ! catchEnv.CreateMutableBinding(argName, false).
-            exception_param.pattern.bound_names(&mut |arg_name| {
-                let arg_name = String::from_str(ctx.agent, arg_name.name.as_str(), ctx.gc);
-                ctx.add_instruction_with_identifier(Instruction::CreateMutableBinding, arg_name);
-            });
-            // 5. Let status be Completion(BindingInitialization of CatchParameter with arguments thrownValue and catchEnv).
-            // 6. If status is an abrupt completion, then
-            // a. Set the running execution context's LexicalEnvironment to oldEnv.
-            // b. Return ? status.
-            match &exception_param.pattern.kind {
-                ast::BindingPatternKind::BindingIdentifier(identifier) => {
-                    let identifier_string = ctx.create_identifier(&identifier.name);
+            // Jumps out of the try block must first pop the catch clause's
+            // exception jump target.
+            let block_exits = TryStatementExits::take(ctx, has_return_exits);
+            let on_exit = |ctx: &mut CompileContext, _: bool| {
+                ctx.add_instruction(Instruction::PopExceptionJumpTarget);
+            };
+            if finalizer.is_some() {
+                // The jumps then go through the finally block exit paths.
+                block_exits.compile_exit_paths(ctx, None, on_exit);
+            } else {
+                ctx.current_break = previous_break.take();
+                ctx.current_continue = previous_continue.take();
+                ctx.current_return = previous_return.take();
+                ctx.current_depth_of_loop_scope = depth_of_loop_scope;
+                block_exits.compile_exit_paths(ctx, depth_of_loop_scope, on_exit);
+            }
+
+            // 2. If B is a throw completion, let C be Completion(CatchClauseEvaluation of Catch with argument B.[[Value]]).
+            ctx.set_jump_target_here(jump_to_catch.unwrap());
+            if let Some(exception_param) = &catch_clause.param {
+                // 1. Let oldEnv be the running execution context's LexicalEnvironment.
+                // 2. Let catchEnv be NewDeclarativeEnvironment(oldEnv).
+                // 4. Set the running execution context's LexicalEnvironment to catchEnv.
+                // Note: We skip the declarative environment if there is no catch
+                // param as it's not observable.
+                ctx.add_instruction(Instruction::EnterDeclarativeEnvironment);
+                if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
+                    *i += 1;
+                }
+                // 3. For each element argName of the BoundNames of CatchParameter, do
+                // a. Perform ! catchEnv.CreateMutableBinding(argName, false).
+                exception_param.pattern.bound_names(&mut |arg_name| {
+                    let arg_name = String::from_str(ctx.agent, arg_name.name.as_str(), ctx.gc);
                     ctx.add_instruction_with_identifier(
-                        Instruction::ResolveBinding,
-                        identifier_string,
+                        Instruction::CreateMutableBinding,
+                        arg_name,
                     );
-                    ctx.add_instruction(Instruction::InitializeReferencedBinding);
-                }
-                ast::BindingPatternKind::ObjectPattern(pattern) => {
-                    ctx.add_instruction(Instruction::Load);
-                    ctx.lexical_binding_state = true;
-                    pattern.compile(ctx);
+                });
+                // 5. Let status be Completion(BindingInitialization of CatchParameter with arguments thrownValue and catchEnv).
+                // 6. If status is an abrupt completion, then
+                // a. Set the running execution context's LexicalEnvironment to oldEnv.
+                // b. Return ? status.
+                match &exception_param.pattern.kind {
+                    ast::BindingPatternKind::BindingIdentifier(identifier) => {
+                        let identifier_string = ctx.create_identifier(&identifier.name);
+                        ctx.add_instruction_with_identifier(
+                            Instruction::ResolveBinding,
+                            identifier_string,
+                        );
+                        ctx.add_instruction(Instruction::InitializeReferencedBinding);
+                    }
+                    ast::BindingPatternKind::ObjectPattern(pattern) => {
+                        ctx.add_instruction(Instruction::Load);
+                        ctx.lexical_binding_state = true;
+                        pattern.compile(ctx);
+                    }
+                    ast::BindingPatternKind::ArrayPattern(pattern) => {
+                        ctx.add_instruction(Instruction::Load);
+                        ctx.lexical_binding_state = true;
+                        pattern.compile(ctx);
+                    }
+                    ast::BindingPatternKind::AssignmentPattern(_) => unreachable!(),
                 }
-                ast::BindingPatternKind::ArrayPattern(pattern) => {
-                    ctx.add_instruction(Instruction::Load);
-                    ctx.lexical_binding_state = true;
-                    pattern.compile(ctx);
+            }
+            // 7. Let B be Completion(Evaluation of Block).
+            catch_clause.body.compile(ctx);
+            // 8. Set the running execution context's LexicalEnvironment to oldEnv.
+            if catch_clause.param.is_some() {
+                ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
+                if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
+                    *i -= 1;
                 }
-                ast::BindingPatternKind::AssignmentPattern(_) => unreachable!(),
             }
+            // 3. Else, let C be B.
+            ctx.set_jump_target_here(jump_to_end);
         }
-        // 7. Let B be Completion(Evaluation of Block).
-        catch_clause.body.compile(ctx);
-        // 8. Set the running execution context's LexicalEnvironment to oldEnv.
-        if catch_clause.param.is_some() {
-            ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
-            if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
-                *i -= 1;
+
+        let Some(finalizer) = finalizer else {
+            // 4. Return ? UpdateEmpty(C, undefined).
+            ctx.current_depth_of_loop_scope = depth_of_loop_scope;
+            return;
+        };
+
+        // TryStatement : try Block Finally
+        // TryStatement : try Block Catch Finally
+        let exits = TryStatementExits::take(ctx, true);
+        ctx.current_break = previous_break;
+        ctx.current_continue = previous_continue;
+        ctx.current_return = previous_return;
+        ctx.current_depth_of_loop_scope = depth_of_loop_scope;
+
+        // 2. Let F be Completion(Evaluation of Finally).
+        // 3. If F is a normal completion, set F to B.
+        ctx.add_instruction(Instruction::PopExceptionJumpTarget);
+        finalizer.compile(ctx);
+        let jump_to_end = ctx.add_instruction_with_jump_slot(Instruction::Jump);
+
+        // Throw completion: The thrown value is in the result register.
+        ctx.set_jump_target_here(jump_to_finally.unwrap());
+        ctx.add_instruction(Instruction::Load);
+        compile_finalizer_with_saved_value(ctx, finalizer);
+        ctx.add_instruction(Instruction::Store);
+        ctx.add_instruction(Instruction::Throw);
+
+        // Break, continue and return completions: A return value is in the
+        // result register.
+        exits.compile_exit_paths(ctx, depth_of_loop_scope, |ctx, is_return| {
+            ctx.add_instruction(Instruction::PopExceptionJumpTarget);
+            if is_return {
+                ctx.add_instruction(Instruction::Load);
+                compile_finalizer_with_saved_value(ctx, finalizer);
+                ctx.add_instruction(Instruction::Store);
+            } else {
+                finalizer.compile(ctx);
             }
-        }
-        // 9. Return ? B.
+        });
+
+        // 4. Return ? UpdateEmpty(F, undefined).
         ctx.set_jump_target_here(jump_to_end);
     }
 }
 
 impl CompileEvaluation for ast::WhileStatement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
-        let previous_depth_of_loop = ctx.current_depth_of_loop_scope.replace(0);
-        let previous_continue = ctx.current_continue.replace(vec![]);
-        let previous_break = ctx.current_break.replace(vec![]);
+        let scope = ctx.enter_breakable_statement();
 
         // 2. Repeat
         let start_jump = ctx.get_jump_index_to_here();
@@ -2696,34 +3672,27 @@ impl CompileEvaluation for ast::WhileStatement<'_> {
         // e. If LoopContinues(stmtResult, labelSet) is false, return ? UpdateEmpty(stmtResult, V).
         // f. If stmtResult.[[Value]] is not EMPTY, set V to stmtResult.[[Value]].
         ctx.add_jump_instruction_to_index(Instruction::Jump, start_jump.clone());
-        let own_continues = ctx.current_continue.take().unwrap();
-        for continue_entry in own_continues {
+        for continue_entry in ctx.take_own_continues(&scope) {
             ctx.set_jump_target(continue_entry, start_jump.clone());
         }
 
         ctx.set_jump_target_here(end_jump);
 
-        let own_breaks = ctx.current_break.take().unwrap();
-        for break_entry in own_breaks {
+        for break_entry in ctx.take_own_breaks(&scope) {
             ctx.set_jump_target_here(break_entry);
         }
-        ctx.current_break = previous_break;
-        ctx.current_continue = previous_continue;
-        ctx.current_depth_of_loop_scope = previous_depth_of_loop;
+        ctx.exit_jump_target(scope, |_| {});
     }
 }
 
 impl CompileEvaluation for ast::DoWhileStatement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
-        let previous_depth_of_loop = ctx.current_depth_of_loop_scope.replace(0);
-        let previous_continue = ctx.current_continue.replace(vec![]);
-        let previous_break = ctx.current_break.replace(vec![]);
+        let scope = ctx.enter_breakable_statement();
 
         let start_jump = ctx.get_jump_index_to_here();
         self.body.compile(ctx);
 
-        let own_continues = ctx.current_continue.take().unwrap();
-        for continue_entry in own_continues {
+        for continue_entry in ctx.take_own_continues(&scope) {
             ctx.set_jump_target_here(continue_entry);
         }
 
@@ -2736,49 +3705,205 @@ impl CompileEvaluation for ast::DoWhileStatement<'_> {
         ctx.add_jump_instruction_to_index(Instruction::Jump, start_jump);
         ctx.set_jump_target_here(end_jump);
 
-        let own_breaks = ctx.current_break.take().unwrap();
-        for break_entry in own_breaks {
+        for break_entry in ctx.take_own_breaks(&scope) {
             ctx.set_jump_target_here(break_entry);
         }
-        ctx.current_break = previous_break;
-        ctx.current_continue = previous_continue;
-        ctx.current_depth_of_loop_scope = previous_depth_of_loop;
+        ctx.exit_jump_target(scope, |_| {});
     }
 }
 
 impl CompileEvaluation for ast::BreakStatement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
-        if let Some(label) = &self.label {
-            let label = label.name.as_str();
-            todo!("break {};", label);
-        }
         if let Some(depth) = ctx.current_depth_of_loop_scope {
             for _ in 0..depth {
                 // We have to exit the declarative environments we've entered.
                 ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
             }
         }
-        let break_jump = ctx.add_instruction_with_jump_slot(Instruction::Jump);
+        // Note: If the target is further out, the statements in between
+        // forward the jump to it.
+        let break_jump = BreakOrContinueJump {
+            label: self.label.as_ref().map(|label| label.name.to_string()),
+            jump: ctx.add_instruction_with_jump_slot(Instruction::Jump),
+        };
         ctx.current_break.as_mut().unwrap().push(break_jump);
     }
 }
 
 impl CompileEvaluation for ast::ContinueStatement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
-        if let Some(label) = &self.label {
-            let label = label.name.as_str();
-            todo!("continue {};", label);
-        }
         let depth = ctx.current_depth_of_loop_scope.unwrap();
         for _ in 0..depth {
             // We have to exit the declarative environments we've entered.
             ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
         }
-        let continue_jump = ctx.add_instruction_with_jump_slot(Instruction::Jump);
+        // Note: If the target is further out, the statements in between
+        // forward the jump to it.
+        let continue_jump = BreakOrContinueJump {
+            label: self.label.as_ref().map(|label| label.name.to_string()),
+            jump: ctx.add_instruction_with_jump_slot(Instruction::Jump),
+        };
         ctx.current_continue.as_mut().unwrap().push(continue_jump);
     }
 }
 
+impl CompileEvaluation for ast::LabeledStatement<'_> {
+    /// ### [14.13.4 Runtime Semantics: LabelledEvaluation](https://tc39.es/ecma262/#sec-runtime-semantics-labelledevaluation)
+    fn compile(&self, ctx: &mut CompileContext) {
+        // LabelledStatement : LabelIdentifier : LabelledItem
+        // 1. Let label be the StringValue of LabelIdentifier.
+        // 2. Let newLabelSet be the list-concatenation of labelSet and « label ».
+        ctx.current_label_set.push(self.label.name.to_string());
+        // 3. Let stmtResult be Completion(LabelledEvaluation of LabelledItem with argument newLabelSet).
+        match &self.body {
+            // Note: Loops, switch statements and nested labelled statements
+            // take the label set from the context.
+            ast::Statement::DoWhileStatement(_)
+            | ast::Statement::ForInStatement(_)
+            | ast::Statement::ForOfStatement(_)
+            | ast::Statement::ForStatement(_)
+            | ast::Statement::LabeledStatement(_)
+            | ast::Statement::SwitchStatement(_)
+            | ast::Statement::WhileStatement(_) => self.body.compile(ctx),
+            _ => {
+                // LabelledItem : Statement
+                // 1. If Statement is either a LabelledStatement or a
+                //    BreakableStatement, then [handled above]
+                // 2. Else,
+                //     a. Return ? Evaluation of Statement.
+                let scope = ctx.enter_labelled_statement();
+                self.body.compile(ctx);
+                // 4. If stmtResult is a break completion and
+                //    stmtResult.[[Target]] is label, then
+                //     a. Set stmtResult to NormalCompletion(stmtResult.[[Value]]).
+                let own_breaks = ctx.take_own_breaks(&scope);
+                ctx.exit_jump_target(scope, |_| {});
+                for break_entry in own_breaks {
+                    ctx.set_jump_target_here(break_entry);
+                }
+            }
+        }
+        // 5. Return ? stmtResult.
+    }
+}
+
+impl CompileEvaluation for ast::ExportNamedDeclaration<'_> {
+    /// ### [16.2.3.7 Runtime Semantics: Evaluation](https://tc39.es/ecma262/#sec-exports-runtime-semantics-evaluation)
+    fn compile(&self, ctx: &mut CompileContext) {
+        // ExportDeclaration :
+        //     export ExportFromClause FromClause WithClause_opt ;
+        //     export NamedExports ;
+        // 1. Return EMPTY.
+        let Some(declaration) = &self.declaration else {
+            return;
+        };
+        match declaration {
+            // ExportDeclaration : export VariableStatement
+            // ExportDeclaration : export Declaration
+            // 1. Return ? Evaluation of VariableStatement / Declaration.
+            ast::Declaration::VariableDeclaration(decl) => decl.compile(ctx),
+            ast::Declaration::FunctionDeclaration(_) => {
+                // Note: Function declarations are hoisted when the module
+                // environment is initialized.
+            }
+            ast::Declaration::ClassDeclaration(decl) => decl.compile(ctx),
+            #[cfg(feature = "typescript")]
+            ast::Declaration::TSTypeAliasDeclaration(_)
+            | ast::Declaration::TSInterfaceDeclaration(_) => {}
+            #[cfg(not(feature = "typescript"))]
+            ast::Declaration::TSTypeAliasDeclaration(_)
+            | ast::Declaration::TSInterfaceDeclaration(_) => unreachable!(),
+            ast::Declaration::TSEnumDeclaration(_)
+            | ast::Declaration::TSModuleDeclaration(_)
+            | ast::Declaration::TSImportEqualsDeclaration(_) => unreachable!(),
+        }
+    }
+}
+
+impl CompileEvaluation for ast::ExportDefaultDeclaration<'_> {
+    /// ### [16.2.3.7 Runtime Semantics: Evaluation](https://tc39.es/ecma262/#sec-exports-runtime-semantics-evaluation)
+    fn compile(&self, ctx: &mut CompileContext) {
+        match &self.declaration {
+            // ExportDeclaration : export default HoistableDeclaration
+            ast::ExportDefaultDeclarationKind::FunctionDeclaration(_) => {
+                // 1. Return ? Evaluation of HoistableDeclaration.
+                // Note: Function declarations are hoisted when the module
+                // environment is initialized.
+            }
+            // ExportDeclaration : export default ClassDeclaration
+            ast::ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                // 1. Let value be ? BindingClassDeclarationEvaluation of
+                //    ClassDeclaration.
+                // 2. Let className be the sole element of the BoundNames of
+                //    ClassDeclaration.
+                if class.id.is_some() {
+                    // Note: A named class declaration initializes its own
+                    // binding.
+                    class.compile(ctx);
+                    // 4. Return EMPTY.
+                    return;
+                }
+                // Note: BindingClassDeclarationEvaluation of
+                // `class ClassTail` is ClassDefinitionEvaluation of ClassTail
+                // with arguments undefined and "default".
+                ctx.add_instruction_with_constant(
+                    Instruction::StoreConstant,
+                    BUILTIN_STRING_MEMORY.default,
+                );
+                ctx.name_identifier = Some(NamedEvaluationParameter::Result);
+                class.compile(ctx);
+                // 3. If className is "*default*", then
+                // a. Let env be the running execution context's
+                //    LexicalEnvironment.
+                // b. Perform ? InitializeBoundName("*default*", value, env).
+                ctx.add_instruction_with_identifier(
+                    Instruction::ResolveBinding,
+                    BUILTIN_STRING_MEMORY._default_,
+                );
+                ctx.add_instruction(Instruction::InitializeReferencedBinding);
+            }
+            #[cfg(feature = "typescript")]
+            ast::ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {}
+            #[cfg(not(feature = "typescript"))]
+            ast::ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => unreachable!(),
+            // ExportDeclaration : export default AssignmentExpression ;
+            expression => {
+                let expression = expression.to_expression();
+                // 1. If IsAnonymousFunctionDefinition(AssignmentExpression) is
+                //    true, then
+                if is_anonymous_function_definition(expression) {
+                    // a. Let value be ? NamedEvaluation of
+                    //    AssignmentExpression with argument "default".
+                    ctx.add_instruction_with_constant(
+                        Instruction::StoreConstant,
+                        BUILTIN_STRING_MEMORY.default,
+                    );
+                    ctx.name_identifier = Some(NamedEvaluationParameter::Result);
+                    expression.compile(ctx);
+                    ctx.name_identifier = None;
+                } else {
+                    // 2. Else,
+                    // a. Let rhs be ? Evaluation of AssignmentExpression.
+                    expression.compile(ctx);
+                    // b. Let value be ? GetValue(rhs).
+                    if is_reference(expression) {
+                        ctx.add_instruction(Instruction::GetValue);
+                    }
+                }
+                // 3. Let env be the running execution context's
+                //    LexicalEnvironment.
+                // 4. Perform ? InitializeBoundName("*default*", value, env).
+                ctx.add_instruction_with_identifier(
+                    Instruction::ResolveBinding,
+                    BUILTIN_STRING_MEMORY._default_,
+                );
+                ctx.add_instruction(Instruction::InitializeReferencedBinding);
+                // 5. Return EMPTY.
+            }
+        }
+    }
+}
+
 impl CompileEvaluation for ast::Statement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
         match self {
@@ -2801,15 +3926,18 @@ impl CompileEvaluation for ast::Statement<'_> {
             Statement::DoWhileStatement(statement) => statement.compile(ctx),
             Statement::ForInStatement(statement) => statement.compile(ctx),
             Statement::ForOfStatement(statement) => statement.compile(ctx),
-            Statement::LabeledStatement(_) => todo!(),
+            Statement::LabeledStatement(statement) => statement.compile(ctx),
             Statement::SwitchStatement(statement) => statement.compile(ctx),
             Statement::WhileStatement(statement) => statement.compile(ctx),
-            Statement::WithStatement(_) => todo!(),
+            Statement::WithStatement(statement) => statement.compile(ctx),
             Statement::ClassDeclaration(x) => x.compile(ctx),
-            Statement::ImportDeclaration(_) => todo!(),
-            Statement::ExportAllDeclaration(_) => todo!(),
-            Statement::ExportDefaultDeclaration(_) => todo!(),
-            Statement::ExportNamedDeclaration(_) => todo!(),
+            Statement::ImportDeclaration(_) | Statement::ExportAllDeclaration(_) => {
+                // Note: Import and export-from declarations are resolved
+                // when the module is linked. There is no work left to do
+                // here.
+            }
+            Statement::ExportDefaultDeclaration(statement) => statement.compile(ctx),
+            Statement::ExportNamedDeclaration(statement) => statement.compile(ctx),
             #[cfg(feature = "typescript")]
             Statement::TSTypeAliasDeclaration(_) | Statement::TSInterfaceDeclaration(_) => {}
             #[cfg(not(feature = "typescript"))]
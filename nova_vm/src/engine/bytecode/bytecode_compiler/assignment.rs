@@ -38,7 +38,9 @@ impl CompileEvaluation for ast::AssignmentExpression<'_> {
                 ctx.add_instruction(Instruction::Store);
                 return;
             }
-            ast::AssignmentTarget::PrivateFieldExpression(_) => todo!(),
+            ast::AssignmentTarget::PrivateFieldExpression(expression) => {
+                expression.compile(ctx);
+            }
             ast::AssignmentTarget::StaticMemberExpression(expression) => {
                 expression.compile(ctx);
             }
@@ -186,7 +188,12 @@ impl CompileEvaluation for ast::AssignmentTarget<'_> {
             ast::AssignmentTarget::ObjectAssignmentTarget(object) => {
                 object.compile(ctx);
             }
-            ast::AssignmentTarget::PrivateFieldExpression(_) => todo!(),
+            ast::AssignmentTarget::PrivateFieldExpression(expression) => {
+                ctx.add_instruction(Instruction::Load);
+                expression.compile(ctx);
+                ctx.add_instruction(Instruction::Store);
+                ctx.add_instruction(Instruction::PutValue);
+            }
             ast::AssignmentTarget::StaticMemberExpression(expression) => {
                 ctx.add_instruction(Instruction::Load);
                 expression.compile(ctx);
@@ -222,11 +229,14 @@ impl CompileEvaluation for ast::ArrayAssignmentTarget<'_> {
 impl CompileEvaluation for ast::ObjectAssignmentTarget<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
         ctx.add_instruction(Instruction::ToObject);
-        if self.properties.len() > 1 || self.rest.is_some() {
+        if self.properties.len() > 1 || (self.rest.is_some() && !self.properties.is_empty()) {
             ctx.add_instruction(Instruction::LoadCopy);
         }
         for (index, property) in self.properties.iter().enumerate() {
-            property.compile(ctx);
+            // Note: If a rest element follows, we keep the references of all
+            // properties read in the reference stack so that they can be
+            // excluded from the rest object.
+            compile_assignment_target_property(property, ctx, self.rest.is_some());
             let offset = if self.rest.is_some() {
                 index + 1
             } else {
@@ -239,81 +249,107 @@ impl CompileEvaluation for ast::ObjectAssignmentTarget<'_> {
                 ctx.add_instruction(Instruction::Store);
             }
         }
-        if let Some(_rest) = &self.rest {
-            todo!()
+        if let Some(rest) = &self.rest {
+            // 13.15.5.4 Runtime Semantics: RestDestructuringAssignmentEvaluation
+            // AssignmentRestProperty : ... DestructuringAssignmentTarget
+            // 2. Let restObj be OrdinaryObjectCreate(%Object.prototype%).
+            // 3. Perform ? CopyDataProperties(restObj, value, excludedNames).
+            ctx.add_instruction_with_immediate(
+                Instruction::CopyDataPropertiesIntoObject,
+                self.properties.len(),
+            );
+            // 1. Let lref be ? Evaluation of DestructuringAssignmentTarget.
+            // 4. Return ? PutValue(lref, restObj).
+            rest.target.compile(ctx);
         }
     }
 }
 
-impl CompileEvaluation for ast::AssignmentTargetProperty<'_> {
-    fn compile(&self, ctx: &mut CompileContext) {
-        match self {
-            ast::AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(identifier) => {
-                identifier.compile(ctx);
-            }
-            ast::AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
-                property.compile(ctx);
-            }
+/// ### [13.15.5.3 Runtime Semantics: PropertyDestructuringAssignmentEvaluation](https://tc39.es/ecma262/#sec-runtime-semantics-propertydestructuringassignmentevaluation)
+///
+/// If `keep_reference` is true, the property reference is left on the
+/// reference stack for a following AssignmentRestProperty.
+fn compile_assignment_target_property(
+    property: &ast::AssignmentTargetProperty<'_>,
+    ctx: &mut CompileContext,
+    keep_reference: bool,
+) {
+    match property {
+        ast::AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(identifier) => {
+            compile_assignment_target_property_identifier(identifier, ctx, keep_reference);
+        }
+        ast::AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+            compile_assignment_target_property_property(property, ctx, keep_reference);
         }
     }
 }
 
-impl CompileEvaluation for ast::AssignmentTargetPropertyIdentifier<'_> {
-    fn compile(&self, ctx: &mut CompileContext) {
-        let key = String::from_str(ctx.agent, self.binding.name.as_str(), ctx.gc);
-        ctx.add_instruction_with_identifier(
-            Instruction::EvaluatePropertyAccessWithIdentifierKey,
-            key,
-        );
+fn compile_property_get_value(ctx: &mut CompileContext, keep_reference: bool) {
+    if keep_reference {
+        ctx.add_instruction(Instruction::GetValueKeepReference);
+        ctx.add_instruction(Instruction::PushReference);
+    } else {
         ctx.add_instruction(Instruction::GetValue);
-        if let Some(init) = &self.init {
-            ctx.add_instruction(Instruction::LoadCopy);
-            ctx.add_instruction(Instruction::IsUndefined);
-            let jump_slot = ctx.add_instruction_with_jump_slot(Instruction::JumpIfNot);
-            ctx.add_instruction(Instruction::Store);
-            if is_anonymous_function_definition(init) {
-                let identifier_string = ctx.create_identifier(&self.binding.name);
-                ctx.add_instruction_with_constant(Instruction::StoreConstant, identifier_string);
-                ctx.name_identifier = Some(NamedEvaluationParameter::Result);
-            }
-            init.compile(ctx);
-            ctx.name_identifier = None;
-            if is_reference(init) {
-                ctx.add_instruction(Instruction::GetValue);
-            }
-            ctx.add_instruction(Instruction::Load);
-            ctx.set_jump_target_here(jump_slot);
-            ctx.add_instruction(Instruction::Store);
+    }
+}
+
+fn compile_assignment_target_property_identifier(
+    property: &ast::AssignmentTargetPropertyIdentifier<'_>,
+    ctx: &mut CompileContext,
+    keep_reference: bool,
+) {
+    let key = String::from_str(ctx.agent, property.binding.name.as_str(), ctx.gc);
+    ctx.add_instruction_with_identifier(Instruction::EvaluatePropertyAccessWithIdentifierKey, key);
+    compile_property_get_value(ctx, keep_reference);
+    if let Some(init) = &property.init {
+        ctx.add_instruction(Instruction::LoadCopy);
+        ctx.add_instruction(Instruction::IsUndefined);
+        let jump_slot = ctx.add_instruction_with_jump_slot(Instruction::JumpIfNot);
+        ctx.add_instruction(Instruction::Store);
+        if is_anonymous_function_definition(init) {
+            let identifier_string = ctx.create_identifier(&property.binding.name);
+            ctx.add_instruction_with_constant(Instruction::StoreConstant, identifier_string);
+            ctx.name_identifier = Some(NamedEvaluationParameter::Result);
+        }
+        init.compile(ctx);
+        ctx.name_identifier = None;
+        if is_reference(init) {
+            ctx.add_instruction(Instruction::GetValue);
         }
-        self.binding.compile(ctx);
-        ctx.add_instruction(Instruction::PutValue);
+        ctx.add_instruction(Instruction::Load);
+        ctx.set_jump_target_here(jump_slot);
+        ctx.add_instruction(Instruction::Store);
     }
+    property.binding.compile(ctx);
+    ctx.add_instruction(Instruction::PutValue);
 }
 
-impl CompileEvaluation for ast::AssignmentTargetPropertyProperty<'_> {
-    fn compile(&self, ctx: &mut CompileContext) {
-        match &self.name {
-            ast::PropertyKey::StaticIdentifier(identifier) => {
-                let key = String::from_str(ctx.agent, identifier.name.as_str(), ctx.gc);
-                ctx.add_instruction_with_identifier(
-                    Instruction::EvaluatePropertyAccessWithIdentifierKey,
-                    key,
-                );
-            }
-            ast::PropertyKey::PrivateIdentifier(_) => todo!(),
-            _ => {
-                ctx.add_instruction(Instruction::Load);
-                let name = self.name.to_expression();
-                name.compile(ctx);
-                if is_reference(name) {
-                    ctx.add_instruction(Instruction::GetValue);
-                }
-                ctx.add_instruction(Instruction::EvaluatePropertyAccessWithExpressionKey);
+fn compile_assignment_target_property_property(
+    property: &ast::AssignmentTargetPropertyProperty<'_>,
+    ctx: &mut CompileContext,
+    keep_reference: bool,
+) {
+    match &property.name {
+        ast::PropertyKey::StaticIdentifier(identifier) => {
+            let key = String::from_str(ctx.agent, identifier.name.as_str(), ctx.gc);
+            ctx.add_instruction_with_identifier(
+                Instruction::EvaluatePropertyAccessWithIdentifierKey,
+                key,
+            );
+        }
+        ast::PropertyKey::PrivateIdentifier(_) => unreachable!(),
+        _ => {
+            ctx.add_instruction(Instruction::Load);
+            let name = property.name.to_expression();
+            name.compile(ctx);
+            if is_reference(name) {
+                ctx.add_instruction(Instruction::GetValue);
             }
+            ctx.add_instruction(Instruction::EvaluatePropertyAccessWithExpressionKey);
         }
-        ctx.add_instruction(Instruction::GetValue);
-        self.binding.compile(ctx);
     }
+    compile_property_get_value(ctx, keep_reference);
+    property.binding.compile(ctx);
 }
 
 impl CompileEvaluation for ast::AssignmentTargetMaybeDefault<'_> {
@@ -68,23 +68,15 @@ impl CompileEvaluation for ast::Class<'_> {
         }
         // 4. Let outerPrivateEnvironment be the running execution context's PrivateEnvironment.
         // 5. Let classPrivateEnvironment be NewPrivateEnvironment(outerPrivateEnvironment).
-        // 6. If ClassBody is present, then
-        for dn in self
+        // Note: The classPrivateEnvironment is only created if the class
+        // declares any Private Names. ClassHeritage is evaluated with
+        // outerPrivateEnvironment, so the environment is entered and its
+        // Private Names created only after ClassHeritage has been evaluated.
+        let has_private_environment = self
             .body
             .body
             .iter()
-            .filter(|class_element| class_element.private_bound_identifiers().is_some())
-        {
-            let dn = dn.private_bound_identifiers().unwrap();
-            let _dn = String::from_str(ctx.agent, dn.name.as_str(), ctx.gc);
-            // TODO: Private elements.
-            // a. For each String dn of the PrivateBoundIdentifiers of ClassBody, do
-            //     i. If classPrivateEnvironment.[[Names]] contains a Private Name pn such that pn.[[Description]] is dn, then
-            //         1. Assert: This is only possible for getter/setter pairs.
-            //     ii. Else,
-            //         1. Let name be a new Private Name whose [[Description]] is dn.
-            //         2. Append name to classPrivateEnvironment.[[Names]].
-        }
+            .any(|class_element| class_element.private_bound_identifiers().is_some());
 
         let mut has_constructor_parent = false;
 
@@ -246,6 +238,25 @@ impl CompileEvaluation for ast::Class<'_> {
         // Note: We have returned to classEnv if we ever left it.
         // 12. Set the running execution context's LexicalEnvironment to classEnv.
         // 13. Set the running execution context's PrivateEnvironment to classPrivateEnvironment.
+        if has_private_environment {
+            ctx.add_instruction(Instruction::EnterPrivateEnvironment);
+            // 6. If ClassBody is present, then
+            // a. For each String dn of the PrivateBoundIdentifiers of ClassBody, do
+            for dn in self
+                .body
+                .body
+                .iter()
+                .filter_map(|class_element| class_element.private_bound_identifiers())
+            {
+                let dn = String::from_str(ctx.agent, dn.name.as_str(), ctx.gc);
+                // i. If classPrivateEnvironment.[[Names]] contains a Private Name pn such that pn.[[Description]] is dn, then
+                //     1. Assert: This is only possible for getter/setter pairs.
+                // ii. Else,
+                //     1. Let name be a new Private Name whose [[Description]] is dn.
+                //     2. Append name to classPrivateEnvironment.[[Names]].
+                ctx.add_instruction_with_identifier(Instruction::CreatePrivateName, dn);
+            }
+        }
 
         // Before calling CreateDefaultConstructor we need to smuggle the
         // className to the top of the stack.
@@ -332,9 +343,12 @@ impl CompileEvaluation for ast::Class<'_> {
         // 19. If ClassBody is not present, let elements be a new empty List.
         // 20. Else, let elements be the NonConstructorElements of ClassBody.
         // 21. Let instancePrivateMethods be a new empty List.
-        // let mut instance_private_methods = vec![];
+        // Note: The instance private methods are collected into the
+        // classPrivateEnvironment by the ClassDefinePrivate* instructions.
+        let mut has_instance_private_methods = false;
         // 22. Let staticPrivateMethods be a new empty List.
-        // let mut static_private_methods = vec![];
+        // Note: Static private methods are added to F directly by the
+        // ClassDefinePrivate* instructions.
         // 23. Let instanceFields be a new empty List.
         let mut instance_fields = vec![];
         // 24. Let staticElements be a new empty List.
@@ -347,7 +361,7 @@ impl CompileEvaluation for ast::Class<'_> {
                     // creates a function that will be immediately invoked
                     // later. The function is never visible to JavaScript code
                     // and thus doesn't _actually_ need to get created here.
-                    static_elements.push(static_block.as_ref());
+                    static_elements.push(StaticElement::Block(static_block.as_ref()));
                 }
                 // a. If IsStatic of e is false, then
                 // i. Let element be Completion(ClassElementEvaluation of e with argument proto).
@@ -363,26 +377,36 @@ impl CompileEvaluation for ast::Class<'_> {
                         swap_to_constructor(ctx, &mut proto_is_on_top);
                     } else {
                         swap_to_proto(ctx, &mut proto_is_on_top);
+                        has_instance_private_methods |= matches!(
+                            method_definition.key,
+                            ast::PropertyKey::PrivateIdentifier(_)
+                        );
                     }
                     define_method(method_definition, ctx);
                 }
                 ast::ClassElement::PropertyDefinition(property_definition) => {
-                    if property_definition.computed {
+                    let field = if property_definition.computed {
                         compile_computed_field_name(
                             ctx,
-                            &mut instance_fields,
+                            instance_fields.len() + static_elements.len(),
                             &property_definition.key,
                             &property_definition.value,
-                        );
+                        )
                     } else {
-                        let ast::PropertyKey::StaticIdentifier(key) = &property_definition.key
-                        else {
-                            unreachable!()
-                        };
-                        instance_fields.push(PropertyInitializerField::Static((
-                            key,
-                            &property_definition.value,
-                        )));
+                        match &property_definition.key {
+                            ast::PropertyKey::StaticIdentifier(key) => {
+                                PropertyInitializerField::Static((key, &property_definition.value))
+                            }
+                            ast::PropertyKey::PrivateIdentifier(key) => {
+                                PropertyInitializerField::Private((key, &property_definition.value))
+                            }
+                            _ => unreachable!(),
+                        }
+                    };
+                    if property_definition.r#static {
+                        static_elements.push(StaticElement::Field(field));
+                    } else {
+                        instance_fields.push(field);
                     }
                 }
                 ast::ClassElement::AccessorProperty(_) => todo!(),
@@ -440,19 +464,27 @@ impl CompileEvaluation for ast::Class<'_> {
 
         // 28. Set F.[[PrivateMethods]] to instancePrivateMethods.
         // 29. Set F.[[Fields]] to instanceFields.
-        if !instance_fields.is_empty() {
+        if !instance_fields.is_empty() || has_instance_private_methods {
             let mut constructor_ctx = CompileContext::new(ctx.agent, ctx.gc);
-            for ele in instance_fields {
-                match ele {
-                    PropertyInitializerField::Static((property_key, value)) => {
-                        constructor_ctx.compile_class_static_field(property_key, value);
-                    }
-                    PropertyInitializerField::Computed((key_id, value)) => {
-                        constructor_ctx.compile_class_computed_field(key_id, value);
-                    }
-                }
+            if has_instance_private_methods {
+                // InitializeInstanceElements ( O, constructor )
+                // 1. Let methods be the value of constructor.[[PrivateMethods]].
+                // 2. For each PrivateElement method of methods, do
+                //     a. Perform ? PrivateMethodOrAccessorAdd(O, method).
+                constructor_ctx.add_instruction(Instruction::ResolveThisBinding);
+                constructor_ctx.add_instruction(Instruction::ClassInitializePrivateMethods);
+            }
+            for field in instance_fields.iter() {
+                compile_class_field(&mut constructor_ctx, field);
             }
-            if let Some(constructor) = constructor {
+            if constructor.is_some() && has_constructor_parent {
+                // Derived class constructors have no this binding until the
+                // super() call returns, so the initializers are run by
+                // EvaluateSuper instead of at the start of the constructor.
+                let initializer = constructor_ctx.finish();
+                ctx.function_expressions[constructor_index as usize]
+                    .compiled_initializer_bytecode = Some(initializer);
+            } else if let Some(constructor) = constructor {
                 let constructor_data = CompileFunctionBodyData {
                     // SAFETY: The SourceCode that contains this code cannot be garbage collected
                     // as long as the constructor function we produce here lives.
@@ -470,6 +502,7 @@ impl CompileEvaluation for ast::Class<'_> {
                         >(&constructor.value.params)
                     },
                     is_concise_body: false,
+                    is_async_generator: false,
                     is_lexical: false,
                     // Class code is always strict.
                     is_strict: true,
@@ -490,29 +523,46 @@ impl CompileEvaluation for ast::Class<'_> {
         //     a. Perform ! PrivateMethodOrAccessorAdd(F, method).
         // 31. For each element elementRecord of staticElements, do
         for element_record in static_elements.iter() {
-            // a. If elementRecord is a ClassFieldDefinition Record, then
-            //     i. Let result be Completion(DefineField(F, elementRecord)).
-            // b. Else,
-            //     i. Assert: elementRecord is a ClassStaticBlockDefinition Record.
-            //     ii. Let result be Completion(Call(elementRecord.[[BodyFunction]], F)).
-            element_record.compile(ctx);
+            match element_record {
+                // a. If elementRecord is a ClassFieldDefinition Record, then
+                StaticElement::Field(field) => {
+                    // i. Let result be Completion(DefineField(F, elementRecord)).
+                    // Note: The initializer is evaluated as a method of F,
+                    // so we enter a function environment with F as the this
+                    // binding like with static blocks.
+                    ctx.add_instruction(Instruction::EnterClassStaticElementEnvironment);
+                    compile_class_field(ctx, field);
+                    ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
+                    ctx.add_instruction(Instruction::ExitVariableEnvironment);
+                }
+                // b. Else,
+                StaticElement::Block(static_block) => {
+                    // i. Assert: elementRecord is a ClassStaticBlockDefinition Record.
+                    // ii. Let result be Completion(Call(elementRecord.[[BodyFunction]], F)).
+                    static_block.compile(ctx);
+                }
+            }
             // c. If result is an abrupt completion, then
             //     i. Set the running execution context's PrivateEnvironment to outerPrivateEnvironment.
             //     ii. Return ? result.
         }
+        // 32. Set the running execution context's PrivateEnvironment to outerPrivateEnvironment.
+        if has_private_environment {
+            ctx.add_instruction(Instruction::ExitPrivateEnvironment);
+        }
         // Note: We finally leave classEnv here. See step 26.
         ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
         if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
             *i -= 1;
         }
 
-        // 32. Set the running execution context's PrivateEnvironment to outerPrivateEnvironment.
         // 33. Return F.
 
         // 15.7.15 Runtime Semantics: BindingClassDeclarationEvaluation
         // ClassDeclaration: class BindingIdentifier ClassTail
-        if self.is_declaration() {
-            let class_identifier = class_identifier.unwrap();
+        // NOTE: `export default class {}` has no BindingIdentifier; the
+        // export default evaluation initializes the "*default*" binding.
+        if let (true, Some(class_identifier)) = (self.is_declaration(), class_identifier) {
             // 4. Let env be the running execution context's LexicalEnvironment.
             // 5. Perform ? InitializeBoundName(className, value, env).
             // => a. Perform ! environment.InitializeBinding(name, value).
@@ -530,17 +580,49 @@ impl CompileEvaluation for ast::Class<'_> {
 enum PropertyInitializerField<'a, 'gc> {
     Static((&'a ast::IdentifierName<'a>, &'a Option<ast::Expression<'a>>)),
     Computed((String<'gc>, &'a Option<ast::Expression<'a>>)),
+    Private(
+        (
+            &'a ast::PrivateIdentifier<'a>,
+            &'a Option<ast::Expression<'a>>,
+        ),
+    ),
 }
 
+#[derive(Debug)]
+enum StaticElement<'a, 'gc> {
+    Block(&'a ast::StaticBlock<'a>),
+    Field(PropertyInitializerField<'a, 'gc>),
+}
+
+/// Compile a field definition into the current context. The field is defined
+/// on the current `this` value.
+fn compile_class_field<'gc>(
+    ctx: &mut CompileContext<'_, 'gc, '_>,
+    field: &PropertyInitializerField<'_, 'gc>,
+) {
+    match field {
+        PropertyInitializerField::Static((property_key, value)) => {
+            ctx.compile_class_static_field(property_key, value);
+        }
+        PropertyInitializerField::Computed((key_id, value)) => {
+            ctx.compile_class_computed_field(*key_id, value);
+        }
+        PropertyInitializerField::Private((private_identifier, value)) => {
+            ctx.compile_class_private_field(private_identifier, value);
+        }
+    }
+}
+
+/// Evaluates a computed field name and stores it in a hidden binding in the
+/// classEnv. The `index` must be unique among the fields of the class.
 fn compile_computed_field_name<'a, 'gc>(
     ctx: &mut CompileContext<'_, 'gc, '_>,
-    property_fields: &mut Vec<PropertyInitializerField<'a, 'gc>>,
+    index: usize,
     key: &ast::PropertyKey<'_>,
     value: &'a Option<ast::Expression<'a>>,
-) {
+) -> PropertyInitializerField<'a, 'gc> {
     // TODO: Handle lifetime logic.
-    let computed_key_id =
-        String::from_string(ctx.agent, format!("^{}", property_fields.len()), ctx.gc);
+    let computed_key_id = String::from_string(ctx.agent, format!("^{}", index), ctx.gc);
     let key = match key {
         // These should not show up as computed
         ast::PropertyKey::StaticMemberExpression(_)
@@ -554,7 +636,7 @@ fn compile_computed_field_name<'a, 'gc>(
     }
     ctx.add_instruction_with_identifier(Instruction::ResolveBinding, computed_key_id);
     ctx.add_instruction(Instruction::InitializeReferencedBinding);
-    property_fields.push(PropertyInitializerField::Computed((computed_key_id, value)));
+    PropertyInitializerField::Computed((computed_key_id, value))
 }
 
 /// Creates an ECMAScript constructor for a class.
@@ -603,6 +685,7 @@ fn define_constructor_method(
             // CompileContext holds a name identifier for us if this is NamedEvaluation.
             identifier: None,
             compiled_bytecode: None,
+            compiled_initializer_bytecode: None,
         },
         has_constructor_parent.into(),
     )
@@ -616,7 +699,16 @@ fn define_constructor_method(
 /// at the top of the stack. The object is second on the stack.
 fn define_method(class_element: &ast::MethodDefinition, ctx: &mut CompileContext) -> IndexType {
     // 1. Let propKey be ? Evaluation of ClassElementName.
-    if let Some(prop_name) = class_element.prop_name() {
+    if let ast::PropertyKey::PrivateIdentifier(private_identifier) = &class_element.key {
+        // Note: The Private Name is resolved from its description when the
+        // method is defined. The description is used as the function name.
+        let description = String::from_string(
+            ctx.agent,
+            format!("#{}", private_identifier.name.as_str()),
+            ctx.gc,
+        );
+        ctx.add_instruction_with_constant(Instruction::LoadConstant, description);
+    } else if let Some(prop_name) = class_element.prop_name() {
         let prop_name = String::from_str(ctx.agent, prop_name.0, ctx.gc);
         ctx.add_instruction_with_constant(Instruction::LoadConstant, prop_name);
     } else {
@@ -638,11 +730,15 @@ fn define_method(class_element: &ast::MethodDefinition, ctx: &mut CompileContext
     //     a. Let prototype be %Function.prototype%.
     // 6. Let sourceText be the source text matched by MethodDefinition.
     // 7. Let closure be OrdinaryFunctionCreate(prototype, sourceText, UniqueFormalParameters, FunctionBody, non-lexical-this, env, privateEnv).
-    let instruction = match &class_element.kind {
-        MethodDefinitionKind::Constructor => unreachable!(),
-        MethodDefinitionKind::Method => Instruction::ObjectDefineMethod,
-        MethodDefinitionKind::Get => Instruction::ObjectDefineGetter,
-        MethodDefinitionKind::Set => Instruction::ObjectDefineSetter,
+    let is_private = matches!(class_element.key, ast::PropertyKey::PrivateIdentifier(_));
+    let instruction = match (&class_element.kind, is_private) {
+        (MethodDefinitionKind::Constructor, _) => unreachable!(),
+        (MethodDefinitionKind::Method, false) => Instruction::ObjectDefineMethod,
+        (MethodDefinitionKind::Get, false) => Instruction::ObjectDefineGetter,
+        (MethodDefinitionKind::Set, false) => Instruction::ObjectDefineSetter,
+        (MethodDefinitionKind::Method, true) => Instruction::ClassDefinePrivateMethod,
+        (MethodDefinitionKind::Get, true) => Instruction::ClassDefinePrivateGetter,
+        (MethodDefinitionKind::Set, true) => Instruction::ClassDefinePrivateSetter,
     };
     // CompileContext holds a name identifier for us if this is NamedEvaluation.
     let identifier = ctx.name_identifier.take();
@@ -664,9 +760,11 @@ fn define_method(class_element: &ast::MethodDefinition, ctx: &mut CompileContext
             }),
             identifier,
             compiled_bytecode: None,
+            compiled_initializer_bytecode: None,
         },
-        // enumerable: false,
-        false.into(),
+        // Private methods: is_static
+        // Other methods: enumerable: false,
+        (is_private && class_element.r#static).into(),
     )
 }
 
@@ -2,7 +2,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::{CompileContext, CompileEvaluation, Instruction, JumpIndex, is_reference};
+use super::{
+    CompileContext, CompileEvaluation, Instruction, JumpIndex, compile_async_iterator_close,
+    is_reference,
+};
 use crate::ecmascript::types::{String, Value};
 use oxc_ast::ast::{self, BindingPatternKind, ForStatementLeft};
 use oxc_ecmascript::BoundNames;
@@ -121,7 +124,6 @@ fn for_in_of_body_evaluation(
     key_result: Option<JumpIndex>,
     iteration_kind: IterationKind,
     lhs_kind: LeftHandSideKind,
-    // _label_set: (),
 ) {
     // 1. If iteratorKind is not present, set iteratorKind to SYNC.
     let iterator_kind = match iteration_kind {
@@ -154,9 +156,8 @@ fn for_in_of_body_evaluation(
         None
     };
 
-    let previous_depth_of_loop = ctx.current_depth_of_loop_scope.replace(0);
-    let previous_continue = ctx.current_continue.replace(vec![]);
-    let previous_break = ctx.current_break.replace(vec![]);
+    // Note: The labelSet of the loop is taken from the CompileContext.
+    let scope = ctx.enter_breakable_statement();
 
     // 6. Repeat,
     let repeat_jump = ctx.get_jump_index_to_here();
@@ -166,8 +167,18 @@ fn for_in_of_body_evaluation(
     // d. Let done be ? IteratorComplete(nextResult).
     // e. If done is true, return V.
     // f. Let nextValue be ? IteratorValue(nextResult).
-    let jump_to_end = ctx.add_instruction_with_jump_slot(Instruction::IteratorStepValue);
+    let jump_to_end = if iterator_kind == IteratorKind::Async {
+        ctx.add_instruction(Instruction::IteratorCallNextMethod);
+        ctx.add_instruction(Instruction::Await);
+        let jump_to_end = ctx.add_instruction_with_jump_slot(Instruction::IteratorComplete);
+        ctx.add_instruction(Instruction::IteratorValue);
+        jump_to_end
+    } else {
+        ctx.add_instruction_with_jump_slot(Instruction::IteratorStepValue)
+    };
     let mut entered_declarative_environment = false;
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    let mut disposable_scope = None;
     // g. If lhsKind is either ASSIGNMENT or VAR-BINDING, then
     match lhs_kind {
         LeftHandSideKind::Assignment | LeftHandSideKind::VarBinding => {
@@ -195,6 +206,7 @@ fn for_in_of_body_evaluation(
                             assert_eq!(decl.declarations.len(), 1);
                             let declaration = decl.declarations.first().unwrap();
                             ctx.add_instruction(Instruction::Load);
+                            ctx.lexical_binding_state = false;
                             match &declaration.id.kind {
                                 BindingPatternKind::BindingIdentifier(_) => unreachable!(),
                                 BindingPatternKind::ObjectPattern(pattern) => pattern.compile(ctx),
@@ -279,10 +291,32 @@ fn for_in_of_body_evaluation(
                 );
             });
             // v. Set the running execution context's LexicalEnvironment to iterationEnv.
+            // Note: `using` and `await using` declarations add the value to
+            // iterationEnv.[[DisposeCapability]], which is disposed of after
+            // the evaluation of stmt.
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            match lhs.kind {
+                ast::VariableDeclarationKind::Using => {
+                    disposable_scope = Some(ctx.enter_disposable_scope(false));
+                }
+                ast::VariableDeclarationKind::AwaitUsing => {
+                    disposable_scope = Some(ctx.enter_disposable_scope(true));
+                }
+                _ => {}
+            }
             // vi. If destructuring is true, then
             if destructuring {
                 // 1. Let status be Completion(ForDeclarationBindingInitialization of lhs with arguments nextValue and iterationEnv).
-                todo!();
+                assert_eq!(lhs.declarations.len(), 1);
+                let declaration = lhs.declarations.first().unwrap();
+                ctx.add_instruction(Instruction::Load);
+                ctx.lexical_binding_state = true;
+                match &declaration.id.kind {
+                    BindingPatternKind::ObjectPattern(pattern) => pattern.compile(ctx),
+                    BindingPatternKind::ArrayPattern(pattern) => pattern.compile(ctx),
+                    BindingPatternKind::BindingIdentifier(_)
+                    | BindingPatternKind::AssignmentPattern(_) => unreachable!(),
+                }
             } else {
                 // vii. Else,
                 // 1. Assert: lhs binds a single name.
@@ -295,6 +329,16 @@ fn for_in_of_body_evaluation(
                         String::from_str(ctx.agent, binding_identifier.name.as_str(), ctx.gc);
                     // 3. Let lhsRef be ! ResolveBinding(lhsName).
                     ctx.add_instruction_with_identifier(Instruction::ResolveBinding, lhs_name);
+                    #[cfg(feature = "proposal-explicit-resource-management")]
+                    match lhs.kind {
+                        ast::VariableDeclarationKind::Using => {
+                            ctx.add_instruction(Instruction::AddDisposableResource)
+                        }
+                        ast::VariableDeclarationKind::AwaitUsing => {
+                            ctx.add_instruction(Instruction::AddAsyncDisposableResource)
+                        }
+                        _ => {}
+                    }
                     // 4. Let status be Completion(InitializeReferencedBinding(lhsRef, nextValue)).
                     ctx.add_instruction(Instruction::InitializeReferencedBinding)
                 });
@@ -314,12 +358,16 @@ fn for_in_of_body_evaluation(
 
     // j. Let result be Completion(Evaluation of stmt).
     stmt.compile(ctx);
+    // Set result to Completion(DisposeResources(iterationEnv.[[DisposeCapability]], result)).
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    if let Some(disposable_scope) = disposable_scope {
+        ctx.exit_disposable_scope(disposable_scope);
+    }
 
     // k. Set the running execution context's LexicalEnvironment to oldEnv.
     // l. Corollary: If LoopContinues(result, labelSet) is true, then
     // jump to repeat_jump.
-    let own_continues = ctx.current_continue.take().unwrap();
-    ctx.current_continue = previous_continue;
+    let own_continues = ctx.take_own_continues(&scope);
     if entered_declarative_environment {
         // Note: If we've entered a declarative environment then we have to
         // exit it before we continue back to repeat_jump.
@@ -340,30 +388,34 @@ fn for_in_of_body_evaluation(
     ctx.add_jump_instruction_to_index(Instruction::Jump, repeat_jump);
 
     // l. If LoopContinues(result, labelSet) is false, then
-    let own_breaks = ctx.current_break.take().unwrap();
-    ctx.current_break = previous_break;
-    for break_entry in own_breaks {
+    for break_entry in ctx.take_own_breaks(&scope) {
         ctx.set_jump_target_here(break_entry);
     }
-    ctx.current_depth_of_loop_scope = previous_depth_of_loop;
-    // i. If iterationKind is ENUMERATE, then
-    if iteration_kind == IterationKind::Enumerate {
-        // 1. Return ? UpdateEmpty(result, V).
-        // TODO: This is probably a no-op.
-    } else {
-        // ii. Else,
-        // 1. Assert: iterationKind is ITERATE.
-        assert_eq!(iteration_kind, IterationKind::Iterate);
-        // 2. Set status to Completion(UpdateEmpty(result, V)).
-        // TODO: This is probably a no-op.
-        // 3. If iteratorKind is ASYNC, return ? AsyncIteratorClose(iteratorRecord, status).
-        if iterator_kind == IteratorKind::Async {
-            ctx.add_instruction(Instruction::AsyncIteratorClose);
-        } else {
-            // 4. Return ? IteratorClose(iteratorRecord, status).
+    let close_iterator = |ctx: &mut CompileContext| {
+        // i. If iterationKind is ENUMERATE, then
+        if iteration_kind == IterationKind::Enumerate {
+            // 1. Return ? UpdateEmpty(result, V).
+            // Note: The property iterator only needs to be popped from the
+            // iterator stack, which IteratorClose does.
             ctx.add_instruction(Instruction::IteratorClose);
+        } else {
+            // ii. Else,
+            // 1. Assert: iterationKind is ITERATE.
+            // 2. Set status to Completion(UpdateEmpty(result, V)).
+            // TODO: This is probably a no-op.
+            // 3. If iteratorKind is ASYNC, return ? AsyncIteratorClose(iteratorRecord, status).
+            if iterator_kind == IteratorKind::Async {
+                compile_async_iterator_close(ctx);
+            } else {
+                // 4. Return ? IteratorClose(iteratorRecord, status).
+                ctx.add_instruction(Instruction::IteratorClose);
+            }
         }
-    }
+    };
+    close_iterator(ctx);
+    // Break and continue jumps to enclosing labels must close the iterator
+    // too.
+    ctx.exit_jump_target(scope, close_iterator);
     // m. If result.[[Value]] is not EMPTY, set V to result.[[Value]].
     ctx.set_jump_target_here(jump_to_end);
     if let Some(key_result) = key_result {
@@ -415,17 +467,12 @@ impl CompileEvaluation for ast::ForInStatement<'_> {
             key_result,
             IterationKind::Enumerate,
             lhs_kind,
-            // label_set,
         );
     }
 }
 
 impl CompileEvaluation for ast::ForOfStatement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
-        let previous_depth_of_loop = ctx.current_depth_of_loop_scope.replace(0);
-        let previous_continue = ctx.current_continue.replace(vec![]);
-        let previous_break = ctx.current_break.replace(vec![]);
-
         let mut uninitialized_bound_names = vec![];
 
         let lhs_kind = match &self.left {
@@ -466,18 +513,6 @@ impl CompileEvaluation for ast::ForOfStatement<'_> {
         // ForIn/OfHeadEvaluation should never return a jump for ITERATE or
         // ASYNC-ITERATE.
         debug_assert!(key_result.is_none());
-        for_in_of_body_evaluation(
-            ctx,
-            &self.left,
-            &self.body,
-            None,
-            iteration_kind,
-            lhs_kind,
-            // label_set,
-        );
-
-        ctx.current_break = previous_break;
-        ctx.current_continue = previous_continue;
-        ctx.current_depth_of_loop_scope = previous_depth_of_loop;
+        for_in_of_body_evaluation(ctx, &self.left, &self.body, None, iteration_kind, lhs_kind);
     }
 }
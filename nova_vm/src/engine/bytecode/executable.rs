@@ -11,7 +11,9 @@ use std::marker::PhantomData;
 use crate::{
     ecmascript::{
         execution::Agent,
-        scripts_and_modules::script::Script,
+        scripts_and_modules::{
+            module::module_semantics::source_text_module_records::SourceTextModule, script::Script,
+        },
         syntax_directed_operations::function_definitions::CompileFunctionBodyData,
         types::{String, Value},
     },
@@ -83,6 +85,9 @@ pub(crate) struct FunctionExpression<'a> {
     pub(crate) identifier: Option<NamedEvaluationParameter>,
     /// Optionally eagerly compile the FunctionExpression into bytecode.
     pub(crate) compiled_bytecode: Option<Executable<'a>>,
+    /// Class field initializers of a derived class constructor, compiled
+    /// separately as they must only run after the super() call returns.
+    pub(crate) compiled_initializer_bytecode: Option<Executable<'a>>,
 }
 
 // SAFETY: Property implemented as a lifetime transmute.
@@ -134,6 +139,7 @@ pub struct ExecutableHeapData<'a> {
     pub(crate) constants: Box<[Value<'a>]>,
     pub(crate) function_expressions: Box<[FunctionExpression<'a>]>,
     pub(crate) arrow_function_expressions: Box<[ArrowFunctionExpression]>,
+    pub(crate) template_literals: Box<[SendableRef<ast::TemplateLiteral<'static>>]>,
     pub(crate) class_initializer_bytecodes: Box<[(Option<Executable<'a>>, bool)]>,
 }
 
@@ -159,6 +165,27 @@ impl<'gc> Executable<'gc> {
         ctx.finish()
     }
 
+    pub(crate) fn compile_module(
+        agent: &mut Agent,
+        module: SourceTextModule,
+        gc: NoGcScope<'gc, '_>,
+    ) -> Self {
+        if agent.options.print_internals {
+            eprintln!();
+            eprintln!("=== Compiling Module ===");
+            eprintln!();
+        }
+        // SAFETY: Module uniquely owns the Program and the body buffer does
+        // not move under any circumstances during heap operations.
+        let body: &[Statement] =
+            unsafe { core::mem::transmute(agent[module].ecmascript_code.body.as_slice()) };
+        let mut ctx = CompileContext::new(agent, gc);
+
+        ctx.compile_module_body(body);
+        ctx.do_implicit_return();
+        ctx.finish()
+    }
+
     pub(crate) fn compile_function_body(
         agent: &mut Agent,
         data: CompileFunctionBodyData<'_>,
@@ -287,6 +314,14 @@ impl<'gc> Executable<'gc> {
         &agent[self].arrow_function_expressions[index]
     }
 
+    fn fetch_template_literal(
+        self,
+        agent: &Agent,
+        index: usize,
+    ) -> &'static ast::TemplateLiteral<'static> {
+        agent[self].template_literals[index].get()
+    }
+
     fn fetch_class_initializer_bytecode(
         self,
         agent: &Agent,
@@ -359,6 +394,15 @@ impl Scoped<'_, Executable<'static>> {
             .fetch_arrow_function_expression(agent, index)
     }
 
+    #[inline]
+    pub(super) fn fetch_template_literal(
+        &self,
+        agent: &Agent,
+        index: usize,
+    ) -> &'static ast::TemplateLiteral<'static> {
+        self.get(agent).fetch_template_literal(agent, index)
+    }
+
     #[inline]
     pub(super) fn fetch_class_initializer_bytecode<'gc>(
         &self,
@@ -477,6 +521,7 @@ impl HeapMarkAndSweep for ExecutableHeapData<'static> {
             constants,
             function_expressions: _,
             arrow_function_expressions: _,
+            template_literals: _,
             class_initializer_bytecodes,
         } = self;
         constants.mark_values(queues);
@@ -491,6 +536,7 @@ impl HeapMarkAndSweep for ExecutableHeapData<'static> {
             constants,
             function_expressions: _,
             arrow_function_expressions: _,
+            template_literals: _,
             class_initializer_bytecodes,
         } = self;
         constants.sweep_values(compactions);
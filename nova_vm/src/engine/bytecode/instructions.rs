@@ -86,6 +86,17 @@ pub enum Instruction {
     EvaluatePropertyAccessWithExpressionKey,
     /// Store EvaluatePropertyAccessWithIdentifierKey() as the result value.
     EvaluatePropertyAccessWithIdentifierKey,
+    /// Store MakePrivateReference(baseValue, privateIdentifier) as the
+    /// current reference, with baseValue being the result value.
+    MakePrivateReference,
+    /// Store MakeSuperPropertyReference(actualThis, propertyKey, strict) as
+    /// the current reference, with actualThis being the top stack value and
+    /// propertyKey being ToPropertyKey() of the result value.
+    MakeSuperPropertyReferenceWithExpressionKey,
+    /// Store MakeSuperPropertyReference(actualThis, propertyKey, strict) as
+    /// the current reference, with actualThis being the this binding of the
+    /// running execution context and propertyKey being the given identifier.
+    MakeSuperPropertyReferenceWithIdentifierKey,
     /// Store [GetValue()](https://tc39.es/ecma262/#sec-getvalue) as the result
     /// value.
     ///
@@ -94,6 +105,9 @@ pub enum Instruction {
     /// analysed from the AST. Non-reference values are already in the result
     /// value so a `GetValue` call would be a no-op.
     GetValue,
+    /// Perform GetTemplateObject for the template literal at the given index
+    /// and store the result as the result value.
+    GetTemplateObject,
     /// Same as GetValue without taking the reference slot. Used for reference
     /// property updates and function calls (where `this` comes from the
     /// reference).
@@ -104,6 +118,11 @@ pub enum Instruction {
     GreaterThanEquals,
     /// Store HasProperty() as the result value.
     HasProperty,
+    /// Store true as the result value if the result value contains a
+    /// PrivateElement whose \[\[Key]] is the Private Name with the given
+    /// identifier as its \[\[Description]], false otherwise. Throws a
+    /// TypeError if the result value is not an object.
+    HasPrivateElement,
     Increment,
     Decrement,
     /// Store InstanceofOperator() as the result value.
@@ -121,6 +140,27 @@ pub enum Instruction {
     /// Store CreateBuiltinFunction(defaultConstructor, 0, className) as the
     /// result value.
     ClassDefineDefaultConstructor,
+    /// Create and define a private method on a class or its instances.
+    ///
+    /// The Private Name's \[\[Description]] is at the top of the stack, the
+    /// home object is second on the stack. The home object is not popped from
+    /// the stack. The second argument is true if the method is static.
+    ClassDefinePrivateMethod,
+    ClassDefinePrivateGetter,
+    ClassDefinePrivateSetter,
+    /// Call PrivateFieldAdd(object, privateName, value) with value being the
+    /// result value, object being the top stack value and privateName being
+    /// the Private Name with the given identifier as its \[\[Description]].
+    /// The object is popped from the stack.
+    ClassDefinePrivateField,
+    /// Perform PrivateMethodOrAccessorAdd(object, method) for each private
+    /// method and accessor of the class's instances, with object being the
+    /// result value.
+    ClassInitializePrivateMethods,
+    /// Add a new Private Name with the given identifier as its
+    /// \[\[Description]] to the running execution context's
+    /// PrivateEnvironment.
+    CreatePrivateName,
     /// Store IsLooselyEqual() as the result value.
     IsLooselyEqual,
     /// Take the result value and the top stack value, compare them using
@@ -184,7 +224,8 @@ pub enum Instruction {
     /// Call `object[[SetPrototypeOf]](value)` on the object on the stack using
     /// the current result value as the parameter.
     ObjectSetPrototype,
-    /// Pop a jump target for uncaught exceptions
+    /// Pop a jump target for uncaught exceptions and restore the lexical and
+    /// private environments that were active when it was pushed.
     PopExceptionJumpTarget,
     /// Pop the last stored reference.
     PopReference,
@@ -234,6 +275,24 @@ pub enum Instruction {
     /// Performs Yield() on the result value, and after resuming, stores the
     /// value passed to `next()` as the result value.
     Yield,
+    /// Placed directly after a `Yield` instruction inside a try statement with
+    /// a finally block. If the generator is resumed with a return completion,
+    /// execution continues at the jump slot with the return value as the
+    /// result value. Otherwise this instruction does nothing.
+    YieldReturnTarget,
+    /// Performs GeneratorYield or AsyncGeneratorYield on the result value
+    /// without further processing, and after resuming, stores the value passed
+    /// to `next()` as the result value.
+    ///
+    /// This is used by `yield*`: In sync generators the result value is the
+    /// inner iterator's result object which is yielded as-is, and in async
+    /// generators the result value is yielded without being awaited.
+    YieldDelegate,
+    /// Placed directly after a `YieldDelegate` instruction. If the generator
+    /// is resumed with a throw completion, execution continues at the jump
+    /// slot with the thrown value as the result value. Otherwise this
+    /// instruction does nothing.
+    YieldThrowTarget,
     /// Perform CreateImmutableBinding in the running execution context's
     /// LexicalEnvironment with an identifier parameter and `true`
     CreateImmutableBinding,
@@ -260,6 +319,11 @@ pub enum Instruction {
     /// spec requires that creation of bindings in the environment is done
     /// first. This is immaterial because creating the bindings cannot fail.
     EnterDeclarativeEnvironment,
+    /// Create a new object environment with the result value as the binding
+    /// object and \[\[IsWithEnvironment]] set to true, and set it as the
+    /// running execution context's LexicalEnvironment. The result value must
+    /// be an object.
+    EnterObjectEnvironment,
     /// Enter a new FunctionEnvironment with the top of the stack as the this
     /// binding and \[\[FunctionObject]]. This is used for class static
     /// initializers.
@@ -268,6 +332,13 @@ pub enum Instruction {
     /// value's \[\[OuterEnv]].
     ExitDeclarativeEnvironment,
     ExitVariableEnvironment,
+    /// Create a new PrivateEnvironment Record whose outer environment is the
+    /// running execution context's PrivateEnvironment and set it as the
+    /// running execution context's PrivateEnvironment.
+    EnterPrivateEnvironment,
+    /// Reset the running execution context's PrivateEnvironment to its
+    /// current value's \[\[OuterPrivateEnvironment]].
+    ExitPrivateEnvironment,
     /// Begin binding values using destructuring
     BeginSimpleObjectBindingPattern,
     /// Begin binding values using a sync iterator for known repetitions
@@ -346,6 +417,28 @@ pub enum Instruction {
     /// Perform IteratorStepValue on the current iterator and jump to
     /// index if iterator completed.
     IteratorStepValue,
+    /// Call the next method of the current iterator with no arguments and
+    /// store the result as the result value.
+    IteratorCallNextMethod,
+    /// Call the next method of the current iterator with the result value as
+    /// the argument and store the result as the result value.
+    IteratorCallNextMethodWithValue,
+    /// Call the throw method of the current iterator with the result value as
+    /// the argument and store the result as the result value. If the iterator
+    /// has no throw method, jump to index.
+    IteratorCallThrowMethod,
+    /// Call the return method of the current iterator with the result value
+    /// as the argument and store the result as the result value. If the
+    /// iterator has no return method, pop the iterator off the iterator stack
+    /// and jump to index.
+    IteratorCallReturnMethod,
+    /// Throw a TypeError if the result value is not an object. Otherwise
+    /// perform IteratorComplete on the result value, and if it is true pop
+    /// the current iterator off the iterator stack and jump to index.
+    IteratorComplete,
+    /// Perform IteratorValue on the result value and store it as the result
+    /// value.
+    IteratorValue,
     /// Perform IteratorStepValue on the current iterator, putting the resulting
     /// value on the result value, or undefined if the iterator has completed.
     ///
@@ -358,10 +451,53 @@ pub enum Instruction {
     IteratorRestIntoArray,
     /// Perform CloseIterator on the current iterator
     IteratorClose,
-    /// Perform AsyncCloseIterator on the current iterator
+    /// Pop the current iterator off the iterator stack and call its return
+    /// method, storing the result as the result value. The result should then
+    /// be awaited and checked to be an object. If the iterator has no return
+    /// method, jump to index.
     AsyncIteratorClose,
     /// Store GetNewTarget() as the result value.
     GetNewTarget,
+    /// Perform EvaluateImportCall with the specifier popped from the stack
+    /// and the result value as the options, and store the resulting promise
+    /// as the result value.
+    ImportCall,
+    /// Store the `import.meta` object of the active module as the result
+    /// value.
+    ImportMeta,
+    /// Create a new RegExp object from the RegExp literal template in the
+    /// result value, and store it as the result value.
+    #[cfg(feature = "regexp")]
+    InstantiateRegExpLiteral,
+    /// Push a new synchronous dispose capability onto the disposable stack.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    EnterDisposableScope,
+    /// Push a new asynchronous dispose capability onto the disposable stack.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    EnterAsyncDisposableScope,
+    /// Perform AddDisposableResource with the result value and sync-dispose
+    /// hint on the current dispose capability.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AddDisposableResource,
+    /// Perform AddDisposableResource with the result value and async-dispose
+    /// hint on the current dispose capability.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AddAsyncDisposableResource,
+    /// Pop the current dispose capability off the disposable stack and
+    /// perform DisposeResources on it with a normal completion.
+    ///
+    /// If the dispose capability is asynchronous, the result value is set to
+    /// a promise that should then be awaited.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposeResources,
+    /// Pop the current dispose capability off the disposable stack and
+    /// perform DisposeResources on it with the result value as a throw
+    /// completion.
+    ///
+    /// If the dispose capability is asynchronous, the result value is set to
+    /// a promise that should then be awaited. Otherwise, this always throws.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposeResourcesWithError,
 }
 
 impl Instruction {
@@ -371,7 +507,14 @@ impl Instruction {
             Self::BeginSimpleArrayBindingPattern
             | Self::BindingPatternBindNamed
             | Self::ClassDefineConstructor
+            | Self::ClassDefinePrivateGetter
+            | Self::ClassDefinePrivateMethod
+            | Self::ClassDefinePrivateSetter
             | Self::InitializeVariableEnvironment
+            | Self::AsyncIteratorClose
+            | Self::IteratorCallReturnMethod
+            | Self::IteratorCallThrowMethod
+            | Self::IteratorComplete
             | Self::IteratorStepValue
             | Self::Jump
             | Self::JumpIfNot
@@ -379,7 +522,9 @@ impl Instruction {
             | Self::ObjectDefineGetter
             | Self::ObjectDefineMethod
             | Self::ObjectDefineSetter
-            | Self::PushExceptionJumpTarget => 2,
+            | Self::PushExceptionJumpTarget
+            | Self::YieldReturnTarget
+            | Self::YieldThrowTarget => 2,
             Self::ArrayCreate
             | Self::ArraySetValue
             | Self::BeginSimpleObjectBindingPattern
@@ -387,18 +532,24 @@ impl Instruction {
             | Self::BindingPatternBindRest
             | Self::BindingPatternGetValueNamed
             | Self::ClassDefineDefaultConstructor
+            | Self::ClassDefinePrivateField
             | Self::CopyDataPropertiesIntoObject
             | Self::CreateCatchBinding
             | Self::CreateImmutableBinding
             | Self::CreateMutableBinding
+            | Self::CreatePrivateName
             | Self::DirectEvalCall
             | Self::EvaluateCall
             | Self::EvaluateNew
             | Self::EvaluateSuper
             | Self::EvaluatePropertyAccessWithIdentifierKey
+            | Self::GetTemplateObject
+            | Self::HasPrivateElement
             | Self::InstantiateArrowFunctionExpression
             | Self::InstantiateOrdinaryFunctionExpression
             | Self::LoadConstant
+            | Self::MakePrivateReference
+            | Self::MakeSuperPropertyReferenceWithIdentifierKey
             | Self::ResolveBinding
             | Self::StoreConstant
             | Self::StringConcat
@@ -411,11 +562,17 @@ impl Instruction {
         debug_assert_eq!(self.argument_count(), 2);
         matches!(
             self,
-            Self::IteratorStepValue
+            Self::AsyncIteratorClose
+                | Self::IteratorCallReturnMethod
+                | Self::IteratorCallThrowMethod
+                | Self::IteratorComplete
+                | Self::IteratorStepValue
                 | Self::Jump
                 | Self::JumpIfNot
                 | Self::JumpIfTrue
                 | Self::PushExceptionJumpTarget
+                | Self::YieldReturnTarget
+                | Self::YieldThrowTarget
         )
     }
 
@@ -440,6 +597,11 @@ impl Instruction {
                 | Self::BindingPatternBind
                 | Self::BindingPatternBindNamed
                 | Self::BindingPatternBindRest
+                | Self::ClassDefinePrivateField
+                | Self::CreatePrivateName
+                | Self::HasPrivateElement
+                | Self::MakePrivateReference
+                | Self::MakeSuperPropertyReferenceWithIdentifierKey
         )
     }
 
@@ -447,6 +609,9 @@ impl Instruction {
         matches!(
             self,
             Self::ClassDefineConstructor
+                | Self::ClassDefinePrivateGetter
+                | Self::ClassDefinePrivateMethod
+                | Self::ClassDefinePrivateSetter
                 | Self::InstantiateArrowFunctionExpression
                 | Self::InstantiateOrdinaryFunctionExpression
                 | Self::ObjectDefineGetter
@@ -462,7 +627,13 @@ impl Instruction {
                 | Self::JumpIfNot
                 | Self::JumpIfTrue
                 | Self::PushExceptionJumpTarget
+                | Self::AsyncIteratorClose
+                | Self::IteratorCallReturnMethod
+                | Self::IteratorCallThrowMethod
+                | Self::IteratorComplete
                 | Self::IteratorStepValue
+                | Self::YieldReturnTarget
+                | Self::YieldThrowTarget
         )
     }
 
@@ -769,6 +940,21 @@ impl Instr {
             Instruction::ObjectDefineGetter => "get function() {}".to_string(),
             Instruction::ObjectDefineMethod => "function() {}".to_string(),
             Instruction::ObjectDefineSetter => "set function() {}".to_string(),
+            Instruction::ClassDefinePrivateMethod => {
+                format!("{}function() {{}}", if arg1 == 1 { "static " } else { "" })
+            }
+            Instruction::ClassDefinePrivateGetter => {
+                format!(
+                    "{}get function() {{}}",
+                    if arg1 == 1 { "static " } else { "" }
+                )
+            }
+            Instruction::ClassDefinePrivateSetter => {
+                format!(
+                    "{}set function() {{}}",
+                    if arg1 == 1 { "static " } else { "" }
+                )
+            }
             _ => unreachable!(),
         }
     }
@@ -1045,6 +1231,8 @@ impl TryFrom<u8> for Instruction {
             std::mem::transmute::<_, u8>(Instruction::EvaluatePropertyAccessWithIdentifierKey)
         };
         const GETVALUE: u8 = unsafe { std::mem::transmute::<_, u8>(Instruction::GetValue) };
+        const GETTEMPLATEOBJECT: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::GetTemplateObject) };
         const GETVALUEKEEPREFERENCE: u8 =
             unsafe { std::mem::transmute::<_, u8>(Instruction::GetValueKeepReference) };
         const GREATERTHAN: u8 = unsafe { std::mem::transmute::<_, u8>(Instruction::GreaterThan) };
@@ -1131,6 +1319,12 @@ impl TryFrom<u8> for Instruction {
         const TYPEOF: u8 = unsafe { std::mem::transmute::<_, u8>(Instruction::Typeof) };
         const UNARYMINUS: u8 = unsafe { std::mem::transmute::<_, u8>(Instruction::UnaryMinus) };
         const YIELD: u8 = unsafe { std::mem::transmute::<_, u8>(Instruction::Yield) };
+        const YIELDRETURNTARGET: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::YieldReturnTarget) };
+        const YIELDDELEGATE: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::YieldDelegate) };
+        const YIELDTHROWTARGET: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::YieldThrowTarget) };
         const CREATEIMMUTABLEBINDING: u8 =
             unsafe { std::mem::transmute::<_, u8>(Instruction::CreateImmutableBinding) };
         const CREATEMUTABLEBINDING: u8 =
@@ -1141,6 +1335,34 @@ impl TryFrom<u8> for Instruction {
             unsafe { std::mem::transmute::<_, u8>(Instruction::InitializeVariableEnvironment) };
         const ENTERDECLARATIVEENVIRONMENT: u8 =
             unsafe { std::mem::transmute::<_, u8>(Instruction::EnterDeclarativeEnvironment) };
+        const ENTEROBJECTENVIRONMENT: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::EnterObjectEnvironment) };
+        const ENTERPRIVATEENVIRONMENT: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::EnterPrivateEnvironment) };
+        const EXITPRIVATEENVIRONMENT: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::ExitPrivateEnvironment) };
+        const CLASSDEFINEPRIVATEMETHOD: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::ClassDefinePrivateMethod) };
+        const CLASSDEFINEPRIVATEGETTER: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::ClassDefinePrivateGetter) };
+        const CLASSDEFINEPRIVATESETTER: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::ClassDefinePrivateSetter) };
+        const CLASSDEFINEPRIVATEFIELD: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::ClassDefinePrivateField) };
+        const CLASSINITIALIZEPRIVATEMETHODS: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::ClassInitializePrivateMethods) };
+        const CREATEPRIVATENAME: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::CreatePrivateName) };
+        const MAKEPRIVATEREFERENCE: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::MakePrivateReference) };
+        const MAKESUPERPROPERTYREFERENCEWITHEXPRESSIONKEY: u8 = unsafe {
+            std::mem::transmute::<_, u8>(Instruction::MakeSuperPropertyReferenceWithExpressionKey)
+        };
+        const MAKESUPERPROPERTYREFERENCEWITHIDENTIFIERKEY: u8 = unsafe {
+            std::mem::transmute::<_, u8>(Instruction::MakeSuperPropertyReferenceWithIdentifierKey)
+        };
+        const HASPRIVATEELEMENT: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::HasPrivateElement) };
         const ENTERCLASSSTATICELEMENTENVIRONMENT: u8 = unsafe {
             std::mem::transmute::<_, u8>(Instruction::EnterClassStaticElementEnvironment)
         };
@@ -1176,6 +1398,18 @@ impl TryFrom<u8> for Instruction {
             unsafe { std::mem::transmute::<_, u8>(Instruction::GetIteratorAsync) };
         const ITERATORSTEPVALUE: u8 =
             unsafe { std::mem::transmute::<_, u8>(Instruction::IteratorStepValue) };
+        const ITERATORCALLNEXTMETHOD: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::IteratorCallNextMethod) };
+        const ITERATORCALLNEXTMETHODWITHVALUE: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::IteratorCallNextMethodWithValue) };
+        const ITERATORCALLTHROWMETHOD: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::IteratorCallThrowMethod) };
+        const ITERATORCALLRETURNMETHOD: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::IteratorCallReturnMethod) };
+        const ITERATORCOMPLETE: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::IteratorComplete) };
+        const ITERATORVALUE: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::IteratorValue) };
         const ITERATORSTEPVALUEORUNDEFINED: u8 =
             unsafe { std::mem::transmute::<_, u8>(Instruction::IteratorStepValueOrUndefined) };
         const ITERATORRESTINTOARRAY: u8 =
@@ -1185,6 +1419,29 @@ impl TryFrom<u8> for Instruction {
         const ASYNCITERATORCLOSE: u8 =
             unsafe { std::mem::transmute::<_, u8>(Instruction::AsyncIteratorClose) };
         const GETNEWTARGET: u8 = unsafe { std::mem::transmute::<_, u8>(Instruction::GetNewTarget) };
+        const IMPORTCALL: u8 = unsafe { std::mem::transmute::<_, u8>(Instruction::ImportCall) };
+        const IMPORTMETA: u8 = unsafe { std::mem::transmute::<_, u8>(Instruction::ImportMeta) };
+        #[cfg(feature = "regexp")]
+        const INSTANTIATEREGEXPLITERAL: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::InstantiateRegExpLiteral) };
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        const ENTERDISPOSABLESCOPE: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::EnterDisposableScope) };
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        const ENTERASYNCDISPOSABLESCOPE: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::EnterAsyncDisposableScope) };
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        const ADDDISPOSABLERESOURCE: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::AddDisposableResource) };
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        const ADDASYNCDISPOSABLERESOURCE: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::AddAsyncDisposableResource) };
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        const DISPOSERESOURCES: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::DisposeResources) };
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        const DISPOSERESOURCESWITHERROR: u8 =
+            unsafe { std::mem::transmute::<_, u8>(Instruction::DisposeResourcesWithError) };
         match value {
             ADDITION => Ok(Instruction::ApplyStringOrNumericBinaryOperator(
                 BinaryOperator::Addition,
@@ -1275,6 +1532,7 @@ impl TryFrom<u8> for Instruction {
                 Ok(Instruction::EvaluatePropertyAccessWithIdentifierKey)
             }
             GETVALUE => Ok(Instruction::GetValue),
+            GETTEMPLATEOBJECT => Ok(Instruction::GetTemplateObject),
             GETVALUEKEEPREFERENCE => Ok(Instruction::GetValueKeepReference),
             GREATERTHAN => Ok(Instruction::GreaterThan),
             GREATERTHANEQUALS => Ok(Instruction::GreaterThanEquals),
@@ -1337,16 +1595,36 @@ impl TryFrom<u8> for Instruction {
             TYPEOF => Ok(Instruction::Typeof),
             UNARYMINUS => Ok(Instruction::UnaryMinus),
             YIELD => Ok(Instruction::Yield),
+            YIELDRETURNTARGET => Ok(Instruction::YieldReturnTarget),
+            YIELDDELEGATE => Ok(Instruction::YieldDelegate),
+            YIELDTHROWTARGET => Ok(Instruction::YieldThrowTarget),
             CREATEIMMUTABLEBINDING => Ok(Instruction::CreateImmutableBinding),
             CREATEMUTABLEBINDING => Ok(Instruction::CreateMutableBinding),
             INITIALIZEREFERENCEDBINDING => Ok(Instruction::InitializeReferencedBinding),
             INITIALIZEVARIABLEENVIRONMENT => Ok(Instruction::InitializeVariableEnvironment),
             ENTERDECLARATIVEENVIRONMENT => Ok(Instruction::EnterDeclarativeEnvironment),
+            ENTEROBJECTENVIRONMENT => Ok(Instruction::EnterObjectEnvironment),
             ENTERCLASSSTATICELEMENTENVIRONMENT => {
                 Ok(Instruction::EnterClassStaticElementEnvironment)
             }
             EXITDECLARATIVEENVIRONMENT => Ok(Instruction::ExitDeclarativeEnvironment),
             EXITVARIABLEENVIRONMENT => Ok(Instruction::ExitVariableEnvironment),
+            ENTERPRIVATEENVIRONMENT => Ok(Instruction::EnterPrivateEnvironment),
+            EXITPRIVATEENVIRONMENT => Ok(Instruction::ExitPrivateEnvironment),
+            CLASSDEFINEPRIVATEMETHOD => Ok(Instruction::ClassDefinePrivateMethod),
+            CLASSDEFINEPRIVATEGETTER => Ok(Instruction::ClassDefinePrivateGetter),
+            CLASSDEFINEPRIVATESETTER => Ok(Instruction::ClassDefinePrivateSetter),
+            CLASSDEFINEPRIVATEFIELD => Ok(Instruction::ClassDefinePrivateField),
+            CLASSINITIALIZEPRIVATEMETHODS => Ok(Instruction::ClassInitializePrivateMethods),
+            CREATEPRIVATENAME => Ok(Instruction::CreatePrivateName),
+            MAKEPRIVATEREFERENCE => Ok(Instruction::MakePrivateReference),
+            MAKESUPERPROPERTYREFERENCEWITHEXPRESSIONKEY => {
+                Ok(Instruction::MakeSuperPropertyReferenceWithExpressionKey)
+            }
+            MAKESUPERPROPERTYREFERENCEWITHIDENTIFIERKEY => {
+                Ok(Instruction::MakeSuperPropertyReferenceWithIdentifierKey)
+            }
+            HASPRIVATEELEMENT => Ok(Instruction::HasPrivateElement),
             BEGINSIMPLEOBJECTBINDINGPATTERN => Ok(Instruction::BeginSimpleObjectBindingPattern),
             BEGINSIMPLEARRAYBINDINGPATTERN => Ok(Instruction::BeginSimpleArrayBindingPattern),
             BINDINGPATTERNBIND => Ok(Instruction::BindingPatternBind),
@@ -1361,11 +1639,33 @@ impl TryFrom<u8> for Instruction {
             GETITERATORSYNC => Ok(Instruction::GetIteratorSync),
             GETITERATORASYNC => Ok(Instruction::GetIteratorAsync),
             ITERATORSTEPVALUE => Ok(Instruction::IteratorStepValue),
+            ITERATORCALLNEXTMETHOD => Ok(Instruction::IteratorCallNextMethod),
+            ITERATORCALLNEXTMETHODWITHVALUE => Ok(Instruction::IteratorCallNextMethodWithValue),
+            ITERATORCALLTHROWMETHOD => Ok(Instruction::IteratorCallThrowMethod),
+            ITERATORCALLRETURNMETHOD => Ok(Instruction::IteratorCallReturnMethod),
+            ITERATORCOMPLETE => Ok(Instruction::IteratorComplete),
+            ITERATORVALUE => Ok(Instruction::IteratorValue),
             ITERATORSTEPVALUEORUNDEFINED => Ok(Instruction::IteratorStepValueOrUndefined),
             ITERATORRESTINTOARRAY => Ok(Instruction::IteratorRestIntoArray),
             ITERATORCLOSE => Ok(Instruction::IteratorClose),
             ASYNCITERATORCLOSE => Ok(Instruction::AsyncIteratorClose),
             GETNEWTARGET => Ok(Instruction::GetNewTarget),
+            IMPORTCALL => Ok(Instruction::ImportCall),
+            IMPORTMETA => Ok(Instruction::ImportMeta),
+            #[cfg(feature = "regexp")]
+            INSTANTIATEREGEXPLITERAL => Ok(Instruction::InstantiateRegExpLiteral),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ENTERDISPOSABLESCOPE => Ok(Instruction::EnterDisposableScope),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ENTERASYNCDISPOSABLESCOPE => Ok(Instruction::EnterAsyncDisposableScope),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ADDDISPOSABLERESOURCE => Ok(Instruction::AddDisposableResource),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ADDASYNCDISPOSABLERESOURCE => Ok(Instruction::AddAsyncDisposableResource),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            DISPOSERESOURCES => Ok(Instruction::DisposeResources),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            DISPOSERESOURCESWITHERROR => Ok(Instruction::DisposeResourcesWithError),
             _ => Err(()),
         }
     }
@@ -7,11 +7,13 @@ use std::{marker::PhantomData, ptr::NonNull};
 use crate::{
     ecmascript::{
         abstract_operations::{
-            operations_on_iterator_objects::{IteratorRecord, get_iterator_from_method},
+            operations_on_iterator_objects::{
+                IteratorRecord, create_iter_result_object, get_iterator_from_method,
+            },
             operations_on_objects::{call_function, get, get_method, throw_not_callable},
             type_conversion::to_boolean,
         },
-        builtins::{Array, ScopedArgumentsList},
+        builtins::{ArgumentsList, Array, ScopedArgumentsList},
         execution::{Agent, JsResult, agent::ExceptionType},
         types::{
             BUILTIN_STRING_MEMORY, InternalMethods, IntoValue, Object, PropertyKey, PropertyKeySet,
@@ -65,6 +67,83 @@ impl<'a> ActiveIterator<'a> {
         }
     }
 
+    /// Call the next method of the iterator, optionally with a value, and
+    /// return the result.
+    ///
+    /// Iterators that are not backed by an iterator object are stepped
+    /// directly and produce a new iterator result object.
+    pub(super) fn call_next<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        value: Option<Value>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let value = value.bind(gc.nogc());
+        let VmIteratorRecord::GenericIterator(iter) = self.get(agent) else {
+            let value = self
+                .step_value(agent, gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            let done = value.is_none();
+            return Ok(create_iter_result_object(
+                agent,
+                value.unwrap_or(Value::Undefined).unbind(),
+                done,
+                gc.into_nogc(),
+            )
+            .into_value());
+        };
+        let next_method = iter.next_method.bind(gc.nogc());
+        let iterator = iter.iterator.bind(gc.nogc());
+        let mut value = value.unbind();
+        call_function(
+            agent,
+            next_method.unbind(),
+            iterator.into_value().unbind(),
+            value.as_mut().map(ArgumentsList::from_mut_value),
+            gc,
+        )
+    }
+
+    /// Call the method of the iterator with the given name with a value, and
+    /// return the result. If the method is undefined, None is returned.
+    pub(super) fn call_method<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        name: PropertyKey<'static>,
+        value: Value,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Option<Value<'gc>>> {
+        let value = value.bind(gc.nogc());
+        let VmIteratorRecord::GenericIterator(iter) = self.get(agent) else {
+            return Ok(None);
+        };
+        let iterator = iter.iterator.bind(gc.nogc());
+        let value = value.scope(agent, gc.nogc());
+        let Some(method) = get_method(agent, iterator.into_value().unbind(), name, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
+        else {
+            return Ok(None);
+        };
+        // Note: The iterator is kept alive by the VM's iterator stack, but
+        // may have moved.
+        let VmIteratorRecord::GenericIterator(iter) = self.get(agent) else {
+            unreachable!()
+        };
+        let iterator = iter.iterator.bind(gc.nogc());
+        // SAFETY: value is not shared.
+        let mut value = unsafe { value.take(agent) };
+        call_function(
+            agent,
+            method.unbind(),
+            iterator.into_value().unbind(),
+            Some(ArgumentsList::from_mut_value(&mut value)),
+            gc,
+        )
+        .map(Some)
+    }
+
     pub(super) fn remaining_length_estimate(&mut self, agent: &mut Agent) -> Option<usize> {
         self.get(agent).remaining_length_estimate(agent)
     }
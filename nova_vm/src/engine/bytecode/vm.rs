@@ -14,13 +14,17 @@ use oxc_syntax::operator::BinaryOperator;
 use crate::{
     ecmascript::{
         abstract_operations::{
-            operations_on_iterator_objects::iterator_close_with_value,
+            operations_on_iterator_objects::{
+                get_iterator, iterator_close_with_value, iterator_complete, iterator_value,
+            },
             operations_on_objects::{
                 call, call_function, construct, copy_data_properties,
                 copy_data_properties_into_object, create_data_property_or_throw,
-                define_property_or_throw, get_method, has_property, ordinary_has_instance, set,
-                try_copy_data_properties_into_object, try_create_data_property,
-                try_create_data_property_or_throw, try_define_property_or_throw, try_has_property,
+                define_property_or_throw, get_method, has_property, initialize_instance_elements,
+                ordinary_has_instance, private_element_find, private_field_add,
+                private_method_or_accessor_add, set, try_copy_data_properties_into_object,
+                try_create_data_property, try_create_data_property_or_throw,
+                try_define_property_or_throw, try_has_property,
             },
             testing_and_comparison::{
                 is_callable, is_constructor, is_less_than, is_loosely_equal, is_strictly_equal,
@@ -40,17 +44,23 @@ use crate::{
             set_function_name,
         },
         execution::{
-            Agent, Environment, JsResult, ProtoIntrinsics,
-            agent::{ExceptionType, JsError, resolve_binding, try_resolve_binding},
+            Agent, Environment, JsResult, PrivateEnvironment, ProtoIntrinsics,
+            agent::{
+                ExceptionType, JsError, get_active_script_or_module, resolve_binding,
+                try_resolve_binding,
+            },
             get_this_environment, new_class_static_element_environment,
-            new_declarative_environment,
+            new_declarative_environment, new_object_environment, new_private_environment,
         },
+        scripts_and_modules::{ScriptOrModule, module::import_calls::evaluate_import_call},
+        syntax_directed_operations::template_literals::get_template_object,
         types::{
             BUILTIN_STRING_MEMORY, Base, BigInt, Function, InternalMethods, IntoFunction,
             IntoObject, IntoValue, Number, Numeric, Object, OrdinaryObject, Primitive,
-            PropertyDescriptor, PropertyKey, PropertyKeySet, Reference, String, Value,
-            get_this_value, get_value, initialize_referenced_binding, is_private_reference,
-            is_super_reference, put_value, try_get_value, try_initialize_referenced_binding,
+            PrivateElement, PrivateElementKind, PropertyDescriptor, PropertyKey, PropertyKeySet,
+            Reference, String, Value, get_this_value, get_value, initialize_referenced_binding,
+            is_private_reference, is_super_reference, make_super_property_reference,
+            push_private_method, put_value, try_get_value, try_initialize_referenced_binding,
         },
     },
     engine::{
@@ -70,11 +80,45 @@ use crate::{
 };
 
 use super::iterator::ActiveIterator;
+#[cfg(feature = "regexp")]
+use crate::ecmascript::builtins::regexp::reg_exp_instantiate_literal;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_disposable_objects::{
+            add_disposable_resource, dispose_resources, dispose_resources_async,
+        },
+        builtins::disposable_stack::{
+            DisposableStack,
+            data::{DisposableStackHeapData, DisposeHint},
+        },
+    },
+    heap::CreateHeapData,
+};
 
 struct EmptyParametersList(ast::FormalParameters<'static>);
 unsafe impl Send for EmptyParametersList {}
 unsafe impl Sync for EmptyParametersList {}
 
+/// Get an instance of the production FormalParameters : \[empty].
+fn empty_parameters() -> &'static ast::FormalParameters<'static> {
+    // We have to create a temporary allocator to create the empty
+    // items Vec. The allocator will never be asked to allocate
+    // anything.
+    static EMPTY_PARAMETERS: OnceLock<EmptyParametersList> = OnceLock::new();
+    &EMPTY_PARAMETERS
+        .get_or_init(|| {
+            let allocator: &'static oxc_allocator::Allocator = Box::leak(Box::default());
+            EmptyParametersList(ast::FormalParameters {
+                span: Default::default(),
+                kind: ast::FormalParameterKind::FormalParameter,
+                items: oxc_allocator::Vec::new_in(allocator),
+                rest: None,
+            })
+        })
+        .0
+}
+
 #[derive(Debug)]
 pub(crate) enum ExecutionResult<'a> {
     Return(Value<'a>),
@@ -86,6 +130,10 @@ pub(crate) enum ExecutionResult<'a> {
     Yield {
         vm: SuspendedVm,
         yielded_value: Value<'a>,
+        /// True if the value was yielded by `yield*`. The value should then
+        /// be yielded as-is: It is not wrapped in an iterator result object
+        /// in sync generators, nor awaited in async generators.
+        delegate: bool,
     },
 }
 impl<'a> ExecutionResult<'a> {
@@ -111,9 +159,14 @@ unsafe impl Bindable for ExecutionResult<'_> {
                 vm,
                 awaited_value: awaited_value.unbind(),
             },
-            Self::Yield { vm, yielded_value } => ExecutionResult::Yield {
+            Self::Yield {
+                vm,
+                yielded_value,
+                delegate,
+            } => ExecutionResult::Yield {
                 vm,
                 yielded_value: yielded_value.unbind(),
+                delegate,
             },
         }
     }
@@ -127,9 +180,14 @@ unsafe impl Bindable for ExecutionResult<'_> {
                 vm,
                 awaited_value: awaited_value.bind(gc),
             },
-            Self::Yield { vm, yielded_value } => ExecutionResult::Yield {
+            Self::Yield {
+                vm,
+                yielded_value,
+                delegate,
+            } => ExecutionResult::Yield {
                 vm,
                 yielded_value: yielded_value.bind(gc),
+                delegate,
             },
         }
     }
@@ -142,6 +200,7 @@ enum ContinuationKind {
     Normal,
     Return,
     Yield,
+    YieldDelegate,
     Await,
 }
 
@@ -152,6 +211,8 @@ struct ExceptionJumpTarget<'a> {
     ip: usize,
     /// The lexical environment which contains this exception jump target.
     lexical_environment: Environment<'a>,
+    /// The private environment which contains this exception jump target.
+    private_environment: Option<PrivateEnvironment<'a>>,
 }
 
 /// ## Notes
@@ -166,6 +227,10 @@ pub(crate) struct Vm {
     reference_stack: Vec<Reference<'static>>,
     iterator_stack: Vec<VmIteratorRecord<'static>>,
     exception_jump_target_stack: Vec<ExceptionJumpTarget<'static>>,
+    /// Dispose capabilities of the `using` declaration scopes that are
+    /// currently entered.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    disposable_stack: Vec<DisposableStack<'static>>,
     result: Option<Value<'static>>,
     reference: Option<Reference<'static>>,
 }
@@ -189,6 +254,10 @@ pub(crate) struct SuspendedVm {
     /// Note: Exception jump stack is non-empty only if the code awaits inside
     /// a try block. This means that often no heap data clone is required.
     exception_jump_target_stack: Box<[ExceptionJumpTarget<'static>]>,
+    /// Note: Disposable stack is non-empty only if the code awaits inside a
+    /// scope containing `using` declarations.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    disposable_stack: Box<[DisposableStack<'static>]>,
 }
 
 impl SuspendedVm {
@@ -212,13 +281,46 @@ impl SuspendedVm {
     ) -> ExecutionResult<'gc> {
         // Optimisation: Avoid unsuspending the Vm if we're just going to throw
         // out of it immediately.
-        if self.exception_jump_target_stack.is_empty() {
+        if self.exception_jump_target_stack.is_empty()
+            && Vm::get_yield_resumption_target(
+                agent,
+                &executable,
+                self.ip,
+                Instruction::YieldThrowTarget,
+            )
+            .is_none()
+        {
             let err = JsError::new(err.unbind());
             return ExecutionResult::Throw(err);
         }
         let vm = Vm::from_suspended(self);
         vm.resume_throw(agent, executable, err, gc)
     }
+
+    pub(crate) fn resume_return<'gc>(
+        self,
+        agent: &mut Agent,
+        executable: Scoped<Executable>,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> ExecutionResult<'gc> {
+        // Optimisation: Avoid unsuspending the Vm if we're not inside any try
+        // statement or `yield*`; no finally block or inner iterator can
+        // observe the return.
+        if self.exception_jump_target_stack.is_empty()
+            && Vm::get_yield_resumption_target(
+                agent,
+                &executable,
+                self.ip,
+                Instruction::YieldReturnTarget,
+            )
+            .is_none()
+        {
+            return ExecutionResult::Return(value.unbind().bind(gc.into_nogc()));
+        }
+        let vm = Vm::from_suspended(self);
+        vm.resume_return(agent, executable, value, gc)
+    }
 }
 
 impl Vm {
@@ -229,6 +331,8 @@ impl Vm {
             reference_stack: Vec::new(),
             iterator_stack: Vec::new(),
             exception_jump_target_stack: Vec::new(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stack: Vec::new(),
             result: None,
             reference: None,
         }
@@ -241,6 +345,8 @@ impl Vm {
             reference_stack: self.reference_stack.into_boxed_slice(),
             iterator_stack: self.iterator_stack.into_boxed_slice(),
             exception_jump_target_stack: self.exception_jump_target_stack.into_boxed_slice(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stack: self.disposable_stack.into_boxed_slice(),
         }
     }
 
@@ -251,6 +357,8 @@ impl Vm {
             reference_stack: suspended.reference_stack.into_vec(),
             iterator_stack: suspended.iterator_stack.into_vec(),
             exception_jump_target_stack: suspended.exception_jump_target_stack.into_vec(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stack: suspended.disposable_stack.into_vec(),
             result: None,
             reference: None,
         }
@@ -329,6 +437,18 @@ impl Vm {
         gc: GcScope<'gc, '_>,
     ) -> ExecutionResult<'gc> {
         let err = err.bind(gc.nogc());
+        // If we suspended at a `yield*`, the throw completion is forwarded to
+        // the inner iterator.
+        if let Some(target) = Self::get_yield_resumption_target(
+            agent,
+            &executable,
+            self.ip,
+            Instruction::YieldThrowTarget,
+        ) {
+            self.ip = target;
+            self.result = Some(err.unbind());
+            return self.inner_execute(agent, executable, gc);
+        }
         let err = JsError::new(err.unbind());
         if !self.handle_error(agent, err) {
             return ExecutionResult::Throw(err);
@@ -336,6 +456,54 @@ impl Vm {
         self.inner_execute(agent, executable, gc)
     }
 
+    pub fn resume_return<'gc>(
+        mut self,
+        agent: &mut Agent,
+        executable: Scoped<Executable>,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> ExecutionResult<'gc> {
+        // If the Yield we suspended at is inside a try statement with a
+        // finally block, it is followed by a YieldReturnTarget pointing at the
+        // code that runs the finally blocks and returns. A `yield*` likewise
+        // points at the code that forwards the return to the inner iterator.
+        // Otherwise we can return directly.
+        if let Some(target) = Self::get_yield_resumption_target(
+            agent,
+            &executable,
+            self.ip,
+            Instruction::YieldReturnTarget,
+        ) {
+            self.ip = target;
+            self.result = Some(value.unbind());
+            self.inner_execute(agent, executable, gc)
+        } else {
+            ExecutionResult::Return(value.unbind().bind(gc.into_nogc()))
+        }
+    }
+
+    /// Find the jump target of the given resumption marker instruction placed
+    /// after the yield instruction that the VM suspended at, if any.
+    ///
+    /// A `YieldThrowTarget` is always placed before a `YieldReturnTarget`.
+    fn get_yield_resumption_target(
+        agent: &Agent,
+        executable: &Scoped<Executable>,
+        mut ip: usize,
+        marker: Instruction,
+    ) -> Option<usize> {
+        let instructions = executable.get_instructions(agent);
+        while let Some(instr) = Instr::consume_instruction(instructions, &mut ip) {
+            if instr.kind == marker {
+                return Some(instr.get_jump_slot());
+            }
+            if instr.kind != Instruction::YieldThrowTarget {
+                break;
+            }
+        }
+        None
+    }
+
     fn inner_execute<'gc>(
         mut self,
         agent: &mut Agent,
@@ -365,6 +533,15 @@ impl Vm {
                     return ExecutionResult::Yield {
                         vm: self.suspend(),
                         yielded_value,
+                        delegate: false,
+                    };
+                }
+                Ok(ContinuationKind::YieldDelegate) => {
+                    let yielded_value = self.result.take().unwrap();
+                    return ExecutionResult::Yield {
+                        vm: self.suspend(),
+                        yielded_value,
+                        delegate: true,
                     };
                 }
                 Ok(ContinuationKind::Await) => {
@@ -391,6 +568,7 @@ impl Vm {
         if let Some(ejt) = self.exception_jump_target_stack.pop() {
             self.ip = ejt.ip;
             agent.set_current_lexical_environment(ejt.lexical_environment);
+            agent.set_current_private_environment(ejt.private_environment);
             self.result = Some(err.value().unbind());
             true
         } else {
@@ -515,6 +693,7 @@ impl Vm {
                         .unbind()
                         .get_this_binding(agent, gc.into_nogc())
                         .into_value(),
+                    Environment::Module(idx) => idx.get_this_binding(),
                     Environment::Object(_) => unreachable!(),
                 };
                 vm.result = Some(result.unbind());
@@ -743,25 +922,13 @@ impl Vm {
                 // 3. Let privateEnv be the running execution context's PrivateEnvironment.
                 let private_env = agent.current_private_environment(gc.nogc());
                 // 5. Let formalParameterList be an instance of the production FormalParameters : [empty] .
-                // We have to create a temporary allocator to create the empty
-                // items Vec. The allocator will never be asked to allocate
-                // anything.
-                static EMPTY_PARAMETERS: OnceLock<EmptyParametersList> = OnceLock::new();
-                let empty_parameters = EMPTY_PARAMETERS.get_or_init(|| {
-                    let allocator: &'static oxc_allocator::Allocator = Box::leak(Box::default());
-                    EmptyParametersList(ast::FormalParameters {
-                        span: Default::default(),
-                        kind: ast::FormalParameterKind::FormalParameter,
-                        items: oxc_allocator::Vec::new_in(allocator),
-                        rest: None,
-                    })
-                });
+                let empty_parameters = empty_parameters();
                 let params = OrdinaryFunctionCreateParams {
                     function_prototype: None,
                     source_code: None,
                     // 4. Let sourceText be the source text matched by MethodDefinition.
                     source_text: function_expression.span,
-                    parameters_list: &empty_parameters.0,
+                    parameters_list: empty_parameters,
                     body: function_expression.body.as_ref().unwrap(),
                     is_async: function_expression.r#async,
                     is_generator: function_expression.generator,
@@ -895,6 +1062,139 @@ impl Vm {
                 )?;
                 // c. Return unused.
             }
+            Instruction::ClassDefinePrivateMethod
+            | Instruction::ClassDefinePrivateGetter
+            | Instruction::ClassDefinePrivateSetter => {
+                let FunctionExpression { expression, .. } =
+                    executable.fetch_function_expression(agent, instr.get_first_index(), gc.nogc());
+                let function_expression = expression.get();
+                let is_static = instr.get_second_bool();
+                // 1. Let propKey be ? Evaluation of ClassElementName.
+                let description = String::try_from(vm.stack.pop().unwrap())
+                    .unwrap()
+                    .bind(gc.nogc());
+                let object = Object::try_from(*vm.stack.last().unwrap())
+                    .unwrap()
+                    .bind(gc.nogc());
+                // 2. Let env be the running execution context's LexicalEnvironment.
+                let env = agent.current_lexical_environment(gc.nogc());
+                // 3. Let privateEnv be the running execution context's PrivateEnvironment.
+                let private_env = agent.current_private_environment(gc.nogc()).unwrap();
+                let parameters_list = if instr.kind == Instruction::ClassDefinePrivateGetter {
+                    // Let formalParameterList be an instance of the
+                    // production FormalParameters : [empty] .
+                    empty_parameters()
+                } else {
+                    &function_expression.params
+                };
+                let params = OrdinaryFunctionCreateParams {
+                    function_prototype: None,
+                    source_code: None,
+                    // Let sourceText be the source text matched by MethodDefinition.
+                    source_text: function_expression.span,
+                    parameters_list,
+                    body: function_expression.body.as_ref().unwrap(),
+                    is_concise_arrow_function: false,
+                    is_async: function_expression.r#async,
+                    is_generator: function_expression.generator,
+                    lexical_this: false,
+                    env,
+                    private_env: Some(private_env),
+                };
+                // Let closure be OrdinaryFunctionCreate(
+                //      prototype,
+                //      sourceText,
+                //      UniqueFormalParameters,
+                //      FunctionBody,
+                //      non-lexical-this,
+                //      env,
+                //      privateEnv
+                //  ).
+                let closure = ordinary_function_create(agent, params, gc.nogc());
+                // Perform MakeMethod(closure, object).
+                make_method(agent, closure, object);
+                // Perform SetFunctionName(closure, propKey).
+                set_function_name(agent, closure, description.into(), None, gc.nogc());
+                // The Private Name was created by CreatePrivateName using the
+                // description without the leading '#'.
+                let private_name =
+                    private_env.resolve_private_identifier(agent, &description.as_str(agent)[1..]);
+                let closure = closure.into_function();
+                // If propKey is a Private Name, then
+                // a. Return PrivateElement { [[Key]]: propKey, ... }.
+                let element = PrivateElement {
+                    key: private_name,
+                    kind: match instr.kind {
+                        Instruction::ClassDefinePrivateMethod => {
+                            PrivateElementKind::Method(closure)
+                        }
+                        Instruction::ClassDefinePrivateGetter => PrivateElementKind::Accessor {
+                            get: Some(closure),
+                            set: None,
+                        },
+                        _ => PrivateElementKind::Accessor {
+                            get: None,
+                            set: Some(closure),
+                        },
+                    },
+                };
+                // ii. If IsStatic of e is false, let container be
+                //     instancePrivateMethods.
+                // iii. Else, let container be staticPrivateMethods.
+                if is_static {
+                    // Note: F is a newly created class constructor, so we
+                    // can perform PrivateMethodOrAccessorAdd(F, method)
+                    // immediately without observable differences.
+                    let holder = object.private_elements_holder(agent, true).unwrap();
+                    push_private_method(
+                        agent.heap.private_elements.entry(holder).or_default(),
+                        element,
+                    );
+                } else {
+                    private_env.add_instance_private_method(agent, element);
+                }
+            }
+            Instruction::ClassDefinePrivateField => {
+                let value = vm.result.take().unwrap().bind(gc.nogc());
+                let object = Object::try_from(vm.stack.pop().unwrap())
+                    .unwrap()
+                    .bind(gc.nogc());
+                let description =
+                    executable.fetch_identifier(agent, instr.get_first_index(), gc.nogc());
+                let private_name = agent
+                    .current_private_environment(gc.nogc())
+                    .unwrap()
+                    .resolve_private_identifier(agent, description.as_str(agent));
+                // 1. Let fieldName be fieldRecord.[[Name]].
+                // 2. Let initializer be fieldRecord.[[Initializer]].
+                // 3. If initializer is not empty, then
+                // a. Let initValue be ? Call(initializer, receiver).
+                // 4. Else,
+                // a. Let initValue be undefined.
+                // 5. If fieldName is a Private Name, then
+                // a. Perform ? PrivateFieldAdd(receiver, fieldName, initValue).
+                private_field_add(agent, object, private_name, value, gc.nogc()).unbind()?;
+            }
+            Instruction::ClassInitializePrivateMethods => {
+                let object = Object::try_from(vm.result.take().unwrap())
+                    .unwrap()
+                    .bind(gc.nogc());
+                let private_env = agent.current_private_environment(gc.nogc()).unwrap();
+                // 1. Let methods be the value of constructor.[[PrivateMethods]].
+                // 2. For each PrivateElement method of methods, do
+                for index in 0..agent[private_env].instance_private_methods.len() {
+                    let method = agent[private_env].instance_private_methods[index];
+                    // a. Perform ? PrivateMethodOrAccessorAdd(O, method).
+                    private_method_or_accessor_add(agent, object, method, gc.nogc()).unbind()?;
+                }
+            }
+            Instruction::CreatePrivateName => {
+                let description =
+                    executable.fetch_identifier(agent, instr.get_first_index(), gc.nogc());
+                let private_env = agent.current_private_environment(gc.nogc()).unwrap();
+                let description = description.as_str(agent).to_string();
+                private_env.add_private_name(agent, &description);
+            }
             Instruction::ObjectSetPrototype => {
                 let prop_value = vm.result.take().unwrap().bind(gc.nogc());
                 // i. Perform ! object.[[SetPrototypeOf]](propValue).
@@ -965,6 +1265,17 @@ impl Vm {
 
                 vm.result = Some(result.unbind());
             }
+            Instruction::GetTemplateObject => {
+                let template_literal =
+                    executable.fetch_template_literal(agent, instr.get_first_index());
+                let template = with_vm_gc(
+                    agent,
+                    vm,
+                    |agent, gc| get_template_object(agent, template_literal, gc),
+                    gc,
+                );
+                vm.result = Some(template.into_value().unbind());
+            }
             Instruction::Typeof => {
                 // 2. If val is a Reference Record, then
                 let val = if let Some(reference) = vm.reference.take() {
@@ -1278,10 +1589,12 @@ impl Vm {
                 let FunctionExpression {
                     expression,
                     compiled_bytecode,
+                    compiled_initializer_bytecode,
                     ..
                 } = executable.fetch_function_expression(agent, instr.get_first_index(), gc.nogc());
                 let function_expression = expression.get();
                 let compiled_bytecode = *compiled_bytecode;
+                let compiled_initializer_bytecode = *compiled_initializer_bytecode;
                 let has_constructor_parent = instr.get_second_bool();
 
                 let class_name = String::try_from(vm.stack.pop().unwrap()).unwrap();
@@ -1315,6 +1628,8 @@ impl Vm {
                 if let Some(compiled_bytecode) = compiled_bytecode {
                     agent[function].compiled_bytecode = Some(compiled_bytecode.unbind());
                 }
+                agent[function].compiled_initializer_bytecode =
+                    compiled_initializer_bytecode.unbind();
                 set_function_name(agent, function, class_name.into(), None, gc.nogc());
                 make_constructor(agent, function, Some(false), Some(proto), gc.nogc());
                 agent[function].ecmascript_function.home_object = Some(proto.into_object());
@@ -1635,12 +1950,26 @@ impl Vm {
                     .bind(gc.nogc());
                 // 9. Let F be thisER.[[FunctionObject]].
                 // 10. Assert: F is an ECMAScript function object.
-                let Function::ECMAScriptFunction(_f) =
-                    this_er.get_function_object(agent, gc.nogc())
+                let Function::ECMAScriptFunction(f) = this_er.get_function_object(agent, gc.nogc())
                 else {
                     unreachable!();
                 };
                 // 11. Perform ? InitializeInstanceElements(result, F).
+                let result = if agent[f].compiled_initializer_bytecode.is_some() {
+                    let f = f.unbind();
+                    let o = result.unbind();
+                    let scoped_result = o.scope(agent, gc.nogc());
+                    with_vm_gc(
+                        agent,
+                        vm,
+                        |agent, gc| initialize_instance_elements(agent, o, f.into_function(), gc),
+                        gc.reborrow(),
+                    )
+                    .unbind()?;
+                    scoped_result.get(agent).bind(gc.nogc())
+                } else {
+                    result
+                };
                 // 12. Return result.
                 vm.result = Some(result.into_value().unbind());
             }
@@ -1678,6 +2007,7 @@ impl Vm {
                     referenced_name: property_key.unbind(),
                     strict,
                     this_value: None,
+                    private_name: None,
                 });
             }
             Instruction::EvaluatePropertyAccessWithIdentifierKey => {
@@ -1695,8 +2025,108 @@ impl Vm {
                     referenced_name: property_name_string.unbind().into(),
                     strict,
                     this_value: None,
+                    private_name: None,
                 });
             }
+            Instruction::MakePrivateReference => {
+                let base_value = vm.result.take().unwrap().bind(gc.nogc());
+                let private_identifier =
+                    executable.fetch_identifier(agent, instr.get_first_index(), gc.nogc());
+                // 1. Let privateEnv be the running execution context's PrivateEnvironment.
+                // 2. Assert: privateEnv is not null.
+                let private_env = agent.current_private_environment(gc.nogc()).unwrap();
+                // 3. Let privateName be ResolvePrivateIdentifier(privateEnv, privateIdentifier).
+                let private_name =
+                    private_env.resolve_private_identifier(agent, private_identifier.as_str(agent));
+                // 4. Return the Reference Record {
+                vm.reference = Some(Reference {
+                    // [[Base]]: baseValue,
+                    base: Base::Value(base_value.unbind()),
+                    // [[ReferencedName]]: privateName,
+                    referenced_name: private_identifier.unbind().into(),
+                    private_name: Some(private_name),
+                    // [[Strict]]: true,
+                    strict: true,
+                    // [[ThisValue]]: empty
+                    this_value: None,
+                });
+                // }.
+            }
+            Instruction::MakeSuperPropertyReferenceWithExpressionKey => {
+                // ### SuperProperty : super [ Expression ]
+                // 4. Let propertyNameValue be ? GetValue(propertyNameReference).
+                let property_name_value = vm.result.take().unwrap().bind(gc.nogc());
+                // 5. Let propertyKey be ? ToPropertyKey(propertyNameValue).
+                let property_key =
+                    if property_name_value.is_string() || property_name_value.is_integer() {
+                        unwrap_try(to_property_key_simple(
+                            agent,
+                            property_name_value,
+                            gc.nogc(),
+                        ))
+                    } else {
+                        let property_name_value = property_name_value.unbind();
+                        with_vm_gc(
+                            agent,
+                            vm,
+                            |agent, gc| to_property_key(agent, property_name_value, gc),
+                            gc.reborrow(),
+                        )
+                        .unbind()?
+                        .bind(gc.nogc())
+                    };
+                // 2. Let actualThis be ? env.GetThisBinding().
+                let actual_this = vm.stack.pop().unwrap().bind(gc.nogc());
+                // 6. Let strict be IsStrict(this SuperProperty).
+                let strict = agent
+                    .running_execution_context()
+                    .ecmascript_code
+                    .unwrap()
+                    .is_strict_mode;
+                // 7. Return MakeSuperPropertyReference(actualThis, propertyKey, strict).
+                vm.reference = Some(
+                    make_super_property_reference(
+                        agent,
+                        actual_this,
+                        property_key,
+                        strict,
+                        gc.nogc(),
+                    )
+                    .unbind(),
+                );
+            }
+            Instruction::MakeSuperPropertyReferenceWithIdentifierKey => {
+                // ### SuperProperty : super . IdentifierName
+                // 1. Let env be GetThisEnvironment().
+                let Environment::Function(env) = get_this_environment(agent, gc.nogc()) else {
+                    unreachable!()
+                };
+                // 2. Let actualThis be ? env.GetThisBinding().
+                let actual_this = env
+                    .get_this_binding(agent, gc.nogc())
+                    .unbind()?
+                    .bind(gc.nogc());
+                // 3. Let propertyKey be the StringValue of IdentifierName.
+                let property_key =
+                    executable.fetch_identifier(agent, instr.get_first_index(), gc.nogc());
+                // 4. Let strict be IsStrict(this SuperProperty).
+                let strict = agent
+                    .running_execution_context()
+                    .ecmascript_code
+                    .unwrap()
+                    .is_strict_mode;
+                // 5. Return MakeSuperPropertyReference(actualThis, propertyKey, strict).
+                vm.reference = Some(
+                    make_super_property_reference(
+                        agent,
+                        actual_this,
+                        property_key.into(),
+                        strict,
+                        gc.nogc(),
+                    )
+                    .unbind(),
+                );
+            }
             Instruction::Jump => {
                 let ip = instr.get_jump_slot();
                 vm.ip = ip;
@@ -1790,6 +2220,43 @@ impl Vm {
                 let result = result == Some(false);
                 vm.result = Some(result.into());
             }
+            Instruction::HasPrivateElement => {
+                let rval = vm.result.take().unwrap().bind(gc.nogc());
+                // RelationalExpression : PrivateIdentifier in ShiftExpression
+                // 1. Let privateIdentifier be the StringValue of PrivateIdentifier.
+                let private_identifier =
+                    executable.fetch_identifier(agent, instr.get_first_index(), gc.nogc());
+                // 4. If rval is not an Object, throw a TypeError exception.
+                let Ok(rval) = Object::try_from(rval) else {
+                    let rval = rval.unbind();
+                    let error_message = with_vm_gc(
+                        agent,
+                        vm,
+                        |agent, gc| {
+                            format!(
+                                "The right-hand side of an `in` expression must be an object, got '{}'.",
+                                rval.string_repr(agent, gc).as_str(agent)
+                            )
+                        },
+                        gc.reborrow(),
+                    );
+                    return Err(agent.throw_exception(
+                        ExceptionType::TypeError,
+                        error_message,
+                        gc.into_nogc(),
+                    ));
+                };
+                // 5. Let privateEnv be the running execution context's PrivateEnvironment.
+                // 6. Assert: privateEnv is not null.
+                let private_env = agent.current_private_environment(gc.nogc()).unwrap();
+                // 7. Let privateName be ResolvePrivateIdentifier(privateEnv, privateIdentifier).
+                let private_name =
+                    private_env.resolve_private_identifier(agent, private_identifier.as_str(agent));
+                // 8. If PrivateElementFind(rval, privateName) is not empty, return true.
+                // 9. Return false.
+                let result = private_element_find(agent, rval, private_name, gc.nogc()).is_some();
+                vm.result = Some(result.into());
+            }
             Instruction::HasProperty => {
                 let lval = vm.stack.pop().unwrap().bind(gc.nogc());
                 let rval = vm.result.take().unwrap().bind(gc.nogc());
@@ -1954,6 +2421,15 @@ impl Vm {
                 let new_env = new_declarative_environment(agent, Some(outer_env), gc.nogc());
                 agent.set_current_lexical_environment(new_env.into());
             }
+            Instruction::EnterObjectEnvironment => {
+                let binding_object = Object::try_from(vm.result.take().unwrap())
+                    .unwrap()
+                    .bind(gc.nogc());
+                let outer_env = agent.current_lexical_environment(gc.nogc());
+                let new_env =
+                    new_object_environment(agent, binding_object, true, Some(outer_env), gc.nogc());
+                agent.set_current_lexical_environment(new_env.into());
+            }
             Instruction::EnterClassStaticElementEnvironment => {
                 let class_constructor = Function::try_from(*vm.stack.last().unwrap())
                     .unwrap()
@@ -1979,6 +2455,16 @@ impl Vm {
                     .unwrap();
                 agent.set_current_variable_environment(old_env);
             }
+            Instruction::EnterPrivateEnvironment => {
+                let outer_private_env = agent.current_private_environment(gc.nogc());
+                let new_private_env = new_private_environment(agent, outer_private_env, gc.nogc());
+                agent.set_current_private_environment(Some(new_private_env));
+            }
+            Instruction::ExitPrivateEnvironment => {
+                let private_env = agent.current_private_environment(gc.nogc()).unwrap();
+                let outer_private_env = agent[private_env].outer_private_environment;
+                agent.set_current_private_environment(outer_private_env);
+            }
             Instruction::CreateMutableBinding => {
                 let lex_env = agent.current_lexical_environment(gc.nogc());
                 let name = executable.fetch_identifier(agent, instr.get_first_index(), gc.nogc());
@@ -2018,10 +2504,16 @@ impl Vm {
                 vm.exception_jump_target_stack.push(ExceptionJumpTarget {
                     ip: instr.get_jump_slot(),
                     lexical_environment: agent.current_lexical_environment(gc.nogc()).unbind(),
+                    private_environment: agent.current_private_environment(gc.nogc()).unbind(),
                 });
             }
             Instruction::PopExceptionJumpTarget => {
-                vm.exception_jump_target_stack.pop().unwrap();
+                // Note: Jumps out of a try block or catch clause (break,
+                // continue, return) may have exited inner environments
+                // already; restore the environments of the try statement.
+                let ejt = vm.exception_jump_target_stack.pop().unwrap();
+                agent.set_current_lexical_environment(ejt.lexical_environment);
+                agent.set_current_private_environment(ejt.private_environment);
             }
             Instruction::InstanceofOperator => {
                 let lval = vm.stack.pop().unwrap();
@@ -2262,7 +2754,19 @@ impl Vm {
                 vm.iterator_stack.push(result);
             }
             Instruction::GetIteratorAsync => {
-                todo!();
+                let expr_value = vm.result.take().unwrap();
+                let result = with_vm_gc(
+                    agent,
+                    vm,
+                    |agent, gc| get_iterator(agent, expr_value, true, gc),
+                    gc,
+                )?;
+                vm.iterator_stack.push(match result {
+                    Some(iterator_record) => {
+                        VmIteratorRecord::GenericIterator(iterator_record.unbind())
+                    }
+                    None => VmIteratorRecord::InvalidIterator,
+                });
             }
             Instruction::IteratorStepValue => {
                 let result = with_vm_gc(
@@ -2286,6 +2790,95 @@ impl Vm {
                     result?;
                 }
             }
+            Instruction::IteratorCallNextMethod | Instruction::IteratorCallNextMethodWithValue => {
+                let value = if instr.kind == Instruction::IteratorCallNextMethodWithValue {
+                    Some(vm.result.take().unwrap())
+                } else {
+                    None
+                };
+                let result = with_vm_gc(
+                    agent,
+                    vm,
+                    |agent, gc| ActiveIterator::new(agent, gc.nogc()).call_next(agent, value, gc),
+                    gc,
+                );
+                if let Ok(result) = result {
+                    vm.result = Some(result.unbind());
+                } else {
+                    // Iterator threw an error: pop the iterator from the stack
+                    // and rethrow the error.
+                    vm.iterator_stack.pop();
+                    result?;
+                }
+            }
+            Instruction::IteratorCallThrowMethod | Instruction::IteratorCallReturnMethod => {
+                let name = if instr.kind == Instruction::IteratorCallThrowMethod {
+                    BUILTIN_STRING_MEMORY.throw.into()
+                } else {
+                    BUILTIN_STRING_MEMORY.r#return.into()
+                };
+                // Note: The value is kept in the result slot while calling
+                // the method; if the method is undefined it stays there.
+                let value = vm.result.unwrap();
+                let result = with_vm_gc(
+                    agent,
+                    vm,
+                    |agent, gc| {
+                        ActiveIterator::new(agent, gc.nogc()).call_method(agent, name, value, gc)
+                    },
+                    gc,
+                );
+                match result {
+                    Ok(Some(result)) => vm.result = Some(result.unbind()),
+                    Ok(None) => {
+                        if instr.kind == Instruction::IteratorCallReturnMethod {
+                            vm.iterator_stack.pop();
+                        }
+                        vm.ip = instr.get_jump_slot();
+                    }
+                    Err(err) => {
+                        // Iterator threw an error: pop the iterator from the
+                        // stack and rethrow the error.
+                        vm.iterator_stack.pop();
+                        return Err(err);
+                    }
+                }
+            }
+            Instruction::IteratorComplete => {
+                let Ok(result) = Object::try_from(vm.result.unwrap()) else {
+                    vm.iterator_stack.pop();
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "Iterator returned a non-object result",
+                        gc.into_nogc(),
+                    ));
+                };
+                let done = with_vm_gc(
+                    agent,
+                    vm,
+                    |agent, gc| iterator_complete(agent, result, gc),
+                    gc,
+                );
+                match done {
+                    Ok(true) => {
+                        vm.iterator_stack.pop();
+                        vm.ip = instr.get_jump_slot();
+                    }
+                    Ok(false) => {}
+                    Err(err) => {
+                        vm.iterator_stack.pop();
+                        return Err(err);
+                    }
+                }
+            }
+            Instruction::IteratorValue => {
+                let Ok(result) = Object::try_from(vm.result.take().unwrap()) else {
+                    unreachable!()
+                };
+                let value =
+                    with_vm_gc(agent, vm, |agent, gc| iterator_value(agent, result, gc), gc)?;
+                vm.result = Some(value.unbind());
+            }
             Instruction::IteratorStepValueOrUndefined => {
                 let result = with_vm_gc(
                     agent,
@@ -2362,7 +2955,69 @@ impl Vm {
                     )?;
                 }
             }
+            Instruction::AsyncIteratorClose => {
+                let iterator = vm.iterator_stack.pop().unwrap();
+                let VmIteratorRecord::GenericIterator(iterator_record) = iterator else {
+                    // Only async iterators are closed asynchronously.
+                    vm.ip = instr.get_jump_slot();
+                    return Ok(ContinuationKind::Normal);
+                };
+                let result = with_vm_gc::<JsResult<Option<Value>>>(
+                    agent,
+                    vm,
+                    |agent, mut gc| {
+                        // 1. Assert: iteratorRecord.[[Iterator]] is an Object.
+                        // 2. Let iterator be iteratorRecord.[[Iterator]].
+                        let iterator = iterator_record.iterator.bind(gc.nogc());
+                        let scoped_iterator = iterator.scope(agent, gc.nogc());
+                        // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+                        // 4. If innerResult is a normal completion, then
+                        //    a. Let return be innerResult.[[Value]].
+                        let Some(r#return) = get_method(
+                            agent,
+                            iterator.into_value().unbind(),
+                            BUILTIN_STRING_MEMORY.r#return.into(),
+                            gc.reborrow(),
+                        )
+                        .unbind()?
+                        .bind(gc.nogc()) else {
+                            //    b. If return is undefined, return ? completion.
+                            return Ok(None);
+                        };
+                        //    c. Set innerResult to Completion(Call(return, iterator)).
+                        // Note: The following steps are performed by the
+                        // instructions following this one.
+                        //    d. If innerResult is a normal completion, set
+                        //       innerResult to Completion(Await(innerResult.[[Value]])).
+                        // 5. If completion is a throw completion, return ? completion.
+                        // 6. If innerResult is a throw completion, return ? innerResult.
+                        // 7. If innerResult.[[Value]] is not an Object, throw a TypeError exception.
+                        // 8. Return ? completion.
+                        call_function(
+                            agent,
+                            r#return.unbind(),
+                            scoped_iterator.get(agent).into_value(),
+                            None,
+                            gc,
+                        )
+                        .map(Some)
+                    },
+                    gc,
+                )?;
+                if let Some(result) = result {
+                    vm.result = Some(result.unbind());
+                } else {
+                    vm.ip = instr.get_jump_slot();
+                }
+            }
             Instruction::Yield => return Ok(ContinuationKind::Yield),
+            Instruction::YieldReturnTarget => {
+                // Only relevant when resuming with a return completion.
+            }
+            Instruction::YieldDelegate => return Ok(ContinuationKind::YieldDelegate),
+            Instruction::YieldThrowTarget => {
+                // Only relevant when resuming with a throw completion.
+            }
             Instruction::CreateUnmappedArgumentsObject => {
                 let Some(VmIteratorRecord::SliceIterator(slice)) = vm.iterator_stack.last() else {
                     unreachable!()
@@ -2389,6 +3044,133 @@ impl Vm {
                         .unbind(),
                 );
             }
+            Instruction::ImportCall => {
+                let options = vm.result.take().unwrap();
+                let specifier = vm.stack.pop().unwrap();
+                let result = with_vm_gc(
+                    agent,
+                    vm,
+                    |agent, gc| evaluate_import_call(agent, specifier, options, gc),
+                    gc,
+                );
+                vm.result = Some(result.into_value().unbind());
+            }
+            Instruction::ImportMeta => {
+                let gc = gc.into_nogc();
+                // 1. Let module be GetActiveScriptOrModule().
+                // 2. Assert: module is a Source Text Module Record.
+                let Some(ScriptOrModule::SourceTextModule(module)) =
+                    get_active_script_or_module(agent, gc)
+                else {
+                    unreachable!()
+                };
+                // 3. Let importMeta be module.[[ImportMeta]].
+                // 4. If importMeta is EMPTY, then
+                let import_meta = if let Some(import_meta) = agent[module].import_meta {
+                    import_meta.bind(gc)
+                } else {
+                    // a. Set importMeta to OrdinaryObjectCreate(null).
+                    let Object::Object(import_meta) =
+                        ordinary_object_create_with_intrinsics(agent, None, None, gc)
+                    else {
+                        unreachable!()
+                    };
+                    // b. Let importMetaValues be
+                    //    HostGetImportMetaProperties(module).
+                    let host_hooks = agent.host_hooks;
+                    let import_meta_values =
+                        host_hooks.get_import_meta_properties(agent, module, gc);
+                    // c. For each Record { [[Key]], [[Value]] } p of
+                    //    importMetaValues, do
+                    for (key, value) in import_meta_values {
+                        // i. Perform ! CreateDataPropertyOrThrow(importMeta,
+                        //    p.[[Key]], p.[[Value]]).
+                        unwrap_try(try_create_data_property_or_throw(
+                            agent,
+                            import_meta,
+                            key,
+                            value,
+                            gc,
+                        ))
+                        .unwrap();
+                    }
+                    // d. Perform HostFinalizeImportMeta(importMeta, module).
+                    host_hooks.finalize_import_meta(agent, import_meta, module, gc);
+                    // e. Set module.[[ImportMeta]] to importMeta.
+                    agent[module].import_meta = Some(import_meta.unbind());
+                    import_meta
+                };
+                // f. Return importMeta.
+                // 5. Else,
+                // a. Assert: importMeta is an Object.
+                // b. Return importMeta.
+                vm.result = Some(import_meta.into_value().unbind());
+            }
+            #[cfg(feature = "regexp")]
+            Instruction::InstantiateRegExpLiteral => {
+                let Value::RegExp(template) = vm.result.take().unwrap().bind(gc.nogc()) else {
+                    unreachable!()
+                };
+                let regexp = reg_exp_instantiate_literal(agent, template, gc.nogc());
+                vm.result = Some(regexp.into_value().unbind());
+            }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Instruction::EnterDisposableScope | Instruction::EnterAsyncDisposableScope => {
+                // Let blockEnv.[[DisposeCapability]] be NewDisposeCapability().
+                let is_async = instr.kind == Instruction::EnterAsyncDisposableScope;
+                let dispose_capability: DisposableStack = agent
+                    .heap
+                    .create(DisposableStackHeapData::new(is_async))
+                    .bind(gc.nogc());
+                vm.disposable_stack.push(dispose_capability.unbind());
+            }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Instruction::AddDisposableResource | Instruction::AddAsyncDisposableResource => {
+                // Note: The value is kept as the result value for the
+                // binding initialization that follows.
+                let value = vm.result.unwrap();
+                let dispose_capability = *vm.disposable_stack.last().unwrap();
+                let hint = if instr.kind == Instruction::AddAsyncDisposableResource {
+                    DisposeHint::AsyncDispose
+                } else {
+                    DisposeHint::SyncDispose
+                };
+                with_vm_gc(
+                    agent,
+                    vm,
+                    |agent, gc| {
+                        add_disposable_resource(agent, dispose_capability, value, hint, None, gc)
+                    },
+                    gc,
+                )?;
+            }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Instruction::DisposeResources | Instruction::DisposeResourcesWithError => {
+                let completion = if instr.kind == Instruction::DisposeResourcesWithError {
+                    Some(JsError::new(vm.result.take().unwrap()))
+                } else {
+                    None
+                };
+                let dispose_capability = vm.disposable_stack.pop().unwrap();
+                if dispose_capability.is_async(agent) {
+                    let promise = with_vm_gc(
+                        agent,
+                        vm,
+                        |agent, gc| {
+                            dispose_resources_async(agent, dispose_capability, completion, gc)
+                        },
+                        gc,
+                    );
+                    vm.result = Some(promise.into_value().unbind());
+                } else {
+                    with_vm_gc(
+                        agent,
+                        vm,
+                        |agent, gc| dispose_resources(agent, dispose_capability, completion, gc),
+                        gc,
+                    )?;
+                }
+            }
             other => todo!("{other:?}"),
         }
 
@@ -2703,7 +3485,7 @@ fn typeof_operator(agent: &Agent, val: Value, gc: NoGcScope) -> String<'static>
         Value::FinalizationRegistry(_) |
         Value::Map(_) |
         Value::Promise(_) |
-        Value::AsyncFromSyncIterator |
+        Value::AsyncFromSyncIterator(_) |
         Value::AsyncGenerator(_) |
         Value::ArrayIterator(_) |
         Value::MapIterator(_) |
@@ -2712,7 +3494,8 @@ fn typeof_operator(agent: &Agent, val: Value, gc: NoGcScope) -> String<'static>
         Value::Module(_) |
         Value::EmbedderObject(_) => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "regexp")]
-        Value::RegExp(_) => BUILTIN_STRING_MEMORY.object,
+        Value::RegExp(_) |
+        Value::RegExpStringIterator(_) => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "weak-refs")]
         Value::WeakMap(_) |
         Value::WeakRef(_) |
@@ -2740,12 +3523,14 @@ fn typeof_operator(agent: &Agent, val: Value, gc: NoGcScope) -> String<'static>
         Value::Float16Array(_) => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "date")]
         Value::Date(_)  => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        Value::DisposableStack(_) => BUILTIN_STRING_MEMORY.object,
         // 13. If val has a [[Call]] internal slot, return "function".
         Value::BoundFunction(_) | Value::BuiltinFunction(_) | Value::ECMAScriptFunction(_) |
         Value::BuiltinGeneratorFunction |
         Value::BuiltinConstructorFunction(_) |
         Value::BuiltinPromiseResolvingFunction(_) |
-        Value::BuiltinPromiseCollectorFunction |
+        Value::BuiltinPromiseCollectorFunction(_) |
         Value::BuiltinProxyRevokerFunction => BUILTIN_STRING_MEMORY.function,
         Value::Proxy(proxy) => {
             if proxy.is_callable(agent, gc) {
@@ -2853,16 +3638,20 @@ impl HeapMarkAndSweep for ExceptionJumpTarget<'static> {
         let Self {
             ip: _,
             lexical_environment,
+            private_environment,
         } = self;
         lexical_environment.mark_values(queues);
+        private_environment.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Self {
             ip: _,
             lexical_environment,
+            private_environment,
         } = self;
         lexical_environment.sweep_values(compactions);
+        private_environment.sweep_values(compactions);
     }
 }
 
@@ -2874,6 +3663,8 @@ impl HeapMarkAndSweep for Vm {
             reference_stack,
             iterator_stack,
             exception_jump_target_stack,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stack,
             result,
             reference,
         } = self;
@@ -2881,6 +3672,8 @@ impl HeapMarkAndSweep for Vm {
         reference_stack.as_slice().mark_values(queues);
         iterator_stack.as_slice().mark_values(queues);
         exception_jump_target_stack.as_slice().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        disposable_stack.as_slice().mark_values(queues);
         result.mark_values(queues);
         reference.mark_values(queues);
     }
@@ -2892,6 +3685,8 @@ impl HeapMarkAndSweep for Vm {
             reference_stack,
             iterator_stack,
             exception_jump_target_stack,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stack,
             result,
             reference,
         } = self;
@@ -2901,6 +3696,8 @@ impl HeapMarkAndSweep for Vm {
         exception_jump_target_stack
             .as_mut_slice()
             .sweep_values(compactions);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        disposable_stack.as_mut_slice().sweep_values(compactions);
         result.sweep_values(compactions);
         reference.sweep_values(compactions);
     }
@@ -2914,11 +3711,15 @@ impl HeapMarkAndSweep for SuspendedVm {
             reference_stack,
             iterator_stack,
             exception_jump_target_stack,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stack,
         } = self;
         stack.mark_values(queues);
         reference_stack.mark_values(queues);
         iterator_stack.mark_values(queues);
         exception_jump_target_stack.mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        disposable_stack.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -2928,10 +3729,14 @@ impl HeapMarkAndSweep for SuspendedVm {
             reference_stack,
             iterator_stack,
             exception_jump_target_stack,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stack,
         } = self;
         stack.sweep_values(compactions);
         reference_stack.sweep_values(compactions);
         iterator_stack.sweep_values(compactions);
         exception_jump_target_stack.sweep_values(compactions);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        disposable_stack.sweep_values(compactions);
     }
 }
@@ -9,20 +9,24 @@ pub(crate) use private::{HeapRootCollectionData, RootableCollectionSealed, Roota
 
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "regexp")]
+use crate::ecmascript::builtins::text_processing::regexp_objects::regexp_string_iterator_objects::RegExpStringIterator;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{ArrayBuffer, data_view::DataView};
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::builtins::{weak_map::WeakMap, weak_ref::WeakRef, weak_set::WeakSet};
 #[cfg(feature = "date")]
 use crate::ecmascript::types::DATE_DISCRIMINANT;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::types::DISPOSABLE_STACK_DISCRIMINANT;
 #[cfg(feature = "proposal-float16array")]
 use crate::ecmascript::types::FLOAT_16_ARRAY_DISCRIMINANT;
-#[cfg(feature = "regexp")]
-use crate::ecmascript::types::REGEXP_DISCRIMINANT;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::types::SHARED_ARRAY_BUFFER_DISCRIMINANT;
 #[cfg(feature = "array-buffer")]
@@ -33,6 +37,8 @@ use crate::ecmascript::types::{
     UINT_8_ARRAY_DISCRIMINANT, UINT_8_CLAMPED_ARRAY_DISCRIMINANT, UINT_16_ARRAY_DISCRIMINANT,
     UINT_32_ARRAY_DISCRIMINANT,
 };
+#[cfg(feature = "regexp")]
+use crate::ecmascript::types::{REGEXP_DISCRIMINANT, REGEXP_STRING_ITERATOR_DISCRIMINANT};
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::types::{
     WEAK_MAP_DISCRIMINANT, WEAK_REF_DISCRIMINANT, WEAK_SET_DISCRIMINANT,
@@ -53,6 +59,7 @@ use crate::{
             Array, BuiltinConstructorFunction, BuiltinFunction, ECMAScriptFunction,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
+            control_abstraction_objects::iteration::async_from_sync_iterator_objects::AsyncFromSyncIterator,
             embedder_object::EmbedderObject,
             error::Error,
             finalization_registry::FinalizationRegistry,
@@ -64,7 +71,8 @@ use crate::{
             primitive_objects::PrimitiveObject,
             promise::Promise,
             promise_objects::promise_abstract_operations::{
-                promise_reaction_records::PromiseReaction,
+                promise_collector_functions::BuiltinPromiseCollectorFunction,
+                promise_group_records::PromiseGroup, promise_reaction_records::PromiseReaction,
                 promise_resolving_functions::BuiltinPromiseResolvingFunction,
             },
             proxy::Proxy,
@@ -74,7 +82,14 @@ use crate::{
             DeclarativeEnvironment, FunctionEnvironment, GlobalEnvironment, ModuleEnvironment,
             ObjectEnvironment, PrivateEnvironment, Realm,
         },
-        scripts_and_modules::{script::Script, source_code::SourceCode},
+        scripts_and_modules::{
+            module::module_semantics::{
+                GraphLoadingState, source_text_module_records::SourceTextModule,
+                synthetic_module_records::SyntheticModule,
+            },
+            script::Script,
+            source_code::SourceCode,
+        },
         types::{
             ARGUMENTS_DISCRIMINANT, ARRAY_DISCRIMINANT, ARRAY_ITERATOR_DISCRIMINANT,
             ASYNC_FROM_SYNC_ITERATOR_DISCRIMINANT, ASYNC_GENERATOR_DISCRIMINANT,
@@ -99,12 +114,18 @@ pub mod private {
 
     #[cfg(feature = "date")]
     use crate::ecmascript::builtins::date::Date;
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    use crate::ecmascript::builtins::disposable_stack::DisposableStack;
     #[cfg(feature = "regexp")]
     use crate::ecmascript::builtins::regexp::RegExp;
+    #[cfg(feature = "regexp")]
+    use crate::ecmascript::builtins::text_processing::regexp_objects::regexp_string_iterator_objects::RegExpStringIterator;
     #[cfg(feature = "shared-array-buffer")]
     use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
     #[cfg(feature = "array-buffer")]
-    use crate::ecmascript::builtins::{ArrayBuffer, data_view::DataView, typed_array::TypedArray};
+    use crate::ecmascript::builtins::{
+        ArrayBuffer, array_buffer::AnyArrayBuffer, data_view::DataView, typed_array::TypedArray,
+    };
     #[cfg(feature = "set")]
     use crate::ecmascript::builtins::{
         keyed_collections::set_objects::set_iterator_objects::set_iterator::SetIterator, set::Set,
@@ -119,6 +140,7 @@ pub mod private {
                 ECMAScriptFunction,
                 async_generator_objects::AsyncGenerator,
                 bound_function::BoundFunction,
+                control_abstraction_objects::iteration::async_from_sync_iterator_objects::AsyncFromSyncIterator,
                 embedder_object::EmbedderObject,
                 error::Error,
                 finalization_registry::FinalizationRegistry,
@@ -130,6 +152,8 @@ pub mod private {
                 primitive_objects::PrimitiveObject,
                 promise::Promise,
                 promise_objects::promise_abstract_operations::{
+                    promise_collector_functions::BuiltinPromiseCollectorFunction,
+                    promise_group_records::PromiseGroup,
                     promise_reaction_records::PromiseReaction,
                     promise_resolving_functions::BuiltinPromiseResolvingFunction,
                 },
@@ -139,7 +163,16 @@ pub mod private {
                 DeclarativeEnvironment, Environment, FunctionEnvironment, GlobalEnvironment,
                 ModuleEnvironment, ObjectEnvironment, PrivateEnvironment, Realm, agent::JsError,
             },
-            scripts_and_modules::{script::Script, source_code::SourceCode},
+            scripts_and_modules::{
+                module::module_semantics::{
+                    GraphLoadingState, ModuleLoadingPayload, Referrer,
+                    abstract_module_records::AbstractModule,
+                    source_text_module_records::SourceTextModule,
+                    synthetic_module_records::SyntheticModule,
+                },
+                script::Script,
+                source_code::SourceCode,
+            },
             types::{
                 BigInt, Function, Number, Numeric, Object, OrdinaryObject, Primitive, PropertyKey,
                 PropertyKeySet, String, Symbol, Value,
@@ -151,20 +184,27 @@ pub mod private {
 
     /// Marker trait to make Rootable not implementable outside of nova_vm.
     pub trait RootableSealed {}
+    impl RootableSealed for AbstractModule<'_> {}
     impl RootableSealed for Array<'_> {}
     #[cfg(feature = "array-buffer")]
+    impl RootableSealed for AnyArrayBuffer<'_> {}
+    #[cfg(feature = "array-buffer")]
     impl RootableSealed for ArrayBuffer<'_> {}
     impl RootableSealed for ArrayIterator<'_> {}
+    impl RootableSealed for AsyncFromSyncIterator<'_> {}
     impl RootableSealed for AsyncGenerator<'_> {}
     impl RootableSealed for BigInt<'_> {}
     impl RootableSealed for BoundFunction<'_> {}
     impl RootableSealed for BuiltinConstructorFunction<'_> {}
     impl RootableSealed for BuiltinFunction<'_> {}
+    impl RootableSealed for BuiltinPromiseCollectorFunction<'_> {}
     impl RootableSealed for BuiltinPromiseResolvingFunction<'_> {}
     #[cfg(feature = "array-buffer")]
     impl RootableSealed for DataView<'_> {}
     #[cfg(feature = "date")]
     impl RootableSealed for Date<'_> {}
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    impl RootableSealed for DisposableStack<'_> {}
     impl RootableSealed for ECMAScriptFunction<'_> {}
     impl RootableSealed for EmbedderObject<'_> {}
     impl RootableSealed for Error<'_> {}
@@ -172,8 +212,10 @@ pub mod private {
     impl RootableSealed for FinalizationRegistry<'_> {}
     impl RootableSealed for Function<'_> {}
     impl RootableSealed for Generator<'_> {}
+    impl RootableSealed for GraphLoadingState<'_> {}
     impl RootableSealed for Map<'_> {}
     impl RootableSealed for MapIterator<'_> {}
+    impl RootableSealed for ModuleLoadingPayload<'_> {}
     impl RootableSealed for Module<'_> {}
     impl RootableSealed for Number<'_> {}
     impl RootableSealed for Numeric<'_> {}
@@ -182,13 +224,19 @@ pub mod private {
     impl RootableSealed for Primitive<'_> {}
     impl RootableSealed for PrimitiveObject<'_> {}
     impl RootableSealed for Promise<'_> {}
+    impl RootableSealed for PromiseGroup<'_> {}
     impl RootableSealed for PromiseReaction<'_> {}
     impl RootableSealed for PropertyKey<'_> {}
     impl RootableSealed for Proxy<'_> {}
     impl RootableSealed for Realm<'_> {}
+    impl RootableSealed for Referrer<'_> {}
     #[cfg(feature = "regexp")]
     impl RootableSealed for RegExp<'_> {}
+    #[cfg(feature = "regexp")]
+    impl RootableSealed for RegExpStringIterator<'_> {}
     impl RootableSealed for Script<'_> {}
+    impl RootableSealed for SourceTextModule<'_> {}
+    impl RootableSealed for SyntheticModule<'_> {}
     #[cfg(feature = "set")]
     impl RootableSealed for Set<'_> {}
     #[cfg(feature = "set")]
@@ -436,7 +484,8 @@ pub enum HeapRootData {
         BUILTIN_CONSTRUCTOR_FUNCTION_DISCRIMINANT,
     BuiltinPromiseResolvingFunction(BuiltinPromiseResolvingFunction<'static>) =
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
-    BuiltinPromiseCollectorFunction = BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
+    BuiltinPromiseCollectorFunction(BuiltinPromiseCollectorFunction<'static>) =
+        BUILTIN_PROMISE_COLLECTOR_FUNCTION_DISCRIMINANT,
     BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
     PrimitiveObject(PrimitiveObject<'static>),
     Arguments(OrdinaryObject<'static>) = ARGUMENTS_DISCRIMINANT,
@@ -447,6 +496,8 @@ pub enum HeapRootData {
     DataView(DataView<'static>) = DATA_VIEW_DISCRIMINANT,
     #[cfg(feature = "date")]
     Date(Date<'static>) = DATE_DISCRIMINANT,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack(DisposableStack<'static>) = DISPOSABLE_STACK_DISCRIMINANT,
     Error(Error<'static>) = ERROR_DISCRIMINANT,
     FinalizationRegistry(FinalizationRegistry<'static>) = FINALIZATION_REGISTRY_DISCRIMINANT,
     Map(Map<'static>) = MAP_DISCRIMINANT,
@@ -488,7 +539,7 @@ pub enum HeapRootData {
     Float32Array(TypedArrayIndex<'static>) = FLOAT_32_ARRAY_DISCRIMINANT,
     #[cfg(feature = "array-buffer")]
     Float64Array(TypedArrayIndex<'static>) = FLOAT_64_ARRAY_DISCRIMINANT,
-    AsyncFromSyncIterator = ASYNC_FROM_SYNC_ITERATOR_DISCRIMINANT,
+    AsyncFromSyncIterator(AsyncFromSyncIterator<'static>) = ASYNC_FROM_SYNC_ITERATOR_DISCRIMINANT,
     AsyncGenerator(AsyncGenerator<'static>) = ASYNC_GENERATOR_DISCRIMINANT,
     ArrayIterator(ArrayIterator<'static>) = ARRAY_ITERATOR_DISCRIMINANT,
     #[cfg(feature = "set")]
@@ -496,6 +547,8 @@ pub enum HeapRootData {
     MapIterator(MapIterator<'static>) = MAP_ITERATOR_DISCRIMINANT,
     Generator(Generator<'static>) = GENERATOR_DISCRIMINANT,
     StringIterator(StringIterator<'static>) = STRING_ITERATOR_DISCRIMINANT,
+    #[cfg(feature = "regexp")]
+    RegExpStringIterator(RegExpStringIterator<'static>) = REGEXP_STRING_ITERATOR_DISCRIMINANT,
     Module(Module<'static>) = MODULE_DISCRIMINANT,
     EmbedderObject(EmbedderObject<'static>) = EMBEDDER_OBJECT_DISCRIMINANT,
     // Non-Value types go here. If the 128 variants here are not enough, we can
@@ -506,9 +559,13 @@ pub enum HeapRootData {
     // The order here shouldn't be important at all, feel free to eg. keep
     // these in alphabetical order.
     Executable(Executable<'static>),
+    GraphLoadingState(GraphLoadingState<'static>),
+    PromiseGroup(PromiseGroup<'static>),
     PromiseReaction(PromiseReaction<'static>),
     Realm(Realm<'static>),
     Script(Script<'static>),
+    SourceTextModule(SourceTextModule<'static>),
+    SyntheticModule(SyntheticModule<'static>),
     SourceCode(SourceCode<'static>),
     DeclarativeEnvironment(DeclarativeEnvironment<'static>),
     FunctionEnvironment(FunctionEnvironment<'static>),
@@ -535,7 +592,9 @@ impl From<Object<'static>> for HeapRootData {
             Object::BuiltinPromiseResolvingFunction(builtin_promise_resolving_function) => {
                 Self::BuiltinPromiseResolvingFunction(builtin_promise_resolving_function)
             }
-            Object::BuiltinPromiseCollectorFunction => Self::BuiltinPromiseCollectorFunction,
+            Object::BuiltinPromiseCollectorFunction(builtin_promise_collector_function) => {
+                Self::BuiltinPromiseCollectorFunction(builtin_promise_collector_function)
+            }
             Object::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
             Object::PrimitiveObject(primitive_object) => Self::PrimitiveObject(primitive_object),
             Object::Arguments(ordinary_object) => Self::Arguments(ordinary_object),
@@ -543,6 +602,8 @@ impl From<Object<'static>> for HeapRootData {
             Object::ArrayBuffer(array_buffer) => Self::ArrayBuffer(array_buffer),
             Object::DataView(data_view) => Self::DataView(data_view),
             Object::Date(date) => Self::Date(date),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(disposable_stack) => Self::DisposableStack(disposable_stack),
             Object::Error(error) => Self::Error(error),
             Object::FinalizationRegistry(finalization_registry) => {
                 Self::FinalizationRegistry(finalization_registry)
@@ -572,13 +633,15 @@ impl From<Object<'static>> for HeapRootData {
             Object::Float16Array(base_index) => Self::Float16Array(base_index),
             Object::Float32Array(base_index) => Self::Float32Array(base_index),
             Object::Float64Array(base_index) => Self::Float64Array(base_index),
-            Object::AsyncFromSyncIterator => Self::AsyncFromSyncIterator,
+            Object::AsyncFromSyncIterator(data) => Self::AsyncFromSyncIterator(data),
             Object::AsyncGenerator(r#gen) => Self::AsyncGenerator(r#gen),
             Object::ArrayIterator(array_iterator) => Self::ArrayIterator(array_iterator),
             #[cfg(feature = "set")]
             Object::SetIterator(set_iterator) => Self::SetIterator(set_iterator),
             Object::MapIterator(map_iterator) => Self::MapIterator(map_iterator),
             Object::StringIterator(generator) => Self::StringIterator(generator),
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(generator) => Self::RegExpStringIterator(generator),
             Object::Generator(generator) => Self::Generator(generator),
             Object::Module(module) => Self::Module(module),
             Object::EmbedderObject(embedder_object) => Self::EmbedderObject(embedder_object),
@@ -644,7 +707,9 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::BuiltinPromiseResolvingFunction(builtin_promise_resolving_function) => {
                 builtin_promise_resolving_function.mark_values(queues)
             }
-            HeapRootData::BuiltinPromiseCollectorFunction => todo!(),
+            HeapRootData::BuiltinPromiseCollectorFunction(builtin_promise_collector_function) => {
+                builtin_promise_collector_function.mark_values(queues)
+            }
             HeapRootData::BuiltinProxyRevokerFunction => todo!(),
             HeapRootData::PrimitiveObject(primitive_object) => primitive_object.mark_values(queues),
             HeapRootData::Arguments(ordinary_object) => ordinary_object.mark_values(queues),
@@ -655,6 +720,8 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::DataView(data_view) => data_view.mark_values(queues),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => date.mark_values(queues),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            HeapRootData::DisposableStack(disposable_stack) => disposable_stack.mark_values(queues),
             HeapRootData::Error(error) => error.mark_values(queues),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 finalization_registry.mark_values(queues)
@@ -700,7 +767,7 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::Float32Array(base_index) => base_index.mark_values(queues),
             #[cfg(feature = "array-buffer")]
             HeapRootData::Float64Array(base_index) => base_index.mark_values(queues),
-            HeapRootData::AsyncFromSyncIterator => todo!(),
+            HeapRootData::AsyncFromSyncIterator(data) => data.mark_values(queues),
             HeapRootData::AsyncGenerator(r#gen) => r#gen.mark_values(queues),
 
             HeapRootData::ArrayIterator(array_iterator) => array_iterator.mark_values(queues),
@@ -708,13 +775,21 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::SetIterator(set_iterator) => set_iterator.mark_values(queues),
             HeapRootData::MapIterator(map_iterator) => map_iterator.mark_values(queues),
             HeapRootData::StringIterator(generator) => generator.mark_values(queues),
+            #[cfg(feature = "regexp")]
+            HeapRootData::RegExpStringIterator(generator) => generator.mark_values(queues),
             HeapRootData::Generator(generator) => generator.mark_values(queues),
             HeapRootData::Module(module) => module.mark_values(queues),
             HeapRootData::EmbedderObject(embedder_object) => embedder_object.mark_values(queues),
             HeapRootData::Executable(exe) => exe.mark_values(queues),
+            HeapRootData::GraphLoadingState(state) => state.mark_values(queues),
+            HeapRootData::PromiseGroup(promise_group) => promise_group.mark_values(queues),
             HeapRootData::PromiseReaction(promise_reaction) => promise_reaction.mark_values(queues),
             HeapRootData::Realm(realm) => realm.mark_values(queues),
             HeapRootData::Script(script) => script.mark_values(queues),
+            HeapRootData::SourceTextModule(source_text_module) => {
+                source_text_module.mark_values(queues)
+            }
+            HeapRootData::SyntheticModule(synthetic_module) => synthetic_module.mark_values(queues),
             HeapRootData::SourceCode(source_code) => source_code.mark_values(queues),
             HeapRootData::DeclarativeEnvironment(declarative_environment_index) => {
                 declarative_environment_index.mark_values(queues)
@@ -759,7 +834,9 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::BuiltinPromiseResolvingFunction(builtin_promise_resolving_function) => {
                 builtin_promise_resolving_function.sweep_values(compactions)
             }
-            HeapRootData::BuiltinPromiseCollectorFunction => todo!(),
+            HeapRootData::BuiltinPromiseCollectorFunction(builtin_promise_collector_function) => {
+                builtin_promise_collector_function.sweep_values(compactions)
+            }
             HeapRootData::BuiltinProxyRevokerFunction => todo!(),
             HeapRootData::PrimitiveObject(primitive_object) => {
                 primitive_object.sweep_values(compactions)
@@ -772,6 +849,10 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::DataView(data_view) => data_view.sweep_values(compactions),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => date.sweep_values(compactions),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            HeapRootData::DisposableStack(disposable_stack) => {
+                disposable_stack.sweep_values(compactions)
+            }
             HeapRootData::Error(error) => error.sweep_values(compactions),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 finalization_registry.sweep_values(compactions)
@@ -817,24 +898,34 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::Float32Array(base_index) => base_index.sweep_values(compactions),
             #[cfg(feature = "array-buffer")]
             HeapRootData::Float64Array(base_index) => base_index.sweep_values(compactions),
-            HeapRootData::AsyncFromSyncIterator => todo!(),
+            HeapRootData::AsyncFromSyncIterator(data) => data.sweep_values(compactions),
             HeapRootData::AsyncGenerator(r#gen) => r#gen.sweep_values(compactions),
             HeapRootData::ArrayIterator(array_iterator) => array_iterator.sweep_values(compactions),
             #[cfg(feature = "set")]
             HeapRootData::SetIterator(set_iterator) => set_iterator.sweep_values(compactions),
             HeapRootData::MapIterator(map_iterator) => map_iterator.sweep_values(compactions),
             HeapRootData::StringIterator(generator) => generator.sweep_values(compactions),
+            #[cfg(feature = "regexp")]
+            HeapRootData::RegExpStringIterator(generator) => generator.sweep_values(compactions),
             HeapRootData::Generator(generator) => generator.sweep_values(compactions),
             HeapRootData::Module(module) => module.sweep_values(compactions),
             HeapRootData::EmbedderObject(embedder_object) => {
                 embedder_object.sweep_values(compactions)
             }
             HeapRootData::Executable(exe) => exe.sweep_values(compactions),
+            HeapRootData::GraphLoadingState(state) => state.sweep_values(compactions),
+            HeapRootData::PromiseGroup(promise_group) => promise_group.sweep_values(compactions),
             HeapRootData::PromiseReaction(promise_reaction) => {
                 promise_reaction.sweep_values(compactions)
             }
             HeapRootData::Realm(realm) => realm.sweep_values(compactions),
             HeapRootData::Script(script) => script.sweep_values(compactions),
+            HeapRootData::SourceTextModule(source_text_module) => {
+                source_text_module.sweep_values(compactions)
+            }
+            HeapRootData::SyntheticModule(synthetic_module) => {
+                synthetic_module.sweep_values(compactions)
+            }
             HeapRootData::SourceCode(source_code) => source_code.sweep_values(compactions),
             HeapRootData::DeclarativeEnvironment(declarative_environment_index) => {
                 declarative_environment_index.sweep_values(compactions)
@@ -31,10 +31,14 @@ use self::{
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::data::DateHeapData;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::data::DisposableStackHeapData;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExpHeapData;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::data::SharedArrayBufferHeapData;
+#[cfg(feature = "regexp")]
+use crate::ecmascript::builtins::text_processing::regexp_objects::regexp_string_iterator_objects::RegExpStringIteratorHeapData;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{
     ArrayBufferHeapData,
@@ -60,7 +64,10 @@ use crate::{
             control_abstraction_objects::{
                 async_function_objects::await_reaction::AwaitReaction,
                 generator_objects::GeneratorHeapData,
+                iteration::async_from_sync_iterator_objects::AsyncFromSyncIteratorHeapData,
                 promise_objects::promise_abstract_operations::{
+                    promise_collector_functions::PromiseCollectorFunctionHeapData,
+                    promise_group_records::PromiseGroupRecord,
                     promise_reaction_records::PromiseReactionRecord,
                     promise_resolving_functions::PromiseResolvingFunctionHeapData,
                 },
@@ -79,6 +86,10 @@ use crate::{
         },
         execution::{Agent, Environments, Realm, RealmRecord},
         scripts_and_modules::{
+            module::module_semantics::{
+                GraphLoadingStateRecord, source_text_module_records::SourceTextModuleRecord,
+                synthetic_module_records::SyntheticModuleRecord,
+            },
             script::{Script, ScriptRecord},
             source_code::SourceCodeHeapData,
         },
@@ -86,7 +97,8 @@ use crate::{
             BUILTIN_STRINGS_LIST, BigIntHeapData, BoundFunctionHeapData,
             BuiltinConstructorHeapData, BuiltinFunctionHeapData, ECMAScriptFunctionHeapData,
             HeapNumber, HeapString, NumberHeapData, Object, ObjectHeapData, OrdinaryObject,
-            PropertyKey, String, StringHeapData, Symbol, SymbolHeapData, Value, bigint::HeapBigInt,
+            PrivateElement, PropertyKey, String, StringHeapData, Symbol, SymbolHeapData, Value,
+            bigint::HeapBigInt,
         },
     },
     engine::{
@@ -95,11 +107,10 @@ use crate::{
         rootable::HeapRootData,
     },
 };
-#[cfg(feature = "array-buffer")]
 use ahash::AHashMap;
 use element_array::{ElementDescriptor, ElementsVector};
 use hashbrown::HashTable;
-pub(crate) use heap_bits::{CompactionLists, HeapMarkAndSweep, WorkQueues};
+pub(crate) use heap_bits::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues};
 use indexes::TypedArrayIndex;
 use wtf8::Wtf8;
 
@@ -111,6 +122,7 @@ pub struct Heap {
     pub array_buffer_detach_keys: AHashMap<ArrayBuffer<'static>, DetachKey>,
     pub arrays: Vec<Option<ArrayHeapData<'static>>>,
     pub array_iterators: Vec<Option<ArrayIteratorHeapData<'static>>>,
+    pub async_from_sync_iterators: Vec<Option<AsyncFromSyncIteratorHeapData<'static>>>,
     pub async_generators: Vec<Option<AsyncGeneratorHeapData<'static>>>,
     pub(crate) await_reactions: Vec<Option<AwaitReaction<'static>>>,
     pub bigints: Vec<Option<BigIntHeapData>>,
@@ -125,6 +137,8 @@ pub struct Heap {
     pub data_view_byte_offsets: AHashMap<DataView<'static>, usize>,
     #[cfg(feature = "date")]
     pub dates: Vec<Option<DateHeapData<'static>>>,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub disposable_stacks: Vec<Option<DisposableStackHeapData<'static>>>,
     pub ecmascript_functions: Vec<Option<ECMAScriptFunctionHeapData<'static>>>,
     /// ElementsArrays is where all element arrays live;
     /// Element arrays are static arrays of Values plus
@@ -137,12 +151,23 @@ pub struct Heap {
     pub(crate) executables: Vec<ExecutableHeapData<'static>>,
     pub finalization_registrys: Vec<Option<FinalizationRegistryHeapData<'static>>>,
     pub generators: Vec<Option<GeneratorHeapData<'static>>>,
+    pub graph_loading_states: Vec<Option<GraphLoadingStateRecord<'static>>>,
     pub(crate) globals: RefCell<Vec<Option<HeapRootData>>>,
     pub maps: Vec<Option<MapHeapData<'static>>>,
     pub map_iterators: Vec<Option<MapIteratorHeapData<'static>>>,
     pub numbers: Vec<Option<NumberHeapData>>,
     pub objects: Vec<Option<ObjectHeapData<'static>>>,
     pub primitive_objects: Vec<Option<PrimitiveObjectHeapData<'static>>>,
+    /// ### \[\[PrivateElements]]
+    ///
+    /// Holds the private elements of all objects that have any. The elements
+    /// are keyed by the backing object of the object that holds them, or by
+    /// the object itself if it cannot have a backing object.
+    pub(crate) private_elements: AHashMap<Object<'static>, Vec<PrivateElement<'static>>>,
+    /// Counts created Private Names; see [`PrivateName`](crate::ecmascript::types::PrivateName).
+    pub(crate) private_name_counter: u32,
+    pub promise_collector_functions: Vec<Option<PromiseCollectorFunctionHeapData<'static>>>,
+    pub promise_group_records: Vec<Option<PromiseGroupRecord<'static>>>,
     pub promise_reaction_records: Vec<Option<PromiseReactionRecord<'static>>>,
     pub promise_resolving_functions: Vec<Option<PromiseResolvingFunctionHeapData<'static>>>,
     pub promises: Vec<Option<PromiseHeapData<'static>>>,
@@ -150,6 +175,8 @@ pub struct Heap {
     pub realms: Vec<Option<RealmRecord<'static>>>,
     #[cfg(feature = "regexp")]
     pub regexps: Vec<Option<RegExpHeapData<'static>>>,
+    #[cfg(feature = "regexp")]
+    pub regexp_string_iterators: Vec<Option<RegExpStringIteratorHeapData<'static>>>,
     #[cfg(feature = "set")]
     pub sets: Vec<Option<SetHeapData<'static>>>,
     #[cfg(feature = "set")]
@@ -173,6 +200,8 @@ pub struct Heap {
     pub weak_sets: Vec<Option<WeakSetHeapData<'static>>>,
     pub modules: Vec<Option<ModuleHeapData<'static>>>,
     pub scripts: Vec<Option<ScriptRecord<'static>>>,
+    pub source_text_modules: Vec<Option<SourceTextModuleRecord<'static>>>,
+    pub synthetic_modules: Vec<Option<SyntheticModuleRecord<'static>>>,
     pub string_iterators: Vec<Option<StringIteratorHeapData<'static>>>,
     // Parsed ASTs referred by functions must be dropped after functions.
     // These are held in the SourceCodeHeapData structs.
@@ -223,6 +252,7 @@ impl Heap {
             array_buffer_detach_keys: AHashMap::with_capacity(0),
             arrays: Vec::with_capacity(1024),
             array_iterators: Vec::with_capacity(256),
+            async_from_sync_iterators: Vec::with_capacity(0),
             async_generators: Vec::with_capacity(0),
             await_reactions: Vec::with_capacity(1024),
             bigints: Vec::with_capacity(1024),
@@ -237,6 +267,8 @@ impl Heap {
             data_view_byte_offsets: AHashMap::with_capacity(0),
             #[cfg(feature = "date")]
             dates: Vec::with_capacity(1024),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks: Vec::with_capacity(0),
             ecmascript_functions: Vec::with_capacity(1024),
             elements: ElementArrays {
                 e2pow4: ElementArray2Pow4::with_capacity(1024),
@@ -255,6 +287,7 @@ impl Heap {
             source_codes: Vec::with_capacity(0),
             finalization_registrys: Vec::with_capacity(0),
             generators: Vec::with_capacity(1024),
+            graph_loading_states: Vec::with_capacity(0),
             globals: RefCell::new(Vec::with_capacity(1024)),
             maps: Vec::with_capacity(128),
             map_iterators: Vec::with_capacity(128),
@@ -262,6 +295,10 @@ impl Heap {
             numbers: Vec::with_capacity(1024),
             objects: Vec::with_capacity(1024),
             primitive_objects: Vec::with_capacity(0),
+            private_elements: AHashMap::with_capacity(0),
+            private_name_counter: 0,
+            promise_collector_functions: Vec::with_capacity(0),
+            promise_group_records: Vec::with_capacity(0),
             promise_reaction_records: Vec::with_capacity(0),
             promise_resolving_functions: Vec::with_capacity(0),
             promises: Vec::with_capacity(0),
@@ -269,7 +306,11 @@ impl Heap {
             realms: Vec::with_capacity(1),
             #[cfg(feature = "regexp")]
             regexps: Vec::with_capacity(1024),
+            #[cfg(feature = "regexp")]
+            regexp_string_iterators: Vec::with_capacity(0),
             scripts: Vec::with_capacity(1),
+            source_text_modules: Vec::with_capacity(0),
+            synthetic_modules: Vec::with_capacity(0),
             #[cfg(feature = "set")]
             sets: Vec::with_capacity(128),
             #[cfg(feature = "set")]
@@ -15,10 +15,14 @@ use super::{
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "regexp")]
+use crate::ecmascript::builtins::text_processing::regexp_objects::regexp_string_iterator_objects::RegExpStringIterator;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{ArrayBuffer, data_view::DataView};
 #[cfg(feature = "set")]
@@ -35,8 +39,10 @@ use crate::ecmascript::{
         control_abstraction_objects::{
             async_function_objects::await_reaction::AwaitReactionIdentifier,
             generator_objects::Generator,
+            iteration::async_from_sync_iterator_objects::AsyncFromSyncIterator,
             promise_objects::promise_abstract_operations::{
-                promise_reaction_records::PromiseReaction,
+                promise_collector_functions::BuiltinPromiseCollectorFunction,
+                promise_group_records::PromiseGroup, promise_reaction_records::PromiseReaction,
                 promise_resolving_functions::BuiltinPromiseResolvingFunction,
             },
         },
@@ -53,11 +59,19 @@ use crate::ecmascript::{
         text_processing::string_objects::string_iterator_objects::StringIterator,
     },
     execution::{
-        DeclarativeEnvironment, FunctionEnvironment, GlobalEnvironment, ObjectEnvironment, Realm,
+        DeclarativeEnvironment, FunctionEnvironment, GlobalEnvironment, ModuleEnvironment,
+        ObjectEnvironment, PrivateEnvironment, Realm,
+    },
+    scripts_and_modules::{
+        module::module_semantics::{
+            GraphLoadingState, source_text_module_records::SourceTextModule,
+            synthetic_module_records::SyntheticModule,
+        },
+        script::Script,
+        source_code::SourceCode,
     },
-    scripts_and_modules::{script::Script, source_code::SourceCode},
     types::{
-        BUILTIN_STRINGS_LIST, HeapNumber, HeapString, OrdinaryObject, Symbol, Value,
+        BUILTIN_STRINGS_LIST, HeapNumber, HeapString, Object, OrdinaryObject, Symbol, Value,
         bigint::HeapBigInt,
     },
 };
@@ -69,6 +83,7 @@ pub struct HeapBits {
     pub array_buffers: Box<[bool]>,
     pub arrays: Box<[bool]>,
     pub array_iterators: Box<[bool]>,
+    pub async_from_sync_iterators: Box<[bool]>,
     pub async_generators: Box<[bool]>,
     pub await_reactions: Box<[bool]>,
     pub bigints: Box<[bool]>,
@@ -79,6 +94,8 @@ pub struct HeapBits {
     pub data_views: Box<[bool]>,
     #[cfg(feature = "date")]
     pub dates: Box<[bool]>,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub disposable_stacks: Box<[bool]>,
     pub declarative_environments: Box<[bool]>,
     pub e_2_10: Box<[(bool, u16)]>,
     pub e_2_12: Box<[(bool, u16)]>,
@@ -96,14 +113,19 @@ pub struct HeapBits {
     pub finalization_registrys: Box<[bool]>,
     pub function_environments: Box<[bool]>,
     pub generators: Box<[bool]>,
+    pub graph_loading_states: Box<[bool]>,
     pub global_environments: Box<[bool]>,
     pub maps: Box<[bool]>,
     pub map_iterators: Box<[bool]>,
     pub modules: Box<[bool]>,
     pub numbers: Box<[bool]>,
+    pub module_environments: Box<[bool]>,
     pub object_environments: Box<[bool]>,
+    pub private_environments: Box<[bool]>,
     pub objects: Box<[bool]>,
     pub primitive_objects: Box<[bool]>,
+    pub promise_collector_functions: Box<[bool]>,
+    pub promise_group_records: Box<[bool]>,
     pub promise_reaction_records: Box<[bool]>,
     pub promise_resolving_functions: Box<[bool]>,
     pub promises: Box<[bool]>,
@@ -111,7 +133,11 @@ pub struct HeapBits {
     pub realms: Box<[bool]>,
     #[cfg(feature = "regexp")]
     pub regexps: Box<[bool]>,
+    #[cfg(feature = "regexp")]
+    pub regexp_string_iterators: Box<[bool]>,
     pub scripts: Box<[bool]>,
+    pub source_text_modules: Box<[bool]>,
+    pub synthetic_modules: Box<[bool]>,
     #[cfg(feature = "set")]
     pub sets: Box<[bool]>,
     #[cfg(feature = "set")]
@@ -137,6 +163,7 @@ pub(crate) struct WorkQueues {
     pub array_buffers: Vec<ArrayBuffer<'static>>,
     pub arrays: Vec<Array<'static>>,
     pub array_iterators: Vec<ArrayIterator<'static>>,
+    pub async_from_sync_iterators: Vec<AsyncFromSyncIterator<'static>>,
     pub async_generators: Vec<AsyncGenerator<'static>>,
     pub await_reactions: Vec<AwaitReactionIdentifier<'static>>,
     pub bigints: Vec<HeapBigInt<'static>>,
@@ -147,6 +174,8 @@ pub(crate) struct WorkQueues {
     pub data_views: Vec<DataView<'static>>,
     #[cfg(feature = "date")]
     pub dates: Vec<Date<'static>>,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub disposable_stacks: Vec<DisposableStack<'static>>,
     pub declarative_environments: Vec<DeclarativeEnvironment<'static>>,
     pub e_2_10: Vec<(ElementIndex<'static>, u32)>,
     pub e_2_12: Vec<(ElementIndex<'static>, u32)>,
@@ -164,22 +193,31 @@ pub(crate) struct WorkQueues {
     pub finalization_registrys: Vec<FinalizationRegistry<'static>>,
     pub function_environments: Vec<FunctionEnvironment<'static>>,
     pub generators: Vec<Generator<'static>>,
+    pub graph_loading_states: Vec<GraphLoadingState<'static>>,
     pub global_environments: Vec<GlobalEnvironment<'static>>,
     pub maps: Vec<Map<'static>>,
     pub map_iterators: Vec<MapIterator<'static>>,
     pub modules: Vec<Module<'static>>,
     pub numbers: Vec<HeapNumber<'static>>,
+    pub module_environments: Vec<ModuleEnvironment<'static>>,
     pub object_environments: Vec<ObjectEnvironment<'static>>,
+    pub private_environments: Vec<PrivateEnvironment<'static>>,
     pub objects: Vec<OrdinaryObject<'static>>,
     pub primitive_objects: Vec<PrimitiveObject<'static>>,
     pub promises: Vec<Promise<'static>>,
+    pub promise_collector_functions: Vec<BuiltinPromiseCollectorFunction<'static>>,
+    pub promise_group_records: Vec<PromiseGroup<'static>>,
     pub promise_reaction_records: Vec<PromiseReaction<'static>>,
     pub promise_resolving_functions: Vec<BuiltinPromiseResolvingFunction<'static>>,
     pub proxys: Vec<Proxy<'static>>,
     pub realms: Vec<Realm<'static>>,
     #[cfg(feature = "regexp")]
     pub regexps: Vec<RegExp<'static>>,
+    #[cfg(feature = "regexp")]
+    pub regexp_string_iterators: Vec<RegExpStringIterator<'static>>,
     pub scripts: Vec<Script<'static>>,
+    pub source_text_modules: Vec<SourceTextModule<'static>>,
+    pub synthetic_modules: Vec<SyntheticModule<'static>>,
     #[cfg(feature = "set")]
     pub sets: Vec<Set<'static>>,
     #[cfg(feature = "set")]
@@ -205,6 +243,7 @@ impl HeapBits {
         let array_buffers = vec![false; heap.array_buffers.len()];
         let arrays = vec![false; heap.arrays.len()];
         let array_iterators = vec![false; heap.array_iterators.len()];
+        let async_from_sync_iterators = vec![false; heap.async_from_sync_iterators.len()];
         let async_generators = vec![false; heap.async_generators.len()];
         let await_reactions = vec![false; heap.await_reactions.len()];
         let bigints = vec![false; heap.bigints.len()];
@@ -215,6 +254,8 @@ impl HeapBits {
         let data_views = vec![false; heap.data_views.len()];
         #[cfg(feature = "date")]
         let dates = vec![false; heap.dates.len()];
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let disposable_stacks = vec![false; heap.disposable_stacks.len()];
         let declarative_environments = vec![false; heap.environments.declarative.len()];
         let e_2_10 = vec![(false, 0u16); heap.elements.e2pow10.values.len()];
         let e_2_12 = vec![(false, 0u16); heap.elements.e2pow12.values.len()];
@@ -232,14 +273,19 @@ impl HeapBits {
         let finalization_registrys = vec![false; heap.finalization_registrys.len()];
         let function_environments = vec![false; heap.environments.function.len()];
         let generators = vec![false; heap.generators.len()];
+        let graph_loading_states = vec![false; heap.graph_loading_states.len()];
         let global_environments = vec![false; heap.environments.global.len()];
         let maps = vec![false; heap.maps.len()];
         let map_iterators = vec![false; heap.map_iterators.len()];
         let modules = vec![false; heap.modules.len()];
         let numbers = vec![false; heap.numbers.len()];
+        let module_environments = vec![false; heap.environments.module.len()];
         let object_environments = vec![false; heap.environments.object.len()];
+        let private_environments = vec![false; heap.environments.private.len()];
         let objects = vec![false; heap.objects.len()];
         let primitive_objects = vec![false; heap.primitive_objects.len()];
+        let promise_collector_functions = vec![false; heap.promise_collector_functions.len()];
+        let promise_group_records = vec![false; heap.promise_group_records.len()];
         let promise_reaction_records = vec![false; heap.promise_reaction_records.len()];
         let promise_resolving_functions = vec![false; heap.promise_resolving_functions.len()];
         let promises = vec![false; heap.promises.len()];
@@ -247,7 +293,11 @@ impl HeapBits {
         let realms = vec![false; heap.realms.len()];
         #[cfg(feature = "regexp")]
         let regexps = vec![false; heap.regexps.len()];
+        #[cfg(feature = "regexp")]
+        let regexp_string_iterators = vec![false; heap.regexp_string_iterators.len()];
         let scripts = vec![false; heap.scripts.len()];
+        let source_text_modules = vec![false; heap.source_text_modules.len()];
+        let synthetic_modules = vec![false; heap.synthetic_modules.len()];
         #[cfg(feature = "set")]
         let sets = vec![false; heap.sets.len()];
         #[cfg(feature = "set")]
@@ -270,6 +320,7 @@ impl HeapBits {
             array_buffers: array_buffers.into_boxed_slice(),
             arrays: arrays.into_boxed_slice(),
             array_iterators: array_iterators.into_boxed_slice(),
+            async_from_sync_iterators: async_from_sync_iterators.into_boxed_slice(),
             async_generators: async_generators.into_boxed_slice(),
             await_reactions: await_reactions.into_boxed_slice(),
             bigints: bigints.into_boxed_slice(),
@@ -280,6 +331,8 @@ impl HeapBits {
             data_views: data_views.into_boxed_slice(),
             #[cfg(feature = "date")]
             dates: dates.into_boxed_slice(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks: disposable_stacks.into_boxed_slice(),
             declarative_environments: declarative_environments.into_boxed_slice(),
             e_2_10: e_2_10.into_boxed_slice(),
             e_2_12: e_2_12.into_boxed_slice(),
@@ -297,14 +350,19 @@ impl HeapBits {
             finalization_registrys: finalization_registrys.into_boxed_slice(),
             function_environments: function_environments.into_boxed_slice(),
             generators: generators.into_boxed_slice(),
+            graph_loading_states: graph_loading_states.into_boxed_slice(),
             global_environments: global_environments.into_boxed_slice(),
             maps: maps.into_boxed_slice(),
             map_iterators: map_iterators.into_boxed_slice(),
             modules: modules.into_boxed_slice(),
             numbers: numbers.into_boxed_slice(),
+            module_environments: module_environments.into_boxed_slice(),
             object_environments: object_environments.into_boxed_slice(),
+            private_environments: private_environments.into_boxed_slice(),
             objects: objects.into_boxed_slice(),
             primitive_objects: primitive_objects.into_boxed_slice(),
+            promise_collector_functions: promise_collector_functions.into_boxed_slice(),
+            promise_group_records: promise_group_records.into_boxed_slice(),
             promise_reaction_records: promise_reaction_records.into_boxed_slice(),
             promise_resolving_functions: promise_resolving_functions.into_boxed_slice(),
             promises: promises.into_boxed_slice(),
@@ -312,7 +370,11 @@ impl HeapBits {
             realms: realms.into_boxed_slice(),
             #[cfg(feature = "regexp")]
             regexps: regexps.into_boxed_slice(),
+            #[cfg(feature = "regexp")]
+            regexp_string_iterators: regexp_string_iterators.into_boxed_slice(),
             scripts: scripts.into_boxed_slice(),
+            source_text_modules: source_text_modules.into_boxed_slice(),
+            synthetic_modules: synthetic_modules.into_boxed_slice(),
             #[cfg(feature = "set")]
             sets: sets.into_boxed_slice(),
             #[cfg(feature = "set")]
@@ -332,6 +394,95 @@ impl HeapBits {
             weak_sets: weak_sets.into_boxed_slice(),
         }
     }
+
+    #[cfg(feature = "weak-refs")]
+    /// Returns true if the Value has been marked as reachable. Values that do
+    /// not live on the heap are always considered marked.
+    pub(crate) fn is_marked(&self, value: Value) -> bool {
+        match value {
+            Value::String(data) => self.strings[data.get_index()],
+            Value::Symbol(data) => self.symbols[data.get_index()],
+            Value::Number(data) => self.numbers[data.get_index()],
+            Value::BigInt(data) => self.bigints[data.get_index()],
+            _ => match Object::try_from(value) {
+                Ok(object) => self.is_object_marked(object),
+                Err(_) => true,
+            },
+        }
+    }
+
+    /// Returns true if the Object has been marked as reachable.
+    pub(crate) fn is_object_marked(&self, object: Object) -> bool {
+        match object {
+            Object::Object(data) => self.objects[data.get_index()],
+            Object::BoundFunction(data) => self.bound_functions[data.get_index()],
+            Object::BuiltinFunction(data) => self.builtin_functions[data.get_index()],
+            Object::ECMAScriptFunction(data) => self.ecmascript_functions[data.get_index()],
+            Object::BuiltinGeneratorFunction => true,
+            Object::BuiltinConstructorFunction(data) => self.builtin_constructors[data.get_index()],
+            Object::BuiltinPromiseResolvingFunction(data) => {
+                self.promise_resolving_functions[data.get_index()]
+            }
+            Object::BuiltinPromiseCollectorFunction(data) => {
+                self.promise_collector_functions[data.get_index()]
+            }
+            Object::BuiltinProxyRevokerFunction => true,
+            Object::PrimitiveObject(data) => self.primitive_objects[data.get_index()],
+            Object::Arguments(data) => self.objects[data.get_index()],
+            Object::Array(data) => self.arrays[data.get_index()],
+            #[cfg(feature = "array-buffer")]
+            Object::ArrayBuffer(data) => self.array_buffers[data.get_index()],
+            #[cfg(feature = "array-buffer")]
+            Object::DataView(data) => self.data_views[data.get_index()],
+            #[cfg(feature = "date")]
+            Object::Date(data) => self.dates[data.get_index()],
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => self.disposable_stacks[data.get_index()],
+            Object::Error(data) => self.errors[data.get_index()],
+            Object::FinalizationRegistry(data) => self.finalization_registrys[data.get_index()],
+            Object::Map(data) => self.maps[data.get_index()],
+            Object::Promise(data) => self.promises[data.get_index()],
+            Object::Proxy(data) => self.proxys[data.get_index()],
+            #[cfg(feature = "regexp")]
+            Object::RegExp(data) => self.regexps[data.get_index()],
+            #[cfg(feature = "set")]
+            Object::Set(data) => self.sets[data.get_index()],
+            #[cfg(feature = "shared-array-buffer")]
+            Object::SharedArrayBuffer(data) => self.shared_array_buffers[data.get_index()],
+            #[cfg(feature = "weak-refs")]
+            Object::WeakMap(data) => self.weak_maps[data.get_index()],
+            #[cfg(feature = "weak-refs")]
+            Object::WeakRef(data) => self.weak_refs[data.get_index()],
+            #[cfg(feature = "weak-refs")]
+            Object::WeakSet(data) => self.weak_sets[data.get_index()],
+            #[cfg(feature = "array-buffer")]
+            Object::Int8Array(data)
+            | Object::Uint8Array(data)
+            | Object::Uint8ClampedArray(data)
+            | Object::Int16Array(data)
+            | Object::Uint16Array(data)
+            | Object::Int32Array(data)
+            | Object::Uint32Array(data)
+            | Object::BigInt64Array(data)
+            | Object::BigUint64Array(data)
+            | Object::Float32Array(data)
+            | Object::Float64Array(data) => self.typed_arrays[data.into_index()],
+            #[cfg(feature = "proposal-float16array")]
+            Object::Float16Array(data) => self.typed_arrays[data.into_index()],
+            Object::AsyncFromSyncIterator(data) => self.async_from_sync_iterators[data.get_index()],
+            Object::AsyncGenerator(data) => self.async_generators[data.get_index()],
+            Object::ArrayIterator(data) => self.array_iterators[data.get_index()],
+            #[cfg(feature = "set")]
+            Object::SetIterator(data) => self.set_iterators[data.get_index()],
+            Object::MapIterator(data) => self.map_iterators[data.get_index()],
+            Object::StringIterator(data) => self.string_iterators[data.get_index()],
+            #[cfg(feature = "regexp")]
+            Object::RegExpStringIterator(data) => self.regexp_string_iterators[data.get_index()],
+            Object::Generator(data) => self.generators[data.get_index()],
+            Object::Module(data) => self.modules[data.get_index()],
+            Object::EmbedderObject(data) => self.embedder_objects[data.get_index()],
+        }
+    }
 }
 
 impl WorkQueues {
@@ -341,6 +492,7 @@ impl WorkQueues {
             array_buffers: Vec::with_capacity(heap.array_buffers.len() / 4),
             arrays: Vec::with_capacity(heap.arrays.len() / 4),
             array_iterators: Vec::with_capacity(heap.array_iterators.len() / 4),
+            async_from_sync_iterators: Vec::with_capacity(heap.async_from_sync_iterators.len() / 4),
             async_generators: Vec::with_capacity(heap.async_generators.len() / 4),
             await_reactions: Vec::with_capacity(heap.await_reactions.len() / 4),
             bigints: Vec::with_capacity(heap.bigints.len() / 4),
@@ -351,6 +503,8 @@ impl WorkQueues {
             data_views: Vec::with_capacity(heap.data_views.len() / 4),
             #[cfg(feature = "date")]
             dates: Vec::with_capacity(heap.dates.len() / 4),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks: Vec::with_capacity(heap.disposable_stacks.len() / 4),
             declarative_environments: Vec::with_capacity(heap.environments.declarative.len() / 4),
             e_2_10: Vec::with_capacity(heap.elements.e2pow10.values.len() / 4),
             e_2_12: Vec::with_capacity(heap.elements.e2pow12.values.len() / 4),
@@ -368,14 +522,21 @@ impl WorkQueues {
             finalization_registrys: Vec::with_capacity(heap.finalization_registrys.len() / 4),
             function_environments: Vec::with_capacity(heap.environments.function.len() / 4),
             generators: Vec::with_capacity(heap.generators.len() / 4),
+            graph_loading_states: Vec::with_capacity(heap.graph_loading_states.len() / 4),
             global_environments: Vec::with_capacity(heap.environments.global.len() / 4),
             maps: Vec::with_capacity(heap.maps.len() / 4),
             map_iterators: Vec::with_capacity(heap.map_iterators.len() / 4),
             modules: Vec::with_capacity(heap.modules.len() / 4),
             numbers: Vec::with_capacity(heap.numbers.len() / 4),
+            module_environments: Vec::with_capacity(heap.environments.module.len() / 4),
             object_environments: Vec::with_capacity(heap.environments.object.len() / 4),
+            private_environments: Vec::with_capacity(heap.environments.private.len() / 4),
             objects: Vec::with_capacity(heap.objects.len() / 4),
             primitive_objects: Vec::with_capacity(heap.primitive_objects.len() / 4),
+            promise_collector_functions: Vec::with_capacity(
+                heap.promise_collector_functions.len() / 4,
+            ),
+            promise_group_records: Vec::with_capacity(heap.promise_group_records.len() / 4),
             promise_reaction_records: Vec::with_capacity(heap.promise_reaction_records.len() / 4),
             promise_resolving_functions: Vec::with_capacity(
                 heap.promise_resolving_functions.len() / 4,
@@ -385,7 +546,11 @@ impl WorkQueues {
             realms: Vec::with_capacity(heap.realms.len() / 4),
             #[cfg(feature = "regexp")]
             regexps: Vec::with_capacity(heap.regexps.len() / 4),
+            #[cfg(feature = "regexp")]
+            regexp_string_iterators: Vec::with_capacity(heap.regexp_string_iterators.len() / 4),
             scripts: Vec::with_capacity(heap.scripts.len() / 4),
+            source_text_modules: Vec::with_capacity(heap.source_text_modules.len() / 4),
+            synthetic_modules: Vec::with_capacity(heap.synthetic_modules.len() / 4),
             #[cfg(feature = "set")]
             sets: Vec::with_capacity(heap.sets.len() / 4),
             #[cfg(feature = "set")]
@@ -412,6 +577,7 @@ impl WorkQueues {
             array_buffers,
             arrays,
             array_iterators,
+            async_from_sync_iterators,
             async_generators,
             await_reactions,
             bigints,
@@ -422,6 +588,8 @@ impl WorkQueues {
             data_views,
             #[cfg(feature = "date")]
             dates,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks,
             declarative_environments,
             e_2_10,
             e_2_12,
@@ -439,22 +607,31 @@ impl WorkQueues {
             finalization_registrys,
             function_environments,
             generators,
+            graph_loading_states,
             global_environments,
             maps,
             map_iterators,
             modules,
             numbers,
+            module_environments,
             object_environments,
+            private_environments,
             objects,
             primitive_objects,
             promises,
+            promise_collector_functions,
+            promise_group_records,
             promise_reaction_records,
             promise_resolving_functions,
             proxys,
             realms,
             #[cfg(feature = "regexp")]
             regexps,
+            #[cfg(feature = "regexp")]
+            regexp_string_iterators,
             scripts,
+            source_text_modules,
+            synthetic_modules,
             #[cfg(feature = "set")]
             sets,
             #[cfg(feature = "set")]
@@ -476,6 +653,8 @@ impl WorkQueues {
 
         #[cfg(not(feature = "date"))]
         let dates: &[bool; 0] = &[];
+        #[cfg(not(feature = "proposal-explicit-resource-management"))]
+        let disposable_stacks: &[bool; 0] = &[];
         #[cfg(not(feature = "array-buffer"))]
         let data_views: &[bool; 0] = &[];
         #[cfg(not(feature = "array-buffer"))]
@@ -492,6 +671,8 @@ impl WorkQueues {
         let weak_sets: &[bool; 0] = &[];
         #[cfg(not(feature = "regexp"))]
         let regexps: &[bool; 0] = &[];
+        #[cfg(not(feature = "regexp"))]
+        let regexp_string_iterators: &[bool; 0] = &[];
         #[cfg(not(feature = "set"))]
         let sets: &[bool; 0] = &[];
         #[cfg(not(feature = "set"))]
@@ -499,6 +680,7 @@ impl WorkQueues {
         array_buffers.is_empty()
             && arrays.is_empty()
             && array_iterators.is_empty()
+            && async_from_sync_iterators.is_empty()
             && async_generators.is_empty()
             && await_reactions.is_empty()
             && bigints.is_empty()
@@ -507,6 +689,7 @@ impl WorkQueues {
             && builtin_functions.is_empty()
             && data_views.is_empty()
             && dates.is_empty()
+            && disposable_stacks.is_empty()
             && declarative_environments.is_empty()
             && e_2_10.is_empty()
             && e_2_12.is_empty()
@@ -524,21 +707,29 @@ impl WorkQueues {
             && finalization_registrys.is_empty()
             && function_environments.is_empty()
             && generators.is_empty()
+            && graph_loading_states.is_empty()
             && global_environments.is_empty()
             && maps.is_empty()
             && map_iterators.is_empty()
             && modules.is_empty()
             && numbers.is_empty()
+            && module_environments.is_empty()
             && object_environments.is_empty()
+            && private_environments.is_empty()
             && objects.is_empty()
             && primitive_objects.is_empty()
+            && promise_collector_functions.is_empty()
+            && promise_group_records.is_empty()
             && promise_reaction_records.is_empty()
             && promise_resolving_functions.is_empty()
             && promises.is_empty()
             && proxys.is_empty()
             && realms.is_empty()
             && regexps.is_empty()
+            && regexp_string_iterators.is_empty()
             && scripts.is_empty()
+            && source_text_modules.is_empty()
+            && synthetic_modules.is_empty()
             && sets.is_empty()
             && set_iterators.is_empty()
             && shared_array_buffers.is_empty()
@@ -709,6 +900,7 @@ pub(crate) struct CompactionLists {
     pub array_buffers: CompactionList,
     pub arrays: CompactionList,
     pub array_iterators: CompactionList,
+    pub async_from_sync_iterators: CompactionList,
     pub async_generators: CompactionList,
     pub await_reactions: CompactionList,
     pub bigints: CompactionList,
@@ -719,6 +911,8 @@ pub(crate) struct CompactionLists {
     pub data_views: CompactionList,
     #[cfg(feature = "date")]
     pub dates: CompactionList,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub disposable_stacks: CompactionList,
     pub declarative_environments: CompactionList,
     pub e_2_10: CompactionList,
     pub e_2_12: CompactionList,
@@ -736,14 +930,19 @@ pub(crate) struct CompactionLists {
     pub finalization_registrys: CompactionList,
     pub function_environments: CompactionList,
     pub generators: CompactionList,
+    pub graph_loading_states: CompactionList,
     pub global_environments: CompactionList,
     pub maps: CompactionList,
     pub map_iterators: CompactionList,
     pub modules: CompactionList,
     pub numbers: CompactionList,
+    pub module_environments: CompactionList,
     pub object_environments: CompactionList,
+    pub private_environments: CompactionList,
     pub objects: CompactionList,
     pub primitive_objects: CompactionList,
+    pub promise_collector_functions: CompactionList,
+    pub promise_group_records: CompactionList,
     pub promise_reaction_records: CompactionList,
     pub promise_resolving_functions: CompactionList,
     pub promises: CompactionList,
@@ -751,7 +950,11 @@ pub(crate) struct CompactionLists {
     pub realms: CompactionList,
     #[cfg(feature = "regexp")]
     pub regexps: CompactionList,
+    #[cfg(feature = "regexp")]
+    pub regexp_string_iterators: CompactionList,
     pub scripts: CompactionList,
+    pub source_text_modules: CompactionList,
+    pub synthetic_modules: CompactionList,
     #[cfg(feature = "set")]
     pub sets: CompactionList,
     #[cfg(feature = "set")]
@@ -785,13 +988,17 @@ impl CompactionLists {
         Self {
             modules: CompactionList::from_mark_bits(&bits.modules),
             scripts: CompactionList::from_mark_bits(&bits.scripts),
+            source_text_modules: CompactionList::from_mark_bits(&bits.source_text_modules),
+            synthetic_modules: CompactionList::from_mark_bits(&bits.synthetic_modules),
             realms: CompactionList::from_mark_bits(&bits.realms),
             declarative_environments: CompactionList::from_mark_bits(
                 &bits.declarative_environments,
             ),
             function_environments: CompactionList::from_mark_bits(&bits.function_environments),
             global_environments: CompactionList::from_mark_bits(&bits.global_environments),
+            module_environments: CompactionList::from_mark_bits(&bits.module_environments),
             object_environments: CompactionList::from_mark_bits(&bits.object_environments),
+            private_environments: CompactionList::from_mark_bits(&bits.private_environments),
             e_2_4: CompactionList::from_mark_u8s(&bits.e_2_4),
             e_2_6: CompactionList::from_mark_u8s(&bits.e_2_6),
             e_2_8: CompactionList::from_mark_u8s(&bits.e_2_8),
@@ -804,6 +1011,9 @@ impl CompactionLists {
             #[cfg(feature = "array-buffer")]
             array_buffers: CompactionList::from_mark_bits(&bits.array_buffers),
             array_iterators: CompactionList::from_mark_bits(&bits.array_iterators),
+            async_from_sync_iterators: CompactionList::from_mark_bits(
+                &bits.async_from_sync_iterators,
+            ),
             async_generators: CompactionList::from_mark_bits(&bits.async_generators),
             await_reactions: CompactionList::from_mark_bits(&bits.await_reactions),
             bigints: CompactionList::from_mark_bits(&bits.bigints),
@@ -813,15 +1023,22 @@ impl CompactionLists {
             ecmascript_functions: CompactionList::from_mark_bits(&bits.ecmascript_functions),
             embedder_objects: CompactionList::from_mark_bits(&bits.embedder_objects),
             generators: CompactionList::from_mark_bits(&bits.generators),
+            graph_loading_states: CompactionList::from_mark_bits(&bits.graph_loading_states),
             source_codes: CompactionList::from_mark_bits(&bits.source_codes),
             #[cfg(feature = "date")]
             dates: CompactionList::from_mark_bits(&bits.dates),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks: CompactionList::from_mark_bits(&bits.disposable_stacks),
             errors: CompactionList::from_mark_bits(&bits.errors),
             executables: CompactionList::from_mark_bits(&bits.executables),
             maps: CompactionList::from_mark_bits(&bits.maps),
             map_iterators: CompactionList::from_mark_bits(&bits.map_iterators),
             numbers: CompactionList::from_mark_bits(&bits.numbers),
             objects: CompactionList::from_mark_bits(&bits.objects),
+            promise_collector_functions: CompactionList::from_mark_bits(
+                &bits.promise_collector_functions,
+            ),
+            promise_group_records: CompactionList::from_mark_bits(&bits.promise_group_records),
             promise_reaction_records: CompactionList::from_mark_bits(
                 &bits.promise_reaction_records,
             ),
@@ -832,6 +1049,8 @@ impl CompactionLists {
             primitive_objects: CompactionList::from_mark_bits(&bits.primitive_objects),
             #[cfg(feature = "regexp")]
             regexps: CompactionList::from_mark_bits(&bits.regexps),
+            #[cfg(feature = "regexp")]
+            regexp_string_iterators: CompactionList::from_mark_bits(&bits.regexp_string_iterators),
             #[cfg(feature = "set")]
             sets: CompactionList::from_mark_bits(&bits.sets),
             #[cfg(feature = "set")]
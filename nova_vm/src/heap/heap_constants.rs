@@ -105,6 +105,10 @@ pub(crate) enum IntrinsicObjectIndexes {
     GeneratorPrototype,
     AsyncGeneratorPrototype,
     AsyncFunctionPrototype,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStackPrototype,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AsyncDisposableStackPrototype,
 
     // Reflection
     ReflectObject,
@@ -116,6 +120,8 @@ pub(crate) enum IntrinsicObjectIndexes {
     ReferenceErrorPrototype,
     SyntaxErrorPrototype,
     TypeErrorPrototype,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    SuppressedErrorPrototype,
 
     // Others
     URIErrorPrototype,
@@ -214,6 +220,10 @@ pub(crate) enum IntrinsicConstructorIndexes {
     GeneratorFunction,
     AsyncGeneratorFunction,
     AsyncFunction,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AsyncDisposableStack,
 
     // Reflection
     Proxy,
@@ -225,6 +235,8 @@ pub(crate) enum IntrinsicConstructorIndexes {
     ReferenceError,
     SyntaxError,
     TypeError,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    SuppressedError,
 
     // Others
     URIError,
@@ -241,12 +253,18 @@ pub(crate) enum IntrinsicFunctionIndexes {
     ArrayPrototypeSort,
     ArrayPrototypeToString,
     ArrayPrototypeValues,
+    AsyncFromSyncIteratorPrototypeNext,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AsyncDisposableStackPrototypeDisposeAsync,
     #[cfg(feature = "date")]
     DatePrototypeToUTCString,
     DecodeURI,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStackPrototypeDispose,
     DecodeURIComponent,
     EncodeURI,
     EncodeURIComponent,
+    #[cfg(feature = "annex-b-global")]
     Escape,
     Eval,
     GeneratorFunctionPrototypePrototypeNext,
@@ -264,10 +282,18 @@ pub(crate) enum IntrinsicFunctionIndexes {
     ThrowTypeError,
     #[cfg(feature = "array-buffer")]
     TypedArrayPrototypeValues,
+    #[cfg(feature = "annex-b-global")]
     Unescape,
 }
+#[cfg(feature = "annex-b-global")]
 pub(crate) const LAST_INTRINSIC_FUNCTION_INDEX: IntrinsicFunctionIndexes =
     IntrinsicFunctionIndexes::Unescape;
+#[cfg(all(not(feature = "annex-b-global"), feature = "array-buffer"))]
+pub(crate) const LAST_INTRINSIC_FUNCTION_INDEX: IntrinsicFunctionIndexes =
+    IntrinsicFunctionIndexes::TypedArrayPrototypeValues;
+#[cfg(not(any(feature = "annex-b-global", feature = "array-buffer")))]
+pub(crate) const LAST_INTRINSIC_FUNCTION_INDEX: IntrinsicFunctionIndexes =
+    IntrinsicFunctionIndexes::ThrowTypeError;
 
 impl IntrinsicObjectIndexes {
     const OBJECT_INDEX_OFFSET: u32 = 0;
@@ -352,7 +378,9 @@ pub(crate) const fn intrinsic_function_count() -> usize {
 #[derive(Debug, Clone, Copy)]
 #[repr(u32)]
 pub(crate) enum WellKnownSymbolIndexes {
+    AsyncDispose,
     AsyncIterator,
+    Dispose,
     HasInstance,
     IsConcatSpreadable,
     Iterator,
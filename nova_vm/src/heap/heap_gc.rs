@@ -4,6 +4,10 @@
 
 use std::thread;
 
+use ahash::AHashMap;
+
+#[cfg(feature = "array-buffer")]
+use super::indexes::TypedArrayIndex;
 use super::{
     Heap, WellKnownSymbolIndexes,
     element_array::ElementArrays,
@@ -12,17 +16,20 @@ use super::{
         mark_descriptors, sweep_heap_elements_vector_descriptors,
         sweep_heap_u8_elements_vector_values, sweep_heap_u16_elements_vector_values,
         sweep_heap_u32_elements_vector_values, sweep_heap_vector_values, sweep_lookup_table,
+        sweep_side_table_values,
     },
     indexes::{ElementIndex, StringIndex},
 };
-#[cfg(feature = "array-buffer")]
-use super::{heap_bits::sweep_side_table_values, indexes::TypedArrayIndex};
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::SharedArrayBuffer;
+#[cfg(feature = "regexp")]
+use crate::ecmascript::builtins::text_processing::regexp_objects::regexp_string_iterator_objects::RegExpStringIterator;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{ArrayBuffer, data_view::DataView};
 #[cfg(feature = "set")]
@@ -40,14 +47,18 @@ use crate::{
             control_abstraction_objects::{
                 async_function_objects::await_reaction::AwaitReactionIdentifier,
                 generator_objects::Generator,
+                iteration::async_from_sync_iterator_objects::AsyncFromSyncIterator,
                 promise_objects::promise_abstract_operations::{
-                    promise_reaction_records::PromiseReaction,
+                    promise_collector_functions::BuiltinPromiseCollectorFunction,
+                    promise_group_records::PromiseGroup, promise_reaction_records::PromiseReaction,
                     promise_resolving_functions::BuiltinPromiseResolvingFunction,
                 },
             },
             embedder_object::EmbedderObject,
             error::Error,
-            finalization_registry::FinalizationRegistry,
+            finalization_registry::{
+                FinalizationRegistry, enqueue_finalization_registry_cleanup_jobs,
+            },
             indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIterator,
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIterator,
             map::Map,
@@ -59,12 +70,19 @@ use crate::{
         },
         execution::{
             Agent, DeclarativeEnvironment, Environments, FunctionEnvironment, GlobalEnvironment,
-            ObjectEnvironment, Realm,
+            ModuleEnvironment, ObjectEnvironment, PrivateEnvironment, Realm,
+        },
+        scripts_and_modules::{
+            module::module_semantics::{
+                GraphLoadingState, source_text_module_records::SourceTextModule,
+                synthetic_module_records::SyntheticModule,
+            },
+            script::Script,
+            source_code::SourceCode,
         },
-        scripts_and_modules::{script::Script, source_code::SourceCode},
         types::{
-            BUILTIN_STRINGS_LIST, HeapNumber, HeapString, OrdinaryObject, Symbol,
-            bigint::HeapBigInt,
+            BUILTIN_STRINGS_LIST, HeapNumber, HeapString, Object, OrdinaryObject, PrivateElement,
+            Symbol, bigint::HeapBigInt,
         },
     },
     engine::{
@@ -87,7 +105,9 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
         (0..BUILTIN_STRINGS_LIST.len()).map(|index| HeapString(StringIndex::from_index(index))),
     );
     queues.symbols.extend_from_slice(&[
+        WellKnownSymbolIndexes::AsyncDispose.into(),
         WellKnownSymbolIndexes::AsyncIterator.into(),
+        WellKnownSymbolIndexes::Dispose.into(),
         WellKnownSymbolIndexes::HasInstance.into(),
         WellKnownSymbolIndexes::IsConcatSpreadable.into(),
         WellKnownSymbolIndexes::Iterator.into(),
@@ -102,6 +122,16 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
         WellKnownSymbolIndexes::Unscopables.into(),
     ]);
     agent.mark_values(&mut queues);
+    // Objects without heap data are always alive, and so are their private
+    // elements.
+    for (holder, elements) in agent.heap.private_elements.iter() {
+        if matches!(
+            holder,
+            Object::BuiltinGeneratorFunction | Object::BuiltinProxyRevokerFunction
+        ) {
+            elements.as_slice().mark_values(&mut queues);
+        }
+    }
 
     while !queues.is_empty() {
         let Heap {
@@ -111,6 +141,7 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 array_buffer_detach_keys: _,
             arrays,
             array_iterators,
+            async_from_sync_iterators,
             async_generators,
             await_reactions,
             bigints,
@@ -125,6 +156,8 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 data_view_byte_offsets: _,
             #[cfg(feature = "date")]
             dates,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks,
             ecmascript_functions,
             elements,
             embedder_objects,
@@ -134,6 +167,7 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
             source_codes,
             finalization_registrys,
             generators,
+            graph_loading_states,
             globals: _,
             maps,
             map_iterators,
@@ -141,6 +175,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
             numbers,
             objects,
             primitive_objects,
+            private_elements,
+            private_name_counter: _,
+            promise_collector_functions,
+            promise_group_records,
             promise_reaction_records,
             promise_resolving_functions,
             promises,
@@ -148,7 +186,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
             realms,
             #[cfg(feature = "regexp")]
             regexps,
+            #[cfg(feature = "regexp")]
+            regexp_string_iterators,
             scripts,
+            source_text_modules,
+            synthetic_modules,
             #[cfg(feature = "set")]
             sets,
             #[cfg(feature = "set")]
@@ -180,8 +222,9 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
             declarative: declarative_environments,
             function: function_environments,
             global: global_environments,
+            module: module_environments,
             object: object_environments,
-            private: _private_environments,
+            private: private_environments,
         } = environments;
         let ElementArrays {
             e2pow4,
@@ -219,6 +262,34 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 scripts.get(index).mark_values(&mut queues);
             }
         });
+        let mut source_text_module_marks: Box<[SourceTextModule]> =
+            queues.source_text_modules.drain(..).collect();
+        source_text_module_marks.sort();
+        source_text_module_marks.iter().for_each(|&idx| {
+            let index = idx.into_index();
+            if let Some(marked) = bits.source_text_modules.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                source_text_modules.get(index).mark_values(&mut queues);
+            }
+        });
+        let mut synthetic_module_marks: Box<[SyntheticModule]> =
+            queues.synthetic_modules.drain(..).collect();
+        synthetic_module_marks.sort();
+        synthetic_module_marks.iter().for_each(|&idx| {
+            let index = idx.into_index();
+            if let Some(marked) = bits.synthetic_modules.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                synthetic_modules.get(index).mark_values(&mut queues);
+            }
+        });
         let mut realm_marks: Box<[Realm]> = queues.realms.drain(..).collect();
         realm_marks.sort();
         realm_marks.iter().for_each(|&idx| {
@@ -275,6 +346,20 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 global_environments.get(index).mark_values(&mut queues);
             }
         });
+        let mut module_environment_marks: Box<[ModuleEnvironment]> =
+            queues.module_environments.drain(..).collect();
+        module_environment_marks.sort();
+        module_environment_marks.iter().for_each(|&idx| {
+            let index = idx.into_index();
+            if let Some(marked) = bits.module_environments.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                module_environments.get(index).mark_values(&mut queues);
+            }
+        });
         let mut object_environment_marks: Box<[ObjectEnvironment]> =
             queues.object_environments.drain(..).collect();
         object_environment_marks.sort();
@@ -289,6 +374,20 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 object_environments.get(index).mark_values(&mut queues);
             }
         });
+        let mut private_environment_marks: Box<[PrivateEnvironment]> =
+            queues.private_environments.drain(..).collect();
+        private_environment_marks.sort();
+        private_environment_marks.iter().for_each(|&idx| {
+            let index = idx.into_index();
+            if let Some(marked) = bits.private_environments.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                private_environments.get(index).mark_values(&mut queues);
+            }
+        });
 
         let mut array_marks: Box<[Array]> = queues.arrays.drain(..).collect();
         array_marks.sort();
@@ -334,6 +433,22 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 array_iterators.get(index).mark_values(&mut queues);
             }
         });
+        let mut async_from_sync_iterator_marks: Box<[AsyncFromSyncIterator]> =
+            queues.async_from_sync_iterators.drain(..).collect();
+        async_from_sync_iterator_marks.sort();
+        async_from_sync_iterator_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.async_from_sync_iterators.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                async_from_sync_iterators
+                    .get(index)
+                    .mark_values(&mut queues);
+            }
+        });
         let mut async_generator_marks: Box<[AsyncGenerator]> =
             queues.async_generators.drain(..).collect();
         async_generator_marks.sort();
@@ -502,6 +617,23 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 }
             });
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        {
+            let mut disposable_stack_marks: Box<[DisposableStack]> =
+                queues.disposable_stacks.drain(..).collect();
+            disposable_stack_marks.sort();
+            disposable_stack_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.disposable_stacks.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    disposable_stacks.get(index).mark_values(&mut queues);
+                }
+            });
+        }
         let mut embedder_object_marks: Box<[EmbedderObject]> =
             queues.embedder_objects.drain(..).collect();
         embedder_object_marks.sort();
@@ -514,6 +646,9 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 }
                 *marked = true;
                 embedder_objects.get(index).mark_values(&mut queues);
+                if let Some(elements) = private_elements.get(&Object::EmbedderObject(idx)) {
+                    elements.as_slice().mark_values(&mut queues);
+                }
             }
         });
         let mut finalization_registry_marks: Box<[FinalizationRegistry]> =
@@ -543,6 +678,20 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 generators.get(index).mark_values(&mut queues);
             }
         });
+        let mut graph_loading_state_marks: Box<[GraphLoadingState]> =
+            queues.graph_loading_states.drain(..).collect();
+        graph_loading_state_marks.sort();
+        graph_loading_state_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.graph_loading_states.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                graph_loading_states.get(index).mark_values(&mut queues);
+            }
+        });
         let mut object_marks: Box<[OrdinaryObject]> = queues.objects.drain(..).collect();
         object_marks.sort();
         object_marks.iter().for_each(|&idx| {
@@ -554,6 +703,9 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 }
                 *marked = true;
                 objects.get(index).mark_values(&mut queues);
+                if let Some(elements) = private_elements.get(&Object::Object(idx)) {
+                    elements.as_slice().mark_values(&mut queues);
+                }
             }
         });
         let mut promise_marks: Box<[Promise]> = queues.promises.drain(..).collect();
@@ -569,6 +721,36 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 promises.get(index).mark_values(&mut queues);
             }
         });
+        let mut promise_collector_function_marks: Box<[BuiltinPromiseCollectorFunction]> =
+            queues.promise_collector_functions.drain(..).collect();
+        promise_collector_function_marks.sort();
+        promise_collector_function_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.promise_collector_functions.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                promise_collector_functions
+                    .get(index)
+                    .mark_values(&mut queues);
+            }
+        });
+        let mut promise_group_record_marks: Box<[PromiseGroup]> =
+            queues.promise_group_records.drain(..).collect();
+        promise_group_record_marks.sort();
+        promise_group_record_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.promise_group_records.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                promise_group_records.get(index).mark_values(&mut queues);
+            }
+        });
         let mut promise_reaction_record_marks: Box<[PromiseReaction]> =
             queues.promise_reaction_records.drain(..).collect();
         promise_reaction_record_marks.sort();
@@ -610,6 +792,9 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 }
                 *marked = true;
                 proxys.get(index).mark_values(&mut queues);
+                if let Some(elements) = private_elements.get(&Object::Proxy(idx)) {
+                    elements.as_slice().mark_values(&mut queues);
+                }
             }
         });
         let mut map_marks: Box<[Map]> = queues.maps.drain(..).collect();
@@ -680,6 +865,20 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                     regexps.get(index).mark_values(&mut queues);
                 }
             });
+            let mut regexp_string_iterator_marks: Box<[RegExpStringIterator]> =
+                queues.regexp_string_iterators.drain(..).collect();
+            regexp_string_iterator_marks.sort();
+            regexp_string_iterator_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.regexp_string_iterators.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    regexp_string_iterators.get(index).mark_values(&mut queues);
+                }
+            });
         }
         #[cfg(feature = "set")]
         {
@@ -997,9 +1196,76 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc
                 }
             }
         });
+
+        #[cfg(feature = "weak-refs")]
+        if queues.is_empty() {
+            // All values reachable through strong references have been
+            // marked: WeakMap entries whose keys are now known to be
+            // reachable have their values marked. This may in turn make more
+            // keys reachable, so marking continues until no new values are
+            // found.
+            weak_maps
+                .iter()
+                .zip(bits.weak_maps.iter())
+                .for_each(|(weak_map, &marked)| {
+                    if let (Some(weak_map), true) = (weak_map, marked) {
+                        weak_map.mark_ephemeron_values(&bits, &mut queues);
+                    }
+                });
+        }
+    }
+
+    // Empty the weakly held targets of live FinalizationRegistries that were
+    // not reachable.
+    agent
+        .heap
+        .finalization_registrys
+        .iter_mut()
+        .zip(bits.finalization_registrys.iter())
+        .for_each(|(finalization_registry, &marked)| {
+            if let (Some(finalization_registry), true) = (finalization_registry, marked) {
+                finalization_registry.sweep_weak_targets(&bits);
+            }
+        });
+    #[cfg(feature = "weak-refs")]
+    {
+        // Empty the targets of live WeakRefs, and remove the entries of live
+        // WeakMaps and WeakSets, that were not reachable.
+        let Heap {
+            weak_maps,
+            weak_refs,
+            weak_sets,
+            ..
+        } = &mut agent.heap;
+        weak_refs
+            .iter_mut()
+            .zip(bits.weak_refs.iter())
+            .for_each(|(weak_ref, &marked)| {
+                if let (Some(weak_ref), true) = (weak_ref, marked) {
+                    weak_ref.sweep_weak_target(&bits);
+                }
+            });
+        weak_maps
+            .iter_mut()
+            .zip(bits.weak_maps.iter())
+            .for_each(|(weak_map, &marked)| {
+                if let (Some(weak_map), true) = (weak_map, marked) {
+                    weak_map.sweep_weak_keys(&bits);
+                }
+            });
+        weak_sets
+            .iter_mut()
+            .zip(bits.weak_sets.iter())
+            .for_each(|(weak_set, &marked)| {
+                if let (Some(weak_set), true) = (weak_set, marked) {
+                    weak_set.sweep_weak_keys(&bits);
+                }
+            });
     }
 
     sweep(agent, &bits, root_realms, gc);
+
+    enqueue_finalization_registry_cleanup_jobs(agent);
 }
 
 fn sweep(
@@ -1023,6 +1289,7 @@ fn sweep(
         array_buffer_detach_keys,
         arrays,
         array_iterators,
+        async_from_sync_iterators,
         async_generators,
         await_reactions,
         bigints,
@@ -1037,6 +1304,8 @@ fn sweep(
         data_view_byte_offsets,
         #[cfg(feature = "date")]
         dates,
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        disposable_stacks,
         ecmascript_functions,
         elements,
         embedder_objects,
@@ -1046,6 +1315,7 @@ fn sweep(
         source_codes,
         finalization_registrys,
         generators,
+        graph_loading_states,
         globals,
         maps,
         map_iterators,
@@ -1053,6 +1323,10 @@ fn sweep(
         numbers,
         objects,
         primitive_objects,
+        private_elements,
+        private_name_counter: _,
+        promise_collector_functions,
+        promise_group_records,
         promise_reaction_records,
         promise_resolving_functions,
         promises,
@@ -1060,7 +1334,11 @@ fn sweep(
         realms,
         #[cfg(feature = "regexp")]
         regexps,
+        #[cfg(feature = "regexp")]
+        regexp_string_iterators,
         scripts,
+        source_text_modules,
+        synthetic_modules,
         #[cfg(feature = "set")]
         sets,
         #[cfg(feature = "set")]
@@ -1094,8 +1372,9 @@ fn sweep(
         declarative,
         function,
         global,
+        module,
         object,
-        private: _private_environments,
+        private,
     } = environments;
     let ElementArrays {
         e2pow4,
@@ -1245,6 +1524,15 @@ fn sweep(
                 sweep_heap_vector_values(array_iterators, &compactions, &bits.array_iterators);
             });
         }
+        if !async_from_sync_iterators.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    async_from_sync_iterators,
+                    &compactions,
+                    &bits.async_from_sync_iterators,
+                );
+            });
+        }
         if !async_generators.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(async_generators, &compactions, &bits.async_generators);
@@ -1301,6 +1589,12 @@ fn sweep(
                 sweep_heap_vector_values(dates, &compactions, &bits.dates);
             });
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        if !disposable_stacks.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(disposable_stacks, &compactions, &bits.disposable_stacks);
+            });
+        }
         if !declarative.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(declarative, &compactions, &bits.declarative_environments);
@@ -1349,6 +1643,15 @@ fn sweep(
                 sweep_heap_vector_values(generators, &compactions, &bits.generators);
             });
         }
+        if !graph_loading_states.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    graph_loading_states,
+                    &compactions,
+                    &bits.graph_loading_states,
+                );
+            });
+        }
         if !global.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(global, &compactions, &bits.global_environments);
@@ -1374,21 +1677,54 @@ fn sweep(
                 sweep_heap_vector_values(numbers, &compactions, &bits.numbers);
             });
         }
+        if !module.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(module, &compactions, &bits.module_environments);
+            });
+        }
         if !object.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(object, &compactions, &bits.object_environments);
             });
         }
+        if !private.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(private, &compactions, &bits.private_environments);
+            });
+        }
         if !objects.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(objects, &compactions, &bits.objects);
             });
         }
+        if !private_elements.is_empty() {
+            s.spawn(|| {
+                sweep_private_elements(private_elements, &compactions, bits);
+            });
+        }
         if !primitive_objects.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(primitive_objects, &compactions, &bits.primitive_objects);
             });
         }
+        if !promise_collector_functions.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    promise_collector_functions,
+                    &compactions,
+                    &bits.promise_collector_functions,
+                );
+            });
+        }
+        if !promise_group_records.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    promise_group_records,
+                    &compactions,
+                    &bits.promise_group_records,
+                );
+            });
+        }
         if !promise_reaction_records.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(
@@ -1428,11 +1764,35 @@ fn sweep(
                 sweep_heap_vector_values(regexps, &compactions, &bits.regexps);
             });
         }
+        #[cfg(feature = "regexp")]
+        if !regexp_string_iterators.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    regexp_string_iterators,
+                    &compactions,
+                    &bits.regexp_string_iterators,
+                );
+            });
+        }
         if !scripts.is_empty() {
             s.spawn(|| {
                 sweep_heap_vector_values(scripts, &compactions, &bits.scripts);
             });
         }
+        if !source_text_modules.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    source_text_modules,
+                    &compactions,
+                    &bits.source_text_modules,
+                );
+            });
+        }
+        if !synthetic_modules.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(synthetic_modules, &compactions, &bits.synthetic_modules);
+            });
+        }
         #[cfg(feature = "set")]
         if !sets.is_empty() {
             s.spawn(|| {
@@ -1518,6 +1878,25 @@ fn sweep(
     });
 }
 
+/// Sweep the \[\[PrivateElements]] side table: Entries of unreachable holders
+/// are removed, and the holders and elements of the remaining entries are
+/// compacted.
+fn sweep_private_elements(
+    private_elements: &mut AHashMap<Object<'static>, Vec<PrivateElement<'static>>>,
+    compactions: &CompactionLists,
+    bits: &HeapBits,
+) {
+    *private_elements = core::mem::take(private_elements)
+        .into_iter()
+        .filter(|(holder, _)| bits.is_object_marked(*holder))
+        .map(|(mut holder, mut elements)| {
+            holder.sweep_values(compactions);
+            elements.as_mut_slice().sweep_values(compactions);
+            (holder, elements)
+        })
+        .collect();
+}
+
 #[test]
 fn test_heap_gc() {
     use crate::engine::context::GcScope;
@@ -4,10 +4,14 @@
 
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::data::DateHeapData;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::data::DisposableStackHeapData;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExpHeapData;
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::builtins::shared_array_buffer::data::SharedArrayBufferHeapData;
+#[cfg(feature = "regexp")]
+use crate::ecmascript::builtins::text_processing::regexp_objects::regexp_string_iterator_objects::RegExpStringIteratorHeapData;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::builtins::{
     ArrayBufferHeapData, data_view::data::DataViewHeapData, typed_array::data::TypedArrayHeapData,
@@ -25,14 +29,21 @@ use crate::ecmascript::builtins::{
 use crate::{
     ecmascript::{
         builtins::{
-            ArrayHeapData, async_generator_objects::AsyncGeneratorHeapData,
-            control_abstraction_objects::generator_objects::GeneratorHeapData,
-            embedder_object::data::EmbedderObjectHeapData, error::ErrorHeapData,
+            ArrayHeapData,
+            async_generator_objects::AsyncGeneratorHeapData,
+            control_abstraction_objects::{
+                generator_objects::GeneratorHeapData,
+                iteration::async_from_sync_iterator_objects::AsyncFromSyncIteratorHeapData,
+            },
+            embedder_object::data::EmbedderObjectHeapData,
+            error::ErrorHeapData,
             finalization_registry::data::FinalizationRegistryHeapData,
             indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIteratorHeapData,
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIteratorHeapData,
-            map::data::MapHeapData, primitive_objects::PrimitiveObjectHeapData,
-            promise::data::PromiseHeapData, proxy::data::ProxyHeapData,
+            map::data::MapHeapData,
+            primitive_objects::PrimitiveObjectHeapData,
+            promise::data::PromiseHeapData,
+            proxy::data::ProxyHeapData,
             text_processing::string_objects::string_iterator_objects::StringIteratorHeapData,
         },
         types::{
@@ -193,6 +204,7 @@ impl<T> Default for BaseIndex<'_, T> {
 pub type ArrayBufferIndex<'a> = BaseIndex<'a, ArrayBufferHeapData<'static>>;
 pub type ArrayIndex<'a> = BaseIndex<'a, ArrayHeapData<'static>>;
 pub type ArrayIteratorIndex<'a> = BaseIndex<'a, ArrayIteratorHeapData<'static>>;
+pub type AsyncFromSyncIteratorIndex<'a> = BaseIndex<'a, AsyncFromSyncIteratorHeapData<'static>>;
 pub type AsyncGeneratorIndex<'a> = BaseIndex<'a, AsyncGeneratorHeapData<'static>>;
 pub type BigIntIndex<'a> = BaseIndex<'a, BigIntHeapData>;
 pub type BoundFunctionIndex<'a> = BaseIndex<'a, BoundFunctionHeapData<'static>>;
@@ -202,6 +214,8 @@ pub type BuiltinConstructorIndex<'a> = BaseIndex<'a, BuiltinConstructorHeapData<
 pub type DataViewIndex<'a> = BaseIndex<'a, DataViewHeapData<'static>>;
 #[cfg(feature = "date")]
 pub type DateIndex<'a> = BaseIndex<'a, DateHeapData<'static>>;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub type DisposableStackIndex<'a> = BaseIndex<'a, DisposableStackHeapData<'static>>;
 pub type ECMAScriptFunctionIndex<'a> = BaseIndex<'a, ECMAScriptFunctionHeapData<'static>>;
 pub type ElementIndex<'a> = BaseIndex<'a, [Option<Value<'static>>]>;
 pub type EmbedderObjectIndex<'a> = BaseIndex<'a, EmbedderObjectHeapData>;
@@ -218,6 +232,8 @@ pub type PromiseIndex<'a> = BaseIndex<'a, PromiseHeapData<'static>>;
 pub type ProxyIndex<'a> = BaseIndex<'a, ProxyHeapData<'static>>;
 #[cfg(feature = "regexp")]
 pub type RegExpIndex<'a> = BaseIndex<'a, RegExpHeapData<'static>>;
+#[cfg(feature = "regexp")]
+pub type RegExpStringIteratorIndex<'a> = BaseIndex<'a, RegExpStringIteratorHeapData<'static>>;
 #[cfg(feature = "set")]
 pub type SetIndex<'a> = BaseIndex<'a, SetHeapData<'static>>;
 #[cfg(feature = "set")]